@@ -2,9 +2,31 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 //! Asset browser resource and directory scanning.
-
+//!
+//! Full scans of the assets directory run on the IO task pool so large
+//! projects don't stall the editor; the tree is replaced once the scan
+//! finishes (see [`AssetBrowser::request_scan`]).
+//!
+//! A file system watcher on the assets directory sets
+//! [`AssetBrowser::needs_rescan`] when files are added, removed, or renamed
+//! outside the editor, and the tree is rescanned once the changes settle.
+
+use bevy::asset::AssetId;
 use bevy::prelude::*;
-use std::path::PathBuf;
+use bevy::tasks::{block_on, futures_lite::future, IoTaskPool, Task};
+use bevy_egui::EguiUserTextures;
+use notify::{EventKind, RecursiveMode, Watcher};
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::SceneFormat;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::unused_assets::AssetTrash;
+
+/// How long the assets directory must be quiet before a pending rescan runs.
+const RESCAN_DELAY: Duration = Duration::from_millis(200);
 
 /// Represents a file or directory entry in the asset browser.
 #[derive(Clone, Debug)]
@@ -52,7 +74,7 @@ pub enum AssetType {
     Image,
     /// Audio files (.ogg, .wav, .mp3)
     Audio,
-    /// Scene files (.scn.ron)
+    /// Scene files (.scn.ron, .scn.json)
     Scene,
     /// Unknown file type
     Unknown,
@@ -80,57 +102,238 @@ impl AssetType {
     }
 }
 
+/// Number of image previews kept loaded unless configured otherwise.
+pub const DEFAULT_PREVIEW_CAPACITY: usize = 32;
+
+/// Least-recently-used cache of image preview handles.
+///
+/// Holding a handle keeps its image loaded, so the cache is capped. Methods
+/// that drop entries return the dropped handles so their egui textures can be
+/// released as well (see [`cache_preview`]).
+#[derive(Clone, Debug)]
+pub struct PreviewCache {
+    capacity: usize,
+    /// Entries from least to most recently used.
+    entries: Vec<(String, Handle<Image>)>,
+}
+
+impl Default for PreviewCache {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_PREVIEW_CAPACITY,
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl PreviewCache {
+    /// Most previews kept at once.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Changes the capacity (at least 1), evicting the oldest entries if over it.
+    pub fn set_capacity(&mut self, capacity: usize) -> Vec<Handle<Image>> {
+        self.capacity = capacity.max(1);
+        self.evict()
+    }
+
+    /// Number of cached previews.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no previews are cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Cached handles, least recently used first.
+    pub fn handles(&self) -> impl Iterator<Item = &Handle<Image>> {
+        self.entries.iter().map(|(_, handle)| handle)
+    }
+
+    /// Caches `handle` under `key` as the most recently used entry.
+    ///
+    /// Returns the handles evicted to stay within capacity.
+    pub fn insert(&mut self, key: String, handle: Handle<Image>) -> Vec<Handle<Image>> {
+        self.entries.retain(|(existing, _)| *existing != key);
+        self.entries.push((key, handle));
+        self.evict()
+    }
+
+    /// Keeps only the entries for which `keep` returns true; returns the others' handles.
+    pub fn retain(
+        &mut self,
+        mut keep: impl FnMut(&str, &Handle<Image>) -> bool,
+    ) -> Vec<Handle<Image>> {
+        let mut removed = Vec::new();
+        self.entries.retain(|(key, handle)| {
+            let kept = keep(key, handle);
+            if !kept {
+                removed.push(handle.clone());
+            }
+            kept
+        });
+        removed
+    }
+
+    fn evict(&mut self) -> Vec<Handle<Image>> {
+        let excess = self.entries.len().saturating_sub(self.capacity);
+        self.entries
+            .drain(..excess)
+            .map(|(_, handle)| handle)
+            .collect()
+    }
+}
+
+/// Caches a preview so it stays loaded, and releases whatever the cache evicts.
+pub fn cache_preview(world: &mut World, key: String, handle: Handle<Image>) {
+    let evicted = world
+        .resource_mut::<AssetBrowser>()
+        .preview_handles
+        .insert(key, handle);
+    release_previews(world, &evicted);
+}
+
+/// Unregisters dropped previews from egui so their GPU textures can be freed.
+///
+/// Images still cached under another key keep their texture.
+pub fn release_previews(world: &mut World, released: &[Handle<Image>]) {
+    if released.is_empty() {
+        return;
+    }
+    let cached: Vec<AssetId<Image>> = world
+        .resource::<AssetBrowser>()
+        .preview_handles
+        .handles()
+        .map(Handle::id)
+        .collect();
+    let Some(mut egui_user_textures) = world.get_resource_mut::<EguiUserTextures>() else {
+        return;
+    };
+    for handle in released {
+        if !cached.contains(&handle.id()) {
+            egui_user_textures.remove_image(handle);
+        }
+    }
+}
+
+/// Search query and type filter for the asset browser.
+///
+/// While either is set the browser shows a flat list of matching files
+/// instead of the directory tree.
+#[derive(Clone, Debug, Default)]
+pub struct AssetFilter {
+    /// Case-insensitive text matched against file names, including extensions.
+    pub query: String,
+    /// Only show files of this type, if set.
+    pub asset_type: Option<AssetType>,
+}
+
+impl AssetFilter {
+    /// Whether the filter hides anything.
+    pub fn is_active(&self) -> bool {
+        !self.query.trim().is_empty() || self.asset_type.is_some()
+    }
+
+    /// Whether a file entry passes the filter.
+    pub fn matches(&self, entry: &AssetEntry) -> bool {
+        if entry.is_directory {
+            return false;
+        }
+        if let Some(asset_type) = self.asset_type {
+            if AssetBrowser::get_asset_type(&entry.path) != asset_type {
+                return false;
+            }
+        }
+        let query = self.query.trim().to_lowercase();
+        query.is_empty() || entry.name.to_lowercase().contains(&query)
+    }
+}
+
 /// Resource tracking the state of the asset browser.
 #[derive(Resource, Default)]
 pub struct AssetBrowser {
+    /// Absolute path of the assets directory being browsed.
+    pub assets_root: PathBuf,
     /// Root entries of the asset tree.
     pub files: Vec<AssetEntry>,
     /// Currently selected file path.
     pub selected_path: Option<String>,
-    /// Cached preview handles for images.
-    pub preview_handles: std::collections::HashMap<String, Handle<Image>>,
-    /// Whether the browser needs to be rescanned.
+    /// Image previews kept loaded, least recently shown evicted first.
+    pub preview_handles: PreviewCache,
+    /// Whether the browser needs to be rescanned; set by the file watcher
+    /// or by tools that change files, and cleared by the next scan.
     pub needs_rescan: bool,
     /// Currently playing audio entity (if any).
     pub audio_playback_entity: Option<Entity>,
     /// Path of currently playing audio.
     pub playing_audio_path: Option<String>,
+    /// Search query and type filter.
+    pub filter: AssetFilter,
+    /// Scan running in the background, if any.
+    scan_task: Option<Task<Vec<AssetEntry>>>,
 }
 
 impl AssetBrowser {
-    /// Creates a new asset browser and performs initial scan.
-    pub fn new() -> Self {
+    /// Creates a new asset browser for the given assets directory and starts the initial scan.
+    pub fn new(assets_root: PathBuf) -> Self {
         let mut browser = Self {
+            assets_root,
             files: Vec::new(),
             selected_path: None,
-            preview_handles: std::collections::HashMap::new(),
+            preview_handles: PreviewCache::default(),
             needs_rescan: false,
             audio_playback_entity: None,
             playing_audio_path: None,
+            filter: AssetFilter::default(),
+            scan_task: None,
         };
-        browser.scan_assets_directory();
+        browser.request_scan();
         browser
     }
 
-    /// Scans the assets directory and populates the file tree.
-    pub fn scan_assets_directory(&mut self) {
-        self.files.clear();
+    /// Starts scanning the assets directory in the background.
+    ///
+    /// The tree keeps showing the previous scan until this one finishes. A
+    /// scan already running is cancelled, since its result would be stale.
+    pub fn request_scan(&mut self) {
+        let assets_root = self.assets_root.clone();
+        let task =
+            IoTaskPool::get_or_init(Default::default).spawn(async move { scan_tree(&assets_root) });
+        self.scan_task = Some(task);
+        self.needs_rescan = false;
+    }
 
-        let assets_path = PathBuf::from("assets");
-        if !assets_path.exists() {
-            // Create assets directory if it doesn't exist
-            let _ = std::fs::create_dir_all(&assets_path);
-        }
+    /// Whether a background scan is running.
+    pub fn is_scanning(&self) -> bool {
+        self.scan_task.is_some()
+    }
 
-        if let Ok(entries) = Self::scan_directory(&assets_path, "") {
-            self.files = entries;
-        }
+    /// Scans the assets directory and populates the file tree, blocking until done.
+    ///
+    /// Used after the browser's own file operations, which need the new tree
+    /// at once. Cancels any background scan.
+    pub fn scan_assets_directory(&mut self) {
+        self.scan_task = None;
+        let files = scan_tree(&self.assets_root);
+        self.apply_scan(files);
+    }
 
+    /// Replaces the tree with a finished scan, keeping expanded directories expanded.
+    fn apply_scan(&mut self, files: Vec<AssetEntry>) {
+        let mut expanded = Vec::new();
+        Self::collect_expanded(&self.files, &mut expanded);
+        self.files = files;
+        for path in expanded {
+            self.set_expanded(&path);
+        }
         self.needs_rescan = false;
     }
 
     /// Recursively scans a directory.
-    fn scan_directory(path: &PathBuf, relative_base: &str) -> std::io::Result<Vec<AssetEntry>> {
+    fn scan_directory(path: &Path, relative_base: &str) -> std::io::Result<Vec<AssetEntry>> {
         let mut entries = Vec::new();
 
         let mut dir_entries: Vec<_> = std::fs::read_dir(path)?.filter_map(|e| e.ok()).collect();
@@ -183,12 +386,35 @@ impl AssetBrowser {
 
     /// Gets the asset type for a file path.
     pub fn get_asset_type(path: &str) -> AssetType {
+        if SceneFormat::from_file_name(path).is_some() {
+            return AssetType::Scene;
+        }
         path.rsplit('.')
             .next()
             .map(AssetType::from_extension)
             .unwrap_or(AssetType::Unknown)
     }
 
+    /// Files anywhere in the tree that pass the current filter, in tree order.
+    pub fn filtered_files(&self) -> Vec<&AssetEntry> {
+        fn collect<'a>(
+            entries: &'a [AssetEntry],
+            filter: &AssetFilter,
+            out: &mut Vec<&'a AssetEntry>,
+        ) {
+            for entry in entries {
+                if filter.matches(entry) {
+                    out.push(entry);
+                }
+                collect(&entry.children, filter, out);
+            }
+        }
+
+        let mut matches = Vec::new();
+        collect(&self.files, &self.filter, &mut matches);
+        matches
+    }
+
     /// Toggles expansion state of a directory at the given path.
     pub fn toggle_directory(&mut self, path: &str) {
         Self::toggle_directory_recursive(&mut self.files, path);
@@ -206,21 +432,342 @@ impl AssetBrowser {
         }
     }
 
+    fn collect_expanded(entries: &[AssetEntry], expanded: &mut Vec<String>) {
+        for entry in entries.iter().filter(|entry| entry.is_directory) {
+            if entry.expanded {
+                expanded.push(entry.path.clone());
+            }
+            Self::collect_expanded(&entry.children, expanded);
+        }
+    }
+
+    /// Expands the directory at `path` and its ancestors.
+    fn set_expanded(&mut self, path: &str) {
+        let mut entries = &mut self.files;
+        while let Some(entry) = entries.iter_mut().find(|entry| {
+            entry.is_directory
+                && (entry.path == path || path.starts_with(&format!("{}/", entry.path)))
+        }) {
+            entry.expanded = true;
+            entries = &mut entry.children;
+        }
+    }
+
+    /// Renames the file or directory at `path` within its folder.
+    ///
+    /// Returns the new path. The selection follows the renamed entry.
+    pub fn rename_entry(&mut self, path: &str, new_name: &str) -> Result<String, String> {
+        let new_name = validate_file_name(new_name)?;
+        let new_path = join_asset_path(parent_asset_path(path), new_name);
+        if new_path == path {
+            return Ok(new_path);
+        }
+        let target = self.assets_root.join(&new_path);
+        if target.exists() {
+            return Err(format!("'{}' already exists", new_path));
+        }
+        std::fs::rename(self.assets_root.join(path), &target).map_err(|e| e.to_string())?;
+
+        if let Some(selected) = &self.selected_path {
+            if let Some(rest) = strip_asset_path(selected, path) {
+                self.selected_path = Some(format!("{}{}", new_path, rest));
+            }
+        }
+        let was_expanded = self.find_entry(path).is_some_and(|entry| entry.expanded);
+        self.scan_assets_directory();
+        if was_expanded {
+            self.set_expanded(&new_path);
+        }
+        Ok(new_path)
+    }
+
+    /// Removes the file or directory (with its contents) at `path` through
+    /// `trash`, normally to the system trash.
+    pub fn delete_entry(&mut self, path: &str, trash: AssetTrash) -> Result<(), String> {
+        trash.remove(&self.assets_root, &[path.to_string()])?;
+
+        if self
+            .selected_path
+            .as_deref()
+            .is_some_and(|selected| strip_asset_path(selected, path).is_some())
+        {
+            self.selected_path = None;
+        }
+        self.scan_assets_directory();
+        Ok(())
+    }
+
+    /// Creates a folder named `name` inside `parent` ("" for the assets root).
+    ///
+    /// Returns the new folder's path; its parent is expanded to show it.
+    pub fn create_folder(&mut self, parent: &str, name: &str) -> Result<String, String> {
+        let name = validate_file_name(name)?;
+        let path = join_asset_path(parent, name);
+        let full_path = self.assets_root.join(&path);
+        if full_path.exists() {
+            return Err(format!("'{}' already exists", path));
+        }
+        std::fs::create_dir_all(&full_path).map_err(|e| e.to_string())?;
+
+        self.scan_assets_directory();
+        if !parent.is_empty() {
+            self.set_expanded(parent);
+        }
+        Ok(path)
+    }
+
+    /// Creates a file named `name` inside `parent` ("" for the assets root)
+    /// holding `contents`.
+    ///
+    /// Returns the new file's path; it is selected and its folder expanded.
+    pub fn create_file(
+        &mut self,
+        parent: &str,
+        name: &str,
+        contents: &str,
+    ) -> Result<String, String> {
+        let name = validate_file_name(name)?;
+        let path = join_asset_path(parent, name);
+        let full_path = self.assets_root.join(&path);
+        if full_path.exists() {
+            return Err(format!("'{}' already exists", path));
+        }
+        std::fs::write(&full_path, contents).map_err(|e| e.to_string())?;
+
+        self.scan_assets_directory();
+        if !parent.is_empty() {
+            self.set_expanded(parent);
+        }
+        self.selected_path = Some(path.clone());
+        Ok(path)
+    }
+
+    /// Folder that new files go into: the selected folder, or the selected file's folder.
+    ///
+    /// "" is the assets root.
+    pub fn target_folder(&self) -> String {
+        let Some(selected) = &self.selected_path else {
+            return String::new();
+        };
+        match self.find_entry(selected) {
+            Some(entry) if entry.is_directory => entry.path.clone(),
+            _ => parent_asset_path(selected).to_string(),
+        }
+    }
+
+    /// Copies external files into `folder` ("" for the assets root).
+    ///
+    /// Names that are taken get a numeric suffix rather than being
+    /// overwritten. Returns the new paths; the last one is selected and its
+    /// folder expanded.
+    pub fn import_files(&mut self, files: &[PathBuf], folder: &str) -> Result<Vec<String>, String> {
+        let target_dir = self.assets_root.join(folder);
+        std::fs::create_dir_all(&target_dir).map_err(|e| e.to_string())?;
+
+        let mut imported = Vec::new();
+        let mut result = Ok(());
+        for file in files {
+            let Some(file_name) = file
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+            else {
+                continue;
+            };
+            let name = unique_file_name(&target_dir, &file_name);
+            if let Err(e) = std::fs::copy(file, target_dir.join(&name)) {
+                result = Err(format!("{}: {}", file.display(), e));
+                break;
+            }
+            imported.push(join_asset_path(folder, &name));
+        }
+
+        self.scan_assets_directory();
+        if !folder.is_empty() {
+            self.set_expanded(folder);
+        }
+        if let Some(last) = imported.last() {
+            self.selected_path = Some(last.clone());
+        }
+        result.map(|()| imported)
+    }
+
+    /// Finds the entry at `path` anywhere in the tree.
+    pub fn find_entry(&self, path: &str) -> Option<&AssetEntry> {
+        fn find<'a>(entries: &'a [AssetEntry], path: &str) -> Option<&'a AssetEntry> {
+            entries.iter().find_map(|entry| {
+                if entry.path == path {
+                    Some(entry)
+                } else {
+                    find(&entry.children, path)
+                }
+            })
+        }
+        find(&self.files, path)
+    }
+
     /// Checks if a specific audio file is playing.
     pub fn is_playing(&self, path: &str) -> bool {
         self.playing_audio_path.as_ref() == Some(&path.to_string())
     }
 }
 
+/// Scans the assets directory into a tree, creating the directory if it doesn't exist.
+fn scan_tree(assets_root: &Path) -> Vec<AssetEntry> {
+    if !assets_root.exists() {
+        let _ = std::fs::create_dir_all(assets_root);
+    }
+    AssetBrowser::scan_directory(assets_root, "").unwrap_or_default()
+}
+
+/// Trims `name` and checks it is a single path component.
+fn validate_file_name(name: &str) -> Result<&str, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Name cannot be empty".to_string());
+    }
+    if name == "." || name == ".." || name.contains(['/', '\\']) {
+        return Err(format!("'{}' is not a valid name", name));
+    }
+    Ok(name)
+}
+
+/// `file_name`, or `stem_2.ext`, `stem_3.ext`, ... if it exists in `dir`.
+fn unique_file_name(dir: &Path, file_name: &str) -> String {
+    if !dir.join(file_name).exists() {
+        return file_name.to_string();
+    }
+    let (stem, extension) = match file_name.split_once('.') {
+        Some((stem, extension)) => (stem, format!(".{}", extension)),
+        None => (file_name, String::new()),
+    };
+    (2..)
+        .map(|index| format!("{}_{}{}", stem, index, extension))
+        .find(|name| !dir.join(name).exists())
+        .expect("some numbered name is free")
+}
+
+/// The folder containing `path`, "" for entries at the assets root.
+fn parent_asset_path(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(parent, _)| parent)
+}
+
+fn join_asset_path(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}/{}", parent, name)
+    }
+}
+
+/// The remainder of `path` if it is `base` or lies inside it ("" or "/...").
+fn strip_asset_path<'a>(path: &'a str, base: &str) -> Option<&'a str> {
+    path.strip_prefix(base)
+        .filter(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
 /// Marker component for audio preview playback.
 #[derive(Component)]
 pub struct AudioPreviewMarker;
 
+/// Resource watching the assets directory for external changes.
+#[derive(Resource)]
+pub struct AssetWatcher {
+    /// Kept alive for as long as the directory is watched.
+    _watcher: notify::RecommendedWatcher,
+    events: Mutex<Receiver<notify::Result<notify::Event>>>,
+    /// When the last relevant change was seen.
+    last_change: Option<Instant>,
+}
+
+impl AssetWatcher {
+    /// Starts watching `assets_root` and everything below it.
+    pub fn new(assets_root: &Path) -> notify::Result<Self> {
+        let (sender, receiver) = channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        watcher.watch(assets_root, RecursiveMode::Recursive)?;
+        Ok(Self {
+            _watcher: watcher,
+            events: Mutex::new(receiver),
+            last_change: None,
+        })
+    }
+}
+
 /// Plugin that sets up the asset browser.
 pub struct AssetBrowserPlugin;
 
 impl Plugin for AssetBrowserPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(AssetBrowser::new());
+        let assets_root = app
+            .world()
+            .get_resource::<ProjectRoot>()
+            .cloned()
+            .unwrap_or_else(ProjectRoot::discover)
+            .assets_dir();
+        let browser = AssetBrowser::new(assets_root);
+
+        // Without a watcher the tree only updates on "⟳ Refresh"
+        match AssetWatcher::new(&browser.assets_root) {
+            Ok(watcher) => {
+                app.insert_resource(watcher);
+            }
+            Err(e) => warn!("Not watching the assets directory for changes: {}", e),
+        }
+        app.insert_resource(browser).add_systems(
+            Update,
+            (watch_assets_directory, rescan_assets, finish_asset_scan).chain(),
+        );
+    }
+}
+
+/// Flags a rescan when files in the assets directory are created, removed, or renamed.
+///
+/// Content-only changes don't alter the tree and are ignored.
+fn watch_assets_directory(
+    watcher: Option<ResMut<AssetWatcher>>,
+    mut browser: ResMut<AssetBrowser>,
+) {
+    let Some(mut watcher) = watcher else {
+        return;
+    };
+    let Ok(events) = watcher.events.get_mut() else {
+        return;
+    };
+    let mut changed = false;
+    for event in events.try_iter().flatten() {
+        changed |= matches!(
+            event.kind,
+            EventKind::Create(_)
+                | EventKind::Remove(_)
+                | EventKind::Modify(notify::event::ModifyKind::Name(_))
+        );
+    }
+    if changed {
+        watcher.last_change = Some(Instant::now());
+        browser.needs_rescan = true;
+    }
+}
+
+/// Rescans the tree when flagged, once the directory has been quiet for [`RESCAN_DELAY`].
+fn rescan_assets(watcher: Option<Res<AssetWatcher>>, mut browser: ResMut<AssetBrowser>) {
+    if !browser.needs_rescan {
+        return;
+    }
+    let settling = watcher
+        .and_then(|watcher| watcher.last_change)
+        .is_some_and(|last_change| last_change.elapsed() < RESCAN_DELAY);
+    if !settling {
+        browser.request_scan();
+    }
+}
+
+/// Applies the background scan's result once it finishes.
+fn finish_asset_scan(mut browser: ResMut<AssetBrowser>) {
+    let Some(task) = browser.scan_task.as_mut() else {
+        return;
+    };
+    if let Some(files) = block_on(future::poll_once(task)) {
+        browser.scan_task = None;
+        browser.apply_scan(files);
     }
 }
@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Viewport display of [`AudioZone`] areas.
+//!
+//! Each zone is drawn as a tinted rectangle in its entity's space, colored by
+//! kind and labelled with the file it plays, so overlapping music areas are
+//! easy to spot.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::ambient::{AudioZone, AudioZoneKind};
+
+use crate::editor_camera::ViewportCamera;
+
+/// Color of music zones.
+const COLOR_MUSIC: egui::Color32 = egui::Color32::from_rgb(120, 140, 255);
+
+/// Color of ambience zones.
+const COLOR_AMBIENCE: egui::Color32 = egui::Color32::from_rgb(90, 210, 140);
+
+/// Opacity of the zone fill.
+const FILL_OPACITY: f32 = 0.12;
+
+/// Draws every audio zone in the viewport.
+pub fn draw_audio_zones(painter: &egui::Painter, viewport_rect: egui::Rect, world: &mut World) {
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return;
+    };
+
+    let mut zones = world.query::<(&AudioZone, &GlobalTransform)>();
+    for (zone, transform) in zones.iter(world) {
+        let corners: Vec<egui::Pos2> = [
+            Vec2::new(-1.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, -1.0),
+            Vec2::new(-1.0, -1.0),
+        ]
+        .iter()
+        .map(|corner| {
+            let position = transform
+                .transform_point((*corner * zone.half_size).extend(0.0))
+                .truncate();
+            view.world_to_screen(position, viewport_rect)
+        })
+        .collect();
+        if !viewport_rect.intersects(egui::Rect::from_points(&corners)) {
+            continue;
+        }
+        let (color, kind) = match zone.kind {
+            AudioZoneKind::Music => (COLOR_MUSIC, "♫"),
+            AudioZoneKind::Ambience => (COLOR_AMBIENCE, "🔊"),
+        };
+
+        let top_left = corners[0];
+        painter.add(egui::Shape::convex_polygon(
+            corners,
+            color.gamma_multiply(FILL_OPACITY),
+            egui::Stroke::new(1.5, color),
+        ));
+        let file = zone.sound.rsplit('/').next().unwrap_or_default();
+        let label = if file.is_empty() {
+            format!("{} (no sound)", kind)
+        } else {
+            format!("{} {}", kind, file)
+        };
+        painter.text(
+            top_left + egui::vec2(4.0, 4.0),
+            egui::Align2::LEFT_TOP,
+            label,
+            egui::FontId::proportional(11.0),
+            color,
+        );
+    }
+}
@@ -0,0 +1,188 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Periodic autosaves of the open scene, with rotating backups.
+//!
+//! While the scene has unsaved changes and the editor is stopped, a copy is
+//! written next to the scene file every [`AutosaveSettings::interval`]
+//! seconds, as `level.scn.ron.autosave`. The previous autosave moves to
+//! `.autosave.1`, that one to `.autosave.2`, and so on, keeping
+//! [`AutosaveSettings::backups`] older copies. The scene file itself and its
+//! dirty flag are left alone. Scenes that were never saved have nowhere to
+//! autosave to. Saving the scene deletes its latest autosave, since the scene
+//! file is then the newest version, but keeps the older copies as backups.
+//!
+//! On startup the assets directory is searched for autosaves newer than their
+//! scene file, which the recovery prompt offers to open.
+
+use bevy::prelude::*;
+use sandbox_engine::editor_state::EditorPlayState;
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::{save_scene_copy, SceneManager, SceneResult, SceneSaved};
+use std::path::{Path, PathBuf};
+
+use crate::ui::file_menu::set_error_message;
+use crate::unused_assets::collect_files;
+
+/// Extension added to a scene file's name for its autosave.
+pub const AUTOSAVE_EXTENSION: &str = "autosave";
+
+/// When and how much to autosave.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct AutosaveSettings {
+    /// Whether the scene is autosaved.
+    pub enabled: bool,
+    /// Seconds of unsaved changes between autosaves.
+    pub interval: f32,
+    /// Older autosaves kept besides the latest one.
+    pub backups: usize,
+}
+
+impl Default for AutosaveSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval: 120.0,
+            backups: 3,
+        }
+    }
+}
+
+/// Resource tracking the autosave timer.
+#[derive(Resource, Default)]
+pub struct AutosaveState {
+    /// Seconds the scene has had unsaved changes since the last autosave.
+    pub since_autosave: f32,
+}
+
+/// Autosaves found on startup that are newer than their scene file.
+#[derive(Resource, Default)]
+pub struct AutosaveRecovery {
+    /// Autosave files waiting for the user to recover or delete them.
+    pub found: Vec<PathBuf>,
+}
+
+/// Plugin that autosaves the scene and looks for autosaves to recover.
+pub struct AutosavePlugin;
+
+impl Plugin for AutosavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AutosaveSettings>()
+            .init_resource::<AutosaveState>()
+            .init_resource::<AutosaveRecovery>()
+            .add_systems(Startup, find_autosaves_on_startup)
+            .add_systems(Update, (autosave_scene, remove_autosaves_on_save));
+    }
+}
+
+/// Path of the autosave for `scene`.
+pub fn autosave_path(scene: &Path) -> PathBuf {
+    let mut file_name = scene.as_os_str().to_os_string();
+    file_name.push(".");
+    file_name.push(AUTOSAVE_EXTENSION);
+    PathBuf::from(file_name)
+}
+
+/// Path of the `number`th older autosave of `scene`, counting from 1.
+pub fn backup_path(scene: &Path, number: usize) -> PathBuf {
+    let mut file_name = autosave_path(scene).into_os_string();
+    file_name.push(format!(".{}", number));
+    PathBuf::from(file_name)
+}
+
+/// The scene file an autosave belongs to, or `None` if `autosave` isn't one.
+pub fn autosaved_scene(autosave: &Path) -> Option<PathBuf> {
+    let name = autosave.to_str()?;
+    name.strip_suffix(&format!(".{}", AUTOSAVE_EXTENSION))
+        .map(PathBuf::from)
+}
+
+/// Writes an autosave of the scene for `scene`, first moving older autosaves
+/// one place down and dropping the oldest past `backups`.
+pub fn write_autosave(world: &mut World, scene: &Path, backups: usize) -> SceneResult<PathBuf> {
+    let autosave = autosave_path(scene);
+    if autosave.is_file() {
+        if backups == 0 {
+            let _ = std::fs::remove_file(&autosave);
+        } else {
+            let _ = std::fs::remove_file(backup_path(scene, backups));
+            for number in (1..backups).rev() {
+                let _ = std::fs::rename(backup_path(scene, number), backup_path(scene, number + 1));
+            }
+            let _ = std::fs::rename(&autosave, backup_path(scene, 1));
+        }
+    }
+    save_scene_copy(world, &autosave)?;
+    Ok(autosave)
+}
+
+/// Autosaves under `assets_dir` that are newer than their scene file, or
+/// whose scene file is gone, sorted by path.
+pub fn find_recoverable_autosaves(assets_dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    collect_files(assets_dir, "", &mut files);
+    let mut found: Vec<PathBuf> = files
+        .into_iter()
+        .map(|(relative, _)| assets_dir.join(relative))
+        .filter(|autosave| {
+            let Some(scene) = autosaved_scene(autosave) else {
+                return false;
+            };
+            let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+            match (modified(autosave), modified(&scene)) {
+                (Some(autosaved), Some(saved)) => autosaved > saved,
+                (Some(_), None) => true,
+                _ => false,
+            }
+        })
+        .collect();
+    found.sort();
+    found
+}
+
+/// Looks for autosaves left behind by an editor that didn't save its scene.
+fn find_autosaves_on_startup(root: Res<ProjectRoot>, mut recovery: ResMut<AutosaveRecovery>) {
+    recovery.found = find_recoverable_autosaves(&root.assets_dir());
+}
+
+/// Counts how long the scene has had unsaved changes and autosaves it once
+/// the interval has passed.
+fn autosave_scene(world: &mut World) {
+    let settings = world.resource::<AutosaveSettings>().clone();
+    let (dirty, path) = {
+        let manager = world.resource::<SceneManager>();
+        (manager.dirty, manager.current_scene_path.clone())
+    };
+    let Some(path) = path.filter(|_| dirty && settings.enabled) else {
+        world.resource_mut::<AutosaveState>().since_autosave = 0.0;
+        return;
+    };
+
+    let delta = world.resource::<Time<Real>>().delta_secs();
+    let mut state = world.resource_mut::<AutosaveState>();
+    state.since_autosave += delta;
+    let due = state.since_autosave >= settings.interval;
+    // Play mode changes aren't part of the scene; wait until stopped
+    let stopped = *world.resource::<State<EditorPlayState>>().get() == EditorPlayState::Stopped;
+    if !due || !stopped {
+        return;
+    }
+
+    world.resource_mut::<AutosaveState>().since_autosave = 0.0;
+    if let Err(e) = write_autosave(world, &path, settings.backups) {
+        set_error_message(world, &format!("Failed to autosave: {}", e));
+    }
+}
+
+/// Deletes the latest autosave of a scene once it is saved, and stops
+/// offering it for recovery. Older copies are kept as backups.
+fn remove_autosaves_on_save(
+    mut saved: EventReader<SceneSaved>,
+    mut recovery: ResMut<AutosaveRecovery>,
+) {
+    for event in saved.read() {
+        let autosave = autosave_path(&event.path);
+        let _ = std::fs::remove_file(&autosave);
+        recovery.found.retain(|found| *found != autosave);
+    }
+}
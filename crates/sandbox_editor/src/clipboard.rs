@@ -0,0 +1,207 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Entity copy/paste through an in-memory scene clipboard.
+//!
+//! Copied entities (with their children) are serialized to scene RON, so the
+//! clipboard survives loading another scene and pastes into whatever scene is
+//! open. Duplicate uses the same extraction without going through text.
+//! The inspector's Transform section copies and pastes through the same
+//! resource, separately from copied entities.
+
+use bevy::ecs::entity::{EntityHashMap, EntityHashSet};
+use bevy::prelude::*;
+use bevy::scene::serde::SceneDeserializer;
+use bevy::scene::{DynamicScene, DynamicSceneBuilder};
+use sandbox_engine::scene::{drop_unsaved_children, scene_entities, SceneManager};
+use serde::de::DeserializeSeed;
+
+use crate::selection::EditorSelection;
+use crate::ui::file_menu::{set_error_message, set_success_message};
+use crate::ui::selection_roots;
+
+/// Resource holding the copied entities as scene RON.
+#[derive(Resource, Default)]
+pub struct EditorClipboard {
+    /// Serialized scene of the copied entities, if anything was copied.
+    pub scene: Option<String>,
+    /// Transform copied from the inspector, if any.
+    pub transform: Option<Transform>,
+}
+
+/// Plugin that sets up the entity clipboard.
+pub struct ClipboardPlugin;
+
+impl Plugin for ClipboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EditorClipboard>();
+    }
+}
+
+/// Extracts `roots` and their descendants into a scene.
+///
+/// Only entities a save would write are extracted, as in `extract_scene`:
+/// runtime children such as particles and glyphs are left out, along with
+/// their entries in `Children`. The roots' `Parent` is stripped since it
+/// points outside the extracted set.
+pub fn extract_subtrees(world: &World, roots: &[Entity]) -> DynamicScene {
+    let saved: EntityHashSet = scene_entities(world).into_iter().collect();
+    let mut entities = Vec::new();
+    for &root in roots.iter().filter(|root| saved.contains(*root)) {
+        entities.push(root);
+        collect_descendants(world, root, &saved, &mut entities);
+    }
+    let extracted: EntityHashSet = entities.iter().copied().collect();
+
+    let mut scene = DynamicSceneBuilder::from_world(world)
+        .extract_entities(entities.into_iter())
+        .build();
+    drop_unsaved_children(world, &mut scene, &extracted);
+    for dynamic in &mut scene.entities {
+        if roots.contains(&dynamic.entity) {
+            dynamic
+                .components
+                .retain(|component| !component.represents::<Parent>());
+        }
+    }
+    scene
+}
+
+/// Spawns a scene's entities as new entities.
+///
+/// Returns the map from scene entities to the spawned ones.
+pub fn spawn_subtrees(
+    world: &mut World,
+    scene: &DynamicScene,
+) -> Result<EntityHashMap<Entity>, String> {
+    let mut entity_map = EntityHashMap::default();
+    scene
+        .write_to_world(world, &mut entity_map)
+        .map_err(|e| format!("{:?}", e))?;
+    Ok(entity_map)
+}
+
+/// Copies the selected entities and their children to the clipboard (Ctrl+C).
+pub fn copy_selection(world: &mut World) {
+    let roots = selection_roots(world);
+    if roots.is_empty() {
+        return;
+    }
+
+    let scene = extract_subtrees(world, &roots);
+    let serialized = {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        scene.serialize(&type_registry)
+    };
+    match serialized {
+        Ok(serialized) => {
+            world.resource_mut::<EditorClipboard>().scene = Some(serialized);
+            set_success_message(world, &format!("Copied {} entities", roots.len()));
+        }
+        Err(e) => set_error_message(world, &format!("Failed to copy: {}", e)),
+    }
+}
+
+/// Pastes the clipboard into the current scene and selects the pasted entities (Ctrl+V).
+///
+/// Pasted entities keep their copied transforms and are placed at the top level.
+pub fn paste_clipboard(world: &mut World) {
+    paste(world);
+}
+
+/// Pastes the clipboard so the pasted entities are centered on `position`.
+///
+/// The center is the average position of the pasted top-level entities, so
+/// their layout relative to each other is kept.
+pub fn paste_clipboard_at(world: &mut World, position: Vec2) {
+    let pasted = paste(world);
+    if pasted.is_empty() {
+        return;
+    }
+
+    let translations: Vec<Vec2> = pasted
+        .iter()
+        .filter_map(|&entity| world.get::<Transform>(entity))
+        .map(|transform| transform.translation.truncate())
+        .collect();
+    if translations.is_empty() {
+        return;
+    }
+    let center = translations.iter().sum::<Vec2>() / translations.len() as f32;
+    let offset = (position - center).extend(0.0);
+    for entity in pasted {
+        if let Some(mut transform) = world.get_mut::<Transform>(entity) {
+            transform.translation += offset;
+        }
+    }
+}
+
+/// Spawns the clipboard contents, selects them, and returns the pasted top-level entities.
+fn paste(world: &mut World) -> Vec<Entity> {
+    let Some(serialized) = world.resource::<EditorClipboard>().scene.clone() else {
+        return Vec::new();
+    };
+
+    let scene = {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &type_registry,
+        };
+        bevy::scene::ron::de::Deserializer::from_str(&serialized)
+            .map_err(|e| e.to_string())
+            .and_then(|mut deserializer| {
+                scene_deserializer
+                    .deserialize(&mut deserializer)
+                    .map_err(|e| e.to_string())
+            })
+    };
+    let scene = match scene {
+        Ok(scene) => scene,
+        Err(e) => {
+            set_error_message(world, &format!("Failed to paste: {}", e));
+            return Vec::new();
+        }
+    };
+
+    let entity_map = match spawn_subtrees(world, &scene) {
+        Ok(entity_map) => entity_map,
+        Err(e) => {
+            set_error_message(world, &format!("Failed to paste: {}", e));
+            return Vec::new();
+        }
+    };
+
+    let pasted: Vec<Entity> = scene
+        .entities
+        .iter()
+        .filter(|dynamic| {
+            !dynamic
+                .components
+                .iter()
+                .any(|component| component.represents::<Parent>())
+        })
+        .filter_map(|dynamic| entity_map.get(&dynamic.entity).copied())
+        .collect();
+    world.resource_mut::<EditorSelection>().set(pasted.clone());
+
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
+    }
+    pasted
+}
+
+/// Appends the descendants of `entity` that are in `saved` to `out`.
+fn collect_descendants(
+    world: &World,
+    entity: Entity,
+    saved: &EntityHashSet,
+    out: &mut Vec<Entity>,
+) {
+    let Some(children) = world.get::<Children>(entity) else {
+        return;
+    };
+    for &child in children.iter().filter(|child| saved.contains(*child)) {
+        out.push(child);
+        collect_descendants(world, child, saved, out);
+    }
+}
@@ -0,0 +1,81 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Inspectors registered by game crates for their own components.
+//!
+//! A custom inspector replaces the built-in or reflection-driven editor for
+//! one component type. It gets the component's inspector section, the world,
+//! and the inspected entity, and edits the component directly:
+//! ```ignore
+//! use sandbox_editor::custom_inspector::RegisterInspectorExt;
+//! use sandbox_editor::egui;
+//!
+//! app.register_inspector::<Fuel>(|ui: &mut egui::Ui, world: &mut World, entity: Entity| {
+//!     let Some(mut fuel) = world.get_mut::<Fuel>(entity) else {
+//!         return;
+//!     };
+//!     ui.add(egui::ProgressBar::new(fuel.amount / fuel.capacity));
+//!     ui.add(egui::Slider::new(&mut fuel.amount, 0.0..=fuel.capacity));
+//! });
+//! ```
+//!
+//! For reflect-registered components the editor snapshots the component
+//! around the call, so edits made by a custom inspector are undoable and
+//! mark the scene dirty without extra work in the inspector.
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy_egui::egui;
+use std::any::TypeId;
+use std::sync::Arc;
+
+/// A function drawing the inspector section for one component.
+pub type InspectorFn = dyn Fn(&mut egui::Ui, &mut World, Entity) + Send + Sync;
+
+/// Resource mapping component types to their custom inspectors.
+#[derive(Resource, Default)]
+pub struct CustomInspectors {
+    inspectors: HashMap<TypeId, Arc<InspectorFn>>,
+}
+
+impl CustomInspectors {
+    /// Registers `inspector` for component `T`, replacing any earlier one.
+    pub fn register<T: Component>(
+        &mut self,
+        inspector: impl Fn(&mut egui::Ui, &mut World, Entity) + Send + Sync + 'static,
+    ) {
+        self.inspectors
+            .insert(TypeId::of::<T>(), Arc::new(inspector));
+    }
+
+    /// The inspector registered for a component type.
+    pub fn get(&self, type_id: TypeId) -> Option<Arc<InspectorFn>> {
+        self.inspectors.get(&type_id).cloned()
+    }
+
+    /// Whether a component type has a custom inspector.
+    pub fn contains(&self, type_id: TypeId) -> bool {
+        self.inspectors.contains_key(&type_id)
+    }
+}
+
+/// Registers custom inspectors on an `App`.
+pub trait RegisterInspectorExt {
+    /// Uses `inspector` to edit component `T` in the editor inspector.
+    fn register_inspector<T: Component>(
+        &mut self,
+        inspector: impl Fn(&mut egui::Ui, &mut World, Entity) + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl RegisterInspectorExt for App {
+    fn register_inspector<T: Component>(
+        &mut self,
+        inspector: impl Fn(&mut egui::Ui, &mut World, Entity) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<CustomInspectors>()
+            .register::<T>(inspector);
+        self
+    }
+}
@@ -0,0 +1,184 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Editor camera with pan and zoom, separate from game cameras.
+//!
+//! While stopped, the viewport shows the editor camera. While playing, the
+//! viewport switches to the game's camera if the scene has one.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::editor_state::EditorPlayState;
+use sandbox_engine::project::ProjectSettings;
+use sandbox_engine::scene::SceneExcluded;
+
+/// Minimum and maximum zoom factors. The maximum is in points per texture
+/// pixel, so projects with more pixels per unit can zoom further in.
+const MIN_ZOOM: f32 = 0.05;
+const MAX_ZOOM: f32 = 20.0;
+
+/// Zoom change per scroll point.
+const ZOOM_SPEED: f32 = 0.0015;
+
+/// Marks the editor's own camera.
+#[derive(Component)]
+pub struct EditorCamera {
+    /// Zoom factor; 1.0 maps one world unit to one UI point.
+    pub zoom: f32,
+}
+
+impl Default for EditorCamera {
+    fn default() -> Self {
+        Self { zoom: 1.0 }
+    }
+}
+
+/// Snapshot of the editor camera used for viewport coordinate conversion.
+#[derive(Clone, Copy, Debug)]
+pub struct ViewportCamera {
+    /// World position at the center of the viewport.
+    pub position: Vec2,
+    /// Zoom factor (UI points per world unit).
+    pub zoom: f32,
+}
+
+impl ViewportCamera {
+    /// Reads the editor camera from the world.
+    pub fn from_world(world: &mut World) -> Option<Self> {
+        let mut query = world.query::<(&Transform, &EditorCamera)>();
+        query.iter(world).next().map(|(transform, camera)| Self {
+            position: transform.translation.truncate(),
+            zoom: camera.zoom,
+        })
+    }
+
+    /// Converts world coordinates to screen coordinates.
+    pub fn world_to_screen(&self, world_pos: Vec2, viewport_rect: egui::Rect) -> egui::Pos2 {
+        let relative = (world_pos - self.position) * self.zoom;
+        let viewport_center = viewport_rect.center();
+
+        egui::pos2(
+            viewport_center.x + relative.x,
+            viewport_center.y - relative.y, // Screen Y is inverted
+        )
+    }
+
+    /// Converts screen coordinates to world coordinates.
+    pub fn screen_to_world(&self, screen_pos: egui::Pos2, viewport_rect: egui::Rect) -> Vec2 {
+        let viewport_center = viewport_rect.center();
+        let relative = Vec2::new(
+            screen_pos.x - viewport_center.x,
+            viewport_center.y - screen_pos.y, // Screen Y is inverted
+        );
+
+        self.position + relative / self.zoom
+    }
+
+    /// Converts a screen-space delta to a world-space delta.
+    pub fn screen_delta_to_world(&self, delta: egui::Vec2) -> Vec2 {
+        Vec2::new(delta.x, -delta.y) / self.zoom
+    }
+}
+
+/// Moves the editor camera so `target` is at the center of the viewport.
+pub fn center_editor_camera(world: &mut World, target: Vec2) {
+    let mut cameras = world.query_filtered::<&mut Transform, With<EditorCamera>>();
+    for mut transform in cameras.iter_mut(world) {
+        transform.translation.x = target.x;
+        transform.translation.y = target.y;
+    }
+}
+
+/// Plugin that sets up the editor camera.
+pub struct EditorCameraPlugin;
+
+impl Plugin for EditorCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_editor_camera)
+            .add_systems(OnEnter(EditorPlayState::Playing), activate_game_camera)
+            .add_systems(OnEnter(EditorPlayState::Stopped), activate_editor_camera);
+    }
+}
+
+fn spawn_editor_camera(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Editor Camera"),
+        Camera2d,
+        EditorCamera::default(),
+        SceneExcluded,
+    ));
+}
+
+/// Switches the viewport to the game's camera, if the scene has one.
+fn activate_game_camera(
+    mut editor_cameras: Query<&mut Camera, With<EditorCamera>>,
+    mut game_cameras: Query<&mut Camera, (With<Camera2d>, Without<EditorCamera>)>,
+) {
+    let Some(mut game_camera) = game_cameras.iter_mut().next() else {
+        return;
+    };
+    game_camera.is_active = true;
+
+    for mut camera in &mut editor_cameras {
+        camera.is_active = false;
+    }
+}
+
+/// Switches the viewport back to the editor camera.
+fn activate_editor_camera(
+    mut editor_cameras: Query<&mut Camera, With<EditorCamera>>,
+    mut game_cameras: Query<&mut Camera, (With<Camera2d>, Without<EditorCamera>)>,
+) {
+    for mut camera in &mut game_cameras {
+        camera.is_active = false;
+    }
+    for mut camera in &mut editor_cameras {
+        camera.is_active = true;
+    }
+}
+
+/// Pans the editor camera with the middle mouse button and zooms with the scroll wheel.
+///
+/// Zooming keeps the world point under the pointer fixed.
+pub fn handle_camera_input(
+    ui: &egui::Ui,
+    world: &mut World,
+    viewport_rect: egui::Rect,
+    response: &egui::Response,
+) {
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return;
+    };
+
+    let mut position = view.position;
+    let mut zoom = view.zoom;
+    let max_zoom = MAX_ZOOM * world.resource::<ProjectSettings>().pixels_per_unit.max(1.0);
+
+    if response.dragged_by(egui::PointerButton::Middle) {
+        position -= view.screen_delta_to_world(response.drag_delta());
+    }
+
+    if response.hovered() {
+        let scroll = ui.input(|input| input.raw_scroll_delta.y);
+        if scroll != 0.0 {
+            zoom = (zoom * (1.0 + scroll * ZOOM_SPEED)).clamp(MIN_ZOOM, max_zoom);
+
+            // Keep the point under the pointer in place
+            if let Some(pointer) = response.hover_pos() {
+                let anchor = view.screen_to_world(pointer, viewport_rect);
+                position = anchor - (anchor - view.position) * (view.zoom / zoom);
+            }
+        }
+    }
+
+    if position == view.position && zoom == view.zoom {
+        return;
+    }
+
+    let mut query = world.query::<(&mut Transform, &mut EditorCamera)>();
+    if let Some((mut transform, mut camera)) = query.iter_mut(world).next() {
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+        camera.zoom = zoom;
+    }
+}
@@ -0,0 +1,237 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Viewport display and editing of [`ParticleEmitter`] shapes.
+//!
+//! The primary selection's emitter has its `EmitterShape` drawn in the
+//! emitter's local space, with an arrow along its direction. While stopped,
+//! the shape gets handles: a circle's or cone's radius, a box's corner, and a
+//! cone's angle. Dragging one edits the shape as one undo step. Turn on the
+//! viewport's Simulate Particles toggle (`ParticlePreview`) to watch the
+//! effect change while dragging.
+
+use bevy::prelude::*;
+use bevy::reflect::PartialReflect;
+use bevy_egui::egui;
+use sandbox_engine::editor_state::EditorPlayState;
+use sandbox_engine::particles::{EmitterShape, ParticleEmitter};
+use sandbox_engine::scene::SceneManager;
+use std::any::TypeId;
+use std::f32::consts::TAU;
+
+use crate::editor_camera::ViewportCamera;
+use crate::selection::EditorSelection;
+use crate::undo::{record_component_edit, snapshot_components};
+
+/// Color of emitter shapes and handles.
+const COLOR_EMITTER: egui::Color32 = egui::Color32::from_rgb(255, 220, 90);
+
+/// Color of a hovered or dragged handle.
+const COLOR_HANDLE_ACTIVE: egui::Color32 = egui::Color32::WHITE;
+
+/// Radius of the handles, in points.
+const HANDLE_RADIUS: f32 = 5.0;
+
+/// Distance from a handle within which it can be grabbed, in points.
+const HANDLE_HIT_RADIUS: f32 = 8.0;
+
+/// Length of the direction arrow for shapes without a size, in world units.
+const POINT_ARROW_LENGTH: f32 = 24.0;
+
+/// Segments used to draw a full circle.
+const CIRCLE_SEGMENTS: usize = 48;
+
+/// Which part of a shape a handle changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ShapeHandle {
+    /// A circle's or cone's radius.
+    Radius,
+    /// A box's half size, from its corner.
+    Corner,
+    /// A cone's angle either side of the direction.
+    Angle,
+}
+
+/// Resource tracking a drag of an emitter shape handle.
+#[derive(Resource, Default)]
+pub struct EmitterShapeDrag {
+    /// Handle being dragged.
+    handle: Option<ShapeHandle>,
+    /// The emitter, with its `ParticleEmitter` from before the drag.
+    before: Vec<(Entity, Option<Box<dyn PartialReflect>>)>,
+}
+
+/// Draws the selected emitter's shape and handles dragging its handles.
+///
+/// Returns whether a handle owns the pointer, so the gizmo and picking
+/// should ignore it this frame.
+pub fn draw_emitter_shape(
+    painter: &egui::Painter,
+    viewport_rect: egui::Rect,
+    world: &mut World,
+    response: &egui::Response,
+) -> bool {
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return false;
+    };
+    let Some(entity) = world.resource::<EditorSelection>().selected_entity else {
+        return false;
+    };
+    let Some((emitter, transform)) = world
+        .get::<ParticleEmitter>(entity)
+        .cloned()
+        .zip(world.get::<GlobalTransform>(entity).copied())
+    else {
+        return false;
+    };
+    let to_screen = |local: Vec2| {
+        let position = transform.transform_point(local.extend(0.0)).truncate();
+        view.world_to_screen(position, viewport_rect)
+    };
+
+    let stroke = egui::Stroke::new(1.5, COLOR_EMITTER);
+    let direction = Vec2::from_angle(emitter.direction.to_radians());
+    let mut handles: Vec<(ShapeHandle, Vec2, String)> = Vec::new();
+    let arrow_length = match emitter.shape {
+        EmitterShape::Point => {
+            painter.circle_stroke(to_screen(Vec2::ZERO), 3.0, stroke);
+            POINT_ARROW_LENGTH
+        }
+        EmitterShape::Circle { radius } => {
+            let outline = (0..=CIRCLE_SEGMENTS)
+                .map(|i| {
+                    to_screen(Vec2::from_angle(i as f32 / CIRCLE_SEGMENTS as f32 * TAU) * radius)
+                })
+                .collect();
+            painter.add(egui::Shape::line(outline, stroke));
+            handles.push((
+                ShapeHandle::Radius,
+                direction * radius,
+                format!("Radius {}", radius),
+            ));
+            radius.max(POINT_ARROW_LENGTH)
+        }
+        EmitterShape::Box { half_size } => {
+            let corners = [
+                Vec2::new(-1.0, -1.0),
+                Vec2::new(1.0, -1.0),
+                Vec2::new(1.0, 1.0),
+                Vec2::new(-1.0, 1.0),
+            ]
+            .map(|corner| to_screen(corner * half_size));
+            painter.add(egui::Shape::closed_line(corners.to_vec(), stroke));
+            handles.push((
+                ShapeHandle::Corner,
+                half_size,
+                format!("Size {} × {}", half_size.x * 2.0, half_size.y * 2.0),
+            ));
+            half_size.max_element().max(POINT_ARROW_LENGTH)
+        }
+        EmitterShape::Cone { angle, radius } => {
+            let half = angle.to_radians();
+            let segments = ((CIRCLE_SEGMENTS as f32 * half / TAU).ceil() as usize).max(2) * 2;
+            let mut outline = vec![to_screen(Vec2::ZERO)];
+            outline.extend((0..=segments).map(|i| {
+                let t = i as f32 / segments as f32 * 2.0 - 1.0;
+                to_screen(Vec2::from_angle(emitter.direction.to_radians() + t * half) * radius)
+            }));
+            painter.add(egui::Shape::closed_line(outline, stroke));
+            handles.push((
+                ShapeHandle::Radius,
+                direction * radius,
+                format!("Radius {}", radius),
+            ));
+            handles.push((
+                ShapeHandle::Angle,
+                Vec2::from_angle(emitter.direction.to_radians() + half) * radius,
+                format!("Angle {}°", angle),
+            ));
+            radius.max(POINT_ARROW_LENGTH)
+        }
+    };
+    let origin = to_screen(Vec2::ZERO);
+    painter.arrow(
+        origin,
+        to_screen(direction * arrow_length) - origin,
+        egui::Stroke::new(1.0, COLOR_EMITTER.gamma_multiply(0.6)),
+    );
+
+    if *world.resource::<State<EditorPlayState>>().get() != EditorPlayState::Stopped {
+        return false;
+    }
+    let hit = |pos: egui::Pos2| {
+        handles
+            .iter()
+            .find(|(_, local, _)| to_screen(*local).distance(pos) <= HANDLE_HIT_RADIUS)
+            .map(|(handle, ..)| *handle)
+    };
+
+    if response.drag_started_by(egui::PointerButton::Primary) {
+        let press_origin = response.ctx.input(|input| input.pointer.press_origin());
+        if let Some(handle) = press_origin.and_then(hit) {
+            let before = snapshot_components(world, &[entity], TypeId::of::<ParticleEmitter>());
+            *world.resource_mut::<EmitterShapeDrag>() = EmitterShapeDrag {
+                handle: Some(handle),
+                before,
+            };
+        }
+    }
+
+    let dragging = world.resource::<EmitterShapeDrag>().handle;
+    if let (Some(handle), Some(pointer)) = (dragging, response.interact_pointer_pos()) {
+        let pointer = view.screen_to_world(pointer, viewport_rect).extend(0.0);
+        let local = transform
+            .affine()
+            .inverse()
+            .transform_point3(pointer)
+            .truncate();
+        if let Some(mut emitter) = world.get_mut::<ParticleEmitter>(entity) {
+            let direction = emitter.direction;
+            match (&mut emitter.shape, handle) {
+                (
+                    EmitterShape::Circle { radius } | EmitterShape::Cone { radius, .. },
+                    ShapeHandle::Radius,
+                ) => *radius = local.length(),
+                (EmitterShape::Box { half_size }, ShapeHandle::Corner) => {
+                    *half_size = local.abs();
+                }
+                (EmitterShape::Cone { angle, .. }, ShapeHandle::Angle) => {
+                    let from_direction = Vec2::from_angle(direction.to_radians()).angle_to(local);
+                    *angle = from_direction.abs().to_degrees().min(180.0);
+                }
+                _ => {}
+            }
+        }
+    }
+    if dragging.is_some() && response.drag_stopped() {
+        let drag = std::mem::take(&mut *world.resource_mut::<EmitterShapeDrag>());
+        record_component_edit(
+            world,
+            "Edit Emitter Shape",
+            TypeId::of::<ParticleEmitter>(),
+            drag.before,
+        );
+        if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+            manager.mark_dirty();
+        }
+    }
+
+    let active = dragging.or_else(|| response.hover_pos().and_then(hit));
+    for (handle, local, label) in handles {
+        let pos = to_screen(local);
+        let color = if active == Some(handle) {
+            COLOR_HANDLE_ACTIVE
+        } else {
+            COLOR_EMITTER
+        };
+        painter.circle_filled(pos, HANDLE_RADIUS, color);
+        painter.text(
+            pos + egui::vec2(0.0, -HANDLE_RADIUS - 2.0),
+            egui::Align2::CENTER_BOTTOM,
+            label,
+            egui::FontId::proportional(11.0),
+            COLOR_EMITTER,
+        );
+    }
+    dragging.is_some()
+}
@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Viewport display of [`Attractor`] and [`Repulsor`] fields.
+//!
+//! Each field is drawn as a circle at its radius, with arrows pointing in
+//! for attractors and out for repulsors.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::physics::{Attractor, Repulsor};
+
+use crate::editor_camera::ViewportCamera;
+
+/// Color of attractor fields.
+const COLOR_ATTRACTOR: egui::Color32 = egui::Color32::from_rgb(80, 200, 255);
+
+/// Color of repulsor fields.
+const COLOR_REPULSOR: egui::Color32 = egui::Color32::from_rgb(255, 130, 80);
+
+/// Length of the direction arrows, in points.
+const ARROW_LENGTH: f32 = 10.0;
+
+/// Draws the radius of every force field in the viewport.
+pub fn draw_force_fields(painter: &egui::Painter, viewport_rect: egui::Rect, world: &mut World) {
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return;
+    };
+
+    let mut fields = Vec::new();
+    let mut attractors = world.query::<(&Attractor, &GlobalTransform)>();
+    for (attractor, transform) in attractors.iter(world) {
+        fields.push((transform.translation().truncate(), attractor.radius, true));
+    }
+    let mut repulsors = world.query::<(&Repulsor, &GlobalTransform)>();
+    for (repulsor, transform) in repulsors.iter(world) {
+        fields.push((transform.translation().truncate(), repulsor.radius, false));
+    }
+
+    for (center, radius, attracts) in fields {
+        let screen_center = view.world_to_screen(center, viewport_rect);
+        let screen_radius =
+            screen_center.distance(view.world_to_screen(center + Vec2::X * radius, viewport_rect));
+        let field_rect =
+            egui::Rect::from_center_size(screen_center, egui::Vec2::splat(screen_radius * 2.0));
+        if !viewport_rect.intersects(field_rect) {
+            continue;
+        }
+        let (color, label) = if attracts {
+            (COLOR_ATTRACTOR, "Attractor")
+        } else {
+            (COLOR_REPULSOR, "Repulsor")
+        };
+
+        painter.circle_stroke(screen_center, screen_radius, egui::Stroke::new(1.5, color));
+        // Arrows on the circle pointing towards or away from the center
+        if screen_radius > ARROW_LENGTH * 2.0 {
+            for direction in [egui::Vec2::X, egui::Vec2::Y, -egui::Vec2::X, -egui::Vec2::Y] {
+                let edge = screen_center + direction * screen_radius;
+                let stroke = egui::Stroke::new(1.5, color);
+                if attracts {
+                    painter.arrow(edge, -direction * ARROW_LENGTH, stroke);
+                } else {
+                    painter.arrow(
+                        edge - direction * ARROW_LENGTH,
+                        direction * ARROW_LENGTH,
+                        stroke,
+                    );
+                }
+            }
+        }
+        painter.text(
+            screen_center - egui::vec2(0.0, screen_radius + 2.0),
+            egui::Align2::CENTER_BOTTOM,
+            label,
+            egui::FontId::proportional(11.0),
+            color,
+        );
+    }
+}
@@ -2,12 +2,22 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 //! Transform gizmo rendering and interaction for the editor.
+//!
+//! The gizmo translates the selected entity along its axes, rotates it with a
+//! ring, scales it with corner handles, or resizes its sprite with edge and
+//! corner handles and moves its pivot, depending on the current
+//! [`GizmoMode`] (W/E/R/T).
 
 use bevy::prelude::*;
+use bevy::sprite::Anchor;
 use bevy_egui::egui;
 
+use crate::editor_camera::ViewportCamera;
+use crate::modal_transform::ModalTransformState;
+use crate::picking::{sprite_local_rect, sprite_world_corners};
 use crate::selection::EditorSelection;
 use sandbox_engine::editor_state::EditorPlayState;
+use sandbox_engine::scene::EditorLocked;
 
 /// Resource tracking gizmo drag state.
 #[derive(Resource, Default)]
@@ -18,14 +28,156 @@ pub struct GizmoDragState {
     pub drag_start: Option<egui::Pos2>,
     /// World position of entity when drag started.
     pub entity_start_pos: Option<Vec3>,
+    /// Rotation of entity when drag started.
+    pub entity_start_rotation: Option<Quat>,
+    /// Scale of entity when drag started.
+    pub entity_start_scale: Option<Vec3>,
+    /// Local sprite rectangle (before scaling) when a resize drag started.
+    pub entity_start_rect: Option<Rect>,
+    /// World transforms of a multi-entity drag's entities when it started.
+    pub group_start: Vec<(Entity, Transform)>,
+    /// Pivot of a multi-entity drag when it started, in world space.
+    pub pivot_start: Option<Vec2>,
+    /// Selection bounds of a multi-entity scale drag when it started.
+    pub bounds_start: Option<Rect>,
 }
 
-/// Gizmo axis being manipulated.
+/// Which transform property the gizmo manipulates.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum GizmoMode {
+    #[default]
+    Translate,
+    Rotate,
+    Scale,
+    /// Resizes the sprite with edge and corner handles.
+    Rect,
+}
+
+impl GizmoMode {
+    /// All modes in toolbar order.
+    pub const ALL: [GizmoMode; 4] = [
+        GizmoMode::Translate,
+        GizmoMode::Rotate,
+        GizmoMode::Scale,
+        GizmoMode::Rect,
+    ];
+
+    /// Toolbar label.
+    pub fn label(self) -> &'static str {
+        match self {
+            GizmoMode::Translate => "✥ Move",
+            GizmoMode::Rotate => "⟲ Rotate",
+            GizmoMode::Scale => "⤡ Scale",
+            GizmoMode::Rect => "▭ Rect",
+        }
+    }
+
+    /// Hotkey that selects this mode.
+    pub fn hotkey(self) -> egui::Key {
+        match self {
+            GizmoMode::Translate => egui::Key::W,
+            GizmoMode::Rotate => egui::Key::E,
+            GizmoMode::Scale => egui::Key::R,
+            GizmoMode::Rect => egui::Key::T,
+        }
+    }
+}
+
+/// Point a multi-entity selection rotates and scales about.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum GizmoPivot {
+    /// Center of the box around the selected origins.
+    #[default]
+    SelectionCenter,
+    /// Origin of the primary selection.
+    ActiveEntity,
+    /// Each entity rotates and scales about its own origin.
+    IndividualOrigins,
+}
+
+impl GizmoPivot {
+    /// All pivot modes in menu order.
+    pub const ALL: [GizmoPivot; 3] = [
+        GizmoPivot::SelectionCenter,
+        GizmoPivot::ActiveEntity,
+        GizmoPivot::IndividualOrigins,
+    ];
+
+    /// Menu label.
+    pub fn label(self) -> &'static str {
+        match self {
+            GizmoPivot::SelectionCenter => "Selection Center",
+            GizmoPivot::ActiveEntity => "Active Entity",
+            GizmoPivot::IndividualOrigins => "Individual Origins",
+        }
+    }
+}
+
+/// What the rect gizmo changes when resizing a sprite.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum RectGizmoTarget {
+    /// Sets `Sprite::custom_size`, keeping the transform's scale.
+    #[default]
+    CustomSize,
+    /// Sets `Transform::scale`, keeping the sprite's size.
+    Scale,
+}
+
+/// Snapping applied while dragging gizmos.
+///
+/// Holding Ctrl during a drag snaps even when `enabled` is off.
+#[derive(Resource, Clone, Copy, PartialEq, Debug)]
+pub struct SnapSettings {
+    /// Whether snapping is always on.
+    pub enabled: bool,
+    /// Grid size in world units for translation.
+    pub grid_size: f32,
+    /// Rotation increment in degrees.
+    pub rotation_increment: f32,
+}
+
+impl Default for SnapSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            grid_size: 50.0,
+            rotation_increment: 15.0,
+        }
+    }
+}
+
+impl SnapSettings {
+    /// Whether to snap, given the current modifier state.
+    pub(crate) fn active(&self, ctrl_held: bool) -> bool {
+        self.enabled || ctrl_held
+    }
+}
+
+/// Rounds `value` to the nearest multiple of `step`; a non-positive step disables rounding.
+pub(crate) fn quantize(value: f32, step: f32) -> f32 {
+    if step > 0.0 {
+        (value / step).round() * step
+    } else {
+        value
+    }
+}
+
+/// Gizmo handle being manipulated.
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum GizmoAxis {
     X,
     Y,
     Center,
+    /// Scale handle at a bounds corner; components are the corner's signs.
+    Corner(IVec2),
+    /// Uniform scale handle at the origin.
+    Uniform,
+    /// Rect handle on a sprite edge or corner; a zero component leaves that axis alone.
+    Resize(IVec2),
+    /// Sprite pivot (anchor) marker.
+    Pivot,
+    /// Rotation ring.
+    Ring,
 }
 
 /// Gizmo visual constants.
@@ -34,6 +186,15 @@ const GIZMO_THICKNESS: f32 = 3.0;
 const GIZMO_HEAD_SIZE: f32 = 12.0;
 const GIZMO_CENTER_SIZE: f32 = 16.0;
 const GIZMO_HIT_RADIUS: f32 = 12.0;
+const GIZMO_CORNER_SIZE: f32 = 10.0;
+const GIZMO_RING_RADIUS: f32 = 60.0;
+
+/// Screen points of drag per doubling of uniform scale.
+const UNIFORM_SCALE_SPEED: f32 = 100.0;
+/// Smallest scale magnitude the scale gizmo will set.
+const MIN_SCALE: f32 = 0.01;
+/// Smallest sprite size, in local units, the rect gizmo will set.
+const MIN_RECT_SIZE: f32 = 1.0;
 
 const COLOR_X: egui::Color32 = egui::Color32::from_rgb(230, 80, 80);
 const COLOR_Y: egui::Color32 = egui::Color32::from_rgb(80, 200, 80);
@@ -42,20 +203,37 @@ const COLOR_CENTER: egui::Color32 = egui::Color32::from_rgb(255, 255, 100);
 const COLOR_X_HOVER: egui::Color32 = egui::Color32::from_rgb(255, 120, 120);
 const COLOR_Y_HOVER: egui::Color32 = egui::Color32::from_rgb(120, 255, 120);
 const COLOR_CENTER_HOVER: egui::Color32 = egui::Color32::from_rgb(255, 255, 180);
+const COLOR_RING: egui::Color32 = egui::Color32::from_rgb(90, 140, 255);
+const COLOR_RING_HOVER: egui::Color32 = egui::Color32::from_rgb(150, 190, 255);
+const COLOR_BOUNDS: egui::Color32 = egui::Color32::from_rgba_premultiplied(90, 160, 255, 160);
+const COLOR_CORNER: egui::Color32 = egui::Color32::from_rgb(90, 160, 255);
+const COLOR_CORNER_HOVER: egui::Color32 = egui::Color32::from_rgb(170, 210, 255);
+const COLOR_PIVOT_PREVIEW: egui::Color32 =
+    egui::Color32::from_rgba_premultiplied(120, 120, 60, 120);
+
+/// Degrees the pivot preview swings the sprite each way.
+const PIVOT_PREVIEW_ANGLE: f32 = 30.0;
 
 /// Plugin that sets up the gizmo system.
 pub struct GizmoPlugin;
 
 impl Plugin for GizmoPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<GizmoDragState>();
+        app.init_resource::<GizmoDragState>()
+            .init_resource::<GizmoMode>()
+            .init_resource::<GizmoPivot>()
+            .init_resource::<RectGizmoTarget>()
+            .init_resource::<SnapSettings>();
     }
 }
 
-/// Renders translation gizmos for the selected entity.
+/// Renders the gizmo for the selection in the current [`GizmoMode`].
 ///
-/// Call this from the viewport drawing code, passing the painter and viewport rect.
-pub fn draw_translation_gizmo(
+/// A multi-entity selection gets one gizmo for the group, pivoting as set by
+/// [`GizmoPivot`]; the rect gizmo always edits the primary selection only.
+/// Locked entities (`EditorLocked`) get no gizmo. Call this from the viewport
+/// drawing code, passing the painter and viewport rect.
+pub fn draw_gizmo(
     painter: &egui::Painter,
     viewport_rect: egui::Rect,
     world: &mut World,
@@ -67,11 +245,62 @@ pub fn draw_translation_gizmo(
         return;
     }
 
+    let mode = *world.resource::<GizmoMode>();
+    let group = group_targets(world);
+    if group.len() > 1 && mode != GizmoMode::Rect {
+        draw_group_gizmo(painter, viewport_rect, world, response, &group, mode);
+        return;
+    }
+
     let selected_entity = world.resource::<EditorSelection>().selected_entity;
-    let Some(entity) = selected_entity else {
+    let Some(entity) = selected_entity.filter(|&entity| !is_locked(world, entity)) else {
         return;
     };
 
+    match mode {
+        GizmoMode::Translate => {
+            draw_translation_gizmo(painter, viewport_rect, world, response, entity)
+        }
+        GizmoMode::Rotate => draw_rotation_gizmo(painter, viewport_rect, world, response, entity),
+        GizmoMode::Scale => draw_scale_gizmo(painter, viewport_rect, world, response, entity),
+        GizmoMode::Rect => draw_rect_gizmo(painter, viewport_rect, world, response, entity),
+    }
+}
+
+fn is_locked(world: &World, entity: Entity) -> bool {
+    world.get::<EditorLocked>(entity).is_some()
+}
+
+/// Switches the gizmo mode with W (move), E (rotate), R (scale), and T (rect).
+///
+/// Only active while stopped, so game input in play mode is unaffected, and
+/// paused during a modal transform.
+pub fn handle_gizmo_mode_shortcuts(ctx: &egui::Context, world: &mut World) {
+    if ctx.wants_keyboard_input()
+        || *world.resource::<State<EditorPlayState>>().get() != EditorPlayState::Stopped
+        || world.resource::<ModalTransformState>().is_active()
+    {
+        return;
+    }
+
+    let pressed = ctx.input_mut(|input| {
+        GizmoMode::ALL
+            .into_iter()
+            .find(|mode| input.consume_key(egui::Modifiers::NONE, mode.hotkey()))
+    });
+    if let Some(mode) = pressed {
+        *world.resource_mut::<GizmoMode>() = mode;
+    }
+}
+
+/// Renders and handles the translation gizmo for an entity.
+fn draw_translation_gizmo(
+    painter: &egui::Painter,
+    viewport_rect: egui::Rect,
+    world: &mut World,
+    response: &egui::Response,
+    entity: Entity,
+) {
     // Get entity's transform
     let Some(transform) = world.get::<Transform>(entity) else {
         return;
@@ -79,24 +308,17 @@ pub fn draw_translation_gizmo(
     let entity_world_pos = transform.translation;
 
     // Get camera for world-to-screen conversion
-    let camera_transform = {
-        let mut query = world.query_filtered::<&Transform, With<Camera2d>>();
-        query.iter(world).next().copied()
-    };
-
-    let Some(camera_transform) = camera_transform else {
+    let Some(view) = ViewportCamera::from_world(world) else {
         return;
     };
 
     // Convert world position to screen position
-    let screen_pos = world_to_screen(
-        entity_world_pos.truncate(),
-        camera_transform.translation.truncate(),
-        viewport_rect,
-    );
+    let screen_pos = view.world_to_screen(entity_world_pos.truncate(), viewport_rect);
 
     // Check for hover/interaction
     let pointer_pos = response.hover_pos();
+    let snap = *world.resource::<SnapSettings>();
+    let snapping = snap.active(response.ctx.input(|input| input.modifiers.ctrl));
     let mut drag_state = world.resource_mut::<GizmoDragState>();
 
     let hovered_axis = if let Some(pos) = pointer_pos {
@@ -106,7 +328,7 @@ pub fn draw_translation_gizmo(
     };
 
     // Handle drag interaction
-    if response.drag_started() {
+    if response.drag_started_by(egui::PointerButton::Primary) {
         if let Some(axis) = hovered_axis {
             drag_state.dragging = Some(axis);
             drag_state.drag_start = pointer_pos;
@@ -115,9 +337,7 @@ pub fn draw_translation_gizmo(
     }
 
     if response.drag_stopped() {
-        drag_state.dragging = None;
-        drag_state.drag_start = None;
-        drag_state.entity_start_pos = None;
+        *drag_state = GizmoDragState::default();
     }
 
     // Calculate new position if dragging
@@ -128,22 +348,27 @@ pub fn draw_translation_gizmo(
             drag_state.entity_start_pos,
         ) {
             if let Some(current_pos) = pointer_pos {
-                let delta = current_pos - start_pos;
-
-                // Convert screen delta to world delta (assuming 1:1 scale for now)
-                let world_delta = Vec3::new(
-                    match axis {
-                        GizmoAxis::X | GizmoAxis::Center => delta.x,
-                        GizmoAxis::Y => 0.0,
-                    },
-                    match axis {
-                        GizmoAxis::Y | GizmoAxis::Center => -delta.y, // Screen Y is inverted
-                        GizmoAxis::X => 0.0,
-                    },
-                    0.0,
-                );
+                let delta = view.screen_delta_to_world(current_pos - start_pos);
+
+                // Constrain the world delta to the dragged axis
+                let world_delta = match axis {
+                    GizmoAxis::X => Vec3::new(delta.x, 0.0, 0.0),
+                    GizmoAxis::Y => Vec3::new(0.0, delta.y, 0.0),
+                    _ => delta.extend(0.0),
+                };
 
-                Some(entity_start + world_delta)
+                // Snap the dragged axes to the grid
+                let mut position = entity_start + world_delta;
+                if snapping {
+                    if axis != GizmoAxis::Y {
+                        position.x = quantize(position.x, snap.grid_size);
+                    }
+                    if axis != GizmoAxis::X {
+                        position.y = quantize(position.y, snap.grid_size);
+                    }
+                }
+
+                Some(position)
             } else {
                 None
             }
@@ -190,6 +415,1036 @@ pub fn draw_translation_gizmo(
     painter.rect_filled(center_rect, 2.0, center_color);
 }
 
+/// Returns the gizmo handle under the pointer for the selected entity, if any.
+///
+/// Returns `None` when gizmos are hidden (not stopped) or nothing is selected.
+pub fn gizmo_hit(
+    world: &mut World,
+    viewport_rect: egui::Rect,
+    pointer: egui::Pos2,
+) -> Option<GizmoAxis> {
+    if *world.resource::<State<EditorPlayState>>().get() != EditorPlayState::Stopped {
+        return None;
+    }
+
+    let view = ViewportCamera::from_world(world)?;
+    let mode = *world.resource::<GizmoMode>();
+    let group = group_targets(world);
+    if group.len() > 1 && mode != GizmoMode::Rect {
+        let gizmo = group_gizmo(world, &group)?;
+        let pivot = view.world_to_screen(gizmo.pivot, viewport_rect);
+        return match mode {
+            GizmoMode::Translate => hit_test_gizmo(pointer, pivot),
+            GizmoMode::Rotate => hit_test_rotation_gizmo(pointer, pivot),
+            _ => hit_test_scale_gizmo(pointer, &gizmo.scale_handles(&view, viewport_rect)),
+        };
+    }
+
+    let entity = world.resource::<EditorSelection>().selected_entity?;
+    if is_locked(world, entity) {
+        return None;
+    }
+
+    match mode {
+        GizmoMode::Translate => {
+            let entity_pos = world.get::<Transform>(entity)?.translation.truncate();
+            hit_test_gizmo(pointer, view.world_to_screen(entity_pos, viewport_rect))
+        }
+        GizmoMode::Rotate => {
+            let entity_pos = world.get::<Transform>(entity)?.translation.truncate();
+            hit_test_rotation_gizmo(pointer, view.world_to_screen(entity_pos, viewport_rect))
+        }
+        GizmoMode::Scale => {
+            let handles = scale_handles(world, entity, &view, viewport_rect)?;
+            hit_test_scale_gizmo(pointer, &handles)
+        }
+        GizmoMode::Rect => {
+            let handles = rect_handles(world, entity, &view, viewport_rect)?;
+            hit_test_rect_gizmo(pointer, &handles)
+        }
+    }
+}
+
+/// Renders and handles the rotation gizmo for an entity.
+///
+/// Dragging the ring rotates the entity about Z by the angle swept around its origin.
+fn draw_rotation_gizmo(
+    painter: &egui::Painter,
+    viewport_rect: egui::Rect,
+    world: &mut World,
+    response: &egui::Response,
+    entity: Entity,
+) {
+    let Some(transform) = world.get::<Transform>(entity).copied() else {
+        return;
+    };
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return;
+    };
+    let origin = view.world_to_screen(transform.translation.truncate(), viewport_rect);
+
+    let pointer_pos = response.hover_pos();
+    let hovered = pointer_pos.and_then(|pos| hit_test_rotation_gizmo(pos, origin));
+    let snap = *world.resource::<SnapSettings>();
+    let snapping = snap.active(response.ctx.input(|input| input.modifiers.ctrl));
+
+    let mut drag_state = world.resource_mut::<GizmoDragState>();
+
+    if response.drag_started_by(egui::PointerButton::Primary) && hovered.is_some() {
+        drag_state.dragging = hovered;
+        drag_state.drag_start = pointer_pos;
+        drag_state.entity_start_rotation = Some(transform.rotation);
+    }
+
+    if response.drag_stopped() {
+        *drag_state = GizmoDragState::default();
+    }
+
+    let new_rotation = match (
+        drag_state.dragging,
+        drag_state.drag_start,
+        drag_state.entity_start_rotation,
+        pointer_pos,
+    ) {
+        (Some(GizmoAxis::Ring), Some(start), Some(start_rotation), Some(current)) => {
+            let start_angle = screen_angle(start - origin);
+            let current_angle = screen_angle(current - origin);
+            let rotation = Quat::from_rotation_z(current_angle - start_angle) * start_rotation;
+            if snapping {
+                // Snap the resulting Z angle to the rotation increment
+                let (_, _, angle) = rotation.to_euler(EulerRot::XYZ);
+                let snapped = quantize(angle.to_degrees(), snap.rotation_increment);
+                Some(Quat::from_rotation_z(snapped.to_radians()))
+            } else {
+                Some(rotation)
+            }
+        }
+        _ => None,
+    };
+
+    if let Some(rotation) = new_rotation {
+        if let Some(mut transform) = world.get_mut::<Transform>(entity) {
+            transform.rotation = rotation;
+        }
+    }
+
+    let active = world.resource::<GizmoDragState>().dragging.or(hovered);
+    let color = if active == Some(GizmoAxis::Ring) {
+        COLOR_RING_HOVER
+    } else {
+        COLOR_RING
+    };
+    painter.circle_stroke(
+        origin,
+        GIZMO_RING_RADIUS,
+        egui::Stroke::new(GIZMO_THICKNESS, color),
+    );
+
+    // Current heading: the entity's local +X axis
+    let (_, _, angle) = new_rotation
+        .unwrap_or(transform.rotation)
+        .to_euler(EulerRot::XYZ);
+    let heading = egui::vec2(angle.cos(), -angle.sin()) * GIZMO_RING_RADIUS;
+    painter.line_segment(
+        [origin, origin + heading],
+        egui::Stroke::new(GIZMO_THICKNESS * 0.5, color),
+    );
+    painter.circle_filled(origin, GIZMO_CENTER_SIZE * 0.25, color);
+}
+
+/// Returns the counter-clockwise angle of a screen-space vector (screen Y is inverted).
+pub(crate) fn screen_angle(vector: egui::Vec2) -> f32 {
+    (-vector.y).atan2(vector.x)
+}
+
+/// Screen-space handle positions of the scale gizmo.
+struct ScaleHandles {
+    /// Entity origin.
+    origin: egui::Pos2,
+    /// Bounds corners with their local-space positions (before scaling).
+    corners: Vec<(IVec2, Vec2, egui::Pos2)>,
+}
+
+/// Computes scale handle positions from the entity's sprite bounds.
+///
+/// Entities without sprite bounds get a fixed-size box around their origin.
+fn scale_handles(
+    world: &World,
+    entity: Entity,
+    view: &ViewportCamera,
+    viewport_rect: egui::Rect,
+) -> Option<ScaleHandles> {
+    let transform = *world.get::<Transform>(entity)?;
+
+    let local_rect = world
+        .get::<Sprite>(entity)
+        .and_then(|sprite| sprite_local_rect(world, sprite))
+        .unwrap_or_else(|| {
+            Rect::from_center_half_size(Vec2::ZERO, Vec2::splat(0.5 * GIZMO_LENGTH / view.zoom))
+        });
+
+    let corners = [
+        IVec2::new(-1, -1),
+        IVec2::new(1, -1),
+        IVec2::new(1, 1),
+        IVec2::new(-1, 1),
+    ]
+    .into_iter()
+    .map(|sign| {
+        let local = Vec2::new(
+            if sign.x < 0 {
+                local_rect.min.x
+            } else {
+                local_rect.max.x
+            },
+            if sign.y < 0 {
+                local_rect.min.y
+            } else {
+                local_rect.max.y
+            },
+        );
+        let world_pos = transform.transform_point(local.extend(0.0)).truncate();
+        (sign, local, view.world_to_screen(world_pos, viewport_rect))
+    })
+    .collect();
+
+    Some(ScaleHandles {
+        origin: view.world_to_screen(transform.translation.truncate(), viewport_rect),
+        corners,
+    })
+}
+
+/// Renders and handles the scale gizmo for an entity.
+///
+/// Corner handles scale about the entity's origin so the dragged corner follows
+/// the pointer; the center handle scales uniformly.
+fn draw_scale_gizmo(
+    painter: &egui::Painter,
+    viewport_rect: egui::Rect,
+    world: &mut World,
+    response: &egui::Response,
+    entity: Entity,
+) {
+    let Some(transform) = world.get::<Transform>(entity).copied() else {
+        return;
+    };
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return;
+    };
+    let Some(handles) = scale_handles(world, entity, &view, viewport_rect) else {
+        return;
+    };
+
+    let pointer_pos = response.hover_pos();
+    let hovered = pointer_pos.and_then(|pos| hit_test_scale_gizmo(pos, &handles));
+
+    let mut drag_state = world.resource_mut::<GizmoDragState>();
+
+    if response.drag_started_by(egui::PointerButton::Primary) {
+        if let Some(handle) = hovered {
+            drag_state.dragging = Some(handle);
+            drag_state.drag_start = pointer_pos;
+            drag_state.entity_start_scale = Some(transform.scale);
+        }
+    }
+
+    if response.drag_stopped() {
+        *drag_state = GizmoDragState::default();
+    }
+
+    let new_scale = match (
+        drag_state.dragging,
+        drag_state.drag_start,
+        drag_state.entity_start_scale,
+        pointer_pos,
+    ) {
+        (Some(GizmoAxis::Corner(sign)), _, Some(start_scale), Some(current)) => {
+            handles
+                .corners
+                .iter()
+                .find(|(corner, _, _)| *corner == sign)
+                .map(|&(_, local, _)| {
+                    // Express the pointer in the entity's rotated frame
+                    let pointer_world = view.screen_to_world(current, viewport_rect);
+                    let relative = transform.rotation.inverse()
+                        * (pointer_world - transform.translation.truncate()).extend(0.0);
+
+                    let axis_scale = |offset: f32, extent: f32, start: f32| {
+                        if extent.abs() < f32::EPSILON {
+                            start
+                        } else {
+                            (offset / extent).abs().max(MIN_SCALE) * start.signum()
+                        }
+                    };
+                    Vec3::new(
+                        axis_scale(relative.x, local.x, start_scale.x),
+                        axis_scale(relative.y, local.y, start_scale.y),
+                        start_scale.z,
+                    )
+                })
+        }
+        (Some(GizmoAxis::Uniform), Some(start), Some(start_scale), Some(current)) => {
+            // Dragging right or up grows, left or down shrinks
+            let delta = current - start;
+            let factor = 2f32.powf((delta.x - delta.y) / UNIFORM_SCALE_SPEED);
+            let scale = start_scale.truncate() * factor;
+            Some(Vec3::new(
+                scale.x.abs().max(MIN_SCALE) * scale.x.signum(),
+                scale.y.abs().max(MIN_SCALE) * scale.y.signum(),
+                start_scale.z,
+            ))
+        }
+        _ => None,
+    };
+
+    if let Some(scale) = new_scale {
+        if let Some(mut transform) = world.get_mut::<Transform>(entity) {
+            transform.scale = scale;
+        }
+    }
+
+    let active = world.resource::<GizmoDragState>().dragging.or(hovered);
+
+    // Bounds outline
+    let outline: Vec<egui::Pos2> = handles.corners.iter().map(|&(_, _, pos)| pos).collect();
+    painter.add(egui::Shape::closed_line(
+        outline,
+        egui::Stroke::new(1.5, COLOR_BOUNDS),
+    ));
+
+    // Corner handles
+    for &(sign, _, pos) in &handles.corners {
+        let color = if active == Some(GizmoAxis::Corner(sign)) {
+            COLOR_CORNER_HOVER
+        } else {
+            COLOR_CORNER
+        };
+        painter.rect_filled(
+            egui::Rect::from_center_size(pos, egui::vec2(GIZMO_CORNER_SIZE, GIZMO_CORNER_SIZE)),
+            1.0,
+            color,
+        );
+    }
+
+    // Uniform scale handle (yellow circle)
+    let center_color = if active == Some(GizmoAxis::Uniform) {
+        COLOR_CENTER_HOVER
+    } else {
+        COLOR_CENTER
+    };
+    painter.circle_filled(handles.origin, GIZMO_CENTER_SIZE * 0.5, center_color);
+}
+
+/// Screen-space handle positions of the rect gizmo.
+struct RectHandles {
+    /// Local sprite rectangle (before scaling).
+    local_rect: Rect,
+    /// Entity origin, where the sprite's anchor sits.
+    pivot: egui::Pos2,
+    /// Sprite corners in screen space, counter-clockwise from bottom-left.
+    outline: Vec<egui::Pos2>,
+    /// Edge midpoints and corners with their signs.
+    handles: Vec<(IVec2, egui::Pos2)>,
+}
+
+/// Computes rect handle positions from the entity's sprite bounds.
+///
+/// Returns `None` for entities without a sized sprite.
+fn rect_handles(
+    world: &World,
+    entity: Entity,
+    view: &ViewportCamera,
+    viewport_rect: egui::Rect,
+) -> Option<RectHandles> {
+    let transform = *world.get::<Transform>(entity)?;
+    let local_rect = sprite_local_rect(world, world.get::<Sprite>(entity)?)?;
+
+    let to_screen = |sign: IVec2| {
+        let local = local_rect.center() + local_rect.half_size() * sign.as_vec2();
+        let world_pos = transform.transform_point(local.extend(0.0)).truncate();
+        view.world_to_screen(world_pos, viewport_rect)
+    };
+
+    let outline = [
+        IVec2::new(-1, -1),
+        IVec2::new(1, -1),
+        IVec2::new(1, 1),
+        IVec2::new(-1, 1),
+    ]
+    .map(to_screen)
+    .to_vec();
+    let handles = (-1..=1)
+        .flat_map(|x| (-1..=1).map(move |y| IVec2::new(x, y)))
+        .filter(|&sign| sign != IVec2::ZERO)
+        .map(|sign| (sign, to_screen(sign)))
+        .collect();
+
+    Some(RectHandles {
+        local_rect,
+        pivot: view.world_to_screen(transform.translation.truncate(), viewport_rect),
+        outline,
+        handles,
+    })
+}
+
+/// Moves the dragged sides of `rect` to `pointer`, keeping the opposite sides fixed.
+///
+/// With `keep_aspect`, the rectangle keeps its aspect ratio: corners grow from
+/// the opposite corner, edges grow the other axis evenly about its center.
+fn resize_rect(rect: Rect, sign: IVec2, pointer: Vec2, keep_aspect: bool) -> Rect {
+    let mut min = rect.min;
+    let mut max = rect.max;
+    for axis in 0..2 {
+        match sign[axis] {
+            1 => max[axis] = pointer[axis].max(min[axis] + MIN_RECT_SIZE),
+            -1 => min[axis] = pointer[axis].min(max[axis] - MIN_RECT_SIZE),
+            _ => {}
+        }
+    }
+    let resized = Rect { min, max };
+    if !keep_aspect || rect.width() <= 0.0 || rect.height() <= 0.0 {
+        return resized;
+    }
+
+    let factors = resized.size() / rect.size();
+    let factor = match (sign.x, sign.y) {
+        (0, _) => factors.y,
+        (_, 0) => factors.x,
+        _ => factors.x.max(factors.y),
+    };
+    let size = (rect.size() * factor).max(Vec2::splat(MIN_RECT_SIZE));
+    let mut min = rect.min;
+    let mut max = rect.max;
+    for axis in 0..2 {
+        match sign[axis] {
+            1 => max[axis] = min[axis] + size[axis],
+            -1 => min[axis] = max[axis] - size[axis],
+            _ => {
+                let center = rect.center()[axis];
+                min[axis] = center - size[axis] * 0.5;
+                max[axis] = center + size[axis] * 0.5;
+            }
+        }
+    }
+    Rect { min, max }
+}
+
+/// Renders and handles the rect gizmo for an entity.
+///
+/// Edge and corner handles resize the sprite so the opposite side stays in
+/// place, through `custom_size` or the transform's scale depending on
+/// [`RectGizmoTarget`]. Holding Shift keeps the aspect ratio.
+///
+/// The pivot marker at the entity's origin moves the sprite's `Anchor`
+/// without moving the sprite on screen. It snaps to the nine standard anchors
+/// when dropped near one (always with Ctrl), and previews the sprite rotated
+/// about the new pivot while dragging.
+fn draw_rect_gizmo(
+    painter: &egui::Painter,
+    viewport_rect: egui::Rect,
+    world: &mut World,
+    response: &egui::Response,
+    entity: Entity,
+) {
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return;
+    };
+    let Some(handles) = rect_handles(world, entity, &view, viewport_rect) else {
+        return;
+    };
+    let Some(transform) = world.get::<Transform>(entity).copied() else {
+        return;
+    };
+
+    let pointer_pos = response.hover_pos();
+    let hovered = pointer_pos.and_then(|pos| hit_test_rect_gizmo(pos, &handles));
+    let (keep_aspect, snap_pivot) = response
+        .ctx
+        .input(|input| (input.modifiers.shift, input.modifiers.ctrl));
+    let target = *world.resource::<RectGizmoTarget>();
+
+    let mut drag_state = world.resource_mut::<GizmoDragState>();
+
+    if response.drag_started_by(egui::PointerButton::Primary) {
+        // Hit test where the button went down; handles are small
+        let press_origin = response.ctx.input(|input| input.pointer.press_origin());
+        let pressed = press_origin.and_then(|pos| hit_test_rect_gizmo(pos, &handles));
+        if let Some(handle) = pressed.or(hovered) {
+            drag_state.dragging = Some(handle);
+            drag_state.drag_start = press_origin.or(pointer_pos);
+            drag_state.entity_start_pos = Some(transform.translation);
+            drag_state.entity_start_scale = Some(transform.scale);
+            drag_state.entity_start_rect = Some(handles.local_rect);
+        }
+    }
+
+    if response.drag_stopped() {
+        *drag_state = GizmoDragState::default();
+    }
+
+    let mut pivot_preview = None;
+    if let (Some(handle), Some(start_pos), Some(start_scale), Some(start_rect), Some(current)) = (
+        drag_state.dragging,
+        drag_state.entity_start_pos,
+        drag_state.entity_start_scale,
+        drag_state.entity_start_rect,
+        pointer_pos,
+    ) {
+        // Express the pointer in the sprite's unscaled frame at drag start
+        let pointer_world = view.screen_to_world(current, viewport_rect);
+        let relative =
+            transform.rotation.inverse() * (pointer_world - start_pos.truncate()).extend(0.0);
+        let scale = start_scale.truncate();
+        let local = relative.truncate() / scale.abs().max(Vec2::splat(f32::EPSILON));
+        // Mirrored axes flip which side the handle is on
+        let local = local * scale.signum();
+
+        match handle {
+            GizmoAxis::Resize(sign) => {
+                let rect = resize_rect(start_rect, sign, local, keep_aspect);
+
+                // The origin sits at the sprite's anchor, so it moves with the resized rect
+                let anchor_offset = -start_rect.min / start_rect.size();
+                let origin = rect.min + rect.size() * anchor_offset;
+                let translation = start_pos + transform.rotation * (origin * scale).extend(0.0);
+
+                if let Some(mut transform) = world.get_mut::<Transform>(entity) {
+                    transform.translation = translation;
+                    if target == RectGizmoTarget::Scale {
+                        let factor = rect.size() / start_rect.size();
+                        transform.scale = (scale * factor).extend(start_scale.z);
+                    }
+                }
+                if target == RectGizmoTarget::CustomSize {
+                    if let Some(mut sprite) = world.get_mut::<Sprite>(entity) {
+                        sprite.custom_size = Some(rect.size());
+                    }
+                }
+            }
+            GizmoAxis::Pivot => {
+                let snap_distance = GIZMO_HIT_RADIUS / (view.zoom * scale.abs().max_element());
+                let (anchor, pivot) = pivot_anchor(start_rect, local, snap_pivot, snap_distance);
+                let translation = start_pos + transform.rotation * (pivot * scale).extend(0.0);
+
+                if let Some(mut transform) = world.get_mut::<Transform>(entity) {
+                    transform.translation = translation;
+                }
+                if let Some(mut sprite) = world.get_mut::<Sprite>(entity) {
+                    sprite.anchor = anchor;
+                }
+                pivot_preview = Some(Transform {
+                    translation,
+                    ..transform
+                });
+            }
+            _ => {}
+        }
+    }
+
+    let active = world.resource::<GizmoDragState>().dragging.or(hovered);
+
+    painter.add(egui::Shape::closed_line(
+        handles.outline.clone(),
+        egui::Stroke::new(1.5, COLOR_BOUNDS),
+    ));
+    for &(sign, pos) in &handles.handles {
+        let color = if active == Some(GizmoAxis::Resize(sign)) {
+            COLOR_CORNER_HOVER
+        } else {
+            COLOR_CORNER
+        };
+        if sign.x != 0 && sign.y != 0 {
+            painter.rect_filled(
+                egui::Rect::from_center_size(pos, egui::vec2(GIZMO_CORNER_SIZE, GIZMO_CORNER_SIZE)),
+                1.0,
+                color,
+            );
+        } else {
+            painter.circle_filled(pos, GIZMO_CORNER_SIZE * 0.5, color);
+        }
+    }
+
+    // Rotation preview: the sprite swung both ways about the new pivot
+    if let Some(preview) = pivot_preview {
+        let local_rect = world
+            .get::<Sprite>(entity)
+            .and_then(|sprite| sprite_local_rect(world, sprite))
+            .unwrap_or(handles.local_rect);
+        for angle in [-PIVOT_PREVIEW_ANGLE, PIVOT_PREVIEW_ANGLE] {
+            let swung = Transform {
+                rotation: Quat::from_rotation_z(angle.to_radians()) * preview.rotation,
+                ..preview
+            };
+            let outline = [
+                local_rect.min,
+                Vec2::new(local_rect.max.x, local_rect.min.y),
+                local_rect.max,
+                Vec2::new(local_rect.min.x, local_rect.max.y),
+            ]
+            .map(|corner| {
+                let world_pos = swung.transform_point(corner.extend(0.0)).truncate();
+                view.world_to_screen(world_pos, viewport_rect)
+            })
+            .to_vec();
+            painter.add(egui::Shape::closed_line(
+                outline,
+                egui::Stroke::new(1.0, COLOR_PIVOT_PREVIEW),
+            ));
+        }
+    }
+
+    // Pivot marker: a ring with a crosshair at the entity's origin
+    let color = if active == Some(GizmoAxis::Pivot) {
+        COLOR_CENTER_HOVER
+    } else {
+        COLOR_CENTER
+    };
+    let pivot = view.world_to_screen(
+        pivot_preview
+            .map_or(transform.translation, |preview| preview.translation)
+            .truncate(),
+        viewport_rect,
+    );
+    let radius = GIZMO_CENTER_SIZE * 0.5;
+    painter.circle_stroke(pivot, radius, egui::Stroke::new(2.0, color));
+    for direction in [egui::vec2(1.0, 0.0), egui::vec2(0.0, 1.0)] {
+        painter.line_segment(
+            [pivot - direction * radius, pivot + direction * radius],
+            egui::Stroke::new(1.0, color),
+        );
+    }
+}
+
+/// Anchor for a pivot dragged to `pivot` (local to the sprite's origin at drag start).
+///
+/// Snaps to the nearest standard anchor within `snap_distance` local units,
+/// or always when `snap` is set. Returns the anchor and the snapped pivot.
+fn pivot_anchor(rect: Rect, pivot: Vec2, snap: bool, snap_distance: f32) -> (Anchor, Vec2) {
+    let standard = (-1..=1)
+        .flat_map(|x| (-1..=1).map(move |y| IVec2::new(x, y)))
+        .map(|sign| (sign, rect.center() + rect.half_size() * sign.as_vec2()))
+        .min_by(|(_, a), (_, b)| a.distance(pivot).total_cmp(&b.distance(pivot)));
+
+    if let Some((sign, position)) = standard {
+        if snap || position.distance(pivot) <= snap_distance {
+            return (standard_anchor(sign), position);
+        }
+    }
+    let size = rect.size().max(Vec2::splat(f32::EPSILON));
+    (Anchor::Custom((pivot - rect.min) / size - 0.5), pivot)
+}
+
+/// The named anchor at a rect position given by per-axis signs.
+fn standard_anchor(sign: IVec2) -> Anchor {
+    match (sign.x, sign.y) {
+        (-1, -1) => Anchor::BottomLeft,
+        (0, -1) => Anchor::BottomCenter,
+        (1, -1) => Anchor::BottomRight,
+        (-1, 0) => Anchor::CenterLeft,
+        (1, 0) => Anchor::CenterRight,
+        (-1, 1) => Anchor::TopLeft,
+        (0, 1) => Anchor::TopCenter,
+        (1, 1) => Anchor::TopRight,
+        _ => Anchor::Center,
+    }
+}
+
+/// Selected entities a multi-entity gizmo moves, primary last.
+///
+/// Skips locked entities, entities without a transform, and entities whose
+/// ancestor is selected too (they follow their ancestor).
+pub(crate) fn group_targets(world: &World) -> Vec<Entity> {
+    let selected = &world.resource::<EditorSelection>().entities;
+    let has_selected_ancestor = |entity: Entity| {
+        let mut current = entity;
+        while let Some(parent) = world.get::<Parent>(current) {
+            if selected.contains(&parent.get()) {
+                return true;
+            }
+            current = parent.get();
+        }
+        false
+    };
+    selected
+        .iter()
+        .copied()
+        .filter(|&entity| {
+            world.get::<Transform>(entity).is_some()
+                && !is_locked(world, entity)
+                && !has_selected_ancestor(entity)
+        })
+        .collect()
+}
+
+/// World-space layout of a multi-entity gizmo.
+pub(crate) struct GroupGizmo {
+    /// Entities with their current world transforms.
+    pub(crate) members: Vec<(Entity, Transform)>,
+    /// Point the group rotates and scales about.
+    pub(crate) pivot: Vec2,
+    /// Box around the members' origins and sprite bounds.
+    bounds: Rect,
+}
+
+impl GroupGizmo {
+    /// Scale handles at the bounds corners, with the uniform handle at the pivot.
+    fn scale_handles(&self, view: &ViewportCamera, viewport_rect: egui::Rect) -> ScaleHandles {
+        let corners = [
+            IVec2::new(-1, -1),
+            IVec2::new(1, -1),
+            IVec2::new(1, 1),
+            IVec2::new(-1, 1),
+        ]
+        .into_iter()
+        .map(|sign| {
+            let corner = self.bounds.center() + self.bounds.half_size() * sign.as_vec2();
+            (sign, corner, view.world_to_screen(corner, viewport_rect))
+        })
+        .collect();
+        ScaleHandles {
+            origin: view.world_to_screen(self.pivot, viewport_rect),
+            corners,
+        }
+    }
+}
+
+/// Computes the group gizmo's pivot and bounds for the current [`GizmoPivot`].
+pub(crate) fn group_gizmo(world: &World, entities: &[Entity]) -> Option<GroupGizmo> {
+    let members: Vec<(Entity, Transform)> = entities
+        .iter()
+        .filter_map(|&entity| {
+            let transform = world.get::<GlobalTransform>(entity)?;
+            Some((entity, transform.compute_transform()))
+        })
+        .collect();
+    let (_, first) = members.first()?;
+
+    let mut origins = Rect::from_center_size(first.translation.truncate(), Vec2::ZERO);
+    let mut bounds = origins;
+    for (entity, transform) in &members {
+        let origin = transform.translation.truncate();
+        origins = origins.union_point(origin);
+        bounds = bounds.union_point(origin);
+        let sprite_corners = world
+            .get::<Sprite>(*entity)
+            .zip(world.get::<GlobalTransform>(*entity))
+            .and_then(|(sprite, global)| sprite_world_corners(world, sprite, global));
+        for corner in sprite_corners.into_iter().flatten() {
+            bounds = bounds.union_point(corner);
+        }
+    }
+
+    let primary = world.resource::<EditorSelection>().selected_entity;
+    let pivot = match *world.resource::<GizmoPivot>() {
+        GizmoPivot::ActiveEntity => members
+            .iter()
+            .find(|(entity, _)| Some(*entity) == primary)
+            .map_or(origins.center(), |(_, transform)| {
+                transform.translation.truncate()
+            }),
+        GizmoPivot::SelectionCenter | GizmoPivot::IndividualOrigins => origins.center(),
+    };
+
+    Some(GroupGizmo {
+        members,
+        pivot,
+        // Keep corner handles apart for tightly packed or single-point groups
+        bounds: Rect::from_center_half_size(
+            bounds.center(),
+            bounds.half_size().max(Vec2::splat(GIZMO_CORNER_SIZE)),
+        ),
+    })
+}
+
+/// Maps a group member's world transform at drag start to its dragged transform.
+pub(crate) type GroupUpdate = Box<dyn Fn(&Transform) -> Transform>;
+
+/// Sets an entity's transform from a world-space transform, relative to its parent.
+pub(crate) fn set_world_transform(world: &mut World, entity: Entity, transform: Transform) {
+    let parent_global = world
+        .get::<Parent>(entity)
+        .and_then(|parent| world.get::<GlobalTransform>(parent.get()))
+        .copied();
+    let local = match parent_global {
+        Some(parent) => GlobalTransform::from(transform).reparented_to(&parent),
+        None => transform,
+    };
+    if let Some(mut current) = world.get_mut::<Transform>(entity) {
+        *current = local;
+    }
+}
+
+/// Renders and handles one gizmo moving, rotating, or scaling several entities.
+///
+/// Drags work in world space from the transforms captured when the drag
+/// started. Rotation and scale pivot about [`GroupGizmo::pivot`], or about each
+/// entity's own origin with [`GizmoPivot::IndividualOrigins`]. Scaling
+/// multiplies each entity's scale by the drag factor, so rotated entities
+/// stretch along their own axes.
+fn draw_group_gizmo(
+    painter: &egui::Painter,
+    viewport_rect: egui::Rect,
+    world: &mut World,
+    response: &egui::Response,
+    entities: &[Entity],
+    mode: GizmoMode,
+) {
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return;
+    };
+    let Some(gizmo) = group_gizmo(world, entities) else {
+        return;
+    };
+    let individual = *world.resource::<GizmoPivot>() == GizmoPivot::IndividualOrigins;
+    let pivot_screen = view.world_to_screen(gizmo.pivot, viewport_rect);
+    let scale_handles = gizmo.scale_handles(&view, viewport_rect);
+
+    let hit_test = |pos: egui::Pos2| match mode {
+        GizmoMode::Translate => hit_test_gizmo(pos, pivot_screen),
+        GizmoMode::Rotate => hit_test_rotation_gizmo(pos, pivot_screen),
+        _ => hit_test_scale_gizmo(pos, &scale_handles),
+    };
+    let pointer_pos = response.hover_pos();
+    let hovered = pointer_pos.and_then(hit_test);
+    let snap = *world.resource::<SnapSettings>();
+    let snapping = snap.active(response.ctx.input(|input| input.modifiers.ctrl));
+
+    let mut drag_state = world.resource_mut::<GizmoDragState>();
+
+    if response.drag_started_by(egui::PointerButton::Primary) {
+        // Start from where the button went down, so the first movement counts
+        let press_origin = response.ctx.input(|input| input.pointer.press_origin());
+        if let Some(handle) = press_origin.and_then(hit_test).or(hovered) {
+            drag_state.dragging = Some(handle);
+            drag_state.drag_start = press_origin.or(pointer_pos);
+            drag_state.group_start = gizmo.members.clone();
+            drag_state.pivot_start = Some(gizmo.pivot);
+            drag_state.bounds_start = Some(gizmo.bounds);
+        }
+    }
+
+    if response.drag_stopped() {
+        *drag_state = GizmoDragState::default();
+    }
+
+    let mut sweep = None;
+    if let (Some(handle), Some(start), Some(pivot), Some(bounds), Some(current)) = (
+        drag_state.dragging,
+        drag_state.drag_start,
+        drag_state.pivot_start,
+        drag_state.bounds_start,
+        pointer_pos,
+    ) {
+        let members = drag_state.group_start.clone();
+        let pivot_screen = view.world_to_screen(pivot, viewport_rect);
+
+        // Each drag maps a member's start transform to its new world transform
+        let update: Option<GroupUpdate> = match handle {
+            GizmoAxis::X | GizmoAxis::Y | GizmoAxis::Center => {
+                let delta = view.screen_delta_to_world(current - start);
+                let mut delta = match handle {
+                    GizmoAxis::X => Vec2::new(delta.x, 0.0),
+                    GizmoAxis::Y => Vec2::new(0.0, delta.y),
+                    _ => delta,
+                };
+                // Snap the gizmo to the grid and move every member by the same amount
+                if snapping {
+                    let target = pivot + delta;
+                    if handle != GizmoAxis::Y {
+                        delta.x = quantize(target.x, snap.grid_size) - pivot.x;
+                    }
+                    if handle != GizmoAxis::X {
+                        delta.y = quantize(target.y, snap.grid_size) - pivot.y;
+                    }
+                }
+                Some(group_translate(delta))
+            }
+            GizmoAxis::Ring => {
+                let mut angle =
+                    screen_angle(current - pivot_screen) - screen_angle(start - pivot_screen);
+                if snapping {
+                    angle = quantize(angle.to_degrees(), snap.rotation_increment).to_radians();
+                }
+                sweep = Some(angle);
+                Some(group_rotate(angle, pivot, individual))
+            }
+            GizmoAxis::Corner(sign) => {
+                let corner = bounds.center() + bounds.half_size() * sign.as_vec2();
+                let pointer = view.screen_to_world(current, viewport_rect);
+                let axis_factor = |offset: f32, extent: f32| {
+                    if extent.abs() < f32::EPSILON {
+                        1.0
+                    } else {
+                        (offset / extent).abs().max(MIN_SCALE)
+                    }
+                };
+                let factor = Vec2::new(
+                    axis_factor(pointer.x - pivot.x, corner.x - pivot.x),
+                    axis_factor(pointer.y - pivot.y, corner.y - pivot.y),
+                );
+                Some(group_scale(factor, pivot, individual))
+            }
+            GizmoAxis::Uniform => {
+                // Dragging right or up grows, left or down shrinks
+                let delta = current - start;
+                let factor = 2f32.powf((delta.x - delta.y) / UNIFORM_SCALE_SPEED);
+                Some(group_scale(
+                    Vec2::splat(factor.max(MIN_SCALE)),
+                    pivot,
+                    individual,
+                ))
+            }
+            _ => None,
+        };
+
+        if let Some(update) = update {
+            for (entity, transform) in &members {
+                set_world_transform(world, *entity, update(transform));
+            }
+        }
+    }
+
+    // Member origins
+    for (_, transform) in &gizmo.members {
+        let origin = view.world_to_screen(transform.translation.truncate(), viewport_rect);
+        painter.circle_filled(origin, 2.5, COLOR_BOUNDS);
+    }
+
+    let active = world.resource::<GizmoDragState>().dragging.or(hovered);
+    match mode {
+        GizmoMode::Translate => {
+            let x_color = if active == Some(GizmoAxis::X) {
+                COLOR_X_HOVER
+            } else {
+                COLOR_X
+            };
+            draw_arrow(
+                painter,
+                pivot_screen,
+                egui::vec2(GIZMO_LENGTH, 0.0),
+                x_color,
+            );
+            let y_color = if active == Some(GizmoAxis::Y) {
+                COLOR_Y_HOVER
+            } else {
+                COLOR_Y
+            };
+            draw_arrow(
+                painter,
+                pivot_screen,
+                egui::vec2(0.0, -GIZMO_LENGTH),
+                y_color,
+            );
+            let center_color = if active == Some(GizmoAxis::Center) {
+                COLOR_CENTER_HOVER
+            } else {
+                COLOR_CENTER
+            };
+            painter.rect_filled(
+                egui::Rect::from_center_size(
+                    pivot_screen,
+                    egui::vec2(GIZMO_CENTER_SIZE, GIZMO_CENTER_SIZE),
+                ),
+                2.0,
+                center_color,
+            );
+        }
+        GizmoMode::Rotate => {
+            let color = if active == Some(GizmoAxis::Ring) {
+                COLOR_RING_HOVER
+            } else {
+                COLOR_RING
+            };
+            painter.circle_stroke(
+                pivot_screen,
+                GIZMO_RING_RADIUS,
+                egui::Stroke::new(GIZMO_THICKNESS, color),
+            );
+            // The angle swept so far, starting from +X
+            let angle = sweep.unwrap_or(0.0);
+            let heading = egui::vec2(angle.cos(), -angle.sin()) * GIZMO_RING_RADIUS;
+            painter.line_segment(
+                [pivot_screen, pivot_screen + heading],
+                egui::Stroke::new(GIZMO_THICKNESS * 0.5, color),
+            );
+            painter.circle_filled(pivot_screen, GIZMO_CENTER_SIZE * 0.25, color);
+        }
+        _ => {
+            let outline: Vec<egui::Pos2> = scale_handles
+                .corners
+                .iter()
+                .map(|&(_, _, pos)| pos)
+                .collect();
+            painter.add(egui::Shape::closed_line(
+                outline,
+                egui::Stroke::new(1.5, COLOR_BOUNDS),
+            ));
+            for &(sign, _, pos) in &scale_handles.corners {
+                let color = if active == Some(GizmoAxis::Corner(sign)) {
+                    COLOR_CORNER_HOVER
+                } else {
+                    COLOR_CORNER
+                };
+                painter.rect_filled(
+                    egui::Rect::from_center_size(
+                        pos,
+                        egui::vec2(GIZMO_CORNER_SIZE, GIZMO_CORNER_SIZE),
+                    ),
+                    1.0,
+                    color,
+                );
+            }
+            let center_color = if active == Some(GizmoAxis::Uniform) {
+                COLOR_CENTER_HOVER
+            } else {
+                COLOR_CENTER
+            };
+            painter.circle_filled(pivot_screen, GIZMO_CENTER_SIZE * 0.5, center_color);
+        }
+    }
+}
+
+/// A group move by `delta` in world space.
+pub(crate) fn group_translate(delta: Vec2) -> GroupUpdate {
+    Box::new(move |transform: &Transform| Transform {
+        translation: transform.translation + delta.extend(0.0),
+        ..*transform
+    })
+}
+
+/// A group rotation by `angle` radians about `pivot`, or about each origin when `individual`.
+pub(crate) fn group_rotate(angle: f32, pivot: Vec2, individual: bool) -> GroupUpdate {
+    let rotation = Quat::from_rotation_z(angle);
+    Box::new(move |transform: &Transform| {
+        let mut translation = transform.translation;
+        if !individual {
+            let offset = (translation.truncate() - pivot).extend(0.0);
+            translation = pivot.extend(translation.z) + rotation * offset;
+        }
+        Transform {
+            translation,
+            rotation: rotation * transform.rotation,
+            scale: transform.scale,
+        }
+    })
+}
+
+/// A group scale by `factor` about `pivot`, or about each origin when `individual`.
+pub(crate) fn group_scale(factor: Vec2, pivot: Vec2, individual: bool) -> GroupUpdate {
+    Box::new(move |transform: &Transform| {
+        let mut translation = transform.translation;
+        if !individual {
+            let offset = (translation.truncate() - pivot) * factor;
+            translation = (pivot + offset).extend(translation.z);
+        }
+        Transform {
+            translation,
+            rotation: transform.rotation,
+            scale: (transform.scale.truncate() * factor).extend(transform.scale.z),
+        }
+    })
+}
+
 /// Draws an arrow from origin in the given direction.
 fn draw_arrow(
     painter: &egui::Painter,
@@ -246,6 +1501,42 @@ fn hit_test_gizmo(pointer: egui::Pos2, gizmo_center: egui::Pos2) -> Option<Gizmo
     None
 }
 
+/// Hit tests the rotation ring.
+fn hit_test_rotation_gizmo(pointer: egui::Pos2, gizmo_center: egui::Pos2) -> Option<GizmoAxis> {
+    let distance = (pointer - gizmo_center).length();
+    ((distance - GIZMO_RING_RADIUS).abs() < GIZMO_HIT_RADIUS).then_some(GizmoAxis::Ring)
+}
+
+/// Hit tests the scale gizmo's corner and uniform handles.
+fn hit_test_scale_gizmo(pointer: egui::Pos2, handles: &ScaleHandles) -> Option<GizmoAxis> {
+    if (pointer - handles.origin).length() < GIZMO_CENTER_SIZE * 0.5 + GIZMO_HIT_RADIUS * 0.5 {
+        return Some(GizmoAxis::Uniform);
+    }
+
+    handles
+        .corners
+        .iter()
+        .find(|&&(_, _, pos)| (pointer - pos).length() < GIZMO_HIT_RADIUS)
+        .map(|&(sign, _, _)| GizmoAxis::Corner(sign))
+}
+
+/// Hit tests the rect gizmo's pivot, corner, and edge handles, in that order.
+fn hit_test_rect_gizmo(pointer: egui::Pos2, handles: &RectHandles) -> Option<GizmoAxis> {
+    if (pointer - handles.pivot).length() < GIZMO_CENTER_SIZE * 0.5 + GIZMO_HIT_RADIUS * 0.5 {
+        return Some(GizmoAxis::Pivot);
+    }
+
+    let hit = |&&(_, pos): &&(IVec2, egui::Pos2)| (pointer - pos).length() < GIZMO_HIT_RADIUS;
+    let corner = handles
+        .handles
+        .iter()
+        .filter(|(sign, _)| sign.x != 0 && sign.y != 0)
+        .find(hit);
+    corner
+        .or_else(|| handles.handles.iter().find(hit))
+        .map(|&(sign, _)| GizmoAxis::Resize(sign))
+}
+
 /// Calculates the distance from a point to a line segment.
 fn distance_to_line_segment(point: egui::Pos2, start: egui::Pos2, end: egui::Pos2) -> f32 {
     let line = end - start;
@@ -260,14 +1551,3 @@ fn distance_to_line_segment(point: egui::Pos2, start: egui::Pos2, end: egui::Pos
 
     (point - projection).length()
 }
-
-/// Converts world coordinates to screen coordinates.
-fn world_to_screen(world_pos: Vec2, camera_pos: Vec2, viewport_rect: egui::Rect) -> egui::Pos2 {
-    let relative = world_pos - camera_pos;
-    let viewport_center = viewport_rect.center();
-
-    egui::pos2(
-        viewport_center.x + relative.x,
-        viewport_center.y - relative.y, // Screen Y is inverted
-    )
-}
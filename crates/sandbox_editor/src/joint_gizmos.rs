@@ -0,0 +1,205 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Viewport display and editing of [`DistanceJoint`] anchors.
+//!
+//! Every joint is drawn as a line between its two anchors, with thin spokes
+//! from each entity's origin to its anchor. While stopped, the primary
+//! selection's joint gets a handle on each anchor; dragging one moves that
+//! anchor, kept in the owning entity's local space, as one undo step.
+
+use bevy::prelude::*;
+use bevy::reflect::PartialReflect;
+use bevy_egui::egui;
+use sandbox_engine::editor_state::EditorPlayState;
+use sandbox_engine::joints::DistanceJoint;
+use sandbox_engine::scene::SceneManager;
+use std::any::TypeId;
+
+use crate::editor_camera::ViewportCamera;
+use crate::selection::EditorSelection;
+use crate::undo::{record_component_edit, snapshot_components};
+
+/// Color of joint lines and anchors.
+const COLOR_JOINT: egui::Color32 = egui::Color32::from_rgb(255, 170, 60);
+
+/// Color of a hovered or dragged handle.
+const COLOR_HANDLE_ACTIVE: egui::Color32 = egui::Color32::WHITE;
+
+/// Radius of the anchor handles, in points.
+const HANDLE_RADIUS: f32 = 5.0;
+
+/// Distance from a handle within which it can be grabbed, in points.
+const HANDLE_HIT_RADIUS: f32 = 8.0;
+
+/// Which end of a joint a handle moves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum JointEnd {
+    /// `DistanceJoint::anchor`, on the joint's own entity.
+    Anchor,
+    /// `DistanceJoint::connected_anchor`, on the connected entity.
+    Connected,
+}
+
+/// Resource tracking a drag of a joint anchor handle.
+#[derive(Resource, Default)]
+pub struct JointAnchorDrag {
+    /// End being dragged.
+    end: Option<JointEnd>,
+    /// The joint's entity, with its `DistanceJoint` from before the drag.
+    before: Vec<(Entity, Option<Box<dyn PartialReflect>>)>,
+}
+
+/// A joint's ends in world space.
+struct JointView {
+    entity: Entity,
+    joint: DistanceJoint,
+    origin: Vec2,
+    anchor: Vec2,
+    /// Connected entity's transform, origin, and the connected anchor.
+    connected: Option<(GlobalTransform, Vec2, Vec2)>,
+}
+
+/// Draws joints and handles dragging the selected joint's anchors.
+///
+/// Returns whether a handle owns the pointer, so the gizmo and picking
+/// should ignore it this frame.
+pub fn draw_joints(
+    painter: &egui::Painter,
+    viewport_rect: egui::Rect,
+    world: &mut World,
+    response: &egui::Response,
+) -> bool {
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return false;
+    };
+    let to_screen = |position: Vec2| view.world_to_screen(position, viewport_rect);
+
+    let mut joints = world.query::<(Entity, &DistanceJoint, &GlobalTransform)>();
+    let views: Vec<JointView> = joints
+        .iter(world)
+        .map(|(entity, joint, transform)| JointView {
+            entity,
+            joint: *joint,
+            origin: transform.translation().truncate(),
+            anchor: transform
+                .transform_point(joint.anchor.extend(0.0))
+                .truncate(),
+            connected: joint
+                .connected
+                .and_then(|connected| world.get::<GlobalTransform>(connected))
+                .map(|connected| {
+                    (
+                        *connected,
+                        connected.translation().truncate(),
+                        connected
+                            .transform_point(joint.connected_anchor.extend(0.0))
+                            .truncate(),
+                    )
+                }),
+        })
+        .collect();
+
+    let thin = egui::Stroke::new(1.0, COLOR_JOINT.gamma_multiply(0.6));
+    for joint in &views {
+        painter.line_segment([to_screen(joint.origin), to_screen(joint.anchor)], thin);
+        painter.circle_stroke(to_screen(joint.anchor), 3.0, thin);
+        let Some((_, origin, anchor)) = joint.connected else {
+            continue;
+        };
+        painter.line_segment([to_screen(origin), to_screen(anchor)], thin);
+        painter.circle_stroke(to_screen(anchor), 3.0, thin);
+        painter.line_segment(
+            [to_screen(joint.anchor), to_screen(anchor)],
+            egui::Stroke::new(1.5, COLOR_JOINT),
+        );
+    }
+
+    if *world.resource::<State<EditorPlayState>>().get() != EditorPlayState::Stopped {
+        return false;
+    }
+    let primary = world.resource::<EditorSelection>().selected_entity;
+    let Some(selected) = views.iter().find(|joint| Some(joint.entity) == primary) else {
+        return false;
+    };
+
+    let mut handles = vec![(JointEnd::Anchor, to_screen(selected.anchor))];
+    if let Some((_, _, anchor)) = selected.connected {
+        handles.push((JointEnd::Connected, to_screen(anchor)));
+    }
+    let hit = |pos: egui::Pos2| {
+        handles
+            .iter()
+            .find(|(_, handle)| handle.distance(pos) <= HANDLE_HIT_RADIUS)
+            .map(|(end, _)| *end)
+    };
+
+    if response.drag_started_by(egui::PointerButton::Primary) {
+        let press_origin = response.ctx.input(|input| input.pointer.press_origin());
+        if let Some(end) = press_origin.and_then(hit) {
+            let before =
+                snapshot_components(world, &[selected.entity], TypeId::of::<DistanceJoint>());
+            *world.resource_mut::<JointAnchorDrag>() = JointAnchorDrag {
+                end: Some(end),
+                before,
+            };
+        }
+    }
+
+    let dragging = world.resource::<JointAnchorDrag>().end;
+    if let (Some(end), Some(pointer)) = (dragging, response.interact_pointer_pos()) {
+        let pointer = view.screen_to_world(pointer, viewport_rect).extend(0.0);
+        let owner = match end {
+            JointEnd::Anchor => world.get::<GlobalTransform>(selected.entity).copied(),
+            JointEnd::Connected => selected.connected.map(|(transform, _, _)| transform),
+        };
+        let local = owner.map(|owner| {
+            owner
+                .affine()
+                .inverse()
+                .transform_point3(pointer)
+                .truncate()
+        });
+        if let (Some(local), Some(mut joint)) =
+            (local, world.get_mut::<DistanceJoint>(selected.entity))
+        {
+            match end {
+                JointEnd::Anchor => joint.anchor = local,
+                JointEnd::Connected => joint.connected_anchor = local,
+            }
+        }
+    }
+    if dragging.is_some() && response.drag_stopped() {
+        let drag = std::mem::take(&mut *world.resource_mut::<JointAnchorDrag>());
+        record_component_edit(
+            world,
+            "Move Joint Anchor",
+            TypeId::of::<DistanceJoint>(),
+            drag.before,
+        );
+        if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+            manager.mark_dirty();
+        }
+    }
+
+    let active = dragging.or_else(|| response.hover_pos().and_then(hit));
+    for (end, pos) in handles {
+        let color = if active == Some(end) {
+            COLOR_HANDLE_ACTIVE
+        } else {
+            COLOR_JOINT
+        };
+        painter.circle_filled(pos, HANDLE_RADIUS, color);
+    }
+    if let Some((_, _, anchor)) = selected.connected {
+        let middle = to_screen(selected.anchor.lerp(anchor, 0.5));
+        painter.text(
+            middle + egui::vec2(0.0, -6.0),
+            egui::Align2::CENTER_BOTTOM,
+            format!("Length {}", selected.joint.length),
+            egui::FontId::proportional(11.0),
+            COLOR_JOINT,
+        );
+    }
+    dragging.is_some()
+}
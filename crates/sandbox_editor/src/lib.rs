@@ -0,0 +1,803 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Sandbox Editor - Integrated editor for the Sandbox Engine
+//!
+//! The editor is a library so it can be driven headlessly by the UI test
+//! harness; `main.rs` only opens the window and adds `EditorPlugin`.
+
+use bevy::prelude::*;
+use bevy_egui::EguiPlugin;
+use sandbox_engine::editor_state::EditorPlayState;
+use sandbox_engine::embedded::EmbeddedAssetsPlugin;
+use sandbox_engine::particles::{ParticleEmitter, ParticlePreview};
+use sandbox_engine::project::ProjectSettings;
+use sandbox_engine::scene::SceneLoadSettings;
+use sandbox_engine::time_control::TimeControl;
+use sandbox_engine::SandboxPlugins;
+
+pub mod assets;
+pub mod audio_zones;
+pub mod autosave;
+pub mod clipboard;
+pub mod custom_inspector;
+pub mod editor_camera;
+pub mod emitter_gizmos;
+pub mod force_fields;
+pub mod gizmo;
+pub mod joint_gizmos;
+pub mod minimap;
+pub mod modal_transform;
+pub mod notes;
+pub mod picking;
+pub mod pins;
+pub mod play_indicator;
+pub mod presets;
+pub mod problems;
+pub mod scene_diff;
+pub mod scene_lock;
+pub mod scene_watch;
+pub mod selection;
+pub mod spawn_points;
+pub mod sprite_variants;
+pub mod ui;
+pub mod undo;
+pub mod unused_assets;
+pub mod usages;
+pub mod viewport;
+pub mod viewport_menu;
+pub mod wrap_bounds;
+
+/// egui as used by the editor, for game crates writing custom inspectors.
+pub use bevy_egui::egui;
+
+use assets::{AssetBrowserPlugin, AssetType};
+use audio_zones::draw_audio_zones;
+use autosave::AutosavePlugin;
+use clipboard::ClipboardPlugin;
+use custom_inspector::CustomInspectors;
+use editor_camera::{handle_camera_input, EditorCameraPlugin, ViewportCamera};
+use emitter_gizmos::{draw_emitter_shape, EmitterShapeDrag};
+use force_fields::draw_force_fields;
+use gizmo::{
+    draw_gizmo, handle_gizmo_mode_shortcuts, GizmoMode, GizmoPivot, GizmoPlugin, RectGizmoTarget,
+    SnapSettings,
+};
+use joint_gizmos::{draw_joints, JointAnchorDrag};
+use minimap::{draw_minimap, MinimapSettings};
+use modal_transform::{handle_modal_transform_keys, update_modal_transform, ModalTransformState};
+use notes::{draw_note_icons, NotesPlugin};
+use pins::PinPlugin;
+use play_indicator::{dim_during_play, draw_play_border, PlayIndicatorPlugin};
+use presets::PresetPlugin;
+use scene_lock::{SceneLockPlugin, SceneLockState};
+use scene_watch::SceneWatchPlugin;
+use selection::{EditorSelection, SelectionPlugin};
+use spawn_points::draw_spawn_points;
+use sprite_variants::SpriteVariantPlugin;
+use ui::asset_browser::accept_asset_drop;
+use ui::{
+    add_image_sprite, animation_editor_window, asset_browser_panel, asset_operation_window,
+    autosave_recovery_prompt, cancel_pick_on_escape, color_grades_window, confirm_window_close,
+    dialogue_preview_window, find_replace_window, hierarchy_panel, inspector_panel, memory_window,
+    menu_bar, problems_window, request_stop, save_issues_prompt, scene_changes_window,
+    scene_lock_prompt, scene_reload_prompt, scene_search_window, sequence_editor_window,
+    status_messages, stop_prompt, systems_window, teams_window, time_of_day_window,
+    unsaved_changes_prompt, unused_assets_window, usages_window, watch_window, world_flags_window,
+    AddComponentState, AnimationEditorState, AssetOperationState, ColorGradesWindowState,
+    DialoguePreviewState, EntityPickerState, FindReplaceState, HierarchyState, InspectorFocus,
+    MemoryWindowState, PrefabLinkState, ProblemsState, SaveIssuesPrompt, SceneChangesState,
+    SceneSearchState, SequenceEditorState, StopPromptState, SystemsWindowState, TeamsWindowState,
+    TimeOfDayWindowState, UnsavedChangesPrompt, UnusedAssetsState, UsagesState, WatchWindowState,
+    WorldFlagsWindowState,
+};
+use undo::UndoPlugin;
+use unused_assets::AssetTrash;
+use viewport::{fit_viewport_texture, ViewportPlugin};
+use viewport_menu::{viewport_context_menu, ViewportMenuState};
+use wrap_bounds::{draw_wrap_bounds, WrapBoundsDrag};
+
+/// Plugin that adds the editor: engine plugins, editor tools, and the UI.
+///
+/// Expects `DefaultPlugins` (windowed, or headless for tests) and a
+/// `ProjectRoot` resource to be set up by the app. Turn off
+/// `WindowPlugin::close_when_requested` so closing the window asks to save
+/// unsaved changes first.
+pub struct EditorPlugin;
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(EmbeddedAssetsPlugin)
+            .add_plugins(EguiPlugin)
+            .add_plugins(SandboxPlugins)
+            .add_plugins(SelectionPlugin)
+            .add_plugins(GizmoPlugin)
+            .add_plugins(AssetBrowserPlugin)
+            .add_plugins(SceneLockPlugin)
+            .add_plugins(SceneWatchPlugin)
+            .add_plugins(AutosavePlugin)
+            .add_plugins(ViewportPlugin)
+            .add_plugins(EditorCameraPlugin)
+            .add_plugins(PresetPlugin)
+            .add_plugins(UndoPlugin)
+            .add_plugins(ClipboardPlugin)
+            .add_plugins(PlayIndicatorPlugin)
+            .add_plugins(PinPlugin)
+            .add_plugins(NotesPlugin)
+            .add_plugins(SpriteVariantPlugin)
+            .insert_resource(SceneLoadSettings {
+                keep_editor_only: true,
+            })
+            .init_resource::<AnimationEditorState>()
+            .init_resource::<AddComponentState>()
+            .init_resource::<AssetOperationState>()
+            .init_resource::<ColorGradesWindowState>()
+            .init_resource::<CustomInspectors>()
+            .init_resource::<DialoguePreviewState>()
+            .init_resource::<FindReplaceState>()
+            .init_resource::<picking::RubberBandState>()
+            .init_resource::<MemoryWindowState>()
+            .init_resource::<MinimapSettings>()
+            .init_resource::<SystemsWindowState>()
+            .init_resource::<WatchWindowState>()
+            .init_resource::<ModalTransformState>()
+            .init_resource::<ViewportMenuState>()
+            .init_resource::<EntityPickerState>()
+            .init_resource::<HierarchyState>()
+            .init_resource::<JointAnchorDrag>()
+            .init_resource::<EmitterShapeDrag>()
+            .init_resource::<InspectorFocus>()
+            .init_resource::<PrefabLinkState>()
+            .init_resource::<ProblemsState>()
+            .init_resource::<SceneChangesState>()
+            .init_resource::<SceneSearchState>()
+            .init_resource::<SequenceEditorState>()
+            .init_resource::<StopPromptState>()
+            .init_resource::<SaveIssuesPrompt>()
+            .init_resource::<UnsavedChangesPrompt>()
+            .init_resource::<TeamsWindowState>()
+            .init_resource::<TimeOfDayWindowState>()
+            .init_resource::<UnusedAssetsState>()
+            .init_resource::<AssetTrash>()
+            .init_resource::<UsagesState>()
+            .init_resource::<WorldFlagsWindowState>()
+            .init_resource::<WrapBoundsDrag>()
+            .add_systems(Update, (confirm_window_close, editor_ui));
+    }
+}
+
+/// Draws the editor UI into the window's egui context.
+fn editor_ui(world: &mut World) {
+    // Extract egui context (absent when running headless)
+    let Some(mut egui_ctx) = world
+        .query::<&mut bevy_egui::EguiContext>()
+        .iter_mut(world)
+        .next()
+        .map(|context| context.clone())
+    else {
+        return;
+    };
+
+    draw_editor(egui_ctx.get_mut(), world);
+}
+
+/// Draws every editor panel and window for one egui frame.
+///
+/// Public so the UI test harness can run frames against its own context.
+pub fn draw_editor(ctx: &egui::Context, world: &mut World) {
+    // Menu bar (File, Edit, etc.)
+    menu_bar(ctx, world);
+
+    // Status messages (errors, success notifications)
+    status_messages(ctx, world);
+
+    // Warning when opening a scene locked by someone else
+    scene_lock_prompt(ctx, world);
+
+    // Save, discard, or keep unsaved changes before New, Load, or quitting
+    unsaved_changes_prompt(ctx, world);
+
+    // Issues found in the scene before saving it
+    save_issues_prompt(ctx, world);
+
+    // Offer autosaves newer than their scene, found on startup
+    autosave_recovery_prompt(ctx, world);
+
+    // Offer to reload the scene when its file changes on disk
+    scene_reload_prompt(ctx, world);
+
+    // Keep or discard runtime changes when stopping play mode
+    stop_prompt(ctx, world);
+
+    // Confirm asset browser renames, deletes, and new folders
+    asset_operation_window(ctx, world);
+
+    // Escape cancels the entity reference eyedropper
+    cancel_pick_on_escape(ctx, world);
+
+    // G/R/S over the viewport start a modal transform; X/Y, numbers, Enter and Esc drive it
+    handle_modal_transform_keys(ctx, world);
+
+    // W/E/R/T switch the gizmo mode
+    handle_gizmo_mode_shortcuts(ctx, world);
+
+    // Animation editor window (floating)
+    animation_editor_window(ctx, world);
+
+    // Find-and-replace window (floating)
+    find_replace_window(ctx, world);
+
+    // Find in Scene window (floating)
+    scene_search_window(ctx, world);
+
+    // World flags window (floating)
+    world_flags_window(ctx, world);
+
+    // Team relations window (floating)
+    teams_window(ctx, world);
+
+    // Time of day and tint gradient window (floating)
+    time_of_day_window(ctx, world);
+
+    // Color group grades window (floating)
+    color_grades_window(ctx, world);
+
+    // Watched field values and graphs (sampled every frame, window floating)
+    ui::watch::sample_watches(world);
+    watch_window(ctx, world);
+
+    // Per-system toggles window (floating)
+    systems_window(ctx, world);
+
+    // Memory diagnostics window (floating)
+    memory_window(ctx, world);
+
+    // Unused asset finder window (floating)
+    unused_assets_window(ctx, world);
+
+    // Broken asset reference window (floating)
+    problems_window(ctx, world);
+
+    // Unsaved changes compared with the scene file (floating)
+    scene_changes_window(ctx, world);
+
+    // Asset usages window (floating, opened from the asset browser)
+    usages_window(ctx, world);
+
+    // Dialogue preview window (floating, opened from the asset browser)
+    dialogue_preview_window(ctx, world);
+
+    // Sequence timeline editor (floating, opened from the asset browser)
+    sequence_editor_window(ctx, world);
+
+    // Top toolbar with play/pause/stop controls
+    egui::TopBottomPanel::top("toolbar")
+        .exact_height(36.0)
+        .show(ctx, |ui| {
+            ui.horizontal_centered(|ui| {
+                toolbar_ui(ui, world);
+            });
+        });
+
+    // Left panel - Hierarchy (narrow)
+    egui::SidePanel::left("hierarchy_panel")
+        .resizable(true)
+        .default_width(200.0)
+        .min_width(150.0)
+        .max_width(400.0)
+        .show(ctx, |ui| {
+            dim_during_play(ui, world);
+            hierarchy_panel(ui, world);
+        });
+
+    // Right panel - Inspector
+    egui::SidePanel::right("inspector_panel")
+        .resizable(true)
+        .default_width(300.0)
+        .min_width(200.0)
+        .max_width(500.0)
+        .show(ctx, |ui| {
+            dim_during_play(ui, world);
+            inspector_panel(ui, world);
+        });
+
+    // Bottom panel - Asset Browser
+    egui::TopBottomPanel::bottom("asset_browser_panel")
+        .resizable(true)
+        .default_height(200.0)
+        .min_height(100.0)
+        .max_height(400.0)
+        .show(ctx, |ui| {
+            dim_during_play(ui, world);
+            asset_browser_panel(ui, world);
+        });
+
+    // Central panel - Viewport
+    egui::CentralPanel::default().show(ctx, |ui| {
+        viewport_panel(ui, world);
+    });
+}
+
+/// Renders the toolbar with play/pause/stop controls.
+fn toolbar_ui(ui: &mut egui::Ui, world: &mut World) {
+    let current_state = *world.resource::<State<EditorPlayState>>().get();
+
+    ui.add_space(10.0);
+
+    // Play button
+    let play_enabled = current_state != EditorPlayState::Playing;
+    if ui
+        .add_enabled(play_enabled, egui::Button::new("▶ Play"))
+        .clicked()
+    {
+        if let Some(mut next_state) = world.get_resource_mut::<NextState<EditorPlayState>>() {
+            next_state.set(EditorPlayState::Playing);
+        }
+    }
+
+    // Pause button
+    let pause_enabled = current_state == EditorPlayState::Playing;
+    if ui
+        .add_enabled(pause_enabled, egui::Button::new("⏸ Pause"))
+        .clicked()
+    {
+        if let Some(mut next_state) = world.get_resource_mut::<NextState<EditorPlayState>>() {
+            next_state.set(EditorPlayState::Paused);
+        }
+    }
+
+    // Resume button (only shown when paused)
+    if current_state == EditorPlayState::Paused && ui.button("▶ Resume").clicked() {
+        if let Some(mut next_state) = world.get_resource_mut::<NextState<EditorPlayState>>() {
+            next_state.set(EditorPlayState::Playing);
+        }
+    }
+
+    // Stop button (asks whether to keep runtime changes, if there are any)
+    let stop_enabled = current_state != EditorPlayState::Stopped;
+    if ui
+        .add_enabled(stop_enabled, egui::Button::new("⏹ Stop"))
+        .clicked()
+    {
+        request_stop(world);
+    }
+
+    ui.separator();
+
+    // Display current state
+    let state_text = match current_state {
+        EditorPlayState::Stopped => "Stopped",
+        EditorPlayState::Playing => "Playing",
+        EditorPlayState::Paused => "Paused",
+    };
+    ui.label(format!("State: {}", state_text));
+
+    ui.separator();
+
+    // Game speed debug slider
+    let mut time_scale = world.resource::<TimeControl>().base_scale;
+    let response = ui
+        .add(
+            egui::Slider::new(&mut time_scale, 0.0..=2.0)
+                .step_by(0.05)
+                .fixed_decimals(2)
+                .text("⏱ Speed"),
+        )
+        .on_hover_text("Game speed while playing (double-click to reset)");
+    if response.double_clicked() {
+        time_scale = 1.0;
+    }
+    if time_scale != world.resource::<TimeControl>().base_scale {
+        world.resource_mut::<TimeControl>().base_scale = time_scale;
+    }
+
+    ui.separator();
+
+    // Gizmo mode segment
+    let current_mode = *world.resource::<GizmoMode>();
+    for mode in GizmoMode::ALL {
+        if ui
+            .selectable_label(current_mode == mode, mode.label())
+            .on_hover_text(format!("Hotkey: {:?}", mode.hotkey()))
+            .clicked()
+        {
+            *world.resource_mut::<GizmoMode>() = mode;
+        }
+    }
+
+    // Pivot for multi-entity rotation and scale
+    let mut pivot = *world.resource::<GizmoPivot>();
+    egui::ComboBox::from_id_salt("gizmo_pivot")
+        .width(120.0)
+        .selected_text(pivot.label())
+        .show_ui(ui, |ui| {
+            for mode in GizmoPivot::ALL {
+                ui.selectable_value(&mut pivot, mode, mode.label());
+            }
+        })
+        .response
+        .on_hover_text("Pivot for rotating and scaling several entities");
+    if pivot != *world.resource::<GizmoPivot>() {
+        *world.resource_mut::<GizmoPivot>() = pivot;
+    }
+
+    // What the rect gizmo resizes
+    if current_mode == GizmoMode::Rect {
+        let mut target = *world.resource::<RectGizmoTarget>();
+        egui::ComboBox::from_id_salt("rect_gizmo_target")
+            .width(80.0)
+            .selected_text(match target {
+                RectGizmoTarget::CustomSize => "Size",
+                RectGizmoTarget::Scale => "Scale",
+            })
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut target, RectGizmoTarget::CustomSize, "Size")
+                    .on_hover_text("Resize by setting the sprite's custom size");
+                ui.selectable_value(&mut target, RectGizmoTarget::Scale, "Scale")
+                    .on_hover_text("Resize by setting the transform's scale");
+            })
+            .response
+            .on_hover_text("What rect handles change (hold Shift to keep the aspect ratio)");
+        if target != *world.resource::<RectGizmoTarget>() {
+            *world.resource_mut::<RectGizmoTarget>() = target;
+        }
+    }
+
+    // Snapping toggle and settings
+    let mut snap = *world.resource::<SnapSettings>();
+    ui.toggle_value(&mut snap.enabled, "🧲 Snap")
+        .on_hover_text("Snap gizmo drags to the grid (hold Ctrl to snap temporarily)");
+    ui.menu_button("▾", |ui| {
+        ui.horizontal(|ui| {
+            ui.label("Grid size:");
+            ui.add(
+                egui::DragValue::new(&mut snap.grid_size)
+                    .speed(1.0)
+                    .range(1.0..=1000.0),
+            );
+        });
+        ui.horizontal(|ui| {
+            ui.label("Rotation step (deg):");
+            ui.add(
+                egui::DragValue::new(&mut snap.rotation_increment)
+                    .speed(1.0)
+                    .range(1.0..=180.0),
+            );
+        });
+    });
+    if snap != *world.resource::<SnapSettings>() {
+        *world.resource_mut::<SnapSettings>() = snap;
+    }
+
+    // Warn when the open scene is locked by another editor
+    if let Some(lock) = &world.resource::<SceneLockState>().foreign {
+        ui.separator();
+        ui.colored_label(
+            egui::Color32::from_rgb(230, 160, 60),
+            format!("🔒 Locked by {}@{}", lock.user, lock.host),
+        );
+    }
+}
+
+/// Renders the viewport panel with the game view and gizmos.
+fn viewport_panel(ui: &mut egui::Ui, world: &mut World) {
+    ui.horizontal(|ui| {
+        ui.heading("Viewport");
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            let mut visible = world.resource::<MinimapSettings>().visible;
+            if ui
+                .toggle_value(&mut visible, "🗺 Minimap")
+                .on_hover_text("Show a scene overview; click it to move the camera")
+                .changed()
+            {
+                world.resource_mut::<MinimapSettings>().visible = visible;
+            }
+            // Emitters only run during play unless previewed
+            let has_emitters = world
+                .query_filtered::<(), With<ParticleEmitter>>()
+                .iter(world)
+                .next()
+                .is_some();
+            if has_emitters {
+                let stopped =
+                    *world.resource::<State<EditorPlayState>>().get() == EditorPlayState::Stopped;
+                let mut preview = world.resource::<ParticlePreview>().enabled;
+                if ui
+                    .add_enabled(
+                        stopped,
+                        egui::SelectableLabel::new(preview, "✨ Simulate Particles"),
+                    )
+                    .on_hover_text("Run particle emitters while stopped")
+                    .clicked()
+                {
+                    preview = !preview;
+                    world.resource_mut::<ParticlePreview>().enabled = preview;
+                }
+            }
+        });
+    });
+    ui.separator();
+
+    // Allocate viewport area
+    let available_size = ui.available_size();
+    let (rect, response) = ui.allocate_exact_size(available_size, egui::Sense::click_and_drag());
+
+    // Draw the scene camera's render target, resized to fit the panel
+    let painter = ui.painter_at(rect);
+    let texture_id = fit_viewport_texture(world, rect, ui.ctx().pixels_per_point());
+    painter.image(
+        texture_id,
+        rect,
+        egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+        egui::Color32::WHITE,
+    );
+
+    // Pan (middle mouse) and zoom (scroll wheel) the editor camera
+    handle_camera_input(ui, world, rect, &response);
+
+    // Draw grid (subtle reference lines)
+    draw_viewport_grid(&painter, rect, world);
+
+    // Outline the bounds of selected sprites
+    draw_selection_outlines(&painter, rect, world);
+
+    // Attractor and repulsor radii
+    draw_force_fields(&painter, rect, world);
+
+    // Music and ambience zones
+    draw_audio_zones(&painter, rect, world);
+
+    // Note icons, with the text of the hovered one
+    draw_note_icons(&painter, rect, world, &response);
+
+    // Spawn point flags, which select their spawn point when clicked
+    let spawn_point_clicked = draw_spawn_points(&painter, rect, world, &response);
+
+    // Wrap-around bounds, with handles on the selected entity's
+    let wrap_bounds_dragging = draw_wrap_bounds(&painter, rect, world, &response);
+
+    // Joint connections, with anchor handles on the selected joint
+    let joint_dragging = draw_joints(&painter, rect, world, &response);
+
+    // The selected emitter's shape, with handles for its size and angle
+    let emitter_dragging = draw_emitter_shape(&painter, rect, world, &response);
+
+    // A running modal transform owns the pointer until confirmed or cancelled
+    if !update_modal_transform(&painter, rect, world, &response)
+        && !wrap_bounds_dragging
+        && !joint_dragging
+        && !emitter_dragging
+    {
+        // Draw gizmos for selected entity
+        draw_gizmo(&painter, rect, world, &response);
+
+        // Click to select the sprite under the pointer, or drag a selection rectangle
+        if !spawn_point_clicked && world.resource::<gizmo::GizmoDragState>().dragging.is_none() {
+            picking::handle_viewport_click(world, rect, &response);
+        }
+        picking::handle_rubber_band(&painter, world, rect, &response);
+    }
+
+    // Right-click to create or paste entities at the pointer
+    viewport_context_menu(world, rect, &response);
+
+    // Drop an image from the asset browser to spawn a sprite where it lands
+    if let Some(path) = accept_asset_drop(ui, &response, AssetType::Image) {
+        let position = response
+            .hover_pos()
+            .zip(ViewportCamera::from_world(world))
+            .map(|(pointer, view)| view.screen_to_world(pointer, rect));
+        if let Some(position) = position {
+            add_image_sprite(world, &path, position);
+        }
+    }
+
+    // Scene overview in the corner
+    draw_minimap(ui, world, rect);
+
+    // Green (playing) or orange (paused) border and badge
+    draw_play_border(&painter, rect, world);
+}
+
+/// Outlines the sprite bounds of every selected entity.
+///
+/// The primary selection is drawn brighter and labeled with its size in world units.
+fn draw_selection_outlines(painter: &egui::Painter, viewport_rect: egui::Rect, world: &mut World) {
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return;
+    };
+    let (selected, primary) = {
+        let selection = world.resource::<EditorSelection>();
+        (selection.entities.clone(), selection.selected_entity)
+    };
+
+    for entity in selected {
+        let Some((sprite, transform)) = world
+            .get::<Sprite>(entity)
+            .zip(world.get::<GlobalTransform>(entity))
+        else {
+            continue;
+        };
+        let Some(corners) = picking::sprite_world_corners(world, sprite, transform) else {
+            continue;
+        };
+
+        let is_primary = primary == Some(entity);
+        let color = if is_primary {
+            egui::Color32::from_rgb(255, 200, 60)
+        } else {
+            egui::Color32::from_rgba_unmultiplied(255, 200, 60, 140)
+        };
+
+        let points: Vec<egui::Pos2> = corners
+            .iter()
+            .map(|&corner| view.world_to_screen(corner, viewport_rect))
+            .collect();
+        painter.add(egui::Shape::convex_polygon(
+            points.clone(),
+            egui::Color32::from_rgba_unmultiplied(255, 200, 60, 18),
+            egui::Stroke::NONE,
+        ));
+        painter.add(egui::Shape::closed_line(
+            points.clone(),
+            egui::Stroke::new(if is_primary { 2.0 } else { 1.0 }, color),
+        ));
+
+        if is_primary {
+            let size = Vec2::new(
+                corners[0].distance(corners[1]),
+                corners[1].distance(corners[2]),
+            );
+            let top = points
+                .iter()
+                .copied()
+                .min_by(|a, b| a.y.total_cmp(&b.y))
+                .unwrap_or(points[0]);
+            painter.text(
+                top - egui::vec2(0.0, 4.0),
+                egui::Align2::CENTER_BOTTOM,
+                format!("{} × {}", format_units(size.x), format_units(size.y)),
+                egui::FontId::proportional(11.0),
+                color,
+            );
+        }
+    }
+}
+
+/// Draws a subtle grid in the viewport for spatial reference.
+///
+/// Lines are a round number of world units apart (1, 2, or 5 times a power of
+/// ten), at least 40 points on screen, so spacing grows as the editor camera
+/// zooms out. Zoomed in below one unit in a project with more than one pixel
+/// per unit, lines are a power of two texture pixels apart instead and
+/// labelled in pixels, so they line up with the pixels of auto-sized sprites.
+fn draw_viewport_grid(painter: &egui::Painter, viewport_rect: egui::Rect, world: &mut World) {
+    let grid_color = egui::Color32::from_rgba_unmultiplied(100, 100, 120, 30);
+
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return;
+    };
+
+    // Grid spacing: a round number of world units, at least 40 points apart
+    let pixels_per_unit = world.resource::<ProjectSettings>().pixels_per_unit;
+    let (grid_spacing, in_pixels) = grid_step(40.0 / view.zoom, pixels_per_unit);
+    let label = |value: f32| {
+        if in_pixels {
+            format!("{}px", format_units(value * pixels_per_unit))
+        } else {
+            format_units(value)
+        }
+    };
+    let label_color = egui::Color32::from_rgba_unmultiplied(160, 160, 180, 140);
+    let label_font = egui::FontId::monospace(10.0);
+
+    // Visible world bounds
+    let min = view.screen_to_world(viewport_rect.left_bottom(), viewport_rect);
+    let max = view.screen_to_world(viewport_rect.right_top(), viewport_rect);
+
+    // Vertical lines
+    let mut x = (min.x / grid_spacing).floor() * grid_spacing;
+    while x <= max.x {
+        let screen_x = view.world_to_screen(Vec2::new(x, 0.0), viewport_rect).x;
+        painter.line_segment(
+            [
+                egui::pos2(screen_x, viewport_rect.top()),
+                egui::pos2(screen_x, viewport_rect.bottom()),
+            ],
+            egui::Stroke::new(1.0, grid_color),
+        );
+        painter.text(
+            egui::pos2(screen_x + 2.0, viewport_rect.bottom() - 2.0),
+            egui::Align2::LEFT_BOTTOM,
+            label(x),
+            label_font.clone(),
+            label_color,
+        );
+        x += grid_spacing;
+    }
+
+    // Horizontal lines
+    let mut y = (min.y / grid_spacing).floor() * grid_spacing;
+    while y <= max.y {
+        let screen_y = view.world_to_screen(Vec2::new(0.0, y), viewport_rect).y;
+        painter.line_segment(
+            [
+                egui::pos2(viewport_rect.left(), screen_y),
+                egui::pos2(viewport_rect.right(), screen_y),
+            ],
+            egui::Stroke::new(1.0, grid_color),
+        );
+        painter.text(
+            egui::pos2(viewport_rect.left() + 2.0, screen_y - 2.0),
+            egui::Align2::LEFT_BOTTOM,
+            label(y),
+            label_font.clone(),
+            label_color,
+        );
+        y += grid_spacing;
+    }
+
+    // Draw origin axes (more visible)
+    let origin = view.world_to_screen(Vec2::ZERO, viewport_rect);
+
+    if viewport_rect.x_range().contains(origin.x) {
+        painter.line_segment(
+            [
+                egui::pos2(origin.x, viewport_rect.top()),
+                egui::pos2(origin.x, viewport_rect.bottom()),
+            ],
+            egui::Stroke::new(
+                1.0,
+                egui::Color32::from_rgba_unmultiplied(200, 100, 100, 60),
+            ),
+        );
+    }
+
+    if viewport_rect.y_range().contains(origin.y) {
+        painter.line_segment(
+            [
+                egui::pos2(viewport_rect.left(), origin.y),
+                egui::pos2(viewport_rect.right(), origin.y),
+            ],
+            egui::Stroke::new(
+                1.0,
+                egui::Color32::from_rgba_unmultiplied(100, 200, 100, 60),
+            ),
+        );
+    }
+}
+
+/// Grid spacing in world units for lines at least `min` units apart, and
+/// whether it is a number of texture pixels rather than of units.
+fn grid_step(min: f32, pixels_per_unit: f32) -> (f32, bool) {
+    let units = nice_step(min);
+    if units >= 1.0 || pixels_per_unit <= 1.0 {
+        return (units, false);
+    }
+    // Powers of two divide the usual tile sizes evenly
+    let pixels = (min * pixels_per_unit).max(1.0).log2().ceil().exp2();
+    if pixels >= pixels_per_unit {
+        return (1.0, false);
+    }
+    (pixels / pixels_per_unit, true)
+}
+
+/// Returns the smallest 1, 2, or 5 times a power of ten that is at least `min`.
+fn nice_step(min: f32) -> f32 {
+    let magnitude = 10f32.powf(min.max(f32::EPSILON).log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|&step| step >= min)
+        .unwrap_or(10.0 * magnitude)
+}
+
+/// Formats a length in world units without trailing zeros.
+fn format_units(value: f32) -> String {
+    let formatted = format!("{:.2}", value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "-0" => "0".to_string(),
+        _ => trimmed.to_string(),
+    }
+}
@@ -0,0 +1,121 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Scene overview minimap docked in the viewport corner.
+//!
+//! Shows every entity as a dot and the editor camera's view as a rectangle.
+//! Clicking or dragging on the minimap moves the editor camera there.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::editor_camera::{center_editor_camera, ViewportCamera};
+use crate::selection::EditorSelection;
+
+/// Minimap size and margin from the viewport corner, in points.
+const MINIMAP_SIZE: egui::Vec2 = egui::vec2(180.0, 130.0);
+const MINIMAP_MARGIN: f32 = 8.0;
+
+/// Fraction of the scene bounds added as padding on each side.
+const BOUNDS_PADDING: f32 = 0.1;
+
+/// Resource controlling the minimap.
+#[derive(Resource, Default)]
+pub struct MinimapSettings {
+    /// Whether the minimap is shown.
+    pub visible: bool,
+}
+
+/// Draws the minimap over the bottom-right corner of the viewport and handles clicks on it.
+///
+/// Must be called after the viewport area is allocated so the minimap receives
+/// pointer input instead of the viewport.
+pub fn draw_minimap(ui: &mut egui::Ui, world: &mut World, viewport_rect: egui::Rect) {
+    if !world.resource::<MinimapSettings>().visible {
+        return;
+    }
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return;
+    };
+
+    let map_rect = egui::Rect::from_min_size(
+        viewport_rect.max - MINIMAP_SIZE - egui::vec2(MINIMAP_MARGIN, MINIMAP_MARGIN),
+        MINIMAP_SIZE,
+    );
+    if !viewport_rect.contains_rect(map_rect) {
+        return;
+    }
+
+    // Entity positions, excluding cameras
+    let selection = world.resource::<EditorSelection>().entities.clone();
+    let mut query = world.query_filtered::<(Entity, &GlobalTransform), Without<Camera>>();
+    let points: Vec<(Vec2, bool)> = query
+        .iter(world)
+        .map(|(entity, transform)| {
+            (
+                transform.translation().truncate(),
+                selection.contains(&entity),
+            )
+        })
+        .collect();
+
+    // The camera's visible area in world units
+    let view_rect = Rect::from_corners(
+        view.screen_to_world(viewport_rect.left_bottom(), viewport_rect),
+        view.screen_to_world(viewport_rect.right_top(), viewport_rect),
+    );
+
+    // Scene bounds covering all entities and the current view
+    let mut bounds = view_rect;
+    for (point, _) in &points {
+        bounds = bounds.union_point(*point);
+    }
+    bounds = bounds.inflate(bounds.size().max_element() * BOUNDS_PADDING);
+
+    // Uniform scale that fits the bounds into the minimap, centered
+    let scale = (map_rect.width() / bounds.width()).min(map_rect.height() / bounds.height());
+    let to_map = |point: Vec2| {
+        let offset = (point - bounds.center()) * scale;
+        map_rect.center() + egui::vec2(offset.x, -offset.y)
+    };
+    let to_world = |pos: egui::Pos2| {
+        let offset = pos - map_rect.center();
+        bounds.center() + Vec2::new(offset.x, -offset.y) / scale
+    };
+
+    let response = ui.interact(
+        map_rect,
+        ui.id().with("minimap"),
+        egui::Sense::click_and_drag(),
+    );
+
+    let painter = ui.painter_at(map_rect);
+    painter.rect(
+        map_rect,
+        4.0,
+        egui::Color32::from_rgba_unmultiplied(20, 20, 28, 200),
+        egui::Stroke::new(1.0, egui::Color32::from_gray(90)),
+    );
+
+    for (point, selected) in &points {
+        let (radius, color) = if *selected {
+            (3.0, egui::Color32::from_rgb(255, 255, 100))
+        } else {
+            (1.5, egui::Color32::from_gray(200))
+        };
+        painter.circle_filled(to_map(*point), radius, color);
+    }
+
+    painter.rect_stroke(
+        egui::Rect::from_two_pos(to_map(view_rect.min), to_map(view_rect.max)),
+        0.0,
+        egui::Stroke::new(1.0, egui::Color32::from_rgb(90, 160, 255)),
+    );
+
+    // Jump the editor camera to the clicked point
+    if response.clicked() || response.dragged_by(egui::PointerButton::Primary) {
+        if let Some(pointer) = response.interact_pointer_pos() {
+            center_editor_camera(world, to_world(pointer));
+        }
+    }
+}
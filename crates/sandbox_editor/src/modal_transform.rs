@@ -0,0 +1,403 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Keyboard-driven modal transforms, Blender style.
+//!
+//! With the pointer over the viewport, G (grab), R (rotate), or S (scale)
+//! starts moving the selection with the pointer. X or Y then constrains the
+//! operation to that world axis (press again to clear), and typing a number
+//! applies an exact value instead: units moved, degrees rotated, or a scale
+//! factor. Enter or a left click confirms as one undo step; Escape or a right
+//! click restores the selection.
+//!
+//! Outside the viewport, R keeps selecting the scale gizmo. The selection
+//! pivots as set by [`GizmoPivot`] and Ctrl snaps like gizmo drags.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::editor_state::EditorPlayState;
+use sandbox_engine::scene::SceneManager;
+use std::any::TypeId;
+
+use crate::editor_camera::ViewportCamera;
+use crate::gizmo::{
+    group_gizmo, group_rotate, group_scale, group_targets, group_translate, quantize, screen_angle,
+    set_world_transform, GizmoPivot, GroupUpdate, SnapSettings,
+};
+use crate::undo::{record_component_edit, snapshot_components};
+
+/// Transform a modal operation changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModalKind {
+    Grab,
+    Rotate,
+    Scale,
+}
+
+impl ModalKind {
+    /// Key that starts the operation.
+    pub fn hotkey(self) -> egui::Key {
+        match self {
+            ModalKind::Grab => egui::Key::G,
+            ModalKind::Rotate => egui::Key::R,
+            ModalKind::Scale => egui::Key::S,
+        }
+    }
+
+    /// Name shown in the viewport and the undo history.
+    pub fn label(self) -> &'static str {
+        match self {
+            ModalKind::Grab => "Grab",
+            ModalKind::Rotate => "Rotate",
+            ModalKind::Scale => "Scale",
+        }
+    }
+}
+
+/// World axis a modal operation is constrained to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModalAxis {
+    X,
+    Y,
+}
+
+/// An in-progress modal transform.
+pub struct ModalOperation {
+    /// Transform being changed.
+    pub kind: ModalKind,
+    /// Axis constraint, if any.
+    pub axis: Option<ModalAxis>,
+    /// Number typed so far; overrides the pointer when it parses.
+    pub input: String,
+    /// Pointer position when the operation started.
+    start_pointer: egui::Pos2,
+    /// World point rotation and scale pivot about.
+    pivot: Vec2,
+    /// Whether each entity pivots about its own origin.
+    individual: bool,
+    /// World transforms when the operation started.
+    start_world: Vec<(Entity, Transform)>,
+    /// Local transforms when the operation started, restored on cancel.
+    start_local: Vec<(Entity, Transform)>,
+    /// Set by Enter; the viewport applies the final value, then confirms.
+    confirm_requested: bool,
+}
+
+impl ModalOperation {
+    /// The typed value, if it is a number.
+    fn typed_value(&self) -> Option<f32> {
+        self.input.parse().ok()
+    }
+}
+
+/// Resource tracking the modal transform.
+#[derive(Resource, Default)]
+pub struct ModalTransformState {
+    /// The running operation, if any.
+    pub operation: Option<ModalOperation>,
+    /// Viewport rectangle from the last frame, for starting operations.
+    pub viewport_rect: Option<egui::Rect>,
+}
+
+impl ModalTransformState {
+    /// Whether an operation is running.
+    pub fn is_active(&self) -> bool {
+        self.operation.is_some()
+    }
+}
+
+/// Handles the modal transform's keys: G/R/S to start, X/Y, digits, Enter and Escape.
+///
+/// Runs before the gizmo shortcuts so R over the viewport starts a rotation.
+pub fn handle_modal_transform_keys(ctx: &egui::Context, world: &mut World) {
+    if ctx.wants_keyboard_input()
+        || *world.resource::<State<EditorPlayState>>().get() != EditorPlayState::Stopped
+    {
+        return;
+    }
+
+    if !world.resource::<ModalTransformState>().is_active() {
+        start_on_hotkey(ctx, world);
+        return;
+    }
+
+    let (axis, text, backspace, enter, escape) = ctx.input_mut(|input| {
+        let axis = [(egui::Key::X, ModalAxis::X), (egui::Key::Y, ModalAxis::Y)]
+            .into_iter()
+            .find(|&(key, _)| input.consume_key(egui::Modifiers::NONE, key))
+            .map(|(_, axis)| axis);
+        let text: String = input
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                egui::Event::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .flat_map(str::chars)
+            .filter(|c| c.is_ascii_digit() || *c == '.' || *c == '-')
+            .collect();
+        let backspace = input.consume_key(egui::Modifiers::NONE, egui::Key::Backspace);
+        let enter = input.consume_key(egui::Modifiers::NONE, egui::Key::Enter);
+        let escape = input.consume_key(egui::Modifiers::NONE, egui::Key::Escape);
+        (axis, text, backspace, enter, escape)
+    });
+
+    let mut state = world.resource_mut::<ModalTransformState>();
+    if let Some(operation) = state.operation.as_mut() {
+        if let Some(axis) = axis {
+            operation.axis = (operation.axis != Some(axis)).then_some(axis);
+        }
+        operation.input.push_str(&text);
+        if backspace {
+            operation.input.pop();
+        }
+        operation.confirm_requested |= enter;
+    }
+
+    if escape {
+        cancel(world);
+    }
+}
+
+/// Starts an operation when G, R, or S is pressed over the viewport with a selection.
+fn start_on_hotkey(ctx: &egui::Context, world: &mut World) {
+    let Some(viewport_rect) = world.resource::<ModalTransformState>().viewport_rect else {
+        return;
+    };
+    let Some(pointer) = ctx
+        .input(|input| input.pointer.latest_pos())
+        .filter(|&pos| viewport_rect.contains(pos))
+    else {
+        return;
+    };
+    let entities = group_targets(world);
+    let Some(gizmo) = group_gizmo(world, &entities) else {
+        return;
+    };
+
+    let pressed = ctx.input_mut(|input| {
+        [ModalKind::Grab, ModalKind::Rotate, ModalKind::Scale]
+            .into_iter()
+            .find(|kind| input.consume_key(egui::Modifiers::NONE, kind.hotkey()))
+    });
+    let Some(kind) = pressed else {
+        return;
+    };
+
+    let start_local = entities
+        .iter()
+        .filter_map(|&entity| Some((entity, *world.get::<Transform>(entity)?)))
+        .collect();
+    world.resource_mut::<ModalTransformState>().operation = Some(ModalOperation {
+        kind,
+        axis: None,
+        input: String::new(),
+        start_pointer: pointer,
+        pivot: gizmo.pivot,
+        individual: *world.resource::<GizmoPivot>() == GizmoPivot::IndividualOrigins,
+        start_world: gizmo.members,
+        start_local,
+        confirm_requested: false,
+    });
+}
+
+/// Applies the running operation from the pointer or typed value and draws its overlay.
+///
+/// Enter or a left click confirms and a right click cancels. Returns `true` while an
+/// operation is running, so the viewport skips gizmos and picking.
+pub fn update_modal_transform(
+    painter: &egui::Painter,
+    viewport_rect: egui::Rect,
+    world: &mut World,
+    response: &egui::Response,
+) -> bool {
+    world.resource_mut::<ModalTransformState>().viewport_rect = Some(viewport_rect);
+    if !world.resource::<ModalTransformState>().is_active() {
+        return false;
+    }
+    if *world.resource::<State<EditorPlayState>>().get() != EditorPlayState::Stopped {
+        cancel(world);
+        return false;
+    }
+
+    if response.secondary_clicked() {
+        cancel(world);
+        return true;
+    }
+
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return true;
+    };
+    let snap = *world.resource::<SnapSettings>();
+    let snapping = snap.active(response.ctx.input(|input| input.modifiers.ctrl));
+    let pointer = response
+        .ctx
+        .input(|input| input.pointer.latest_pos())
+        .unwrap_or(viewport_rect.center());
+
+    let state = world.resource::<ModalTransformState>();
+    let Some(operation) = state.operation.as_ref() else {
+        return true;
+    };
+    let pivot_screen = view.world_to_screen(operation.pivot, viewport_rect);
+    let typed = operation.typed_value();
+    let axis = operation.axis;
+
+    let (update, readout): (GroupUpdate, String) = match operation.kind {
+        ModalKind::Grab => {
+            let mut delta = match typed {
+                Some(value) => match axis {
+                    Some(ModalAxis::Y) => Vec2::new(0.0, value),
+                    _ => Vec2::new(value, 0.0),
+                },
+                None => view.screen_delta_to_world(pointer - operation.start_pointer),
+            };
+            match axis {
+                Some(ModalAxis::X) => delta.y = 0.0,
+                Some(ModalAxis::Y) => delta.x = 0.0,
+                None => {}
+            }
+            // Snap only the axes that move, so a constrained grab keeps the other one
+            if snapping && typed.is_none() {
+                let target = operation.pivot + delta;
+                if axis != Some(ModalAxis::Y) {
+                    delta.x = quantize(target.x, snap.grid_size) - operation.pivot.x;
+                }
+                if axis != Some(ModalAxis::X) {
+                    delta.y = quantize(target.y, snap.grid_size) - operation.pivot.y;
+                }
+            }
+            (
+                group_translate(delta),
+                format!("{}, {}", format_value(delta.x), format_value(delta.y)),
+            )
+        }
+        ModalKind::Rotate => {
+            let mut degrees = match typed {
+                Some(value) => value,
+                None => (screen_angle(pointer - pivot_screen)
+                    - screen_angle(operation.start_pointer - pivot_screen))
+                .to_degrees(),
+            };
+            if snapping && typed.is_none() {
+                degrees = quantize(degrees, snap.rotation_increment);
+            }
+            (
+                group_rotate(degrees.to_radians(), operation.pivot, operation.individual),
+                format!("{}°", format_value(degrees)),
+            )
+        }
+        ModalKind::Scale => {
+            let factor = typed.unwrap_or_else(|| {
+                let start = (operation.start_pointer - pivot_screen).length().max(1.0);
+                (pointer - pivot_screen).length() / start
+            });
+            let factor = match axis {
+                Some(ModalAxis::X) => Vec2::new(factor, 1.0),
+                Some(ModalAxis::Y) => Vec2::new(1.0, factor),
+                None => Vec2::splat(factor),
+            };
+            (
+                group_scale(factor, operation.pivot, operation.individual),
+                format!("{}, {}", format_value(factor.x), format_value(factor.y)),
+            )
+        }
+    };
+
+    // Axis guide through the pivot
+    if let Some(axis) = axis {
+        let (direction, color) = match axis {
+            ModalAxis::X => (egui::vec2(1.0, 0.0), egui::Color32::from_rgb(230, 80, 80)),
+            ModalAxis::Y => (egui::vec2(0.0, 1.0), egui::Color32::from_rgb(80, 200, 80)),
+        };
+        let reach = viewport_rect.width().max(viewport_rect.height());
+        painter.line_segment(
+            [
+                pivot_screen - direction * reach,
+                pivot_screen + direction * reach,
+            ],
+            egui::Stroke::new(1.0, color),
+        );
+    }
+
+    let axis_label = match axis {
+        Some(ModalAxis::X) => " along X",
+        Some(ModalAxis::Y) => " along Y",
+        None => "",
+    };
+    let typed_label = if operation.input.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", operation.input)
+    };
+    painter.text(
+        viewport_rect.left_bottom() + egui::vec2(8.0, -8.0),
+        egui::Align2::LEFT_BOTTOM,
+        format!(
+            "{}{}: {}{}  (X/Y constrain, type a value, Enter confirm, Esc cancel)",
+            operation.kind.label(),
+            axis_label,
+            readout,
+            typed_label
+        ),
+        egui::FontId::proportional(13.0),
+        egui::Color32::from_rgb(255, 220, 120),
+    );
+
+    let members = operation.start_world.clone();
+    let confirm_requested = operation.confirm_requested;
+    for (entity, transform) in &members {
+        set_world_transform(world, *entity, update(transform));
+    }
+    if confirm_requested || response.clicked() {
+        confirm(world);
+    }
+    true
+}
+
+/// Keeps the current transforms and records them as one undo step.
+fn confirm(world: &mut World) {
+    let Some(operation) = world.resource_mut::<ModalTransformState>().operation.take() else {
+        return;
+    };
+    let entities: Vec<Entity> = operation.start_local.iter().map(|(e, _)| *e).collect();
+    let type_id = TypeId::of::<Transform>();
+
+    // Snapshot the start state by restoring it briefly, then put the result back
+    let after: Vec<(Entity, Transform)> = entities
+        .iter()
+        .filter_map(|&entity| Some((entity, *world.get::<Transform>(entity)?)))
+        .collect();
+    restore(world, &operation.start_local);
+    let before = snapshot_components(world, &entities, type_id);
+    restore(world, &after);
+    record_component_edit(world, operation.kind.label(), type_id, before);
+
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
+    }
+}
+
+/// Restores the transforms from before the operation.
+fn cancel(world: &mut World) {
+    if let Some(operation) = world.resource_mut::<ModalTransformState>().operation.take() {
+        restore(world, &operation.start_local);
+    }
+}
+
+fn restore(world: &mut World, transforms: &[(Entity, Transform)]) {
+    for (entity, transform) in transforms {
+        if let Some(mut current) = world.get_mut::<Transform>(*entity) {
+            *current = *transform;
+        }
+    }
+}
+
+/// Formats a value with up to two decimals.
+fn format_value(value: f32) -> String {
+    let formatted = format!("{:.2}", value);
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
@@ -0,0 +1,141 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Editor support for [`EditorNote`]: a multi-line inspector and note icons.
+//!
+//! Entities with a note get a 📝 icon, in the note's color, next to their
+//! name in the hierarchy and at their position in the viewport; hovering
+//! either shows the text. Notes are stripped from scenes loaded by games.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::editor_state::EditorPlayState;
+use sandbox_engine::scene::EditorNote;
+
+use crate::custom_inspector::RegisterInspectorExt;
+use crate::editor_camera::ViewportCamera;
+
+/// Icon drawn for notes.
+pub const NOTE_ICON: &str = "📝";
+
+/// Radius of a note's icon in the viewport, in points.
+const ICON_RADIUS: f32 = 9.0;
+
+/// Plugin registering the note inspector.
+pub struct NotesPlugin;
+
+impl Plugin for NotesPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_inspector::<EditorNote>(note_inspector);
+    }
+}
+
+/// The note's color for egui.
+pub fn note_color(note: &EditorNote) -> egui::Color32 {
+    let [r, g, b, a] = note.color.to_srgba().to_u8_array();
+    egui::Color32::from_rgba_unmultiplied(r, g, b, a)
+}
+
+/// Edits a note's text and color.
+fn note_inspector(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
+    let Some(mut note) = world.get::<EditorNote>(entity).cloned() else {
+        return;
+    };
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        ui.label("Color:");
+        let srgba = note.color.to_srgba();
+        let mut color = [srgba.red, srgba.green, srgba.blue, srgba.alpha];
+        if ui.color_edit_button_rgba_unmultiplied(&mut color).changed() {
+            note.color = Color::srgba(color[0], color[1], color[2], color[3]);
+            changed = true;
+        }
+    });
+    changed |= ui
+        .add(
+            egui::TextEdit::multiline(&mut note.text)
+                .hint_text("Notes for other designers, e.g. TODOs")
+                .desired_rows(3)
+                .desired_width(f32::INFINITY),
+        )
+        .changed();
+    ui.weak("Editor-only: not loaded by the game.");
+
+    if changed {
+        if let Some(mut current) = world.get_mut::<EditorNote>(entity) {
+            *current = note;
+        }
+    }
+}
+
+/// Draws an icon at every noted entity in the viewport, with the text on hover.
+///
+/// Hidden while simulating, since notes don't exist at runtime.
+pub fn draw_note_icons(
+    painter: &egui::Painter,
+    viewport_rect: egui::Rect,
+    world: &mut World,
+    response: &egui::Response,
+) {
+    if *world.resource::<State<EditorPlayState>>().get() != EditorPlayState::Stopped {
+        return;
+    }
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return;
+    };
+
+    let mut hovered = None;
+    let mut notes = world.query::<(&EditorNote, &GlobalTransform)>();
+    for (note, transform) in notes.iter(world) {
+        let center = view.world_to_screen(transform.translation().truncate(), viewport_rect);
+        if !viewport_rect.contains(center) {
+            continue;
+        }
+        let color = note_color(note);
+        painter.circle(
+            center,
+            ICON_RADIUS,
+            egui::Color32::from_black_alpha(160),
+            egui::Stroke::new(1.5, color),
+        );
+        painter.text(
+            center,
+            egui::Align2::CENTER_CENTER,
+            NOTE_ICON,
+            egui::FontId::proportional(11.0),
+            color,
+        );
+        if response
+            .hover_pos()
+            .is_some_and(|pointer| pointer.distance(center) <= ICON_RADIUS)
+        {
+            hovered = Some((center, note.text.clone(), color));
+        }
+    }
+
+    // Text of the hovered note beside its icon
+    let Some((center, text, color)) = hovered else {
+        return;
+    };
+    let text = if text.trim().is_empty() {
+        "(empty note)".to_string()
+    } else {
+        text
+    };
+    let galley = painter.layout(
+        text,
+        egui::FontId::proportional(12.0),
+        egui::Color32::WHITE,
+        240.0,
+    );
+    let position = center + egui::vec2(ICON_RADIUS + 6.0, -galley.size().y / 2.0);
+    let background = egui::Rect::from_min_size(position, galley.size()).expand(4.0);
+    painter.rect(
+        background,
+        3.0,
+        egui::Color32::from_black_alpha(220),
+        egui::Stroke::new(1.0, color),
+    );
+    painter.galley(position, galley, egui::Color32::WHITE);
+}
@@ -0,0 +1,255 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Viewport entity picking against sprite bounds.
+//!
+//! Clicking selects the sprite under the pointer; dragging in empty space
+//! draws a selection rectangle that selects every sprite it touches. Bitmap
+//! text is picked by its glyph sprites.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::bitmap_font::BitmapGlyph;
+use sandbox_engine::scene::EditorLocked;
+
+use crate::editor_camera::ViewportCamera;
+use crate::gizmo::{gizmo_hit, GizmoDragState};
+use crate::selection::EditorSelection;
+use crate::ui::entity_picker::try_consume_pick;
+
+/// Returns the size of a sprite in local units, if it can be determined.
+///
+/// Uses `custom_size` when set, then the texture `rect`, then the loaded image size.
+pub fn sprite_size(world: &World, sprite: &Sprite) -> Option<Vec2> {
+    if let Some(size) = sprite.custom_size {
+        return Some(size);
+    }
+    if let Some(rect) = sprite.rect {
+        return Some(rect.size());
+    }
+    world
+        .resource::<Assets<Image>>()
+        .get(&sprite.image)
+        .map(|image| image.size_f32())
+}
+
+/// Returns the local-space rectangle covered by a sprite, accounting for its anchor.
+pub fn sprite_local_rect(world: &World, sprite: &Sprite) -> Option<Rect> {
+    let size = sprite_size(world, sprite)?;
+    let anchor = sprite.anchor.as_vec();
+    Some(Rect::from_corners(
+        -size * (Vec2::splat(0.5) + anchor),
+        size * (Vec2::splat(0.5) - anchor),
+    ))
+}
+
+/// Resource tracking an in-progress rubber-band selection.
+#[derive(Resource, Default)]
+pub struct RubberBandState {
+    /// Screen position where the drag started.
+    pub start: Option<egui::Pos2>,
+}
+
+/// Returns the world-space corners of a sprite, counter-clockwise from bottom-left.
+pub fn sprite_world_corners(
+    world: &World,
+    sprite: &Sprite,
+    transform: &GlobalTransform,
+) -> Option<[Vec2; 4]> {
+    let local_rect = sprite_local_rect(world, sprite)?;
+    Some(
+        [
+            local_rect.min,
+            Vec2::new(local_rect.max.x, local_rect.min.y),
+            local_rect.max,
+            Vec2::new(local_rect.min.x, local_rect.max.y),
+        ]
+        .map(|corner| transform.transform_point(corner.extend(0.0)).truncate()),
+    )
+}
+
+/// Returns the world-space bounding box of a sprite entity.
+fn sprite_world_bounds(
+    world: &World,
+    sprite: &Sprite,
+    transform: &GlobalTransform,
+) -> Option<Rect> {
+    let corners = sprite_world_corners(world, sprite, transform)?;
+    Some(corners[1..].iter().fold(
+        Rect::from_center_size(corners[0], Vec2::ZERO),
+        |bounds, &corner| bounds.union_point(corner),
+    ))
+}
+
+/// The entity selected by clicking `entity`'s sprite: bitmap text glyphs
+/// select their text, unless it is locked.
+fn pick_target(world: &World, entity: Entity) -> Option<Entity> {
+    if world.get::<BitmapGlyph>(entity).is_none() {
+        return Some(entity);
+    }
+    let text = world.get::<Parent>(entity)?.get();
+    world.get::<EditorLocked>(text).is_none().then_some(text)
+}
+
+/// Returns all unlocked sprite entities whose bounds intersect the given world rectangle.
+pub fn pick_entities_in_rect(world: &mut World, world_rect: Rect) -> Vec<Entity> {
+    let mut query =
+        world.query_filtered::<(Entity, &Sprite, &GlobalTransform), Without<EditorLocked>>();
+    let mut hits: Vec<Entity> = query
+        .iter(world)
+        .filter(|(_, sprite, transform)| {
+            sprite_world_bounds(world, sprite, transform)
+                .is_some_and(|bounds| !bounds.intersect(world_rect).is_empty())
+        })
+        .filter_map(|(entity, _, _)| pick_target(world, entity))
+        .collect();
+    hits.sort_by_key(|entity| entity.index());
+    hits.dedup();
+    hits
+}
+
+/// Returns all unlocked sprite entities whose bounds contain the given world position.
+///
+/// Results are ordered front to back (highest Z first).
+pub fn pick_entities_at(world: &mut World, world_pos: Vec2) -> Vec<Entity> {
+    let mut hits: Vec<(Entity, f32)> = Vec::new();
+
+    let mut query =
+        world.query_filtered::<(Entity, &Sprite, &GlobalTransform), Without<EditorLocked>>();
+    for (entity, sprite, global_transform) in query.iter(world) {
+        let Some(local_rect) = sprite_local_rect(world, sprite) else {
+            continue;
+        };
+
+        let affine = global_transform.affine();
+        if affine.matrix3.determinant() == 0.0 {
+            continue;
+        }
+
+        let z = global_transform.translation().z;
+        let local_pos = affine.inverse().transform_point3(world_pos.extend(z));
+        if local_rect.contains(local_pos.truncate()) {
+            hits.push((entity, z));
+        }
+    }
+
+    hits.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.index().cmp(&b.0.index())));
+    let mut picked: Vec<Entity> = Vec::new();
+    for (entity, _) in hits {
+        if let Some(entity) = pick_target(world, entity).filter(|e| !picked.contains(e)) {
+            picked.push(entity);
+        }
+    }
+    picked
+}
+
+/// Picks the entity under the pointer when the viewport is clicked.
+///
+/// Repeated clicks on overlapping sprites cycle through them; clicking empty
+/// space clears the selection and Ctrl+click toggles the topmost sprite in the
+/// selection. Clicks on the gizmo are ignored. While an entity reference
+/// eyedropper is active, the clicked entity is assigned to it.
+pub fn handle_viewport_click(
+    world: &mut World,
+    viewport_rect: egui::Rect,
+    response: &egui::Response,
+) {
+    if !response.clicked() {
+        return;
+    }
+    let Some(pointer) = response.interact_pointer_pos() else {
+        return;
+    };
+    if gizmo_hit(world, viewport_rect, pointer).is_some() {
+        return;
+    }
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return;
+    };
+    let world_pos = view.screen_to_world(pointer, viewport_rect);
+
+    let hits = pick_entities_at(world, world_pos);
+
+    // An active eyedropper takes the topmost hit instead of changing the selection
+    if let Some(&top) = hits.first() {
+        if try_consume_pick(world, top) {
+            return;
+        }
+    }
+
+    if response.ctx.input(|input| input.modifiers.ctrl) {
+        if let Some(&top) = hits.first() {
+            world.resource_mut::<EditorSelection>().toggle(top);
+        }
+        return;
+    }
+
+    let current = world.resource::<EditorSelection>().selected_entity;
+
+    // Select the entity after the current selection so repeated clicks cycle.
+    let next = match current.and_then(|entity| hits.iter().position(|&hit| hit == entity)) {
+        Some(index) => hits.get((index + 1) % hits.len()).copied(),
+        None => hits.first().copied(),
+    };
+
+    let mut selection = world.resource_mut::<EditorSelection>();
+    match next {
+        Some(entity) => selection.select(entity),
+        None => selection.clear(),
+    }
+}
+
+/// Handles rubber-band selection by dragging in empty viewport space.
+///
+/// On release, every sprite intersecting the rectangle is selected; with Ctrl
+/// held they are added to the current selection instead.
+pub fn handle_rubber_band(
+    painter: &egui::Painter,
+    world: &mut World,
+    viewport_rect: egui::Rect,
+    response: &egui::Response,
+) {
+    let gizmo_dragging = world.resource::<GizmoDragState>().dragging.is_some();
+
+    if response.drag_started_by(egui::PointerButton::Primary) && !gizmo_dragging {
+        world.resource_mut::<RubberBandState>().start = response.interact_pointer_pos();
+    }
+
+    let Some(start) = world.resource::<RubberBandState>().start else {
+        return;
+    };
+    let Some(current) = response.interact_pointer_pos().or(response.hover_pos()) else {
+        return;
+    };
+    let screen_rect = egui::Rect::from_two_pos(start, current);
+
+    painter.rect(
+        screen_rect,
+        0.0,
+        egui::Color32::from_rgba_unmultiplied(90, 160, 255, 30),
+        egui::Stroke::new(1.0, egui::Color32::from_rgb(90, 160, 255)),
+    );
+
+    if !response.drag_stopped() {
+        return;
+    }
+    world.resource_mut::<RubberBandState>().start = None;
+
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return;
+    };
+    let world_rect = Rect::from_corners(
+        view.screen_to_world(screen_rect.min, viewport_rect),
+        view.screen_to_world(screen_rect.max, viewport_rect),
+    );
+    let hits = pick_entities_in_rect(world, world_rect);
+
+    let additive = response.ctx.input(|input| input.modifiers.ctrl);
+    let mut selection = world.resource_mut::<EditorSelection>();
+    if additive {
+        let existing = selection.entities.clone();
+        selection.set(existing.into_iter().chain(hits));
+    } else {
+        selection.set(hits);
+    }
+}
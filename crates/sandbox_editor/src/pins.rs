@@ -0,0 +1,188 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Pinned inspector fields, whose play-mode edits survive Stop.
+//!
+//! Top-level fields of reflection-edited components have a 📌 toggle in the
+//! inspector. Edits to a pinned field while playing or paused are recorded,
+//! and once Stop has restored the scene the recorded values are written back
+//! and the scene is marked dirty, so values such as thrust or spawn rates can
+//! be tuned live without being lost.
+
+use bevy::prelude::*;
+use bevy::reflect::{PartialReflect, ReflectMut, ReflectRef};
+use bevy::utils::{HashMap, HashSet};
+use sandbox_engine::editor_state::{restore_snapshot, EditorPlayState};
+use sandbox_engine::scene::SceneManager;
+use std::any::TypeId;
+
+use crate::ui::file_menu::set_success_message;
+
+/// A top-level field of one entity's component.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FieldPin {
+    pub entity: Entity,
+    pub type_id: TypeId,
+    /// Field name, or index for tuple structs.
+    pub field: String,
+}
+
+/// Resource holding pinned fields and their values edited during play.
+#[derive(Resource, Default)]
+pub struct PinnedFields {
+    pins: HashSet<FieldPin>,
+    recorded: HashMap<FieldPin, Box<dyn PartialReflect>>,
+}
+
+impl PinnedFields {
+    /// Whether a field is pinned.
+    pub fn is_pinned(&self, pin: &FieldPin) -> bool {
+        self.pins.contains(pin)
+    }
+
+    /// Pins or unpins a field.
+    pub fn set_pinned(&mut self, pin: FieldPin, pinned: bool) {
+        if pinned {
+            self.pins.insert(pin);
+        } else {
+            self.recorded.remove(&pin);
+            self.pins.remove(&pin);
+        }
+    }
+
+    /// Names of the pinned fields of one entity's component.
+    pub fn pinned_fields(&self, entity: Entity, type_id: TypeId) -> HashSet<String> {
+        self.pins
+            .iter()
+            .filter(|pin| pin.entity == entity && pin.type_id == type_id)
+            .map(|pin| pin.field.clone())
+            .collect()
+    }
+
+    /// Number of values recorded during the current play session.
+    pub fn recorded_len(&self) -> usize {
+        self.recorded.len()
+    }
+}
+
+/// Plugin re-applying pinned values after Stop restores the scene.
+pub struct PinPlugin;
+
+impl Plugin for PinPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PinnedFields>()
+            .add_systems(
+                OnEnter(EditorPlayState::Stopped),
+                reapply_pinned_values.after(restore_snapshot),
+            )
+            .add_systems(OnEnter(EditorPlayState::Playing), clear_stale_pins);
+    }
+}
+
+/// Records pinned fields that differ between `before` and `after` while not stopped.
+///
+/// Called by the inspector with a component's value before and after an edit.
+pub(crate) fn record_pinned_edits(
+    world: &mut World,
+    entity: Entity,
+    type_id: TypeId,
+    before: &dyn PartialReflect,
+    after: &dyn PartialReflect,
+) {
+    if *world.resource::<State<EditorPlayState>>().get() == EditorPlayState::Stopped {
+        return;
+    }
+    let mut pinned = world.resource_mut::<PinnedFields>();
+    for field in pinned.pinned_fields(entity, type_id) {
+        let (Some(old), Some(new)) = (field_ref(before, &field), field_ref(after, &field)) else {
+            continue;
+        };
+        if old.reflect_partial_eq(new) != Some(true) {
+            let pin = FieldPin {
+                entity,
+                type_id,
+                field,
+            };
+            pinned.recorded.insert(pin, new.clone_value());
+        }
+    }
+}
+
+/// Writes the values recorded during play back into the restored scene.
+fn reapply_pinned_values(world: &mut World) {
+    let recorded = std::mem::take(&mut world.resource_mut::<PinnedFields>().recorded);
+    if recorded.is_empty() {
+        return;
+    }
+
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let mut applied = 0;
+    for (pin, value) in recorded {
+        let Some(reflect_component) = type_registry
+            .get(pin.type_id)
+            .and_then(|registration| registration.data::<ReflectComponent>())
+        else {
+            continue;
+        };
+        let Some(mut component) = world
+            .get_entity_mut(pin.entity)
+            .ok()
+            .and_then(|entity| reflect_component.reflect_mut(entity))
+        else {
+            continue;
+        };
+        if let Some(field) = field_mut(component.as_partial_reflect_mut(), &pin.field) {
+            if field.try_apply(value.as_ref()).is_ok() {
+                applied += 1;
+            }
+        }
+    }
+    drop(type_registry);
+
+    if applied > 0 {
+        if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+            manager.mark_dirty();
+        }
+        let noun = if applied == 1 { "value" } else { "values" };
+        set_success_message(world, &format!("Kept {} pinned {}", applied, noun));
+    }
+}
+
+/// Drops pins on entities that no longer exist.
+fn clear_stale_pins(world: &mut World) {
+    let pins: Vec<FieldPin> = world
+        .resource::<PinnedFields>()
+        .pins
+        .iter()
+        .cloned()
+        .collect();
+    let stale: Vec<FieldPin> = pins
+        .into_iter()
+        .filter(|pin| world.get_entity(pin.entity).is_err())
+        .collect();
+    let mut pinned = world.resource_mut::<PinnedFields>();
+    for pin in stale {
+        pinned.set_pinned(pin, false);
+    }
+}
+
+/// A top-level field of a struct or tuple struct, by name or index.
+fn field_ref<'a>(value: &'a dyn PartialReflect, field: &str) -> Option<&'a dyn PartialReflect> {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => value.field(field),
+        ReflectRef::TupleStruct(value) => value.field(field.parse().ok()?),
+        _ => None,
+    }
+}
+
+fn field_mut<'a>(
+    value: &'a mut dyn PartialReflect,
+    field: &str,
+) -> Option<&'a mut dyn PartialReflect> {
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => value.field_mut(field),
+        ReflectMut::TupleStruct(value) => value.field_mut(field.parse().ok()?),
+        _ => None,
+    }
+}
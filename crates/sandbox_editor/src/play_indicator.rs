@@ -0,0 +1,114 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Makes play mode unmistakable.
+//!
+//! While the game runs the viewport gets a green border and badge (orange
+//! while paused), and the window title gains a `[PLAYING]` or `[PAUSED]`
+//! suffix. Optionally the hierarchy, inspector, and asset panels are dimmed,
+//! since the scene is restored on Stop unless runtime changes are kept.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_egui::egui;
+use sandbox_engine::editor_state::EditorPlayState;
+
+/// Border and badge colors for each simulating state.
+const COLOR_PLAYING: egui::Color32 = egui::Color32::from_rgb(70, 200, 90);
+const COLOR_PAUSED: egui::Color32 = egui::Color32::from_rgb(240, 150, 40);
+
+/// Width of the viewport border, in points.
+const BORDER_WIDTH: f32 = 3.0;
+
+/// Opacity of dimmed panels.
+const DIMMED_OPACITY: f32 = 0.5;
+
+/// Resource controlling the play-mode indicator.
+#[derive(Resource, Default)]
+pub struct PlayIndicatorSettings {
+    /// Whether panels whose edits don't survive Stop are dimmed during play.
+    pub dim_panels: bool,
+}
+
+/// Plugin keeping the window title in sync with the play state.
+pub struct PlayIndicatorPlugin;
+
+impl Plugin for PlayIndicatorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayIndicatorSettings>().add_systems(
+            Update,
+            update_window_title.run_if(state_changed::<EditorPlayState>),
+        );
+    }
+}
+
+/// Title suffix and indicator color for a play state; `None` when stopped.
+fn indicator(state: EditorPlayState) -> Option<(&'static str, egui::Color32)> {
+    match state {
+        EditorPlayState::Stopped => None,
+        EditorPlayState::Playing => Some(("PLAYING", COLOR_PLAYING)),
+        EditorPlayState::Paused => Some(("PAUSED", COLOR_PAUSED)),
+    }
+}
+
+/// Appends the play state to the primary window's title, e.g. "Sandbox Editor [PLAYING]".
+fn update_window_title(
+    state: Res<State<EditorPlayState>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    for mut window in &mut windows {
+        let base = window
+            .title
+            .trim_end_matches(" [PLAYING]")
+            .trim_end_matches(" [PAUSED]")
+            .to_string();
+        window.title = match indicator(*state.get()) {
+            Some((label, _)) => format!("{} [{}]", base, label),
+            None => base,
+        };
+    }
+}
+
+/// Outlines the viewport and shows a badge while the game is playing or paused.
+pub fn draw_play_border(painter: &egui::Painter, viewport_rect: egui::Rect, world: &World) {
+    let Some((label, color)) = indicator(*world.resource::<State<EditorPlayState>>().get()) else {
+        return;
+    };
+
+    painter.rect_stroke(
+        viewport_rect.shrink(BORDER_WIDTH / 2.0),
+        0.0,
+        egui::Stroke::new(BORDER_WIDTH, color),
+    );
+
+    let icon = if label == "PLAYING" { "▶" } else { "⏸" };
+    let galley = painter.layout_no_wrap(
+        format!("{} {}", icon, label),
+        egui::FontId::proportional(13.0),
+        egui::Color32::BLACK,
+    );
+    let badge = egui::Rect::from_min_size(
+        egui::pos2(
+            viewport_rect.center().x - galley.size().x / 2.0 - 6.0,
+            viewport_rect.top(),
+        ),
+        galley.size() + egui::vec2(12.0, 4.0),
+    );
+    painter.rect_filled(badge, egui::Rounding::same(3.0), color);
+    painter.galley(
+        badge.min + egui::vec2(6.0, 2.0),
+        galley,
+        egui::Color32::BLACK,
+    );
+}
+
+/// Dims `ui` during play if [`PlayIndicatorSettings::dim_panels`] is on.
+///
+/// The panel stays usable; dimming only signals that play-mode edits are temporary.
+pub fn dim_during_play(ui: &mut egui::Ui, world: &World) {
+    if world.resource::<PlayIndicatorSettings>().dim_panels
+        && *world.resource::<State<EditorPlayState>>().get() != EditorPlayState::Stopped
+    {
+        ui.multiply_opacity(DIMMED_OPACITY);
+    }
+}
@@ -0,0 +1,217 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Named component presets stored in the project.
+//!
+//! A preset is a reflected component value saved as RON at
+//! `presets/<type directory>/<preset name>.ron` under the project root. The
+//! type directory is the component's type path with every character other
+//! than a letter, digit, or `_` replaced by `_` (see [`preset_dir_name`]), so
+//! paths like `my_game::Status<my_game::Burning>` make valid folder names on
+//! every platform.
+
+use bevy::prelude::*;
+use bevy::reflect::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use bevy::scene::ron;
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::SceneManager;
+use serde::de::DeserializeSeed;
+use std::any::TypeId;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::undo::{record_component_edit, snapshot_components};
+
+/// Resource listing the presets available in the project.
+#[derive(Resource, Default)]
+pub struct ComponentPresets {
+    /// Directory presets are stored in.
+    pub directory: PathBuf,
+    /// Preset names by type directory name.
+    pub presets: BTreeMap<String, Vec<String>>,
+    /// Name typed into the "save preset" field.
+    pub new_preset_name: String,
+}
+
+impl ComponentPresets {
+    /// Creates the preset list for a directory and scans it.
+    pub fn new(directory: PathBuf) -> Self {
+        let mut presets = Self {
+            directory,
+            ..default()
+        };
+        presets.rescan();
+        presets
+    }
+
+    /// Rescans the preset directory.
+    pub fn rescan(&mut self) {
+        self.presets.clear();
+
+        let Ok(type_dirs) = std::fs::read_dir(&self.directory) else {
+            return;
+        };
+        for type_dir in type_dirs.filter_map(|entry| entry.ok()) {
+            let dir_name = type_dir.file_name().to_string_lossy().to_string();
+            let Ok(files) = std::fs::read_dir(type_dir.path()) else {
+                continue;
+            };
+
+            let mut names: Vec<String> = files
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    entry
+                        .file_name()
+                        .to_string_lossy()
+                        .strip_suffix(".ron")
+                        .map(str::to_string)
+                })
+                .collect();
+            names.sort();
+
+            if !names.is_empty() {
+                self.presets.insert(dir_name, names);
+            }
+        }
+    }
+
+    /// Returns the preset names for a component type.
+    pub fn names_for(&self, type_path: &str) -> &[String] {
+        self.presets
+            .get(&preset_dir_name(type_path))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    fn preset_path(&self, type_path: &str, name: &str) -> PathBuf {
+        self.directory
+            .join(preset_dir_name(type_path))
+            .join(format!("{}.ron", name))
+    }
+}
+
+/// Name of the directory holding a component type's presets: the type path
+/// with characters other than ASCII letters, digits, and `_` replaced by `_`.
+pub fn preset_dir_name(type_path: &str) -> String {
+    type_path
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Plugin that sets up component presets.
+pub struct PresetPlugin;
+
+impl Plugin for PresetPlugin {
+    fn build(&self, app: &mut App) {
+        let directory = app
+            .world()
+            .get_resource::<ProjectRoot>()
+            .cloned()
+            .unwrap_or_else(ProjectRoot::discover)
+            .resolve("presets");
+        app.insert_resource(ComponentPresets::new(directory));
+    }
+}
+
+/// Returns the type path of a reflect-registered component type.
+pub fn component_type_path(world: &World, type_id: TypeId) -> Option<String> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    type_registry
+        .get(type_id)
+        .filter(|registration| registration.data::<ReflectComponent>().is_some())
+        .map(|registration| registration.type_info().type_path().to_string())
+}
+
+/// Saves an entity's component as a named preset.
+pub fn save_preset(
+    world: &mut World,
+    entity: Entity,
+    type_id: TypeId,
+    name: &str,
+) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() || name.contains(['/', '\\']) {
+        return Err("Preset names must be non-empty and cannot contain slashes".to_string());
+    }
+
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+
+    let registration = type_registry
+        .get(type_id)
+        .ok_or("Component type is not registered for reflection")?;
+    let reflect_component = registration
+        .data::<ReflectComponent>()
+        .ok_or("Component type does not reflect Component")?;
+    let value = reflect_component
+        .reflect(world.entity(entity))
+        .ok_or("Entity does not have this component")?;
+
+    let serializer = TypedReflectSerializer::new(value.as_partial_reflect(), &type_registry);
+    let serialized = ron::ser::to_string_pretty(&serializer, ron::ser::PrettyConfig::default())
+        .map_err(|e| format!("Failed to serialize component: {}", e))?;
+
+    let type_path = registration.type_info().type_path().to_string();
+    let mut presets = world.resource_mut::<ComponentPresets>();
+    let path = presets.preset_path(&type_path, name);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(&path, serialized).map_err(|e| e.to_string())?;
+
+    presets.rescan();
+    Ok(())
+}
+
+/// Applies a named preset to an entity's component as one undo step.
+pub fn apply_preset(
+    world: &mut World,
+    entity: Entity,
+    type_id: TypeId,
+    name: &str,
+) -> Result<(), String> {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+
+    let registration = type_registry
+        .get(type_id)
+        .ok_or("Component type is not registered for reflection")?;
+    let reflect_component = registration
+        .data::<ReflectComponent>()
+        .ok_or("Component type does not reflect Component")?;
+
+    let path = world
+        .resource::<ComponentPresets>()
+        .preset_path(registration.type_info().type_path(), name);
+    let data = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+
+    let mut deserializer = ron::de::Deserializer::from_str(&data).map_err(|e| e.to_string())?;
+    let value = TypedReflectDeserializer::new(registration, &type_registry)
+        .deserialize(&mut deserializer)
+        .map_err(|e| format!("Failed to read preset: {}", e))?;
+
+    let before = snapshot_components(world, &[entity], type_id);
+    reflect_component.apply(world.entity_mut(entity), value.as_ref());
+    record_component_edit(world, format!("Apply Preset '{}'", name), type_id, before);
+
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
+    }
+    Ok(())
+}
+
+/// Deletes a named preset.
+pub fn delete_preset(world: &mut World, type_path: &str, name: &str) -> Result<(), String> {
+    let mut presets = world.resource_mut::<ComponentPresets>();
+    let path = presets.preset_path(type_path, name);
+    std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+    presets.rescan();
+    Ok(())
+}
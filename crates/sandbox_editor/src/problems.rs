@@ -0,0 +1,195 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Finds asset references that point at missing files.
+//!
+//! Every `AssetPath` in the open scene is checked against the assets
+//! directory, and so is every `AssetPath` in the scene files (`.scn.ron` and `.scn.json`)
+//! under it. The file of the open scene is skipped, since its live entities
+//! are checked instead. A sprite whose path has a typo otherwise just renders
+//! blank.
+//!
+//! Paths from other asset sources (`embedded://...`) are not checked.
+
+use bevy::prelude::*;
+use bevy::reflect::FromReflect;
+use sandbox_engine::assets::AssetPath;
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::{parse_scene, SceneFormat, SceneManager};
+use std::any::TypeId;
+use std::path::Path;
+
+use crate::unused_assets::{collect_files, normalize_reference};
+
+/// What is wrong.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProblemKind {
+    /// An `AssetPath` names a file that doesn't exist.
+    MissingAsset(String),
+    /// A scene file could not be read or parsed, so it wasn't checked.
+    UnreadableScene(String),
+}
+
+/// Where a problem was found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProblemLocation {
+    /// An entity in the open scene.
+    Entity(Entity),
+    /// A scene file on disk, relative to the assets directory, and the
+    /// offending entity's name, if the problem is with one entity.
+    SceneFile {
+        file: String,
+        entity: Option<String>,
+    },
+}
+
+/// A problem found by [`find_problems`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Problem {
+    pub kind: ProblemKind,
+    pub location: ProblemLocation,
+}
+
+impl Problem {
+    /// One-line description, without the location.
+    pub fn message(&self) -> String {
+        match &self.kind {
+            ProblemKind::MissingAsset(path) => format!("Missing asset '{}'", path),
+            ProblemKind::UnreadableScene(error) => format!("Scene can't be checked: {}", error),
+        }
+    }
+}
+
+/// Checks the open scene and the scene files under the assets directory.
+///
+/// Problems in the open scene come first, then unreadable scene files, then
+/// missing assets in scene files sorted by path.
+pub fn find_problems(world: &mut World) -> Vec<Problem> {
+    let Some(assets_dir) = world
+        .get_resource::<ProjectRoot>()
+        .map(ProjectRoot::assets_dir)
+    else {
+        return Vec::new();
+    };
+
+    let mut problems = Vec::new();
+    let mut query = world.query::<(Entity, &AssetPath)>();
+    let mut live: Vec<(Entity, String)> = query
+        .iter(world)
+        .filter(|(_, asset_path)| is_missing(&assets_dir, &asset_path.path))
+        .map(|(entity, asset_path)| (entity, asset_path.path.clone()))
+        .collect();
+    live.sort_by_key(|(entity, _)| entity.index());
+    problems.extend(live.into_iter().map(|(entity, path)| Problem {
+        kind: ProblemKind::MissingAsset(path),
+        location: ProblemLocation::Entity(entity),
+    }));
+
+    let (references, unreadable) = scene_file_references(world, &assets_dir);
+    for (file, error) in unreadable {
+        problems.push(Problem {
+            kind: ProblemKind::UnreadableScene(error),
+            location: ProblemLocation::SceneFile { file, entity: None },
+        });
+    }
+    for reference in references {
+        if is_missing(&assets_dir, &reference.path) {
+            problems.push(Problem {
+                kind: ProblemKind::MissingAsset(reference.path),
+                location: ProblemLocation::SceneFile {
+                    file: reference.file,
+                    entity: Some(reference.entity),
+                },
+            });
+        }
+    }
+    problems
+}
+
+/// An `AssetPath` in a scene file on disk.
+pub(crate) struct SceneReference {
+    /// Scene file, relative to the assets directory.
+    pub file: String,
+    /// Name of the entity holding the `AssetPath`, or "Entity N".
+    pub entity: String,
+    /// The referenced path, as written.
+    pub path: String,
+}
+
+/// Scene files under `assets_dir` other than the open scene, relative and sorted.
+///
+/// The open scene is skipped because its entities are live in the world.
+pub(crate) fn scene_files(world: &World, assets_dir: &Path) -> Vec<String> {
+    let open_scene = world
+        .get_resource::<SceneManager>()
+        .and_then(|manager| manager.current_scene_path.clone());
+    let mut files = Vec::new();
+    collect_files(assets_dir, "", &mut files);
+    let mut scenes: Vec<String> = files
+        .into_iter()
+        .map(|(relative, _)| relative)
+        .filter(|relative| SceneFormat::from_file_name(relative).is_some())
+        .filter(|relative| open_scene.as_deref() != Some(assets_dir.join(relative).as_path()))
+        .collect();
+    scenes.sort();
+    scenes
+}
+
+/// Reads the `AssetPath`s of every scene file under `assets_dir`, in path
+/// order, skipping the open scene.
+///
+/// Also returns the files that couldn't be read or parsed, with the error.
+pub(crate) fn scene_file_references(
+    world: &World,
+    assets_dir: &Path,
+) -> (Vec<SceneReference>, Vec<(String, String)>) {
+    let mut references = Vec::new();
+    let mut unreadable = Vec::new();
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    for file in scene_files(world, assets_dir) {
+        let scene = std::fs::read_to_string(assets_dir.join(&file))
+            .map_err(|e| e.to_string())
+            .and_then(|data| parse_scene(&data, &type_registry).map_err(|e| e.to_string()));
+        let scene = match scene {
+            Ok(scene) => scene,
+            Err(error) => {
+                unreadable.push((file, error));
+                continue;
+            }
+        };
+
+        for entity in &scene.entities {
+            let name = entity
+                .components
+                .iter()
+                .find_map(|component| Name::from_reflect(component.as_ref()))
+                .map_or_else(
+                    || format!("Entity {}", entity.entity.index()),
+                    |name| name.to_string(),
+                );
+            for component in &entity.components {
+                // Struct conversion goes by field names, so check the type first
+                let is_asset_path = component
+                    .get_represented_type_info()
+                    .is_some_and(|info| info.type_id() == TypeId::of::<AssetPath>());
+                if let Some(asset_path) = is_asset_path
+                    .then(|| AssetPath::from_reflect(component.as_ref()))
+                    .flatten()
+                {
+                    references.push(SceneReference {
+                        file: file.clone(),
+                        entity: name.clone(),
+                        path: asset_path.path,
+                    });
+                }
+            }
+        }
+    }
+    (references, unreadable)
+}
+
+/// Whether `path` should name a file under `assets_dir` but doesn't.
+fn is_missing(assets_dir: &Path, path: &str) -> bool {
+    let path = normalize_reference(path);
+    !path.is_empty() && !path.contains("://") && !assets_dir.join(path).is_file()
+}
@@ -0,0 +1,280 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Compares the open scene with its file on disk.
+//!
+//! The world is extracted exactly as a save would write it and compared with
+//! the parsed scene file: entities are matched by their [`SceneEntityId`]
+//! key, and components and scene resources by type, using their RON form.
+//! Anything that wouldn't change the file on Ctrl+S isn't a difference.
+
+use bevy::prelude::*;
+use bevy::reflect::serde::TypedReflectSerializer;
+use bevy::reflect::{FromReflect, TypeRegistry};
+use bevy::scene::{ron, DynamicEntity};
+use sandbox_engine::scene::{
+    extract_scene, parse_scene, scene_entities, SceneEntityId, SceneError, SceneManager,
+    SceneResult,
+};
+use std::collections::{BTreeMap, HashMap};
+
+/// How an entity, component, or resource differs from the file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change {
+    /// Only in the open scene.
+    Added,
+    /// Only in the file.
+    Removed,
+    /// In both, with different values.
+    Modified,
+}
+
+/// A component or resource that differs from the file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValueDiff {
+    /// Full type path, as written in the file.
+    pub type_path: String,
+    /// Short type name for display.
+    pub name: String,
+    /// Pretty RON in the file, if it has the value.
+    pub before: Option<String>,
+    /// Pretty RON in the open scene, if it has the value.
+    pub after: Option<String>,
+}
+
+impl ValueDiff {
+    /// Whether the value was added, removed, or changed.
+    pub fn change(&self) -> Change {
+        match (&self.before, &self.after) {
+            (None, _) => Change::Added,
+            (_, None) => Change::Removed,
+            _ => Change::Modified,
+        }
+    }
+}
+
+/// An entity that differs from the file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntityDiff {
+    /// The entity's key in the file.
+    pub key: u32,
+    /// The live entity, unless it was removed.
+    pub entity: Option<Entity>,
+    /// The entity's `Name` (from the open scene if it has one), or "Entity N".
+    pub name: String,
+    pub change: Change,
+    /// Differing components, in type path order; every component for added
+    /// and removed entities.
+    pub components: Vec<ValueDiff>,
+}
+
+/// Differences between the open scene and its file, from [`diff_scene_with_file`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SceneDiff {
+    /// Differing entities, in key order.
+    pub entities: Vec<EntityDiff>,
+    /// Differing scene resources, in type path order.
+    pub resources: Vec<ValueDiff>,
+}
+
+impl SceneDiff {
+    /// Whether saving would leave the file as it is.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty() && self.resources.is_empty()
+    }
+
+    /// Number of entities with the given change.
+    pub fn entity_count(&self, change: Change) -> usize {
+        self.entities
+            .iter()
+            .filter(|entity| entity.change == change)
+            .count()
+    }
+}
+
+/// A line of a [`diff_lines`] result.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Compares the open scene with the file at `SceneManager::current_scene_path`.
+///
+/// A scene that was never saved is compared with an empty file, so
+/// everything in it is added. Extracting the scene gives new entities their
+/// keys, as saving would.
+pub fn diff_scene_with_file(world: &mut World) -> SceneResult<SceneDiff> {
+    let path = world
+        .get_resource::<SceneManager>()
+        .and_then(|manager| manager.current_scene_path.clone());
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let saved = match path {
+        Some(path) => {
+            let data = std::fs::read_to_string(path).map_err(SceneError::FileRead)?;
+            parse_scene(&data, &type_registry)?
+        }
+        None => DynamicScene::default(),
+    };
+    let current = extract_scene(world);
+
+    let live: HashMap<u32, Entity> = scene_entities(world)
+        .into_iter()
+        .filter_map(|entity| Some((world.get::<SceneEntityId>(entity)?.0, entity)))
+        .collect();
+    let mut diff = diff_scenes(&saved, &current, &type_registry);
+    for entity in &mut diff.entities {
+        if entity.change != Change::Removed {
+            entity.entity = live.get(&entity.key).copied();
+        }
+    }
+    Ok(diff)
+}
+
+/// Compares two scenes whose entities are keyed as in a scene file.
+///
+/// [`EntityDiff::entity`] is left empty, since neither scene is live.
+pub fn diff_scenes(
+    saved: &DynamicScene,
+    current: &DynamicScene,
+    type_registry: &TypeRegistry,
+) -> SceneDiff {
+    let saved_entities: BTreeMap<u32, &DynamicEntity> = saved
+        .entities
+        .iter()
+        .map(|entity| (entity.entity.index(), entity))
+        .collect();
+    let current_entities: BTreeMap<u32, &DynamicEntity> = current
+        .entities
+        .iter()
+        .map(|entity| (entity.entity.index(), entity))
+        .collect();
+
+    let mut keys: Vec<u32> = saved_entities
+        .keys()
+        .chain(current_entities.keys())
+        .copied()
+        .collect();
+    keys.sort();
+    keys.dedup();
+
+    let mut entities = Vec::new();
+    for key in keys {
+        let before = saved_entities.get(&key);
+        let after = current_entities.get(&key);
+        let change = match (before, after) {
+            (None, _) => Change::Added,
+            (_, None) => Change::Removed,
+            _ => Change::Modified,
+        };
+        let components = diff_values(
+            before.map_or(&[][..], |entity| &entity.components),
+            after.map_or(&[][..], |entity| &entity.components),
+            type_registry,
+        );
+        if components.is_empty() {
+            continue;
+        }
+        let name = after
+            .into_iter()
+            .chain(before)
+            .flat_map(|entity| &entity.components)
+            .filter(|component| component.represents::<Name>())
+            .find_map(|component| Name::from_reflect(component.as_ref()))
+            .map_or_else(|| format!("Entity {}", key), |name| name.to_string());
+        entities.push(EntityDiff {
+            key,
+            entity: None,
+            name,
+            change,
+            components,
+        });
+    }
+
+    SceneDiff {
+        entities,
+        resources: diff_values(&saved.resources, &current.resources, type_registry),
+    }
+}
+
+/// Compares two sets of reflected values by type, in type path order.
+fn diff_values(
+    before: &[Box<dyn PartialReflect>],
+    after: &[Box<dyn PartialReflect>],
+    type_registry: &TypeRegistry,
+) -> Vec<ValueDiff> {
+    let mut values: BTreeMap<String, ValueDiff> = BTreeMap::new();
+    for (value, is_before) in before
+        .iter()
+        .map(|value| (value, true))
+        .chain(after.iter().map(|value| (value, false)))
+    {
+        let Some(type_info) = value.get_represented_type_info() else {
+            continue;
+        };
+        let type_path = type_info.type_path().to_string();
+        let entry = values
+            .entry(type_path.clone())
+            .or_insert_with(|| ValueDiff {
+                type_path,
+                name: type_info.type_path_table().short_path().to_string(),
+                before: None,
+                after: None,
+            });
+        let serialized = to_ron(value.as_ref(), type_registry);
+        if is_before {
+            entry.before = Some(serialized);
+        } else {
+            entry.after = Some(serialized);
+        }
+    }
+    values
+        .into_values()
+        .filter(|value| value.before != value.after)
+        .collect()
+}
+
+/// Serializes a reflected value to pretty RON, or describes why it can't be.
+fn to_ron(value: &dyn PartialReflect, type_registry: &TypeRegistry) -> String {
+    let serializer = TypedReflectSerializer::new(value, type_registry);
+    ron::ser::to_string_pretty(&serializer, ron::ser::PrettyConfig::default())
+        .unwrap_or_else(|e| format!("<not serializable: {}>", e))
+}
+
+/// Line-by-line difference between two texts, keeping the longest run of
+/// common lines.
+pub fn diff_lines<'a>(before: &'a str, after: &'a str) -> Vec<DiffLine<'a>> {
+    let before: Vec<&str> = before.lines().collect();
+    let after: Vec<&str> = after.lines().collect();
+
+    // common[i][j]: common lines of before[i..] and after[j..]
+    let mut common = vec![vec![0usize; after.len() + 1]; before.len() + 1];
+    for i in (0..before.len()).rev() {
+        for j in (0..after.len()).rev() {
+            common[i][j] = if before[i] == after[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < before.len() || j < after.len() {
+        if i < before.len() && j < after.len() && before[i] == after[j] {
+            lines.push(DiffLine::Same(before[i]));
+            i += 1;
+            j += 1;
+        } else if i < before.len() && (j == after.len() || common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(DiffLine::Removed(before[i]));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(after[j]));
+            j += 1;
+        }
+    }
+    lines
+}
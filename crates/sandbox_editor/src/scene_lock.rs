@@ -0,0 +1,255 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Scene lockfiles for multi-user awareness.
+//!
+//! While a scene is open, the editor keeps a `.lock` sidecar next to it that
+//! records who has it open. Opening a scene locked by someone else warns
+//! first; locks that haven't been refreshed recently are considered stale and
+//! can be taken over.
+
+use bevy::prelude::*;
+use sandbox_engine::scene::SceneManager;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// How often the held lock's timestamp is refreshed, in seconds.
+const LOCK_REFRESH_SECS: f32 = 60.0;
+
+/// Locks not refreshed for this many seconds are considered stale.
+const LOCK_STALE_SECS: u64 = 5 * 60;
+
+/// Contents of a scene lockfile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockInfo {
+    /// Name of the user holding the lock.
+    pub user: String,
+    /// Host the lock was taken on.
+    pub host: String,
+    /// Process ID of the editor holding the lock.
+    pub pid: u32,
+    /// Last refresh time, in seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+impl LockInfo {
+    /// Creates lock info for this editor process.
+    pub fn current() -> Self {
+        Self {
+            user: current_user(),
+            host: current_host(),
+            pid: std::process::id(),
+            timestamp: unix_now(),
+        }
+    }
+
+    /// Whether this lock was taken by this editor process.
+    pub fn is_ours(&self) -> bool {
+        self.pid == std::process::id() && self.user == current_user() && self.host == current_host()
+    }
+
+    /// Seconds since the lock was last refreshed.
+    pub fn age_secs(&self) -> u64 {
+        unix_now().saturating_sub(self.timestamp)
+    }
+
+    /// Whether the lock has not been refreshed recently.
+    pub fn is_stale(&self) -> bool {
+        self.age_secs() > LOCK_STALE_SECS
+    }
+
+    /// Returns a human-readable description of the lock holder.
+    pub fn describe(&self) -> String {
+        format!(
+            "{}@{} (pid {}), last seen {} ago",
+            self.user,
+            self.host,
+            self.pid,
+            format_age(self.age_secs())
+        )
+    }
+
+    fn to_file_contents(&self) -> String {
+        format!(
+            "user={}\nhost={}\npid={}\ntimestamp={}\n",
+            self.user, self.host, self.pid, self.timestamp
+        )
+    }
+
+    fn from_file_contents(contents: &str) -> Option<Self> {
+        let mut user = None;
+        let mut host = None;
+        let mut pid = None;
+        let mut timestamp = None;
+
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            match key.trim() {
+                "user" => user = Some(value.trim().to_string()),
+                "host" => host = Some(value.trim().to_string()),
+                "pid" => pid = value.trim().parse().ok(),
+                "timestamp" => timestamp = value.trim().parse().ok(),
+                _ => {}
+            }
+        }
+
+        Some(Self {
+            user: user?,
+            host: host?,
+            pid: pid?,
+            timestamp: timestamp?,
+        })
+    }
+}
+
+/// Returns the lockfile path for a scene file.
+pub fn lock_path(scene_path: &Path) -> PathBuf {
+    let mut file_name = scene_path.as_os_str().to_os_string();
+    file_name.push(".lock");
+    PathBuf::from(file_name)
+}
+
+/// Reads the lock for a scene, if one exists and is readable.
+pub fn read_lock(scene_path: &Path) -> Option<LockInfo> {
+    let contents = std::fs::read_to_string(lock_path(scene_path)).ok()?;
+    LockInfo::from_file_contents(&contents)
+}
+
+/// Writes (or refreshes) this editor's lock for a scene.
+pub fn write_lock(scene_path: &Path) -> std::io::Result<()> {
+    std::fs::write(
+        lock_path(scene_path),
+        LockInfo::current().to_file_contents(),
+    )
+}
+
+/// Removes the lock for a scene if this editor holds it.
+pub fn release_lock(scene_path: &Path) {
+    if read_lock(scene_path).is_some_and(|lock| lock.is_ours()) {
+        let _ = std::fs::remove_file(lock_path(scene_path));
+    }
+}
+
+/// Returns the lock held by someone else on a scene, if any.
+pub fn foreign_lock(scene_path: &Path) -> Option<LockInfo> {
+    read_lock(scene_path).filter(|lock| !lock.is_ours())
+}
+
+/// A pending warning about opening a scene locked by someone else.
+#[derive(Clone, Debug)]
+pub struct LockPrompt {
+    /// Scene the user tried to open.
+    pub scene_path: PathBuf,
+    /// The lock found on that scene.
+    pub lock: LockInfo,
+}
+
+/// Resource tracking the lock this editor holds.
+#[derive(Resource, Default)]
+pub struct SceneLockState {
+    /// Scene whose lock this editor currently holds.
+    pub held: Option<PathBuf>,
+    /// Scene path last seen on the `SceneManager`.
+    pub observed: Option<PathBuf>,
+    /// Scene opened despite a foreign lock, if any.
+    pub foreign: Option<LockInfo>,
+    /// Lock warning waiting for the user's decision.
+    pub prompt: Option<LockPrompt>,
+    /// Seconds since the held lock was last refreshed.
+    pub since_refresh: f32,
+}
+
+impl Drop for SceneLockState {
+    fn drop(&mut self) {
+        if let Some(path) = self.held.take() {
+            release_lock(&path);
+        }
+    }
+}
+
+/// Plugin that sets up scene lock tracking.
+pub struct SceneLockPlugin;
+
+impl Plugin for SceneLockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SceneLockState>()
+            .add_systems(Update, sync_scene_lock);
+    }
+}
+
+/// Acquires, releases, and refreshes the lock as the current scene changes.
+fn sync_scene_lock(
+    time: Res<Time<Real>>,
+    manager: Res<SceneManager>,
+    mut state: ResMut<SceneLockState>,
+) {
+    if manager.current_scene_path != state.observed {
+        if let Some(previous) = state.held.take() {
+            release_lock(&previous);
+        }
+        state.observed = manager.current_scene_path.clone();
+        state.foreign = None;
+        state.since_refresh = 0.0;
+
+        if let Some(path) = state.observed.clone() {
+            match foreign_lock(&path) {
+                Some(lock) if !lock.is_stale() => {
+                    warn!("Scene {} is locked by {}", path.display(), lock.describe());
+                    state.foreign = Some(lock);
+                }
+                _ => {
+                    if let Err(e) = write_lock(&path) {
+                        warn!("Failed to lock scene {}: {}", path.display(), e);
+                    } else {
+                        state.held = Some(path);
+                    }
+                }
+            }
+        }
+        return;
+    }
+
+    state.since_refresh += time.delta_secs();
+    if state.since_refresh >= LOCK_REFRESH_SECS {
+        state.since_refresh = 0.0;
+        if let Some(path) = &state.held {
+            let _ = write_lock(path);
+        }
+    }
+}
+
+fn current_user() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+fn current_host() -> String {
+    std::env::var("HOSTNAME")
+        .or_else(|_| std::env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| {
+            std::fs::read_to_string("/etc/hostname")
+                .ok()
+                .map(|name| name.trim().to_string())
+        })
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn format_age(secs: u64) -> String {
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        _ => format!("{}h", secs / 3600),
+    }
+}
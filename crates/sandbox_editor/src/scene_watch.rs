@@ -0,0 +1,153 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Notices when the open scene's file is changed outside the editor.
+//!
+//! The scene's folder is watched (editors and git often replace files rather
+//! than write them in place). A change is only reported once the file has
+//! been quiet for a moment and its contents differ from what the editor last
+//! loaded or saved, so the editor's own saves never count. The File menu's
+//! reload prompt, or `SceneManager::auto_reload`, acts on it.
+
+use bevy::prelude::*;
+use notify::{EventKind, RecursiveMode, Watcher};
+use sandbox_engine::scene::SceneManager;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How long the file must be quiet before a change is reported.
+const SETTLE_DELAY: Duration = Duration::from_millis(200);
+
+/// Resource tracking the open scene's file on disk.
+#[derive(Resource, Default)]
+pub struct SceneWatchState {
+    /// Scene file being watched.
+    observed: Option<PathBuf>,
+    watcher: Option<SceneFileWatcher>,
+    /// Hash of the contents the editor last loaded or saved.
+    known: Option<u64>,
+    /// When the last event for the file arrived, while waiting for it to settle.
+    last_event: Option<Instant>,
+    /// Scene file whose contents changed on disk, awaiting a reload or dismissal.
+    pub changed: Option<PathBuf>,
+}
+
+impl SceneWatchState {
+    /// Accepts the file's current contents as known, e.g. when the user
+    /// keeps their version instead of reloading.
+    pub fn acknowledge(&mut self) {
+        if let Some(path) = self.changed.take() {
+            self.known = hash_file(&path);
+        }
+    }
+}
+
+/// A watcher on the folder holding the scene file.
+struct SceneFileWatcher {
+    /// Kept alive for as long as the folder is watched.
+    _watcher: notify::RecommendedWatcher,
+    events: Mutex<Receiver<notify::Result<notify::Event>>>,
+}
+
+impl SceneFileWatcher {
+    fn new(path: &Path) -> notify::Result<Self> {
+        let (sender, receiver) = channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        let folder = path.parent().unwrap_or(Path::new("."));
+        watcher.watch(folder, RecursiveMode::NonRecursive)?;
+        Ok(Self {
+            _watcher: watcher,
+            events: Mutex::new(receiver),
+        })
+    }
+}
+
+/// Plugin that watches the open scene's file.
+pub struct SceneWatchPlugin;
+
+impl Plugin for SceneWatchPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SceneWatchState>()
+            .add_systems(Update, watch_scene_file);
+    }
+}
+
+/// Follows the current scene path and flags external changes to its file.
+fn watch_scene_file(manager: Res<SceneManager>, mut state: ResMut<SceneWatchState>) {
+    if manager.current_scene_path != state.observed {
+        state.observed = manager.current_scene_path.clone();
+        state.changed = None;
+        state.last_event = None;
+        state.known = state.observed.as_deref().and_then(hash_file);
+        state.watcher =
+            state
+                .observed
+                .as_deref()
+                .and_then(|path| match SceneFileWatcher::new(path) {
+                    Ok(watcher) => Some(watcher),
+                    Err(e) => {
+                        warn!("Not watching {} for changes: {}", path.display(), e);
+                        None
+                    }
+                });
+        return;
+    }
+    let Some(path) = state.observed.clone() else {
+        return;
+    };
+
+    // Loading or saving leaves the scene clean; remember what's on disk then
+    if manager.is_changed() && !manager.dirty {
+        state.known = hash_file(&path);
+    }
+
+    let touched = state.watcher.as_mut().is_some_and(|watcher| {
+        let Ok(events) = watcher.events.get_mut() else {
+            return false;
+        };
+        let mut touched = false;
+        for event in events.try_iter().flatten() {
+            touched |= matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+                && event.paths.iter().any(|changed| same_file(changed, &path));
+        }
+        touched
+    });
+    if touched {
+        state.last_event = Some(Instant::now());
+    }
+
+    let settled = state
+        .last_event
+        .is_some_and(|last_event| last_event.elapsed() >= SETTLE_DELAY);
+    if !settled {
+        return;
+    }
+    state.last_event = None;
+    // Deleted or half-written files are left alone
+    let Some(hash) = hash_file(&path) else {
+        return;
+    };
+    if state.known != Some(hash) {
+        state.known = Some(hash);
+        state.changed = Some(path);
+    }
+}
+
+/// Whether two paths name the same file, allowing for one being canonical.
+fn same_file(a: &Path, b: &Path) -> bool {
+    a == b
+        || match (a.canonicalize(), b.canonicalize()) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+}
+
+fn hash_file(path: &Path) -> Option<u64> {
+    let contents = std::fs::read(path).ok()?;
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    Some(hasher.finish())
+}
@@ -5,11 +5,132 @@
 
 use bevy::prelude::*;
 
-/// Resource tracking the currently selected entity in the editor.
+/// Resource tracking the currently selected entities in the editor.
+///
+/// `selected_entity` is the primary selection (the most recently selected
+/// entity, always last in `entities`), which single-entity tools such as the
+/// inspector and the rect gizmo operate on.
 #[derive(Resource, Default)]
 pub struct EditorSelection {
-    /// The currently selected entity, if any.
+    /// The primary selected entity, if any.
     pub selected_entity: Option<Entity>,
+    /// All selected entities, including the primary one.
+    pub entities: Vec<Entity>,
+}
+
+impl EditorSelection {
+    /// Replaces the selection with a single entity.
+    pub fn select(&mut self, entity: Entity) {
+        self.selected_entity = Some(entity);
+        self.entities = vec![entity];
+    }
+
+    /// Replaces the selection with `entities`; the last one becomes primary.
+    pub fn set(&mut self, entities: impl IntoIterator<Item = Entity>) {
+        self.entities.clear();
+        for entity in entities {
+            if !self.entities.contains(&entity) {
+                self.entities.push(entity);
+            }
+        }
+        self.selected_entity = self.entities.last().copied();
+    }
+
+    /// Adds an entity to the selection, or removes it if already selected.
+    pub fn toggle(&mut self, entity: Entity) {
+        if let Some(index) = self.entities.iter().position(|&e| e == entity) {
+            self.entities.remove(index);
+            if self.selected_entity == Some(entity) {
+                self.selected_entity = self.entities.last().copied();
+            }
+        } else {
+            self.entities.push(entity);
+            self.selected_entity = Some(entity);
+        }
+    }
+
+    /// Clears the selection.
+    pub fn clear(&mut self) {
+        self.selected_entity = None;
+        self.entities.clear();
+    }
+
+    /// Whether an entity is selected.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.entities.contains(&entity)
+    }
+}
+
+/// Maximum number of entries kept in the selection history.
+const HISTORY_LIMIT: usize = 100;
+
+/// Resource tracking previously selected entities for back/forward navigation.
+#[derive(Resource, Default)]
+pub struct SelectionHistory {
+    /// Selected entities, oldest first.
+    pub entries: Vec<Entity>,
+    /// Index of the current entry in `entries`.
+    pub cursor: usize,
+    /// Set while navigating so the resulting selection change isn't recorded.
+    navigating: bool,
+}
+
+impl SelectionHistory {
+    /// Whether there is an earlier entry to go back to.
+    pub fn can_go_back(&self) -> bool {
+        self.cursor > 0
+    }
+
+    /// Whether there is a later entry to go forward to.
+    pub fn can_go_forward(&self) -> bool {
+        self.cursor + 1 < self.entries.len()
+    }
+
+    /// Records a new selection, discarding any forward entries.
+    fn record(&mut self, entity: Entity) {
+        if self.entries.get(self.cursor) == Some(&entity) {
+            return;
+        }
+        if !self.entries.is_empty() {
+            self.entries.truncate(self.cursor + 1);
+        }
+        self.entries.push(entity);
+        if self.entries.len() > HISTORY_LIMIT {
+            self.entries.remove(0);
+        }
+        self.cursor = self.entries.len() - 1;
+    }
+}
+
+/// Selects the previous (`step < 0`) or next (`step > 0`) entity in the selection history.
+///
+/// Entries for despawned entities are skipped.
+pub fn navigate_selection_history(world: &mut World, step: isize) {
+    let target = {
+        let history = world.resource::<SelectionHistory>();
+        let mut index = history.cursor as isize;
+        loop {
+            index += step.signum();
+            let Some(&entity) = usize::try_from(index)
+                .ok()
+                .and_then(|i| history.entries.get(i))
+            else {
+                break None;
+            };
+            if world.get_entity(entity).is_ok() {
+                break Some((index as usize, entity));
+            }
+        }
+    };
+
+    let Some((index, entity)) = target else {
+        return;
+    };
+
+    let mut history = world.resource_mut::<SelectionHistory>();
+    history.cursor = index;
+    history.navigating = true;
+    world.resource_mut::<EditorSelection>().select(entity);
 }
 
 /// Marker component added to selected entities.
@@ -22,7 +143,11 @@ pub struct SelectionPlugin;
 impl Plugin for SelectionPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<EditorSelection>()
-            .add_systems(PostUpdate, sync_selection_marker);
+            .init_resource::<SelectionHistory>()
+            .add_systems(
+                PostUpdate,
+                (sync_selection_marker, record_selection_history),
+            );
     }
 }
 
@@ -38,15 +163,36 @@ fn sync_selection_marker(
 
     // Remove marker from previously selected entities
     for entity in &selected_query {
-        if Some(entity) != selection.selected_entity {
+        if !selection.contains(entity) {
             commands.entity(entity).remove::<EditorSelected>();
         }
     }
 
-    // Add marker to newly selected entity
-    if let Some(entity) = selection.selected_entity {
+    // Add marker to newly selected entities
+    for &entity in &selection.entities {
         if !selected_query.contains(entity) {
-            commands.entity(entity).insert(EditorSelected);
+            if let Some(mut entity_commands) = commands.get_entity(entity) {
+                entity_commands.insert(EditorSelected);
+            }
         }
     }
 }
+
+/// Records selection changes in the selection history.
+fn record_selection_history(
+    selection: Res<EditorSelection>,
+    mut history: ResMut<SelectionHistory>,
+) {
+    if !selection.is_changed() {
+        return;
+    }
+
+    if history.navigating {
+        history.navigating = false;
+        return;
+    }
+
+    if let Some(entity) = selection.selected_entity {
+        history.record(entity);
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Viewport icons for [`SpawnPoint`]s.
+//!
+//! Spawn points have no sprite, so each one is drawn as a flag in its kind's
+//! color with its id underneath. Clicking the flag selects the spawn point.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::editor_state::EditorPlayState;
+use sandbox_engine::spawn::{SpawnKind, SpawnPoint};
+
+use crate::editor_camera::ViewportCamera;
+use crate::selection::EditorSelection;
+
+/// Height of a spawn point's flag in the viewport, in points.
+const FLAG_HEIGHT: f32 = 18.0;
+
+/// Distance from a spawn point within which a click selects it, in points.
+const CLICK_RADIUS: f32 = 12.0;
+
+/// Flag color for each kind of spawn point.
+pub fn spawn_kind_color(kind: SpawnKind) -> egui::Color32 {
+    match kind {
+        SpawnKind::Player => egui::Color32::from_rgb(80, 220, 120),
+        SpawnKind::Enemy => egui::Color32::from_rgb(235, 80, 80),
+        SpawnKind::Item => egui::Color32::from_rgb(240, 200, 60),
+        SpawnKind::Other => egui::Color32::from_rgb(120, 180, 255),
+    }
+}
+
+/// Draws a flag at every spawn point and selects one when its flag is clicked.
+///
+/// Returns whether a click landed on a flag, so viewport picking can skip it.
+/// Hidden while simulating, like other editor-only overlays.
+pub fn draw_spawn_points(
+    painter: &egui::Painter,
+    viewport_rect: egui::Rect,
+    world: &mut World,
+    response: &egui::Response,
+) -> bool {
+    if *world.resource::<State<EditorPlayState>>().get() != EditorPlayState::Stopped {
+        return false;
+    }
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return false;
+    };
+
+    let click = response
+        .clicked()
+        .then(|| response.interact_pointer_pos())
+        .flatten();
+    let mut clicked = None;
+    let mut spawns = world.query::<(Entity, &SpawnPoint, &GlobalTransform)>();
+    for (entity, spawn, transform) in spawns.iter(world) {
+        let base = view.world_to_screen(transform.translation().truncate(), viewport_rect);
+        if !viewport_rect.contains(base) {
+            continue;
+        }
+        let color = spawn_kind_color(spawn.kind);
+        let top = base - egui::vec2(0.0, FLAG_HEIGHT);
+
+        // Base ring, pole, and pennant
+        painter.circle(
+            base,
+            4.0,
+            egui::Color32::from_black_alpha(160),
+            egui::Stroke::new(1.5, color),
+        );
+        painter.line_segment([base, top], egui::Stroke::new(2.0, color));
+        painter.add(egui::Shape::convex_polygon(
+            vec![top, top + egui::vec2(12.0, 4.0), top + egui::vec2(0.0, 8.0)],
+            color,
+            egui::Stroke::new(1.0, egui::Color32::from_black_alpha(200)),
+        ));
+        painter.text(
+            base + egui::vec2(0.0, 6.0),
+            egui::Align2::CENTER_TOP,
+            &spawn.id,
+            egui::FontId::proportional(11.0),
+            color,
+        );
+
+        let flag_center = base + egui::vec2(3.0, -FLAG_HEIGHT / 2.0);
+        if click.is_some_and(|pointer| pointer.distance(flag_center) <= CLICK_RADIUS) {
+            clicked = Some(entity);
+        }
+    }
+
+    let Some(entity) = clicked else {
+        return false;
+    };
+    let toggle = response.ctx.input(|input| input.modifiers.ctrl);
+    let mut selection = world.resource_mut::<EditorSelection>();
+    if toggle {
+        selection.toggle(entity);
+    } else {
+        selection.select(entity);
+    }
+    true
+}
@@ -0,0 +1,140 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Inspector for [`SpriteVariant`]: a thumbnail of every variant.
+//!
+//! Clicking a thumbnail shows that variant; Random hands the pick back to
+//! the engine, which rolls a new one from the seed. Images can be dropped
+//! from the asset browser onto a path field or the "+ Variant" button.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiUserTextures};
+use sandbox_engine::assets::{AssetPath, SpriteVariant};
+
+use crate::assets::{cache_preview, AssetType};
+use crate::custom_inspector::RegisterInspectorExt;
+use crate::ui::asset_browser::accept_asset_drop;
+
+/// Edge of a variant thumbnail, in points.
+const THUMBNAIL_SIZE: f32 = 48.0;
+
+/// Plugin registering the sprite variant inspector.
+pub struct SpriteVariantPlugin;
+
+impl Plugin for SpriteVariantPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_inspector::<SpriteVariant>(sprite_variant_inspector);
+    }
+}
+
+/// Lists the variants with previews, and edits the selection and seed.
+fn sprite_variant_inspector(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
+    let Some(mut variant) = world.get::<SpriteVariant>(entity).cloned() else {
+        return;
+    };
+    let original = variant.clone();
+
+    let mut removed = None;
+    for index in 0..variant.variants.len() {
+        ui.horizontal(|ui| {
+            let selected = variant.selected == Some(index);
+            let preview = thumbnail(ui, world, &variant.variants[index].path, selected);
+            if preview.clicked() {
+                variant.selected = Some(index);
+            }
+            ui.vertical(|ui| {
+                let response = ui.text_edit_singleline(&mut variant.variants[index].path);
+                if let Some(dropped) = accept_asset_drop(ui, &response, AssetType::Image) {
+                    variant.variants[index].path = dropped;
+                }
+                ui.horizontal(|ui| {
+                    if selected {
+                        ui.strong("Shown");
+                    } else if ui.small_button("Show").clicked() {
+                        variant.selected = Some(index);
+                    }
+                    if ui.small_button("🗑").on_hover_text("Remove").clicked() {
+                        removed = Some(index);
+                    }
+                });
+            });
+        });
+    }
+    if let Some(index) = removed {
+        variant.variants.remove(index);
+        variant.selected = match variant.selected {
+            Some(selected) if selected == index => None,
+            Some(selected) if selected > index => Some(selected - 1),
+            selected => selected,
+        };
+    }
+    if variant.variants.is_empty() {
+        ui.weak("No variants yet.");
+    }
+
+    let add = ui
+        .button("+ Variant")
+        .on_hover_text("Add a texture, or drop an image here");
+    if let Some(dropped) = accept_asset_drop(ui, &add, AssetType::Image) {
+        variant.variants.push(AssetPath::new(dropped));
+    } else if add.clicked() {
+        variant.variants.push(AssetPath::default());
+    }
+
+    ui.horizontal(|ui| {
+        ui.label("Seed:");
+        ui.add(egui::DragValue::new(&mut variant.seed))
+            .on_hover_text("Mixed with the entity, so copies differ");
+        if ui
+            .button("🎲 Random")
+            .on_hover_text("Pick a variant at random from the next seed")
+            .clicked()
+        {
+            variant.seed = variant.seed.wrapping_add(1);
+            variant.selected = None;
+        }
+    });
+
+    if variant != original {
+        if let Some(mut current) = world.get_mut::<SpriteVariant>(entity) {
+            *current = variant;
+        }
+    }
+}
+
+/// Shows a clickable preview of the image at `path`, outlined when selected.
+fn thumbnail(ui: &mut egui::Ui, world: &mut World, path: &str, selected: bool) -> egui::Response {
+    let size = egui::Vec2::splat(THUMBNAIL_SIZE);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+    if !path.is_empty() {
+        let handle: Handle<Image> = world.resource::<AssetServer>().load(path);
+        // Keep the image loaded while it's among the recently previewed
+        cache_preview(world, format!("variant:{}", path), handle.clone());
+        let image_size = world
+            .resource::<Assets<Image>>()
+            .get(&handle)
+            .map(Image::size_f32);
+        match image_size {
+            Some(image_size) => {
+                let texture_id = world.resource_mut::<EguiUserTextures>().add_image(handle);
+                let scale = (THUMBNAIL_SIZE / image_size.x).min(THUMBNAIL_SIZE / image_size.y);
+                let fitted = egui::Rect::from_center_size(
+                    rect.center(),
+                    egui::vec2(image_size.x * scale, image_size.y * scale),
+                );
+                egui::Image::new(egui::load::SizedTexture::new(texture_id, fitted.size()))
+                    .paint_at(ui, fitted);
+            }
+            None => {
+                ui.put(rect, egui::Spinner::new());
+            }
+        }
+    }
+    let stroke = if selected {
+        ui.visuals().selection.stroke
+    } else {
+        ui.visuals().widgets.noninteractive.bg_stroke
+    };
+    ui.painter().rect_stroke(rect, 2.0, stroke);
+    response.on_hover_text(path)
+}
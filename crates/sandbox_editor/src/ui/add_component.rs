@@ -0,0 +1,153 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! "Add Component" picker for the inspector.
+//!
+//! Lists every reflect-registered component type that can be default
+//! constructed (`#[reflect(Component, Default)]`) and is not already on the
+//! entity, filtered by a search box.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::scene::SceneManager;
+use std::any::TypeId;
+
+use super::file_menu::{set_error_message, set_success_message};
+use crate::undo::{record_component_edit, snapshot_components};
+
+/// State for the Add Component picker.
+#[derive(Resource, Default)]
+pub struct AddComponentState {
+    /// Only list components whose name contains this text (case-insensitive).
+    pub search: String,
+}
+
+/// A component type that can be added from the picker.
+struct ComponentChoice {
+    type_id: TypeId,
+    short_name: String,
+    type_path: String,
+}
+
+/// Displays the Add Component button and its searchable list.
+pub fn add_component_menu(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
+    let choices = addable_components(world, entity);
+
+    let mut state = std::mem::take(&mut *world.resource_mut::<AddComponentState>());
+    let mut chosen = None;
+    ui.menu_button("+ Add Component", |ui| {
+        let search = ui.add(
+            egui::TextEdit::singleline(&mut state.search)
+                .hint_text("Search components")
+                .desired_width(200.0),
+        );
+        if ui.memory(|memory| memory.focused().is_none()) {
+            search.request_focus();
+        }
+        ui.separator();
+
+        let needle = state.search.to_lowercase();
+        let matching: Vec<&ComponentChoice> = choices
+            .iter()
+            .filter(|choice| choice.type_path.to_lowercase().contains(&needle))
+            .collect();
+
+        egui::ScrollArea::vertical()
+            .max_height(300.0)
+            .show(ui, |ui| {
+                for choice in &matching {
+                    if ui
+                        .button(&choice.short_name)
+                        .on_hover_text(&choice.type_path)
+                        .clicked()
+                    {
+                        chosen = Some(choice.type_id);
+                        ui.close_menu();
+                    }
+                }
+                if matching.is_empty() {
+                    ui.weak("No matching components");
+                }
+            });
+
+        // Enter adds the only match
+        if matching.len() == 1
+            && search.lost_focus()
+            && ui.input(|i| i.key_pressed(egui::Key::Enter))
+        {
+            chosen = Some(matching[0].type_id);
+            ui.close_menu();
+        }
+    });
+    if chosen.is_some() {
+        state.search.clear();
+    }
+    *world.resource_mut::<AddComponentState>() = state;
+
+    if let Some(type_id) = chosen {
+        add_default_component(world, entity, type_id);
+    }
+}
+
+/// Component types with a registered `Default` that `entity` does not have yet, sorted by name.
+fn addable_components(world: &World, entity: Entity) -> Vec<ComponentChoice> {
+    let Ok(entity_ref) = world.get_entity(entity) else {
+        return Vec::new();
+    };
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let mut choices: Vec<ComponentChoice> = type_registry
+        .iter()
+        .filter(|registration| {
+            registration.data::<ReflectComponent>().is_some()
+                && registration.data::<ReflectDefault>().is_some()
+                && !entity_ref.contains_type_id(registration.type_id())
+        })
+        .map(|registration| {
+            let path_table = registration.type_info().type_path_table();
+            ComponentChoice {
+                type_id: registration.type_id(),
+                short_name: path_table.short_path().to_string(),
+                type_path: path_table.path().to_string(),
+            }
+        })
+        .collect();
+    choices.sort_by(|a, b| a.short_name.cmp(&b.short_name));
+    choices
+}
+
+/// Inserts a default-constructed component onto an entity, recording undo.
+fn add_default_component(world: &mut World, entity: Entity, type_id: TypeId) {
+    let before = snapshot_components(world, &[entity], type_id);
+
+    let short_name = {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        let Some(registration) = type_registry.get(type_id) else {
+            return;
+        };
+        let short_name = registration
+            .type_info()
+            .type_path_table()
+            .short_path()
+            .to_string();
+        let (Some(reflect_component), Some(reflect_default)) = (
+            registration.data::<ReflectComponent>(),
+            registration.data::<ReflectDefault>(),
+        ) else {
+            set_error_message(world, &format!("{} has no default value", short_name));
+            return;
+        };
+        let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        let value = reflect_default.default();
+        reflect_component.insert(&mut entity_mut, value.as_partial_reflect(), &type_registry);
+        short_name
+    };
+
+    record_component_edit(world, format!("Add {}", short_name), type_id, before);
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
+    }
+    set_success_message(world, &format!("Added {}", short_name));
+}
@@ -2,11 +2,63 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 //! Asset browser panel UI.
+//!
+//! The search field and type filters flatten the tree into a list of
+//! matching files, labelled with their paths.
+//!
+//! Files can be dragged out of the tree and dropped onto asset fields in the
+//! inspector; see [`DraggedAsset`] and [`accept_asset_drop`]. Right-clicking
+//! a row offers rename, delete, and new folder (see [`super::asset_operations`]),
+//! and Find Usages (see [`super::usages`]).
+//! "Import..." copies external files into the selected folder. A spinner in
+//! the header shows while the directory is being scanned.
 
+use bevy::asset::LoadState;
 use bevy::prelude::*;
 use bevy_egui::egui;
+use sandbox_engine::time_control::PauseExempt;
 
-use crate::assets::{AssetBrowser, AssetEntry, AssetType, AudioPreviewMarker};
+use super::asset_operations::{AssetOperation, AssetOperationState};
+use super::dialogue_preview::DialoguePreviewState;
+use super::file_menu::{set_error_message, set_success_message};
+use super::sequence_editor::SequenceEditorState;
+use super::usages::UsagesState;
+use crate::assets::{cache_preview, AssetBrowser, AssetEntry, AssetType, AudioPreviewMarker};
+
+/// Drag-and-drop payload for a file dragged out of the asset browser.
+#[derive(Clone, Debug)]
+pub struct DraggedAsset {
+    /// Path relative to the assets directory.
+    pub path: String,
+    /// Type of the file, from its extension.
+    pub asset_type: AssetType,
+}
+
+/// Makes `response` a drop target for assets of type `accepts`.
+///
+/// While an asset is dragged over the widget it is outlined, green if it can
+/// be dropped and red otherwise. Returns the dropped path on release.
+pub fn accept_asset_drop(
+    ui: &egui::Ui,
+    response: &egui::Response,
+    accepts: AssetType,
+) -> Option<String> {
+    let hovered = response.dnd_hover_payload::<DraggedAsset>()?;
+    let accepted = hovered.asset_type == accepts;
+    let color = if accepted {
+        egui::Color32::from_rgb(80, 200, 80)
+    } else {
+        egui::Color32::from_rgb(220, 80, 80)
+    };
+    ui.painter().rect_stroke(
+        response.rect.expand(2.0),
+        2.0,
+        egui::Stroke::new(2.0, color),
+    );
+
+    let dropped = response.dnd_release_payload::<DraggedAsset>()?;
+    accepted.then(|| dropped.path.clone())
+}
 
 /// Displays the asset browser panel.
 pub fn asset_browser_panel(ui: &mut egui::Ui, world: &mut World) {
@@ -14,15 +66,40 @@ pub fn asset_browser_panel(ui: &mut egui::Ui, world: &mut World) {
         ui.heading("Assets");
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
             if ui.button("⟳ Refresh").clicked() {
-                world.resource_mut::<AssetBrowser>().scan_assets_directory();
+                world.resource_mut::<AssetBrowser>().request_scan();
+            }
+            let folder = world.resource::<AssetBrowser>().target_folder();
+            let hover = if folder.is_empty() {
+                "Copy files into the assets folder".to_string()
+            } else {
+                format!("Copy files into {}", folder)
+            };
+            if ui.button("📥 Import...").on_hover_text(hover).clicked() {
+                handle_import(world, &folder);
+            }
+            if ui.button("📁 New Folder").clicked() {
+                world
+                    .resource_mut::<AssetOperationState>()
+                    .open(AssetOperation::NewFolder(String::new()));
+            }
+            if world.resource::<AssetBrowser>().is_scanning() {
+                ui.weak("Scanning...");
+                ui.spinner();
             }
         });
     });
+    search_bar(ui, world);
     ui.separator();
 
     // Get asset browser state (clone to avoid borrow issues)
-    let files = world.resource::<AssetBrowser>().files.clone();
-    let selected_path = world.resource::<AssetBrowser>().selected_path.clone();
+    let browser = world.resource::<AssetBrowser>();
+    let scanning = browser.is_scanning();
+    let files = browser.files.clone();
+    let selected_path = browser.selected_path.clone();
+    let matches: Option<Vec<AssetEntry>> = browser
+        .filter
+        .is_active()
+        .then(|| browser.filtered_files().into_iter().cloned().collect());
 
     // Layout: file tree on left, preview on right
     ui.columns(2, |columns| {
@@ -31,11 +108,21 @@ pub fn asset_browser_panel(ui: &mut egui::Ui, world: &mut World) {
             .id_salt("asset_tree")
             .auto_shrink([false; 2])
             .show(&mut columns[0], |ui| {
-                for entry in &files {
-                    display_asset_entry(ui, world, entry, &selected_path);
+                if let Some(matches) = &matches {
+                    // Flat list of matching files, with their folders
+                    for entry in matches {
+                        display_asset_file(ui, world, entry, &selected_path, &entry.path);
+                    }
+                    if matches.is_empty() && !files.is_empty() {
+                        ui.label("No matching assets.");
+                    }
+                } else {
+                    for entry in &files {
+                        display_asset_entry(ui, world, entry, &selected_path);
+                    }
                 }
 
-                if files.is_empty() {
+                if files.is_empty() && !scanning {
                     ui.label("No assets found.");
                     ui.label("Add files to the 'assets' directory.");
                 }
@@ -54,6 +141,27 @@ pub fn asset_browser_panel(ui: &mut egui::Ui, world: &mut World) {
     });
 }
 
+/// Displays the search field and type filters above the file tree.
+fn search_bar(ui: &mut egui::Ui, world: &mut World) {
+    let mut filter = world.resource::<AssetBrowser>().filter.clone();
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::TextEdit::singleline(&mut filter.query)
+                .hint_text("🔍 Search assets")
+                .desired_width(180.0),
+        );
+        if !filter.query.is_empty() && ui.small_button("✖").on_hover_text("Clear").clicked() {
+            filter.query.clear();
+        }
+        ui.separator();
+        ui.selectable_value(&mut filter.asset_type, None, "All");
+        ui.selectable_value(&mut filter.asset_type, Some(AssetType::Image), "Images");
+        ui.selectable_value(&mut filter.asset_type, Some(AssetType::Audio), "Audio");
+        ui.selectable_value(&mut filter.asset_type, Some(AssetType::Scene), "Scenes");
+    });
+    world.resource_mut::<AssetBrowser>().filter = filter;
+}
+
 /// Recursively displays an asset entry in the tree.
 fn display_asset_entry(
     ui: &mut egui::Ui,
@@ -61,8 +169,6 @@ fn display_asset_entry(
     entry: &AssetEntry,
     selected_path: &Option<String>,
 ) {
-    let is_selected = selected_path.as_ref() == Some(&entry.path);
-
     if entry.is_directory {
         // Directory entry with expandable header
         let expanded = entry.expanded;
@@ -73,7 +179,12 @@ fn display_asset_entry(
                     .resource_mut::<AssetBrowser>()
                     .toggle_directory(&entry.path);
             }
-            ui.label(format!("📁 {}", entry.name));
+            let is_selected = selected_path.as_ref() == Some(&entry.path);
+            let response = ui.selectable_label(is_selected, format!("📁 {}", entry.name));
+            if response.clicked() {
+                world.resource_mut::<AssetBrowser>().selected_path = Some(entry.path.clone());
+            }
+            asset_context_menu(&response, world, entry);
         });
 
         // Show children if expanded
@@ -85,15 +196,107 @@ fn display_asset_entry(
             });
         }
     } else {
-        // File entry
-        let asset_type = AssetBrowser::get_asset_type(&entry.path);
-        let icon = asset_type.icon();
+        display_asset_file(ui, world, entry, selected_path, &entry.name);
+    }
+}
 
-        let response = ui.selectable_label(is_selected, format!("{} {}", icon, entry.name));
+/// Displays a selectable, draggable file entry labelled `label`.
+fn display_asset_file(
+    ui: &mut egui::Ui,
+    world: &mut World,
+    entry: &AssetEntry,
+    selected_path: &Option<String>,
+    label: &str,
+) {
+    let is_selected = selected_path.as_ref() == Some(&entry.path);
+    let asset_type = AssetBrowser::get_asset_type(&entry.path);
+    let icon = asset_type.icon();
+
+    // Files can be dragged onto asset fields in the inspector. The label
+    // senses clicks too, so a press only starts a drag once the pointer moves.
+    let response = ui
+        .selectable_label(is_selected, format!("{} {}", icon, label))
+        .interact(egui::Sense::drag());
+    response.dnd_set_drag_payload(DraggedAsset {
+        path: entry.path.clone(),
+        asset_type,
+    });
+
+    if response.clicked() {
+        world.resource_mut::<AssetBrowser>().selected_path = Some(entry.path.clone());
+    }
+    asset_context_menu(&response, world, entry);
+}
+
+/// Right-click menu with file operations for an asset browser row.
+fn asset_context_menu(response: &egui::Response, world: &mut World, entry: &AssetEntry) {
+    let mut operation = None;
+    let mut find_usages = false;
+    let mut preview_dialogue = false;
+    let mut edit_sequence = false;
+    response.context_menu(|ui| {
+        if entry.is_directory && ui.button("New Folder...").clicked() {
+            operation = Some(AssetOperation::NewFolder(entry.path.clone()));
+        }
+        if entry.is_directory && ui.button("New Sequence...").clicked() {
+            operation = Some(AssetOperation::NewSequence(entry.path.clone()));
+        }
+        if ui.button("Find Usages").clicked() {
+            find_usages = true;
+            ui.close_menu();
+        }
+        if entry.path.ends_with(".dialogue.ron") && ui.button("Preview Dialogue").clicked() {
+            preview_dialogue = true;
+            ui.close_menu();
+        }
+        if entry.path.ends_with(".sequence.ron") && ui.button("Edit Sequence").clicked() {
+            edit_sequence = true;
+            ui.close_menu();
+        }
+        if ui.button("Rename...").clicked() {
+            operation = Some(AssetOperation::Rename(entry.path.clone()));
+        }
+        if ui.button("Delete...").clicked() {
+            operation = Some(AssetOperation::Delete(entry.path.clone()));
+        }
+        if operation.is_some() {
+            ui.close_menu();
+        }
+    });
+    if let Some(operation) = operation {
+        world.resource_mut::<AssetOperationState>().open(operation);
+    }
+    if find_usages {
+        world.resource_mut::<UsagesState>().open(entry.path.clone());
+    }
+    if preview_dialogue {
+        world
+            .resource_mut::<DialoguePreviewState>()
+            .open(entry.path.clone());
+    }
+    if edit_sequence {
+        world
+            .resource_mut::<SequenceEditorState>()
+            .open(entry.path.clone());
+    }
+}
+
+/// Copies files picked in a file dialog into `folder` of the assets directory.
+fn handle_import(world: &mut World, folder: &str) {
+    let dialog = rfd::FileDialog::new().set_title("Import Assets");
+    let Some(files) = dialog.pick_files() else {
+        return;
+    };
 
-        if response.clicked() {
-            world.resource_mut::<AssetBrowser>().selected_path = Some(entry.path.clone());
+    let result = world
+        .resource_mut::<AssetBrowser>()
+        .import_files(&files, folder);
+    match result {
+        Ok(imported) if imported.len() == 1 => {
+            set_success_message(world, &format!("Imported {}", imported[0]))
         }
+        Ok(imported) => set_success_message(world, &format!("Imported {} files", imported.len())),
+        Err(e) => set_error_message(world, &format!("Import failed: {}", e)),
     }
 }
 
@@ -104,6 +307,16 @@ fn display_asset_preview(ui: &mut egui::Ui, world: &mut World, path: &str) {
     ui.label(format!("Path: {}", path));
     ui.separator();
 
+    if let Some(folder) = world
+        .resource::<AssetBrowser>()
+        .find_entry(path)
+        .filter(|entry| entry.is_directory)
+    {
+        ui.label(format!("Folder with {} items", folder.children.len()));
+        ui.label("Imported files go here");
+        return;
+    }
+
     match asset_type {
         AssetType::Image => {
             display_image_preview(ui, world, path);
@@ -127,11 +340,8 @@ fn display_image_preview(ui: &mut egui::Ui, world: &mut World, path: &str) {
     let asset_path = path.to_string();
     let handle: Handle<Image> = world.resource::<AssetServer>().load(&asset_path);
 
-    // Store handle in browser to keep it alive
-    world
-        .resource_mut::<AssetBrowser>()
-        .preview_handles
-        .insert(path.to_string(), handle.clone());
+    // Keep the image loaded while it's among the recently previewed
+    cache_preview(world, path.to_string(), handle.clone());
 
     // Check if image is loaded
     let images = world.resource::<Assets<Image>>();
@@ -157,6 +367,11 @@ fn display_image_preview(ui: &mut egui::Ui, world: &mut World, path: &str) {
 
         // Display the image
         ui.image(egui::load::SizedTexture::new(texture_id, display_size));
+    } else if let Some(LoadState::Failed(e)) =
+        world.resource::<AssetServer>().get_load_state(&handle)
+    {
+        ui.colored_label(egui::Color32::from_rgb(230, 80, 80), "Failed to load image");
+        ui.label(e.to_string());
     } else {
         ui.spinner();
         ui.label("Loading...");
@@ -211,6 +426,7 @@ fn display_audio_preview(ui: &mut egui::Ui, world: &mut World, path: &str) {
                     AudioPlayer::<AudioSource>(handle),
                     PlaybackSettings::DESPAWN,
                     AudioPreviewMarker,
+                    PauseExempt,
                 ))
                 .id();
 
@@ -0,0 +1,241 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Rename, delete, new-folder, and new-sequence dialogs for the asset browser.
+//!
+//! Opened from the context menu of an asset browser row (or the New Folder
+//! button for the assets root). The file system operation runs when the
+//! dialog is confirmed, and the browser rescans afterwards. Renaming offers
+//! to repoint the open scene's and scene files' references to the new path.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use sandbox_engine::sequence::Sequence;
+
+use super::file_menu::{set_error_message, set_success_message};
+use super::sequence_editor::SequenceEditorState;
+use crate::assets::AssetBrowser;
+use crate::unused_assets::AssetTrash;
+use crate::usages::{find_usages, update_references};
+
+/// File operation awaiting confirmation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AssetOperation {
+    /// Rename the entry at this path.
+    Rename(String),
+    /// Delete the entry at this path.
+    Delete(String),
+    /// Create a folder inside this path ("" for the assets root).
+    NewFolder(String),
+    /// Create an empty sequence file inside this path and open it.
+    NewSequence(String),
+}
+
+/// State for the asset operation dialog.
+#[derive(Resource, Default)]
+pub struct AssetOperationState {
+    /// Operation being confirmed, if the dialog is open.
+    pub operation: Option<AssetOperation>,
+    /// Name typed for Rename, New Folder, and New Sequence.
+    pub name: String,
+    /// Whether Rename updates references to the renamed path.
+    pub update_references: bool,
+    /// References to the entry being renamed; `None` until counted.
+    pub references: Option<usize>,
+}
+
+impl AssetOperationState {
+    /// Opens the dialog for `operation`, pre-filling the name when renaming.
+    pub fn open(&mut self, operation: AssetOperation) {
+        self.name = match &operation {
+            AssetOperation::Rename(path) => path.rsplit('/').next().unwrap_or(path).to_string(),
+            AssetOperation::Delete(_)
+            | AssetOperation::NewFolder(_)
+            | AssetOperation::NewSequence(_) => String::new(),
+        };
+        self.update_references = true;
+        self.references = None;
+        self.operation = Some(operation);
+    }
+}
+
+/// Displays the asset operation dialog.
+pub fn asset_operation_window(ctx: &egui::Context, world: &mut World) {
+    let Some(operation) = world.resource::<AssetOperationState>().operation.clone() else {
+        return;
+    };
+    if let AssetOperation::Rename(path) = &operation {
+        if world.resource::<AssetOperationState>().references.is_none() {
+            let count = find_usages(world, path).usages.len();
+            world.resource_mut::<AssetOperationState>().references = Some(count);
+        }
+    }
+    let mut name = world.resource::<AssetOperationState>().name.clone();
+    let mut update = world.resource::<AssetOperationState>().update_references;
+    let references = world
+        .resource::<AssetOperationState>()
+        .references
+        .unwrap_or(0);
+
+    let title = match &operation {
+        AssetOperation::Rename(_) => "Rename Asset",
+        AssetOperation::Delete(_) => "Delete Asset",
+        AssetOperation::NewFolder(_) => "New Folder",
+        AssetOperation::NewSequence(_) => "New Sequence",
+    };
+    let mut confirmed = false;
+    let mut cancelled = false;
+    egui::Window::new(title)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            let confirm_label = match &operation {
+                AssetOperation::Rename(path) => {
+                    ui.label(format!("Rename '{}' to:", path));
+                    name_field(ui, &mut name, &mut confirmed);
+                    if references > 0 {
+                        let label = match references {
+                            1 => "Update 1 reference".to_string(),
+                            n => format!("Update {} references", n),
+                        };
+                        ui.checkbox(&mut update, label)
+                            .on_hover_text("Point the open scene and scene files at the new path");
+                    }
+                    "Rename"
+                }
+                AssetOperation::Delete(path) => {
+                    let is_directory = world
+                        .resource::<AssetBrowser>()
+                        .find_entry(path)
+                        .is_some_and(|entry| entry.is_directory);
+                    if is_directory {
+                        ui.label(format!(
+                            "Delete the folder '{}' and everything in it?",
+                            path
+                        ));
+                    } else {
+                        ui.label(format!("Delete '{}'?", path));
+                    }
+                    ui.weak("It will be moved to the system trash.");
+                    "🗑 Delete"
+                }
+                AssetOperation::NewFolder(parent) => {
+                    if parent.is_empty() {
+                        ui.label("Folder name:");
+                    } else {
+                        ui.label(format!("Folder name in '{}':", parent));
+                    }
+                    name_field(ui, &mut name, &mut confirmed);
+                    "Create"
+                }
+                AssetOperation::NewSequence(parent) => {
+                    if parent.is_empty() {
+                        ui.label("Sequence name:");
+                    } else {
+                        ui.label(format!("Sequence name in '{}':", parent));
+                    }
+                    name_field(ui, &mut name, &mut confirmed);
+                    ui.weak(format!("Saved as {}", sequence_file_name(&name)));
+                    "Create"
+                }
+            };
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui.button(confirm_label).clicked() {
+                    confirmed = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    cancelled = true;
+                }
+            });
+        });
+
+    let mut state = world.resource_mut::<AssetOperationState>();
+    state.name = name.clone();
+    state.update_references = update;
+    if cancelled {
+        world.resource_mut::<AssetOperationState>().operation = None;
+        return;
+    }
+    if !confirmed {
+        return;
+    }
+
+    let mut created_sequence = None;
+    let trash = *world.resource::<AssetTrash>();
+    let mut browser = world.resource_mut::<AssetBrowser>();
+    let result = match &operation {
+        AssetOperation::Rename(path) => match browser.rename_entry(path, &name) {
+            Ok(new_path) if update && references > 0 => {
+                match update_references(world, path, &new_path) {
+                    Ok(count) => Ok(format!(
+                        "Renamed to {}, updated {} references",
+                        new_path, count
+                    )),
+                    Err(e) => {
+                        world.resource_mut::<AssetOperationState>().operation = None;
+                        let message = format!(
+                            "Renamed to {}, but updating references failed: {}",
+                            new_path, e
+                        );
+                        set_error_message(world, &message);
+                        return;
+                    }
+                }
+            }
+            result => result.map(|new_path| format!("Renamed to {}", new_path)),
+        },
+        AssetOperation::Delete(path) => browser
+            .delete_entry(path, trash)
+            .map(|()| format!("Moved {} to the trash", path)),
+        AssetOperation::NewFolder(parent) => browser
+            .create_folder(parent, &name)
+            .map(|path| format!("Created folder {}", path)),
+        AssetOperation::NewSequence(parent) => {
+            match bevy::scene::ron::ser::to_string_pretty(&Sequence::default(), Default::default())
+            {
+                Ok(contents) => browser
+                    .create_file(parent, &sequence_file_name(&name), &contents)
+                    .map(|path| {
+                        created_sequence = Some(path.clone());
+                        format!("Created sequence {}", path)
+                    }),
+                Err(e) => Err(e.to_string()),
+            }
+        }
+    };
+    if let Some(path) = created_sequence {
+        world.resource_mut::<SequenceEditorState>().open(path);
+    }
+    match result {
+        Ok(message) => {
+            world.resource_mut::<AssetOperationState>().operation = None;
+            set_success_message(world, &message);
+        }
+        // Keep the dialog open so the name can be corrected
+        Err(e) => set_error_message(world, &format!("{} failed: {}", title, e)),
+    }
+}
+
+/// File name for a sequence called `name`, adding the `.sequence.ron` extension.
+fn sequence_file_name(name: &str) -> String {
+    let name = name.trim();
+    if name.ends_with(".sequence.ron") {
+        name.to_string()
+    } else {
+        format!("{}.sequence.ron", name)
+    }
+}
+
+/// Single-line name field, focused when the dialog opens; Enter confirms.
+fn name_field(ui: &mut egui::Ui, name: &mut String, confirmed: &mut bool) {
+    let response = ui.text_edit_singleline(name);
+    if !response.has_focus() && !response.lost_focus() {
+        response.request_focus();
+    }
+    if response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter)) {
+        *confirmed = true;
+    }
+}
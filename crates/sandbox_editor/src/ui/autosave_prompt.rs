@@ -0,0 +1,105 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Offers to recover autosaves newer than their scene file.
+//!
+//! "Recover" opens the autosave in place of its scene, with unsaved changes,
+//! so saving writes it back over the scene file. "Delete" removes the
+//! autosave; older backups are kept.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::{load_scene, SceneManager};
+use std::path::{Path, PathBuf};
+
+use super::file_menu::{set_error_message, set_success_message};
+use crate::autosave::{autosaved_scene, AutosaveRecovery};
+use crate::undo::UndoStack;
+
+/// Action chosen in the recovery prompt.
+enum RecoveryAction {
+    Recover(PathBuf),
+    Delete(PathBuf),
+    Later,
+}
+
+/// Displays the autosave recovery prompt while there are autosaves to recover.
+pub fn autosave_recovery_prompt(ctx: &egui::Context, world: &mut World) {
+    let found = world.resource::<AutosaveRecovery>().found.clone();
+    if found.is_empty() {
+        return;
+    }
+    let root = world.resource::<ProjectRoot>().clone();
+
+    let mut action = None;
+    egui::Window::new("Recover Autosave")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label("These scenes have autosaves newer than their last save:");
+            egui::Grid::new("autosaves").num_columns(3).show(ui, |ui| {
+                for autosave in &found {
+                    let scene = autosaved_scene(autosave).unwrap_or_default();
+                    ui.label(display_path(&root, &scene));
+                    if ui
+                        .button("Recover")
+                        .on_hover_text("Open the autosave in place of the scene")
+                        .clicked()
+                    {
+                        action = Some(RecoveryAction::Recover(autosave.clone()));
+                    }
+                    if ui.button("Delete").clicked() {
+                        action = Some(RecoveryAction::Delete(autosave.clone()));
+                    }
+                    ui.end_row();
+                }
+            });
+            ui.separator();
+            if ui.button("Not Now").clicked() {
+                action = Some(RecoveryAction::Later);
+            }
+        });
+
+    let Some(action) = action else {
+        return;
+    };
+    let mut recovery = world.resource_mut::<AutosaveRecovery>();
+    match action {
+        RecoveryAction::Recover(autosave) => {
+            recovery.found.retain(|path| *path != autosave);
+            recover(world, &autosave);
+        }
+        RecoveryAction::Delete(autosave) => {
+            recovery.found.retain(|path| *path != autosave);
+            if let Err(e) = std::fs::remove_file(&autosave) {
+                set_error_message(world, &format!("Failed to delete autosave: {}", e));
+            }
+        }
+        RecoveryAction::Later => recovery.found.clear(),
+    }
+}
+
+/// Opens `autosave` as its scene, marked as having unsaved changes.
+fn recover(world: &mut World, autosave: &Path) {
+    let Some(scene) = autosaved_scene(autosave) else {
+        return;
+    };
+    match load_scene(world, autosave) {
+        Ok(()) => {
+            world.resource_mut::<UndoStack>().clear();
+            let mut manager = world.resource_mut::<SceneManager>();
+            manager.current_scene_path = Some(scene.clone());
+            manager.mark_dirty();
+            set_success_message(world, &format!("Recovered: {}", scene.display()));
+        }
+        Err(e) => set_error_message(world, &format!("Failed to recover autosave: {}", e)),
+    }
+}
+
+/// `path` relative to the assets directory, if it's inside it.
+fn display_path(root: &ProjectRoot, path: &Path) -> String {
+    root.to_asset_path(path)
+        .unwrap_or_else(|| path.display().to_string())
+}
@@ -0,0 +1,149 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Window for tuning the tint and brightness of each sprite color group.
+//!
+//! Edits apply to the project's `ColorGrades` immediately, so the viewport
+//! (and a running game) shows them on the next frame; Save writes them to the
+//! project file.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::color_grade::{ColorGrade, ColorGroup};
+use sandbox_engine::project::{ProjectRoot, ProjectSettings, PROJECT_FILE_NAME};
+use std::collections::BTreeMap;
+
+use super::file_menu::{set_error_message, set_success_message};
+
+/// State for the color grades window.
+#[derive(Resource, Default)]
+pub struct ColorGradesWindowState {
+    /// Whether the window is open.
+    pub open: bool,
+    /// Whether the grades have changed since they were last saved.
+    pub unsaved: bool,
+    /// Name typed for a new group.
+    pub new_group: String,
+}
+
+/// Displays the color grades window.
+pub fn color_grades_window(ctx: &egui::Context, world: &mut World) {
+    if !world.resource::<ColorGradesWindowState>().open {
+        return;
+    }
+
+    let mut state = std::mem::take(&mut *world.resource_mut::<ColorGradesWindowState>());
+    let original = world.resource::<ProjectSettings>().color_grades.clone();
+    let mut grades = original.clone();
+    let mut members: BTreeMap<String, usize> = BTreeMap::new();
+    for group in world.query::<&ColorGroup>().iter(world) {
+        *members.entry(group.0.clone()).or_default() += 1;
+    }
+    let mut save = false;
+
+    egui::Window::new("Color Grades")
+        .open(&mut state.open)
+        .default_width(360.0)
+        .show(ctx, |ui| {
+            let mut removed = None;
+            egui::Grid::new("color_grades")
+                .num_columns(4)
+                .striped(true)
+                .show(ui, |ui| {
+                    for (name, grade) in grades.groups.iter_mut() {
+                        let count = members.get(name).copied().unwrap_or(0);
+                        ui.label(name).on_hover_text(format!("{} entities", count));
+                        let srgba = grade.tint.to_srgba();
+                        let mut color = [srgba.red, srgba.green, srgba.blue];
+                        if ui.color_edit_button_rgb(&mut color).changed() {
+                            grade.tint = Color::srgb(color[0], color[1], color[2]);
+                        }
+                        ui.add(
+                            egui::DragValue::new(&mut grade.brightness)
+                                .range(0.0..=4.0)
+                                .speed(0.01)
+                                .prefix("× "),
+                        )
+                        .on_hover_text("Brightness");
+                        if ui.small_button("🗑").on_hover_text("Remove").clicked() {
+                            removed = Some(name.clone());
+                        }
+                        ui.end_row();
+                    }
+                });
+            if let Some(name) = removed {
+                grades.groups.remove(&name);
+            }
+            if grades.groups.is_empty() {
+                ui.weak("No color grades yet.");
+            }
+
+            // Groups used in the scene that have no grade yet
+            let ungraded: Vec<&String> = members
+                .keys()
+                .filter(|name| !name.is_empty() && !grades.groups.contains_key(*name))
+                .collect();
+            if !ungraded.is_empty() {
+                ui.horizontal_wrapped(|ui| {
+                    ui.weak("In the scene:");
+                    for name in ungraded {
+                        if ui
+                            .small_button(format!("+ {}", name))
+                            .on_hover_text("Add a grade for this group")
+                            .clicked()
+                        {
+                            grades.groups.insert(name.clone(), ColorGrade::default());
+                        }
+                    }
+                });
+            }
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut state.new_group)
+                        .hint_text("Group name")
+                        .desired_width(160.0),
+                );
+                let name = state.new_group.trim().to_string();
+                let can_add = !name.is_empty() && !grades.groups.contains_key(&name);
+                if ui
+                    .add_enabled(can_add, egui::Button::new("+ Add Group"))
+                    .clicked()
+                {
+                    grades.groups.insert(name, ColorGrade::default());
+                    state.new_group.clear();
+                }
+            });
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(state.unsaved, egui::Button::new("💾 Save"))
+                    .clicked()
+                {
+                    save = true;
+                }
+                if state.unsaved {
+                    ui.weak("Unsaved changes");
+                }
+            });
+        });
+
+    if grades != original {
+        world.resource_mut::<ProjectSettings>().color_grades = grades;
+        state.unsaved = true;
+    }
+    if save {
+        let root = world.resource::<ProjectRoot>().clone();
+        match world.resource::<ProjectSettings>().save(&root) {
+            Ok(()) => {
+                state.unsaved = false;
+                set_success_message(
+                    world,
+                    &format!("Saved color grades to {}", PROJECT_FILE_NAME),
+                );
+            }
+            Err(e) => set_error_message(world, &format!("Failed to save color grades: {}", e)),
+        }
+    }
+    *world.resource_mut::<ColorGradesWindowState>() = state;
+}
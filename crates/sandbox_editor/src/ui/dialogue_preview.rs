@@ -0,0 +1,180 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Window that steps through a dialogue file, opened from the asset browser.
+//!
+//! The preview runs against a copy of the world's flags taken when it
+//! starts, so conditions read the current game state but the flag changes
+//! it makes don't leak into the scene.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::dialogue::{Dialogue, DialogueRunner, DialogueStep};
+use sandbox_engine::flags::WorldFlags;
+use sandbox_engine::project::ProjectRoot;
+
+/// State for the dialogue preview window.
+#[derive(Resource, Default)]
+pub struct DialoguePreviewState {
+    /// Dialogue file being previewed; the window is open while set.
+    pub path: Option<String>,
+    /// The running conversation; `None` until the file is loaded.
+    pub runner: Option<DialogueRunner>,
+    /// Flags the preview reads and changes.
+    pub flags: WorldFlags,
+    /// Lines shown and choices picked so far.
+    pub transcript: Vec<String>,
+    /// Problems found in the file, or the error it failed to load with.
+    pub problems: Vec<String>,
+}
+
+impl DialoguePreviewState {
+    /// Opens the window for `path` and starts the conversation on the next frame.
+    pub fn open(&mut self, path: impl Into<String>) {
+        self.path = Some(path.into());
+        self.runner = None;
+        self.problems.clear();
+    }
+}
+
+/// Action chosen in the dialogue preview window.
+enum PreviewAction {
+    Restart,
+    Continue,
+    Choose(usize),
+}
+
+/// Displays the dialogue preview window.
+pub fn dialogue_preview_window(ctx: &egui::Context, world: &mut World) {
+    let Some(path) = world.resource::<DialoguePreviewState>().path.clone() else {
+        return;
+    };
+    if world.resource::<DialoguePreviewState>().runner.is_none()
+        && world.resource::<DialoguePreviewState>().problems.is_empty()
+    {
+        restart(world, &path);
+    }
+
+    let mut state = std::mem::take(&mut *world.resource_mut::<DialoguePreviewState>());
+    let mut open = true;
+    let mut action = None;
+    egui::Window::new(format!("Dialogue Preview: {}", path))
+        .id(egui::Id::new("dialogue_preview"))
+        .open(&mut open)
+        .default_width(420.0)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.button("⟲ Restart").clicked() {
+                    action = Some(PreviewAction::Restart);
+                }
+                if let Some(node) = state.runner.as_ref().and_then(DialogueRunner::node) {
+                    ui.weak(format!("Node: {}", node));
+                }
+            });
+            for problem in &state.problems {
+                ui.colored_label(egui::Color32::YELLOW, format!("⚠ {}", problem));
+            }
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .max_height(300.0)
+                .auto_shrink([false, true])
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    for entry in &state.transcript {
+                        ui.weak(entry);
+                    }
+                    let Some(runner) = &state.runner else {
+                        return;
+                    };
+                    match runner.step() {
+                        DialogueStep::Line { speaker, text } => {
+                            ui.label(speaker_line(speaker, text));
+                            if ui.button("Continue ▸").clicked() {
+                                action = Some(PreviewAction::Continue);
+                            }
+                        }
+                        DialogueStep::Choices(choices) => {
+                            for (number, (index, text)) in choices.iter().enumerate() {
+                                if ui.button(format!("{}. {}", number + 1, text)).clicked() {
+                                    action = Some(PreviewAction::Choose(*index));
+                                }
+                            }
+                        }
+                        DialogueStep::End => {
+                            ui.weak("(End of conversation)");
+                        }
+                    }
+                });
+
+            ui.collapsing("Flags", |ui| {
+                if state.flags.values.is_empty() {
+                    ui.weak("No flags set.");
+                }
+                for (key, value) in &state.flags.values {
+                    ui.label(format!("{} = {}", key, value));
+                }
+            });
+        });
+
+    if let Some(runner) = &mut state.runner {
+        match action {
+            Some(PreviewAction::Continue) => {
+                if let DialogueStep::Line { speaker, text } = runner.step() {
+                    state.transcript.push(speaker_line(speaker, text));
+                }
+                runner.advance(&mut state.flags);
+            }
+            Some(PreviewAction::Choose(index)) => {
+                if let DialogueStep::Choices(choices) = runner.step() {
+                    if let Some((_, text)) = choices.iter().find(|(i, _)| *i == index) {
+                        state.transcript.push(format!("▸ {}", text));
+                    }
+                }
+                runner.choose(index, &mut state.flags);
+            }
+            _ => {}
+        }
+    }
+    if !open {
+        state = DialoguePreviewState::default();
+    }
+    *world.resource_mut::<DialoguePreviewState>() = state;
+    if matches!(action, Some(PreviewAction::Restart)) {
+        restart(world, &path);
+    }
+}
+
+/// A line as shown in the transcript, prefixed with its speaker.
+fn speaker_line(speaker: &str, text: &str) -> String {
+    if speaker.is_empty() {
+        text.to_string()
+    } else {
+        format!("{}: {}", speaker, text)
+    }
+}
+
+/// Reloads the dialogue file and starts it over with the world's current flags.
+fn restart(world: &mut World, path: &str) {
+    let file = match world.get_resource::<ProjectRoot>() {
+        Some(root) => root.resolve_asset(path),
+        None => path.into(),
+    };
+    let mut flags = world
+        .get_resource::<WorldFlags>()
+        .cloned()
+        .unwrap_or_default();
+    let mut state = world.resource_mut::<DialoguePreviewState>();
+    state.transcript.clear();
+    match Dialogue::load(&file) {
+        Ok(dialogue) => {
+            state.problems = dialogue.validate();
+            state.runner = Some(DialogueRunner::new(dialogue, &mut flags));
+        }
+        Err(e) => {
+            state.problems = vec![format!("Failed to load: {}", e)];
+            state.runner = None;
+        }
+    }
+    state.flags = flags;
+}
@@ -0,0 +1,288 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Inspector widget for component fields that reference other entities.
+//!
+//! Fields of type `Entity` or `Option<Entity>` on reflected components get a
+//! picker with an eyedropper mode (click an entity in the viewport or
+//! hierarchy to assign it) and a button to select the referenced entity.
+
+use bevy::ecs::component::ComponentId;
+use bevy::prelude::*;
+use bevy::reflect::{ReflectFromPtr, ReflectMut, ReflectRef};
+use bevy_egui::egui;
+use sandbox_engine::scene::SceneManager;
+
+use crate::selection::EditorSelection;
+
+/// The field waiting for an entity to be picked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PickTarget {
+    /// Entity owning the component.
+    pub owner: Entity,
+    /// Component holding the field.
+    pub component_id: ComponentId,
+    /// Name of the field.
+    pub field: String,
+}
+
+/// Resource tracking the active eyedropper, if any.
+#[derive(Resource, Default)]
+pub struct EntityPickerState {
+    /// Field that the next clicked entity will be assigned to.
+    pub target: Option<PickTarget>,
+}
+
+/// Assigns `picked` to the eyedropper's target field, if the eyedropper is active.
+///
+/// Returns `true` if the click was consumed by the eyedropper.
+pub fn try_consume_pick(world: &mut World, picked: Entity) -> bool {
+    let Some(target) = world
+        .get_resource_mut::<EntityPickerState>()
+        .and_then(|mut state| state.target.take())
+    else {
+        return false;
+    };
+
+    set_entity_field(world, &target, Some(picked));
+    true
+}
+
+/// Cancels the eyedropper when Escape is pressed.
+pub fn cancel_pick_on_escape(ctx: &egui::Context, world: &mut World) {
+    let active = world
+        .get_resource::<EntityPickerState>()
+        .is_some_and(|state| state.target.is_some());
+
+    if active && ctx.input(|input| input.key_pressed(egui::Key::Escape)) {
+        world.resource_mut::<EntityPickerState>().target = None;
+    }
+}
+
+/// Entity-valued field found on a component.
+struct EntityField {
+    name: String,
+    value: Option<Entity>,
+    optional: bool,
+}
+
+/// Action to perform after UI interaction.
+enum PickerAction {
+    None,
+    StartPicking(String),
+    CancelPicking,
+    Locate(Entity),
+    Clear(String),
+}
+
+/// Displays picker widgets for the entity-valued fields of a reflected component.
+///
+/// Returns `false` if the component has no such fields.
+pub fn display_entity_ref_fields(
+    ui: &mut egui::Ui,
+    world: &mut World,
+    entity: Entity,
+    component_id: ComponentId,
+    type_id: Option<std::any::TypeId>,
+) -> bool {
+    let fields = collect_entity_fields(world, entity, component_id, type_id);
+    if fields.is_empty() {
+        return false;
+    }
+
+    let active_field = world
+        .get_resource::<EntityPickerState>()
+        .and_then(|state| state.target.clone())
+        .filter(|target| target.owner == entity && target.component_id == component_id)
+        .map(|target| target.field);
+
+    let mut action = PickerAction::None;
+    for field in &fields {
+        let target_name = field.value.map(|target| entity_label(world, target));
+        let picking = active_field.as_deref() == Some(field.name.as_str());
+
+        ui.horizontal(|ui| {
+            ui.label(format!("{}:", field.name));
+            ui.label(target_name.as_deref().unwrap_or("None"));
+
+            if ui
+                .selectable_label(picking, "🎯")
+                .on_hover_text("Pick an entity in the viewport or hierarchy (Esc to cancel)")
+                .clicked()
+            {
+                action = if picking {
+                    PickerAction::CancelPicking
+                } else {
+                    PickerAction::StartPicking(field.name.clone())
+                };
+            }
+
+            if let Some(target) = field.value {
+                if ui
+                    .small_button("Locate")
+                    .on_hover_text("Select the referenced entity")
+                    .clicked()
+                {
+                    action = PickerAction::Locate(target);
+                }
+            }
+
+            if field.optional && field.value.is_some() && ui.small_button("✕").clicked() {
+                action = PickerAction::Clear(field.name.clone());
+            }
+        });
+
+        if picking {
+            ui.weak("Click an entity to assign it...");
+        }
+    }
+
+    match action {
+        PickerAction::StartPicking(field) => {
+            world.resource_mut::<EntityPickerState>().target = Some(PickTarget {
+                owner: entity,
+                component_id,
+                field,
+            });
+        }
+        PickerAction::CancelPicking => {
+            world.resource_mut::<EntityPickerState>().target = None;
+        }
+        PickerAction::Locate(target) => {
+            if world.get_entity(target).is_ok() {
+                world.resource_mut::<EditorSelection>().select(target);
+            }
+        }
+        PickerAction::Clear(field) => {
+            let target = PickTarget {
+                owner: entity,
+                component_id,
+                field,
+            };
+            set_entity_field(world, &target, None);
+        }
+        PickerAction::None => {}
+    }
+
+    true
+}
+
+/// Collects the `Entity` and `Option<Entity>` fields of a reflected struct component.
+fn collect_entity_fields(
+    world: &World,
+    entity: Entity,
+    component_id: ComponentId,
+    type_id: Option<std::any::TypeId>,
+) -> Vec<EntityField> {
+    let mut fields = Vec::new();
+
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+
+    let Some(reflect_from_ptr) = type_id
+        .and_then(|type_id| type_registry.get(type_id))
+        .and_then(|registration| registration.data::<ReflectFromPtr>())
+    else {
+        return fields;
+    };
+    let Some(component_ptr) = world.get_by_id(entity, component_id) else {
+        return fields;
+    };
+
+    // SAFETY: The pointer is to a live component of the type the registration describes
+    let reflect = unsafe { reflect_from_ptr.as_reflect(component_ptr) };
+    let ReflectRef::Struct(component) = reflect.reflect_ref() else {
+        return fields;
+    };
+
+    for index in 0..component.field_len() {
+        let (Some(name), Some(value)) = (component.name_at(index), component.field_at(index))
+        else {
+            continue;
+        };
+        if let Some(target) = value.try_downcast_ref::<Entity>() {
+            fields.push(EntityField {
+                name: name.to_string(),
+                value: Some(*target),
+                optional: false,
+            });
+        } else if let Some(target) = value.try_downcast_ref::<Option<Entity>>() {
+            fields.push(EntityField {
+                name: name.to_string(),
+                value: *target,
+                optional: true,
+            });
+        }
+    }
+
+    fields
+}
+
+/// Writes an entity reference into the target field.
+///
+/// Clearing (`None`) only applies to `Option<Entity>` fields.
+fn set_entity_field(world: &mut World, target: &PickTarget, value: Option<Entity>) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+
+    let Some(type_id) = world
+        .components()
+        .get_info(target.component_id)
+        .and_then(|info| info.type_id())
+    else {
+        return;
+    };
+    let Some(reflect_from_ptr) = type_registry
+        .get(type_id)
+        .and_then(|registration| registration.data::<ReflectFromPtr>())
+    else {
+        return;
+    };
+    let Ok(mut owner) = world.get_entity_mut(target.owner) else {
+        return;
+    };
+    let Ok(component_ptr) = owner.get_mut_by_id(target.component_id) else {
+        return;
+    };
+
+    // SAFETY: The pointer is to a live component of the type the registration describes
+    let reflect = unsafe { reflect_from_ptr.as_reflect_mut(component_ptr.into_inner()) };
+    let ReflectMut::Struct(component) = reflect.reflect_mut() else {
+        return;
+    };
+    let Some(field) = component.field_mut(&target.field) else {
+        return;
+    };
+
+    let assigned = if let Some(slot) = field.try_downcast_mut::<Entity>() {
+        match value {
+            Some(entity) => {
+                *slot = entity;
+                true
+            }
+            None => false,
+        }
+    } else if let Some(slot) = field.try_downcast_mut::<Option<Entity>>() {
+        *slot = value;
+        true
+    } else {
+        false
+    };
+
+    if assigned {
+        if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+            manager.mark_dirty();
+        }
+    }
+}
+
+/// Returns a display label for an entity.
+pub fn entity_label(world: &World, entity: Entity) -> String {
+    match world.get_entity(entity) {
+        Ok(entity_ref) => match entity_ref.get::<Name>() {
+            Some(name) => format!("{} ({:?})", name.as_str(), entity),
+            None => format!("{:?}", entity),
+        },
+        Err(_) => format!("{:?} (missing)", entity),
+    }
+}
@@ -0,0 +1,214 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Generic editing of reflected component fields.
+//!
+//! Fields are addressed by reflection paths such as `.translation.x`; only
+//! leaf values with a simple widget (numbers, bools, strings, colors) are
+//! editable.
+
+use bevy::prelude::*;
+use bevy::reflect::{GetPath, PartialReflect, ReflectRef};
+use bevy_egui::egui;
+use sandbox_engine::inspector::InspectorOptions;
+
+/// Maximum nesting depth of struct fields shown.
+const MAX_FIELD_DEPTH: usize = 4;
+
+/// Editable leaf value of a reflected field.
+#[derive(Clone, PartialEq, Debug)]
+pub enum FieldValue {
+    F32(f32),
+    F64(f64),
+    I32(i32),
+    U32(u32),
+    Usize(usize),
+    Bool(bool),
+    String(String),
+    Color(Color),
+}
+
+impl FieldValue {
+    /// Reads a leaf value, if it has an editable type.
+    pub fn read(value: &dyn PartialReflect) -> Option<Self> {
+        if let Some(v) = value.try_downcast_ref::<f32>() {
+            Some(Self::F32(*v))
+        } else if let Some(v) = value.try_downcast_ref::<f64>() {
+            Some(Self::F64(*v))
+        } else if let Some(v) = value.try_downcast_ref::<i32>() {
+            Some(Self::I32(*v))
+        } else if let Some(v) = value.try_downcast_ref::<u32>() {
+            Some(Self::U32(*v))
+        } else if let Some(v) = value.try_downcast_ref::<usize>() {
+            Some(Self::Usize(*v))
+        } else if let Some(v) = value.try_downcast_ref::<bool>() {
+            Some(Self::Bool(*v))
+        } else if let Some(v) = value.try_downcast_ref::<String>() {
+            Some(Self::String(v.clone()))
+        } else {
+            value.try_downcast_ref::<Color>().map(|v| Self::Color(*v))
+        }
+    }
+
+    /// Writes this value into a reflected field of the same type.
+    pub fn write(&self, target: &mut dyn PartialReflect) {
+        match self {
+            Self::F32(v) => target.apply(v),
+            Self::F64(v) => target.apply(v),
+            Self::I32(v) => target.apply(v),
+            Self::U32(v) => target.apply(v),
+            Self::Usize(v) => target.apply(v),
+            Self::Bool(v) => target.apply(v),
+            Self::String(v) => target.apply(v),
+            Self::Color(v) => target.apply(v),
+        }
+    }
+}
+
+/// Collects the editable leaf field paths of a reflected value.
+pub fn collect_field_paths(
+    value: &dyn PartialReflect,
+    prefix: &str,
+    depth: usize,
+    out: &mut Vec<String>,
+) {
+    if FieldValue::read(value).is_some() {
+        out.push(prefix.to_string());
+        return;
+    }
+    if depth >= MAX_FIELD_DEPTH || value.try_downcast_ref::<Quat>().is_some() {
+        return;
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(s) => {
+            for index in 0..s.field_len() {
+                if let (Some(name), Some(field)) = (s.name_at(index), s.field_at(index)) {
+                    collect_field_paths(field, &format!("{}.{}", prefix, name), depth + 1, out);
+                }
+            }
+        }
+        ReflectRef::TupleStruct(s) => {
+            for index in 0..s.field_len() {
+                if let Some(field) = s.field(index) {
+                    collect_field_paths(field, &format!("{}.{}", prefix, index), depth + 1, out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Reads the leaf value at `path` from an entity's component.
+pub fn read_field(
+    world: &World,
+    entity: Entity,
+    reflect_component: &ReflectComponent,
+    path: &str,
+) -> Option<FieldValue> {
+    let component = reflect_component.reflect(world.get_entity(entity).ok()?)?;
+    FieldValue::read(component.reflect_path(path).ok()?)
+}
+
+/// Writes a leaf value at `path` into an entity's component.
+pub fn write_field(
+    world: &mut World,
+    entity: Entity,
+    reflect_component: &ReflectComponent,
+    path: &str,
+    value: &FieldValue,
+) {
+    let Ok(entity_mut) = world.get_entity_mut(entity) else {
+        return;
+    };
+    let Some(mut component) = reflect_component.reflect_mut(entity_mut) else {
+        return;
+    };
+    if let Ok(field) = component.reflect_path_mut(path) {
+        value.write(field);
+    }
+}
+
+/// Shows an editor for a field value, with "—" when values differ.
+///
+/// Returns `true` if the user changed the value.
+pub fn field_widget(ui: &mut egui::Ui, value: &mut FieldValue, mixed: bool) -> bool {
+    field_widget_with_options(ui, value, mixed, &InspectorOptions::default())
+}
+
+/// Like `field_widget`, with the range, step, and tooltip of a field's `InspectorOptions`.
+pub fn field_widget_with_options(
+    ui: &mut egui::Ui,
+    value: &mut FieldValue,
+    mixed: bool,
+    options: &InspectorOptions,
+) -> bool {
+    fn drag<N: egui::emath::Numeric>(
+        ui: &mut egui::Ui,
+        value: &mut N,
+        speed: f64,
+        mixed: bool,
+        options: &InspectorOptions,
+    ) -> bool {
+        let mut widget = options_drag(egui::DragValue::new(value), options, speed);
+        if mixed {
+            widget = widget.custom_formatter(|_, _| "—".to_string());
+        }
+        with_tooltip(ui.add(widget), options).changed()
+    }
+
+    match value {
+        FieldValue::F32(v) => drag(ui, v, 0.1, mixed, options),
+        FieldValue::F64(v) => drag(ui, v, 0.1, mixed, options),
+        FieldValue::I32(v) => drag(ui, v, 1.0, mixed, options),
+        FieldValue::U32(v) => drag(ui, v, 1.0, mixed, options),
+        FieldValue::Usize(v) => drag(ui, v, 1.0, mixed, options),
+        FieldValue::Bool(v) => with_tooltip(
+            ui.add(egui::Checkbox::new(v, "").indeterminate(mixed)),
+            options,
+        )
+        .changed(),
+        FieldValue::String(v) => {
+            if mixed {
+                v.clear();
+            }
+            let response =
+                ui.add(egui::TextEdit::singleline(v).hint_text(if mixed { "—" } else { "" }));
+            with_tooltip(response, options).changed()
+        }
+        FieldValue::Color(v) => {
+            let srgba = v.to_srgba();
+            let mut color = [srgba.red, srgba.green, srgba.blue, srgba.alpha];
+            let response = ui.color_edit_button_rgba_unmultiplied(&mut color);
+            let changed = with_tooltip(response, options).changed();
+            if mixed {
+                ui.weak("—");
+            }
+            if changed {
+                *v = Color::srgba(color[0], color[1], color[2], color[3]);
+            }
+            changed
+        }
+    }
+}
+
+/// Applies a field's range and step to a drag value; `default_speed` is used without a step.
+pub fn options_drag<'a>(
+    widget: egui::DragValue<'a>,
+    options: &InspectorOptions,
+    default_speed: f64,
+) -> egui::DragValue<'a> {
+    let widget = widget.speed(options.step.unwrap_or(default_speed));
+    match (options.min, options.max) {
+        (None, None) => widget,
+        (min, max) => widget.range(min.unwrap_or(f64::NEG_INFINITY)..=max.unwrap_or(f64::INFINITY)),
+    }
+}
+
+/// Adds a field's tooltip, if it has one, to a widget's response.
+pub fn with_tooltip(response: egui::Response, options: &InspectorOptions) -> egui::Response {
+    match options.tooltip {
+        Some(tooltip) => response.on_hover_text(tooltip),
+        None => response,
+    }
+}
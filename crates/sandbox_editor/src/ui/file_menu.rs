@@ -1,13 +1,37 @@
 // SPDX-FileCopyrightText: 2026 the Sandbox contributors
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-//! File menu for scene management operations.
+//! Menu bar: File menu for scene management, plus Edit, View, and Window menus.
 
 use bevy::prelude::*;
+use bevy::window::WindowCloseRequested;
 use bevy_egui::egui;
-use sandbox_engine::scene::{load_scene, new_scene, save_scene, spawn_prefab, SceneManager};
+use sandbox_engine::editor_state::EditorPlayState;
+use sandbox_engine::physics::PhysicsDebugSettings;
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::{
+    load_scene, new_scene, save_scene, spawn_prefab, validate_scene, SceneFormat, SceneIssue,
+    SceneManager,
+};
+use std::path::PathBuf;
 
-use super::AnimationEditorState;
+use super::entity_picker::entity_label;
+use super::hierarchy::{duplicate_selection, group_selection, ungroup_selection};
+use super::{
+    AnimationEditorState, ColorGradesWindowState, FindReplaceState, MemoryWindowState,
+    ProblemsState, SceneChangesState, SceneSearchState, SystemsWindowState, TeamsWindowState,
+    TimeOfDayWindowState, UnusedAssetsState, WatchWindowState, WorldFlagsWindowState,
+};
+use crate::autosave::AutosaveSettings;
+use crate::clipboard::{copy_selection, paste_clipboard, EditorClipboard};
+use crate::play_indicator::PlayIndicatorSettings;
+use crate::scene_lock::{foreign_lock, write_lock, LockPrompt, SceneLockState};
+use crate::scene_watch::SceneWatchState;
+use crate::selection::{navigate_selection_history, EditorSelection};
+use crate::undo::{redo, undo, UndoStack};
+
+/// Extensions offered when opening a scene or prefab; the format is detected on load.
+const SCENE_EXTENSIONS: [&str; 2] = [SceneFormat::Ron.extension(), SceneFormat::Json.extension()];
 
 /// State for tracking pending file operations.
 #[derive(Resource, Default)]
@@ -18,6 +42,33 @@ pub struct FileMenuState {
     pub success_message: Option<String>,
 }
 
+/// A save held back because [`validate_scene`] found issues in the scene.
+#[derive(Resource, Default)]
+pub struct SaveIssuesPrompt {
+    /// Where the scene is to be saved, and what's wrong with it.
+    pub pending: Option<(PathBuf, Vec<SceneIssue>)>,
+    /// What to do once the scene is saved, when saving before leaving it.
+    pub then: Option<SceneChange>,
+}
+
+/// Something that replaces or closes the open scene.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SceneChange {
+    /// Clear it for a new scene.
+    New,
+    /// Open the scene at the path, or pick one in a file dialog.
+    Open(Option<PathBuf>),
+    /// Close the editor.
+    Quit,
+}
+
+/// A scene change held back because the scene has unsaved changes.
+#[derive(Resource, Default)]
+pub struct UnsavedChangesPrompt {
+    /// The change waiting for Save, Discard, or Cancel.
+    pub pending: Option<SceneChange>,
+}
+
 /// Renders the menu bar with File menu.
 pub fn menu_bar(ctx: &egui::Context, world: &mut World) {
     // Handle keyboard shortcuts
@@ -26,12 +77,14 @@ pub fn menu_bar(ctx: &egui::Context, world: &mut World) {
     egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
         egui::menu::bar(ui, |ui| {
             file_menu(ui, world);
+            edit_menu(ui, world);
+            view_menu(ui, world);
             window_menu(ui, world);
         });
     });
 }
 
-/// Handles keyboard shortcuts for file operations.
+/// Handles keyboard shortcuts for file, edit, and selection operations.
 fn handle_keyboard_shortcuts(ctx: &egui::Context, world: &mut World) {
     // Only process shortcuts if no text input is focused
     if ctx.wants_keyboard_input() {
@@ -49,12 +102,53 @@ fn handle_keyboard_shortcuts(ctx: &egui::Context, world: &mut World) {
         }
         // Ctrl+O: Load Scene
         else if input.consume_key(egui::Modifiers::CTRL, egui::Key::O) {
-            handle_load_scene(world);
+            request_scene_change(world, SceneChange::Open(None));
         }
         // Ctrl+N: New Scene
         else if input.consume_key(egui::Modifiers::CTRL, egui::Key::N) {
-            new_scene(world);
-            set_success_message(world, "Created new scene");
+            request_scene_change(world, SceneChange::New);
+        }
+        // Ctrl+Shift+Z / Ctrl+Y: Redo
+        else if input.consume_key(egui::Modifiers::CTRL | egui::Modifiers::SHIFT, egui::Key::Z)
+            || input.consume_key(egui::Modifiers::CTRL, egui::Key::Y)
+        {
+            redo(world);
+        }
+        // Ctrl+Z: Undo
+        else if input.consume_key(egui::Modifiers::CTRL, egui::Key::Z) {
+            undo(world);
+        }
+        // Ctrl+C / Ctrl+V: Copy and paste entities
+        else if input.consume_key(egui::Modifiers::CTRL, egui::Key::C) {
+            copy_selection(world);
+        } else if input.consume_key(egui::Modifiers::CTRL, egui::Key::V) {
+            paste_clipboard(world);
+        }
+        // Ctrl+D: Duplicate
+        else if input.consume_key(egui::Modifiers::CTRL, egui::Key::D) {
+            duplicate_selection(world);
+        }
+        // Ctrl+Shift+G: Ungroup
+        else if input.consume_key(egui::Modifiers::CTRL | egui::Modifiers::SHIFT, egui::Key::G) {
+            ungroup_selection(world);
+        }
+        // Ctrl+G: Group Selection
+        else if input.consume_key(egui::Modifiers::CTRL, egui::Key::G) {
+            group_selection(world);
+        }
+        // Ctrl+F: Find in Scene
+        else if input.consume_key(egui::Modifiers::CTRL, egui::Key::F) {
+            world.resource_mut::<SceneSearchState>().open();
+        }
+        // Ctrl+H: Find and Replace
+        else if input.consume_key(egui::Modifiers::CTRL, egui::Key::H) {
+            world.resource_mut::<FindReplaceState>().open = true;
+        }
+        // Alt+Left / Alt+Right: Selection history
+        else if input.consume_key(egui::Modifiers::ALT, egui::Key::ArrowLeft) {
+            navigate_selection_history(world, -1);
+        } else if input.consume_key(egui::Modifiers::ALT, egui::Key::ArrowRight) {
+            navigate_selection_history(world, 1);
         }
     });
 }
@@ -64,8 +158,7 @@ fn file_menu(ui: &mut egui::Ui, world: &mut World) {
     ui.menu_button("File", |ui| {
         // New Scene
         if menu_item(ui, "New Scene", "Ctrl+N") {
-            new_scene(world);
-            set_success_message(world, "Created new scene");
+            request_scene_change(world, SceneChange::New);
             ui.close_menu();
         }
 
@@ -87,10 +180,47 @@ fn file_menu(ui: &mut egui::Ui, world: &mut World) {
 
         // Load Scene
         if menu_item(ui, "Load Scene...", "Ctrl+O") {
-            handle_load_scene(world);
+            request_scene_change(world, SceneChange::Open(None));
             ui.close_menu();
         }
 
+        // Reload without asking when the file changes on disk
+        let mut auto_reload = world.resource::<SceneManager>().auto_reload;
+        if ui
+            .checkbox(&mut auto_reload, "Auto-Reload Changed Scene")
+            .on_hover_text(
+                "Reload the scene when its file changes on disk, unless it has unsaved changes",
+            )
+            .changed()
+        {
+            world.resource_mut::<SceneManager>().auto_reload = auto_reload;
+        }
+
+        // Autosave interval and backups
+        ui.menu_button("Autosave", |ui| {
+            let mut settings = world.resource::<AutosaveSettings>().clone();
+            ui.checkbox(&mut settings.enabled, "Autosave Scene")
+                .on_hover_text("Write unsaved changes to a .autosave file next to the scene");
+            ui.add_enabled_ui(settings.enabled, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Every");
+                    ui.add(
+                        egui::DragValue::new(&mut settings.interval)
+                            .range(10.0..=3600.0)
+                            .suffix(" s"),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Keep");
+                    ui.add(egui::DragValue::new(&mut settings.backups).range(0..=20));
+                    ui.label("older autosaves");
+                });
+            });
+            if settings != *world.resource::<AutosaveSettings>() {
+                *world.resource_mut::<AutosaveSettings>() = settings;
+            }
+        });
+
         ui.separator();
 
         // Spawn Prefab
@@ -107,6 +237,126 @@ fn file_menu(ui: &mut egui::Ui, world: &mut World) {
     });
 }
 
+/// Renders the Edit menu.
+fn edit_menu(ui: &mut egui::Ui, world: &mut World) {
+    ui.menu_button("Edit", |ui| {
+        let (undo_label, redo_label) = {
+            let stack = world.resource::<UndoStack>();
+            (
+                stack.undo_label().map(str::to_string),
+                stack.redo_label().map(str::to_string),
+            )
+        };
+
+        // Undo
+        let label = match &undo_label {
+            Some(label) => format!("Undo {}", label),
+            None => "Undo".to_string(),
+        };
+        ui.add_enabled_ui(undo_label.is_some(), |ui| {
+            if menu_item(ui, &label, "Ctrl+Z") {
+                undo(world);
+                ui.close_menu();
+            }
+        });
+
+        // Redo
+        let label = match &redo_label {
+            Some(label) => format!("Redo {}", label),
+            None => "Redo".to_string(),
+        };
+        ui.add_enabled_ui(redo_label.is_some(), |ui| {
+            if menu_item(ui, &label, "Ctrl+Y") {
+                redo(world);
+                ui.close_menu();
+            }
+        });
+
+        ui.separator();
+
+        // Clipboard
+        if menu_item(ui, "Copy", "Ctrl+C") {
+            copy_selection(world);
+            ui.close_menu();
+        }
+        let can_paste = world.resource::<EditorClipboard>().scene.is_some();
+        if ui
+            .add_enabled_ui(can_paste, |ui| menu_item(ui, "Paste", "Ctrl+V"))
+            .inner
+        {
+            paste_clipboard(world);
+            ui.close_menu();
+        }
+        if menu_item(ui, "Duplicate", "Ctrl+D") {
+            duplicate_selection(world);
+            ui.close_menu();
+        }
+
+        // Grouping
+        if menu_item(ui, "Group Selection", "Ctrl+G") {
+            group_selection(world);
+            ui.close_menu();
+        }
+        if menu_item(ui, "Ungroup", "Ctrl+Shift+G") {
+            ungroup_selection(world);
+            ui.close_menu();
+        }
+
+        ui.separator();
+
+        // Search
+        if menu_item(ui, "Find in Scene...", "Ctrl+F") {
+            world.resource_mut::<SceneSearchState>().open();
+            ui.close_menu();
+        }
+        if menu_item(ui, "Find and Replace...", "Ctrl+H") {
+            world.resource_mut::<FindReplaceState>().open = true;
+            ui.close_menu();
+        }
+    });
+}
+
+/// Renders the View menu.
+fn view_menu(ui: &mut egui::Ui, world: &mut World) {
+    ui.menu_button("View", |ui| {
+        let Some(mut settings) = world.get_resource::<PhysicsDebugSettings>().cloned() else {
+            return;
+        };
+
+        ui.menu_button("Physics Debug", |ui| {
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut settings.enabled, "Show");
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.weak("F3");
+                });
+            });
+            ui.separator();
+            ui.add_enabled_ui(settings.enabled, |ui| {
+                ui.checkbox(&mut settings.colliders, "Colliders");
+                ui.checkbox(&mut settings.sensors, "Trigger Areas");
+                ui.checkbox(&mut settings.velocities, "Velocities");
+                ui.checkbox(&mut settings.contacts, "Contact Points");
+            });
+            if *world.resource::<State<EditorPlayState>>().get() == EditorPlayState::Stopped {
+                ui.weak("Velocities and contacts update in play mode.");
+            }
+        });
+
+        if *world.resource::<PhysicsDebugSettings>() != settings {
+            *world.resource_mut::<PhysicsDebugSettings>() = settings;
+        }
+
+        let mut dim_panels = world.resource::<PlayIndicatorSettings>().dim_panels;
+        if ui
+            .checkbox(&mut dim_panels, "Dim Panels During Play")
+            .on_hover_text("Fade the hierarchy, inspector, and assets while playing; the scene is restored on Stop")
+            .changed()
+        {
+            world.resource_mut::<PlayIndicatorSettings>().dim_panels = dim_panels;
+        }
+    });
+}
+
 /// Renders the Window menu.
 fn window_menu(ui: &mut egui::Ui, world: &mut World) {
     ui.menu_button("Window", |ui| {
@@ -117,6 +367,66 @@ fn window_menu(ui: &mut egui::Ui, world: &mut World) {
             }
             ui.close_menu();
         }
+
+        // World Flags
+        if ui.button("World Flags").clicked() {
+            world.resource_mut::<WorldFlagsWindowState>().open = true;
+            ui.close_menu();
+        }
+
+        // Team names and relations
+        if ui.button("Teams").clicked() {
+            world.resource_mut::<TeamsWindowState>().open = true;
+            ui.close_menu();
+        }
+
+        // Time of day and tint gradient
+        if ui.button("Time of Day").clicked() {
+            world.resource_mut::<TimeOfDayWindowState>().open = true;
+            ui.close_menu();
+        }
+
+        // Tint and brightness per color group
+        if ui.button("Color Grades").clicked() {
+            world.resource_mut::<ColorGradesWindowState>().open = true;
+            ui.close_menu();
+        }
+
+        // Live field values and graphs
+        if ui.button("Watch").clicked() {
+            world.resource_mut::<WatchWindowState>().open = true;
+            ui.close_menu();
+        }
+
+        // Per-system enable/disable toggles
+        if ui.button("Systems").clicked() {
+            world.resource_mut::<SystemsWindowState>().open = true;
+            ui.close_menu();
+        }
+
+        // Memory diagnostics
+        if ui.button("Memory").clicked() {
+            world.resource_mut::<MemoryWindowState>().open = true;
+            ui.close_menu();
+        }
+
+        // Unused asset finder
+        if ui.button("Unused Assets").clicked() {
+            world.resource_mut::<UnusedAssetsState>().open();
+            ui.close_menu();
+        }
+
+        // Broken asset references
+        if ui.button("Problems").clicked() {
+            world.resource_mut::<ProblemsState>().open();
+            ui.close_menu();
+        }
+
+        // Unsaved changes compared with the scene file
+        if ui.button("Changes").clicked() {
+            world.resource_mut::<SceneChangesState>().open();
+            ui.close_menu();
+        }
     });
 }
 
@@ -132,35 +442,152 @@ fn menu_item(ui: &mut egui::Ui, label: &str, shortcut: &str) -> bool {
     .inner
 }
 
+/// Returns the absolute path of a subdirectory of the project's assets directory.
+fn asset_dir(world: &World, subdirectory: &str) -> PathBuf {
+    world
+        .get_resource::<ProjectRoot>()
+        .map(|root| root.resolve_asset(subdirectory))
+        .unwrap_or_else(|| PathBuf::from("assets").join(subdirectory))
+}
+
 /// Handles saving a scene.
 fn handle_save_scene(world: &mut World, force_dialog: bool) {
+    if let Some(path) = save_path(world, force_dialog) {
+        request_save_scene(world, path, None);
+    }
+}
+
+/// Where to save the scene: its current path, or one picked in a save
+/// dialog if it has none or `force_dialog` is set.
+fn save_path(world: &World, force_dialog: bool) -> Option<PathBuf> {
     // Check if we have an existing path and don't need to show dialog
     let existing_path = world
         .get_resource::<SceneManager>()
         .and_then(|m| m.current_scene_path.clone());
 
-    let path = if force_dialog || existing_path.is_none() {
+    if force_dialog || existing_path.is_none() {
         // Show save dialog
         let dialog = rfd::FileDialog::new()
             .set_title("Save Scene")
-            .add_filter("Scene files", &["scn.ron"])
-            .set_directory("assets/scenes")
-            .set_file_name("scene.scn.ron");
+            .add_filter("Scene files (RON)", &[SceneFormat::Ron.extension()])
+            .add_filter("Scene files (JSON)", &[SceneFormat::Json.extension()])
+            .set_directory(asset_dir(world, "scenes"))
+            .set_file_name(format!(
+                "scene.{}",
+                existing_path
+                    .as_deref()
+                    .map(SceneFormat::from_path)
+                    .unwrap_or_default()
+                    .extension()
+            ));
 
         dialog.save_file()
     } else {
         existing_path
-    };
+    }
+}
 
-    if let Some(path) = path {
-        match save_scene(world, &path) {
-            Ok(()) => {
-                set_success_message(world, &format!("Saved: {}", path.display()));
+/// Saves the scene to `path`, first asking if [`validate_scene`] finds issues,
+/// then carries out `then` if the save succeeded.
+fn request_save_scene(world: &mut World, path: PathBuf, then: Option<SceneChange>) {
+    let issues = validate_scene(world);
+    if issues.is_empty() {
+        if write_scene(world, &path) {
+            if let Some(change) = then {
+                apply_scene_change(world, change);
             }
-            Err(e) => {
-                set_error_message(world, &format!("Failed to save: {}", e));
+        }
+    } else {
+        *world.resource_mut::<SaveIssuesPrompt>() = SaveIssuesPrompt {
+            pending: Some((path, issues)),
+            then,
+        };
+    }
+}
+
+/// Saves the scene and reports the result.
+///
+/// Returns whether the scene was saved.
+fn write_scene(world: &mut World, path: &std::path::Path) -> bool {
+    match save_scene(world, path) {
+        Ok(()) => {
+            set_success_message(world, &format!("Saved: {}", path.display()));
+            true
+        }
+        Err(e) => {
+            set_error_message(world, &format!("Failed to save: {}", e));
+            false
+        }
+    }
+}
+
+/// Save issues prompt action to perform after UI interaction.
+enum SaveIssuesAction {
+    None,
+    SaveAnyway,
+    Cancel,
+    Select(Entity),
+}
+
+/// Lists the issues found before saving, so they can be fixed or saved anyway.
+pub fn save_issues_prompt(ctx: &egui::Context, world: &mut World) {
+    let Some((path, issues)) = world.resource::<SaveIssuesPrompt>().pending.clone() else {
+        return;
+    };
+
+    let mut action = SaveIssuesAction::None;
+    egui::Window::new("Scene Has Problems")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label(format!(
+                "Saving {} would lose or break data:",
+                path.file_name().unwrap_or_default().to_string_lossy()
+            ));
+            egui::ScrollArea::vertical()
+                .max_height(240.0)
+                .show(ui, |ui| {
+                    for issue in &issues {
+                        let entity = issue.entity();
+                        ui.horizontal(|ui| {
+                            ui.weak(entity_label(world, entity));
+                            ui.label(issue.message());
+                            if ui.small_button("Select").clicked() {
+                                action = SaveIssuesAction::Select(entity);
+                            }
+                        });
+                    }
+                });
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui.button("Save Anyway").clicked() {
+                    action = SaveIssuesAction::SaveAnyway;
+                }
+                if ui.button("Cancel").clicked() {
+                    action = SaveIssuesAction::Cancel;
+                }
+            });
+        });
+
+    match action {
+        SaveIssuesAction::SaveAnyway => {
+            let then = std::mem::take(&mut *world.resource_mut::<SaveIssuesPrompt>()).then;
+            if write_scene(world, &path) {
+                if let Some(change) = then {
+                    apply_scene_change(world, change);
+                }
             }
         }
+        SaveIssuesAction::Cancel => {
+            *world.resource_mut::<SaveIssuesPrompt>() = SaveIssuesPrompt::default();
+        }
+        SaveIssuesAction::Select(entity) => {
+            // Fixing the issue is up to the user, so the save is called off
+            *world.resource_mut::<SaveIssuesPrompt>() = SaveIssuesPrompt::default();
+            world.resource_mut::<EditorSelection>().select(entity);
+        }
+        SaveIssuesAction::None => {}
     }
 }
 
@@ -168,18 +595,313 @@ fn handle_save_scene(world: &mut World, force_dialog: bool) {
 fn handle_load_scene(world: &mut World) {
     let dialog = rfd::FileDialog::new()
         .set_title("Load Scene")
-        .add_filter("Scene files", &["scn.ron"])
-        .set_directory("assets/scenes");
+        .add_filter("Scene files", &SCENE_EXTENSIONS)
+        .set_directory(asset_dir(world, "scenes"));
 
     if let Some(path) = dialog.pick_file() {
-        match load_scene(world, &path) {
-            Ok(()) => {
-                set_success_message(world, &format!("Loaded: {}", path.display()));
+        open_unless_locked(world, &path);
+    }
+}
+
+/// Replaces or closes the scene, first asking to save unsaved changes.
+pub(crate) fn request_scene_change(world: &mut World, change: SceneChange) {
+    if world.resource::<SceneManager>().dirty {
+        world.resource_mut::<UnsavedChangesPrompt>().pending = Some(change);
+    } else {
+        apply_scene_change(world, change);
+    }
+}
+
+/// Replaces or closes the scene without asking about unsaved changes.
+fn apply_scene_change(world: &mut World, change: SceneChange) {
+    match change {
+        SceneChange::New => {
+            start_new_scene(world);
+            set_success_message(world, "Created new scene");
+        }
+        SceneChange::Open(None) => handle_load_scene(world),
+        SceneChange::Open(Some(path)) => {
+            open_unless_locked(world, &path);
+        }
+        SceneChange::Quit => {
+            world.send_event(AppExit::Success);
+        }
+    }
+}
+
+/// Opens a scene, first asking to save unsaved changes and warning if
+/// someone else has it open.
+///
+/// Returns whether the scene was loaded right away.
+pub(crate) fn request_open_scene(world: &mut World, path: &std::path::Path) -> bool {
+    if world.resource::<SceneManager>().dirty {
+        request_scene_change(world, SceneChange::Open(Some(path.to_path_buf())));
+        return false;
+    }
+    open_unless_locked(world, path)
+}
+
+/// Opens a scene, first asking if someone else has it open.
+///
+/// Returns whether the scene was loaded right away.
+fn open_unless_locked(world: &mut World, path: &std::path::Path) -> bool {
+    // Warn before opening a scene someone else has open
+    if let Some(lock) = foreign_lock(path) {
+        world.resource_mut::<SceneLockState>().prompt = Some(LockPrompt {
+            scene_path: path.to_path_buf(),
+            lock,
+        });
+        return false;
+    }
+    open_scene(world, path)
+}
+
+/// Clears the scene and the undo history.
+fn start_new_scene(world: &mut World) {
+    new_scene(world);
+    world.resource_mut::<UndoStack>().clear();
+}
+
+/// Loads a scene and reports the result.
+fn open_scene(world: &mut World, path: &std::path::Path) -> bool {
+    match load_scene(world, path) {
+        Ok(()) => {
+            world.resource_mut::<UndoStack>().clear();
+            set_success_message(world, &format!("Loaded: {}", path.display()));
+            true
+        }
+        Err(e) => {
+            set_error_message(world, &format!("Failed to load: {}", e));
+            false
+        }
+    }
+}
+
+/// Unsaved changes prompt action to perform after UI interaction.
+enum UnsavedChangesAction {
+    None,
+    Save,
+    Discard,
+    Cancel,
+    ShowChanges,
+}
+
+/// Asks whether to save the scene's unsaved changes before replacing or
+/// closing it.
+pub fn unsaved_changes_prompt(ctx: &egui::Context, world: &mut World) {
+    let Some(change) = world.resource::<UnsavedChangesPrompt>().pending.clone() else {
+        return;
+    };
+    let scene_name = world
+        .resource::<SceneManager>()
+        .current_scene_path
+        .as_ref()
+        .and_then(|path| path.file_name())
+        .map(|name| name.to_string_lossy().into_owned());
+
+    let mut action = UnsavedChangesAction::None;
+    egui::Window::new("Save Changes?")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            match &scene_name {
+                Some(name) => ui.label(format!("{} has unsaved changes.", name)),
+                None => ui.label("The scene has unsaved changes and was never saved."),
+            };
+            ui.label(match change {
+                SceneChange::New | SceneChange::Open(_) => "Save them before leaving the scene?",
+                SceneChange::Quit => "Save them before quitting?",
+            });
+            if ui.small_button("Show Changes").clicked() {
+                action = UnsavedChangesAction::ShowChanges;
             }
-            Err(e) => {
-                set_error_message(world, &format!("Failed to load: {}", e));
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui.button("Save").clicked() {
+                    action = UnsavedChangesAction::Save;
+                }
+                if ui.button("Discard").clicked() {
+                    action = UnsavedChangesAction::Discard;
+                }
+                if ui.button("Cancel").clicked() {
+                    action = UnsavedChangesAction::Cancel;
+                }
+            });
+        });
+
+    match action {
+        UnsavedChangesAction::Save => {
+            world.resource_mut::<UnsavedChangesPrompt>().pending = None;
+            // Cancelling the save dialog keeps the scene open
+            if let Some(path) = save_path(world, false) {
+                request_save_scene(world, path, Some(change));
+            }
+        }
+        UnsavedChangesAction::Discard => {
+            world.resource_mut::<UnsavedChangesPrompt>().pending = None;
+            apply_scene_change(world, change);
+        }
+        UnsavedChangesAction::Cancel => {
+            world.resource_mut::<UnsavedChangesPrompt>().pending = None;
+        }
+        UnsavedChangesAction::ShowChanges => {
+            world.resource_mut::<SceneChangesState>().open();
+        }
+        UnsavedChangesAction::None => {}
+    }
+}
+
+/// Quits when the window's close button is pressed, first asking to save
+/// unsaved changes.
+///
+/// Needs `WindowPlugin::close_when_requested` off, or the window closes
+/// before the prompt can be answered.
+pub fn confirm_window_close(
+    mut requests: EventReader<WindowCloseRequested>,
+    manager: Res<SceneManager>,
+    mut prompt: ResMut<UnsavedChangesPrompt>,
+    mut exit: EventWriter<AppExit>,
+) {
+    if requests.read().count() == 0 {
+        return;
+    }
+    if manager.dirty {
+        prompt.pending = Some(SceneChange::Quit);
+    } else {
+        exit.send(AppExit::Success);
+    }
+}
+
+/// Lock prompt action to perform after UI interaction.
+enum LockPromptAction {
+    None,
+    OpenAnyway,
+    TakeOver,
+    Cancel,
+}
+
+/// Displays the warning for opening a scene locked by someone else.
+pub fn scene_lock_prompt(ctx: &egui::Context, world: &mut World) {
+    let Some(prompt) = world.resource::<SceneLockState>().prompt.clone() else {
+        return;
+    };
+    let stale = prompt.lock.is_stale();
+
+    let mut action = LockPromptAction::None;
+    egui::Window::new("Scene Locked")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label(format!("{}", prompt.scene_path.display()));
+            ui.label(format!("is locked by {}.", prompt.lock.describe()));
+            if stale {
+                ui.label("The lock looks stale. Take it over if that editor is no longer running.");
+            } else {
+                ui.label("Saving may overwrite their changes.");
             }
+            ui.separator();
+            ui.horizontal(|ui| {
+                if stale {
+                    if ui.button("Take Over").clicked() {
+                        action = LockPromptAction::TakeOver;
+                    }
+                } else if ui.button("Open Anyway").clicked() {
+                    action = LockPromptAction::OpenAnyway;
+                }
+                if ui.button("Cancel").clicked() {
+                    action = LockPromptAction::Cancel;
+                }
+            });
+        });
+
+    match action {
+        LockPromptAction::OpenAnyway => {
+            world.resource_mut::<SceneLockState>().prompt = None;
+            open_scene(world, &prompt.scene_path);
+        }
+        LockPromptAction::TakeOver => {
+            world.resource_mut::<SceneLockState>().prompt = None;
+            if let Err(e) = write_lock(&prompt.scene_path) {
+                set_error_message(world, &format!("Failed to take over lock: {}", e));
+                return;
+            }
+            open_scene(world, &prompt.scene_path);
+        }
+        LockPromptAction::Cancel => {
+            world.resource_mut::<SceneLockState>().prompt = None;
+        }
+        LockPromptAction::None => {}
+    }
+}
+
+/// Reload prompt action to perform after UI interaction.
+enum ReloadPromptAction {
+    None,
+    Reload,
+    Keep,
+}
+
+/// Offers to reload the scene when its file changed on disk.
+///
+/// Waits until play has stopped. With `SceneManager::auto_reload` a scene
+/// without unsaved changes is reloaded without asking.
+pub fn scene_reload_prompt(ctx: &egui::Context, world: &mut World) {
+    let Some(path) = world.resource::<SceneWatchState>().changed.clone() else {
+        return;
+    };
+    if *world.resource::<State<EditorPlayState>>().get() != EditorPlayState::Stopped {
+        return;
+    }
+    let (dirty, auto_reload) = {
+        let manager = world.resource::<SceneManager>();
+        (manager.dirty, manager.auto_reload)
+    };
+
+    let mut action = ReloadPromptAction::None;
+    if auto_reload && !dirty {
+        action = ReloadPromptAction::Reload;
+    } else {
+        egui::Window::new("Scene Changed on Disk")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(format!("{}", path.display()));
+                ui.label("was changed outside the editor.");
+                if dirty {
+                    ui.label("Reloading discards your unsaved changes.");
+                }
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Reload").clicked() {
+                        action = ReloadPromptAction::Reload;
+                    }
+                    if ui.button("Keep Mine").clicked() {
+                        action = ReloadPromptAction::Keep;
+                    }
+                });
+            });
+    }
+
+    match action {
+        ReloadPromptAction::Reload => {
+            world.resource_mut::<SceneWatchState>().changed = None;
+            match load_scene(world, &path) {
+                Ok(()) => {
+                    world.resource_mut::<UndoStack>().clear();
+                    set_success_message(world, &format!("Reloaded: {}", path.display()));
+                }
+                Err(e) => set_error_message(world, &format!("Failed to reload: {}", e)),
+            }
+        }
+        ReloadPromptAction::Keep => {
+            world.resource_mut::<SceneWatchState>().acknowledge();
+            // The file no longer matches the editor's scene
+            world.resource_mut::<SceneManager>().mark_dirty();
         }
+        ReloadPromptAction::None => {}
     }
 }
 
@@ -187,8 +909,8 @@ fn handle_load_scene(world: &mut World) {
 fn handle_spawn_prefab(world: &mut World) {
     let dialog = rfd::FileDialog::new()
         .set_title("Spawn Prefab")
-        .add_filter("Prefab files", &["scn.ron"])
-        .set_directory("assets/prefabs");
+        .add_filter("Prefab files", &SCENE_EXTENSIONS)
+        .set_directory(asset_dir(world, "prefabs"));
 
     if let Some(path) = dialog.pick_file() {
         match spawn_prefab(world, &path) {
@@ -211,8 +933,9 @@ fn handle_save_prefab(world: &mut World) {
 
     let dialog = rfd::FileDialog::new()
         .set_title("Save as Prefab")
-        .add_filter("Prefab files", &["scn.ron"])
-        .set_directory("assets/prefabs")
+        .add_filter("Prefab files (RON)", &[SceneFormat::Ron.extension()])
+        .add_filter("Prefab files (JSON)", &[SceneFormat::Json.extension()])
+        .set_directory(asset_dir(world, "prefabs"))
         .set_file_name("prefab.scn.ron");
 
     if let Some(path) = dialog.save_file() {
@@ -285,7 +1008,8 @@ pub fn status_messages(ctx: &egui::Context, world: &mut World) {
     }
 }
 
-fn set_error_message(world: &mut World, message: &str) {
+/// Shows an error in the status bar.
+pub fn set_error_message(world: &mut World, message: &str) {
     if !world.contains_resource::<FileMenuState>() {
         world.init_resource::<FileMenuState>();
     }
@@ -295,7 +1019,8 @@ fn set_error_message(world: &mut World, message: &str) {
     }
 }
 
-fn set_success_message(world: &mut World, message: &str) {
+/// Shows a success message in the status bar.
+pub fn set_success_message(world: &mut World, message: &str) {
     if !world.contains_resource::<FileMenuState>() {
         world.init_resource::<FileMenuState>();
     }
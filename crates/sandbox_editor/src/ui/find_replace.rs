@@ -0,0 +1,312 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Find-and-replace of component field values across the scene.
+//!
+//! Pick a component and field, enter the value to find, and every entity whose
+//! field matches is listed for preview. "Replace All" writes the replacement
+//! to all matches as one undo step.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::scene::SceneManager;
+use std::any::TypeId;
+use std::collections::BTreeMap;
+
+use super::entity_picker::entity_label;
+use super::fields::{collect_field_paths, field_widget, read_field, write_field, FieldValue};
+use super::file_menu::set_success_message;
+use crate::selection::EditorSelection;
+use crate::undo::{record_component_edit, snapshot_components};
+
+/// State for the find-and-replace window.
+#[derive(Resource, Default)]
+pub struct FindReplaceState {
+    /// Whether the window is open.
+    pub open: bool,
+    /// Type path of the component to search.
+    pub component: Option<String>,
+    /// Field path within the component, e.g. `.path`.
+    pub field: Option<String>,
+    /// Value to search for.
+    pub find: Option<FieldValue>,
+    /// Value to write to matches.
+    pub replace: Option<FieldValue>,
+    /// Match strings that contain the search text instead of equal it.
+    pub substring: bool,
+}
+
+/// Action to perform after UI interaction.
+enum FindReplaceAction {
+    None,
+    Select(Entity),
+    SelectAll,
+    ReplaceAll,
+}
+
+/// Displays the find-and-replace window.
+pub fn find_replace_window(ctx: &egui::Context, world: &mut World) {
+    if !world.resource::<FindReplaceState>().open {
+        return;
+    }
+
+    let components = scene_components(world);
+    let mut state = std::mem::take(&mut *world.resource_mut::<FindReplaceState>());
+    let mut action = FindReplaceAction::None;
+    let mut matches = Vec::new();
+
+    egui::Window::new("Find and Replace")
+        .open(&mut state.open)
+        .default_width(360.0)
+        .show(ctx, |ui| {
+            // Component selection
+            let mut component = state.component.clone();
+            egui::ComboBox::from_label("Component")
+                .selected_text(
+                    component
+                        .as_deref()
+                        .map(short_name)
+                        .unwrap_or("Select component"),
+                )
+                .show_ui(ui, |ui| {
+                    for type_path in components.keys() {
+                        ui.selectable_value(
+                            &mut component,
+                            Some(type_path.clone()),
+                            short_name(type_path),
+                        );
+                    }
+                });
+            if component != state.component {
+                state.component = component;
+                state.field = None;
+                state.find = None;
+                state.replace = None;
+            }
+
+            let Some(&(type_id, ref fields, sample)) = state
+                .component
+                .as_ref()
+                .and_then(|type_path| components.get(type_path))
+            else {
+                return;
+            };
+            let reflect_component = reflect_component_of(world, type_id);
+
+            // Field selection
+            let mut field = state.field.clone();
+            egui::ComboBox::from_label("Field")
+                .selected_text(field.as_deref().unwrap_or("Select field"))
+                .show_ui(ui, |ui| {
+                    for path in fields {
+                        ui.selectable_value(&mut field, Some(path.clone()), path.as_str());
+                    }
+                });
+            if field != state.field {
+                // Seed both values with the sample entity's value so widgets have the right type
+                let template = field
+                    .as_ref()
+                    .and_then(|path| read_field(world, sample, &reflect_component, path));
+                state.field = field;
+                state.find = template.clone();
+                state.replace = template;
+            }
+
+            let (Some(field), Some(find), Some(replace)) =
+                (&state.field, &mut state.find, &mut state.replace)
+            else {
+                return;
+            };
+
+            ui.separator();
+            egui::Grid::new("find_replace_values")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Find:");
+                    field_widget(ui, find, false);
+                    ui.end_row();
+
+                    ui.label("Replace with:");
+                    field_widget(ui, replace, false);
+                    ui.end_row();
+                });
+            if matches!(find, FieldValue::String(_)) {
+                ui.checkbox(&mut state.substring, "Match substring");
+            }
+
+            // Preview list
+            matches = find_matches(world, &reflect_component, field, find, state.substring);
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.label(format!("{} match(es)", matches.len()));
+                if ui
+                    .add_enabled(!matches.is_empty(), egui::Button::new("Select All"))
+                    .clicked()
+                {
+                    action = FindReplaceAction::SelectAll;
+                }
+                if ui
+                    .add_enabled(!matches.is_empty(), egui::Button::new("Replace All"))
+                    .clicked()
+                {
+                    action = FindReplaceAction::ReplaceAll;
+                }
+            });
+
+            egui::ScrollArea::vertical()
+                .max_height(200.0)
+                .show(ui, |ui| {
+                    for &entity in &matches {
+                        if ui
+                            .selectable_label(false, entity_label(world, entity))
+                            .clicked()
+                        {
+                            action = FindReplaceAction::Select(entity);
+                        }
+                    }
+                });
+        });
+
+    *world.resource_mut::<FindReplaceState>() = state;
+
+    match action {
+        FindReplaceAction::Select(entity) => {
+            world.resource_mut::<EditorSelection>().select(entity);
+        }
+        FindReplaceAction::SelectAll => {
+            world.resource_mut::<EditorSelection>().set(matches);
+        }
+        FindReplaceAction::ReplaceAll => replace_all(world, &components, &matches),
+        FindReplaceAction::None => {}
+    }
+}
+
+/// Writes the replacement value to every match as a single undo step.
+fn replace_all(
+    world: &mut World,
+    components: &BTreeMap<String, (TypeId, Vec<String>, Entity)>,
+    matches: &[Entity],
+) {
+    let state = world.resource::<FindReplaceState>();
+    let (Some(type_path), Some(field), Some(find), Some(replace)) = (
+        state.component.clone(),
+        state.field.clone(),
+        state.find.clone(),
+        state.replace.clone(),
+    ) else {
+        return;
+    };
+    let substring = state.substring;
+    let Some(&(type_id, _, _)) = components.get(&type_path) else {
+        return;
+    };
+    let reflect_component = reflect_component_of(world, type_id);
+
+    let before = snapshot_components(world, matches, type_id);
+    for &entity in matches {
+        let value = match (&find, &replace) {
+            (FieldValue::String(find), FieldValue::String(replace)) if substring => {
+                let Some(FieldValue::String(current)) =
+                    read_field(world, entity, &reflect_component, &field)
+                else {
+                    continue;
+                };
+                FieldValue::String(current.replace(find.as_str(), replace))
+            }
+            _ => replace.clone(),
+        };
+        write_field(world, entity, &reflect_component, &field, &value);
+    }
+    record_component_edit(
+        world,
+        format!("Replace {}{}", short_name(&type_path), field),
+        type_id,
+        before,
+    );
+
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
+    }
+    set_success_message(world, &format!("Replaced {} value(s)", matches.len()));
+}
+
+/// Returns the entities whose field matches the search value.
+fn find_matches(
+    world: &mut World,
+    reflect_component: &ReflectComponent,
+    field: &str,
+    find: &FieldValue,
+    substring: bool,
+) -> Vec<Entity> {
+    let entities: Vec<Entity> = world.query::<Entity>().iter(world).collect();
+    let mut matches: Vec<Entity> = entities
+        .into_iter()
+        .filter(|&entity| {
+            let Some(value) = read_field(world, entity, reflect_component, field) else {
+                return false;
+            };
+            match (&value, find) {
+                (FieldValue::String(value), FieldValue::String(find)) if substring => {
+                    !find.is_empty() && value.contains(find.as_str())
+                }
+                _ => &value == find,
+            }
+        })
+        .collect();
+    matches.sort_by_key(|entity| entity.index());
+    matches
+}
+
+/// Collects the reflected components present in the scene, keyed by type path,
+/// with their editable field paths and an entity that has them.
+fn scene_components(world: &mut World) -> BTreeMap<String, (TypeId, Vec<String>, Entity)> {
+    let mut components = BTreeMap::new();
+
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let entities: Vec<Entity> = world.query::<Entity>().iter(world).collect();
+
+    for entity in entities {
+        for info in world.inspect_entity(entity) {
+            let Some(type_id) = info.type_id() else {
+                continue;
+            };
+            let Some(registration) = type_registry.get(type_id) else {
+                continue;
+            };
+            let type_path = registration.type_info().type_path();
+            if components.contains_key(type_path) {
+                continue;
+            }
+            let Some(value) = registration
+                .data::<ReflectComponent>()
+                .and_then(|reflect_component| reflect_component.reflect(world.entity(entity)))
+            else {
+                continue;
+            };
+
+            let mut fields = Vec::new();
+            collect_field_paths(value.as_partial_reflect(), "", 0, &mut fields);
+            if !fields.is_empty() {
+                components.insert(type_path.to_string(), (type_id, fields, entity));
+            }
+        }
+    }
+
+    components
+}
+
+fn reflect_component_of(world: &World, type_id: TypeId) -> ReflectComponent {
+    world
+        .resource::<AppTypeRegistry>()
+        .read()
+        .get(type_id)
+        .and_then(|registration| registration.data::<ReflectComponent>())
+        .cloned()
+        .expect("scene components are reflect-registered")
+}
+
+fn short_name(type_path: &str) -> &str {
+    type_path.rsplit("::").next().unwrap_or(type_path)
+}
@@ -4,15 +4,50 @@
 //! Scene hierarchy panel for the editor.
 
 use bevy::prelude::*;
+use bevy::utils::HashSet;
 use bevy_egui::egui;
-use sandbox_engine::scene::SceneManager;
-
-use crate::selection::EditorSelection;
+use sandbox_engine::ambient::AmbientPreset;
+use sandbox_engine::assets::AssetPath;
+use sandbox_engine::bitmap_font::BitmapGlyph;
+use sandbox_engine::particles::Particle;
+use sandbox_engine::scene::{EditorGroup, EditorLocked, EditorNote, SceneManager};
+use std::path::Path;
+
+use super::entity_picker::try_consume_pick;
+use crate::clipboard::{
+    copy_selection, extract_subtrees, paste_clipboard, spawn_subtrees, EditorClipboard,
+};
+use crate::notes::{note_color, NOTE_ICON};
+use crate::selection::{navigate_selection_history, EditorSelection, SelectionHistory};
+use crate::undo::{record_component_edit, snapshot_components};
+
+/// Offset applied to duplicated entities so the copy is visible next to the original.
+const DUPLICATE_OFFSET: Vec3 = Vec3::new(16.0, -16.0, 0.0);
+
+/// Resource tracking which hierarchy nodes are collapsed.
+///
+/// Nodes are expanded unless listed here, so new entities appear expanded.
+#[derive(Resource, Default)]
+pub struct HierarchyState {
+    /// Entities whose children are hidden.
+    pub collapsed: HashSet<Entity>,
+}
 
 /// Counter for generating unique entity names.
 #[derive(Resource, Default)]
 pub struct EntityCounter(pub u32);
 
+/// Action to perform after UI interaction.
+enum HierarchyAction {
+    None,
+    Copy,
+    Paste,
+    Duplicate,
+    Delete,
+    ToggleVisibility(Entity),
+    ToggleLock(Entity),
+}
+
 /// Displays the scene hierarchy panel.
 pub fn hierarchy_panel(ui: &mut egui::Ui, world: &mut World) {
     ui.heading("Hierarchy");
@@ -20,11 +55,33 @@ pub fn hierarchy_panel(ui: &mut egui::Ui, world: &mut World) {
     // Add Entity button
     ui.horizontal(|ui| {
         if ui.button("+ Add Entity").clicked() {
-            add_new_entity(world);
+            add_new_entity(world, Vec2::ZERO);
         }
         if ui.button("Delete").clicked() {
             delete_selected_entity(world);
         }
+
+        // Selection history navigation
+        let (can_go_back, can_go_forward) = {
+            let history = world.resource::<SelectionHistory>();
+            (history.can_go_back(), history.can_go_forward())
+        };
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            if ui
+                .add_enabled(can_go_forward, egui::Button::new("▶"))
+                .on_hover_text("Forward (Alt+Right)")
+                .clicked()
+            {
+                navigate_selection_history(world, 1);
+            }
+            if ui
+                .add_enabled(can_go_back, egui::Button::new("◀"))
+                .on_hover_text("Back (Alt+Left)")
+                .clicked()
+            {
+                navigate_selection_history(world, -1);
+            }
+        });
     });
 
     ui.separator();
@@ -42,10 +99,10 @@ pub fn hierarchy_panel(ui: &mut egui::Ui, world: &mut World) {
             entity_data.push((entity, name_str, has_children));
         }
 
-        // Filter to root entities (no Parent component)
+        // Filter to root entities (no Parent component), leaving out particles and glyphs
         let mut parent_query = world.query::<&Parent>();
         for (entity, name_str, has_children) in &entity_data {
-            if parent_query.get(world, *entity).is_err() {
+            if parent_query.get(world, *entity).is_err() && !is_runtime_child(world, *entity) {
                 root_entities.push(*entity);
             }
             let _ = (name_str, has_children); // Suppress unused warning
@@ -55,25 +112,61 @@ pub fn hierarchy_panel(ui: &mut egui::Ui, world: &mut World) {
     // Sort by entity index for consistent ordering
     root_entities.sort_by_key(|e| e.index());
 
-    let selected_entity = world.resource::<EditorSelection>().selected_entity;
+    // Forget despawned entities so the collapsed set doesn't grow unbounded
+    let collapsed: Vec<Entity> = world
+        .resource::<HierarchyState>()
+        .collapsed
+        .iter()
+        .copied()
+        .collect();
+    for entity in collapsed {
+        if world.get_entity(entity).is_err() {
+            world
+                .resource_mut::<HierarchyState>()
+                .collapsed
+                .remove(&entity);
+        }
+    }
+
+    let selected = world.resource::<EditorSelection>().entities.clone();
+    let mut action = HierarchyAction::None;
 
     // Display hierarchy
     egui::ScrollArea::vertical()
         .auto_shrink([false; 2])
         .show(ui, |ui| {
             for entity in root_entities {
-                display_entity_tree(ui, world, entity, selected_entity, 0);
+                display_entity_tree(ui, world, entity, &selected, 0, &mut action);
             }
         });
+
+    match action {
+        HierarchyAction::Copy => copy_selection(world),
+        HierarchyAction::Paste => paste_clipboard(world),
+        HierarchyAction::Duplicate => duplicate_selection(world),
+        HierarchyAction::Delete => delete_selected_entity(world),
+        HierarchyAction::ToggleVisibility(entity) => toggle_visibility(world, entity),
+        HierarchyAction::ToggleLock(entity) => toggle_lock(world, entity),
+        HierarchyAction::None => {}
+    }
+}
+
+/// Whether `entity` is a particle or a bitmap text glyph: runtime-only
+/// children that come and go too often to list.
+fn is_runtime_child(world: &World, entity: Entity) -> bool {
+    world.get::<Particle>(entity).is_some() || world.get::<BitmapGlyph>(entity).is_some()
 }
 
 /// Recursively displays an entity and its children in the hierarchy.
+///
+/// Ctrl+click adds or removes an entity from the selection.
 fn display_entity_tree(
     ui: &mut egui::Ui,
     world: &mut World,
     entity: Entity,
-    selected_entity: Option<Entity>,
+    selected: &[Entity],
     depth: usize,
+    action: &mut HierarchyAction,
 ) {
     let indent = depth as f32 * 16.0;
 
@@ -82,36 +175,189 @@ fn display_entity_tree(
         let name = world.get::<Name>(entity).map(|n| n.to_string());
         let children: Vec<Entity> = world
             .get::<Children>(entity)
-            .map(|c| c.iter().copied().collect())
+            .map(|c| {
+                c.iter()
+                    .copied()
+                    .filter(|&child| !is_runtime_child(world, child))
+                    .collect()
+            })
             .unwrap_or_default();
 
         let display_name = name.unwrap_or_else(|| generate_entity_name(world, entity));
         (display_name, children)
     };
 
-    let is_selected = selected_entity == Some(entity);
+    let is_selected = selected.contains(&entity);
+    let is_group = world.get::<EditorGroup>(entity).is_some();
     let has_children = !children.is_empty();
+    let collapsed = world
+        .resource::<HierarchyState>()
+        .collapsed
+        .contains(&entity);
+    let visibility = world.get::<Visibility>(entity).copied();
+    let locked = world.get::<EditorLocked>(entity).is_some();
+    let note = world.get::<EditorNote>(entity).cloned();
 
     ui.horizontal(|ui| {
         ui.add_space(indent);
 
-        // Expand/collapse indicator (placeholder - always expanded for now)
+        // Expand/collapse toggle
         if has_children {
-            ui.label("▼");
+            let arrow = if collapsed { "▶" } else { "▼" };
+            if ui
+                .add(egui::Label::new(arrow).sense(egui::Sense::click()))
+                .clicked()
+            {
+                let mut state = world.resource_mut::<HierarchyState>();
+                if !state.collapsed.remove(&entity) {
+                    state.collapsed.insert(entity);
+                }
+            }
         } else {
             ui.add_space(12.0);
         }
 
-        // Entity button
-        let response = ui.selectable_label(is_selected, &display_name);
-        if response.clicked() {
-            world.resource_mut::<EditorSelection>().selected_entity = Some(entity);
+        // Visibility eye toggle, dimmed while hidden
+        if let Some(visibility) = visibility {
+            let hidden = visibility == Visibility::Hidden;
+            let eye = if hidden {
+                egui::RichText::new("👁").weak()
+            } else {
+                egui::RichText::new("👁")
+            };
+            if ui
+                .add(egui::Label::new(eye).sense(egui::Sense::click()))
+                .on_hover_text(if hidden { "Show" } else { "Hide" })
+                .clicked()
+            {
+                *action = HierarchyAction::ToggleVisibility(entity);
+            }
+        }
+
+        // Lock toggle, dimmed while unlocked
+        let lock = if locked {
+            egui::RichText::new("🔒")
+        } else {
+            egui::RichText::new("🔓").weak()
+        };
+        if ui
+            .add(egui::Label::new(lock).sense(egui::Sense::click()))
+            .on_hover_text(if locked { "Unlock" } else { "Lock" })
+            .clicked()
+        {
+            *action = HierarchyAction::ToggleLock(entity);
+        }
+
+        // Entity button (groups show as folders)
+        let label = if is_group {
+            format!("📁 {}", display_name)
+        } else {
+            display_name
+        };
+        let response = ui.selectable_label(is_selected, label);
+        if response.clicked() && !try_consume_pick(world, entity) {
+            let toggle = ui.input(|input| input.modifiers.ctrl);
+            let mut selection = world.resource_mut::<EditorSelection>();
+            if toggle {
+                selection.toggle(entity);
+            } else {
+                selection.select(entity);
+            }
+        }
+
+        // Right-click acts on the selection, selecting the clicked entity first if needed
+        response.context_menu(|ui| {
+            if !is_selected {
+                world.resource_mut::<EditorSelection>().select(entity);
+            }
+            if context_menu_item(ui, "Copy", "Ctrl+C") {
+                *action = HierarchyAction::Copy;
+                ui.close_menu();
+            }
+            let can_paste = world.resource::<EditorClipboard>().scene.is_some();
+            if ui
+                .add_enabled_ui(can_paste, |ui| context_menu_item(ui, "Paste", "Ctrl+V"))
+                .inner
+            {
+                *action = HierarchyAction::Paste;
+                ui.close_menu();
+            }
+            if context_menu_item(ui, "Duplicate", "Ctrl+D") {
+                *action = HierarchyAction::Duplicate;
+                ui.close_menu();
+            }
+            if ui.button(if locked { "Unlock" } else { "Lock" }).clicked() {
+                *action = HierarchyAction::ToggleLock(entity);
+                ui.close_menu();
+            }
+            if ui.button("Delete").clicked() {
+                *action = HierarchyAction::Delete;
+                ui.close_menu();
+            }
+        });
+
+        // Note icon, showing the note on hover
+        if let Some(note) = note {
+            ui.label(egui::RichText::new(NOTE_ICON).color(note_color(&note)))
+                .on_hover_text(note.text);
         }
     });
 
     // Display children
+    if collapsed {
+        return;
+    }
     for child in children {
-        display_entity_tree(ui, world, child, selected_entity, depth + 1);
+        display_entity_tree(ui, world, child, selected, depth + 1, action);
+    }
+}
+
+/// Renders a context menu item with a keyboard shortcut hint.
+fn context_menu_item(ui: &mut egui::Ui, label: &str, shortcut: &str) -> bool {
+    ui.horizontal(|ui| {
+        let response = ui.button(label);
+        ui.weak(shortcut);
+        response.clicked()
+    })
+    .inner
+}
+
+/// Toggles an entity between hidden and inheriting its parent's visibility.
+///
+/// Editor-only convenience for hiding layers; the change is saved with the
+/// scene and can be undone.
+fn toggle_visibility(world: &mut World, entity: Entity) {
+    let type_id = std::any::TypeId::of::<Visibility>();
+    let before = snapshot_components(world, &[entity], type_id);
+    let Some(mut visibility) = world.get_mut::<Visibility>(entity) else {
+        return;
+    };
+    *visibility = match *visibility {
+        Visibility::Hidden => Visibility::Inherited,
+        _ => Visibility::Hidden,
+    };
+    record_component_edit(world, "Toggle Visibility", type_id, before);
+
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
+    }
+}
+
+/// Adds or removes `EditorLocked` on an entity, as an undoable edit.
+fn toggle_lock(world: &mut World, entity: Entity) {
+    let type_id = std::any::TypeId::of::<EditorLocked>();
+    let before = snapshot_components(world, &[entity], type_id);
+    let mut entity_mut = world.entity_mut(entity);
+    let label = if entity_mut.take::<EditorLocked>().is_some() {
+        "Unlock Entity"
+    } else {
+        entity_mut.insert(EditorLocked);
+        "Lock Entity"
+    };
+    record_component_edit(world, label, type_id, before);
+
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
     }
 }
 
@@ -144,58 +390,282 @@ fn generate_entity_name(world: &World, entity: Entity) -> String {
     format!("Entity ({:?})", entity)
 }
 
-/// Deletes the currently selected entity.
+/// Deletes the currently selected entities.
 fn delete_selected_entity(world: &mut World) {
-    let selected = world.resource::<EditorSelection>().selected_entity;
+    let selected = world.resource::<EditorSelection>().entities.clone();
+    if selected.is_empty() {
+        return;
+    }
 
-    if let Some(entity) = selected {
+    for entity in selected {
         // Check entity exists before despawning
         if world.get_entity(entity).is_ok() {
             world.despawn(entity);
+        }
+    }
 
-            // Clear selection
-            world.resource_mut::<EditorSelection>().selected_entity = None;
+    // Clear selection
+    world.resource_mut::<EditorSelection>().clear();
 
-            // Mark scene as dirty
-            if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
-                manager.mark_dirty();
-            }
-        }
+    // Mark scene as dirty
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
+    }
+}
+
+/// Spawns a new sprite entity with default components at `position` and selects it.
+pub fn add_new_entity(world: &mut World, position: Vec2) -> Entity {
+    let entity_num = next_entity_number(world);
+    let entity = world
+        .spawn((
+            Name::new(format!("Entity {}", entity_num)),
+            Sprite {
+                color: Color::srgb(0.5, 0.5, 0.5),
+                custom_size: Some(Vec2::new(32.0, 32.0)),
+                ..default()
+            },
+            Transform::from_translation(position.extend(0.0)),
+        ))
+        .id();
+    select_new_entity(world, entity);
+    entity
+}
+
+/// Spawns a sprite showing the image at `path` at `position` and selects it.
+///
+/// The entity is named after the file, e.g. "ship" for `textures/ship.png`.
+pub fn add_image_sprite(world: &mut World, path: &str, position: Vec2) -> Entity {
+    let name = Path::new(path).file_stem().map_or_else(
+        || path.to_string(),
+        |stem| stem.to_string_lossy().to_string(),
+    );
+    let entity = world
+        .spawn((
+            Name::new(name),
+            Sprite::default(),
+            AssetPath::new(path),
+            Transform::from_translation(position.extend(0.0)),
+        ))
+        .id();
+    select_new_entity(world, entity);
+    entity
+}
+
+/// Spawns an entity with only a transform at `position` and selects it.
+///
+/// Useful as a group root, spawn point, or marker.
+pub fn add_empty_entity(world: &mut World, position: Vec2) -> Entity {
+    let entity_num = next_entity_number(world);
+    let entity = world
+        .spawn((
+            Name::new(format!("Empty {}", entity_num)),
+            Transform::from_translation(position.extend(0.0)),
+            Visibility::default(),
+        ))
+        .id();
+    select_new_entity(world, entity);
+    entity
+}
+
+/// Spawns an ambient effect preset at `position` and selects it.
+pub fn add_template(world: &mut World, preset: AmbientPreset, position: Vec2) -> Entity {
+    let entity = preset.spawn(world, position.extend(0.0));
+    select_new_entity(world, entity);
+    entity
+}
+
+/// Returns the next number for a default entity name.
+fn next_entity_number(world: &mut World) -> u32 {
+    let mut counter = world.get_resource_or_init::<EntityCounter>();
+    counter.0 += 1;
+    counter.0
+}
+
+/// Selects a newly created entity and marks the scene dirty.
+fn select_new_entity(world: &mut World, entity: Entity) {
+    world.resource_mut::<EditorSelection>().select(entity);
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
     }
 }
 
-/// Spawns a new entity with default components.
-fn add_new_entity(world: &mut World) {
-    // Initialize counter if not present
+/// Parents the selected entities under a new group entity (Ctrl+G).
+///
+/// The group is placed at the centroid of the selection and under the
+/// selection's common parent, if any. Children keep their world transforms.
+pub fn group_selection(world: &mut World) {
+    // Only move the topmost selected entities; descendants come along
+    let roots = selection_roots(world);
+    if roots.is_empty() {
+        return;
+    }
+
+    let centroid = roots
+        .iter()
+        .filter_map(|&entity| world.get::<GlobalTransform>(entity))
+        .map(|transform| transform.translation())
+        .sum::<Vec3>()
+        / roots.len() as f32;
+
+    let parents: Vec<Option<Entity>> = roots
+        .iter()
+        .map(|&entity| world.get::<Parent>(entity).map(|parent| parent.get()))
+        .collect();
+    let common_parent = parents[0].filter(|_| parents.iter().all(|p| *p == parents[0]));
+
     if !world.contains_resource::<EntityCounter>() {
         world.init_resource::<EntityCounter>();
     }
-
-    // Get next entity number
-    let entity_num = {
+    let group_num = {
         let mut counter = world.resource_mut::<EntityCounter>();
         counter.0 += 1;
         counter.0
     };
 
-    // Spawn the entity
-    let entity = world
+    let transform = Transform::from_translation(centroid);
+    let group = world
         .spawn((
-            Name::new(format!("Entity {}", entity_num)),
-            Sprite {
-                color: Color::srgb(0.5, 0.5, 0.5),
-                custom_size: Some(Vec2::new(32.0, 32.0)),
-                ..default()
-            },
-            Transform::default(),
+            Name::new(format!("Group {}", group_num)),
+            EditorGroup,
+            transform,
+            GlobalTransform::from(transform),
+            // Needed so visibility propagates to the grouped entities
+            Visibility::default(),
         ))
         .id();
 
-    // Select the new entity
-    world.resource_mut::<EditorSelection>().selected_entity = Some(entity);
+    if let Some(parent) = common_parent {
+        world.entity_mut(group).set_parent_in_place(parent);
+    }
+    for entity in roots {
+        world.entity_mut(entity).set_parent_in_place(group);
+    }
+
+    world.resource_mut::<EditorSelection>().select(group);
 
-    // Mark scene as dirty
     if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
         manager.mark_dirty();
     }
 }
+
+/// Dissolves the selected groups, moving their children to the group's parent (Ctrl+Shift+G).
+pub fn ungroup_selection(world: &mut World) {
+    let groups: Vec<Entity> = world
+        .resource::<EditorSelection>()
+        .entities
+        .iter()
+        .copied()
+        .filter(|&entity| world.get::<EditorGroup>(entity).is_some())
+        .collect();
+    if groups.is_empty() {
+        return;
+    }
+
+    let mut released = Vec::new();
+    for group in groups {
+        let parent = world.get::<Parent>(group).map(|parent| parent.get());
+        let children: Vec<Entity> = world
+            .get::<Children>(group)
+            .map(|children| children.iter().copied().collect())
+            .unwrap_or_default();
+
+        for child in children {
+            match parent {
+                Some(parent) => world.entity_mut(child).set_parent_in_place(parent),
+                None => world.entity_mut(child).remove_parent_in_place(),
+            };
+            released.push(child);
+        }
+        world.despawn(group);
+    }
+
+    world.resource_mut::<EditorSelection>().set(released);
+
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
+    }
+}
+
+/// Duplicates the selected entities and their children, then selects the copies (Ctrl+D).
+///
+/// Copies every reflect-registered component through a `DynamicScene`, so the
+/// duplicate matches what saving and reloading the scene would produce. Each
+/// copy keeps its original's parent and is offset slightly in local space.
+pub fn duplicate_selection(world: &mut World) {
+    let roots = selection_roots(world);
+    if roots.is_empty() {
+        return;
+    }
+
+    let scene = extract_subtrees(world, &roots);
+    let entity_map = match spawn_subtrees(world, &scene) {
+        Ok(entity_map) => entity_map,
+        Err(e) => {
+            warn!("Failed to duplicate selection: {}", e);
+            return;
+        }
+    };
+
+    let mut copies = Vec::new();
+    for root in roots {
+        let Some(&copy) = entity_map.get(&root) else {
+            continue;
+        };
+        let parent = world.get::<Parent>(root).map(|parent| parent.get());
+
+        let mut copy_mut = world.entity_mut(copy);
+        if let Some(parent) = parent {
+            copy_mut.set_parent(parent);
+        }
+        if let Some(mut transform) = copy_mut.get_mut::<Transform>() {
+            transform.translation += DUPLICATE_OFFSET;
+        }
+        if let Some(mut name) = copy_mut.get_mut::<Name>() {
+            let copy_name = format!("{} (Copy)", name.as_str());
+            name.set(copy_name);
+        }
+        copies.push(copy);
+    }
+
+    world.resource_mut::<EditorSelection>().set(copies);
+
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
+    }
+}
+
+/// Returns the selected entities that have no selected ancestor.
+///
+/// Operations that carry children along (grouping, duplicating, copying) act
+/// on these so nested selections aren't processed twice.
+pub fn selection_roots(world: &World) -> Vec<Entity> {
+    let selected: Vec<Entity> = world
+        .resource::<EditorSelection>()
+        .entities
+        .iter()
+        .copied()
+        .filter(|&entity| world.get_entity(entity).is_ok())
+        .collect();
+
+    selected
+        .iter()
+        .copied()
+        .filter(|&entity| {
+            !selected
+                .iter()
+                .any(|&other| other != entity && is_ancestor(world, other, entity))
+        })
+        .collect()
+}
+
+/// Whether `ancestor` is a (transitive) parent of `entity`.
+fn is_ancestor(world: &World, ancestor: Entity, entity: Entity) -> bool {
+    let mut current = world.get::<Parent>(entity).map(|parent| parent.get());
+    while let Some(parent) = current {
+        if parent == ancestor {
+            return true;
+        }
+        current = world.get::<Parent>(parent).map(|parent| parent.get());
+    }
+    false
+}
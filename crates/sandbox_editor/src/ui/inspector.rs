@@ -4,31 +4,93 @@
 //! Entity inspector panel for the editor.
 
 use bevy::prelude::*;
+use bevy::sprite::Anchor;
 use bevy_egui::egui;
 use sandbox_engine::assets::{AssetPath, SpriteAnimation};
-
-use crate::assets::AssetBrowser;
+use sandbox_engine::scene::{owning_instance, EditorLocked, SceneInstance, SceneManager};
+use std::any::TypeId;
+
+use super::add_component::add_component_menu;
+use super::asset_browser::accept_asset_drop;
+use super::entity_picker::display_entity_ref_fields;
+use super::file_menu::{set_error_message, set_success_message};
+use super::multi_edit::multi_inspector;
+use super::prefab::prefab_section;
+use super::reflect_editor::reflect_component_ui;
+use crate::assets::{cache_preview, AssetType};
+use crate::clipboard::EditorClipboard;
+use crate::custom_inspector::CustomInspectors;
+use crate::picking::sprite_size;
+use crate::presets::{
+    apply_preset, component_type_path, delete_preset, save_preset, ComponentPresets,
+};
 use crate::selection::EditorSelection;
+use crate::undo::{record_component_edit, snapshot_component, snapshot_components};
+
+/// Resource asking the inspector to open and scroll to one component's section.
+#[derive(Resource, Default)]
+pub struct InspectorFocus {
+    component: Option<(Entity, TypeId)>,
+}
+
+impl InspectorFocus {
+    /// Opens `type_id`'s section the next time `entity` is inspected.
+    pub fn reveal(&mut self, entity: Entity, type_id: TypeId) {
+        self.component = Some((entity, type_id));
+    }
+}
 
 /// Displays the entity inspector panel.
 pub fn inspector_panel(ui: &mut egui::Ui, world: &mut World) {
     ui.heading("Inspector");
     ui.separator();
 
-    let selected_entity = world.resource::<EditorSelection>().selected_entity;
+    // Drop despawned entities from the selection
+    let selected: Vec<Entity> = world
+        .resource::<EditorSelection>()
+        .entities
+        .iter()
+        .copied()
+        .filter(|&entity| world.get_entity(entity).is_ok())
+        .collect();
+    if selected.len() != world.resource::<EditorSelection>().entities.len() {
+        world
+            .resource_mut::<EditorSelection>()
+            .set(selected.clone());
+    }
 
-    let Some(entity) = selected_entity else {
-        ui.label("Select an entity to inspect its components.");
-        return;
-    };
+    // Locked entities are shown read-only
+    let locked = selected
+        .iter()
+        .any(|&entity| world.get::<EditorLocked>(entity).is_some());
+    if locked {
+        ui.label("🔒 Locked - unlock in the hierarchy to edit");
+        ui.separator();
+    }
+    // Instance contents come from another file and aren't saved here
+    let instance = selected
+        .iter()
+        .find_map(|&entity| owning_instance(world, entity))
+        .and_then(|instance| world.get::<SceneInstance>(instance));
+    let locked = locked || instance.is_some();
+    if let Some(instance) = instance {
+        ui.label(format!(
+            "📦 Part of an instance of {} - edit that scene to change it",
+            instance.scene
+        ));
+        ui.separator();
+    }
 
-    // Check if entity still exists
-    if world.get_entity(entity).is_err() {
-        ui.label("Selected entity no longer exists.");
-        world.resource_mut::<EditorSelection>().selected_entity = None;
+    if selected.len() > 1 {
+        ui.add_enabled_ui(!locked, |ui| multi_inspector(ui, world, &selected));
         return;
     }
 
+    let Some(&entity) = selected.first() else {
+        ui.label("Select an entity to inspect its components.");
+        return;
+    };
+
     // Display entity ID
     ui.label(format!("Entity: {:?}", entity));
     ui.separator();
@@ -42,9 +104,14 @@ pub fn inspector_panel(ui: &mut egui::Ui, world: &mut World) {
     egui::ScrollArea::vertical()
         .auto_shrink([false; 2])
         .show(ui, |ui| {
-            for (name, component_id, type_id) in &component_data {
-                display_component(ui, world, entity, name, *component_id, *type_id);
-            }
+            ui.add_enabled_ui(!locked, |ui| {
+                prefab_section(ui, world, entity);
+                for (name, component_id, type_id) in &component_data {
+                    display_component(ui, world, entity, name, *component_id, *type_id);
+                }
+                ui.separator();
+                add_component_menu(ui, world, entity);
+            });
         });
 }
 
@@ -60,9 +127,37 @@ fn display_component(
     // Extract short name from full path
     let short_name = component_name.rsplit("::").next().unwrap_or(component_name);
 
-    egui::CollapsingHeader::new(short_name)
-        .default_open(is_common_component(component_name))
-        .show(ui, |ui| {
+    let id = ui.make_persistent_id((entity, component_id));
+    let default_open = is_common_component(component_name);
+    let mut state = egui::collapsing_header::CollapsingState::load_with_default_open(
+        ui.ctx(),
+        id,
+        default_open,
+    );
+
+    // Open the section if a search result pointed here
+    let focused = type_id.is_some()
+        && world.resource::<InspectorFocus>().component == type_id.map(|type_id| (entity, type_id));
+    if focused {
+        state.set_open(true);
+        world.resource_mut::<InspectorFocus>().component = None;
+    }
+
+    let (_, header, _) = state
+        .show_header(ui, |ui| {
+            ui.strong(short_name);
+            if let Some(type_id) = type_id {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    preset_menu(ui, world, entity, type_id);
+                });
+            }
+        })
+        .body(|ui| {
+            // Game-registered inspectors take precedence over the built-in ones
+            if display_custom_inspector(ui, world, entity, type_id, short_name) {
+                return;
+            }
+
             // Try to display editable fields based on component type
             if component_name.contains("Transform") && !component_name.contains("GlobalTransform") {
                 display_transform(ui, world, entity);
@@ -72,17 +167,138 @@ fn display_component(
                 display_sprite(ui, world, entity);
             } else if component_name.contains("AssetPath") {
                 display_asset_path(ui, world, entity);
-            } else if component_name.contains("Velocity") {
-                display_velocity(ui, world, entity, component_id, type_id);
             } else if component_name.contains("Camera2d") {
                 ui.label("2D Camera");
             } else if component_name.contains("Name") && !component_name.contains("TypePath") {
                 display_name_component(ui, world, entity);
-            } else {
-                // Read-only fallback
+            } else if !display_entity_ref_fields(ui, world, entity, component_id, type_id)
+                && !reflect_component_ui(ui, world, entity, type_id, short_name)
+            {
+                // Read-only fallback for types without reflection data
                 ui.label(format!("(type: {})", short_name));
             }
         });
+    if focused {
+        header.response.scroll_to_me(Some(egui::Align::TOP));
+    }
+}
+
+/// Displays the component with its custom inspector, if one is registered.
+///
+/// Changes to reflect-registered components are recorded for undo and mark
+/// the scene dirty. Returns `false` if the type has no custom inspector.
+fn display_custom_inspector(
+    ui: &mut egui::Ui,
+    world: &mut World,
+    entity: Entity,
+    type_id: Option<TypeId>,
+    label: &str,
+) -> bool {
+    let Some(type_id) = type_id else {
+        return false;
+    };
+    let Some(inspector) = world
+        .get_resource::<CustomInspectors>()
+        .and_then(|inspectors| inspectors.get(type_id))
+    else {
+        return false;
+    };
+
+    let before = snapshot_component(world, entity, type_id);
+    inspector(ui, world, entity);
+    let Some(before) = before else {
+        return true;
+    };
+
+    let changed = snapshot_component(world, entity, type_id)
+        .is_none_or(|after| !after.reflect_partial_eq(before.as_ref()).unwrap_or(false));
+    if changed {
+        record_component_edit(world, label, type_id, vec![(entity, Some(before))]);
+        if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+            manager.mark_dirty();
+        }
+    }
+    true
+}
+
+/// Action to perform after preset menu interaction.
+enum PresetAction {
+    None,
+    Apply(String),
+    Save(String),
+    Delete(String),
+}
+
+/// Displays the preset dropdown in a component header.
+fn preset_menu(ui: &mut egui::Ui, world: &mut World, entity: Entity, type_id: TypeId) {
+    let Some(type_path) = component_type_path(world, type_id) else {
+        return;
+    };
+
+    let mut action = PresetAction::None;
+    {
+        let mut presets = world.resource_mut::<ComponentPresets>();
+        let names = presets.names_for(&type_path).to_vec();
+
+        ui.menu_button("☰", |ui| {
+            if names.is_empty() {
+                ui.weak("No presets");
+            }
+            for name in &names {
+                ui.horizontal(|ui| {
+                    if ui.button(name).on_hover_text("Apply preset").clicked() {
+                        action = PresetAction::Apply(name.clone());
+                        ui.close_menu();
+                    }
+                    if ui
+                        .small_button("🗑")
+                        .on_hover_text("Delete preset")
+                        .clicked()
+                    {
+                        action = PresetAction::Delete(name.clone());
+                    }
+                });
+            }
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut presets.new_preset_name)
+                        .hint_text("Preset name")
+                        .desired_width(120.0),
+                );
+                if ui.button("Save").clicked() {
+                    action = PresetAction::Save(presets.new_preset_name.clone());
+                    ui.close_menu();
+                }
+            });
+        })
+        .response
+        .on_hover_text("Presets");
+    }
+
+    match action {
+        PresetAction::Apply(name) => match apply_preset(world, entity, type_id, &name) {
+            Ok(()) => set_success_message(world, &format!("Applied preset '{}'", name)),
+            Err(e) => set_error_message(world, &format!("Failed to apply preset: {}", e)),
+        },
+        PresetAction::Save(name) => match save_preset(world, entity, type_id, &name) {
+            Ok(()) => {
+                world
+                    .resource_mut::<ComponentPresets>()
+                    .new_preset_name
+                    .clear();
+                set_success_message(world, &format!("Saved preset '{}'", name.trim()));
+            }
+            Err(e) => set_error_message(world, &format!("Failed to save preset: {}", e)),
+        },
+        PresetAction::Delete(name) => {
+            if let Err(e) = delete_preset(world, &type_path, &name) {
+                set_error_message(world, &format!("Failed to delete preset: {}", e));
+            }
+        }
+        PresetAction::None => {}
+    }
 }
 
 /// Checks if a component should be expanded by default.
@@ -93,17 +309,52 @@ fn is_common_component(name: &str) -> bool {
         || (name.contains("Name") && !name.contains("TypePath"))
         || name.contains("AssetPath")
         || name.contains("SpriteAnimation")
+        || name.contains("EditorNote")
+}
+
+/// Action chosen in the Transform section's buttons.
+enum TransformAction {
+    ResetTranslation,
+    ResetRotation,
+    ResetScale,
+    Copy,
+    Paste,
 }
 
 /// Displays and edits Transform component.
 fn display_transform(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
+    let copied = world.resource::<EditorClipboard>().transform;
     let Some(mut transform) = world.get_mut::<Transform>(entity) else {
         ui.label("Transform not accessible");
         return;
     };
 
+    let mut action = None;
+    let reset_button =
+        |ui: &mut egui::Ui, hover: &str| ui.small_button("⟲").on_hover_text(hover).clicked();
+
+    ui.horizontal(|ui| {
+        if ui
+            .small_button("📋 Copy")
+            .on_hover_text("Copy this transform")
+            .clicked()
+        {
+            action = Some(TransformAction::Copy);
+        }
+        if ui
+            .add_enabled(copied.is_some(), egui::Button::new("📥 Paste").small())
+            .on_hover_text("Replace this transform with the copied one")
+            .clicked()
+        {
+            action = Some(TransformAction::Paste);
+        }
+    });
+
     ui.horizontal(|ui| {
         ui.label("Position:");
+        if reset_button(ui, "Reset position to 0, 0, 0") {
+            action = Some(TransformAction::ResetTranslation);
+        }
     });
     ui.horizontal(|ui| {
         ui.label("X:");
@@ -134,10 +385,16 @@ fn display_transform(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
         {
             transform.rotation = Quat::from_rotation_z(z_deg.to_radians());
         }
+        if reset_button(ui, "Reset rotation to 0") {
+            action = Some(TransformAction::ResetRotation);
+        }
     });
 
     ui.horizontal(|ui| {
         ui.label("Scale:");
+        if reset_button(ui, "Reset scale to 1, 1, 1") {
+            action = Some(TransformAction::ResetScale);
+        }
     });
     ui.horizontal(|ui| {
         ui.label("X:");
@@ -151,14 +408,70 @@ fn display_transform(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
             transform.scale.y = sy;
         }
     });
+
+    let current = *transform;
+    let Some(action) = action else {
+        return;
+    };
+    let (label, edited) = match action {
+        TransformAction::Copy => {
+            world.resource_mut::<EditorClipboard>().transform = Some(current);
+            set_success_message(world, "Copied transform");
+            return;
+        }
+        TransformAction::Paste => ("Paste Transform", copied.unwrap_or(current)),
+        TransformAction::ResetTranslation => {
+            ("Reset Position", current.with_translation(Vec3::ZERO))
+        }
+        TransformAction::ResetRotation => ("Reset Rotation", current.with_rotation(Quat::IDENTITY)),
+        TransformAction::ResetScale => ("Reset Scale", current.with_scale(Vec3::ONE)),
+    };
+    if edited == current {
+        return;
+    }
+
+    let type_id = TypeId::of::<Transform>();
+    let before = snapshot_components(world, &[entity], type_id);
+    if let Some(mut transform) = world.get_mut::<Transform>(entity) {
+        *transform = edited;
+    }
+    record_component_edit(world, label, type_id, before);
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
+    }
 }
 
+/// Named sprite anchors offered in the inspector, in menu order.
+const SPRITE_ANCHORS: [(Anchor, &str); 9] = [
+    (Anchor::Center, "Center"),
+    (Anchor::TopLeft, "Top Left"),
+    (Anchor::TopCenter, "Top Center"),
+    (Anchor::TopRight, "Top Right"),
+    (Anchor::CenterLeft, "Center Left"),
+    (Anchor::CenterRight, "Center Right"),
+    (Anchor::BottomLeft, "Bottom Left"),
+    (Anchor::BottomCenter, "Bottom Center"),
+    (Anchor::BottomRight, "Bottom Right"),
+];
+
 /// Displays and edits Sprite component.
+///
+/// Edits a copy and writes it back only when a field changes.
 fn display_sprite(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
-    let Some(mut sprite) = world.get_mut::<Sprite>(entity) else {
+    let Some(original) = world.get::<Sprite>(entity) else {
         ui.label("Sprite not accessible");
         return;
     };
+    let mut sprite = original.clone();
+    // Size shown while no custom size is set: the texture rect's or the image's
+    let natural_size = sprite_size(
+        world,
+        &Sprite {
+            custom_size: None,
+            ..sprite.clone()
+        },
+    );
+    let mut changed = false;
 
     // Color editor
     ui.horizontal(|ui| {
@@ -167,6 +480,7 @@ fn display_sprite(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
         let mut color = [srgba.red, srgba.green, srgba.blue, srgba.alpha];
         if ui.color_edit_button_rgba_unmultiplied(&mut color).changed() {
             sprite.color = Color::srgba(color[0], color[1], color[2], color[3]);
+            changed = true;
         }
     });
 
@@ -175,50 +489,121 @@ fn display_sprite(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
         ui.label("Size:");
         if let Some(size) = sprite.custom_size.as_mut() {
             ui.label("W:");
-            ui.add(egui::DragValue::new(&mut size.x).speed(1.0));
+            changed |= ui
+                .add(egui::DragValue::new(&mut size.x).speed(1.0))
+                .changed();
             ui.label("H:");
-            ui.add(egui::DragValue::new(&mut size.y).speed(1.0));
+            changed |= ui
+                .add(egui::DragValue::new(&mut size.y).speed(1.0))
+                .changed();
+            if ui
+                .small_button("✕")
+                .on_hover_text("Use the texture's size")
+                .clicked()
+            {
+                sprite.custom_size = None;
+                changed = true;
+            }
         } else {
-            ui.label("(default)");
+            match natural_size {
+                Some(size) => ui.weak(format!("(default {} × {})", size.x, size.y)),
+                None => ui.weak("(default)"),
+            };
+            if ui
+                .small_button("Set")
+                .on_hover_text("Set a custom size, starting from the current one")
+                .clicked()
+            {
+                sprite.custom_size = Some(natural_size.unwrap_or(Vec2::splat(100.0)));
+                changed = true;
+            }
         }
     });
-}
 
-/// Displays velocity component (generic Vec2 wrapper).
-fn display_velocity(
-    ui: &mut egui::Ui,
-    world: &mut World,
-    entity: Entity,
-    component_id: bevy::ecs::component::ComponentId,
-    type_id: Option<std::any::TypeId>,
-) {
-    // Try to get velocity via reflection
-    let type_registry = world.resource::<AppTypeRegistry>().clone();
-    let type_registry = type_registry.read();
-
-    if let Some(type_id) = type_id {
-        if let Some(registration) = type_registry.get(type_id) {
-            if let Some(reflect_from_ptr) = registration.data::<bevy::reflect::ReflectFromPtr>() {
-                // Get the component data pointer
-                if let Some(component_ptr) = world.get_by_id(entity, component_id) {
-                    // SAFETY: We're getting the reflect from a valid component pointer
-                    let reflect = unsafe { reflect_from_ptr.as_reflect(component_ptr) };
-
-                    // Try to display as tuple struct with Vec2
-                    if let Ok(tuple_struct) = reflect.reflect_ref().as_tuple_struct() {
-                        if let Some(field) = tuple_struct.field(0) {
-                            if let Some(vec2) = field.try_downcast_ref::<Vec2>() {
-                                ui.label(format!("Velocity: ({:.1}, {:.1})", vec2.x, vec2.y));
-                                return;
-                            }
-                        }
-                    }
+    // Flip toggles
+    ui.horizontal(|ui| {
+        ui.label("Flip:");
+        changed |= ui.checkbox(&mut sprite.flip_x, "X").changed();
+        changed |= ui.checkbox(&mut sprite.flip_y, "Y").changed();
+    });
+
+    // Anchor: a named position or a custom offset in -0.5..=0.5
+    ui.horizontal(|ui| {
+        ui.label("Anchor:");
+        let selected = SPRITE_ANCHORS
+            .iter()
+            .find(|(anchor, _)| *anchor == sprite.anchor)
+            .map_or("Custom", |(_, label)| label);
+        egui::ComboBox::from_id_salt(("sprite_anchor", entity))
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                for (anchor, label) in SPRITE_ANCHORS {
+                    changed |= ui
+                        .selectable_value(&mut sprite.anchor, anchor, label)
+                        .changed();
                 }
-            }
+                if ui
+                    .selectable_label(matches!(sprite.anchor, Anchor::Custom(_)), "Custom")
+                    .clicked()
+                {
+                    sprite.anchor = Anchor::Custom(sprite.anchor.as_vec());
+                    changed = true;
+                }
+            });
+    });
+    if let Anchor::Custom(offset) = &mut sprite.anchor {
+        ui.horizontal(|ui| {
+            ui.label("X:");
+            changed |= ui
+                .add(egui::DragValue::new(&mut offset.x).speed(0.01))
+                .changed();
+            ui.label("Y:");
+            changed |= ui
+                .add(egui::DragValue::new(&mut offset.y).speed(0.01))
+                .changed();
+        });
+    }
+
+    // Texture rect: the region of the image to draw, in pixels
+    ui.horizontal(|ui| {
+        let mut use_rect = sprite.rect.is_some();
+        if ui
+            .checkbox(&mut use_rect, "Texture Rect")
+            .on_hover_text("Draw only part of the image")
+            .changed()
+        {
+            let image_size = world
+                .resource::<Assets<Image>>()
+                .get(&sprite.image)
+                .map_or(Vec2::splat(16.0), |image| image.size_f32());
+            sprite.rect = use_rect.then(|| Rect::from_corners(Vec2::ZERO, image_size));
+            changed = true;
+        }
+    });
+    if let Some(rect) = sprite.rect.as_mut() {
+        for (label, corner) in [("Min", &mut rect.min), ("Max", &mut rect.max)] {
+            ui.horizontal(|ui| {
+                ui.label(format!("{}:", label));
+                ui.label("X:");
+                changed |= ui
+                    .add(egui::DragValue::new(&mut corner.x).speed(1.0))
+                    .changed();
+                ui.label("Y:");
+                changed |= ui
+                    .add(egui::DragValue::new(&mut corner.y).speed(1.0))
+                    .changed();
+            });
         }
     }
 
-    ui.label("Velocity: (read-only)");
+    if changed {
+        if let Some(mut current) = world.get_mut::<Sprite>(entity) {
+            *current = sprite;
+        }
+        if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+            manager.mark_dirty();
+        }
+    }
 }
 
 /// Displays Name component.
@@ -242,14 +627,27 @@ fn display_asset_path(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
         ui.label("Path:");
     });
 
-    // Text edit for path
+    // Text edit for path; images from the asset browser can be dropped on it
     let mut path = current_path.clone();
-    let response = ui.text_edit_singleline(&mut path);
+    let response = ui
+        .text_edit_singleline(&mut path)
+        .on_hover_text("Drag an image from the Assets panel here");
     if response.changed() {
         if let Some(mut asset_path) = world.get_mut::<AssetPath>(entity) {
             asset_path.path = path.clone();
         }
     }
+    if let Some(dropped) = accept_asset_drop(ui, &response, AssetType::Image) {
+        let type_id = TypeId::of::<AssetPath>();
+        let before = snapshot_components(world, &[entity], type_id);
+        if let Some(mut asset_path) = world.get_mut::<AssetPath>(entity) {
+            asset_path.path = dropped;
+        }
+        record_component_edit(world, "Set Asset Path", type_id, before);
+        if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+            manager.mark_dirty();
+        }
+    }
 
     // Preview of the texture
     if !current_path.is_empty() {
@@ -259,11 +657,8 @@ fn display_asset_path(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
         // Load and display the texture preview
         let handle: Handle<Image> = world.resource::<AssetServer>().load(&current_path);
 
-        // Store handle to keep it alive
-        world
-            .resource_mut::<AssetBrowser>()
-            .preview_handles
-            .insert(format!("inspector:{}", current_path), handle.clone());
+        // Keep the image loaded while it's among the recently previewed
+        cache_preview(world, format!("inspector:{}", current_path), handle.clone());
 
         let images = world.resource::<Assets<Image>>();
         if let Some(image) = images.get(&handle) {
@@ -289,13 +684,6 @@ fn display_asset_path(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
             ui.label("Loading...");
         }
     }
-
-    // "Browse..." button for file selection
-    if ui.button("Browse...").clicked() {
-        // Set the asset browser to show the current selection
-        // (Full file dialog integration would require native dialog support)
-        ui.label("Use Asset Browser panel to select files");
-    }
 }
 
 /// Displays and edits SpriteAnimation component.
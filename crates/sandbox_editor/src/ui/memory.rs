@@ -0,0 +1,351 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Memory diagnostics window.
+//!
+//! Reports CPU-side asset memory by type, per-component storage in the ECS,
+//! and the asset browser's preview cache. Sizes are estimates: texture and
+//! audio data as loaded, components as their inline size times the number of
+//! entities holding them (heap data such as `String`s is not counted).
+
+use bevy::asset::AssetId;
+use bevy::audio::AudioSource;
+use bevy::ecs::component::ComponentId;
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+use bevy_egui::egui;
+
+use super::file_menu::set_success_message;
+use crate::assets::{release_previews, AssetBrowser};
+
+/// Column the texture table is sorted by.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextureSort {
+    Name,
+    #[default]
+    Size,
+}
+
+/// State for the memory window.
+#[derive(Resource)]
+pub struct MemoryWindowState {
+    /// Whether the window is open.
+    pub open: bool,
+    /// Column the texture table is sorted by.
+    pub sort: TextureSort,
+    /// Whether the texture table is sorted largest (or Z) first.
+    pub descending: bool,
+}
+
+impl Default for MemoryWindowState {
+    fn default() -> Self {
+        Self {
+            open: false,
+            sort: TextureSort::Size,
+            descending: true,
+        }
+    }
+}
+
+/// One loaded texture.
+struct TextureRow {
+    id: AssetId<Image>,
+    name: String,
+    size: UVec2,
+    bytes: usize,
+    /// Whether a sprite in the world uses the texture.
+    in_use: bool,
+    /// Whether the asset browser's preview cache holds the texture.
+    cached: bool,
+}
+
+/// Storage used by one component type.
+struct ComponentRow {
+    name: String,
+    entities: usize,
+    bytes: usize,
+}
+
+/// Displays the memory window.
+pub fn memory_window(ctx: &egui::Context, world: &mut World) {
+    if !world.resource::<MemoryWindowState>().open {
+        return;
+    }
+
+    let mut state = std::mem::take(&mut *world.resource_mut::<MemoryWindowState>());
+    let mut textures = texture_rows(world);
+    sort_textures(&mut textures, state.sort, state.descending);
+    let audio_bytes: usize = world
+        .get_resource::<Assets<AudioSource>>()
+        .map(|sources| sources.iter().map(|(_, source)| source.bytes.len()).sum())
+        .unwrap_or(0);
+    let audio_count = world
+        .get_resource::<Assets<AudioSource>>()
+        .map_or(0, |sources| sources.len());
+    let components = component_rows(world);
+    let entity_count = world.entities().len();
+    let cache_count = world.resource::<AssetBrowser>().preview_handles.len();
+    let cached_bytes: usize = textures.iter().filter(|t| t.cached).map(|t| t.bytes).sum();
+    let unused = textures.iter().filter(|t| t.cached && !t.in_use).count();
+
+    let mut open = state.open;
+    let mut unload = false;
+    let mut capacity = world.resource::<AssetBrowser>().preview_handles.capacity();
+    egui::Window::new("Memory")
+        .open(&mut open)
+        .default_width(420.0)
+        .show(ctx, |ui| {
+            ui.heading("Assets");
+            egui::Grid::new("memory_asset_totals")
+                .num_columns(3)
+                .show(ui, |ui| {
+                    let texture_bytes = textures.iter().map(|t| t.bytes).sum();
+                    ui.label("🖼 Textures");
+                    ui.label(textures.len().to_string());
+                    ui.label(format_bytes(texture_bytes));
+                    ui.end_row();
+                    ui.label("🔊 Audio");
+                    ui.label(audio_count.to_string());
+                    ui.label(format_bytes(audio_bytes));
+                    ui.end_row();
+                    ui.label("Preview cache");
+                    ui.label(cache_count.to_string());
+                    ui.label(format_bytes(cached_bytes));
+                    ui.end_row();
+                });
+            ui.horizontal(|ui| {
+                unload = ui
+                    .add_enabled(unused > 0, egui::Button::new("Unload Unused Assets"))
+                    .on_hover_text(
+                        "Release cached previews that no sprite uses (except the selected one)",
+                    )
+                    .clicked();
+                ui.weak(format!("{} unused", unused));
+            });
+            ui.horizontal(|ui| {
+                ui.label("Keep up to");
+                ui.add(egui::DragValue::new(&mut capacity).range(1..=1024));
+                ui.label("previews");
+            })
+            .response
+            .on_hover_text("Least recently shown previews are released beyond this");
+
+            ui.separator();
+            egui::CollapsingHeader::new(format!("Textures ({})", textures.len()))
+                .default_open(true)
+                .show(ui, |ui| {
+                    texture_table(ui, &textures, &mut state);
+                });
+
+            egui::CollapsingHeader::new(format!("Entities ({}) and components", entity_count))
+                .show(ui, |ui| {
+                    component_table(ui, &components);
+                });
+        });
+    state.open = open;
+    *world.resource_mut::<MemoryWindowState>() = state;
+
+    let mut browser = world.resource_mut::<AssetBrowser>();
+    if capacity != browser.preview_handles.capacity() {
+        let evicted = browser.preview_handles.set_capacity(capacity);
+        release_previews(world, &evicted);
+    }
+    if unload {
+        let released = unload_unused_previews(world);
+        set_success_message(world, &format!("Released {} cached previews", released));
+    }
+}
+
+/// Displays the per-texture table with sortable Name and Size columns.
+fn texture_table(ui: &mut egui::Ui, textures: &[TextureRow], state: &mut MemoryWindowState) {
+    let mut sort_header = |ui: &mut egui::Ui, label: &str, sort: TextureSort| {
+        let arrow = match (state.sort == sort, state.descending) {
+            (false, _) => "",
+            (true, true) => " ⏷",
+            (true, false) => " ⏶",
+        };
+        if ui
+            .selectable_label(state.sort == sort, format!("{}{}", label, arrow))
+            .clicked()
+        {
+            if state.sort == sort {
+                state.descending = !state.descending;
+            } else {
+                state.sort = sort;
+                state.descending = sort == TextureSort::Size;
+            }
+        }
+    };
+
+    egui::ScrollArea::vertical()
+        .id_salt("memory_textures")
+        .max_height(240.0)
+        .auto_shrink([false, true])
+        .show(ui, |ui| {
+            egui::Grid::new("memory_textures_grid")
+                .num_columns(4)
+                .striped(true)
+                .show(ui, |ui| {
+                    sort_header(ui, "Name", TextureSort::Name);
+                    ui.strong("Dimensions");
+                    sort_header(ui, "Size", TextureSort::Size);
+                    ui.strong("Use");
+                    ui.end_row();
+
+                    for texture in textures {
+                        ui.label(&texture.name)
+                            .on_hover_text(format!("{:?}", texture.id));
+                        ui.label(format!("{}×{}", texture.size.x, texture.size.y));
+                        ui.label(format_bytes(texture.bytes));
+                        let usage = match (texture.in_use, texture.cached) {
+                            (true, _) => "sprite",
+                            (false, true) => "preview",
+                            (false, false) => "",
+                        };
+                        ui.weak(usage);
+                        ui.end_row();
+                    }
+                });
+            if textures.is_empty() {
+                ui.weak("No textures loaded.");
+            }
+        });
+}
+
+/// Displays storage per component type, largest first.
+fn component_table(ui: &mut egui::Ui, components: &[ComponentRow]) {
+    egui::ScrollArea::vertical()
+        .id_salt("memory_components")
+        .max_height(240.0)
+        .auto_shrink([false, true])
+        .show(ui, |ui| {
+            egui::Grid::new("memory_components_grid")
+                .num_columns(3)
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong("Component");
+                    ui.strong("Entities");
+                    ui.strong("Size");
+                    ui.end_row();
+                    for component in components {
+                        ui.label(&component.name);
+                        ui.label(component.entities.to_string());
+                        ui.label(format_bytes(component.bytes));
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+/// Textures used by sprites in the world.
+fn sprite_textures(world: &mut World) -> HashSet<AssetId<Image>> {
+    world
+        .query::<&Sprite>()
+        .iter(world)
+        .map(|sprite| sprite.image.id())
+        .collect()
+}
+
+/// Collects every loaded texture with its size and usage.
+fn texture_rows(world: &mut World) -> Vec<TextureRow> {
+    let used = sprite_textures(world);
+    let browser = world.resource::<AssetBrowser>();
+    let cached: HashSet<AssetId<Image>> =
+        browser.preview_handles.handles().map(Handle::id).collect();
+    let asset_server = world.resource::<AssetServer>();
+
+    world
+        .resource::<Assets<Image>>()
+        .iter()
+        .map(|(id, image)| TextureRow {
+            id,
+            name: asset_server
+                .get_path(id)
+                .map_or_else(|| "(generated)".to_string(), |path| path.to_string()),
+            size: image.size(),
+            bytes: image.data.len(),
+            in_use: used.contains(&id),
+            cached: cached.contains(&id),
+        })
+        .collect()
+}
+
+fn sort_textures(textures: &mut [TextureRow], sort: TextureSort, descending: bool) {
+    match sort {
+        TextureSort::Name => textures.sort_by(|a, b| a.name.cmp(&b.name)),
+        TextureSort::Size => textures.sort_by_key(|texture| texture.bytes),
+    }
+    if descending {
+        textures.reverse();
+    }
+}
+
+/// Sums inline component storage over all archetypes, largest first.
+fn component_rows(world: &World) -> Vec<ComponentRow> {
+    let mut counts: HashMap<ComponentId, usize> = HashMap::default();
+    for archetype in world.archetypes().iter() {
+        for component in archetype.components() {
+            *counts.entry(component).or_default() += archetype.len();
+        }
+    }
+
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let mut rows: Vec<ComponentRow> = counts
+        .into_iter()
+        .filter(|(_, entities)| *entities > 0)
+        .filter_map(|(id, entities)| {
+            let info = world.components().get_info(id)?;
+            let name = info
+                .type_id()
+                .and_then(|type_id| type_registry.get(type_id))
+                .map_or_else(
+                    || info.name().to_string(),
+                    |registration| {
+                        registration
+                            .type_info()
+                            .type_path_table()
+                            .short_path()
+                            .to_string()
+                    },
+                );
+            Some(ComponentRow {
+                name,
+                entities,
+                bytes: info.layout().size() * entities,
+            })
+        })
+        .collect();
+    rows.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.name.cmp(&b.name)));
+    rows
+}
+
+/// Drops preview-cache handles that no sprite uses, letting Bevy free the textures.
+///
+/// The selected asset's preview is kept. Returns the number of released previews.
+fn unload_unused_previews(world: &mut World) -> usize {
+    let used = sprite_textures(world);
+
+    let mut browser = world.resource_mut::<AssetBrowser>();
+    let selected = browser.selected_path.clone();
+    let released = browser
+        .preview_handles
+        .retain(|path, handle| used.contains(&handle.id()) || selected.as_deref() == Some(path));
+    release_previews(world, &released);
+    released.len()
+}
+
+/// Formats a byte count with a binary unit, e.g. "1.5 MiB".
+pub(crate) fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
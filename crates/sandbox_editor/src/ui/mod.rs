@@ -3,14 +3,62 @@
 
 //! Editor UI modules.
 
+pub mod add_component;
 pub mod animation_editor;
 pub mod asset_browser;
+pub mod asset_operations;
+pub mod autosave_prompt;
+pub mod color_grades;
+pub mod dialogue_preview;
+pub mod entity_picker;
+pub mod fields;
 pub mod file_menu;
+pub mod find_replace;
 pub mod hierarchy;
 pub mod inspector;
+pub mod memory;
+pub mod multi_edit;
+pub mod prefab;
+pub mod problems;
+pub mod reflect_editor;
+pub mod scene_changes;
+pub mod scene_search;
+pub mod sequence_editor;
+pub mod stop_prompt;
+pub mod systems;
+pub mod teams;
+pub mod time_of_day;
+pub mod unused_assets;
+pub mod usages;
+pub mod watch;
+pub mod world_flags;
 
+pub use add_component::AddComponentState;
 pub use animation_editor::{animation_editor_window, AnimationEditorState};
 pub use asset_browser::asset_browser_panel;
-pub use file_menu::{menu_bar, status_messages};
+pub use asset_operations::{asset_operation_window, AssetOperationState};
+pub use autosave_prompt::autosave_recovery_prompt;
+pub use color_grades::{color_grades_window, ColorGradesWindowState};
+pub use dialogue_preview::{dialogue_preview_window, DialoguePreviewState};
+pub use entity_picker::{cancel_pick_on_escape, EntityPickerState};
+pub use file_menu::{
+    confirm_window_close, menu_bar, save_issues_prompt, scene_lock_prompt, scene_reload_prompt,
+    status_messages, unsaved_changes_prompt, SaveIssuesPrompt, SceneChange, UnsavedChangesPrompt,
+};
+pub use find_replace::{find_replace_window, FindReplaceState};
 pub use hierarchy::*;
 pub use inspector::*;
+pub use memory::{memory_window, MemoryWindowState};
+pub use prefab::PrefabLinkState;
+pub use problems::{problems_window, ProblemsState};
+pub use scene_changes::{scene_changes_window, SceneChangesState};
+pub use scene_search::{scene_search_window, SceneSearchState};
+pub use sequence_editor::{sequence_editor_window, SequenceEditorState};
+pub use stop_prompt::{request_stop, stop_prompt, StopPromptState};
+pub use systems::{systems_window, SystemsWindowState};
+pub use teams::{teams_window, TeamsWindowState};
+pub use time_of_day::{time_of_day_window, TimeOfDayWindowState};
+pub use unused_assets::{unused_assets_window, UnusedAssetsState};
+pub use usages::{usages_window, UsagesState};
+pub use watch::{watch_window, Watch, WatchTarget, WatchWindowState};
+pub use world_flags::{world_flags_window, WorldFlagsWindowState};
@@ -0,0 +1,174 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Inspector view for editing several selected entities at once.
+//!
+//! Shows the reflected components all selected entities share. Fields whose
+//! values differ show "—"; editing a field writes it to every selected entity
+//! as one undo step.
+
+use bevy::ecs::component::ComponentId;
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::scene::SceneManager;
+use std::any::TypeId;
+
+use super::fields::{collect_field_paths, field_widget, read_field, write_field, FieldValue};
+use crate::undo::{record_component_edit, snapshot_components};
+
+/// Displays the shared components of the selected entities.
+pub fn multi_inspector(ui: &mut egui::Ui, world: &mut World, entities: &[Entity]) {
+    ui.label(format!("{} entities selected", entities.len()));
+    ui.weak("Editing a field applies it to all of them.");
+    ui.separator();
+
+    let shared = shared_components(world, entities);
+    if shared.is_empty() {
+        ui.label("The selected entities share no editable components.");
+        return;
+    }
+
+    egui::ScrollArea::vertical()
+        .auto_shrink([false; 2])
+        .show(ui, |ui| {
+            for (name, type_id) in shared {
+                let short_name = name.rsplit("::").next().unwrap_or(&name).to_string();
+                egui::CollapsingHeader::new(&short_name)
+                    .id_salt(("multi_edit", type_id))
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        display_shared_fields(ui, world, entities, &short_name, type_id);
+                    });
+            }
+        });
+}
+
+/// Returns the reflect-registered components present on every entity.
+fn shared_components(world: &World, entities: &[Entity]) -> Vec<(String, TypeId)> {
+    let Some((&first, rest)) = entities.split_first() else {
+        return Vec::new();
+    };
+
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let mut shared: Vec<(ComponentId, String, TypeId)> = world
+        .inspect_entity(first)
+        .filter_map(|info| {
+            let type_id = info.type_id()?;
+            type_registry
+                .get(type_id)?
+                .data::<ReflectComponent>()
+                .map(|_| (info.id(), info.name().to_string(), type_id))
+        })
+        .collect();
+
+    for &entity in rest {
+        let Ok(entity_ref) = world.get_entity(entity) else {
+            continue;
+        };
+        shared.retain(|(id, _, _)| entity_ref.contains_id(*id));
+    }
+
+    shared
+        .into_iter()
+        .map(|(_, name, type_id)| (name, type_id))
+        .collect()
+}
+
+/// Reads a field from the same component on each entity.
+fn read_fields(
+    world: &World,
+    entities: &[Entity],
+    reflect_component: &ReflectComponent,
+    path: &str,
+) -> Vec<Option<FieldValue>> {
+    entities
+        .iter()
+        .map(|&entity| read_field(world, entity, reflect_component, path))
+        .collect()
+}
+
+/// Displays one row per editable field, applying edits to all entities.
+fn display_shared_fields(
+    ui: &mut egui::Ui,
+    world: &mut World,
+    entities: &[Entity],
+    component_name: &str,
+    type_id: TypeId,
+) {
+    let Some(reflect_component) = world
+        .resource::<AppTypeRegistry>()
+        .read()
+        .get(type_id)
+        .and_then(|registration| registration.data::<ReflectComponent>())
+        .cloned()
+    else {
+        return;
+    };
+
+    // Field layout comes from the primary (last selected) entity
+    let mut paths = Vec::new();
+    if let Some(component) = entities
+        .last()
+        .and_then(|&entity| world.get_entity(entity).ok())
+        .and_then(|entity_ref| reflect_component.reflect(entity_ref))
+    {
+        collect_field_paths(component.as_partial_reflect(), "", 0, &mut paths);
+    }
+
+    if paths.is_empty() {
+        ui.weak("(no editable fields)");
+        return;
+    }
+
+    let mut edit: Option<(String, FieldValue)> = None;
+
+    egui::Grid::new(("multi_edit_fields", type_id))
+        .num_columns(3)
+        .show(ui, |ui| {
+            for path in &paths {
+                let values = read_fields(world, entities, &reflect_component, path);
+                let Some(Some(primary)) = values.last().cloned() else {
+                    continue;
+                };
+                let mixed = values.iter().any(|value| value.as_ref() != Some(&primary));
+
+                ui.label(path.trim_start_matches('.'));
+
+                let mut value = primary.clone();
+                let changed = field_widget(ui, &mut value, mixed);
+                if changed && (mixed || value != primary) {
+                    edit = Some((path.clone(), value));
+                }
+
+                if mixed
+                    && ui
+                        .small_button("=")
+                        .on_hover_text("Apply the primary entity's value to all")
+                        .clicked()
+                {
+                    edit = Some((path.clone(), primary));
+                }
+                ui.end_row();
+            }
+        });
+
+    let Some((path, value)) = edit else {
+        return;
+    };
+
+    let before = snapshot_components(world, entities, type_id);
+    for &entity in entities {
+        write_field(world, entity, &reflect_component, &path, &value);
+    }
+
+    record_component_edit(
+        world,
+        format!("{}{}", component_name, path),
+        type_id,
+        before,
+    );
+
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
+    }
+}
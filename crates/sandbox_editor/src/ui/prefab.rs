@@ -0,0 +1,172 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Prefab link section of the inspector.
+//!
+//! Entities spawned from a prefab show the file they came from and their
+//! overrides (fields changed since spawning), with buttons to write the
+//! overrides into the file or reset them to the file's values. The file is
+//! read again every half second, so edits made elsewhere show up.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::editor_state::EditorPlayState;
+use sandbox_engine::prefab::{
+    apply_to_prefab, prefab_overrides, prefab_root, revert_to_prefab, OverrideKind, PrefabOverride,
+    PrefabSource,
+};
+use std::any::TypeId;
+use std::time::{Duration, Instant};
+
+use super::file_menu::{set_error_message, set_success_message};
+use crate::undo::{snapshot_component, ComponentChange, UndoStack};
+
+/// How often the inspected instance is compared with its prefab file again.
+const REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Resource caching the overrides of the inspected prefab instance.
+#[derive(Resource, Default)]
+pub struct PrefabLinkState {
+    /// Prefab root the overrides belong to.
+    root: Option<Entity>,
+    overrides: Vec<PrefabOverride>,
+    /// Why the instance couldn't be compared with its file, if it couldn't.
+    error: Option<String>,
+    refreshed_at: Option<Instant>,
+}
+
+impl PrefabLinkState {
+    /// Compares the instance again on the next frame.
+    pub fn refresh(&mut self) {
+        self.refreshed_at = None;
+    }
+}
+
+/// Shows the prefab link of `entity`'s instance, if it belongs to one.
+pub fn prefab_section(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
+    let Some(root) = prefab_root(world, entity) else {
+        return;
+    };
+    let Some(source) = world.get::<PrefabSource>(root).cloned() else {
+        return;
+    };
+
+    let state = world.resource::<PrefabLinkState>();
+    let stale = state.root != Some(root)
+        || state
+            .refreshed_at
+            .is_none_or(|refreshed_at| refreshed_at.elapsed() >= REFRESH_INTERVAL);
+    if stale {
+        let (overrides, error) = match prefab_overrides(world, root) {
+            Ok(overrides) => (overrides, None),
+            Err(e) => (Vec::new(), Some(e.to_string())),
+        };
+        *world.resource_mut::<PrefabLinkState>() = PrefabLinkState {
+            root: Some(root),
+            overrides,
+            error,
+            refreshed_at: Some(Instant::now()),
+        };
+    }
+
+    ui.label(format!("🔗 Prefab: {}", source.path));
+    let state = world.resource::<PrefabLinkState>();
+    let overrides = state.overrides.clone();
+    if let Some(error) = &state.error {
+        ui.colored_label(egui::Color32::LIGHT_RED, error);
+    } else {
+        egui::CollapsingHeader::new(format!("Overrides ({})", overrides.len()))
+            .id_salt("prefab_overrides")
+            .show(ui, |ui| {
+                if overrides.is_empty() {
+                    ui.weak("Matches the prefab");
+                }
+                for change in &overrides {
+                    ui.label(override_label(world, root, change));
+                }
+            });
+    }
+
+    let stopped = *world.resource::<State<EditorPlayState>>().get() == EditorPlayState::Stopped;
+    let (mut apply, mut revert) = (false, false);
+    ui.horizontal(|ui| {
+        ui.add_enabled_ui(stopped && !overrides.is_empty(), |ui| {
+            apply = ui
+                .button("Apply to Prefab")
+                .on_hover_text("Write the overrides into the prefab file")
+                .clicked();
+            revert = ui
+                .button("Revert to Prefab")
+                .on_hover_text("Reset the overrides to the prefab file's values")
+                .clicked();
+        });
+    });
+    ui.separator();
+
+    if apply {
+        match apply_to_prefab(world, root) {
+            Ok(count) => set_success_message(
+                world,
+                &format!("Applied {} override(s) to {}", count, source.path),
+            ),
+            Err(e) => set_error_message(world, &format!("Failed to apply to prefab: {}", e)),
+        }
+        world.resource_mut::<PrefabLinkState>().refresh();
+    }
+    if revert {
+        revert_with_undo(world, root, &overrides);
+        world.resource_mut::<PrefabLinkState>().refresh();
+    }
+}
+
+/// Describes an override, prefixed with the entity's name unless it is the
+/// prefab root.
+fn override_label(world: &World, root: Entity, change: &PrefabOverride) -> String {
+    let prefix = if change.entity == root {
+        String::new()
+    } else {
+        let name = world
+            .get::<Name>(change.entity)
+            .map_or_else(|| format!("{:?}", change.entity), |name| name.to_string());
+        format!("{} › ", name)
+    };
+    match &change.kind {
+        OverrideKind::Changed(Some(field)) => format!("{}{}.{}", prefix, change.component, field),
+        OverrideKind::Changed(None) => format!("{}{}", prefix, change.component),
+        OverrideKind::Added => format!("{}+ {}", prefix, change.component),
+        OverrideKind::Removed => format!("{}− {}", prefix, change.component),
+    }
+}
+
+/// Reverts the instance, recording the overridden components as one undo step.
+fn revert_with_undo(world: &mut World, root: Entity, overrides: &[PrefabOverride]) {
+    let mut targets: Vec<(Entity, TypeId)> = Vec::new();
+    for change in overrides {
+        if !targets.contains(&(change.entity, change.type_id)) {
+            targets.push((change.entity, change.type_id));
+        }
+    }
+    let before: Vec<_> = targets
+        .into_iter()
+        .map(|(entity, type_id)| (entity, type_id, snapshot_component(world, entity, type_id)))
+        .collect();
+
+    match revert_to_prefab(world, root) {
+        Ok(count) => {
+            let changes = before
+                .into_iter()
+                .map(|(entity, type_id, before)| ComponentChange {
+                    entity,
+                    type_id,
+                    before,
+                    after: snapshot_component(world, entity, type_id),
+                })
+                .collect();
+            world
+                .resource_mut::<UndoStack>()
+                .push("Revert to Prefab", changes);
+            set_success_message(world, &format!("Reverted {} override(s)", count));
+        }
+        Err(e) => set_error_message(world, &format!("Failed to revert to prefab: {}", e)),
+    }
+}
@@ -0,0 +1,127 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Window listing broken asset references.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use super::entity_picker::entity_label;
+use crate::editor_camera::center_editor_camera;
+use crate::problems::{find_problems, Problem, ProblemLocation};
+use crate::selection::EditorSelection;
+
+/// State for the problems window.
+#[derive(Resource, Default)]
+pub struct ProblemsState {
+    /// Whether the window is open.
+    pub open: bool,
+    /// Result of the last scan; `None` until the window scans.
+    pub problems: Option<Vec<Problem>>,
+}
+
+impl ProblemsState {
+    /// Opens the window and scans on the next frame.
+    pub fn open(&mut self) {
+        self.open = true;
+        self.problems = None;
+    }
+}
+
+/// Action chosen in the problems window.
+enum ProblemsAction {
+    Rescan,
+    Select(Entity),
+}
+
+/// Displays the problems window.
+pub fn problems_window(ctx: &egui::Context, world: &mut World) {
+    if !world.resource::<ProblemsState>().open {
+        return;
+    }
+    if world.resource::<ProblemsState>().problems.is_none() {
+        rescan(world);
+    }
+
+    let state = std::mem::take(&mut *world.resource_mut::<ProblemsState>());
+    let mut open = state.open;
+    let mut action = None;
+    egui::Window::new("Problems")
+        .open(&mut open)
+        .default_width(420.0)
+        .show(ctx, |ui| {
+            let Some(problems) = &state.problems else {
+                return;
+            };
+
+            ui.horizontal(|ui| {
+                ui.label(format!("{} problems", problems.len()));
+                if ui.button("⟳ Rescan").clicked() {
+                    action = Some(ProblemsAction::Rescan);
+                }
+            });
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .max_height(300.0)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    egui::Grid::new("problems_grid")
+                        .num_columns(2)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for problem in problems {
+                                ui.label(problem.message());
+                                match &problem.location {
+                                    ProblemLocation::Entity(entity) => {
+                                        ui.horizontal(|ui| {
+                                            ui.weak(entity_label(world, *entity));
+                                            if ui.small_button("Select").clicked() {
+                                                action = Some(ProblemsAction::Select(*entity));
+                                            }
+                                        });
+                                    }
+                                    ProblemLocation::SceneFile { file, entity } => {
+                                        let location = match entity {
+                                            Some(entity) => format!("{} › {}", file, entity),
+                                            None => file.clone(),
+                                        };
+                                        ui.weak(location);
+                                    }
+                                }
+                                ui.end_row();
+                            }
+                        });
+                    if problems.is_empty() {
+                        ui.weak("No problems found.");
+                    }
+                });
+        });
+    *world.resource_mut::<ProblemsState>() = ProblemsState { open, ..state };
+
+    match action {
+        Some(ProblemsAction::Rescan) => rescan(world),
+        Some(ProblemsAction::Select(entity)) => {
+            if world.get_entity(entity).is_err() {
+                rescan(world);
+                return;
+            }
+            focus_entity(world, entity);
+        }
+        None => {}
+    }
+}
+
+/// Selects `entity` and centers the viewport on it.
+pub(crate) fn focus_entity(world: &mut World, entity: Entity) {
+    world.resource_mut::<EditorSelection>().select(entity);
+    if let Some(transform) = world.get::<GlobalTransform>(entity) {
+        let target = transform.translation().truncate();
+        center_editor_camera(world, target);
+    }
+}
+
+fn rescan(world: &mut World) {
+    let problems = find_problems(world);
+    world.resource_mut::<ProblemsState>().problems = Some(problems);
+}
@@ -0,0 +1,533 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Reflection-driven inspector for components without a hand-written editor.
+//!
+//! Any component registered with `#[reflect(Component)]` is edited through
+//! its reflected shape: structs and tuples as labeled rows, enums as a variant
+//! dropdown (including `Option`), `Vec`s with add/remove buttons, and common
+//! math types as compact rows. The component is edited as a copy and written
+//! back only when a value changes, so change detection stays quiet.
+//! Fields declaring `InspectorOptions` get their range, drag step, and tooltip.
+//! Single-row top-level fields have a 📌 toggle; see [`crate::pins`].
+
+use bevy::prelude::*;
+use bevy::reflect::{
+    DynamicEnum, PartialReflect, ReflectFromReflect, ReflectKind, ReflectMut, ReflectRef, TypeInfo,
+    TypeRegistry,
+};
+use bevy::utils::HashSet;
+use bevy_egui::egui;
+use sandbox_engine::inspector::{default_value, default_variant, InspectorOptions};
+use sandbox_engine::scene::SceneManager;
+use std::any::TypeId;
+
+use super::fields::{field_widget_with_options, options_drag, with_tooltip, FieldValue};
+use crate::pins::{record_pinned_edits, FieldPin, PinnedFields};
+use crate::undo::{record_component_edit, snapshot_components};
+
+/// Maximum nesting depth shown before values are elided.
+const MAX_DEPTH: usize = 8;
+
+/// Displays an editor for a reflect-registered component.
+///
+/// Returns `false` if the component cannot be edited through reflection
+/// (not registered, or missing `ReflectComponent`/`FromReflect`).
+pub fn reflect_component_ui(
+    ui: &mut egui::Ui,
+    world: &mut World,
+    entity: Entity,
+    type_id: Option<TypeId>,
+    label: &str,
+) -> bool {
+    let Some(type_id) = type_id else {
+        return false;
+    };
+
+    let pinned_before = world
+        .resource::<PinnedFields>()
+        .pinned_fields(entity, type_id);
+    let mut pinned = pinned_before.clone();
+
+    let (reflect_component, edited) = {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        let Some(registration) = type_registry.get(type_id) else {
+            return false;
+        };
+        let (Some(reflect_component), Some(from_reflect)) = (
+            registration.data::<ReflectComponent>(),
+            registration.data::<ReflectFromReflect>(),
+        ) else {
+            return false;
+        };
+
+        // Edit a concrete copy so nested values keep their real types
+        let Some(mut value) = world
+            .get_entity(entity)
+            .ok()
+            .and_then(|entity_ref| reflect_component.reflect(entity_ref))
+            .and_then(|component| from_reflect.from_reflect(component.as_partial_reflect()))
+        else {
+            return false;
+        };
+
+        let changed = value_ui(
+            ui,
+            value.as_partial_reflect_mut(),
+            &type_registry,
+            0,
+            Some(&mut pinned),
+        );
+        (reflect_component.clone(), changed.then_some(value))
+    };
+
+    if pinned != pinned_before {
+        let mut pins = world.resource_mut::<PinnedFields>();
+        for field in pinned.symmetric_difference(&pinned_before) {
+            let pin = FieldPin {
+                entity,
+                type_id,
+                field: field.clone(),
+            };
+            pins.set_pinned(pin, pinned.contains(field));
+        }
+    }
+
+    let Some(value) = edited else {
+        return true;
+    };
+
+    let before = snapshot_components(world, &[entity], type_id);
+    if let Some((_, Some(old))) = before.first() {
+        record_pinned_edits(
+            world,
+            entity,
+            type_id,
+            old.as_ref(),
+            value.as_partial_reflect(),
+        );
+    }
+    let component = world
+        .get_entity_mut(entity)
+        .ok()
+        .and_then(|entity_mut| reflect_component.reflect_mut(entity_mut));
+    if let Some(mut component) = component {
+        if component.set(value).is_err() {
+            return true;
+        }
+    }
+    record_component_edit(world, label, type_id, before);
+
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
+    }
+    true
+}
+
+/// Displays an editor for a reflected value. Returns `true` if it changed.
+///
+/// `pins` are the pinned field names when `value` is a component, so its
+/// fields get pin toggles.
+fn value_ui(
+    ui: &mut egui::Ui,
+    value: &mut dyn PartialReflect,
+    registry: &TypeRegistry,
+    depth: usize,
+    mut pins: Option<&mut HashSet<String>>,
+) -> bool {
+    if is_inline(value) {
+        return inline_ui(ui, value, &InspectorOptions::default());
+    }
+    if depth >= MAX_DEPTH {
+        ui.weak("…");
+        return false;
+    }
+
+    if let ReflectRef::Enum(_) = value.reflect_ref() {
+        let changed = variant_combo(ui, value, registry);
+        return enum_fields_ui(ui, value, registry, depth) || changed;
+    }
+    if let ReflectRef::List(_) = value.reflect_ref() {
+        return list_ui(ui, value, registry, depth);
+    }
+
+    let info = value.get_represented_type_info();
+    let field_options = |index| info.and_then(|info| InspectorOptions::of_field(info, index));
+    let mut changed = false;
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => {
+            for index in 0..value.field_len() {
+                let name = value.name_at(index).unwrap_or_default().to_string();
+                if let Some(field) = value.field_at_mut(index) {
+                    let options = field_options(index);
+                    let pins = pins.as_deref_mut();
+                    changed |= field_row(ui, &name, field, options, pins, registry, depth);
+                }
+            }
+        }
+        ReflectMut::TupleStruct(value) => {
+            for index in 0..value.field_len() {
+                if let Some(field) = value.field_mut(index) {
+                    let options = field_options(index);
+                    let pins = pins.as_deref_mut();
+                    let label = index.to_string();
+                    changed |= field_row(ui, &label, field, options, pins, registry, depth);
+                }
+            }
+        }
+        ReflectMut::Tuple(value) => {
+            for index in 0..value.field_len() {
+                if let Some(field) = value.field_mut(index) {
+                    changed |=
+                        field_row(ui, &index.to_string(), field, None, None, registry, depth);
+                }
+            }
+        }
+        ReflectMut::Array(value) => {
+            for index in 0..value.len() {
+                if let Some(item) = value.get_mut(index) {
+                    let label = format!("[{}]", index);
+                    changed |= field_row(ui, &label, item, None, None, registry, depth);
+                }
+            }
+        }
+        ReflectMut::Map(value) => {
+            ui.weak(format!("{} entries (read-only)", value.len()));
+        }
+        ReflectMut::Set(value) => {
+            ui.weak(format!("{} entries (read-only)", value.len()));
+        }
+        _ => {
+            ui.weak(format!("{:?}", value));
+        }
+    }
+    changed
+}
+
+/// Displays a labeled field: inline values on one row, enums as a dropdown
+/// with their fields below, and other values in a collapsible section.
+///
+/// `options` are the field's declared `InspectorOptions`, if any. With `pins`,
+/// single-row fields get a toggle pinning the field named `label`.
+fn field_row(
+    ui: &mut egui::Ui,
+    label: &str,
+    value: &mut dyn PartialReflect,
+    options: Option<&InspectorOptions>,
+    pins: Option<&mut HashSet<String>>,
+    registry: &TypeRegistry,
+    depth: usize,
+) -> bool {
+    let options = options.copied().unwrap_or_default();
+    if is_inline(value) {
+        return ui
+            .horizontal(|ui| {
+                pin_toggle(ui, pins, label);
+                with_tooltip(ui.label(format!("{}:", label)), &options);
+                inline_ui(ui, value, &options)
+            })
+            .inner;
+    }
+
+    if let ReflectRef::Enum(_) = value.reflect_ref() {
+        let mut changed = ui
+            .horizontal(|ui| {
+                pin_toggle(ui, pins, label);
+                with_tooltip(ui.label(format!("{}:", label)), &options);
+                variant_combo(ui, value, registry)
+            })
+            .inner;
+        if enum_field_len(value) > 0 {
+            ui.indent(label, |ui| {
+                changed |= enum_fields_ui(ui, value, registry, depth + 1);
+            });
+        }
+        return changed;
+    }
+
+    let response = egui::CollapsingHeader::new(label)
+        .id_salt(label)
+        .default_open(depth == 0)
+        .show(ui, |ui| value_ui(ui, value, registry, depth + 1, None));
+    with_tooltip(response.header_response, &options);
+    response.body_returned.unwrap_or(false)
+}
+
+/// Shows the 📌 toggle for `field`, if pins are offered.
+fn pin_toggle(ui: &mut egui::Ui, pins: Option<&mut HashSet<String>>, field: &str) {
+    let Some(pins) = pins else {
+        return;
+    };
+    let pinned = pins.contains(field);
+    let icon = if pinned {
+        egui::RichText::new("📌")
+    } else {
+        egui::RichText::new("📌").weak()
+    };
+    if ui
+        .add(egui::SelectableLabel::new(pinned, icon))
+        .on_hover_text("Pin: keep edits made during play after Stop")
+        .clicked()
+    {
+        if pinned {
+            pins.remove(field);
+        } else {
+            pins.insert(field.to_string());
+        }
+    }
+}
+
+/// Types shown on a single row.
+fn inline_types() -> [TypeId; 7] {
+    [
+        TypeId::of::<Color>(),
+        TypeId::of::<Vec2>(),
+        TypeId::of::<Vec3>(),
+        TypeId::of::<Vec4>(),
+        TypeId::of::<IVec2>(),
+        TypeId::of::<UVec2>(),
+        TypeId::of::<Quat>(),
+    ]
+}
+
+/// Whether a value is shown on a single row rather than expanded.
+fn is_inline(value: &dyn PartialReflect) -> bool {
+    value.reflect_kind() == ReflectKind::Opaque
+        || is_handle(value)
+        || value
+            .get_represented_type_info()
+            .is_some_and(|info| inline_types().contains(&info.type_id()))
+}
+
+/// Asset handles are shown read-only; their variants are not meaningful to edit.
+fn is_handle(value: &dyn PartialReflect) -> bool {
+    value
+        .reflect_type_path()
+        .starts_with("bevy_asset::handle::Handle<")
+}
+
+/// Displays the single-row editor for an inline value, honoring the field's options.
+fn inline_ui(
+    ui: &mut egui::Ui,
+    value: &mut dyn PartialReflect,
+    options: &InspectorOptions,
+) -> bool {
+    if is_handle(value) {
+        ui.weak(format!("{:?}", value));
+        return false;
+    }
+
+    if let Some(mut field) = FieldValue::read(value) {
+        let changed = field_widget_with_options(ui, &mut field, false, options);
+        if changed {
+            field.write(value);
+        }
+        return changed;
+    }
+
+    macro_rules! drag_integers {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(v) = value.try_downcast_mut::<$ty>() {
+                    let widget = options_drag(egui::DragValue::new(v), options, 1.0);
+                    return with_tooltip(ui.add(widget), options).changed();
+                }
+            )*
+        };
+    }
+    drag_integers!(u8, u16, u64, i8, i16, i64, isize);
+
+    if let Some(v) = value.try_downcast_mut::<Vec2>() {
+        let mut values = v.to_array();
+        let changed = drag_components(ui, &mut values, options);
+        *v = Vec2::from_array(values);
+        return changed;
+    }
+    if let Some(v) = value.try_downcast_mut::<Vec3>() {
+        let mut values = v.to_array();
+        let changed = drag_components(ui, &mut values, options);
+        *v = Vec3::from_array(values);
+        return changed;
+    }
+    if let Some(v) = value.try_downcast_mut::<Vec4>() {
+        let mut values = v.to_array();
+        let changed = drag_components(ui, &mut values, options);
+        *v = Vec4::from_array(values);
+        return changed;
+    }
+    if let Some(v) = value.try_downcast_mut::<IVec2>() {
+        ui.label("x");
+        let x = ui.add(options_drag(egui::DragValue::new(&mut v.x), options, 1.0));
+        ui.label("y");
+        let y = ui.add(options_drag(egui::DragValue::new(&mut v.y), options, 1.0));
+        return x.changed() || y.changed();
+    }
+    if let Some(v) = value.try_downcast_mut::<UVec2>() {
+        ui.label("x");
+        let x = ui.add(options_drag(egui::DragValue::new(&mut v.x), options, 1.0));
+        ui.label("y");
+        let y = ui.add(options_drag(egui::DragValue::new(&mut v.y), options, 1.0));
+        return x.changed() || y.changed();
+    }
+    if let Some(v) = value.try_downcast_mut::<Quat>() {
+        // 2D rotations are shown as degrees around Z
+        let (_, _, z_rad) = v.to_euler(EulerRot::XYZ);
+        let mut z_deg = z_rad.to_degrees();
+        ui.label("z°");
+        if ui
+            .add(egui::DragValue::new(&mut z_deg).speed(1.0))
+            .changed()
+        {
+            *v = Quat::from_rotation_z(z_deg.to_radians());
+            return true;
+        }
+        return false;
+    }
+
+    ui.weak(format!("{:?}", value));
+    false
+}
+
+/// Shows labeled drag values for the components of a float vector.
+fn drag_components(ui: &mut egui::Ui, values: &mut [f32], options: &InspectorOptions) -> bool {
+    let mut changed = false;
+    for (label, value) in ["x", "y", "z", "w"].into_iter().zip(values.iter_mut()) {
+        ui.label(label);
+        let widget = options_drag(egui::DragValue::new(value), options, 0.1);
+        changed |= with_tooltip(ui.add(widget), options).changed();
+    }
+    changed
+}
+
+/// Shows a dropdown of an enum's variants. Returns `true` if the variant changed.
+///
+/// Variants whose fields have no registered `Default` cannot be selected.
+fn variant_combo(
+    ui: &mut egui::Ui,
+    value: &mut dyn PartialReflect,
+    registry: &TypeRegistry,
+) -> bool {
+    let (Some(TypeInfo::Enum(info)), ReflectRef::Enum(current)) =
+        (value.get_represented_type_info(), value.reflect_ref())
+    else {
+        return false;
+    };
+    let current = current.variant_name().to_string();
+
+    let mut selected = None;
+    egui::ComboBox::from_id_salt("variant")
+        .selected_text(&current)
+        .show_ui(ui, |ui| {
+            for variant in info.iter() {
+                let default = default_variant(variant, registry);
+                let response = ui.add_enabled(
+                    default.is_some(),
+                    egui::SelectableLabel::new(variant.name() == current, variant.name()),
+                );
+                if response.clicked() && variant.name() != current {
+                    selected = default.map(|fields| DynamicEnum::new(variant.name(), fields));
+                }
+            }
+        });
+
+    selected.is_some_and(|new_value| value.try_apply(&new_value).is_ok())
+}
+
+/// Number of fields of an enum value's current variant.
+fn enum_field_len(value: &dyn PartialReflect) -> usize {
+    match value.reflect_ref() {
+        ReflectRef::Enum(value) => value.field_len(),
+        _ => 0,
+    }
+}
+
+/// Displays the fields of an enum value's current variant.
+fn enum_fields_ui(
+    ui: &mut egui::Ui,
+    value: &mut dyn PartialReflect,
+    registry: &TypeRegistry,
+    depth: usize,
+) -> bool {
+    let info = value.get_represented_type_info();
+    let ReflectMut::Enum(value) = value.reflect_mut() else {
+        return false;
+    };
+    let variant = value.variant_name().to_string();
+
+    // A single unnamed field, as in `Some(x)`, is shown without a label
+    if value.field_len() == 1 && value.name_at(0).is_none() {
+        return value
+            .field_at_mut(0)
+            .is_some_and(|field| value_ui(ui, field, registry, depth, None));
+    }
+
+    let mut changed = false;
+    for index in 0..value.field_len() {
+        let label = value
+            .name_at(index)
+            .map(str::to_string)
+            .unwrap_or_else(|| index.to_string());
+        let options =
+            info.and_then(|info| InspectorOptions::of_variant_field(info, &variant, index));
+        if let Some(field) = value.field_at_mut(index) {
+            changed |= field_row(ui, &label, field, options, None, registry, depth);
+        }
+    }
+    changed
+}
+
+/// Displays a list's items with remove buttons and an add button.
+fn list_ui(
+    ui: &mut egui::Ui,
+    value: &mut dyn PartialReflect,
+    registry: &TypeRegistry,
+    depth: usize,
+) -> bool {
+    let item_type = match value.get_represented_type_info() {
+        Some(TypeInfo::List(info)) => Some(info.item_ty().id()),
+        _ => None,
+    };
+    let ReflectMut::List(list) = value.reflect_mut() else {
+        return false;
+    };
+
+    let mut changed = false;
+    let mut removed = None;
+    for index in 0..list.len() {
+        let Some(item) = list.get_mut(index) else {
+            continue;
+        };
+        ui.push_id(index, |ui| {
+            ui.horizontal(|ui| {
+                if ui.small_button("🗑").on_hover_text("Remove").clicked() {
+                    removed = Some(index);
+                }
+                ui.vertical(|ui| {
+                    let label = format!("[{}]", index);
+                    changed |= field_row(ui, &label, item, None, None, registry, depth);
+                });
+            });
+        });
+    }
+    if list.is_empty() {
+        ui.weak("Empty");
+    }
+
+    if let Some(index) = removed {
+        list.remove(index);
+        changed = true;
+    }
+
+    let default = item_type.and_then(|type_id| default_value(type_id, registry));
+    if ui
+        .add_enabled(default.is_some(), egui::Button::new("+ Add"))
+        .clicked()
+    {
+        if let Some(default) = default {
+            list.push(default);
+            changed = true;
+        }
+    }
+    changed
+}
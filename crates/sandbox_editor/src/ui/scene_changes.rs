@@ -0,0 +1,219 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Changes window: what saving would change in the scene file.
+//!
+//! Lists added and removed entities, and the components and scene resources
+//! that differ from the file, with a line diff of each value's RON. The diff
+//! is refreshed when the window opens, when the scene is saved or edited for
+//! the first time since, and on Refresh.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::scene::{SceneManager, SceneResult};
+
+use crate::scene_diff::{diff_lines, diff_scene_with_file, Change, DiffLine, SceneDiff, ValueDiff};
+use crate::selection::EditorSelection;
+
+/// Text color of added lines and entities.
+const ADDED_COLOR: egui::Color32 = egui::Color32::from_rgb(110, 200, 110);
+
+/// Text color of removed lines and entities.
+const REMOVED_COLOR: egui::Color32 = egui::Color32::from_rgb(230, 110, 100);
+
+/// Text color of modified entities.
+const MODIFIED_COLOR: egui::Color32 = egui::Color32::from_rgb(220, 190, 90);
+
+/// State for the Changes window.
+#[derive(Resource, Default)]
+pub struct SceneChangesState {
+    /// Whether the window is open.
+    pub open: bool,
+    /// Result of the last comparison; `None` until the window compares.
+    pub diff: Option<SceneResult<SceneDiff>>,
+    /// The scene's dirty flag when it was last compared.
+    compared_dirty: bool,
+}
+
+impl SceneChangesState {
+    /// Opens the window and compares on the next frame.
+    pub fn open(&mut self) {
+        self.open = true;
+        self.diff = None;
+    }
+}
+
+/// Displays the Changes window.
+pub fn scene_changes_window(ctx: &egui::Context, world: &mut World) {
+    if !world.resource::<SceneChangesState>().open {
+        return;
+    }
+    let dirty = world
+        .get_resource::<SceneManager>()
+        .is_some_and(|manager| manager.dirty);
+    let stale = {
+        let state = world.resource::<SceneChangesState>();
+        state.diff.is_none() || state.compared_dirty != dirty
+    };
+    if stale {
+        refresh(world);
+    }
+
+    let mut state = std::mem::take(&mut *world.resource_mut::<SceneChangesState>());
+    let never_saved = world
+        .get_resource::<SceneManager>()
+        .is_none_or(|manager| manager.current_scene_path.is_none());
+    let mut open = state.open;
+    let mut refresh_clicked = false;
+    let mut select = None;
+    egui::Window::new("Changes")
+        .open(&mut open)
+        .default_width(420.0)
+        .show(ctx, |ui| {
+            let Some(diff) = &state.diff else {
+                return;
+            };
+            ui.horizontal(|ui| {
+                match diff {
+                    Ok(diff) if diff.is_empty() => ui.label("No unsaved changes"),
+                    Ok(diff) => ui.label(summary(diff)),
+                    Err(e) => ui.colored_label(REMOVED_COLOR, e.to_string()),
+                };
+                if ui.button("⟳ Refresh").clicked() {
+                    refresh_clicked = true;
+                }
+            });
+            if never_saved {
+                ui.weak("The scene hasn't been saved yet, so everything in it is new.");
+            }
+            let Ok(diff) = diff else {
+                return;
+            };
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .max_height(400.0)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    if !diff.resources.is_empty() {
+                        egui::CollapsingHeader::new("Scene Resources")
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                for resource in &diff.resources {
+                                    value_diff(ui, resource);
+                                }
+                            });
+                    }
+                    for entity in &diff.entities {
+                        let (marker, color) = change_style(entity.change);
+                        let title = egui::RichText::new(format!(
+                            "{} {} (#{})",
+                            marker, entity.name, entity.key
+                        ))
+                        .color(color);
+                        egui::CollapsingHeader::new(title)
+                            .id_salt(("scene_change", entity.key))
+                            .show(ui, |ui| {
+                                if let Some(live) = entity.entity {
+                                    if ui.small_button("Select").clicked() {
+                                        select = Some(live);
+                                    }
+                                }
+                                if entity.change == Change::Modified {
+                                    for component in &entity.components {
+                                        value_diff(ui, component);
+                                    }
+                                } else {
+                                    for component in &entity.components {
+                                        ui.label(&component.name);
+                                    }
+                                }
+                            });
+                    }
+                });
+        });
+    state.open = open;
+    *world.resource_mut::<SceneChangesState>() = state;
+
+    if refresh_clicked {
+        refresh(world);
+    }
+    if let Some(entity) = select {
+        world.resource_mut::<EditorSelection>().select(entity);
+    }
+}
+
+/// Compares the scene with its file again.
+fn refresh(world: &mut World) {
+    let dirty = world
+        .get_resource::<SceneManager>()
+        .is_some_and(|manager| manager.dirty);
+    let diff = diff_scene_with_file(world);
+    let mut state = world.resource_mut::<SceneChangesState>();
+    state.diff = Some(diff);
+    state.compared_dirty = dirty;
+}
+
+/// One-line count of the differences, such as "1 added, 2 modified".
+fn summary(diff: &SceneDiff) -> String {
+    let mut parts: Vec<String> = [
+        (Change::Added, "added"),
+        (Change::Removed, "removed"),
+        (Change::Modified, "modified"),
+    ]
+    .into_iter()
+    .filter_map(|(change, label)| {
+        let count = diff.entity_count(change);
+        (count > 0).then(|| format!("{} {}", count, label))
+    })
+    .collect();
+    if !diff.resources.is_empty() {
+        parts.push(format!("{} resources changed", diff.resources.len()));
+    }
+    parts.join(", ")
+}
+
+/// Marker and color for a change.
+fn change_style(change: Change) -> (&'static str, egui::Color32) {
+    match change {
+        Change::Added => ("+", ADDED_COLOR),
+        Change::Removed => ("−", REMOVED_COLOR),
+        Change::Modified => ("~", MODIFIED_COLOR),
+    }
+}
+
+/// Shows a component or resource that differs from the file, with its RON
+/// and the lines that changed.
+fn value_diff(ui: &mut egui::Ui, value: &ValueDiff) {
+    let (marker, color) = change_style(value.change());
+    ui.colored_label(color, format!("{} {}", marker, value.name));
+    let before = value.before.as_deref().unwrap_or_default();
+    let after = value.after.as_deref().unwrap_or_default();
+    ui.indent(&value.type_path, |ui| {
+        for line in diff_lines(before, after) {
+            match line {
+                DiffLine::Same(text) => {
+                    ui.label(
+                        egui::RichText::new(format!("  {}", text))
+                            .monospace()
+                            .weak(),
+                    );
+                }
+                DiffLine::Removed(text) => {
+                    ui.label(
+                        egui::RichText::new(format!("- {}", text))
+                            .monospace()
+                            .color(REMOVED_COLOR),
+                    );
+                }
+                DiffLine::Added(text) => {
+                    ui.label(
+                        egui::RichText::new(format!("+ {}", text))
+                            .monospace()
+                            .color(ADDED_COLOR),
+                    );
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,241 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Text search over the serialized form of the current scene (Ctrl+F).
+//!
+//! Every component that would be saved with the scene is serialized to RON
+//! and searched case-insensitively, so names, asset paths, and field values
+//! of any reflected component can be found. Clicking a hit selects its entity
+//! and opens the component's section in the inspector. Hits are kept until
+//! the query changes or a component of a scene entity is added or changed,
+//! whether by an edit or at runtime.
+
+use bevy::ecs::component::Tick;
+use bevy::prelude::*;
+use bevy::reflect::serde::TypedReflectSerializer;
+use bevy::scene::ron;
+use bevy_egui::egui;
+use sandbox_engine::scene::should_serialize_entity;
+use std::any::TypeId;
+
+use super::entity_picker::entity_label;
+use super::inspector::InspectorFocus;
+use crate::selection::EditorSelection;
+
+/// Characters of context shown on each side of a match.
+const SNIPPET_CONTEXT: usize = 24;
+
+/// Most hits listed at once.
+const MAX_HITS: usize = 200;
+
+/// State for the scene search window.
+#[derive(Resource, Default)]
+pub struct SceneSearchState {
+    /// Whether the window is open.
+    pub open: bool,
+    /// Text to search for.
+    pub query: String,
+    /// Whether the query field should take keyboard focus next frame.
+    focus_query: bool,
+    /// Hits of the last search.
+    hits: Vec<SceneSearchHit>,
+    /// The query of the last search and the change tick it saw changes up
+    /// to; `None` until the window searches.
+    searched: Option<(String, Tick)>,
+}
+
+impl SceneSearchState {
+    /// Opens the window with keyboard focus in the query field, searching
+    /// again on the next frame.
+    pub fn open(&mut self) {
+        self.open = true;
+        self.focus_query = true;
+        self.searched = None;
+    }
+}
+
+/// A component whose serialized form contains the query.
+#[derive(Clone, Debug)]
+pub struct SceneSearchHit {
+    pub entity: Entity,
+    pub type_id: TypeId,
+    /// Short type name of the component.
+    pub component: String,
+    /// The match with some surrounding serialized text.
+    pub snippet: String,
+}
+
+/// Searches the serialized components of every scene entity for `query`, ignoring case.
+pub fn search_scene(world: &World, query: &str) -> Vec<SceneSearchHit> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let mut entities: Vec<Entity> = world
+        .iter_entities()
+        .filter(|entity| should_serialize_entity(world, entity))
+        .map(|entity| entity.id())
+        .collect();
+    entities.sort_by_key(|entity| entity.index());
+    let scene = DynamicSceneBuilder::from_world(world)
+        .extract_entities(entities.into_iter())
+        .build();
+
+    let mut hits = Vec::new();
+    for entity in &scene.entities {
+        for component in &entity.components {
+            let Some(type_info) = component.get_represented_type_info() else {
+                continue;
+            };
+            let serializer = TypedReflectSerializer::new(component.as_ref(), &type_registry);
+            let Ok(serialized) = ron::to_string(&serializer) else {
+                continue;
+            };
+            let Some(snippet) = snippet(&serialized, &query) else {
+                continue;
+            };
+            hits.push(SceneSearchHit {
+                entity: entity.entity,
+                type_id: type_info.type_id(),
+                component: type_info.type_path_table().short_path().to_string(),
+                snippet,
+            });
+        }
+    }
+    hits
+}
+
+/// The first case-insensitive match of `query` in `text`, with context on either side.
+fn snippet(text: &str, query: &str) -> Option<String> {
+    let lower = text.to_lowercase();
+    let start = lower.find(query)?;
+    // Lowercasing can change byte lengths; show the lowercased text then
+    let text = if lower.len() == text.len() {
+        text
+    } else {
+        &lower
+    };
+
+    let before: Vec<char> = text[..start].chars().collect();
+    let after: Vec<char> = text[start..].chars().collect();
+    let match_len = query.chars().count();
+    let from = before.len().saturating_sub(SNIPPET_CONTEXT);
+    let to = after.len().min(match_len + SNIPPET_CONTEXT);
+
+    let mut snippet = String::new();
+    if from > 0 {
+        snippet.push('…');
+    }
+    snippet.extend(&before[from..]);
+    snippet.extend(&after[..to]);
+    if to < after.len() {
+        snippet.push('…');
+    }
+    Some(snippet)
+}
+
+/// Whether a reflected component of an entity that would be saved was added
+/// or changed after `tick`.
+fn scene_changed_since(world: &World, tick: Tick) -> bool {
+    let this_run = world.read_change_tick();
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    world
+        .iter_entities()
+        .filter(|entity| should_serialize_entity(world, entity))
+        .any(|entity| {
+            entity.archetype().components().any(|id| {
+                let reflected = world
+                    .components()
+                    .get_info(id)
+                    .and_then(|info| info.type_id())
+                    .is_some_and(|type_id| {
+                        type_registry
+                            .get_type_data::<ReflectComponent>(type_id)
+                            .is_some()
+                    });
+                reflected
+                    && entity
+                        .get_change_ticks_by_id(id)
+                        .is_some_and(|ticks| ticks.is_changed(tick, this_run))
+            })
+        })
+}
+
+/// Displays the scene search window.
+pub fn scene_search_window(ctx: &egui::Context, world: &mut World) {
+    if !world.resource::<SceneSearchState>().open {
+        return;
+    }
+
+    let mut state = std::mem::take(&mut *world.resource_mut::<SceneSearchState>());
+    let stale = state.searched.as_ref().is_none_or(|(query, searched_at)| {
+        *query != state.query || scene_changed_since(world, *searched_at)
+    });
+    if stale {
+        state.hits = search_scene(world, &state.query);
+        // Changes from here on get a newer tick than the search saw
+        state.searched = Some((state.query.clone(), world.increment_change_tick()));
+    }
+    // Despawns and removals leave no change tick; skip their hits instead
+    let hits: Vec<&SceneSearchHit> = state
+        .hits
+        .iter()
+        .filter(|hit| {
+            world
+                .get_entity(hit.entity)
+                .is_ok_and(|entity| entity.contains_type_id(hit.type_id))
+        })
+        .collect();
+    let mut clicked = None;
+
+    egui::Window::new("Find in Scene")
+        .open(&mut state.open)
+        .default_width(360.0)
+        .show(ctx, |ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut state.query)
+                    .hint_text("Names, asset paths, field values")
+                    .desired_width(f32::INFINITY),
+            );
+            if std::mem::take(&mut state.focus_query) {
+                response.request_focus();
+            }
+            if state.query.trim().is_empty() {
+                return;
+            }
+
+            match hits.len() {
+                0 => ui.weak("No matches"),
+                1 => ui.weak("1 match"),
+                count if count > MAX_HITS => {
+                    ui.weak(format!("First {} of {} matches", MAX_HITS, count))
+                }
+                count => ui.weak(format!("{} matches", count)),
+            };
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .max_height(300.0)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    for hit in hits.iter().take(MAX_HITS) {
+                        let label =
+                            format!("{} › {}", entity_label(world, hit.entity), hit.component);
+                        if ui.selectable_label(false, label).clicked() {
+                            clicked = Some((hit.entity, hit.type_id));
+                        }
+                        ui.weak(&hit.snippet);
+                    }
+                });
+        });
+    *world.resource_mut::<SceneSearchState>() = state;
+
+    if let Some((entity, type_id)) = clicked {
+        world.resource_mut::<EditorSelection>().select(entity);
+        world
+            .resource_mut::<InspectorFocus>()
+            .reveal(entity, type_id);
+    }
+}
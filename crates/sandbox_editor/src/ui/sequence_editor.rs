@@ -0,0 +1,481 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Timeline window for authoring sequence files, opened from the asset browser.
+//!
+//! Steps are drawn as bars at their start time; steps that run alongside the
+//! previous one share its start. Editing happens on the selected step, and
+//! Save writes the file.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::scene_entities;
+use sandbox_engine::sequence::{Sequence, SequenceCommand, SequenceStep};
+
+use super::file_menu::{set_error_message, set_success_message};
+use crate::editor_camera::ViewportCamera;
+
+/// Width of one second on the timeline, in points.
+const PIXELS_PER_SECOND: f32 = 80.0;
+
+/// Seconds drawn for steps that last until the player finishes them.
+const OPEN_ENDED_SECONDS: f32 = 1.5;
+
+/// State for the sequence editor window.
+#[derive(Resource, Default)]
+pub struct SequenceEditorState {
+    /// Sequence file being edited; the window is open while set.
+    pub path: Option<String>,
+    /// The sequence as edited; `None` until the file is loaded.
+    pub sequence: Option<Sequence>,
+    /// Index of the selected step.
+    pub selected: Option<usize>,
+    /// Whether the sequence has changed since it was loaded or saved.
+    pub unsaved: bool,
+}
+
+impl SequenceEditorState {
+    /// Opens the window for `path` and loads it on the next frame.
+    pub fn open(&mut self, path: impl Into<String>) {
+        *self = Self {
+            path: Some(path.into()),
+            ..default()
+        };
+    }
+}
+
+/// Action chosen in the sequence editor.
+enum SequenceEditorAction {
+    Save,
+    Reload,
+    Add(SequenceCommand),
+    Select(usize),
+    MoveUp(usize),
+    MoveDown(usize),
+    Remove(usize),
+}
+
+/// Commands offered by "+ Add Step", with their starting values.
+fn new_commands() -> [SequenceCommand; 5] {
+    [
+        SequenceCommand::Wait(1.0),
+        SequenceCommand::MoveAlong {
+            entity: String::new(),
+            path: Vec::new(),
+            duration: 1.0,
+        },
+        SequenceCommand::PlayAnimation {
+            entity: String::new(),
+        },
+        SequenceCommand::ShowDialogue {
+            dialogue: String::new(),
+            start: String::new(),
+        },
+        SequenceCommand::CameraPan {
+            to: Vec2::ZERO,
+            duration: 1.0,
+        },
+    ]
+}
+
+/// Displays the sequence editor window.
+pub fn sequence_editor_window(ctx: &egui::Context, world: &mut World) {
+    let Some(path) = world.resource::<SequenceEditorState>().path.clone() else {
+        return;
+    };
+    if world.resource::<SequenceEditorState>().sequence.is_none() && !load(world, &path) {
+        world.resource_mut::<SequenceEditorState>().path = None;
+        return;
+    }
+
+    // Named scene entities, for picking what moves and animates
+    let named: Vec<(String, Vec2)> = scene_entities(world)
+        .into_iter()
+        .filter_map(|entity| {
+            let entity = world.entity(entity);
+            let name = entity.get::<Name>()?.to_string();
+            let position = entity.get::<Transform>()?.translation.truncate();
+            Some((name, position))
+        })
+        .collect();
+    let view_center = ViewportCamera::from_world(world).map(|camera| camera.position);
+
+    let mut state = std::mem::take(&mut *world.resource_mut::<SequenceEditorState>());
+    let original = state.sequence.clone().unwrap_or_default();
+    let mut sequence = original.clone();
+    let mut open = true;
+    let mut action = None;
+    egui::Window::new(format!("Sequence: {}", path))
+        .id(egui::Id::new("sequence_editor"))
+        .open(&mut open)
+        .default_width(520.0)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(state.unsaved, egui::Button::new("💾 Save"))
+                    .clicked()
+                {
+                    action = Some(SequenceEditorAction::Save);
+                }
+                if ui.button("⟳ Reload").clicked() {
+                    action = Some(SequenceEditorAction::Reload);
+                }
+                ui.menu_button("+ Add Step", |ui| {
+                    for command in new_commands() {
+                        if ui.button(command.label()).clicked() {
+                            action = Some(SequenceEditorAction::Add(command));
+                            ui.close_menu();
+                        }
+                    }
+                });
+                if state.unsaved {
+                    ui.weak("Unsaved changes");
+                }
+            });
+            ui.separator();
+
+            if sequence.steps.is_empty() {
+                ui.weak("No steps yet. Add one to start the timeline.");
+            } else {
+                timeline(ui, &sequence, state.selected, &mut action);
+            }
+
+            let selected = state.selected.filter(|&index| index < sequence.steps.len());
+            if let Some(index) = selected {
+                ui.separator();
+                let count = sequence.steps.len();
+                step_details(
+                    ui,
+                    &mut sequence.steps[index],
+                    index,
+                    count,
+                    &named,
+                    view_center,
+                    &mut action,
+                );
+            }
+        });
+
+    if sequence != original {
+        state.sequence = Some(sequence);
+        state.unsaved = true;
+    }
+    if let Some(steps) = state.sequence.as_mut().map(|sequence| &mut sequence.steps) {
+        match &action {
+            Some(SequenceEditorAction::Add(command)) => {
+                let index = state.selected.map_or(steps.len(), |index| index + 1);
+                let index = index.min(steps.len());
+                steps.insert(
+                    index,
+                    SequenceStep {
+                        command: command.clone(),
+                        with_previous: false,
+                    },
+                );
+                state.selected = Some(index);
+                state.unsaved = true;
+            }
+            Some(SequenceEditorAction::Select(index)) => state.selected = Some(*index),
+            Some(SequenceEditorAction::MoveUp(index)) if *index > 0 => {
+                let index = *index;
+                steps.swap(index, index - 1);
+                state.selected = Some(index - 1);
+                state.unsaved = true;
+            }
+            Some(SequenceEditorAction::MoveDown(index)) if index + 1 < steps.len() => {
+                let index = *index;
+                steps.swap(index, index + 1);
+                state.selected = Some(index + 1);
+                state.unsaved = true;
+            }
+            Some(SequenceEditorAction::Remove(index)) if *index < steps.len() => {
+                steps.remove(*index);
+                state.selected = None;
+                state.unsaved = true;
+            }
+            _ => {}
+        }
+    }
+    if !open {
+        state = SequenceEditorState::default();
+    }
+    *world.resource_mut::<SequenceEditorState>() = state;
+
+    match action {
+        Some(SequenceEditorAction::Save) => save(world, &path),
+        Some(SequenceEditorAction::Reload) => {
+            load(world, &path);
+        }
+        _ => {}
+    }
+}
+
+/// Draws the time ruler and a bar per step; clicking a bar selects it.
+fn timeline(
+    ui: &mut egui::Ui,
+    sequence: &Sequence,
+    selected: Option<usize>,
+    action: &mut Option<SequenceEditorAction>,
+) {
+    let starts = sequence.start_times(OPEN_ENDED_SECONDS);
+    let length = sequence.length(OPEN_ENDED_SECONDS);
+    egui::ScrollArea::horizontal()
+        .id_salt("sequence_timeline")
+        .show(ui, |ui| {
+            let width = (length.ceil() + 1.0) * PIXELS_PER_SECOND;
+            let (rect, _) = ui.allocate_exact_size(egui::vec2(width, 16.0), egui::Sense::hover());
+            let painter = ui.painter_at(rect);
+            let color = ui.visuals().weak_text_color();
+            for second in 0..=length.ceil() as u32 {
+                let x = rect.left() + second as f32 * PIXELS_PER_SECOND;
+                painter.line_segment(
+                    [
+                        egui::pos2(x, rect.bottom() - 4.0),
+                        egui::pos2(x, rect.bottom()),
+                    ],
+                    egui::Stroke::new(1.0, color),
+                );
+                painter.text(
+                    egui::pos2(x + 2.0, rect.top()),
+                    egui::Align2::LEFT_TOP,
+                    format!("{}s", second),
+                    egui::FontId::proportional(10.0),
+                    color,
+                );
+            }
+
+            for (index, (step, start)) in sequence.steps.iter().zip(&starts).enumerate() {
+                let duration = step.command.duration();
+                let bar_width = duration.unwrap_or(OPEN_ENDED_SECONDS) * PIXELS_PER_SECOND;
+                ui.horizontal(|ui| {
+                    ui.add_space(start * PIXELS_PER_SECOND);
+                    let label = match duration {
+                        Some(_) => step.command.label().to_string(),
+                        None => format!("{} …", step.command.label()),
+                    };
+                    let response = ui
+                        .add_sized(
+                            [bar_width.max(24.0), 18.0],
+                            egui::SelectableLabel::new(selected == Some(index), label),
+                        )
+                        .on_hover_text(step_summary(step, *start));
+                    if response.clicked() {
+                        *action = Some(SequenceEditorAction::Select(index));
+                    }
+                });
+            }
+        });
+}
+
+/// Hover text for a timeline bar: what the step does and when.
+fn step_summary(step: &SequenceStep, start: f32) -> String {
+    let what = match &step.command {
+        SequenceCommand::Wait(seconds) => format!("Wait {:.1}s", seconds),
+        SequenceCommand::MoveAlong { entity, path, .. } => {
+            format!("Move '{}' through {} points", entity, path.len())
+        }
+        SequenceCommand::PlayAnimation { entity } => format!("Play '{}''s animation", entity),
+        SequenceCommand::ShowDialogue { dialogue, .. } => {
+            format!("Show {} until it ends", dialogue)
+        }
+        SequenceCommand::CameraPan { to, .. } => {
+            format!("Pan the camera to {:.0}, {:.0}", to.x, to.y)
+        }
+    };
+    format!("{} (starts at {:.1}s)", what, start)
+}
+
+/// Fields of the selected step, and buttons to reorder or remove it.
+fn step_details(
+    ui: &mut egui::Ui,
+    step: &mut SequenceStep,
+    index: usize,
+    count: usize,
+    named: &[(String, Vec2)],
+    view_center: Option<Vec2>,
+    action: &mut Option<SequenceEditorAction>,
+) {
+    ui.horizontal(|ui| {
+        ui.strong(format!("{}. {}", index + 1, step.command.label()));
+        if ui
+            .add_enabled(index > 0, egui::Button::new("⬆"))
+            .on_hover_text("Move earlier")
+            .clicked()
+        {
+            *action = Some(SequenceEditorAction::MoveUp(index));
+        }
+        if ui
+            .add_enabled(index + 1 < count, egui::Button::new("⬇"))
+            .on_hover_text("Move later")
+            .clicked()
+        {
+            *action = Some(SequenceEditorAction::MoveDown(index));
+        }
+        if ui.button("🗑").on_hover_text("Remove step").clicked() {
+            *action = Some(SequenceEditorAction::Remove(index));
+        }
+    });
+    ui.add_enabled(
+        index > 0,
+        egui::Checkbox::new(&mut step.with_previous, "Start with previous step"),
+    );
+
+    egui::Grid::new("sequence_step")
+        .num_columns(2)
+        .show(ui, |ui| {
+            match &mut step.command {
+                SequenceCommand::Wait(seconds) => {
+                    ui.label("Seconds");
+                    seconds_field(ui, seconds);
+                    ui.end_row();
+                }
+                SequenceCommand::MoveAlong {
+                    entity,
+                    path,
+                    duration,
+                } => {
+                    ui.label("Entity");
+                    entity_field(ui, entity, named);
+                    ui.end_row();
+                    ui.label("Duration");
+                    seconds_field(ui, duration);
+                    ui.end_row();
+                    let mut removed = None;
+                    for (point_index, point) in path.iter_mut().enumerate() {
+                        ui.label(format!("Point {}", point_index + 1));
+                        ui.horizontal(|ui| {
+                            ui.add(egui::DragValue::new(&mut point.x).prefix("x: "));
+                            ui.add(egui::DragValue::new(&mut point.y).prefix("y: "));
+                            if ui.small_button("🗑").on_hover_text("Remove point").clicked() {
+                                removed = Some(point_index);
+                            }
+                        });
+                        ui.end_row();
+                    }
+                    if let Some(point_index) = removed {
+                        path.remove(point_index);
+                    }
+                    ui.label("");
+                    ui.horizontal(|ui| {
+                        if ui.button("+ Point").clicked() {
+                            // Continue from the last point, or from where the entity is
+                            let start = path.last().copied().or_else(|| {
+                                named
+                                    .iter()
+                                    .find(|(name, _)| name == entity)
+                                    .map(|(_, position)| *position)
+                            });
+                            path.push(start.unwrap_or_default());
+                        }
+                        if let Some(center) = view_center {
+                            if ui
+                                .button("+ View Center")
+                                .on_hover_text("Add a point at the middle of the viewport")
+                                .clicked()
+                            {
+                                path.push(center);
+                            }
+                        }
+                    });
+                    ui.end_row();
+                }
+                SequenceCommand::PlayAnimation { entity } => {
+                    ui.label("Entity");
+                    entity_field(ui, entity, named);
+                    ui.end_row();
+                }
+                SequenceCommand::ShowDialogue { dialogue, start } => {
+                    ui.label("Dialogue");
+                    ui.add(
+                        egui::TextEdit::singleline(dialogue).hint_text("dialogue/npc.dialogue.ron"),
+                    );
+                    ui.end_row();
+                    ui.label("Start node");
+                    ui.add(egui::TextEdit::singleline(start).hint_text("(dialogue's start)"));
+                    ui.end_row();
+                }
+                SequenceCommand::CameraPan { to, duration } => {
+                    ui.label("Target");
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut to.x).prefix("x: "));
+                        ui.add(egui::DragValue::new(&mut to.y).prefix("y: "));
+                        if let Some(center) = view_center {
+                            if ui
+                                .button("⌖")
+                                .on_hover_text("Use the middle of the viewport")
+                                .clicked()
+                            {
+                                *to = center;
+                            }
+                        }
+                    });
+                    ui.end_row();
+                    ui.label("Duration");
+                    seconds_field(ui, duration);
+                    ui.end_row();
+                }
+            }
+        });
+}
+
+/// Non-negative seconds.
+fn seconds_field(ui: &mut egui::Ui, seconds: &mut f32) {
+    ui.add(
+        egui::DragValue::new(seconds)
+            .speed(0.05)
+            .range(0.0..=f32::MAX)
+            .suffix(" s"),
+    );
+}
+
+/// Name of an entity, typed or picked from the scene's named entities.
+fn entity_field(ui: &mut egui::Ui, entity: &mut String, named: &[(String, Vec2)]) {
+    ui.horizontal(|ui| {
+        ui.add(egui::TextEdit::singleline(entity).hint_text("Entity name"));
+        ui.menu_button("▾", |ui| {
+            if named.is_empty() {
+                ui.weak("No named entities in the scene");
+            }
+            for (name, _) in named {
+                if ui.button(name).clicked() {
+                    *entity = name.clone();
+                    ui.close_menu();
+                }
+            }
+        });
+    });
+}
+
+/// Loads the sequence file into the editor; returns whether it loaded.
+fn load(world: &mut World, path: &str) -> bool {
+    let file = world.resource::<ProjectRoot>().resolve_asset(path);
+    match Sequence::load(&file) {
+        Ok(sequence) => {
+            let mut state = world.resource_mut::<SequenceEditorState>();
+            state.sequence = Some(sequence);
+            state.selected = None;
+            state.unsaved = false;
+            true
+        }
+        Err(e) => {
+            set_error_message(world, &format!("Failed to open {}: {}", path, e));
+            false
+        }
+    }
+}
+
+/// Writes the edited sequence to its file.
+fn save(world: &mut World, path: &str) {
+    let file = world.resource::<ProjectRoot>().resolve_asset(path);
+    let Some(sequence) = world.resource::<SequenceEditorState>().sequence.clone() else {
+        return;
+    };
+    match sequence.save(&file) {
+        Ok(()) => {
+            world.resource_mut::<SequenceEditorState>().unsaved = false;
+            set_success_message(world, &format!("Saved {}", path));
+        }
+        Err(e) => set_error_message(world, &format!("Failed to save {}: {}", path, e)),
+    }
+}
@@ -0,0 +1,139 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Confirmation shown when stopping play mode after the scene changed at runtime.
+//!
+//! "Keep Runtime Changes" leaves moved, spawned, and destroyed entities as
+//! they are, so values tuned while playing become part of the edited scene.
+//! "Discard" restores the scene as it was when play started. Pinned fields
+//! (see [`crate::pins`]) are re-applied either way.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::editor_state::{EditorPlayState, EditorSnapshot};
+use sandbox_engine::scene::SceneManager;
+
+use super::file_menu::set_success_message;
+
+/// State for the stop confirmation window.
+#[derive(Resource, Default)]
+pub struct StopPromptState {
+    /// Whether the window is open.
+    pub open: bool,
+    /// Whether the game was playing (not paused) when the prompt opened.
+    resume: bool,
+}
+
+/// Action chosen in the stop confirmation window.
+enum StopPromptAction {
+    Keep,
+    Discard,
+    Cancel,
+}
+
+/// Stops play mode, first asking what to do with runtime changes if there are any.
+///
+/// The game is paused while the prompt is open.
+pub fn request_stop(world: &mut World) {
+    let snapshot = world.resource::<EditorSnapshot>();
+    let changed = !snapshot.spawned_entities(world).is_empty()
+        || !snapshot.moved_entities(world).is_empty()
+        || !snapshot.despawned_entities(world).is_empty();
+    if !changed {
+        set_play_state(world, EditorPlayState::Stopped);
+        return;
+    }
+
+    let playing = *world.resource::<State<EditorPlayState>>().get() == EditorPlayState::Playing;
+    *world.resource_mut::<StopPromptState>() = StopPromptState {
+        open: true,
+        resume: playing,
+    };
+    if playing {
+        set_play_state(world, EditorPlayState::Paused);
+    }
+}
+
+/// Displays the stop confirmation window.
+pub fn stop_prompt(ctx: &egui::Context, world: &mut World) {
+    if !world.resource::<StopPromptState>().open {
+        return;
+    }
+    let snapshot = world.resource::<EditorSnapshot>();
+    let spawned = snapshot.spawned_entities(world).len();
+    let moved = snapshot.moved_entities(world).len();
+    let despawned = snapshot.despawned_entities(world).len();
+
+    let mut action = None;
+    egui::Window::new("Stop Play Mode")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label("The scene changed while playing:");
+            if moved > 0 {
+                ui.label(format!("• {} moved", entity_count(moved)));
+            }
+            if spawned > 0 {
+                ui.label(format!("• {} spawned", entity_count(spawned)));
+            }
+            if despawned > 0 {
+                ui.label(format!("• {} destroyed", entity_count(despawned)));
+            }
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui
+                    .button("Keep Runtime Changes")
+                    .on_hover_text("Stop and keep the play-mode state in the edited scene")
+                    .clicked()
+                {
+                    action = Some(StopPromptAction::Keep);
+                }
+                if ui
+                    .button("Discard")
+                    .on_hover_text("Stop and restore the scene from before play")
+                    .clicked()
+                {
+                    action = Some(StopPromptAction::Discard);
+                }
+                if ui.button("Cancel").clicked() {
+                    action = Some(StopPromptAction::Cancel);
+                }
+            });
+        });
+
+    let Some(action) = action else {
+        return;
+    };
+    let state = std::mem::take(&mut *world.resource_mut::<StopPromptState>());
+    match action {
+        StopPromptAction::Keep => {
+            world.resource_mut::<EditorSnapshot>().keep_runtime_changes = true;
+            set_play_state(world, EditorPlayState::Stopped);
+            if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+                manager.mark_dirty();
+            }
+            set_success_message(world, "Kept runtime changes");
+        }
+        StopPromptAction::Discard => set_play_state(world, EditorPlayState::Stopped),
+        StopPromptAction::Cancel => {
+            if state.resume {
+                set_play_state(world, EditorPlayState::Playing);
+            }
+        }
+    }
+}
+
+fn set_play_state(world: &mut World, state: EditorPlayState) {
+    if let Some(mut next_state) = world.get_resource_mut::<NextState<EditorPlayState>>() {
+        next_state.set(state);
+    }
+}
+
+fn entity_count(count: usize) -> String {
+    if count == 1 {
+        "1 entity".to_string()
+    } else {
+        format!("{} entities", count)
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Window for switching gameplay systems off one at a time.
+//!
+//! Lists the toggleable systems in `Update` by the sets they belong to, with
+//! a checkbox each, so a misbehaving system can be found during play by
+//! turning systems off until it stops. Toggles last until changed, across
+//! play sessions.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::system_toggle::{SystemToggles, ToggleableSystemInfo};
+
+/// State for the systems window.
+#[derive(Resource, Default)]
+pub struct SystemsWindowState {
+    /// Whether the window is open.
+    pub open: bool,
+    /// Only systems whose name contains this are shown.
+    pub filter: String,
+}
+
+/// Displays the systems window.
+pub fn systems_window(ctx: &egui::Context, world: &mut World) {
+    if !world.resource::<SystemsWindowState>().open {
+        return;
+    }
+
+    let mut state = std::mem::take(&mut *world.resource_mut::<SystemsWindowState>());
+    let toggles = world.resource::<SystemToggles>();
+    let systems: Vec<(ToggleableSystemInfo, bool)> = toggles
+        .systems()
+        .iter()
+        .map(|system| (system.clone(), toggles.is_enabled(&system.name)))
+        .collect();
+    let disabled_count = toggles.disabled_count();
+    let mut changes: Vec<(String, bool)> = Vec::new();
+    let mut enable_all = false;
+
+    egui::Window::new("Systems")
+        .open(&mut state.open)
+        .default_width(360.0)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut state.filter)
+                        .hint_text("Filter")
+                        .desired_width(160.0),
+                );
+                if ui
+                    .add_enabled(disabled_count > 0, egui::Button::new("Enable All"))
+                    .clicked()
+                {
+                    enable_all = true;
+                }
+            });
+            if disabled_count > 0 {
+                ui.colored_label(
+                    egui::Color32::YELLOW,
+                    format!("{} system(s) switched off", disabled_count),
+                );
+            }
+            if systems.is_empty() {
+                ui.weak("No toggleable systems yet. They are listed once the game has updated.");
+                return;
+            }
+
+            let filter = state.filter.to_lowercase();
+            egui::ScrollArea::vertical()
+                .max_height(400.0)
+                .show(ui, |ui| {
+                    for group in systems.chunk_by(|(a, _), (b, _)| a.group == b.group) {
+                        let members: Vec<_> = group
+                            .iter()
+                            .filter(|(system, _)| system.name.to_lowercase().contains(&filter))
+                            .collect();
+                        if members.is_empty() {
+                            continue;
+                        }
+                        egui::CollapsingHeader::new(group[0].0.group.as_str())
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                for (system, enabled) in members {
+                                    let mut on = *enabled;
+                                    if ui
+                                        .checkbox(&mut on, short_system_name(&system.name))
+                                        .on_hover_text(&system.name)
+                                        .changed()
+                                    {
+                                        changes.push((system.name.clone(), on));
+                                    }
+                                }
+                            });
+                    }
+                });
+        });
+
+    let mut toggles = world.resource_mut::<SystemToggles>();
+    if enable_all {
+        toggles.enable_all();
+    }
+    for (name, on) in changes {
+        toggles.set_enabled(&name, on);
+    }
+    *world.resource_mut::<SystemsWindowState>() = state;
+}
+
+/// `name` without module paths, generic arguments included:
+/// `game::status::update_statuses<game::Burning>` becomes
+/// `update_statuses<Burning>`.
+pub fn short_system_name(name: &str) -> String {
+    let mut short = String::with_capacity(name.len());
+    let mut segment = String::new();
+    for c in name.chars() {
+        if "<>(),[]& ".contains(c) {
+            short.push_str(segment.rsplit("::").next().unwrap_or_default());
+            segment.clear();
+            short.push(c);
+        } else {
+            segment.push(c);
+        }
+    }
+    short.push_str(segment.rsplit("::").next().unwrap_or_default());
+    short
+}
@@ -0,0 +1,145 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Window for naming teams and setting the relations between them.
+//!
+//! Edits apply to the project's `TeamTable` immediately, so they can be tried
+//! while playing; Save writes them to the project file.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::project::{ProjectRoot, ProjectSettings, PROJECT_FILE_NAME};
+use sandbox_engine::team::Relation;
+
+use super::file_menu::{set_error_message, set_success_message};
+
+/// State for the teams window.
+#[derive(Resource, Default)]
+pub struct TeamsWindowState {
+    /// Whether the window is open.
+    pub open: bool,
+    /// Whether the table has changed since it was last saved.
+    pub unsaved: bool,
+}
+
+/// Displays the teams window.
+pub fn teams_window(ctx: &egui::Context, world: &mut World) {
+    if !world.resource::<TeamsWindowState>().open {
+        return;
+    }
+
+    let mut state = std::mem::take(&mut *world.resource_mut::<TeamsWindowState>());
+    let original = world.resource::<ProjectSettings>().teams.clone();
+    let mut teams = original.clone();
+    let mut save = false;
+
+    egui::Window::new("Teams")
+        .open(&mut state.open)
+        .default_width(360.0)
+        .show(ctx, |ui| {
+            let mut removed = false;
+            egui::Grid::new("team_names")
+                .num_columns(3)
+                .striped(true)
+                .show(ui, |ui| {
+                    let count = teams.names.len();
+                    for (team, name) in teams.names.iter_mut().enumerate() {
+                        ui.weak(team.to_string());
+                        ui.text_edit_singleline(name);
+                        // Only the last team goes, so the others keep their numbers
+                        if team + 1 == count
+                            && ui.small_button("🗑").on_hover_text("Remove").clicked()
+                        {
+                            removed = true;
+                        }
+                        ui.end_row();
+                    }
+                });
+            if removed {
+                teams.remove_last();
+            }
+            if teams.names.is_empty() {
+                ui.weak("No teams yet.");
+            }
+            // Team counts must fit in a `u8`, so the last team is 254
+            let can_add = teams.names.len() < u8::MAX as usize;
+            if ui
+                .add_enabled(can_add, egui::Button::new("+ Add Team"))
+                .clicked()
+            {
+                let name = teams.name(teams.names.len() as u8);
+                teams.names.push(name);
+            }
+
+            let count = teams.names.len() as u8;
+            if count >= 2 {
+                ui.separator();
+                ui.label("Relations");
+                egui::Grid::new("team_relations")
+                    .num_columns(count as usize)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.label("");
+                        for column in 1..count {
+                            ui.strong(teams.name(column));
+                        }
+                        ui.end_row();
+                        for row in 0..count - 1 {
+                            ui.strong(teams.name(row));
+                            for column in 1..count {
+                                if column <= row {
+                                    ui.label("");
+                                    continue;
+                                }
+                                let mut relation = teams.relation(row, column);
+                                egui::ComboBox::from_id_salt(("team_relation", row, column))
+                                    .selected_text(relation.label())
+                                    .show_ui(ui, |ui| {
+                                        for option in Relation::ALL {
+                                            ui.selectable_value(
+                                                &mut relation,
+                                                option,
+                                                option.label(),
+                                            );
+                                        }
+                                    });
+                                teams.set_relation(row, column, relation);
+                            }
+                            ui.end_row();
+                        }
+                    });
+                ui.weak(
+                    "A team is always friendly to itself; entities without a team are neutral.",
+                );
+            }
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(state.unsaved, egui::Button::new("💾 Save"))
+                    .clicked()
+                {
+                    save = true;
+                }
+                if state.unsaved {
+                    ui.weak("Unsaved changes");
+                }
+            });
+        });
+
+    if teams != original {
+        world.resource_mut::<ProjectSettings>().teams = teams;
+        state.unsaved = true;
+    }
+    if save {
+        let root = world.resource::<ProjectRoot>().clone();
+        match world.resource::<ProjectSettings>().save(&root) {
+            Ok(()) => {
+                state.unsaved = false;
+                set_success_message(world, &format!("Saved teams to {}", PROJECT_FILE_NAME));
+            }
+            Err(e) => set_error_message(world, &format!("Failed to save teams: {}", e)),
+        }
+    }
+    *world.resource_mut::<TeamsWindowState>() = state;
+}
@@ -0,0 +1,343 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Window for scrubbing the time of day and editing the scene's tint gradient.
+//!
+//! Scrubbing moves `TimeOfDay` directly, so the viewport previews each hour's
+//! mood; the scene itself is untouched. Gradient edits are previewed through
+//! `CurrentTint` and only reach the gradient file on Save.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::SceneManager;
+use sandbox_engine::tint::{
+    CurrentTint, GlobalTint, TimeOfDay, TintGradient, TintKey, HOURS_PER_DAY,
+};
+use std::any::TypeId;
+
+use super::file_menu::{set_error_message, set_success_message};
+use crate::undo::{record_component_edit, snapshot_components};
+
+/// Where a gradient for a scene using the built-in cycle is saved by default.
+const DEFAULT_GRADIENT_PATH: &str = "gradients/day_night.gradient.ron";
+
+/// Hours labelled under the gradient strip.
+const STRIP_LABELS: [f32; 5] = [0.0, 6.0, 12.0, 18.0, 24.0];
+
+/// State for the time of day window.
+#[derive(Resource)]
+pub struct TimeOfDayWindowState {
+    /// Whether the window is open.
+    pub open: bool,
+    /// Where Save writes a gradient that doesn't have a file yet.
+    pub save_path: String,
+    /// Whether the gradient has changed since it was loaded or saved.
+    pub unsaved: bool,
+    /// Gradient file the edits were made to.
+    edited_path: String,
+}
+
+impl Default for TimeOfDayWindowState {
+    fn default() -> Self {
+        Self {
+            open: false,
+            save_path: DEFAULT_GRADIENT_PATH.to_string(),
+            unsaved: false,
+            edited_path: String::new(),
+        }
+    }
+}
+
+/// Action chosen in the time of day window.
+enum TintAction {
+    Save,
+    Revert,
+}
+
+/// Displays the time of day window.
+pub fn time_of_day_window(ctx: &egui::Context, world: &mut World) {
+    if !world.resource::<TimeOfDayWindowState>().open {
+        return;
+    }
+
+    let mut state = std::mem::take(&mut *world.resource_mut::<TimeOfDayWindowState>());
+    let original_clock = world.resource::<TimeOfDay>().clone();
+    let mut clock = original_clock.clone();
+    let current = world.resource::<CurrentTint>().clone();
+    let mut gradient = current.gradient.clone();
+    let tint = world
+        .query::<(Entity, &GlobalTint)>()
+        .iter(world)
+        .next()
+        .map(|(entity, tint)| (entity, tint.clone()));
+    if state.edited_path != current.path {
+        state.edited_path = current.path.clone();
+        state.unsaved = false;
+    }
+    let mut action = None;
+
+    egui::Window::new("Time of Day")
+        .open(&mut state.open)
+        .default_width(360.0)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Hour:");
+                ui.add(
+                    egui::Slider::new(&mut clock.hour, 0.0..=HOURS_PER_DAY)
+                        .custom_formatter(|hour, _| format_hour(hour as f32)),
+                );
+            });
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut clock.running, "Runs during play");
+                ui.label("Day length:");
+                ui.add(
+                    egui::DragValue::new(&mut clock.day_length)
+                        .range(0.0..=f32::MAX)
+                        .suffix(" s"),
+                );
+            });
+
+            if let Some(hour) = gradient_strip(ui, &gradient, clock.hour) {
+                clock.hour = hour;
+            }
+            ui.horizontal(|ui| {
+                ui.label("Tint:");
+                color_swatch(ui, current.color);
+                ui.weak(format_hour(clock.hour));
+            });
+
+            ui.separator();
+            let Some((_, tint)) = &tint else {
+                ui.weak("Add a Global Tint to an entity to tint the scene by the time of day.");
+                return;
+            };
+            if tint.gradient.is_empty() {
+                ui.label("Gradient: built-in day/night cycle");
+            } else {
+                ui.label(format!("Gradient: {}", tint.gradient));
+            }
+
+            let mut removed = None;
+            egui::Grid::new("tint_keys")
+                .num_columns(3)
+                .striped(true)
+                .show(ui, |ui| {
+                    for (index, key) in gradient.keys.iter_mut().enumerate() {
+                        ui.add(
+                            egui::DragValue::new(&mut key.hour)
+                                .range(0.0..=HOURS_PER_DAY)
+                                .speed(0.05)
+                                .custom_formatter(|hour, _| format_hour(hour as f32)),
+                        );
+                        let srgba = key.color.to_srgba();
+                        let mut color = [srgba.red, srgba.green, srgba.blue, srgba.alpha];
+                        if ui.color_edit_button_rgba_unmultiplied(&mut color).changed() {
+                            key.color = Color::srgba(color[0], color[1], color[2], color[3]);
+                        }
+                        if ui.small_button("🗑").on_hover_text("Remove").clicked() {
+                            removed = Some(index);
+                        }
+                        ui.end_row();
+                    }
+                });
+            if let Some(index) = removed {
+                gradient.keys.remove(index);
+            }
+            if gradient.keys.is_empty() {
+                ui.weak("No keys; sprites keep their colors.");
+            }
+            if ui
+                .button("+ Key")
+                .on_hover_text("Add a key at the current hour")
+                .clicked()
+            {
+                gradient.keys.push(TintKey {
+                    hour: clock.hour,
+                    color: gradient.sample(clock.hour),
+                });
+            }
+
+            ui.separator();
+            if tint.gradient.is_empty() {
+                ui.horizontal(|ui| {
+                    ui.label("Save as:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut state.save_path)
+                            .hint_text(DEFAULT_GRADIENT_PATH),
+                    );
+                });
+            }
+            ui.horizontal(|ui| {
+                let can_save = state.unsaved || tint.gradient.is_empty();
+                if ui
+                    .add_enabled(can_save, egui::Button::new("💾 Save"))
+                    .clicked()
+                {
+                    action = Some(TintAction::Save);
+                }
+                if ui
+                    .add_enabled(state.unsaved, egui::Button::new("⟲ Revert"))
+                    .clicked()
+                {
+                    action = Some(TintAction::Revert);
+                }
+                if state.unsaved {
+                    ui.weak("Unsaved changes");
+                }
+            });
+        });
+
+    if clock != original_clock {
+        *world.resource_mut::<TimeOfDay>() = clock;
+    }
+    if gradient != current.gradient {
+        world.resource_mut::<CurrentTint>().gradient = gradient.clone();
+        state.unsaved = true;
+    }
+    match (action, tint) {
+        (Some(TintAction::Save), Some((entity, tint))) => {
+            let path = if tint.gradient.is_empty() {
+                state.save_path.trim().to_string()
+            } else {
+                tint.gradient.clone()
+            };
+            if save_gradient(world, entity, &tint, &path, &gradient) {
+                state.unsaved = false;
+                state.edited_path = path;
+            }
+        }
+        (Some(TintAction::Revert), Some((_, tint))) => {
+            let reverted = if tint.gradient.is_empty() {
+                Ok(TintGradient::day_night())
+            } else {
+                let file = world
+                    .resource::<ProjectRoot>()
+                    .resolve_asset(&tint.gradient);
+                TintGradient::load(&file)
+            };
+            match reverted {
+                Ok(reverted) => {
+                    world.resource_mut::<CurrentTint>().gradient = reverted;
+                    state.unsaved = false;
+                }
+                Err(e) => set_error_message(world, &format!("Failed to load gradient: {}", e)),
+            }
+        }
+        _ => {}
+    }
+    *world.resource_mut::<TimeOfDayWindowState>() = state;
+}
+
+/// Writes the gradient to `path`, pointing the scene's `GlobalTint` at it if
+/// it used the built-in cycle. Returns whether it was saved.
+fn save_gradient(
+    world: &mut World,
+    entity: Entity,
+    tint: &GlobalTint,
+    path: &str,
+    gradient: &TintGradient,
+) -> bool {
+    if path.is_empty() {
+        set_error_message(world, "Enter a path to save the gradient to");
+        return false;
+    }
+    let file = world.resource::<ProjectRoot>().resolve_asset(path);
+    if let Err(e) = gradient.save(&file) {
+        set_error_message(world, &format!("Failed to save gradient: {}", e));
+        return false;
+    }
+    if tint.gradient != path {
+        let type_id = TypeId::of::<GlobalTint>();
+        let before = snapshot_components(world, &[entity], type_id);
+        if let Some(mut tint) = world.get_mut::<GlobalTint>(entity) {
+            tint.gradient = path.to_string();
+        }
+        record_component_edit(world, "Set Tint Gradient", type_id, before);
+        // Already loaded; keeps the tint from reading the file back
+        world.resource_mut::<CurrentTint>().path = path.to_string();
+        if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+            manager.mark_dirty();
+        }
+    }
+    set_success_message(world, &format!("Saved gradient to {}", path));
+    true
+}
+
+/// Draws the gradient over the day with a marker at `hour`. Returns the hour
+/// under the pointer while it's clicked or dragged.
+fn gradient_strip(ui: &mut egui::Ui, gradient: &TintGradient, hour: f32) -> Option<f32> {
+    const SLICES: usize = 96;
+    let width = ui.available_width().max(120.0);
+    let (rect, response) =
+        ui.allocate_exact_size(egui::vec2(width, 24.0), egui::Sense::click_and_drag());
+    let painter = ui.painter_at(rect.expand(1.0));
+    let slice = rect.width() / SLICES as f32;
+    for index in 0..SLICES {
+        let slice_hour = (index as f32 + 0.5) / SLICES as f32 * HOURS_PER_DAY;
+        let min = egui::pos2(rect.left() + index as f32 * slice, rect.top());
+        painter.rect_filled(
+            egui::Rect::from_min_size(min, egui::vec2(slice + 0.5, rect.height())),
+            0.0,
+            to_color32(gradient.sample(slice_hour)),
+        );
+    }
+    let x = rect.left() + hour / HOURS_PER_DAY * rect.width();
+    painter.vline(
+        x,
+        rect.y_range(),
+        egui::Stroke::new(3.0, egui::Color32::BLACK),
+    );
+    painter.vline(
+        x,
+        rect.y_range(),
+        egui::Stroke::new(1.0, egui::Color32::WHITE),
+    );
+
+    // Hour labels along the bottom
+    let (labels, _) = ui.allocate_exact_size(egui::vec2(width, 14.0), egui::Sense::hover());
+    for label_hour in STRIP_LABELS {
+        let x = labels.left() + label_hour / HOURS_PER_DAY * labels.width();
+        let align = match label_hour {
+            0.0 => egui::Align2::LEFT_TOP,
+            HOURS_PER_DAY => egui::Align2::RIGHT_TOP,
+            _ => egui::Align2::CENTER_TOP,
+        };
+        ui.painter().text(
+            egui::pos2(x, labels.top()),
+            align,
+            format!("{}h", label_hour),
+            egui::FontId::proportional(11.0),
+            ui.visuals().weak_text_color(),
+        );
+    }
+
+    if !(response.clicked() || response.dragged()) {
+        return None;
+    }
+    let pointer = response.interact_pointer_pos()?;
+    let fraction = ((pointer.x - rect.left()) / rect.width()).clamp(0.0, 1.0);
+    Some(fraction * HOURS_PER_DAY)
+}
+
+/// Draws a small box filled with `color`.
+fn color_swatch(ui: &mut egui::Ui, color: Color) {
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(32.0, 16.0), egui::Sense::hover());
+    ui.painter().rect_filled(rect, 2.0, to_color32(color));
+    ui.painter().rect_stroke(
+        rect,
+        2.0,
+        egui::Stroke::new(1.0, ui.visuals().weak_text_color()),
+    );
+}
+
+fn to_color32(color: Color) -> egui::Color32 {
+    let [red, green, blue, alpha] = color.to_srgba().to_u8_array();
+    egui::Color32::from_rgba_unmultiplied(red, green, blue, alpha)
+}
+
+/// Formats an hour of the day as a clock time, e.g. `18:30`.
+fn format_hour(hour: f32) -> String {
+    let minutes = (hour * 60.0).round() as u32;
+    format!("{:02}:{:02}", minutes / 60, minutes % 60)
+}
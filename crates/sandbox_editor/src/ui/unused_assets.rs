@@ -0,0 +1,163 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Window listing asset files that no scene or prefab references.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::project::ProjectRoot;
+use std::collections::BTreeSet;
+
+use super::file_menu::{set_error_message, set_success_message};
+use super::memory::format_bytes;
+use crate::assets::AssetBrowser;
+use crate::unused_assets::{find_unused_assets, AssetTrash, UnusedAssetReport};
+
+/// State for the unused assets window.
+#[derive(Resource, Default)]
+pub struct UnusedAssetsState {
+    /// Whether the window is open.
+    pub open: bool,
+    /// Result of the last scan; `None` until the window scans.
+    pub report: Option<UnusedAssetReport>,
+    /// Paths checked for removal.
+    pub selected: BTreeSet<String>,
+}
+
+impl UnusedAssetsState {
+    /// Opens the window and scans on the next frame.
+    pub fn open(&mut self) {
+        self.open = true;
+        self.report = None;
+        self.selected.clear();
+    }
+}
+
+/// Action chosen in the unused assets window.
+enum UnusedAssetsAction {
+    Rescan,
+    Trash(Vec<String>),
+}
+
+/// Displays the unused assets window.
+pub fn unused_assets_window(ctx: &egui::Context, world: &mut World) {
+    if !world.resource::<UnusedAssetsState>().open {
+        return;
+    }
+    if world.resource::<UnusedAssetsState>().report.is_none() {
+        rescan(world);
+    }
+
+    let mut state = std::mem::take(&mut *world.resource_mut::<UnusedAssetsState>());
+    let mut open = state.open;
+    let mut action = None;
+    egui::Window::new("Unused Assets")
+        .open(&mut open)
+        .default_width(420.0)
+        .show(ctx, |ui| {
+            let Some(report) = &state.report else {
+                return;
+            };
+
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "{} of {} files unused ({}), {} documents scanned",
+                    report.unused.len(),
+                    report.checked,
+                    format_bytes(report.total_size() as usize),
+                    report.documents
+                ));
+                if ui.button("⟳ Rescan").clicked() {
+                    action = Some(UnusedAssetsAction::Rescan);
+                }
+            });
+            ui.weak("Files loaded only from game code are listed too; review before removing.");
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui.button("Select All").clicked() {
+                    state.selected = report.unused.iter().map(|a| a.path.clone()).collect();
+                }
+                if ui.button("Select None").clicked() {
+                    state.selected.clear();
+                }
+            });
+
+            egui::ScrollArea::vertical()
+                .max_height(300.0)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    egui::Grid::new("unused_assets_grid")
+                        .num_columns(2)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for asset in &report.unused {
+                                let mut checked = state.selected.contains(&asset.path);
+                                if ui.checkbox(&mut checked, &asset.path).changed() {
+                                    if checked {
+                                        state.selected.insert(asset.path.clone());
+                                    } else {
+                                        state.selected.remove(&asset.path);
+                                    }
+                                }
+                                ui.label(format_bytes(asset.size as usize));
+                                ui.end_row();
+                            }
+                        });
+                    if report.unused.is_empty() {
+                        ui.weak("Every asset is referenced.");
+                    }
+                });
+
+            ui.separator();
+            let selected_size: u64 = report
+                .unused
+                .iter()
+                .filter(|asset| state.selected.contains(&asset.path))
+                .map(|asset| asset.size)
+                .sum();
+            let label = format!(
+                "🗑 Move {} to Trash ({})",
+                state.selected.len(),
+                format_bytes(selected_size as usize)
+            );
+            if ui
+                .add_enabled(!state.selected.is_empty(), egui::Button::new(label))
+                .clicked()
+            {
+                action = Some(UnusedAssetsAction::Trash(
+                    state.selected.iter().cloned().collect(),
+                ));
+            }
+        });
+    state.open = open;
+    *world.resource_mut::<UnusedAssetsState>() = state;
+
+    match action {
+        Some(UnusedAssetsAction::Rescan) => rescan(world),
+        Some(UnusedAssetsAction::Trash(paths)) => {
+            let assets_dir = world.resource::<ProjectRoot>().assets_dir();
+            let trash = *world.resource::<AssetTrash>();
+            match trash.remove(&assets_dir, &paths) {
+                Ok(count) => {
+                    set_success_message(world, &format!("Moved {} files to the trash", count))
+                }
+                Err(e) => set_error_message(world, &format!("Failed to move to trash: {}", e)),
+            }
+            world.resource_mut::<AssetBrowser>().needs_rescan = true;
+            rescan(world);
+        }
+        None => {}
+    }
+}
+
+/// Rescans the project and drops selections that are no longer unused.
+fn rescan(world: &mut World) {
+    let project_root = world.resource::<ProjectRoot>();
+    let report = find_unused_assets(&project_root.assets_dir(), &[project_root.project_file()]);
+    let mut state = world.resource_mut::<UnusedAssetsState>();
+    state
+        .selected
+        .retain(|path| report.unused.iter().any(|asset| &asset.path == path));
+    state.report = Some(report);
+}
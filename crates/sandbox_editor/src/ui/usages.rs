@@ -0,0 +1,162 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Window listing the references to an asset, opened from the asset browser.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::SceneManager;
+
+use super::entity_picker::entity_label;
+use super::file_menu::request_open_scene;
+use super::problems::focus_entity;
+use crate::usages::{find_usages, Usage, UsageReport};
+
+/// State for the usages window.
+#[derive(Resource, Default)]
+pub struct UsagesState {
+    /// Asset being looked up; the window is open while set.
+    pub asset: Option<String>,
+    /// Result of the last scan; `None` until the window scans.
+    pub report: Option<UsageReport>,
+}
+
+impl UsagesState {
+    /// Opens the window for `asset` and scans on the next frame.
+    pub fn open(&mut self, asset: impl Into<String>) {
+        self.asset = Some(asset.into());
+        self.report = None;
+    }
+}
+
+/// Action chosen in the usages window.
+enum UsagesAction {
+    Rescan,
+    Select(Entity),
+    /// Open a scene file and select the named entity in it.
+    OpenScene {
+        file: String,
+        entity: String,
+    },
+}
+
+/// Displays the usages window.
+pub fn usages_window(ctx: &egui::Context, world: &mut World) {
+    let Some(asset) = world.resource::<UsagesState>().asset.clone() else {
+        return;
+    };
+    if world.resource::<UsagesState>().report.is_none() {
+        rescan(world);
+    }
+
+    let report = world.resource::<UsagesState>().report.clone();
+    // Loading another scene would drop unsaved changes
+    let scene_dirty = world
+        .get_resource::<SceneManager>()
+        .is_some_and(|manager| manager.dirty);
+    let mut open = true;
+    let mut action = None;
+    egui::Window::new("Usages")
+        .open(&mut open)
+        .default_width(420.0)
+        .show(ctx, |ui| {
+            let Some(report) = &report else {
+                return;
+            };
+
+            ui.horizontal(|ui| {
+                ui.label(format!("References to {}: {}", asset, report.usages.len()));
+                if ui.button("⟳ Rescan").clicked() {
+                    action = Some(UsagesAction::Rescan);
+                }
+            });
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .max_height(300.0)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    egui::Grid::new("usages_grid")
+                        .num_columns(3)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for usage in &report.usages {
+                                match usage {
+                                    Usage::Entity { entity, .. } => {
+                                        ui.label(entity_label(world, *entity));
+                                        ui.weak(usage.path());
+                                        if ui.small_button("Select").clicked() {
+                                            action = Some(UsagesAction::Select(*entity));
+                                        }
+                                    }
+                                    Usage::SceneFile { file, entity, .. } => {
+                                        ui.label(format!("{} › {}", file, entity));
+                                        ui.weak(usage.path());
+                                        let button = ui
+                                            .add_enabled(
+                                                !scene_dirty,
+                                                egui::Button::new("Open").small(),
+                                            )
+                                            .on_disabled_hover_text("Save the open scene first");
+                                        if button.clicked() {
+                                            action = Some(UsagesAction::OpenScene {
+                                                file: file.clone(),
+                                                entity: entity.clone(),
+                                            });
+                                        }
+                                    }
+                                }
+                                ui.end_row();
+                            }
+                        });
+                    if report.usages.is_empty() {
+                        ui.weak("No usages found.");
+                    }
+                    for (file, error) in &report.unreadable {
+                        ui.colored_label(
+                            ui.visuals().warn_fg_color,
+                            format!("⚠ {} not checked: {}", file, error),
+                        );
+                    }
+                });
+        });
+    if !open {
+        *world.resource_mut::<UsagesState>() = UsagesState::default();
+    }
+
+    match action {
+        Some(UsagesAction::Rescan) => rescan(world),
+        Some(UsagesAction::Select(entity)) => {
+            if world.get_entity(entity).is_err() {
+                rescan(world);
+                return;
+            }
+            focus_entity(world, entity);
+        }
+        Some(UsagesAction::OpenScene { file, entity }) => {
+            let path = world.resource::<ProjectRoot>().resolve_asset(&file);
+            if request_open_scene(world, &path) {
+                let mut names = world.query::<(Entity, &Name)>();
+                let found = names
+                    .iter(world)
+                    .filter(|(_, name)| name.as_str() == entity)
+                    .map(|(found, _)| found)
+                    .min_by_key(|found| found.index());
+                if let Some(found) = found {
+                    focus_entity(world, found);
+                }
+            }
+            rescan(world);
+        }
+        None => {}
+    }
+}
+
+fn rescan(world: &mut World) {
+    let Some(asset) = world.resource::<UsagesState>().asset.clone() else {
+        return;
+    };
+    let report = find_usages(world, &asset);
+    world.resource_mut::<UsagesState>().report = Some(report);
+}
@@ -0,0 +1,384 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Watch window: live values of chosen component and resource fields.
+//!
+//! A watch is an entity's component field (added from the selected entity)
+//! or a resource field, addressed by a reflection path such as
+//! `.translation.x`. Values are read every frame; numbers are also graphed
+//! while playing, and a value that changed flashes for a moment, which makes
+//! it a lightweight alternative to stepping through gameplay code with a
+//! debugger.
+
+use bevy::prelude::*;
+use bevy::reflect::{GetPath, PartialReflect};
+use bevy_egui::egui;
+use sandbox_engine::editor_state::EditorPlayState;
+use std::any::TypeId;
+use std::collections::VecDeque;
+
+use super::entity_picker::entity_label;
+use super::fields::{collect_field_paths, FieldValue};
+use crate::selection::EditorSelection;
+
+/// Samples kept for each graph.
+const MAX_SAMPLES: usize = 300;
+
+/// How long a changed value stays highlighted, in seconds.
+const CHANGE_HIGHLIGHT_SECS: f64 = 1.0;
+
+/// Size of a watch's graph, in points.
+const GRAPH_SIZE: egui::Vec2 = egui::vec2(140.0, 28.0);
+
+/// What a watch reads from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchTarget {
+    /// A component of an entity.
+    Component { entity: Entity, type_id: TypeId },
+    /// A resource.
+    Resource { type_id: TypeId },
+}
+
+/// One watched field.
+#[derive(Clone, Debug)]
+pub struct Watch {
+    pub target: WatchTarget,
+    /// Reflection path into the component or resource; empty for all of it.
+    pub path: String,
+    /// Last value read, `None` while the target is missing.
+    pub value: Option<String>,
+    /// Numeric values sampled while playing, oldest first.
+    pub samples: VecDeque<f64>,
+    /// Real time at which the value last changed.
+    changed_at: Option<f64>,
+}
+
+impl Watch {
+    /// Watches `path` of `target`.
+    pub fn new(target: WatchTarget, path: impl Into<String>) -> Self {
+        Self {
+            target,
+            path: path.into(),
+            value: None,
+            samples: VecDeque::new(),
+            changed_at: None,
+        }
+    }
+}
+
+/// State for the watch window.
+#[derive(Resource, Default)]
+pub struct WatchWindowState {
+    /// Whether the window is open.
+    pub open: bool,
+    pub watches: Vec<Watch>,
+    /// Component or resource picked for the next watch.
+    new_target: Option<WatchTarget>,
+    /// Field picked for the next watch.
+    new_path: String,
+    /// Play state seen on the previous sample, to clear graphs when play starts.
+    last_play_state: Option<EditorPlayState>,
+}
+
+/// Reads every watch, recording numbers while playing. Runs each frame,
+/// whether or not the window is open.
+pub fn sample_watches(world: &mut World) {
+    if world.resource::<WatchWindowState>().watches.is_empty() {
+        return;
+    }
+    let play_state = *world.resource::<State<EditorPlayState>>().get();
+    let now = world.resource::<Time<Real>>().elapsed_secs_f64();
+    let mut state = std::mem::take(&mut *world.resource_mut::<WatchWindowState>());
+
+    let play_started = state.last_play_state == Some(EditorPlayState::Stopped)
+        && play_state == EditorPlayState::Playing;
+    state.last_play_state = Some(play_state);
+    for watch in &mut state.watches {
+        if play_started {
+            watch.samples.clear();
+        }
+        let reading = read_watch(world, watch.target, &watch.path);
+        let text = reading.as_ref().map(|(text, _)| text.clone());
+        if watch.value.is_some() && text != watch.value {
+            watch.changed_at = Some(now);
+        }
+        watch.value = text;
+        if play_state == EditorPlayState::Playing {
+            if let Some((_, Some(number))) = reading {
+                if watch.samples.len() == MAX_SAMPLES {
+                    watch.samples.pop_front();
+                }
+                watch.samples.push_back(number);
+            }
+        }
+    }
+    *world.resource_mut::<WatchWindowState>() = state;
+}
+
+/// The value at `path` of `target` as text, and as a number if it is one.
+pub fn read_watch(world: &World, target: WatchTarget, path: &str) -> Option<(String, Option<f64>)> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let value = match target {
+        WatchTarget::Component { entity, type_id } => type_registry
+            .get_type_data::<ReflectComponent>(type_id)?
+            .reflect(world.get_entity(entity).ok()?)?,
+        WatchTarget::Resource { type_id } => type_registry
+            .get_type_data::<ReflectResource>(type_id)?
+            .reflect(world)?,
+    };
+    let field: &dyn PartialReflect = if path.is_empty() {
+        value.as_partial_reflect()
+    } else {
+        value.reflect_path(path).ok()?
+    };
+    Some(match FieldValue::read(field) {
+        Some(FieldValue::F32(v)) => (format!("{:.3}", v), Some(v as f64)),
+        Some(FieldValue::F64(v)) => (format!("{:.3}", v), Some(v)),
+        Some(FieldValue::I32(v)) => (v.to_string(), Some(v as f64)),
+        Some(FieldValue::U32(v)) => (v.to_string(), Some(v as f64)),
+        Some(FieldValue::Usize(v)) => (v.to_string(), Some(v as f64)),
+        Some(FieldValue::Bool(v)) => (v.to_string(), Some(if v { 1.0 } else { 0.0 })),
+        Some(FieldValue::String(v)) => (format!("{:?}", v), None),
+        _ => (format!("{:?}", field), None),
+    })
+}
+
+/// Displays the watch window.
+pub fn watch_window(ctx: &egui::Context, world: &mut World) {
+    if !world.resource::<WatchWindowState>().open {
+        return;
+    }
+
+    let mut state = std::mem::take(&mut *world.resource_mut::<WatchWindowState>());
+    let now = world.resource::<Time<Real>>().elapsed_secs_f64();
+    let labels: Vec<String> = state
+        .watches
+        .iter()
+        .map(|watch| watch_label(world, watch))
+        .collect();
+    let sources = watch_sources(world);
+    if state
+        .new_target
+        .is_some_and(|target| !sources.iter().any(|(source, _)| *source == target))
+    {
+        state.new_target = None;
+    }
+    let paths = state
+        .new_target
+        .map(|target| field_paths(world, target))
+        .unwrap_or_default();
+    let mut removed = None;
+    let mut add = false;
+
+    egui::Window::new("Watch")
+        .open(&mut state.open)
+        .default_width(420.0)
+        .show(ctx, |ui| {
+            if state.watches.is_empty() {
+                ui.weak("Nothing watched yet.");
+            }
+            egui::Grid::new("watches")
+                .num_columns(4)
+                .striped(true)
+                .show(ui, |ui| {
+                    for (index, watch) in state.watches.iter().enumerate() {
+                        ui.label(&labels[index]);
+                        let text = watch.value.as_deref().unwrap_or("(missing)");
+                        let fade = watch.changed_at.map_or(0.0, |changed_at| {
+                            1.0 - ((now - changed_at) / CHANGE_HIGHLIGHT_SECS).clamp(0.0, 1.0)
+                        });
+                        if fade > 0.0 {
+                            ui.colored_label(
+                                egui::Color32::YELLOW.gamma_multiply(0.4 + 0.6 * fade as f32),
+                                text,
+                            );
+                            ui.ctx().request_repaint();
+                        } else if watch.value.is_none() {
+                            ui.weak(text);
+                        } else {
+                            ui.label(text);
+                        }
+                        graph(ui, &watch.samples);
+                        if ui
+                            .small_button("🗑")
+                            .on_hover_text("Stop watching")
+                            .clicked()
+                        {
+                            removed = Some(index);
+                        }
+                        ui.end_row();
+                    }
+                });
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                let selected_source = state
+                    .new_target
+                    .and_then(|target| sources.iter().find(|(source, _)| *source == target))
+                    .map_or("Component or resource", |(_, name)| name.as_str());
+                egui::ComboBox::from_id_salt("watch_source")
+                    .selected_text(selected_source)
+                    .width(150.0)
+                    .show_ui(ui, |ui| {
+                        for (source, name) in &sources {
+                            if ui
+                                .selectable_label(state.new_target == Some(*source), name)
+                                .clicked()
+                            {
+                                state.new_target = Some(*source);
+                                state.new_path.clear();
+                            }
+                        }
+                    });
+                let selected_path = if state.new_path.is_empty() {
+                    "(whole value)"
+                } else {
+                    state.new_path.trim_start_matches('.')
+                };
+                egui::ComboBox::from_id_salt("watch_path")
+                    .selected_text(selected_path)
+                    .width(120.0)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut state.new_path, String::new(), "(whole value)");
+                        for path in &paths {
+                            ui.selectable_value(
+                                &mut state.new_path,
+                                path.clone(),
+                                path.trim_start_matches('.'),
+                            );
+                        }
+                    });
+                add = ui
+                    .add_enabled(state.new_target.is_some(), egui::Button::new("+ Watch"))
+                    .clicked();
+            });
+            ui.weak("Components are listed for the selected entity.");
+        });
+
+    if let Some(index) = removed {
+        state.watches.remove(index);
+    }
+    if add {
+        if let Some(target) = state.new_target {
+            state
+                .watches
+                .push(Watch::new(target, state.new_path.clone()));
+        }
+    }
+    *world.resource_mut::<WatchWindowState>() = state;
+}
+
+/// "Entity › Component.path" or "Resource.path".
+fn watch_label(world: &World, watch: &Watch) -> String {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let (entity, type_id) = match watch.target {
+        WatchTarget::Component { entity, type_id } => (Some(entity), type_id),
+        WatchTarget::Resource { type_id } => (None, type_id),
+    };
+    let type_name = type_registry.get(type_id).map_or("?", |registration| {
+        registration.type_info().type_path_table().short_path()
+    });
+    let field = format!("{}{}", type_name, watch.path);
+    match entity {
+        Some(entity) => format!("{} › {}", entity_label(world, entity), field),
+        None => field,
+    }
+}
+
+/// The selected entity's reflected components, then the world's reflected
+/// resources, with their display names.
+fn watch_sources(world: &World) -> Vec<(WatchTarget, String)> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let selected = world
+        .resource::<EditorSelection>()
+        .selected_entity
+        .and_then(|entity| world.get_entity(entity).ok());
+
+    let mut components = Vec::new();
+    let mut resources = Vec::new();
+    for registration in type_registry.iter() {
+        let name = registration.type_info().type_path_table().short_path();
+        if let (Some(entity), Some(reflect)) = (selected, registration.data::<ReflectComponent>()) {
+            if reflect.contains(entity) {
+                components.push((
+                    WatchTarget::Component {
+                        entity: entity.id(),
+                        type_id: registration.type_id(),
+                    },
+                    name.to_string(),
+                ));
+            }
+        }
+        if let Some(reflect) = registration.data::<ReflectResource>() {
+            if reflect.reflect(world).is_some() {
+                resources.push((
+                    WatchTarget::Resource {
+                        type_id: registration.type_id(),
+                    },
+                    format!("Resource: {}", name),
+                ));
+            }
+        }
+    }
+    components.sort_by(|a, b| a.1.cmp(&b.1));
+    resources.sort_by(|a, b| a.1.cmp(&b.1));
+    components.extend(resources);
+    components
+}
+
+/// Leaf field paths of `target`'s value.
+fn field_paths(world: &World, target: WatchTarget) -> Vec<String> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let value = match target {
+        WatchTarget::Component { entity, type_id } => {
+            world.get_entity(entity).ok().and_then(|entity| {
+                type_registry
+                    .get_type_data::<ReflectComponent>(type_id)?
+                    .reflect(entity)
+            })
+        }
+        WatchTarget::Resource { type_id } => type_registry
+            .get_type_data::<ReflectResource>(type_id)
+            .and_then(|reflect| reflect.reflect(world)),
+    };
+    let mut paths = Vec::new();
+    if let Some(value) = value {
+        collect_field_paths(value.as_partial_reflect(), "", 0, &mut paths);
+    }
+    paths.retain(|path| !path.is_empty());
+    paths
+}
+
+/// Draws `samples` as a line scaled to their range.
+fn graph(ui: &mut egui::Ui, samples: &VecDeque<f64>) {
+    let (rect, response) = ui.allocate_exact_size(GRAPH_SIZE, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_stroke(rect, 2.0, ui.visuals().widgets.noninteractive.bg_stroke);
+    if samples.len() < 2 {
+        return;
+    }
+    let (min, max) = samples
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+            (min.min(v), max.max(v))
+        });
+    let span = if max > min { max - min } else { 1.0 };
+    let step = rect.width() / (MAX_SAMPLES - 1) as f32;
+    let start = rect.right() - step * (samples.len() - 1) as f32;
+    let points: Vec<egui::Pos2> = samples
+        .iter()
+        .enumerate()
+        .map(|(index, &v)| {
+            let t = ((v - min) / span) as f32;
+            egui::pos2(
+                start + step * index as f32,
+                rect.bottom() - 2.0 - t * (rect.height() - 4.0),
+            )
+        })
+        .collect();
+    painter.add(egui::Shape::line(
+        points,
+        egui::Stroke::new(1.5, ui.visuals().selection.bg_fill),
+    ));
+    response.on_hover_text(format!("{:.3} … {:.3}", min, max));
+}
@@ -0,0 +1,145 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Window for inspecting and editing `WorldFlags`, e.g. while playtesting a mission.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::flags::{FlagValue, WorldFlags};
+
+/// Kind of value a new flag is created with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FlagKind {
+    #[default]
+    Bool,
+    Number,
+    Text,
+}
+
+impl FlagKind {
+    const ALL: [FlagKind; 3] = [FlagKind::Bool, FlagKind::Number, FlagKind::Text];
+
+    fn label(self) -> &'static str {
+        match self {
+            FlagKind::Bool => "Bool",
+            FlagKind::Number => "Number",
+            FlagKind::Text => "Text",
+        }
+    }
+
+    fn default_value(self) -> FlagValue {
+        match self {
+            FlagKind::Bool => FlagValue::Bool(true),
+            FlagKind::Number => FlagValue::Number(0.0),
+            FlagKind::Text => FlagValue::Text(String::new()),
+        }
+    }
+}
+
+/// State for the world flags window.
+#[derive(Resource, Default)]
+pub struct WorldFlagsWindowState {
+    /// Whether the window is open.
+    pub open: bool,
+    /// Key typed for a new flag.
+    pub new_key: String,
+    /// Kind of value for a new flag.
+    pub new_kind: FlagKind,
+    /// Only show flags whose key contains this text.
+    pub filter: String,
+}
+
+/// Displays the world flags window.
+///
+/// Edits apply immediately, so flags can be changed while the game is playing.
+pub fn world_flags_window(ctx: &egui::Context, world: &mut World) {
+    if !world.resource::<WorldFlagsWindowState>().open {
+        return;
+    }
+
+    let mut state = std::mem::take(&mut *world.resource_mut::<WorldFlagsWindowState>());
+    let original = world.get_resource::<WorldFlags>().cloned();
+    let mut flags = original.clone();
+
+    egui::Window::new("World Flags")
+        .open(&mut state.open)
+        .default_width(320.0)
+        .show(ctx, |ui| {
+            let Some(flags) = &mut flags else {
+                ui.label("WorldFlagsPlugin is not added to this app.");
+                return;
+            };
+
+            ui.horizontal(|ui| {
+                ui.label("🔍");
+                ui.text_edit_singleline(&mut state.filter);
+            });
+            ui.separator();
+
+            let mut removed = None;
+            egui::ScrollArea::vertical()
+                .max_height(300.0)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    egui::Grid::new("world_flags_grid")
+                        .num_columns(3)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for (key, value) in flags.values.iter_mut() {
+                                if !key.contains(state.filter.as_str()) {
+                                    continue;
+                                }
+                                ui.label(key);
+                                match value {
+                                    FlagValue::Bool(value) => {
+                                        ui.checkbox(value, "");
+                                    }
+                                    FlagValue::Number(value) => {
+                                        ui.add(egui::DragValue::new(value).speed(0.1));
+                                    }
+                                    FlagValue::Text(value) => {
+                                        ui.text_edit_singleline(value);
+                                    }
+                                }
+                                if ui.small_button("🗑").on_hover_text("Remove").clicked() {
+                                    removed = Some(key.clone());
+                                }
+                                ui.end_row();
+                            }
+                        });
+                    if flags.values.is_empty() {
+                        ui.weak("No flags set.");
+                    }
+                });
+            if let Some(key) = removed {
+                flags.remove(&key);
+            }
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut state.new_key)
+                        .hint_text("new_flag")
+                        .desired_width(120.0),
+                );
+                egui::ComboBox::from_id_salt("new_flag_kind")
+                    .selected_text(state.new_kind.label())
+                    .show_ui(ui, |ui| {
+                        for kind in FlagKind::ALL {
+                            ui.selectable_value(&mut state.new_kind, kind, kind.label());
+                        }
+                    });
+                let key = state.new_key.trim().to_string();
+                let can_add = !key.is_empty() && flags.get(&key).is_none();
+                if ui.add_enabled(can_add, egui::Button::new("Add")).clicked() {
+                    flags.set(key, state.new_kind.default_value());
+                    state.new_key.clear();
+                }
+            });
+        });
+
+    if let Some(flags) = flags.filter(|flags| Some(flags) != original.as_ref()) {
+        *world.resource_mut::<WorldFlags>() = flags;
+    }
+    *world.resource_mut::<WorldFlagsWindowState>() = state;
+}
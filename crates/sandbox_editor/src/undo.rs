@@ -0,0 +1,233 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Undo/redo history for editor edits.
+//!
+//! Edits are recorded as reflected before/after snapshots of whole components,
+//! so any reflect-registered component can be undone without per-type code.
+
+use bevy::prelude::*;
+use bevy::reflect::PartialReflect;
+use sandbox_engine::scene::SceneManager;
+use std::any::TypeId;
+use std::time::{Duration, Instant};
+
+/// Maximum number of undo steps kept.
+const UNDO_LIMIT: usize = 200;
+
+/// Consecutive edits with the same label within this window merge into one step,
+/// so dragging a value produces a single undo entry.
+const MERGE_WINDOW: Duration = Duration::from_millis(750);
+
+/// Reflected state of a component before and after an edit.
+///
+/// `None` means the entity did not have the component.
+pub struct ComponentChange {
+    pub entity: Entity,
+    pub type_id: TypeId,
+    pub before: Option<Box<dyn PartialReflect>>,
+    pub after: Option<Box<dyn PartialReflect>>,
+}
+
+/// A single undoable step, possibly spanning several entities.
+pub struct UndoEntry {
+    /// Description shown in the Edit menu.
+    pub label: String,
+    pub changes: Vec<ComponentChange>,
+    recorded_at: Instant,
+}
+
+/// Resource holding the undo and redo stacks.
+#[derive(Resource, Default)]
+pub struct UndoStack {
+    undo: Vec<UndoEntry>,
+    redo: Vec<UndoEntry>,
+}
+
+impl UndoStack {
+    /// Label of the step that would be undone, if any.
+    pub fn undo_label(&self) -> Option<&str> {
+        self.undo.last().map(|entry| entry.label.as_str())
+    }
+
+    /// Label of the step that would be redone, if any.
+    pub fn redo_label(&self) -> Option<&str> {
+        self.redo.last().map(|entry| entry.label.as_str())
+    }
+
+    /// Discards all history, e.g. when a different scene is loaded.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    /// Pushes a step, merging it into the previous one when it continues the same edit.
+    pub fn push(&mut self, label: impl Into<String>, changes: Vec<ComponentChange>) {
+        let label = label.into();
+        let changes: Vec<_> = changes
+            .into_iter()
+            .filter(|change| !values_equal(change.before.as_deref(), change.after.as_deref()))
+            .collect();
+        if changes.is_empty() {
+            return;
+        }
+        self.redo.clear();
+
+        if let Some(last) = self.undo.last_mut() {
+            let same_targets = last.changes.len() == changes.len()
+                && last
+                    .changes
+                    .iter()
+                    .zip(&changes)
+                    .all(|(a, b)| a.entity == b.entity && a.type_id == b.type_id);
+            if last.label == label && same_targets && last.recorded_at.elapsed() < MERGE_WINDOW {
+                for (existing, change) in last.changes.iter_mut().zip(changes) {
+                    existing.after = change.after;
+                }
+                last.recorded_at = Instant::now();
+                return;
+            }
+        }
+
+        self.undo.push(UndoEntry {
+            label,
+            changes,
+            recorded_at: Instant::now(),
+        });
+        if self.undo.len() > UNDO_LIMIT {
+            self.undo.remove(0);
+        }
+    }
+}
+
+/// Plugin that sets up undo/redo.
+pub struct UndoPlugin;
+
+impl Plugin for UndoPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UndoStack>();
+    }
+}
+
+/// Captures a reflected copy of an entity's component.
+///
+/// Returns `None` if the entity lacks the component or the type is not reflect-registered.
+pub fn snapshot_component(
+    world: &World,
+    entity: Entity,
+    type_id: TypeId,
+) -> Option<Box<dyn PartialReflect>> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let reflect_component = type_registry
+        .get(type_id)?
+        .data::<ReflectComponent>()?
+        .clone();
+    let entity_ref = world.get_entity(entity).ok()?;
+    reflect_component
+        .reflect(entity_ref)
+        .map(|value| value.clone_value())
+}
+
+/// Snapshots the same component on several entities.
+pub fn snapshot_components(
+    world: &World,
+    entities: &[Entity],
+    type_id: TypeId,
+) -> Vec<(Entity, Option<Box<dyn PartialReflect>>)> {
+    entities
+        .iter()
+        .map(|&entity| (entity, snapshot_component(world, entity, type_id)))
+        .collect()
+}
+
+/// Records an edit of one component type across entities, given snapshots taken before it.
+pub fn record_component_edit(
+    world: &mut World,
+    label: impl Into<String>,
+    type_id: TypeId,
+    before: Vec<(Entity, Option<Box<dyn PartialReflect>>)>,
+) {
+    let changes = before
+        .into_iter()
+        .map(|(entity, before)| ComponentChange {
+            entity,
+            type_id,
+            before,
+            after: snapshot_component(world, entity, type_id),
+        })
+        .collect();
+    world.resource_mut::<UndoStack>().push(label, changes);
+}
+
+/// Reverts the most recent step.
+pub fn undo(world: &mut World) {
+    let Some(entry) = world.resource_mut::<UndoStack>().undo.pop() else {
+        return;
+    };
+    for change in &entry.changes {
+        restore_component(
+            world,
+            change.entity,
+            change.type_id,
+            change.before.as_deref(),
+        );
+    }
+    world.resource_mut::<UndoStack>().redo.push(entry);
+    mark_scene_dirty(world);
+}
+
+/// Reapplies the most recently undone step.
+pub fn redo(world: &mut World) {
+    let Some(entry) = world.resource_mut::<UndoStack>().redo.pop() else {
+        return;
+    };
+    for change in &entry.changes {
+        restore_component(
+            world,
+            change.entity,
+            change.type_id,
+            change.after.as_deref(),
+        );
+    }
+    world.resource_mut::<UndoStack>().undo.push(entry);
+    mark_scene_dirty(world);
+}
+
+/// Sets a component to a snapshot, inserting or removing it as needed.
+fn restore_component(
+    world: &mut World,
+    entity: Entity,
+    type_id: TypeId,
+    value: Option<&dyn PartialReflect>,
+) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let Some(reflect_component) = type_registry
+        .get(type_id)
+        .and_then(|registration| registration.data::<ReflectComponent>())
+    else {
+        return;
+    };
+    let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+        return;
+    };
+
+    match value {
+        Some(value) => reflect_component.apply_or_insert(&mut entity_mut, value, &type_registry),
+        None => reflect_component.remove(&mut entity_mut),
+    }
+}
+
+fn values_equal(a: Option<&dyn PartialReflect>, b: Option<&dyn PartialReflect>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.reflect_partial_eq(b) == Some(true),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn mark_scene_dirty(world: &mut World) {
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
+    }
+}
@@ -0,0 +1,187 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Finds asset files that no scene, prefab, or other RON document references.
+//!
+//! Every `.ron` file under the assets directory (scenes, prefabs, animation
+//! data), every `.scn.json` scene, plus the project file is scanned for
+//! string literals. An asset file
+//! counts as used when one of those strings is its path relative to the
+//! assets directory, optionally followed by a `#label`. These documents
+//! themselves are never reported: they are entry points, not dependencies.
+//!
+//! Files loaded only from game code (`asset_server.load("...")`) cannot be
+//! seen here and are reported as unused.
+
+use bevy::prelude::*;
+use sandbox_engine::scene::SceneFormat;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// An asset file with no references.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnusedAsset {
+    /// Path relative to the assets directory, with `/` separators.
+    pub path: String,
+    /// File size in bytes.
+    pub size: u64,
+}
+
+/// Result of scanning the assets directory for unused files.
+#[derive(Clone, Debug, Default)]
+pub struct UnusedAssetReport {
+    /// Unused files, sorted by path.
+    pub unused: Vec<UnusedAsset>,
+    /// Number of RON documents scanned for references.
+    pub documents: usize,
+    /// Number of non-document asset files checked.
+    pub checked: usize,
+}
+
+impl UnusedAssetReport {
+    /// Total size of the unused files in bytes.
+    pub fn total_size(&self) -> u64 {
+        self.unused.iter().map(|asset| asset.size).sum()
+    }
+}
+
+/// Scans `assets_dir` and reports files not referenced by any RON document
+/// or JSON scene.
+///
+/// `extra_documents` (e.g. the project file) are scanned for references too.
+pub fn find_unused_assets(assets_dir: &Path, extra_documents: &[PathBuf]) -> UnusedAssetReport {
+    let mut files = Vec::new();
+    collect_files(assets_dir, "", &mut files);
+
+    let (documents, candidates): (Vec<_>, Vec<_>) = files.into_iter().partition(|(relative, _)| {
+        relative.ends_with(".ron") || SceneFormat::from_file_name(relative).is_some()
+    });
+
+    let mut references = HashSet::new();
+    let document_paths = documents
+        .iter()
+        .map(|(relative, _)| assets_dir.join(relative))
+        .chain(extra_documents.iter().cloned());
+    for path in document_paths {
+        if let Ok(text) = std::fs::read_to_string(&path) {
+            for literal in string_literals(&text) {
+                references.insert(normalize_reference(&literal));
+            }
+        }
+    }
+
+    let checked = candidates.len();
+    let mut unused: Vec<UnusedAsset> = candidates
+        .into_iter()
+        .filter(|(relative, _)| !references.contains(relative))
+        .map(|(path, size)| UnusedAsset { path, size })
+        .collect();
+    unused.sort_by(|a, b| a.path.cmp(&b.path));
+
+    UnusedAssetReport {
+        unused,
+        documents: documents.len() + extra_documents.len(),
+        checked,
+    }
+}
+
+/// Where the editor puts asset files it deletes.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AssetTrash {
+    /// The system trash, so deletions can be restored from the file manager.
+    #[default]
+    System,
+    /// Deleted permanently, for tests and tools that must not fill the
+    /// user's trash.
+    Permanent,
+}
+
+impl AssetTrash {
+    /// Removes the given files or directories (relative to `assets_dir`).
+    ///
+    /// Returns the number of paths removed.
+    pub fn remove(self, assets_dir: &Path, paths: &[String]) -> Result<usize, String> {
+        match self {
+            AssetTrash::System => move_to_trash(assets_dir, paths),
+            AssetTrash::Permanent => {
+                for path in paths {
+                    let full_path = assets_dir.join(path);
+                    let result = if full_path.is_dir() {
+                        std::fs::remove_dir_all(&full_path)
+                    } else {
+                        std::fs::remove_file(&full_path)
+                    };
+                    result.map_err(|e| e.to_string())?;
+                }
+                Ok(paths.len())
+            }
+        }
+    }
+}
+
+/// Moves the given files (relative to `assets_dir`) to the system trash.
+///
+/// Returns the number of files moved.
+pub fn move_to_trash(assets_dir: &Path, paths: &[String]) -> Result<usize, String> {
+    let full_paths: Vec<PathBuf> = paths.iter().map(|path| assets_dir.join(path)).collect();
+    trash::delete_all(&full_paths).map_err(|e| e.to_string())?;
+    Ok(full_paths.len())
+}
+
+/// Recursively lists files as (relative path, size), skipping hidden entries like the browser does.
+pub(crate) fn collect_files(dir: &Path, relative_base: &str, files: &mut Vec<(String, u64)>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if file_name.starts_with('.') {
+            continue;
+        }
+        let relative = if relative_base.is_empty() {
+            file_name
+        } else {
+            format!("{}/{}", relative_base, file_name)
+        };
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            collect_files(&entry.path(), &relative, files);
+        } else if metadata.is_file() {
+            files.push((relative, metadata.len()));
+        }
+    }
+}
+
+/// Extracts the contents of every double-quoted string in a RON document.
+fn string_literals(text: &str) -> Vec<String> {
+    let mut literals = Vec::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '"' {
+            continue;
+        }
+        let mut literal = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => break,
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        literal.push(escaped);
+                    }
+                }
+                _ => literal.push(c),
+            }
+        }
+        literals.push(literal);
+    }
+    literals
+}
+
+/// Turns a referenced path into the form used for relative asset paths.
+pub(crate) fn normalize_reference(reference: &str) -> String {
+    let path = reference.split('#').next().unwrap_or_default();
+    let path = path.replace('\\', "/");
+    path.trim_start_matches("./").to_string()
+}
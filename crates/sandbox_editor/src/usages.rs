@@ -0,0 +1,212 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Finds the entities and scene files that reference an asset.
+//!
+//! References are `AssetPath` components, both on entities of the open scene
+//! and in the scene files (`.scn.ron`) under the assets directory. Looking up
+//! a folder finds references to every file inside it. [`update_references`]
+//! repoints them after a rename or move.
+
+use bevy::prelude::*;
+use sandbox_engine::assets::AssetPath;
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::SceneManager;
+use std::any::TypeId;
+
+use crate::problems::{scene_file_references, scene_files};
+use crate::undo::{record_component_edit, snapshot_components};
+use crate::unused_assets::normalize_reference;
+
+/// A place that references an asset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Usage {
+    /// An entity in the open scene.
+    Entity { entity: Entity, path: String },
+    /// An entity in a scene file on disk, relative to the assets directory.
+    SceneFile {
+        file: String,
+        entity: String,
+        path: String,
+    },
+}
+
+impl Usage {
+    /// The referencing path, as written.
+    pub fn path(&self) -> &str {
+        match self {
+            Usage::Entity { path, .. } | Usage::SceneFile { path, .. } => path,
+        }
+    }
+}
+
+/// Result of [`find_usages`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UsageReport {
+    /// References found: the open scene first, then scene files by path.
+    pub usages: Vec<Usage>,
+    /// Scene files that couldn't be read, with the error; they may hold
+    /// more references.
+    pub unreadable: Vec<(String, String)>,
+}
+
+/// Finds references to `asset`, a file or folder relative to the assets directory.
+pub fn find_usages(world: &mut World, asset: &str) -> UsageReport {
+    let Some(assets_dir) = world
+        .get_resource::<ProjectRoot>()
+        .map(ProjectRoot::assets_dir)
+    else {
+        return UsageReport::default();
+    };
+
+    let mut query = world.query::<(Entity, &AssetPath)>();
+    let mut live: Vec<(Entity, String)> = query
+        .iter(world)
+        .filter(|(_, asset_path)| references(&asset_path.path, asset))
+        .map(|(entity, asset_path)| (entity, asset_path.path.clone()))
+        .collect();
+    live.sort_by_key(|(entity, _)| entity.index());
+    let mut usages: Vec<Usage> = live
+        .into_iter()
+        .map(|(entity, path)| Usage::Entity { entity, path })
+        .collect();
+
+    let (scene_references, unreadable) = scene_file_references(world, &assets_dir);
+    usages.extend(
+        scene_references
+            .into_iter()
+            .filter(|reference| references(&reference.path, asset))
+            .map(|reference| Usage::SceneFile {
+                file: reference.file,
+                entity: reference.entity,
+                path: reference.path,
+            }),
+    );
+    UsageReport { usages, unreadable }
+}
+
+/// Points every `AssetPath` naming `old` (or a file inside it) at `new`.
+///
+/// Entities in the open scene are updated as one undo step. Scene files are
+/// edited in place, so their formatting and other components are untouched.
+/// Returns the number of references changed.
+pub fn update_references(world: &mut World, old: &str, new: &str) -> Result<usize, String> {
+    let Some(assets_dir) = world
+        .get_resource::<ProjectRoot>()
+        .map(ProjectRoot::assets_dir)
+    else {
+        return Ok(0);
+    };
+
+    let mut query = world.query::<(Entity, &AssetPath)>();
+    let mut live: Vec<(Entity, String)> = query
+        .iter(world)
+        .filter_map(|(entity, asset_path)| {
+            Some((entity, renamed_path(&asset_path.path, old, new)?))
+        })
+        .collect();
+    live.sort_by_key(|(entity, _)| entity.index());
+    let mut updated = live.len();
+    if !live.is_empty() {
+        let entities: Vec<Entity> = live.iter().map(|(entity, _)| *entity).collect();
+        let before = snapshot_components(world, &entities, TypeId::of::<AssetPath>());
+        for (entity, path) in live {
+            if let Some(mut asset_path) = world.get_mut::<AssetPath>(entity) {
+                asset_path.path = path;
+            }
+        }
+        record_component_edit(
+            world,
+            "Update Asset References",
+            TypeId::of::<AssetPath>(),
+            before,
+        );
+        if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+            manager.mark_dirty();
+        }
+    }
+
+    for file in scene_files(world, &assets_dir) {
+        let path = assets_dir.join(&file);
+        let text = std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", file, e))?;
+        let (rewritten, count) = rewrite_scene_text(&text, old, new);
+        if count > 0 {
+            std::fs::write(&path, rewritten).map_err(|e| format!("{}: {}", file, e))?;
+            updated += count;
+        }
+    }
+    Ok(updated)
+}
+
+/// Whether `path` names `asset` or a file inside it.
+fn references(path: &str, asset: &str) -> bool {
+    renamed_path(path, asset, asset).is_some()
+}
+
+/// `path` with `old` replaced by `new`, if it names `old` or a file inside
+/// it. A `#label` suffix is kept.
+fn renamed_path(path: &str, old: &str, new: &str) -> Option<String> {
+    let (base, label) = match path.split_once('#') {
+        Some((base, label)) => (base, Some(label)),
+        None => (path, None),
+    };
+    let base = normalize_reference(base);
+    let old = old.trim_end_matches('/');
+    let rest = if base == old {
+        ""
+    } else {
+        base.strip_prefix(old)
+            .filter(|rest| rest.starts_with('/'))?
+    };
+    let label = label.map(|label| format!("#{}", label)).unwrap_or_default();
+    Some(format!("{}{}{}", new, rest, label))
+}
+
+/// Rewrites the `AssetPath` values in a scene file's text, returning the new
+/// text and the number of paths changed.
+///
+/// Components are serialized as `"type::Path": (path: "...")`, so only the
+/// literal after each `AssetPath` key is touched.
+fn rewrite_scene_text(text: &str, old: &str, new: &str) -> (String, usize) {
+    let key = format!("\"{}\"", AssetPath::type_path());
+    let mut rewritten = String::with_capacity(text.len());
+    let mut rest = text;
+    let mut count = 0;
+    while let Some(found) = rest.find(&key) {
+        let (head, tail) = rest.split_at(found + key.len());
+        rewritten.push_str(head);
+        rest = tail;
+        let Some((start, end)) = path_literal(rest) else {
+            continue;
+        };
+        rewritten.push_str(&rest[..start]);
+        match renamed_path(&rest[start..end], old, new) {
+            Some(renamed) => {
+                rewritten.push_str(&renamed);
+                count += 1;
+            }
+            None => rewritten.push_str(&rest[start..end]),
+        }
+        rest = &rest[end..];
+    }
+    rewritten.push_str(rest);
+    (rewritten, count)
+}
+
+/// Byte range of the string in `: (path: "...")` at the start of `text`.
+///
+/// Strings with escapes are left alone; asset paths don't need them.
+fn path_literal(text: &str) -> Option<(usize, usize)> {
+    let mut offset = 0;
+    for token in [":", "(", "path", ":", "\""] {
+        let skipped = text[offset..].len() - text[offset..].trim_start().len();
+        offset += skipped;
+        if !text[offset..].starts_with(token) {
+            return None;
+        }
+        offset += token.len();
+    }
+    let length = text[offset..].find('"')?;
+    let value = &text[offset..offset + length];
+    (!value.contains('\\')).then_some((offset, offset + length))
+}
@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Render-to-texture target for the editor viewport.
+//!
+//! The scene camera renders into an `Image` that the viewport panel displays,
+//! resized to match the panel.
+
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_resource::{
+    Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+};
+use bevy_egui::{egui, EguiUserTextures};
+
+use crate::editor_camera::EditorCamera;
+
+/// Resource holding the image the scene camera renders into.
+#[derive(Resource)]
+pub struct ViewportTexture {
+    /// The render target image.
+    pub image: Handle<Image>,
+    /// The image registered with egui.
+    pub texture_id: egui::TextureId,
+    /// Current size of the image in physical pixels.
+    pub size: UVec2,
+}
+
+/// Plugin that sets up the viewport render target.
+pub struct ViewportPlugin;
+
+impl Plugin for ViewportPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreStartup, setup_viewport_texture)
+            .add_systems(Update, attach_cameras_to_viewport);
+    }
+}
+
+/// Creates the viewport image and registers it with egui.
+fn setup_viewport_texture(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut egui_user_textures: ResMut<EguiUserTextures>,
+) {
+    let size = UVec2::new(512, 512);
+    let mut image = Image {
+        texture_descriptor: TextureDescriptor {
+            label: Some("editor_viewport"),
+            size: extent(size),
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Bgra8UnormSrgb,
+            mip_level_count: 1,
+            sample_count: 1,
+            usage: TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_DST
+                | TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        },
+        ..default()
+    };
+    image.resize(extent(size));
+
+    let image = images.add(image);
+    let texture_id = egui_user_textures.add_image(image.clone());
+
+    commands.insert_resource(ViewportTexture {
+        image,
+        texture_id,
+        size,
+    });
+}
+
+/// Points newly spawned 2D cameras at the viewport image.
+///
+/// Game cameras start inactive; the editor camera plugin activates them in play mode.
+fn attach_cameras_to_viewport(
+    viewport: Res<ViewportTexture>,
+    mut cameras: Query<(&mut Camera, Has<EditorCamera>), Added<Camera2d>>,
+) {
+    for (mut camera, is_editor_camera) in &mut cameras {
+        camera.target = RenderTarget::Image(viewport.image.clone());
+        if !is_editor_camera {
+            camera.is_active = false;
+        }
+    }
+}
+
+/// Resizes the viewport image to cover `rect` and applies the editor camera's zoom.
+///
+/// Returns the egui texture to paint over `rect`.
+pub fn fit_viewport_texture(
+    world: &mut World,
+    rect: egui::Rect,
+    pixels_per_point: f32,
+) -> egui::TextureId {
+    let desired = UVec2::new(
+        (rect.width() * pixels_per_point).round().max(1.0) as u32,
+        (rect.height() * pixels_per_point).round().max(1.0) as u32,
+    );
+
+    let (image, texture_id, size) = {
+        let viewport = world.resource::<ViewportTexture>();
+        (viewport.image.clone(), viewport.texture_id, viewport.size)
+    };
+
+    if desired != size {
+        if let Some(image) = world.resource_mut::<Assets<Image>>().get_mut(&image) {
+            image.resize(extent(desired));
+        }
+        world.resource_mut::<ViewportTexture>().size = desired;
+    }
+
+    let mut projections = world.query::<(&mut OrthographicProjection, &EditorCamera)>();
+    for (mut projection, camera) in projections.iter_mut(world) {
+        let scale = 1.0 / (camera.zoom * pixels_per_point);
+        if projection.scale != scale {
+            projection.scale = scale;
+        }
+    }
+
+    texture_id
+}
+
+fn extent(size: UVec2) -> Extent3d {
+    Extent3d {
+        width: size.x,
+        height: size.y,
+        depth_or_array_layers: 1,
+    }
+}
@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Right-click context menu in the viewport for creating entities in place.
+//!
+//! The world position under the pointer is captured when the menu opens, so
+//! "Create Sprite Here", "Create Empty Here", the "Create Template Here"
+//! presets, and "Paste Here" place entities where the user clicked rather than
+//! where the pointer is when the item is chosen.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use sandbox_engine::ambient::AmbientPreset;
+
+use crate::clipboard::{paste_clipboard_at, EditorClipboard};
+use crate::editor_camera::ViewportCamera;
+use crate::ui::{add_empty_entity, add_new_entity, add_template};
+
+/// Resource holding the world position the viewport context menu was opened at.
+#[derive(Resource, Default)]
+pub struct ViewportMenuState {
+    pub position: Option<Vec2>,
+}
+
+/// Action chosen in the viewport context menu.
+enum ViewportMenuAction {
+    CreateSprite,
+    CreateEmpty,
+    CreateTemplate(AmbientPreset),
+    Paste,
+}
+
+/// Shows the viewport context menu on right-click and performs the chosen action.
+pub fn viewport_context_menu(
+    world: &mut World,
+    viewport_rect: egui::Rect,
+    response: &egui::Response,
+) {
+    if response.secondary_clicked() {
+        let position = response
+            .interact_pointer_pos()
+            .zip(ViewportCamera::from_world(world))
+            .map(|(pointer, view)| view.screen_to_world(pointer, viewport_rect));
+        world.resource_mut::<ViewportMenuState>().position = position;
+    }
+
+    let can_paste = world.resource::<EditorClipboard>().scene.is_some();
+    let mut action = None;
+    response.context_menu(|ui| {
+        if ui.button("Create Sprite Here").clicked() {
+            action = Some(ViewportMenuAction::CreateSprite);
+            ui.close_menu();
+        }
+        if ui.button("Create Empty Here").clicked() {
+            action = Some(ViewportMenuAction::CreateEmpty);
+            ui.close_menu();
+        }
+        ui.menu_button("Create Template Here", |ui| {
+            for &preset in AmbientPreset::ALL {
+                if ui.button(preset.label()).clicked() {
+                    action = Some(ViewportMenuAction::CreateTemplate(preset));
+                    ui.close_menu();
+                }
+            }
+        });
+        if ui
+            .add_enabled(can_paste, egui::Button::new("Paste Here"))
+            .clicked()
+        {
+            action = Some(ViewportMenuAction::Paste);
+            ui.close_menu();
+        }
+    });
+
+    let Some(action) = action else {
+        return;
+    };
+    let Some(position) = world.resource::<ViewportMenuState>().position else {
+        return;
+    };
+    match action {
+        ViewportMenuAction::CreateSprite => {
+            add_new_entity(world, position);
+        }
+        ViewportMenuAction::CreateEmpty => {
+            add_empty_entity(world, position);
+        }
+        ViewportMenuAction::CreateTemplate(preset) => {
+            add_template(world, preset, position);
+        }
+        ViewportMenuAction::Paste => paste_clipboard_at(world, position),
+    }
+}
@@ -0,0 +1,194 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Viewport display and editing of [`WrapAround`] bounds.
+//!
+//! Every distinct wrap rectangle in the scene is outlined. While stopped, the
+//! primary selection's rectangle gets corner and edge handles; dragging one
+//! resizes the bounds of every selected `WrapAround` entity as one undo step.
+
+use bevy::prelude::*;
+use bevy::reflect::PartialReflect;
+use bevy_egui::egui;
+use sandbox_engine::editor_state::EditorPlayState;
+use sandbox_engine::scene::SceneManager;
+use sandbox_engine::wrap::WrapAround;
+use std::any::TypeId;
+
+use crate::editor_camera::ViewportCamera;
+use crate::selection::EditorSelection;
+use crate::undo::{record_component_edit, snapshot_components};
+
+/// Outline color of wrap bounds.
+const COLOR_BOUNDS: egui::Color32 = egui::Color32::from_rgb(200, 120, 255);
+
+/// Color of a hovered or dragged handle.
+const COLOR_HANDLE_ACTIVE: egui::Color32 = egui::Color32::WHITE;
+
+/// Size of the resize handles, in points.
+const HANDLE_SIZE: f32 = 8.0;
+
+/// Distance from a handle within which it can be grabbed, in points.
+const HANDLE_HIT_RADIUS: f32 = 8.0;
+
+/// Label drawn above the outline.
+pub const WRAP_BOUNDS_LABEL: &str = "Wrap bounds";
+
+/// Resource tracking a drag of a wrap bounds handle.
+#[derive(Resource, Default)]
+pub struct WrapBoundsDrag {
+    /// Edge or corner being dragged: -1, 0, or 1 on each axis.
+    handle: Option<IVec2>,
+    /// Entities being resized, with their `WrapAround` from before the drag.
+    before: Vec<(Entity, Option<Box<dyn PartialReflect>>)>,
+}
+
+/// Draws wrap bounds and handles dragging their handles.
+///
+/// Returns whether a handle owns the pointer, so the gizmo and picking
+/// should ignore it this frame.
+pub fn draw_wrap_bounds(
+    painter: &egui::Painter,
+    viewport_rect: egui::Rect,
+    world: &mut World,
+    response: &egui::Response,
+) -> bool {
+    let Some(view) = ViewportCamera::from_world(world) else {
+        return false;
+    };
+    let to_screen = |bounds: Rect| {
+        egui::Rect::from_two_pos(
+            view.world_to_screen(bounds.min, viewport_rect),
+            view.world_to_screen(bounds.max, viewport_rect),
+        )
+    };
+
+    // Entities often share bounds, so outline each rectangle once
+    let mut outlines: Vec<Rect> = Vec::new();
+    let mut wraps = world.query::<&WrapAround>();
+    for wrap in wraps.iter(world) {
+        if !outlines.contains(&wrap.bounds) {
+            outlines.push(wrap.bounds);
+        }
+    }
+    for bounds in outlines {
+        let rect = to_screen(bounds);
+        painter.rect_stroke(rect, 0.0, egui::Stroke::new(1.5, COLOR_BOUNDS));
+        painter.text(
+            rect.left_top() + egui::vec2(4.0, 4.0),
+            egui::Align2::LEFT_TOP,
+            WRAP_BOUNDS_LABEL,
+            egui::FontId::proportional(11.0),
+            COLOR_BOUNDS,
+        );
+    }
+
+    if *world.resource::<State<EditorPlayState>>().get() != EditorPlayState::Stopped {
+        return false;
+    }
+    let selection = world.resource::<EditorSelection>();
+    let Some(bounds) = selection
+        .selected_entity
+        .and_then(|entity| world.get::<WrapAround>(entity))
+        .map(|wrap| wrap.bounds)
+    else {
+        return false;
+    };
+    let targets: Vec<Entity> = selection
+        .entities
+        .iter()
+        .copied()
+        .filter(|&entity| world.get::<WrapAround>(entity).is_some())
+        .collect();
+
+    let rect = to_screen(bounds);
+    let handles = handle_positions(rect);
+    let hit = |pos: egui::Pos2| {
+        handles
+            .iter()
+            .find(|(_, handle)| handle.distance(pos) <= HANDLE_HIT_RADIUS)
+            .map(|(sign, _)| *sign)
+    };
+
+    if response.drag_started_by(egui::PointerButton::Primary) {
+        let press_origin = response.ctx.input(|input| input.pointer.press_origin());
+        if let Some(sign) = press_origin.and_then(hit) {
+            let before = snapshot_components(world, &targets, TypeId::of::<WrapAround>());
+            *world.resource_mut::<WrapBoundsDrag>() = WrapBoundsDrag {
+                handle: Some(sign),
+                before,
+            };
+        }
+    }
+
+    let dragging = world.resource::<WrapBoundsDrag>().handle;
+    if let (Some(sign), Some(pointer)) = (dragging, response.interact_pointer_pos()) {
+        let pointer = view.screen_to_world(pointer, viewport_rect);
+        let resized = resize_bounds(bounds, sign, pointer);
+        for &entity in &targets {
+            if let Some(mut wrap) = world.get_mut::<WrapAround>(entity) {
+                wrap.bounds = resized;
+            }
+        }
+    }
+    if dragging.is_some() && response.drag_stopped() {
+        let drag = std::mem::take(&mut *world.resource_mut::<WrapBoundsDrag>());
+        record_component_edit(
+            world,
+            "Resize Wrap Bounds",
+            TypeId::of::<WrapAround>(),
+            drag.before,
+        );
+        if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+            manager.mark_dirty();
+        }
+    }
+
+    let active = dragging.or_else(|| response.hover_pos().and_then(hit));
+    for (sign, pos) in handles {
+        let color = if active == Some(sign) {
+            COLOR_HANDLE_ACTIVE
+        } else {
+            COLOR_BOUNDS
+        };
+        painter.rect_filled(
+            egui::Rect::from_center_size(pos, egui::vec2(HANDLE_SIZE, HANDLE_SIZE)),
+            1.0,
+            color,
+        );
+    }
+    dragging.is_some()
+}
+
+/// Corner and edge handles of a screen rectangle, with their world-space directions.
+fn handle_positions(rect: egui::Rect) -> Vec<(IVec2, egui::Pos2)> {
+    let mut handles = Vec::new();
+    for y in -1..=1 {
+        for x in -1..=1 {
+            if x == 0 && y == 0 {
+                continue;
+            }
+            // Screen y grows downwards, world y upwards
+            let pos = egui::pos2(
+                egui::lerp(rect.left()..=rect.right(), (x + 1) as f32 / 2.0),
+                egui::lerp(rect.bottom()..=rect.top(), (y + 1) as f32 / 2.0),
+            );
+            handles.push((IVec2::new(x, y), pos));
+        }
+    }
+    handles
+}
+
+/// Moves the edges of `bounds` picked by `sign` to `pointer`.
+fn resize_bounds(bounds: Rect, sign: IVec2, pointer: Vec2) -> Rect {
+    let mut min = bounds.min;
+    let mut max = bounds.max;
+    for axis in 0..2 {
+        match sign[axis] {
+            -1 => min[axis] = pointer[axis],
+            1 => max[axis] = pointer[axis],
+            _ => {}
+        }
+    }
+    Rect::from_corners(min, max)
+}
@@ -0,0 +1,351 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Headless harness for editor UI smoke tests.
+//!
+//! `EditorHarness` builds the full editor app without a window or GPU and
+//! draws the editor UI into its own egui context. Tests script input the way
+//! a user would (clicking text, pressing shortcuts, typing) and then assert on
+//! the world. Widgets are located by the text they paint, so tests read like
+//! the flows they cover.
+//!
+//! # Example
+//! ```ignore
+//! let mut editor = EditorHarness::new();
+//! editor.key(egui::Modifiers::CTRL, egui::Key::N);
+//! editor.click_text("+ Add Entity");
+//! assert_eq!(editor.entities_named("Entity 1").len(), 1);
+//! ```
+
+use bevy::app::TerminalCtrlCHandlerPlugin;
+use bevy::log::LogPlugin;
+use bevy::prelude::*;
+use bevy::render::settings::{RenderCreation, WgpuSettings};
+use bevy::render::RenderPlugin;
+use bevy::window::ExitCondition;
+use bevy::winit::WinitPlugin;
+use bevy_egui::egui;
+use sandbox_editor::assets::AssetBrowser;
+use sandbox_editor::unused_assets::AssetTrash;
+use sandbox_editor::{draw_editor, EditorPlugin};
+use sandbox_engine::project::{ProjectRoot, PROJECT_FILE_NAME};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+
+/// Size of the simulated editor window, in points.
+const SCREEN_SIZE: egui::Vec2 = egui::vec2(1600.0, 900.0);
+
+/// Seconds of egui time between frames.
+const FRAME_SECONDS: f64 = 1.0 / 60.0;
+
+/// A headless editor driven by scripted egui input.
+pub struct EditorHarness {
+    pub app: App,
+    ctx: egui::Context,
+    time: f64,
+    pointer: egui::Pos2,
+    /// Modifier keys held during every frame, e.g. Shift while dragging.
+    modifiers: egui::Modifiers,
+    events: Vec<egui::Event>,
+    texts: Vec<(String, egui::Rect)>,
+    project_dir: PathBuf,
+}
+
+impl Default for EditorHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EditorHarness {
+    /// Builds the editor in a fresh temporary project and runs a few frames
+    /// so the layout settles.
+    pub fn new() -> Self {
+        let project_dir = temp_project_dir();
+        std::fs::create_dir_all(project_dir.join("assets/scenes"))
+            .expect("temporary project directory should be writable");
+        std::fs::write(project_dir.join(PROJECT_FILE_NAME), "()")
+            .expect("temporary project file should be writable");
+        let project_root = ProjectRoot::new(&project_dir);
+
+        let mut app = App::new();
+        app.add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: None,
+                    exit_condition: ExitCondition::DontExit,
+                    close_when_requested: false,
+                })
+                .set(RenderPlugin {
+                    render_creation: RenderCreation::Automatic(WgpuSettings {
+                        backends: None,
+                        ..default()
+                    }),
+                    ..default()
+                })
+                .set(project_root.asset_plugin())
+                .disable::<WinitPlugin>()
+                .disable::<LogPlugin>()
+                .disable::<TerminalCtrlCHandlerPlugin>(),
+        )
+        .insert_resource(project_root)
+        .add_plugins(EditorPlugin)
+        // Keep deleted test assets out of the developer's trash
+        .insert_resource(AssetTrash::Permanent);
+        app.finish();
+        app.cleanup();
+
+        let mut harness = Self {
+            app,
+            ctx: egui::Context::default(),
+            time: 0.0,
+            pointer: egui::Pos2::ZERO,
+            modifiers: egui::Modifiers::NONE,
+            events: Vec::new(),
+            texts: Vec::new(),
+            project_dir,
+        };
+        harness.run_frames(3);
+        harness.wait_for_asset_scan();
+        harness
+    }
+
+    /// Directory of the temporary project; deleted when the harness is dropped.
+    pub fn project_dir(&self) -> &Path {
+        &self.project_dir
+    }
+
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// Runs one app update, then draws the editor with the queued input.
+    ///
+    /// Mirrors the real app, where the editor UI runs during `Update`.
+    pub fn frame(&mut self) {
+        self.app.update();
+
+        self.time += FRAME_SECONDS;
+        let input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, SCREEN_SIZE)),
+            time: Some(self.time),
+            focused: true,
+            modifiers: self.modifiers,
+            events: std::mem::take(&mut self.events),
+            ..default()
+        };
+
+        let world = self.app.world_mut();
+        let output = self.ctx.run(input, |ctx| draw_editor(ctx, world));
+        self.texts.clear();
+        collect_texts(&output.shapes, &mut self.texts);
+    }
+
+    /// Runs several frames without new input.
+    pub fn run_frames(&mut self, count: usize) {
+        for _ in 0..count {
+            self.frame();
+        }
+    }
+
+    /// Runs frames until the asset browser's background scan has been applied.
+    ///
+    /// Panics if the scan takes longer than a few seconds.
+    pub fn wait_for_asset_scan(&mut self) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while self.world().resource::<AssetBrowser>().is_scanning() {
+            assert!(Instant::now() < deadline, "asset scan did not finish");
+            std::thread::sleep(Duration::from_millis(1));
+            self.frame();
+        }
+    }
+
+    /// Texts painted in the last frame with their screen rectangles, in paint order.
+    pub fn texts(&self) -> &[(String, egui::Rect)] {
+        &self.texts
+    }
+
+    /// Rectangle of the first painted text exactly matching `text`.
+    pub fn find_text(&self, text: &str) -> Option<egui::Rect> {
+        self.texts
+            .iter()
+            .find(|(painted, _)| painted == text)
+            .map(|(_, rect)| *rect)
+    }
+
+    /// Rectangle of the nearest `text` painted to the right of `anchor` on the same row.
+    ///
+    /// Useful for unlabeled widgets such as the drag value after an "X:" label.
+    pub fn find_text_right_of(&self, anchor: &str, text: &str) -> Option<egui::Rect> {
+        let anchor = self.find_text(anchor)?;
+        self.texts
+            .iter()
+            .filter(|(painted, rect)| {
+                painted == text
+                    && rect.left() >= anchor.right()
+                    && (rect.center().y - anchor.center().y).abs() < anchor.height() / 2.0
+            })
+            .map(|(_, rect)| *rect)
+            .min_by(|a, b| a.left().total_cmp(&b.left()))
+    }
+
+    /// Moves the pointer to a screen position without pressing a button.
+    pub fn hover(&mut self, pos: egui::Pos2) {
+        self.pointer = pos;
+        self.events.push(egui::Event::PointerMoved(pos));
+        self.frame();
+    }
+
+    /// Clicks at a screen position (press and release over two frames).
+    pub fn click_at(&mut self, pos: egui::Pos2) {
+        self.click_button_at(pos, egui::PointerButton::Primary);
+    }
+
+    /// Right-clicks at a screen position, e.g. to open a context menu.
+    pub fn right_click_at(&mut self, pos: egui::Pos2) {
+        self.click_button_at(pos, egui::PointerButton::Secondary);
+    }
+
+    fn click_button_at(&mut self, pos: egui::Pos2, button: egui::PointerButton) {
+        self.pointer = pos;
+        self.events.push(egui::Event::PointerMoved(pos));
+        self.events.push(pointer_button(pos, button, true));
+        self.frame();
+        self.events.push(pointer_button(pos, button, false));
+        self.frame();
+        // Let the result of the click show up in the next layout
+        self.frame();
+    }
+
+    /// Drags with the primary button from `from` to `to` while holding `modifiers`.
+    ///
+    /// The pointer first nudges just past egui's click distance, so the drag
+    /// starts close to `from` (where handles are hit tested), then moves to
+    /// `to` in steps.
+    pub fn drag(&mut self, from: egui::Pos2, to: egui::Pos2, modifiers: egui::Modifiers) {
+        self.modifiers = modifiers;
+        self.pointer = from;
+        self.events.push(egui::Event::PointerMoved(from));
+        self.events
+            .push(pointer_button(from, egui::PointerButton::Primary, true));
+        self.frame();
+        let nudge = from + (to - from).normalized() * 8.0;
+        let steps = [nudge, from.lerp(to, 0.5), to];
+        for pos in steps {
+            self.pointer = pos;
+            self.events.push(egui::Event::PointerMoved(pos));
+            self.frame();
+        }
+        self.events
+            .push(pointer_button(to, egui::PointerButton::Primary, false));
+        self.frame();
+        self.modifiers = egui::Modifiers::NONE;
+        self.frame();
+    }
+
+    /// Clicks the center of the first painted `text`.
+    ///
+    /// Panics with the painted texts listed if `text` is not on screen.
+    pub fn click_text(&mut self, text: &str) {
+        let rect = self.find_text(text).unwrap_or_else(|| {
+            panic!(
+                "no text {:?} on screen; painted: {:?}",
+                text,
+                self.texts.iter().map(|(t, _)| t).collect::<Vec<_>>()
+            )
+        });
+        self.click_at(rect.center());
+    }
+
+    /// Presses and releases a key with modifiers held.
+    pub fn key(&mut self, modifiers: egui::Modifiers, key: egui::Key) {
+        for pressed in [true, false] {
+            self.events.push(egui::Event::Key {
+                key,
+                physical_key: None,
+                pressed,
+                repeat: false,
+                modifiers,
+            });
+        }
+        self.frame();
+        self.frame();
+    }
+
+    /// Types text into the focused widget.
+    pub fn type_text(&mut self, text: &str) {
+        self.events.push(egui::Event::Text(text.to_string()));
+        self.frame();
+    }
+
+    /// Entities with the given `Name`, sorted by index.
+    pub fn entities_named(&mut self, name: &str) -> Vec<Entity> {
+        let world = self.app.world_mut();
+        let mut query = world.query::<(Entity, &Name)>();
+        let mut entities: Vec<Entity> = query
+            .iter(world)
+            .filter(|(_, entity_name)| entity_name.as_str() == name)
+            .map(|(entity, _)| entity)
+            .collect();
+        entities.sort_by_key(|entity| entity.index());
+        entities
+    }
+}
+
+impl Drop for EditorHarness {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.project_dir);
+    }
+}
+
+fn pointer_button(pos: egui::Pos2, button: egui::PointerButton, pressed: bool) -> egui::Event {
+    egui::Event::PointerButton {
+        pos,
+        button,
+        pressed,
+        modifiers: egui::Modifiers::NONE,
+    }
+}
+
+/// Collects visible text shapes and their screen rectangles.
+fn collect_texts(shapes: &[egui::epaint::ClippedShape], out: &mut Vec<(String, egui::Rect)>) {
+    for clipped in shapes {
+        collect_shape_texts(&clipped.shape, clipped.clip_rect, out);
+    }
+}
+
+fn collect_shape_texts(
+    shape: &egui::Shape,
+    clip_rect: egui::Rect,
+    out: &mut Vec<(String, egui::Rect)>,
+) {
+    match shape {
+        egui::Shape::Text(text) => {
+            let rect = text.galley.rect.translate(text.pos.to_vec2());
+            if clip_rect.intersects(rect) {
+                out.push((text.galley.text().to_string(), rect));
+            }
+        }
+        egui::Shape::Vec(shapes) => {
+            for shape in shapes {
+                collect_shape_texts(shape, clip_rect, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// A unique temporary directory for one harness.
+fn temp_project_dir() -> PathBuf {
+    static NEXT: AtomicU32 = AtomicU32::new(0);
+    std::env::temp_dir().join(format!(
+        "sandbox_editor_harness_{}_{}",
+        std::process::id(),
+        NEXT.fetch_add(1, Ordering::Relaxed)
+    ))
+}
@@ -0,0 +1,2649 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Editor UI smoke tests: scripted flows through the real editor UI.
+
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use bevy::time::TimeUpdateStrategy;
+use bevy_egui::{egui, EguiUserTextures};
+use sandbox_editor::assets::{cache_preview, AssetBrowser};
+use sandbox_editor::autosave::{
+    autosave_path, backup_path, find_recoverable_autosaves, AutosaveRecovery, AutosaveSettings,
+};
+use sandbox_editor::custom_inspector::CustomInspectors;
+use sandbox_editor::editor_camera::EditorCamera;
+use sandbox_editor::gizmo::{GizmoMode, GizmoPivot, SnapSettings};
+use sandbox_editor::modal_transform::ModalTransformState;
+use sandbox_editor::presets::{apply_preset, preset_dir_name, save_preset, ComponentPresets};
+use sandbox_editor::scene_diff::{diff_lines, Change, DiffLine};
+use sandbox_editor::selection::EditorSelection;
+use sandbox_editor::ui::{add_image_sprite, PrefabLinkState, SceneChangesState, WatchWindowState};
+use sandbox_editor::undo::{undo, UndoStack};
+use sandbox_editor_harness::EditorHarness;
+use sandbox_engine::ambient::{AmbientSound, AudioZone};
+use sandbox_engine::assets::{AnimationFrame, AssetPath, SpriteAnimation, SpriteVariant};
+use sandbox_engine::color_grade::ColorGroup;
+use sandbox_engine::editor_state::EditorPlayState;
+use sandbox_engine::flags::WorldFlags;
+use sandbox_engine::interaction::Interactable;
+use sandbox_engine::joints::DistanceJoint;
+use sandbox_engine::particles::{EmitterShape, Particle, ParticleEmitter, ParticlePreview};
+use sandbox_engine::physics::{Attractor, Collider, ColliderShape, LinearVelocity, Repulsor};
+use sandbox_engine::project::{ProjectRoot, ProjectSettings};
+use sandbox_engine::scene::{
+    load_scene, save_scene, spawn_prefab, EditorLocked, EditorNote, SceneInstance, SceneManager,
+};
+use sandbox_engine::sequence::{Sequence, SequenceCommand};
+use sandbox_engine::spawn::{SpawnKind, SpawnPoint};
+use sandbox_engine::system_toggle::SystemToggles;
+use sandbox_engine::team::Relation;
+use sandbox_engine::time_control::{LocalTimeScale, TimeControl};
+use sandbox_engine::tint::{CurrentTint, GlobalTint, TimeOfDay, TintGradient};
+use sandbox_engine::wrap::WrapAround;
+use std::any::TypeId;
+use std::time::Duration;
+
+/// Sets the Transform X field in the inspector by typing into it.
+fn set_translation_x(editor: &mut EditorHarness, value: &str) {
+    let field = editor
+        .find_text_right_of("X:", "0")
+        .expect("inspector should show the Transform X field");
+    editor.click_at(field.center());
+    editor.key(egui::Modifiers::CTRL, egui::Key::A);
+    editor.type_text(value);
+    editor.key(egui::Modifiers::NONE, egui::Key::Enter);
+}
+
+#[test]
+fn new_scene_add_entity_edit_save_reload() {
+    let mut editor = EditorHarness::new();
+
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor.click_text("+ Add Entity");
+    let entities = editor.entities_named("Entity 1");
+    assert_eq!(entities.len(), 1, "Add Entity should spawn one entity");
+    let entity = entities[0];
+
+    set_translation_x(&mut editor, "42");
+    let transform = *editor.world().get::<Transform>(entity).unwrap();
+    assert_eq!(transform.translation.x, 42.0);
+    let sprite_size = editor.world().get::<Sprite>(entity).unwrap().custom_size;
+
+    // Save to a known path so Ctrl+S does not open a file dialog
+    let path = editor.project_dir().join("assets/scenes/smoke.scn.ron");
+    editor
+        .world_mut()
+        .resource_mut::<SceneManager>()
+        .current_scene_path = Some(path.clone());
+    editor.key(egui::Modifiers::CTRL, egui::Key::S);
+    assert!(path.exists(), "Ctrl+S should write the scene file");
+    assert!(!editor.world().resource::<SceneManager>().dirty);
+
+    load_scene(editor.world_mut(), &path).expect("saved scene should load");
+    editor.run_frames(2);
+
+    let reloaded = editor.entities_named("Entity 1");
+    assert_eq!(
+        reloaded.len(),
+        1,
+        "reload should restore exactly one entity"
+    );
+    let world = editor.world();
+    assert_eq!(*world.get::<Transform>(reloaded[0]).unwrap(), transform);
+    assert_eq!(
+        world.get::<Sprite>(reloaded[0]).unwrap().custom_size,
+        sprite_size
+    );
+}
+
+#[test]
+fn duplicate_selection() {
+    let mut editor = EditorHarness::new();
+
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor.click_text("+ Add Entity");
+    editor.key(egui::Modifiers::CTRL, egui::Key::D);
+
+    assert_eq!(editor.entities_named("Entity 1").len(), 1);
+    assert_eq!(editor.entities_named("Entity 1 (Copy)").len(), 1);
+}
+
+#[test]
+fn duplicate_and_paste_leave_runtime_children_behind() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let world = editor.world_mut();
+    let sparks = world
+        .spawn((
+            Name::new("Sparks"),
+            Transform::default(),
+            ParticleEmitter::default(),
+        ))
+        .with_children(|parent| {
+            parent.spawn((Name::new("Nozzle"), Transform::default()));
+            parent.spawn((
+                Name::new("Spark"),
+                Particle {
+                    velocity: Vec2::ZERO,
+                    acceleration: Vec2::ZERO,
+                    age: 0.0,
+                    lifetime: 1.0,
+                    color: Color::WHITE,
+                    end_color: Color::WHITE,
+                },
+            ));
+        })
+        .id();
+    world.resource_mut::<EditorSelection>().select(sparks);
+    editor.run_frames(1);
+
+    editor.key(egui::Modifiers::CTRL, egui::Key::D);
+    editor.key(egui::Modifiers::CTRL, egui::Key::C);
+    editor.key(egui::Modifiers::CTRL, egui::Key::V);
+    assert_eq!(editor.entities_named("Nozzle").len(), 3);
+    assert_eq!(editor.entities_named("Spark").len(), 1);
+    for copy in editor
+        .entities_named("Sparks (Copy)")
+        .into_iter()
+        .chain(editor.entities_named("Sparks"))
+    {
+        assert_eq!(
+            editor.world().get::<Children>(copy).map(|c| c.len()),
+            Some(if copy == sparks { 2 } else { 1 })
+        );
+    }
+}
+
+#[test]
+fn create_sprite_from_viewport_menu() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    // The world origin is labeled "0" on both grid axes; click up and to the right of it
+    let origin_x = editor
+        .texts()
+        .iter()
+        .filter(|(text, _)| text == "0")
+        .map(|(_, rect)| rect.left())
+        .fold(f32::MIN, f32::max);
+    let viewport = editor.find_text("Viewport").unwrap();
+    let click = egui::pos2(origin_x + 100.0, viewport.bottom() + 200.0);
+    editor.right_click_at(click);
+    editor.click_text("Create Sprite Here");
+
+    let created = editor.entities_named("Entity 1");
+    assert_eq!(created.len(), 1, "Create Sprite Here should spawn a sprite");
+    let translation = editor
+        .world()
+        .get::<Transform>(created[0])
+        .unwrap()
+        .translation;
+    assert!(
+        translation.x > 0.0,
+        "sprite should be placed right of the origin"
+    );
+}
+
+#[test]
+fn switch_enum_variant_in_reflected_component() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    let entity = editor
+        .world_mut()
+        .spawn((
+            Name::new("Rock"),
+            Transform::default(),
+            Collider::circle(10.0),
+        ))
+        .id();
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(entity);
+    editor.run_frames(2);
+
+    // Collider has no hand-written editor; its shape is a reflected enum dropdown
+    let header = editor.find_text("Collider").unwrap();
+    editor.click_at(header.left_center() - egui::vec2(10.0, 0.0)); // expand arrow
+    editor.click_text("Circle");
+    editor.click_text("Rectangle");
+
+    let shape = editor.world().get::<Collider>(entity).unwrap().shape;
+    assert!(
+        matches!(shape, ColliderShape::Rectangle { .. }),
+        "shape should switch to Rectangle, got {:?}",
+        shape
+    );
+}
+
+#[test]
+fn add_component_from_picker() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor.click_text("+ Add Entity");
+    let entity = editor.entities_named("Entity 1")[0];
+
+    editor.click_text("+ Add Component");
+    editor.type_text("linearvel");
+    editor.run_frames(1);
+    editor.click_text("LinearVelocity");
+
+    assert!(editor.world().get::<LinearVelocity>(entity).is_some());
+    assert!(editor.find_text("+ Add Component").is_some());
+}
+
+#[test]
+fn memory_window_lists_textures() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    editor.click_text("Window");
+    editor.click_text("Memory");
+
+    assert!(editor.find_text("Preview cache").is_some());
+    // Built-in textures (the missing-texture fallback, the viewport target) are listed
+    assert!(editor.find_text("Textures (0)").is_none());
+    editor.click_text("Name");
+    assert!(
+        editor.find_text("Name ⏶").is_some(),
+        "Name sorts A to Z first"
+    );
+}
+
+#[test]
+fn preview_cache_releases_the_least_recently_shown_images() {
+    let mut editor = EditorHarness::new();
+    let world = editor.world_mut();
+    let [a, b, c] = [(); 3].map(|()| world.resource_mut::<Assets<Image>>().add(Image::default()));
+    world
+        .resource_mut::<AssetBrowser>()
+        .preview_handles
+        .set_capacity(2);
+
+    // Showing "a" again makes "b" the oldest when "c" arrives
+    for (key, handle) in [("a.png", &a), ("b.png", &b), ("a.png", &a), ("c.png", &c)] {
+        cache_preview(world, key.to_string(), handle.clone());
+        world
+            .resource_mut::<EguiUserTextures>()
+            .add_image(handle.clone());
+    }
+    assert_eq!(world.resource::<AssetBrowser>().preview_handles.len(), 2);
+    let textures = world.resource::<EguiUserTextures>();
+    assert!(textures.image_id(&b).is_none());
+    assert!(textures.image_id(&a).is_some() && textures.image_id(&c).is_some());
+
+    // The cap is set from the Memory window
+    editor.click_text("Window");
+    editor.click_text("Memory");
+    assert!(editor.find_text("Keep up to").is_some());
+}
+
+#[test]
+fn unused_assets_lists_unreferenced_files() {
+    let mut editor = EditorHarness::new();
+    let assets = editor.project_dir().join("assets");
+    std::fs::create_dir_all(assets.join("textures")).unwrap();
+    std::fs::write(assets.join("textures/used.png"), [0u8; 16]).unwrap();
+    std::fs::write(assets.join("textures/orphan.png"), [0u8; 16]).unwrap();
+    std::fs::write(
+        assets.join("scenes/level.scn.ron"),
+        r#"(entities: { 0: (components: { "sandbox_engine::assets::AssetPath": (path: "textures/used.png") }) })"#,
+    )
+    .unwrap();
+
+    editor.click_text("Window");
+    editor.click_text("Unused Assets");
+
+    assert!(editor.find_text("textures/orphan.png").is_some());
+    assert!(editor.find_text("textures/used.png").is_none());
+    assert!(editor.find_text("scenes/level.scn.ron").is_none());
+    editor.click_text("Select All");
+    assert!(editor.find_text("🗑 Move 1 to Trash (16 B)").is_some());
+}
+
+#[test]
+fn problems_lists_broken_asset_references() {
+    let mut editor = EditorHarness::new();
+    let assets = editor.project_dir().join("assets");
+    std::fs::create_dir_all(assets.join("textures")).unwrap();
+    std::fs::write(assets.join("textures/ship.png"), [0u8; 16]).unwrap();
+    std::fs::write(
+        assets.join("scenes/level.scn.ron"),
+        r#"(resources: {}, entities: { 4294967296: (components: {
+            "bevy_core::name::Name": "Rock",
+            "sandbox_engine::assets::AssetPath": (path: "textures/rock.png"),
+        }) })"#,
+    )
+    .unwrap();
+    let broken = editor
+        .world_mut()
+        .spawn((
+            Name::new("Typo"),
+            AssetPath::new("textures/shp.png"),
+            Transform::from_xyz(300.0, 200.0, 0.0),
+        ))
+        .id();
+    editor
+        .world_mut()
+        .spawn((AssetPath::new("textures/ship.png"), Transform::default()));
+    editor.run_frames(1);
+
+    editor.click_text("Window");
+    editor.click_text("Problems");
+
+    assert!(editor.find_text("2 problems").is_some());
+    assert!(editor
+        .find_text("Missing asset 'textures/shp.png'")
+        .is_some());
+    assert!(editor
+        .find_text("Missing asset 'textures/rock.png'")
+        .is_some());
+    assert!(editor.find_text("scenes/level.scn.ron › Rock").is_some());
+    assert!(editor
+        .find_text("Missing asset 'textures/ship.png'")
+        .is_none());
+
+    editor.click_text("Select");
+    assert_eq!(
+        editor.world().resource::<EditorSelection>().selected_entity,
+        Some(broken)
+    );
+}
+
+#[test]
+fn stopping_play_offers_to_keep_or_discard_runtime_changes() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let ship = editor
+        .world_mut()
+        .spawn((Name::new("Ship"), Transform::default()))
+        .id();
+    editor.run_frames(1);
+
+    // Pausing and resuming keeps the snapshot from when play started
+    let play_and_change = |editor: &mut EditorHarness| {
+        editor.click_text("▶ Play");
+        editor.click_text("⏸ Pause");
+        editor.click_text("▶ Resume");
+        let world = editor.world_mut();
+        world.get_mut::<Transform>(ship).unwrap().translation.x = 50.0;
+        world.spawn((Name::new("Bullet"), Transform::default()));
+        editor.click_text("⏹ Stop");
+        // New windows take a frame to size themselves
+        editor.run_frames(1);
+        assert!(editor.find_text("• 1 entity moved").is_some());
+        assert!(editor.find_text("• 1 entity spawned").is_some());
+    };
+
+    play_and_change(&mut editor);
+    editor.click_text("Discard");
+    assert_eq!(
+        *editor.world().resource::<State<EditorPlayState>>().get(),
+        EditorPlayState::Stopped
+    );
+    assert_eq!(
+        editor.world().get::<Transform>(ship).unwrap().translation.x,
+        0.0
+    );
+    assert!(editor.entities_named("Bullet").is_empty());
+
+    play_and_change(&mut editor);
+    editor.click_text("Keep Runtime Changes");
+    assert_eq!(
+        *editor.world().resource::<State<EditorPlayState>>().get(),
+        EditorPlayState::Stopped
+    );
+    assert_eq!(
+        editor.world().get::<Transform>(ship).unwrap().translation.x,
+        50.0
+    );
+    assert_eq!(editor.entities_named("Bullet").len(), 1);
+    assert!(editor.world().resource::<SceneManager>().dirty);
+
+    // Without runtime changes Stop needs no confirmation
+    editor.click_text("▶ Play");
+    editor.click_text("⏹ Stop");
+    assert!(editor.find_text("Discard").is_none());
+    assert_eq!(
+        *editor.world().resource::<State<EditorPlayState>>().get(),
+        EditorPlayState::Stopped
+    );
+}
+
+#[test]
+fn dropping_an_image_in_the_viewport_spawns_a_sprite() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let assets = editor.project_dir().join("assets");
+    std::fs::write(assets.join("crate.png"), [0u8; 16]).unwrap();
+    editor.click_text("⟳ Refresh");
+    editor.wait_for_asset_scan();
+
+    // A selected reference sprite at the origin locates the world origin on screen
+    let world = editor.world_mut();
+    let reference = world
+        .spawn((
+            Sprite::from_color(Color::WHITE, Vec2::splat(40.0)),
+            Transform::default(),
+        ))
+        .id();
+    world.resource_mut::<EditorSelection>().select(reference);
+    editor.run_frames(2);
+    let label = editor.find_text("40 × 40").expect("selection size label");
+    let origin = egui::pos2(label.center().x - 20.0, label.bottom() + 4.0 + 20.0);
+
+    let image = editor.find_text("🖼 crate.png").expect("image in browser");
+    editor.drag(
+        image.center(),
+        origin + egui::vec2(100.0, -50.0),
+        egui::Modifiers::NONE,
+    );
+
+    let spawned = editor.entities_named("crate");
+    assert_eq!(spawned.len(), 1);
+    let entity = spawned[0];
+    assert_eq!(
+        editor.world().get::<AssetPath>(entity).unwrap().path,
+        "crate.png"
+    );
+    assert!(editor.world().get::<Sprite>(entity).is_some());
+    let translation = editor.world().get::<Transform>(entity).unwrap().translation;
+    assert!((translation - Vec3::new(100.0, 50.0, 0.0)).length() < 0.5);
+    assert_eq!(
+        editor.world().resource::<EditorSelection>().selected_entity,
+        Some(entity)
+    );
+}
+
+#[test]
+fn viewport_shows_play_state_badge() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    assert!(editor.find_text("▶ PLAYING").is_none());
+
+    editor.click_text("▶ Play");
+    assert!(editor.find_text("▶ PLAYING").is_some());
+
+    editor.click_text("⏸ Pause");
+    assert!(editor.find_text("⏸ PAUSED").is_some());
+    assert!(editor.find_text("▶ PLAYING").is_none());
+
+    editor.click_text("⏹ Stop");
+    assert!(editor.find_text("⏸ PAUSED").is_none());
+}
+
+#[test]
+fn pausing_freezes_sprite_animations_while_the_editor_keeps_running() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor
+        .world_mut()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            50,
+        )));
+    let frame = |rect| AnimationFrame {
+        rect,
+        duration: 1.0,
+    };
+    let beacon = editor
+        .world_mut()
+        .spawn((
+            Name::new("Beacon"),
+            Sprite::default(),
+            Transform::default(),
+            SpriteAnimation {
+                playing: true,
+                ..SpriteAnimation::new(vec![frame(Rect::new(0.0, 0.0, 8.0, 8.0)); 2])
+            },
+        ))
+        .id();
+    let timer =
+        |editor: &EditorHarness| editor.world().get::<SpriteAnimation>(beacon).unwrap().timer;
+
+    editor.click_text("▶ Play");
+    editor.click_text("⏸ Pause");
+    assert!(editor.world().resource::<TimeControl>().is_paused());
+    let paused_at = timer(&editor);
+    assert!(paused_at > 0.0);
+    editor.run_frames(5);
+    assert_eq!(timer(&editor), paused_at);
+
+    editor.click_text("▶ Resume");
+    editor.run_frames(5);
+    assert!(!editor.world().resource::<TimeControl>().is_paused());
+    assert!(timer(&editor) > paused_at);
+}
+
+#[test]
+fn dragging_an_image_onto_asset_path_sets_it() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let assets = editor.project_dir().join("assets");
+    std::fs::write(assets.join("ship.png"), [0u8; 16]).unwrap();
+    std::fs::write(assets.join("engine.ogg"), [0u8; 16]).unwrap();
+    editor.click_text("⟳ Refresh");
+    editor.wait_for_asset_scan();
+
+    let entity = editor
+        .world_mut()
+        .spawn((Name::new("Ship"), Sprite::default(), AssetPath::default()))
+        .id();
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(entity);
+    editor.run_frames(2);
+
+    // The path field sits just below its "Path:" label
+    let label = editor.find_text("Path:").expect("AssetPath field label");
+    let field = label.left_bottom() + egui::vec2(40.0, 12.0);
+
+    // Audio is rejected by the image field
+    let audio = editor.find_text("🔊 engine.ogg").expect("audio in browser");
+    editor.drag(audio.center(), field, egui::Modifiers::NONE);
+    assert_eq!(editor.world().get::<AssetPath>(entity).unwrap().path, "");
+
+    let image = editor.find_text("🖼 ship.png").expect("image in browser");
+    editor.drag(image.center(), field, egui::Modifiers::NONE);
+    assert_eq!(
+        editor.world().get::<AssetPath>(entity).unwrap().path,
+        "ship.png"
+    );
+
+    editor.key(egui::Modifiers::CTRL, egui::Key::Z);
+    assert_eq!(editor.world().get::<AssetPath>(entity).unwrap().path, "");
+}
+
+#[test]
+fn pinned_fields_keep_play_mode_edits_after_stop() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    let world = editor.world_mut();
+    let tuned = world
+        .spawn((
+            Name::new("Tuned"),
+            Transform::default(),
+            LocalTimeScale(0.5),
+        ))
+        .id();
+    let other = world
+        .spawn((
+            Name::new("Other"),
+            Transform::default(),
+            LocalTimeScale(0.5),
+        ))
+        .id();
+    world.resource_mut::<EditorSelection>().select(tuned);
+    editor.run_frames(2);
+    let header = editor.find_text("LocalTimeScale").unwrap();
+    editor.click_at(header.left_center() - egui::vec2(10.0, 0.0)); // expand arrow
+
+    editor.click_text("▶ Play");
+    editor.click_text("📌");
+    let field = editor
+        .find_text_right_of("0:", "0.50")
+        .expect("inspector should show the LocalTimeScale field");
+    editor.click_at(field.center());
+    editor.key(egui::Modifiers::CTRL, egui::Key::A);
+    editor.type_text("2");
+    editor.key(egui::Modifiers::NONE, egui::Key::Enter);
+
+    // Unpinned values changed during play are restored on Stop
+    editor
+        .world_mut()
+        .get_mut::<LocalTimeScale>(other)
+        .unwrap()
+        .0 = 3.0;
+    editor
+        .world_mut()
+        .resource_mut::<SceneManager>()
+        .mark_clean();
+
+    editor.click_text("⏹ Stop");
+    assert_eq!(editor.world().get::<LocalTimeScale>(tuned).unwrap().0, 2.0);
+    assert_eq!(editor.world().get::<LocalTimeScale>(other).unwrap().0, 0.5);
+    assert!(editor.world().resource::<SceneManager>().dirty);
+}
+
+#[test]
+fn inspector_options_clamp_reflected_fields() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    let entity = editor
+        .world_mut()
+        .spawn((Name::new("Slow"), Transform::default(), LocalTimeScale(0.5)))
+        .id();
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(entity);
+    editor.run_frames(2);
+
+    let header = editor.find_text("LocalTimeScale").unwrap();
+    editor.click_at(header.left_center() - egui::vec2(10.0, 0.0)); // expand arrow
+    let field = editor
+        .find_text_right_of("0:", "0.50")
+        .expect("inspector should show the LocalTimeScale field");
+    editor.click_at(field.center());
+    editor.key(egui::Modifiers::CTRL, egui::Key::A);
+    editor.type_text("25");
+    editor.key(egui::Modifiers::NONE, egui::Key::Enter);
+
+    // `LocalTimeScale` declares a 0..=10 range
+    assert_eq!(
+        editor.world().get::<LocalTimeScale>(entity).unwrap().0,
+        10.0
+    );
+}
+
+/// Game-side component edited by a custom inspector.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Fuel(f32);
+
+#[test]
+fn custom_inspector_replaces_reflected_fields() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    let world = editor.world_mut();
+    world
+        .resource::<AppTypeRegistry>()
+        .write()
+        .register::<Fuel>();
+    world
+        .resource_mut::<CustomInspectors>()
+        .register::<Fuel>(|ui, world, entity| {
+            if ui.button("Refuel").clicked() {
+                world.get_mut::<Fuel>(entity).unwrap().0 = 100.0;
+            }
+        });
+    let entity = world.spawn((Name::new("Tanker"), Fuel(5.0))).id();
+    world.resource_mut::<EditorSelection>().select(entity);
+    editor.run_frames(2);
+
+    let header = editor.find_text("Fuel").unwrap();
+    editor.click_at(header.left_center() - egui::vec2(10.0, 0.0)); // expand arrow
+    editor.click_text("Refuel");
+    assert_eq!(editor.world().get::<Fuel>(entity).unwrap().0, 100.0);
+    assert!(editor.world().resource::<SceneManager>().dirty);
+
+    // The edit was recorded for undo
+    editor.key(egui::Modifiers::CTRL, egui::Key::Z);
+    assert_eq!(editor.world().get::<Fuel>(entity).unwrap().0, 5.0);
+}
+
+#[test]
+fn rect_gizmo_resizes_sprite_from_its_edges() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    let entity = editor
+        .world_mut()
+        .spawn((
+            Name::new("Panel"),
+            Sprite::from_color(Color::WHITE, Vec2::new(100.0, 50.0)),
+            Transform::default(),
+        ))
+        .id();
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(entity);
+    editor.run_frames(2);
+    editor.key(egui::Modifiers::NONE, egui::Key::T);
+
+    // The size label sits just above the sprite's top-right corner
+    let label = editor.find_text("100 × 50").expect("selection size label");
+    let top = egui::pos2(label.center().x - 50.0, label.bottom() + 4.0);
+    editor.drag(top, top - egui::vec2(0.0, 30.0), egui::Modifiers::NONE);
+
+    // The bottom edge stays put while the top follows the pointer
+    let sprite = editor.world().get::<Sprite>(entity).unwrap();
+    assert_eq!(sprite.custom_size, Some(Vec2::new(100.0, 80.0)));
+    let translation = editor.world().get::<Transform>(entity).unwrap().translation;
+    assert!((translation.y - 15.0).abs() < 0.01, "{}", translation);
+
+    // Shift keeps the aspect ratio when dragging a corner
+    let label = editor.find_text("100 × 80").expect("selection size label");
+    let top_right = egui::pos2(label.center().x, label.bottom() + 4.0);
+    editor.drag(
+        top_right,
+        top_right + egui::vec2(50.0, 0.0),
+        egui::Modifiers::SHIFT,
+    );
+
+    let size = editor
+        .world()
+        .get::<Sprite>(entity)
+        .unwrap()
+        .custom_size
+        .unwrap();
+    assert!((size - Vec2::new(150.0, 120.0)).length() < 0.01, "{}", size);
+}
+
+#[test]
+fn pivot_gizmo_moves_anchor_without_moving_sprite() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    let entity = editor
+        .world_mut()
+        .spawn((
+            Name::new("Door"),
+            Sprite::from_color(Color::WHITE, Vec2::new(100.0, 50.0)),
+            Transform::default(),
+        ))
+        .id();
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(entity);
+    editor.run_frames(2);
+    editor.key(egui::Modifiers::NONE, egui::Key::T);
+
+    // The size label sits just above the sprite's top-right corner
+    let label = editor.find_text("100 × 50").expect("selection size label");
+    let top_right = egui::pos2(label.center().x, label.bottom() + 4.0);
+    let pivot = top_right + egui::vec2(-50.0, 25.0);
+    let bottom_left = top_right + egui::vec2(-100.0, 50.0);
+
+    // Dropped near a corner, the pivot snaps to it
+    editor.drag(
+        pivot,
+        bottom_left + egui::vec2(3.0, -2.0),
+        egui::Modifiers::NONE,
+    );
+    assert_eq!(
+        editor.world().get::<Sprite>(entity).unwrap().anchor,
+        Anchor::BottomLeft
+    );
+    let translation = editor.world().get::<Transform>(entity).unwrap().translation;
+    assert_eq!(translation.truncate(), Vec2::new(-50.0, -25.0));
+
+    // Elsewhere it becomes a custom anchor
+    editor.drag(
+        bottom_left,
+        bottom_left + egui::vec2(25.0, 0.0),
+        egui::Modifiers::NONE,
+    );
+    let Anchor::Custom(anchor) = editor.world().get::<Sprite>(entity).unwrap().anchor else {
+        panic!("pivot off the standard positions should be a custom anchor");
+    };
+    assert!(
+        (anchor - Vec2::new(-0.25, -0.5)).length() < 0.01,
+        "{}",
+        anchor
+    );
+    let translation = editor.world().get::<Transform>(entity).unwrap().translation;
+    assert!((translation.truncate() - Vec2::new(-25.0, -25.0)).length() < 0.01);
+}
+
+#[test]
+fn group_gizmo_rotates_selection_about_pivot() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    let world = editor.world_mut();
+    let left = world
+        .spawn((
+            Sprite::from_color(Color::WHITE, Vec2::splat(40.0)),
+            Transform::from_xyz(-100.0, 0.0, 0.0),
+        ))
+        .id();
+    let right = world
+        .spawn((
+            Sprite::from_color(Color::WHITE, Vec2::splat(40.0)),
+            Transform::from_xyz(100.0, 0.0, 0.0),
+        ))
+        .id();
+    world.resource_mut::<EditorSelection>().set([left, right]);
+    editor.run_frames(2);
+    editor.key(egui::Modifiers::NONE, egui::Key::E);
+
+    // The primary's size label sits above its top-right corner at (120, 20)
+    let label = editor.find_text("40 × 40").expect("selection size label");
+    let origin = egui::pos2(label.center().x - 120.0, label.bottom() + 4.0 + 20.0);
+    let quarter_turn = |editor: &mut EditorHarness| {
+        editor.drag(
+            origin + egui::vec2(60.0, 0.0),
+            origin + egui::vec2(0.0, -60.0),
+            egui::Modifiers::NONE,
+        );
+    };
+
+    // Selection center: the pair swings around the origin
+    quarter_turn(&mut editor);
+    let transform = *editor.world().get::<Transform>(left).unwrap();
+    assert!((transform.translation - Vec3::new(0.0, -100.0, 0.0)).length() < 0.01);
+    assert!((transform.rotation.to_euler(EulerRot::XYZ).2.to_degrees() - 90.0).abs() < 0.01);
+
+    // Individual origins: each turns in place
+    *editor.world_mut().resource_mut::<GizmoPivot>() = GizmoPivot::IndividualOrigins;
+    editor.run_frames(2);
+    quarter_turn(&mut editor);
+    let transform = *editor.world().get::<Transform>(right).unwrap();
+    assert!((transform.translation - Vec3::new(0.0, 100.0, 0.0)).length() < 0.01);
+    assert!(
+        transform
+            .rotation
+            .angle_between(Quat::from_rotation_z(std::f32::consts::PI))
+            < 0.001
+    );
+}
+
+#[test]
+fn modal_transform_applies_typed_values_along_an_axis() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    let world = editor.world_mut();
+    let entity = world
+        .spawn((
+            Sprite::from_color(Color::WHITE, Vec2::splat(40.0)),
+            Transform::default(),
+        ))
+        .id();
+    world.resource_mut::<EditorSelection>().select(entity);
+    editor.run_frames(2);
+
+    // Park the pointer over empty viewport space so G/R/S start a modal transform
+    let label = editor.find_text("40 × 40").expect("selection size label");
+    let origin = egui::pos2(label.center().x - 20.0, label.bottom() + 4.0 + 20.0);
+    editor.hover(origin + egui::vec2(150.0, 100.0));
+
+    // G, X, 25, Enter moves exactly 25 units along X
+    editor.key(egui::Modifiers::NONE, egui::Key::G);
+    editor.key(egui::Modifiers::NONE, egui::Key::X);
+    editor.type_text("25");
+    editor.key(egui::Modifiers::NONE, egui::Key::Enter);
+    let transform = *editor.world().get::<Transform>(entity).unwrap();
+    assert_eq!(transform.translation, Vec3::new(25.0, 0.0, 0.0));
+    assert!(!editor.world().resource::<ModalTransformState>().is_active());
+
+    // One undo step restores the start
+    editor.key(egui::Modifiers::CTRL, egui::Key::Z);
+    let transform = *editor.world().get::<Transform>(entity).unwrap();
+    assert_eq!(transform.translation, Vec3::ZERO);
+
+    // Escape cancels a rotation in progress
+    editor.key(egui::Modifiers::NONE, egui::Key::R);
+    editor.type_text("90");
+    assert!(editor.world().get::<Transform>(entity).unwrap().rotation != Quat::IDENTITY);
+    editor.key(egui::Modifiers::NONE, egui::Key::Escape);
+    let transform = *editor.world().get::<Transform>(entity).unwrap();
+    assert_eq!(transform.rotation, Quat::IDENTITY);
+    assert_eq!(
+        editor.world().resource::<EditorSelection>().selected_entity,
+        Some(entity)
+    );
+
+    // S, Y, 2 scales only vertically
+    editor.key(egui::Modifiers::NONE, egui::Key::S);
+    editor.key(egui::Modifiers::NONE, egui::Key::Y);
+    editor.type_text("2");
+    editor.key(egui::Modifiers::NONE, egui::Key::Enter);
+    let transform = *editor.world().get::<Transform>(entity).unwrap();
+    assert!((transform.scale - Vec3::new(1.0, 2.0, 1.0)).length() < 0.001);
+    assert_eq!(
+        *editor.world().resource::<GizmoMode>(),
+        GizmoMode::Translate
+    );
+}
+
+#[test]
+fn modal_grab_along_an_axis_snaps_only_that_axis() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor.world_mut().insert_resource(SnapSettings {
+        enabled: true,
+        grid_size: 10.0,
+        ..default()
+    });
+
+    // Start off the grid on both axes
+    let world = editor.world_mut();
+    let entity = world
+        .spawn((
+            Sprite::from_color(Color::WHITE, Vec2::splat(40.0)),
+            Transform::from_xyz(3.0, 7.0, 0.0),
+        ))
+        .id();
+    world.resource_mut::<EditorSelection>().select(entity);
+    editor.run_frames(2);
+
+    let label = editor.find_text("40 × 40").expect("selection size label");
+    let origin = egui::pos2(label.center().x - 20.0, label.bottom() + 4.0 + 20.0);
+    editor.hover(origin + egui::vec2(150.0, 100.0));
+
+    editor.key(egui::Modifiers::NONE, egui::Key::G);
+    editor.key(egui::Modifiers::NONE, egui::Key::X);
+    editor.hover(origin + egui::vec2(217.0, 160.0));
+    editor.key(egui::Modifiers::NONE, egui::Key::Enter);
+
+    let translation = editor.world().get::<Transform>(entity).unwrap().translation;
+    assert!(translation.x != 3.0, "{:?}", translation);
+    assert_eq!(translation.x % 10.0, 0.0, "{:?}", translation);
+    assert_eq!(translation.y, 7.0);
+}
+
+#[test]
+fn transform_copy_paste_and_reset_in_inspector() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    let world = editor.world_mut();
+    let source_transform = Transform::from_xyz(10.0, 20.0, 1.0)
+        .with_rotation(Quat::from_rotation_z(0.5))
+        .with_scale(Vec3::new(2.0, 3.0, 1.0));
+    let source = world.spawn((Name::new("Source"), source_transform)).id();
+    let target = world
+        .spawn((Name::new("Target"), Transform::default()))
+        .id();
+    world.resource_mut::<EditorSelection>().select(source);
+    editor.run_frames(2);
+
+    editor.click_text("📋 Copy");
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(target);
+    editor.run_frames(2);
+    editor.click_text("📥 Paste");
+    assert_eq!(
+        *editor.world().get::<Transform>(target).unwrap(),
+        source_transform
+    );
+
+    // The first reset button belongs to Position
+    editor.click_text("⟲");
+    let transform = *editor.world().get::<Transform>(target).unwrap();
+    assert_eq!(transform.translation, Vec3::ZERO);
+    assert_eq!(transform.scale, source_transform.scale);
+
+    editor.key(egui::Modifiers::CTRL, egui::Key::Z);
+    assert_eq!(
+        *editor.world().get::<Transform>(target).unwrap(),
+        source_transform
+    );
+}
+
+#[test]
+fn sprite_inspector_edits_size_flip_and_anchor() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    let entity = editor
+        .world_mut()
+        .spawn((Name::new("Crate"), Sprite::default(), Transform::default()))
+        .id();
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(entity);
+    editor.run_frames(2);
+
+    editor.click_text("Set");
+    assert!(editor
+        .world()
+        .get::<Sprite>(entity)
+        .unwrap()
+        .custom_size
+        .is_some());
+
+    editor.click_text("X");
+    assert!(editor.world().get::<Sprite>(entity).unwrap().flip_x);
+
+    editor.click_text("Center");
+    editor.click_text("Bottom Left");
+    assert_eq!(
+        editor.world().get::<Sprite>(entity).unwrap().anchor,
+        Anchor::BottomLeft
+    );
+
+    editor.click_text("✕");
+    assert_eq!(
+        editor.world().get::<Sprite>(entity).unwrap().custom_size,
+        None
+    );
+}
+
+#[test]
+fn asset_search_flattens_the_tree_into_matches() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let assets = editor.project_dir().join("assets");
+    std::fs::create_dir_all(assets.join("ships")).unwrap();
+    std::fs::write(assets.join("ships/hull.png"), [0u8; 16]).unwrap();
+    std::fs::write(assets.join("ships/hull.ogg"), [0u8; 16]).unwrap();
+    std::fs::write(assets.join("rock.png"), [0u8; 16]).unwrap();
+    editor.click_text("⟳ Refresh");
+    editor.wait_for_asset_scan();
+
+    // Nested files are hidden in the collapsed tree
+    assert!(editor.find_text("🖼 hull.png").is_none());
+
+    editor.click_text("🔍 Search assets");
+    editor.type_text("HULL");
+    editor.run_frames(1);
+    assert!(editor.find_text("🖼 ships/hull.png").is_some());
+    assert!(editor.find_text("🔊 ships/hull.ogg").is_some());
+    assert!(editor.find_text("🖼 rock.png").is_none());
+
+    // Type filters narrow the matches further
+    editor.click_text("Audio");
+    assert!(editor.find_text("🖼 ships/hull.png").is_none());
+    assert!(editor.find_text("🔊 ships/hull.ogg").is_some());
+
+    // Matches can be selected like tree entries
+    editor.click_text("🔊 ships/hull.ogg");
+    assert_eq!(
+        editor
+            .world()
+            .resource::<AssetBrowser>()
+            .selected_path
+            .as_deref(),
+        Some("ships/hull.ogg")
+    );
+
+    // Clearing the query and type filter restores the tree
+    editor.click_text("✖");
+    editor.click_text("All");
+    assert!(editor.find_text("🖼 rock.png").is_some());
+    assert!(editor.find_text("🔊 ships/hull.ogg").is_none());
+}
+
+#[test]
+fn asset_browser_renames_deletes_and_creates_folders() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let assets = editor.project_dir().join("assets");
+    std::fs::write(assets.join("rock.png"), [0u8; 16]).unwrap();
+    editor.click_text("⟳ Refresh");
+    editor.wait_for_asset_scan();
+
+    editor.click_text("📁 New Folder");
+    editor.run_frames(1);
+    editor.type_text("sprites");
+    editor.click_text("Create");
+    assert!(assets.join("sprites").is_dir());
+    assert!(editor.find_text("📁 sprites").is_some());
+
+    // Rename pre-fills the current name
+    let rock = editor.find_text("🖼 rock.png").expect("file in browser");
+    editor.right_click_at(rock.center());
+    editor.click_text("Rename...");
+    editor.run_frames(1);
+    editor.key(egui::Modifiers::COMMAND, egui::Key::A);
+    editor.type_text("boulder.png");
+    editor.key(egui::Modifiers::NONE, egui::Key::Enter);
+    assert!(!assets.join("rock.png").exists());
+    assert!(assets.join("boulder.png").exists());
+    assert!(editor.find_text("🖼 boulder.png").is_some());
+
+    // Deleting asks first
+    let boulder = editor.find_text("🖼 boulder.png").unwrap();
+    editor.right_click_at(boulder.center());
+    editor.click_text("Delete...");
+    editor.run_frames(1);
+    assert!(editor.find_text("Delete 'boulder.png'?").is_some());
+    assert!(assets.join("boulder.png").exists());
+    editor.click_text("🗑 Delete");
+    assert!(!assets.join("boulder.png").exists());
+    assert!(editor.find_text("🖼 boulder.png").is_none());
+}
+
+#[test]
+fn find_usages_lists_entities_and_scene_files_referencing_an_asset() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let assets = editor.project_dir().join("assets");
+    std::fs::write(assets.join("rock.png"), [0u8; 16]).unwrap();
+    std::fs::write(
+        assets.join("scenes/level.scn.ron"),
+        r#"(resources: {}, entities: { 4294967296: (components: {
+            "bevy_core::name::Name": "Boulder",
+            "bevy_transform::components::transform::Transform": (
+                translation: (200.0, 0.0, 0.0),
+                rotation: (0.0, 0.0, 0.0, 1.0),
+                scale: (1.0, 1.0, 1.0),
+            ),
+            "sandbox_engine::assets::AssetPath": (path: "rock.png"),
+        }) })"#,
+    )
+    .unwrap();
+    let world = editor.world_mut();
+    let rock = world
+        .spawn((
+            Name::new("Rock"),
+            AssetPath::new("rock.png"),
+            Transform::default(),
+        ))
+        .id();
+    world.spawn((
+        Name::new("Ship"),
+        AssetPath::new("ship.png"),
+        Transform::default(),
+    ));
+    editor.click_text("⟳ Refresh");
+    editor.wait_for_asset_scan();
+
+    let file = editor.find_text("🖼 rock.png").expect("file in browser");
+    editor.right_click_at(file.center());
+    editor.click_text("Find Usages");
+    editor.run_frames(1);
+    assert!(editor.find_text("References to rock.png: 2").is_some());
+    assert!(editor.find_text("scenes/level.scn.ron › Boulder").is_some());
+    assert!(editor.find_text("ship.png").is_none());
+
+    editor.click_text("Select");
+    assert_eq!(
+        editor.world().resource::<EditorSelection>().selected_entity,
+        Some(rock)
+    );
+
+    // Opening the scene file selects the referencing entity in it
+    editor.click_text("Open");
+    let boulder = editor.entities_named("Boulder");
+    assert_eq!(boulder.len(), 1);
+    assert_eq!(
+        editor.world().resource::<EditorSelection>().selected_entity,
+        Some(boulder[0])
+    );
+    assert!(editor.find_text("References to rock.png: 1").is_some());
+}
+
+#[test]
+fn renaming_an_asset_folder_updates_references_to_it() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let assets = editor.project_dir().join("assets");
+    std::fs::create_dir_all(assets.join("textures")).unwrap();
+    std::fs::write(assets.join("textures/rock.png"), [0u8; 16]).unwrap();
+    std::fs::write(
+        assets.join("scenes/level.scn.ron"),
+        r#"(resources: {}, entities: { 4294967296: (components: {
+            "bevy_core::name::Name": "Boulder",
+            "sandbox_engine::assets::AssetPath": (path: "textures/rock.png"),
+        }) })"#,
+    )
+    .unwrap();
+    let rock = editor
+        .world_mut()
+        .spawn((
+            Name::new("Rock"),
+            AssetPath::new("textures/rock.png#frame"),
+            Transform::default(),
+        ))
+        .id();
+    editor.click_text("⟳ Refresh");
+    editor.wait_for_asset_scan();
+
+    let folder = editor.find_text("📁 textures").expect("folder in browser");
+    editor.right_click_at(folder.center());
+    editor.click_text("Rename...");
+    editor.run_frames(1);
+    assert!(editor.find_text("Update 2 references").is_some());
+    editor.key(egui::Modifiers::COMMAND, egui::Key::A);
+    editor.type_text("sprites");
+    editor.key(egui::Modifiers::NONE, egui::Key::Enter);
+    assert!(assets.join("sprites/rock.png").exists());
+
+    // The label survives, and the rewrite is one undo step
+    assert_eq!(
+        editor.world().get::<AssetPath>(rock).unwrap().path,
+        "sprites/rock.png#frame"
+    );
+    let scene = std::fs::read_to_string(assets.join("scenes/level.scn.ron")).unwrap();
+    assert!(scene.contains(r#"(path: "sprites/rock.png")"#));
+    assert!(scene.contains(r#""bevy_core::name::Name": "Boulder""#));
+    editor.key(egui::Modifiers::CTRL, egui::Key::Z);
+    assert_eq!(
+        editor.world().get::<AssetPath>(rock).unwrap().path,
+        "textures/rock.png#frame"
+    );
+}
+
+#[test]
+fn teams_window_edits_and_saves_team_relations() {
+    let mut editor = EditorHarness::new();
+    editor.click_text("Window");
+    editor.click_text("Teams");
+    assert!(editor.find_text("No teams yet.").is_some());
+    editor.click_text("+ Add Team");
+    editor.click_text("+ Add Team");
+    assert!(editor.find_text("Relations").is_some());
+
+    editor.click_text("Neutral");
+    editor.click_text("Hostile");
+    let settings = editor.world().resource::<ProjectSettings>();
+    assert_eq!(settings.teams.names, vec!["Team 0", "Team 1"]);
+    assert_eq!(settings.teams.relation(1, 0), Relation::Hostile);
+    assert!(editor.find_text("Unsaved changes").is_some());
+
+    editor.click_text("💾 Save");
+    assert!(editor.find_text("Unsaved changes").is_none());
+    let saved = ProjectSettings::load(&ProjectRoot::new(editor.project_dir()));
+    assert_eq!(saved.teams.relation(0, 1), Relation::Hostile);
+}
+
+#[test]
+fn color_grades_window_grades_scene_groups_live_and_saves_them() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor.world_mut().spawn((
+        Sprite::default(),
+        Transform::default(),
+        ColorGroup::new("hazards"),
+    ));
+    editor.click_text("Window");
+    editor.click_text("Color Grades");
+    assert!(editor.find_text("No color grades yet.").is_some());
+
+    // Groups used in the scene can be graded in one click
+    editor.click_text("+ hazards");
+    assert!(editor.find_text("No color grades yet.").is_none());
+    let brightness = editor.find_text("× 1.00").unwrap().center();
+    editor.drag(
+        brightness,
+        brightness + egui::vec2(50.0, 0.0),
+        egui::Modifiers::NONE,
+    );
+    let grade = *editor
+        .world()
+        .resource::<ProjectSettings>()
+        .color_grades
+        .grade("hazards")
+        .unwrap();
+    assert!(grade.brightness > 1.0, "{}", grade.brightness);
+    assert!(editor.find_text("Unsaved changes").is_some());
+
+    editor.click_text("💾 Save");
+    assert!(editor.find_text("Unsaved changes").is_none());
+    let saved = ProjectSettings::load(&ProjectRoot::new(editor.project_dir()));
+    assert_eq!(saved.color_grades.grade("hazards"), Some(&grade));
+}
+
+#[test]
+fn systems_window_switches_gameplay_systems_off_and_back_on() {
+    let mut editor = EditorHarness::new();
+    editor.click_text("Window");
+    editor.click_text("Systems");
+    assert!(editor.find_text("GameplaySystemSet").is_some());
+
+    editor.click_text("start_ambient_sounds");
+    let name = "sandbox_engine::ambient::start_ambient_sounds";
+    assert!(!editor.world().resource::<SystemToggles>().is_enabled(name));
+    assert!(editor.find_text("1 system(s) switched off").is_some());
+
+    editor.click_text("Enable All");
+    assert!(editor.world().resource::<SystemToggles>().is_enabled(name));
+    assert!(editor.find_text("1 system(s) switched off").is_none());
+}
+
+#[test]
+fn watch_window_shows_and_graphs_a_field_during_play() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor
+        .world_mut()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            50,
+        )));
+    let probe = editor
+        .world_mut()
+        .spawn((
+            Name::new("Probe"),
+            Transform::default(),
+            LinearVelocity(Vec2::new(100.0, 0.0)),
+        ))
+        .id();
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(probe);
+    editor.click_text("Window");
+    editor.click_text("Watch");
+    assert!(editor.find_text("Nothing watched yet.").is_some());
+
+    // Popup entries are painted last, above the inspector's "Transform"
+    let click_last = |editor: &mut EditorHarness, text: &str| {
+        let (_, rect) = editor
+            .texts()
+            .iter()
+            .rev()
+            .find(|(painted, _)| painted == text)
+            .cloned()
+            .unwrap();
+        editor.click_at(rect.center());
+    };
+    editor.click_text("Component or resource");
+    click_last(&mut editor, "Transform");
+    editor.click_text("(whole value)");
+    click_last(&mut editor, "translation.x");
+    editor.click_text("+ Watch");
+    let label = format!("Probe ({:?}) › Transform.translation.x", probe);
+    assert!(editor.find_text(&label).is_some());
+    assert!(editor.find_text("0.000").is_some());
+
+    editor.click_text("▶ Play");
+    editor.run_frames(10);
+    let watches = &editor.world().resource::<WatchWindowState>().watches;
+    assert_eq!(watches.len(), 1);
+    let samples = &watches[0].samples;
+    assert!(samples.len() >= 10, "{}", samples.len());
+    assert!(samples.back() > samples.front(), "{:?}", samples);
+    assert_ne!(watches[0].value.as_deref(), Some("0.000"));
+}
+
+#[test]
+fn image_sprites_are_named_after_the_file_stem() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let world = editor.world_mut();
+    let ship = add_image_sprite(world, "textures/ship.v2.png", Vec2::ZERO);
+    assert_eq!(world.get::<Name>(ship).unwrap().as_str(), "ship.v2");
+    assert_eq!(
+        world.get::<AssetPath>(ship).unwrap().path,
+        "textures/ship.v2.png"
+    );
+}
+
+#[test]
+fn scene_search_finds_field_values_and_reveals_them() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let world = editor.world_mut();
+    world.spawn((Name::new("Rock"), Transform::default()));
+    let hatch = world
+        .spawn((
+            Name::new("Cargo Bay"),
+            Transform::default(),
+            Interactable {
+                prompt: "Open Hatch".to_string(),
+                ..default()
+            },
+        ))
+        .id();
+    editor.run_frames(1);
+
+    editor.key(egui::Modifiers::CTRL, egui::Key::F);
+    editor.run_frames(1);
+    editor.type_text("open hatch");
+    editor.run_frames(1);
+    assert!(editor.find_text("1 match").is_some());
+
+    let label = format!("Cargo Bay ({:?}) › Interactable", hatch);
+    editor.click_text(&label);
+    assert_eq!(
+        editor
+            .world()
+            .resource::<EditorSelection>()
+            .entities
+            .as_slice(),
+        &[hatch]
+    );
+    // The Interactable section, collapsed by default, is opened
+    editor.run_frames(1);
+    assert!(editor.find_text("prompt:").is_some());
+}
+
+#[test]
+fn scene_search_follows_runtime_changes() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let rock = editor
+        .world_mut()
+        .spawn((Name::new("Rock"), Transform::default()))
+        .id();
+    editor.run_frames(1);
+
+    editor.key(egui::Modifiers::CTRL, egui::Key::F);
+    editor.run_frames(1);
+    editor.type_text("rock");
+    editor.run_frames(1);
+    assert!(editor.find_text("1 match").is_some());
+
+    // Changes that don't mark the scene dirty, like those of running
+    // systems, are searched again too
+    editor
+        .world_mut()
+        .entity_mut(rock)
+        .insert(Name::new("Boulder"));
+    editor.run_frames(1);
+    assert!(!editor.world().resource::<SceneManager>().dirty);
+    assert!(editor.find_text("No matches").is_some());
+
+    editor
+        .world_mut()
+        .spawn((Name::new("Rockfall"), Transform::default()));
+    editor.run_frames(1);
+    assert!(editor.find_text("1 match").is_some());
+
+    editor
+        .world_mut()
+        .entity_mut(rock)
+        .insert(Name::new("Rock"));
+    editor.run_frames(1);
+    assert!(editor.find_text("2 matches").is_some());
+
+    editor.world_mut().entity_mut(rock).remove::<Name>();
+    editor.run_frames(1);
+    assert!(editor.find_text("1 match").is_some());
+}
+
+#[test]
+fn editor_notes_show_icons_and_edit_in_the_inspector() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let gate = editor
+        .world_mut()
+        .spawn((
+            Name::new("Gate"),
+            Transform::default(),
+            EditorNote {
+                text: "TODO: tune".to_string(),
+                ..default()
+            },
+        ))
+        .id();
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(gate);
+    editor.run_frames(2);
+
+    // One icon in the hierarchy, one at the entity in the viewport
+    let icons = editor
+        .texts()
+        .iter()
+        .filter(|(text, _)| text == "📝")
+        .count();
+    assert_eq!(icons, 2);
+    assert!(editor
+        .find_text("Editor-only: not loaded by the game.")
+        .is_some());
+
+    let field = editor
+        .find_text("TODO: tune")
+        .expect("note text in inspector");
+    editor.click_at(field.right_center() + egui::vec2(4.0, 0.0));
+    editor.type_text(" later");
+    assert_eq!(
+        editor.world().get::<EditorNote>(gate).unwrap().text,
+        "TODO: tune later"
+    );
+    assert!(editor.world().resource::<SceneManager>().dirty);
+
+    editor.click_at(egui::pos2(1.0, 300.0));
+    editor.key(egui::Modifiers::CTRL, egui::Key::Z);
+    assert_eq!(
+        editor.world().get::<EditorNote>(gate).unwrap().text,
+        "TODO: tune"
+    );
+}
+
+#[test]
+fn spawn_points_show_flags_that_select_them() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let spawn = editor
+        .world_mut()
+        .spawn((
+            Name::new("Checkpoint"),
+            SpawnPoint::new("checkpoint_a", SpawnKind::Other),
+            Transform::from_xyz(40.0, 0.0, 0.0),
+        ))
+        .id();
+    editor.run_frames(2);
+
+    // The id is labelled under the flag; clicking the flag selects it
+    let label = editor
+        .find_text("checkpoint_a")
+        .expect("spawn point id in the viewport");
+    editor.click_at(label.center_top() + egui::vec2(3.0, -14.0));
+    assert!(editor.world().resource::<EditorSelection>().contains(spawn));
+
+    // Flags are editor overlays, hidden while simulating
+    editor.click_text("▶ Play");
+    editor.run_frames(1);
+    assert!(editor.find_text("checkpoint_a").is_none());
+}
+
+#[test]
+fn wrap_bounds_are_outlined_and_resized_by_their_handles() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let bounds = Rect::new(-100.0, -60.0, 100.0, 60.0);
+    let asteroid = editor
+        .world_mut()
+        .spawn((Name::new("Asteroid"), WrapAround::new(bounds)))
+        .id();
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(asteroid);
+    editor.run_frames(2);
+
+    // Drag the top-left corner up and to the left
+    let label = editor
+        .find_text("Wrap bounds")
+        .expect("wrap bounds outlined in the viewport");
+    let corner = label.left_top() - egui::vec2(4.0, 4.0);
+    editor.drag(
+        corner,
+        corner - egui::vec2(30.0, 30.0),
+        egui::Modifiers::NONE,
+    );
+    let resized = editor.world().get::<WrapAround>(asteroid).unwrap().bounds;
+    assert!(resized.min.x < bounds.min.x && resized.max.y > bounds.max.y);
+    assert_eq!((resized.max.x, resized.min.y), (bounds.max.x, bounds.min.y));
+    assert_eq!(
+        editor.world().resource::<EditorSelection>().selected_entity,
+        Some(asteroid)
+    );
+    assert!(editor.world().resource::<SceneManager>().dirty);
+
+    editor.key(egui::Modifiers::CTRL, egui::Key::Z);
+    assert_eq!(
+        editor.world().get::<WrapAround>(asteroid).unwrap().bounds,
+        bounds
+    );
+}
+
+#[test]
+fn force_fields_show_their_radius_in_the_viewport() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor.world_mut().spawn((
+        Name::new("Black Hole"),
+        Attractor::new(300.0, 80.0),
+        Transform::default(),
+    ));
+    editor.world_mut().spawn((
+        Name::new("Shield"),
+        Repulsor::new(300.0, 40.0),
+        Transform::from_xyz(200.0, 0.0, 0.0),
+    ));
+    editor.run_frames(2);
+
+    let attractor = editor.find_text("Attractor").expect("attractor labelled");
+    let repulsor = editor.find_text("Repulsor").expect("repulsor labelled");
+    // Labels sit on top of each circle, so the larger radius is drawn higher
+    assert!(attractor.bottom() < repulsor.bottom());
+    assert!(attractor.center().x < repulsor.center().x);
+}
+
+#[test]
+fn audio_zones_are_drawn_as_labelled_regions() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor.world_mut().spawn((
+        Name::new("Town"),
+        AudioZone::music("audio/town.ogg", Vec2::new(200.0, 150.0)),
+    ));
+    editor.world_mut().spawn((
+        Name::new("Campfire"),
+        AudioZone::ambience("audio/fire.ogg", Vec2::new(40.0, 40.0)),
+        Transform::from_xyz(50.0, 0.0, 0.0),
+    ));
+    editor.run_frames(2);
+
+    // Labels sit in each zone's top-left corner
+    let town = editor.find_text("♫ town.ogg").expect("music zone labelled");
+    let fire = editor
+        .find_text("🔊 fire.ogg")
+        .expect("ambience zone labelled");
+    assert!(town.top() < fire.top());
+    assert!(town.left() < fire.left());
+}
+
+#[test]
+fn importing_copies_files_into_the_selected_folder() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let outside = editor.project_dir().join("downloads");
+    std::fs::create_dir_all(&outside).unwrap();
+    std::fs::write(outside.join("ship.png"), [0u8; 16]).unwrap();
+    let assets = editor.project_dir().join("assets");
+    std::fs::create_dir_all(assets.join("ships")).unwrap();
+    editor.click_text("⟳ Refresh");
+    editor.wait_for_asset_scan();
+
+    // Selecting a folder makes it the import target
+    editor.click_text("📁 ships");
+    assert!(editor.find_text("Imported files go here").is_some());
+
+    // The file dialog can't run headless, so import as its result would
+    for _ in 0..2 {
+        let mut browser = editor.world_mut().resource_mut::<AssetBrowser>();
+        let folder = browser.target_folder();
+        assert_eq!(folder, "ships");
+        browser
+            .import_files(&[outside.join("ship.png")], &folder)
+            .unwrap();
+        editor
+            .world_mut()
+            .resource_mut::<AssetBrowser>()
+            .selected_path = Some(folder);
+    }
+    editor.run_frames(1);
+
+    // Existing names get a numeric suffix instead of being overwritten
+    assert!(assets.join("ships/ship.png").exists());
+    assert!(assets.join("ships/ship_2.png").exists());
+    assert!(editor.find_text("🖼 ship.png").is_some());
+    assert!(editor.find_text("🖼 ship_2.png").is_some());
+}
+
+#[test]
+fn asset_browser_scans_in_the_background() {
+    let mut editor = EditorHarness::new();
+    std::fs::write(editor.project_dir().join("assets/hull.png"), [0u8; 16]).unwrap();
+
+    // The tree keeps its previous contents until the scan finishes
+    let mut browser = editor.world_mut().resource_mut::<AssetBrowser>();
+    browser.request_scan();
+    assert!(browser.is_scanning());
+    assert!(browser.find_entry("hull.png").is_none());
+
+    editor.wait_for_asset_scan();
+    editor.run_frames(1);
+    assert!(editor.find_text("🖼 hull.png").is_some());
+    assert!(editor.find_text("Scanning...").is_none());
+}
+
+#[test]
+fn asset_browser_refreshes_when_files_change_on_disk() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let assets = editor.project_dir().join("assets");
+
+    // Watcher events arrive asynchronously, so poll for up to a second
+    fn shown_within(editor: &mut EditorHarness, text: &str, present: bool) -> bool {
+        for _ in 0..50 {
+            if editor.find_text(text).is_some() == present {
+                return true;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            editor.run_frames(1);
+        }
+        false
+    }
+
+    // No manual refresh: the watcher picks up external changes
+    std::fs::write(assets.join("beacon.ogg"), [0u8; 16]).unwrap();
+    assert!(shown_within(&mut editor, "🔊 beacon.ogg", true));
+
+    std::fs::remove_file(assets.join("beacon.ogg")).unwrap();
+    assert!(shown_within(&mut editor, "🔊 beacon.ogg", false));
+}
+
+#[test]
+fn dialogue_preview_steps_through_a_conversation_without_touching_flags() {
+    let mut editor = EditorHarness::new();
+    let assets = editor.project_dir().join("assets");
+    std::fs::write(
+        assets.join("trader.dialogue.ron"),
+        r#"(start: "greeting", nodes: {
+            "greeting": (
+                lines: [(speaker: "Trader", text: "Got {ore} ore?")],
+                choices: [
+                    (text: "Sell it", next: Some("sell"), condition: AtLeast("ore", 1.0)),
+                    (text: "Browse", next: Some("shop")),
+                ],
+            ),
+            "sell": (actions: [Remove("ore")], lines: [(text: "Credits change hands.")]),
+        })"#,
+    )
+    .unwrap();
+    editor
+        .world_mut()
+        .resource_mut::<WorldFlags>()
+        .set("ore", 2.0);
+    editor.click_text("⟳ Refresh");
+    editor.wait_for_asset_scan();
+
+    let file = editor
+        .find_text("📄 trader.dialogue.ron")
+        .expect("dialogue in browser");
+    editor.right_click_at(file.center());
+    editor.click_text("Preview Dialogue");
+    editor.run_frames(1);
+    assert!(editor.find_text("Trader: Got 2 ore?").is_some());
+    assert!(editor
+        .find_text("⚠ 'greeting' leads to missing node 'shop'")
+        .is_some());
+
+    editor.click_text("Continue ▸");
+    editor.click_text("1. Sell it");
+    assert!(editor.find_text("Credits change hands.").is_some());
+    assert!(editor.find_text("▸ Sell it").is_some());
+    // The preview works on a copy of the flags
+    assert!(editor.world().resource::<WorldFlags>().get("ore").is_some());
+
+    editor.click_text("Continue ▸");
+    assert!(editor.find_text("(End of conversation)").is_some());
+    editor.click_text("⟲ Restart");
+    assert!(editor.find_text("Trader: Got 2 ore?").is_some());
+    assert!(editor.find_text("▸ Sell it").is_none());
+}
+
+#[test]
+fn sequence_editor_creates_and_saves_a_timeline() {
+    let mut editor = EditorHarness::new();
+    editor
+        .world_mut()
+        .spawn((Name::new("Courier"), Transform::from_xyz(40.0, 20.0, 0.0)));
+    let assets = editor.project_dir().join("assets");
+    editor.click_text("⟳ Refresh");
+    editor.wait_for_asset_scan();
+
+    let folder = editor.find_text("📁 scenes").expect("folder in browser");
+    editor.right_click_at(folder.center());
+    editor.click_text("New Sequence...");
+    editor.type_text("intro");
+    editor.key(egui::Modifiers::NONE, egui::Key::Enter);
+    let file = assets.join("scenes/intro.sequence.ron");
+    assert!(file.exists());
+    assert!(editor
+        .find_text("No steps yet. Add one to start the timeline.")
+        .is_some());
+
+    editor.click_text("+ Add Step");
+    editor.click_text("Move Along");
+    editor.click_text("Entity name");
+    editor.type_text("Courier");
+    editor.click_text("+ Point");
+    editor.click_text("+ Add Step");
+    editor.click_text("Camera Pan");
+    editor.click_text("Start with previous step");
+    editor.click_text("+ Add Step");
+    editor.click_text("Show Dialogue");
+    // Open-ended steps are marked on the timeline
+    assert!(editor.find_text("Show Dialogue …").is_some());
+    assert!(editor.find_text("Unsaved changes").is_some());
+    editor.click_text("💾 Save");
+    assert!(editor.find_text("Unsaved changes").is_none());
+
+    let saved = Sequence::load(&file).unwrap();
+    assert_eq!(saved.steps.len(), 3);
+    assert_eq!(
+        saved.steps[0].command,
+        SequenceCommand::MoveAlong {
+            entity: "Courier".into(),
+            path: vec![Vec2::new(40.0, 20.0)],
+            duration: 1.0,
+        }
+    );
+    assert!(saved.steps[1].with_previous);
+    assert_eq!(saved.start_times(0.0), [0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn time_of_day_window_scrubs_the_tint_and_saves_the_gradient() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let sky = editor
+        .world_mut()
+        .spawn((Name::new("Sky"), GlobalTint::default()))
+        .id();
+    editor.click_text("Window");
+    editor.click_text("Time of Day");
+    assert!(editor
+        .find_text("Gradient: built-in day/night cycle")
+        .is_some());
+
+    // Click the gradient strip above its 18h label
+    let label = editor.find_text("18h").expect("strip should be labelled");
+    editor.click_at(egui::pos2(label.center().x, label.top() - 12.0));
+    let hour = editor.world().resource::<TimeOfDay>().hour;
+    assert!((hour - 18.0).abs() < 0.3, "scrubbed to {}", hour);
+    let expected = TintGradient::day_night().sample(hour);
+    assert_eq!(editor.world().resource::<CurrentTint>().color, expected);
+
+    editor.click_text("+ Key");
+    assert_eq!(
+        editor.world().resource::<CurrentTint>().gradient.keys.len(),
+        TintGradient::day_night().keys.len() + 1
+    );
+    assert!(editor.find_text("Unsaved changes").is_some());
+
+    editor.click_text("💾 Save");
+    assert!(editor.find_text("Unsaved changes").is_none());
+    let path = "gradients/day_night.gradient.ron";
+    assert_eq!(
+        editor.world().get::<GlobalTint>(sky).unwrap().gradient,
+        path
+    );
+    let saved = TintGradient::load(&editor.project_dir().join("assets").join(path)).unwrap();
+    assert_eq!(saved, editor.world().resource::<CurrentTint>().gradient);
+    assert!(editor.find_text(&format!("Gradient: {}", path)).is_some());
+
+    // Pointing the tint at the file can be undone
+    editor.key(egui::Modifiers::CTRL, egui::Key::Z);
+    assert_eq!(editor.world().get::<GlobalTint>(sky).unwrap().gradient, "");
+}
+
+#[test]
+fn scenes_changed_on_disk_are_offered_for_reload() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor
+        .world_mut()
+        .spawn((Name::new("Rock"), Transform::default()));
+    let path = editor.project_dir().join("assets/scenes/watched.scn.ron");
+    save_scene(editor.world_mut(), &path).unwrap();
+    editor.run_frames(2);
+
+    // Watcher events arrive asynchronously, so poll for up to a second
+    fn within(editor: &mut EditorHarness, done: impl Fn(&mut EditorHarness) -> bool) -> bool {
+        for _ in 0..50 {
+            if done(editor) {
+                return true;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            editor.run_frames(1);
+        }
+        false
+    }
+    let rename = |from: &str, to: &str| {
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(&path, contents.replace(from, to)).unwrap();
+    };
+
+    // Saving from the editor isn't an outside change
+    editor.key(egui::Modifiers::CTRL, egui::Key::S);
+    assert!(!within(&mut editor, |editor| editor
+        .find_text("Scene Changed on Disk")
+        .is_some()));
+
+    rename("Rock", "Boulder");
+    assert!(within(&mut editor, |editor| editor
+        .find_text("Scene Changed on Disk")
+        .is_some()));
+    editor.click_text("Reload");
+    assert_eq!(editor.entities_named("Boulder").len(), 1);
+    assert!(editor.entities_named("Rock").is_empty());
+
+    // Unsaved changes are kept unless the user chooses to reload
+    editor
+        .world_mut()
+        .resource_mut::<SceneManager>()
+        .auto_reload = true;
+    editor
+        .world_mut()
+        .resource_mut::<SceneManager>()
+        .mark_dirty();
+    rename("Boulder", "Pebble");
+    assert!(within(&mut editor, |editor| editor
+        .find_text("Reloading discards your unsaved changes.")
+        .is_some()));
+    editor.click_text("Keep Mine");
+    assert!(editor.find_text("Scene Changed on Disk").is_none());
+    assert_eq!(editor.entities_named("Boulder").len(), 1);
+    assert!(editor.world().resource::<SceneManager>().dirty);
+
+    // A clean scene reloads without asking
+    save_scene(editor.world_mut(), &path).unwrap();
+    editor.run_frames(2);
+    rename("Boulder", "Stone");
+    assert!(within(&mut editor, |editor| editor
+        .entities_named("Stone")
+        .len()
+        == 1));
+    assert!(editor.find_text("Scene Changed on Disk").is_none());
+}
+
+#[test]
+fn scene_instances_spawn_read_only_contents() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let world = editor.world_mut();
+    world.spawn((Name::new("Crate"), Transform::default()));
+    let chunk = editor.project_dir().join("assets/prefabs/crates.scn.ron");
+    save_scene(editor.world_mut(), &chunk).unwrap();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    let dock = editor
+        .world_mut()
+        .spawn((Name::new("Dock"), Transform::default()))
+        .id();
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(dock);
+    editor.run_frames(1);
+    editor.click_text("+ Add Component");
+    editor.type_text("sceneinst");
+    editor.run_frames(1);
+    editor.click_text("SceneInstance");
+    editor
+        .world_mut()
+        .get_mut::<SceneInstance>(dock)
+        .unwrap()
+        .scene = "prefabs/crates.scn.ron".to_string();
+    editor.run_frames(2);
+
+    let crates = editor.entities_named("Crate");
+    assert_eq!(crates.len(), 1);
+    assert_eq!(editor.world().get::<Parent>(crates[0]).unwrap().get(), dock);
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(crates[0]);
+    editor.run_frames(1);
+    assert!(editor
+        .find_text(
+            "📦 Part of an instance of prefabs/crates.scn.ron - edit that scene to change it"
+        )
+        .is_some());
+}
+
+#[test]
+fn ambient_templates_are_created_from_the_viewport_menu() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    let viewport = editor.find_text("Viewport").unwrap();
+    let click = egui::pos2(viewport.left() + 200.0, viewport.bottom() + 200.0);
+    editor.right_click_at(click);
+    editor.click_text("Create Template Here");
+    editor.click_text("Snow");
+    editor.right_click_at(click);
+    editor.click_text("Create Template Here");
+    editor.click_text("Engine Hum Zone");
+
+    let snow = editor.entities_named("Snow");
+    assert_eq!(snow.len(), 1);
+    assert!(editor.world().get::<ParticleEmitter>(snow[0]).is_some());
+    let hum = editor.entities_named("Engine Hum Zone");
+    assert_eq!(hum.len(), 1);
+    assert!(editor.world().get::<AmbientSound>(hum[0]).is_some());
+    assert_eq!(editor.world().resource::<EditorSelection>().entities, hum);
+
+    // Snow falls during play without cluttering the hierarchy, and stops with it
+    editor
+        .world_mut()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+    editor.click_text("▶ Play");
+    editor.run_frames(3);
+    let count = editor
+        .world_mut()
+        .query::<&Particle>()
+        .iter(editor.world())
+        .count();
+    assert!(count > 0);
+    assert!(!editor
+        .texts()
+        .iter()
+        .any(|(text, _)| text.starts_with("Particle")));
+    editor.click_text("⏹ Stop");
+    editor.run_frames(1);
+    let count = editor
+        .world_mut()
+        .query::<&Particle>()
+        .iter(editor.world())
+        .count();
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn joint_anchors_are_dragged_in_the_viewport() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let hook = editor
+        .world_mut()
+        .spawn((Name::new("Hook"), Transform::default()))
+        .id();
+    let weight = editor
+        .world_mut()
+        .spawn((
+            Name::new("Weight"),
+            DistanceJoint::new(hook, 200.0),
+            Transform::from_xyz(200.0, 0.0, 0.0),
+        ))
+        .id();
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(weight);
+    editor.run_frames(2);
+
+    // The hook's anchor sits on the world origin, level with the length label;
+    // the origin's x is labelled "0" along the bottom of the viewport
+    let label = editor
+        .find_text("Length 200")
+        .expect("selected joint labelled with its length");
+    let origin_x = editor
+        .texts()
+        .iter()
+        .filter(|(text, _)| text == "0")
+        .max_by(|(_, a), (_, b)| a.bottom().total_cmp(&b.bottom()))
+        .map(|(_, rect)| rect.left() - 2.0)
+        .unwrap();
+    let handle = egui::pos2(origin_x, label.bottom() + 6.0);
+    editor.drag(
+        handle,
+        handle - egui::vec2(0.0, 30.0),
+        egui::Modifiers::NONE,
+    );
+
+    let joint = *editor.world().get::<DistanceJoint>(weight).unwrap();
+    assert!(joint.connected_anchor.y > 0.0, "{:?}", joint);
+    assert!(joint.connected_anchor.x.abs() < 2.0, "{:?}", joint);
+    assert_eq!(joint.anchor, Vec2::ZERO);
+    assert_eq!(
+        editor.world().get::<Transform>(hook).unwrap().translation,
+        Vec3::ZERO
+    );
+    assert!(editor.world().resource::<SceneManager>().dirty);
+
+    editor.key(egui::Modifiers::CTRL, egui::Key::Z);
+    assert_eq!(
+        editor
+            .world()
+            .get::<DistanceJoint>(weight)
+            .unwrap()
+            .connected_anchor,
+        Vec2::ZERO
+    );
+}
+
+#[test]
+fn emitter_shapes_are_dragged_and_previewed_while_stopped() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor
+        .world_mut()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+    let sparks = editor
+        .world_mut()
+        .spawn((
+            Name::new("Sparks"),
+            ParticleEmitter {
+                shape: EmitterShape::Cone {
+                    angle: 30.0,
+                    radius: 80.0,
+                },
+                direction: 0.0,
+                ..default()
+            },
+            Transform::default(),
+        ))
+        .id();
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(sparks);
+    editor.run_frames(2);
+
+    // Each handle is labelled just above it; drag the radius handle outwards
+    let label = editor
+        .find_text("Radius 80")
+        .expect("cone radius handle labelled");
+    let handle = label.center_bottom() + egui::vec2(0.0, 7.0);
+    editor.drag(
+        handle,
+        handle + egui::vec2(40.0, 0.0),
+        egui::Modifiers::NONE,
+    );
+    let shape = editor.world().get::<ParticleEmitter>(sparks).unwrap().shape;
+    let EmitterShape::Cone { angle, radius } = shape else {
+        panic!("{:?}", shape);
+    };
+    assert!(radius > 80.0, "{}", radius);
+    assert_eq!(angle, 30.0);
+    assert_eq!(
+        editor.world().resource::<EditorSelection>().selected_entity,
+        Some(sparks)
+    );
+    assert!(editor.world().resource::<SceneManager>().dirty);
+
+    editor.key(egui::Modifiers::CTRL, egui::Key::Z);
+    assert_eq!(
+        editor.world().get::<ParticleEmitter>(sparks).unwrap().shape,
+        EmitterShape::Cone {
+            angle: 30.0,
+            radius: 80.0
+        }
+    );
+
+    // Simulating runs the emitter without playing, until switched off
+    let count_particles = |editor: &mut EditorHarness| {
+        editor
+            .world_mut()
+            .query::<&Particle>()
+            .iter(editor.world())
+            .count()
+    };
+    assert_eq!(count_particles(&mut editor), 0);
+    editor.click_text("✨ Simulate Particles");
+    editor.run_frames(3);
+    assert!(editor.world().resource::<ParticlePreview>().enabled);
+    assert!(count_particles(&mut editor) > 0);
+    assert_eq!(
+        *editor.world().resource::<State<EditorPlayState>>().get(),
+        EditorPlayState::Stopped
+    );
+    editor.click_text("✨ Simulate Particles");
+    editor.run_frames(1);
+    assert_eq!(count_particles(&mut editor), 0);
+}
+
+#[test]
+fn saving_a_scene_with_problems_asks_first() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let probe = editor
+        .world_mut()
+        .spawn((Name::new("Probe"), Transform::from_xyz(f32::NAN, 0.0, 0.0)))
+        .id();
+    let path = editor.project_dir().join("assets/scenes/problems.scn.ron");
+    editor
+        .world_mut()
+        .resource_mut::<SceneManager>()
+        .current_scene_path = Some(path.clone());
+
+    // Cancel leaves the file unwritten
+    editor.key(egui::Modifiers::CTRL, egui::Key::S);
+    assert!(editor.find_text("Scene Has Problems").is_some());
+    assert!(editor.find_text("Transform is NaN or infinite").is_some());
+    editor.click_text("Cancel");
+    assert!(editor.find_text("Scene Has Problems").is_none());
+    assert!(!path.exists());
+
+    // Select picks the entity to fix, calling the save off
+    editor.key(egui::Modifiers::CTRL, egui::Key::S);
+    editor.click_text("Select");
+    assert_eq!(
+        editor.world().resource::<EditorSelection>().selected_entity,
+        Some(probe)
+    );
+    assert!(!path.exists());
+
+    editor.key(egui::Modifiers::CTRL, egui::Key::S);
+    editor.click_text("Save Anyway");
+    assert!(path.exists());
+    assert!(!editor.world().resource::<SceneManager>().dirty);
+
+    // Once fixed, saving goes straight through
+    editor
+        .world_mut()
+        .get_mut::<Transform>(probe)
+        .unwrap()
+        .translation = Vec3::ZERO;
+    std::fs::remove_file(&path).unwrap();
+    editor.key(egui::Modifiers::CTRL, egui::Key::S);
+    assert!(editor.find_text("Scene Has Problems").is_none());
+    assert!(path.exists());
+}
+
+#[test]
+fn autosaves_rotate_backups_and_are_offered_for_recovery() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor
+        .world_mut()
+        .spawn((Name::new("Base"), Transform::default()));
+    let path = editor.project_dir().join("assets/scenes/autosaved.scn.ron");
+    editor
+        .world_mut()
+        .resource_mut::<SceneManager>()
+        .current_scene_path = Some(path.clone());
+    editor.key(egui::Modifiers::CTRL, egui::Key::S);
+    let saved = std::fs::read_to_string(&path).unwrap();
+
+    // Autosave every frame, keeping two older copies
+    *editor.world_mut().resource_mut::<AutosaveSettings>() = AutosaveSettings {
+        enabled: true,
+        interval: 0.0,
+        backups: 2,
+    };
+    editor.run_frames(2);
+    assert!(
+        !autosave_path(&path).exists(),
+        "clean scenes aren't autosaved"
+    );
+    editor
+        .world_mut()
+        .spawn((Name::new("Late Addition"), Transform::default()));
+    editor
+        .world_mut()
+        .resource_mut::<SceneManager>()
+        .mark_dirty();
+    editor.run_frames(4);
+    assert!(autosave_path(&path).exists());
+    assert!(backup_path(&path, 1).exists());
+    assert!(backup_path(&path, 2).exists());
+    assert!(!backup_path(&path, 3).exists());
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), saved);
+    let manager = editor.world().resource::<SceneManager>();
+    assert!(manager.dirty);
+    assert_eq!(manager.current_scene_path.as_ref(), Some(&path));
+
+    // The autosave is newer than the scene, so it's offered as on startup
+    editor
+        .world_mut()
+        .resource_mut::<AutosaveSettings>()
+        .enabled = false;
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let found = find_recoverable_autosaves(&editor.project_dir().join("assets"));
+    assert_eq!(found, vec![autosave_path(&path)]);
+    editor.world_mut().resource_mut::<AutosaveRecovery>().found = found;
+    editor.run_frames(2);
+    assert!(editor.find_text("Recover Autosave").is_some());
+    assert!(editor.find_text("scenes/autosaved.scn.ron").is_some());
+    editor.click_text("Recover");
+
+    assert!(editor.find_text("Recover Autosave").is_none());
+    assert_eq!(editor.entities_named("Late Addition").len(), 1);
+    let manager = editor.world().resource::<SceneManager>();
+    assert!(manager.dirty);
+    assert_eq!(manager.current_scene_path.as_ref(), Some(&path));
+
+    // Saving makes the scene file the newest version, so the latest autosave
+    // goes, but the older copies stay as backups
+    editor.key(egui::Modifiers::CTRL, egui::Key::S);
+    editor.run_frames(1);
+    assert!(!autosave_path(&path).exists());
+    assert!(backup_path(&path, 1).exists());
+    assert!(backup_path(&path, 2).exists());
+    assert!(find_recoverable_autosaves(&editor.project_dir().join("assets")).is_empty());
+}
+
+#[test]
+fn prefab_overrides_are_reverted_and_applied_from_the_inspector() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let world = editor.world_mut();
+    let turret = world
+        .spawn((Name::new("Turret"), Transform::default()))
+        .id();
+    let barrel = world
+        .spawn((Name::new("Barrel"), Transform::from_xyz(0.0, 10.0, 0.0)))
+        .id();
+    world.entity_mut(turret).add_child(barrel);
+    let prefab = editor.project_dir().join("assets/prefabs/turret.scn.ron");
+    save_scene(editor.world_mut(), &prefab).unwrap();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+
+    spawn_prefab(editor.world_mut(), &prefab).unwrap();
+    let barrel = editor.entities_named("Barrel")[0];
+    let turret = editor.world().get::<Parent>(barrel).unwrap().get();
+    editor.world_mut().entity_mut(barrel).insert(EditorLocked);
+    editor
+        .world_mut()
+        .get_mut::<Transform>(barrel)
+        .unwrap()
+        .translation
+        .y = 40.0;
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(turret);
+    editor.run_frames(1);
+    assert!(editor
+        .find_text("🔗 Prefab: prefabs/turret.scn.ron")
+        .is_some());
+    editor.click_text("Overrides (2)");
+    assert!(editor.find_text("Barrel › Transform.translation").is_some());
+    assert!(editor.find_text("Barrel › + EditorLocked").is_some());
+
+    editor.click_text("Revert to Prefab");
+    assert_eq!(
+        editor
+            .world()
+            .get::<Transform>(barrel)
+            .unwrap()
+            .translation
+            .y,
+        10.0
+    );
+    assert!(editor.world().get::<EditorLocked>(barrel).is_none());
+    assert!(editor.find_text("Overrides (0)").is_some());
+
+    // Undo brings the overrides back, and Apply writes them to the file
+    editor.key(egui::Modifiers::CTRL, egui::Key::Z);
+    assert_eq!(
+        editor
+            .world()
+            .get::<Transform>(barrel)
+            .unwrap()
+            .translation
+            .y,
+        40.0
+    );
+    editor
+        .world_mut()
+        .resource_mut::<PrefabLinkState>()
+        .refresh();
+    editor.run_frames(1);
+    editor.click_text("Apply to Prefab");
+    let saved = std::fs::read_to_string(&prefab).unwrap();
+    assert!(saved.contains("EditorLocked"), "{}", saved);
+    assert!(editor.find_text("Overrides (0)").is_some());
+}
+
+#[test]
+fn sprite_variants_are_previewed_and_picked_in_the_inspector() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let rock = editor
+        .world_mut()
+        .spawn((
+            Name::new("Rock"),
+            Sprite::default(),
+            Transform::default(),
+            SpriteVariant {
+                selected: Some(0),
+                ..SpriteVariant::random(["sprites/rock_a.png", "sprites/rock_b.png"])
+            },
+        ))
+        .id();
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(rock);
+    editor.run_frames(2);
+    assert!(editor.find_text("Shown").is_some());
+    assert_eq!(
+        editor.world().get::<AssetPath>(rock).unwrap().path,
+        "sprites/rock_a.png"
+    );
+
+    // Showing the other variant is one undo step
+    editor.click_text("Show");
+    editor.run_frames(1);
+    assert_eq!(
+        editor.world().get::<SpriteVariant>(rock).unwrap().selected,
+        Some(1)
+    );
+    assert_eq!(
+        editor.world().get::<AssetPath>(rock).unwrap().path,
+        "sprites/rock_b.png"
+    );
+    editor.key(egui::Modifiers::CTRL, egui::Key::Z);
+    editor.run_frames(1);
+    assert_eq!(
+        editor.world().get::<AssetPath>(rock).unwrap().path,
+        "sprites/rock_a.png"
+    );
+
+    // Random picks again from the next seed
+    editor.click_text("🎲 Random");
+    editor.run_frames(1);
+    let variant = editor.world().get::<SpriteVariant>(rock).unwrap().clone();
+    assert_eq!(variant.seed, 1);
+    assert_eq!(variant.selected, variant.pick(rock));
+}
+
+#[test]
+fn unsaved_changes_are_offered_for_saving_before_they_are_lost() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor.click_text("+ Add Entity");
+    assert!(editor.world().resource::<SceneManager>().dirty);
+
+    // Cancel keeps the scene as it is
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor.run_frames(2);
+    assert!(editor.find_text("Save Changes?").is_some());
+    editor.click_text("Cancel");
+    assert!(editor.find_text("Save Changes?").is_none());
+    assert_eq!(editor.entities_named("Entity 1").len(), 1);
+
+    // Save writes the scene, then clears it for the new one
+    let path = editor.project_dir().join("assets/scenes/unsaved.scn.ron");
+    editor
+        .world_mut()
+        .resource_mut::<SceneManager>()
+        .current_scene_path = Some(path.clone());
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor.run_frames(2);
+    assert!(editor
+        .find_text("unsaved.scn.ron has unsaved changes.")
+        .is_some());
+    editor.click_text("Save");
+    assert!(path.exists());
+    assert!(editor.entities_named("Entity 1").is_empty());
+
+    // Discard drops the changes
+    editor.click_text("+ Add Entity");
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor.run_frames(2);
+    editor.click_text("Discard");
+    assert!(editor.entities_named("Entity 1").is_empty());
+    assert!(!editor.world().resource::<SceneManager>().dirty);
+
+    // Closing the window asks too, and quits once answered
+    editor.click_text("+ Add Entity");
+    editor
+        .world_mut()
+        .send_event(bevy::window::WindowCloseRequested {
+            window: Entity::PLACEHOLDER,
+        });
+    editor.run_frames(2);
+    assert!(editor.find_text("Save them before quitting?").is_some());
+    assert!(editor.world().resource::<Events<AppExit>>().is_empty());
+    editor.click_text("Discard");
+    assert!(!editor.world().resource::<Events<AppExit>>().is_empty());
+}
+
+#[test]
+fn json_scenes_save_as_json_and_are_listed_as_scenes() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor.click_text("+ Add Entity");
+    let path = editor.project_dir().join("assets/scenes/level.scn.json");
+    editor
+        .world_mut()
+        .resource_mut::<SceneManager>()
+        .current_scene_path = Some(path.clone());
+
+    // Ctrl+S keeps the open scene's format
+    editor.key(egui::Modifiers::CTRL, egui::Key::S);
+    let data = std::fs::read_to_string(&path).unwrap();
+    assert!(data.trim_start().starts_with('{'), "{}", data);
+
+    editor.click_text("⟳ Refresh");
+    editor.wait_for_asset_scan();
+    editor.click_text("Scenes");
+    assert!(editor.find_text("📄 scenes/level.scn.json").is_some());
+
+    // Reloading detects the format from the contents
+    load_scene(editor.world_mut(), &path).unwrap();
+    assert_eq!(editor.entities_named("Entity 1").len(), 1);
+}
+
+#[test]
+fn changes_window_lists_differences_from_the_scene_file() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let world = editor.world_mut();
+    let rock = world.spawn((Name::new("Rock"), Transform::default())).id();
+    let ship = world.spawn((Name::new("Ship"), Transform::default())).id();
+    let path = editor.project_dir().join("assets/scenes/changes.scn.ron");
+    save_scene(editor.world_mut(), &path).unwrap();
+
+    editor.click_text("Window");
+    editor.click_text("Changes");
+    assert!(editor.find_text("No unsaved changes").is_some());
+
+    let world = editor.world_mut();
+    world.despawn(rock);
+    world.get_mut::<Transform>(ship).unwrap().translation.x = 5.0;
+    let cargo = world.spawn((Name::new("Cargo"), Transform::default())).id();
+    world.resource_mut::<SceneManager>().mark_dirty();
+    editor.run_frames(1);
+
+    // Becoming dirty compares again
+    assert!(editor.find_text("1 added, 1 removed, 1 modified").is_some());
+    assert!(editor.find_text("− Rock (#0)").is_some());
+    assert!(editor.find_text("+ Cargo (#2)").is_some());
+    editor.click_text("~ Ship (#1)");
+    assert!(editor.find_text("~ Transform").is_some());
+
+    let state = editor.world().resource::<SceneChangesState>();
+    let diff = state.diff.as_ref().unwrap().as_ref().unwrap();
+    let changes: Vec<(&str, Change, Option<Entity>)> = diff
+        .entities
+        .iter()
+        .map(|entity| (entity.name.as_str(), entity.change, entity.entity))
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            ("Rock", Change::Removed, None),
+            ("Ship", Change::Modified, Some(ship)),
+            ("Cargo", Change::Added, Some(cargo)),
+        ]
+    );
+    let transform = diff.entities[1]
+        .components
+        .iter()
+        .find(|component| component.name == "Transform")
+        .unwrap();
+    let changed: Vec<DiffLine> = diff_lines(
+        transform.before.as_deref().unwrap(),
+        transform.after.as_deref().unwrap(),
+    )
+    .into_iter()
+    .filter(|line| !matches!(line, DiffLine::Same(_)))
+    .collect();
+    assert_eq!(
+        changed,
+        vec![
+            DiffLine::Removed("    translation: (0.0, 0.0, 0.0),"),
+            DiffLine::Added("    translation: (5.0, 0.0, 0.0),"),
+        ]
+    );
+
+    editor.click_text("Select");
+    assert_eq!(
+        editor
+            .world()
+            .resource::<EditorSelection>()
+            .entities
+            .as_slice(),
+        &[ship]
+    );
+
+    // Saving compares again
+    editor.key(egui::Modifiers::CTRL, egui::Key::S);
+    editor.run_frames(1);
+    assert!(editor.find_text("No unsaved changes").is_some());
+}
+
+#[test]
+fn sizes_and_grid_are_labeled_in_world_units_with_pixels_per_unit() {
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    editor
+        .world_mut()
+        .resource_mut::<ProjectSettings>()
+        .pixels_per_unit = 16.0;
+    // A 16×32 px texture, auto-sized to 1×2 world units
+    let sprite = editor
+        .world_mut()
+        .spawn((
+            Name::new("Crate"),
+            Sprite {
+                custom_size: Some(Vec2::new(1.0, 2.0)),
+                ..default()
+            },
+            Transform::default(),
+        ))
+        .id();
+    editor
+        .world_mut()
+        .resource_mut::<EditorSelection>()
+        .select(sprite);
+    editor.run_frames(2);
+
+    assert!(editor.find_text("1 × 2").is_some());
+
+    // At zoom 1 grid lines are 50 world units (50 points) apart, labeled in world units
+    let minus_fifty = editor
+        .find_text("-50")
+        .expect("the grid line at x = -50 should be labeled");
+    let fifty = editor
+        .find_text_right_of("-50", "50")
+        .expect("the grid line at x = 50 should be labeled");
+    assert!(
+        (fifty.left() - minus_fifty.left() - 100.0).abs() < 1.0,
+        "{:?} {:?}",
+        minus_fifty,
+        fifty
+    );
+
+    // Zoomed in below one unit, lines are texture pixels apart and labelled in pixels
+    editor
+        .world_mut()
+        .query::<&mut EditorCamera>()
+        .single_mut(editor.world_mut())
+        .zoom = 80.0;
+    editor.run_frames(1);
+    let minus_eight = editor
+        .find_text("-8px")
+        .expect("the grid line at x = -0.5 should be labeled in pixels");
+    let eight = editor
+        .find_text_right_of("-8px", "8px")
+        .expect("the grid line at x = 0.5 should be labeled in pixels");
+    assert!(
+        (eight.left() - minus_eight.left() - 80.0).abs() < 1.0,
+        "{:?} {:?}",
+        minus_eight,
+        eight
+    );
+}
+
+#[test]
+fn presets_are_stored_under_sanitized_type_directories() {
+    assert_eq!(
+        preset_dir_name("my_game::Status<my_game::Burning>"),
+        "my_game__Status_my_game__Burning_"
+    );
+
+    let mut editor = EditorHarness::new();
+    editor.key(egui::Modifiers::CTRL, egui::Key::N);
+    let world = editor.world_mut();
+    let source = world.spawn(Transform::from_xyz(4.0, 5.0, 0.0)).id();
+    let target = world.spawn(Transform::default()).id();
+    save_preset(world, source, TypeId::of::<Transform>(), "Corner").unwrap();
+
+    let presets = world.resource::<ComponentPresets>();
+    let file = presets
+        .directory
+        .join("bevy_transform__components__transform__Transform/Corner.ron");
+    assert!(file.is_file(), "{}", file.display());
+    assert_eq!(
+        presets.names_for(Transform::type_path()),
+        ["Corner".to_string()]
+    );
+
+    apply_preset(world, target, TypeId::of::<Transform>(), "Corner").unwrap();
+    assert_eq!(
+        world.get::<Transform>(target).unwrap().translation,
+        Vec3::new(4.0, 5.0, 0.0)
+    );
+
+    // Applying a preset can be undone like a field edit
+    assert_eq!(
+        world.resource::<UndoStack>().undo_label(),
+        Some("Apply Preset 'Corner'")
+    );
+    undo(world);
+    assert_eq!(
+        world.get::<Transform>(target).unwrap().translation,
+        Vec3::ZERO
+    );
+}
@@ -0,0 +1,16 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Engine hot-path benchmarks.
+//!
+//! Run with `cargo bench -p sandbox_engine --features bench`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use sandbox_engine::bench::{engine_benches, run_benches};
+
+fn hot_paths(c: &mut Criterion) {
+    run_benches(c, &engine_benches());
+}
+
+criterion_group!(benches, hot_paths);
+criterion_main!(benches);
@@ -0,0 +1,488 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Looping ambient sounds and ready-made ambient effects.
+//!
+//! An `AmbientSound` loops its sound while gameplay runs, at full volume
+//! within `radius` of the listener (the active 2D camera) and fading out over
+//! the next `falloff` units, so a reactor can hum only when the camera is
+//! near it. Sounds stop when the editor stops.
+//!
+//! An `AudioZone` plays its sound while the listener is inside the zone's
+//! rectangle, fading in on entry and out on exit. Ambience zones play
+//! alongside each other; of the music zones around the listener only the
+//! smallest plays, so walking from one area into another crossfades the
+//! music.
+//!
+//! `AmbientPreset` packages the particle and sound settings for common
+//! ambience (dust, snow, nebula sparkle, engine hum) so games and the
+//! editor's template menu can drop one into a scene. Presets spawn plain
+//! components, so a placed preset is saved like any other entity and can be
+//! tuned in the inspector.
+//!
+//! # Example
+//! ```ignore
+//! AmbientPreset::Snow.spawn(world, Vec3::new(0.0, 400.0, 5.0));
+//! commands.spawn((AmbientSound::new("audio/wind.ogg"), Transform::default()));
+//! commands.spawn((
+//!     AudioZone::music("audio/cave_theme.ogg", Vec2::new(400.0, 300.0)),
+//!     Transform::from_xyz(1200.0, 0.0, 0.0),
+//! ));
+//! ```
+
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+use crate::editor_state::{EditorPlayState, GameplaySystemSet};
+use crate::inspector::InspectorOptions;
+use crate::particles::{EmitterShape, ParticleEmitter};
+use crate::system_toggle::ToggleableSystem;
+
+/// Plugin that plays ambient sounds.
+pub struct AmbientSoundPlugin;
+
+impl Plugin for AmbientSoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<AmbientSound>()
+            .register_type::<AudioZone>()
+            .register_type::<AudioZoneKind>()
+            .add_systems(
+                Update,
+                (
+                    start_ambient_sounds.toggleable(),
+                    update_ambient_volumes.toggleable(),
+                    update_audio_zones.toggleable(),
+                )
+                    .chain()
+                    .in_set(GameplaySystemSet),
+            )
+            .add_systems(
+                OnEnter(EditorPlayState::Stopped),
+                (stop_ambient_sounds, stop_audio_zones),
+            );
+    }
+}
+
+/// A sound looped while gameplay runs, heard near the entity.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+#[require(Transform)]
+pub struct AmbientSound {
+    /// Sound file, relative to the assets directory.
+    pub sound: String,
+    /// Volume when the listener is within `radius`.
+    #[reflect(@InspectorOptions::range(0.0, 1.0))]
+    pub volume: f32,
+    /// Distance within which the sound plays at full volume.
+    #[reflect(@InspectorOptions::min(0.0).with_step(1.0))]
+    pub radius: f32,
+    /// Distance beyond `radius` over which the sound fades to silence.
+    #[reflect(@InspectorOptions::min(0.0).with_step(1.0))]
+    pub falloff: f32,
+}
+
+impl Default for AmbientSound {
+    fn default() -> Self {
+        Self {
+            sound: String::new(),
+            volume: 1.0,
+            radius: 200.0,
+            falloff: 200.0,
+        }
+    }
+}
+
+impl AmbientSound {
+    /// Loops the sound in `path`.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            sound: path.into(),
+            ..default()
+        }
+    }
+
+    /// Volume heard by a listener `distance` units away.
+    pub fn volume_at(&self, distance: f32) -> f32 {
+        if distance <= self.radius {
+            return self.volume;
+        }
+        if self.falloff <= 0.0 {
+            return 0.0;
+        }
+        let fade = 1.0 - (distance - self.radius) / self.falloff;
+        self.volume * fade.clamp(0.0, 1.0)
+    }
+}
+
+/// What an [`AudioZone`] plays.
+#[derive(Reflect, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AudioZoneKind {
+    /// A music track; only the smallest music zone around the listener plays.
+    #[default]
+    Music,
+    /// A looping ambience, played in every zone the listener is in.
+    Ambience,
+}
+
+/// A rectangle that plays a sound while the listener is inside it.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+#[require(Transform)]
+pub struct AudioZone {
+    /// Half the width and height of the zone, in the entity's local space.
+    #[reflect(@InspectorOptions::min(0.0).with_step(1.0))]
+    pub half_size: Vec2,
+    /// Sound file, relative to the assets directory.
+    pub sound: String,
+    /// Whether the sound is music or ambience.
+    pub kind: AudioZoneKind,
+    /// Volume once faded in.
+    #[reflect(@InspectorOptions::range(0.0, 1.0))]
+    pub volume: f32,
+    /// Seconds to fade in after the listener enters.
+    #[reflect(@InspectorOptions::min(0.0))]
+    pub fade_in: f32,
+    /// Seconds to fade out after the listener leaves.
+    #[reflect(@InspectorOptions::min(0.0))]
+    pub fade_out: f32,
+}
+
+impl Default for AudioZone {
+    fn default() -> Self {
+        Self {
+            half_size: Vec2::new(200.0, 200.0),
+            sound: String::new(),
+            kind: AudioZoneKind::Music,
+            volume: 1.0,
+            fade_in: 1.5,
+            fade_out: 1.5,
+        }
+    }
+}
+
+impl AudioZone {
+    /// A music zone playing `path` within `half_size` of the entity.
+    pub fn music(path: impl Into<String>, half_size: Vec2) -> Self {
+        Self {
+            sound: path.into(),
+            half_size,
+            ..default()
+        }
+    }
+
+    /// An ambience zone looping `path` within `half_size` of the entity.
+    pub fn ambience(path: impl Into<String>, half_size: Vec2) -> Self {
+        Self {
+            kind: AudioZoneKind::Ambience,
+            ..Self::music(path, half_size)
+        }
+    }
+
+    /// Whether `point`, in world space, is inside the zone of an entity
+    /// placed at `transform`.
+    pub fn contains(&self, transform: &GlobalTransform, point: Vec2) -> bool {
+        let local = transform
+            .affine()
+            .inverse()
+            .transform_point3(point.extend(0.0))
+            .truncate();
+        local.x.abs() <= self.half_size.x && local.y.abs() <= self.half_size.y
+    }
+}
+
+/// The sound of an [`AudioZone`], fading in or out.
+#[derive(Component, Clone, Debug)]
+pub struct AudioZoneVoice {
+    /// Entity with the `AudioZone`.
+    pub zone: Entity,
+    /// How far the sound has faded in, from 0 to 1.
+    pub level: f32,
+}
+
+/// The playing loop of an `AmbientSound`.
+#[derive(Component)]
+struct AmbientVoice {
+    /// Entity with the `AmbientSound`.
+    source: Entity,
+}
+
+/// Starts a loop for each ambient sound that doesn't have one yet.
+fn start_ambient_sounds(
+    mut commands: Commands,
+    asset_server: Option<Res<AssetServer>>,
+    sounds: Query<(Entity, &AmbientSound)>,
+    voices: Query<&AmbientVoice>,
+) {
+    let Some(asset_server) = asset_server else {
+        return;
+    };
+    for (entity, sound) in &sounds {
+        if sound.sound.is_empty() || voices.iter().any(|voice| voice.source == entity) {
+            continue;
+        }
+        commands.spawn((
+            Name::new(format!("Ambient: {}", sound.sound)),
+            AmbientVoice { source: entity },
+            AudioPlayer::<AudioSource>(asset_server.load(&sound.sound)),
+            // Silent until the first volume update places it
+            PlaybackSettings::LOOP.with_volume(Volume::new(0.0)),
+        ));
+    }
+}
+
+/// Sets each loop's volume from the listener's distance, stopping loops
+/// whose sound was removed.
+fn update_ambient_volumes(
+    mut commands: Commands,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    sounds: Query<(&AmbientSound, &GlobalTransform)>,
+    voices: Query<(Entity, &AmbientVoice, Option<&AudioSink>)>,
+) {
+    let listener = listener_position(&cameras);
+
+    for (entity, voice, sink) in &voices {
+        let Ok((sound, transform)) = sounds.get(voice.source) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+        let Some(sink) = sink else {
+            continue;
+        };
+        let volume = listener.map_or(sound.volume, |listener| {
+            sound.volume_at(listener.distance(transform.translation().truncate()))
+        });
+        sink.set_volume(volume);
+    }
+}
+
+/// Position of the listener: the active 2D camera with the lowest order.
+fn listener_position(cameras: &Query<(&Camera, &GlobalTransform), With<Camera2d>>) -> Option<Vec2> {
+    cameras
+        .iter()
+        .filter(|(camera, _)| camera.is_active)
+        .min_by_key(|(camera, _)| camera.order)
+        .map(|(_, transform)| transform.translation().truncate())
+}
+
+/// Starts the sounds of the zones around the listener and fades each zone's
+/// sound towards whether it should be heard, stopping those faded out.
+fn update_audio_zones(
+    mut commands: Commands,
+    time: Res<Time>,
+    asset_server: Option<Res<AssetServer>>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    zones: Query<(Entity, &AudioZone, &GlobalTransform)>,
+    mut voices: Query<(Entity, &mut AudioZoneVoice, Option<&AudioSink>)>,
+) {
+    let listener = listener_position(&cameras);
+    let around: Vec<(Entity, &AudioZone, &GlobalTransform)> = zones
+        .iter()
+        .filter(|(_, zone, transform)| {
+            !zone.sound.is_empty()
+                && listener.is_some_and(|listener| zone.contains(transform, listener))
+        })
+        .collect();
+    // Nested music zones: the smallest is the most specific
+    let music = around
+        .iter()
+        .filter(|(_, zone, _)| zone.kind == AudioZoneKind::Music)
+        .min_by(|(a, zone_a, transform_a), (b, zone_b, transform_b)| {
+            let area = |zone: &AudioZone, transform: &GlobalTransform| {
+                let scale = transform.scale().truncate().abs();
+                (zone.half_size * scale).element_product()
+            };
+            area(zone_a, transform_a)
+                .total_cmp(&area(zone_b, transform_b))
+                .then(a.cmp(b))
+        })
+        .map(|(entity, ..)| *entity);
+    let audible = |entity: Entity| {
+        around.iter().any(|(zone, audio_zone, _)| {
+            *zone == entity && (audio_zone.kind == AudioZoneKind::Ambience || music == Some(entity))
+        })
+    };
+
+    let delta = time.delta_secs();
+    for (entity, mut voice, sink) in &mut voices {
+        let Ok((_, zone, _)) = zones.get(voice.zone) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+        voice.level = if audible(voice.zone) {
+            fade(voice.level, 1.0, zone.fade_in, delta)
+        } else {
+            fade(voice.level, 0.0, zone.fade_out, delta)
+        };
+        if voice.level <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        if let Some(sink) = sink {
+            sink.set_volume(zone.volume * voice.level);
+        }
+    }
+
+    for &(entity, zone, _) in &around {
+        if !audible(entity) || voices.iter().any(|(_, voice, _)| voice.zone == entity) {
+            continue;
+        }
+        let level = fade(0.0, 1.0, zone.fade_in, delta);
+        let mut voice = commands.spawn((
+            Name::new(format!("Audio Zone: {}", zone.sound)),
+            AudioZoneVoice {
+                zone: entity,
+                level,
+            },
+        ));
+        if let Some(asset_server) = &asset_server {
+            voice.insert((
+                AudioPlayer::<AudioSource>(asset_server.load(&zone.sound)),
+                PlaybackSettings::LOOP.with_volume(Volume::new(zone.volume * level)),
+            ));
+        }
+    }
+}
+
+/// Moves `level` towards `target` at a rate that covers the whole range in
+/// `seconds`; no fade time jumps straight there.
+fn fade(level: f32, target: f32, seconds: f32, delta: f32) -> f32 {
+    if seconds <= 0.0 {
+        return target;
+    }
+    let step = delta / seconds;
+    if level < target {
+        (level + step).min(target)
+    } else {
+        (level - step).max(target)
+    }
+}
+
+/// Stops every audio zone's sound.
+fn stop_audio_zones(mut commands: Commands, voices: Query<Entity, With<AudioZoneVoice>>) {
+    for entity in &voices {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Stops every ambient loop.
+fn stop_ambient_sounds(mut commands: Commands, voices: Query<Entity, With<AmbientVoice>>) {
+    for entity in &voices {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Ready-made ambient effects.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AmbientPreset {
+    /// Motes of dust drifting in a room-sized area.
+    Dust,
+    /// Snow falling from a wide strip; place it above the view.
+    Snow,
+    /// Colored glints twinkling across a patch of space.
+    NebulaSparkle,
+    /// A low hum heard near the entity, for engines and machinery. Needs
+    /// the `embedded_assets` feature for its sound.
+    #[cfg(feature = "embedded_assets")]
+    EngineHum,
+}
+
+impl AmbientPreset {
+    /// Every preset, in menu order.
+    pub const ALL: &'static [AmbientPreset] = &[
+        AmbientPreset::Dust,
+        AmbientPreset::Snow,
+        AmbientPreset::NebulaSparkle,
+        #[cfg(feature = "embedded_assets")]
+        AmbientPreset::EngineHum,
+    ];
+
+    /// Display name, also used as the spawned entity's name.
+    pub fn label(self) -> &'static str {
+        match self {
+            AmbientPreset::Dust => "Dust",
+            AmbientPreset::Snow => "Snow",
+            AmbientPreset::NebulaSparkle => "Nebula Sparkle",
+            #[cfg(feature = "embedded_assets")]
+            AmbientPreset::EngineHum => "Engine Hum Zone",
+        }
+    }
+
+    /// The preset's particle emitter, if it has one.
+    pub fn emitter(self) -> Option<ParticleEmitter> {
+        let emitter = match self {
+            AmbientPreset::Dust => ParticleEmitter {
+                shape: EmitterShape::Box {
+                    half_size: Vec2::new(400.0, 250.0),
+                },
+                rate: 12.0,
+                lifetime: 6.0,
+                speed: 6.0,
+                spread: 180.0,
+                size: 2.0,
+                color: Color::srgba(0.85, 0.78, 0.65, 0.6),
+                end_color: Color::srgba(0.85, 0.78, 0.65, 0.0),
+                ..default()
+            },
+            AmbientPreset::Snow => ParticleEmitter {
+                shape: EmitterShape::Box {
+                    half_size: Vec2::new(500.0, 10.0),
+                },
+                rate: 40.0,
+                lifetime: 10.0,
+                speed: 60.0,
+                direction: -90.0,
+                spread: 10.0,
+                acceleration: Vec2::new(4.0, 0.0),
+                size: 3.0,
+                color: Color::WHITE,
+                end_color: Color::WHITE.with_alpha(0.2),
+                max_particles: 800,
+                ..default()
+            },
+            AmbientPreset::NebulaSparkle => ParticleEmitter {
+                shape: EmitterShape::Circle { radius: 300.0 },
+                rate: 25.0,
+                lifetime: 1.5,
+                speed: 4.0,
+                spread: 180.0,
+                size: 3.0,
+                color: Color::srgb(0.75, 0.6, 1.0),
+                end_color: Color::srgba(0.4, 0.9, 1.0, 0.0),
+                ..default()
+            },
+            #[cfg(feature = "embedded_assets")]
+            AmbientPreset::EngineHum => return None,
+        };
+        Some(emitter)
+    }
+
+    /// The preset's looping sound, if it has one.
+    pub fn sound(self) -> Option<AmbientSound> {
+        match self {
+            #[cfg(feature = "embedded_assets")]
+            AmbientPreset::EngineHum => Some(AmbientSound {
+                sound: crate::embedded::HUM_SOUND_PATH.to_string(),
+                volume: 0.6,
+                radius: 120.0,
+                falloff: 240.0,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Spawns the effect at `translation` and returns the entity.
+    pub fn spawn(self, world: &mut World, translation: Vec3) -> Entity {
+        let mut entity = world.spawn((
+            Name::new(self.label()),
+            Transform::from_translation(translation),
+            Visibility::default(),
+        ));
+        if let Some(emitter) = self.emitter() {
+            entity.insert(emitter);
+        }
+        if let Some(sound) = self.sound() {
+            entity.insert(sound);
+        }
+        entity.id()
+    }
+}
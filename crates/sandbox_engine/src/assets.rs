@@ -6,9 +6,17 @@
 //! This module provides `AssetPath`, a component that stores a path string
 //! that gets automatically synced to a `Handle<Image>` at runtime. This solves
 //! Bevy's handle serialization limitation by storing the path instead of the handle.
+//!
+//! `SpriteVariant` picks the entity's `AssetPath` from a list of textures,
+//! explicitly or at random when it spawns, so one prefab can look different
+//! each time it is placed.
 
 use bevy::prelude::*;
 
+use crate::inspector::InspectorOptions;
+use crate::project::{init_project_settings, ProjectSettings};
+use crate::time_control::LocalTimeScale;
+
 /// A serializable asset path component that syncs to `Handle<Image>`.
 ///
 /// When this component is added to an entity with a `Sprite`, the asset system
@@ -22,8 +30,8 @@ use bevy::prelude::*;
 ///     Transform::default(),
 /// ));
 /// ```
-#[derive(Component, Reflect, Default, Clone, Debug)]
-#[reflect(Component)]
+#[derive(Component, Reflect, Default, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
 pub struct AssetPath {
     /// Path to the asset file, relative to the assets directory.
     pub path: String,
@@ -36,12 +44,26 @@ impl AssetPath {
     }
 }
 
+/// Marks a sprite sized from its texture by `pixels_per_unit`.
+///
+/// Holds the `custom_size` it was last given, so a size changed by hand is
+/// told apart and kept. Saved with the scene, so reloaded sprites follow
+/// later changes to the texture or `pixels_per_unit`.
+#[derive(Component, Reflect, Default, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct AutoSized {
+    /// The `custom_size` set from the texture; `None` at one pixel per unit.
+    pub size: Option<Vec2>,
+}
+
 /// Animation frame data for sprite sheet animations.
 #[derive(Clone, Debug, Reflect, Default)]
+#[reflect(Default)]
 pub struct AnimationFrame {
     /// Rectangle in the sprite sheet for this frame.
     pub rect: Rect,
     /// Duration of this frame in seconds.
+    #[reflect(@InspectorOptions::min(0.0).with_step(0.01).with_tooltip("Seconds"))]
     pub duration: f32,
 }
 
@@ -49,13 +71,14 @@ pub struct AnimationFrame {
 ///
 /// Works with sprite sheets to animate through multiple frames.
 #[derive(Component, Reflect, Default, Clone, Debug)]
-#[reflect(Component)]
+#[reflect(Component, Default)]
 pub struct SpriteAnimation {
     /// List of animation frames.
     pub frames: Vec<AnimationFrame>,
     /// Current frame index.
     pub current_frame: usize,
     /// Time accumulator for frame timing.
+    #[reflect(@InspectorOptions::min(0.0).with_step(0.01))]
     pub timer: f32,
     /// Whether the animation is currently playing.
     pub playing: bool,
@@ -92,15 +115,111 @@ impl SpriteAnimation {
     }
 }
 
+/// Picks a sprite's texture from several variants.
+///
+/// While `selected` is `None`, a variant is picked at random when the entity
+/// spawns and stored in `selected`, so a placed copy keeps its look when the
+/// scene is saved. The pick depends on `seed` and the entity, so copies of a
+/// prefab differ from each other. The chosen texture is written to the
+/// entity's `AssetPath`.
+///
+/// # Example
+/// ```ignore
+/// commands.spawn((
+///     Sprite::default(),
+///     SpriteVariant::random(["sprites/rock_a.png", "sprites/rock_b.png"]),
+///     Transform::default(),
+/// ));
+/// ```
+#[derive(Component, Reflect, Default, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct SpriteVariant {
+    /// Textures to pick from.
+    pub variants: Vec<AssetPath>,
+    /// Index of the variant shown; `None` picks one when the entity spawns.
+    pub selected: Option<usize>,
+    /// Seed for random picks; change it to pick again.
+    #[reflect(@InspectorOptions::tooltip("Mixed with the entity, so copies differ"))]
+    pub seed: u64,
+}
+
+impl SpriteVariant {
+    /// Variants of `paths`, one picked at random.
+    pub fn random<P: Into<String>>(paths: impl IntoIterator<Item = P>) -> Self {
+        Self {
+            variants: paths.into_iter().map(AssetPath::new).collect(),
+            ..default()
+        }
+    }
+
+    /// The variant `entity` gets when picking at random, or `None` without variants.
+    pub fn pick(&self, entity: Entity) -> Option<usize> {
+        if self.variants.is_empty() {
+            return None;
+        }
+        // splitmix64, so neighbouring entities and seeds land far apart
+        let mut hash = (self.seed ^ entity.to_bits()).wrapping_add(0x9e37_79b9_7f4a_7c15);
+        hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        hash ^= hash >> 31;
+        Some((hash % self.variants.len() as u64) as usize)
+    }
+
+    /// Path of the selected variant, if it is in range.
+    pub fn selected_path(&self) -> Option<&str> {
+        self.variants
+            .get(self.selected?)
+            .map(|variant| variant.path.as_str())
+    }
+}
+
 /// Plugin that sets up asset path syncing.
 pub struct AssetPathPlugin;
 
 impl Plugin for AssetPathPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<AssetPath>()
+            .register_type::<AutoSized>()
             .register_type::<SpriteAnimation>()
             .register_type::<AnimationFrame>()
-            .add_systems(Update, (sync_asset_paths, animate_sprites));
+            .register_type::<SpriteVariant>()
+            .add_systems(
+                Update,
+                (
+                    pick_sprite_variants,
+                    sync_asset_paths,
+                    auto_size_sprites,
+                    animate_sprites,
+                )
+                    .chain(),
+            );
+        init_project_settings(app);
+    }
+}
+
+/// System that picks variants for new or edited `SpriteVariant`s and points
+/// their `AssetPath` at the selected one.
+fn pick_sprite_variants(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut SpriteVariant, Option<&mut AssetPath>), Changed<SpriteVariant>>,
+) {
+    for (entity, mut variant, asset_path) in &mut query {
+        if variant.selected.is_none() {
+            variant.selected = variant.pick(entity);
+        }
+        let Some(path) = variant.selected_path() else {
+            continue;
+        };
+        match asset_path {
+            Some(mut asset_path) => {
+                if asset_path.path != path {
+                    asset_path.path = path.to_string();
+                }
+            }
+            None => {
+                commands.entity(entity).insert(AssetPath::new(path));
+            }
+        }
     }
 }
 
@@ -122,20 +241,64 @@ fn sync_asset_paths(
 
         // Update the sprite's image
         sprite.image = handle;
+    }
+}
 
-        // Clear custom_size if it was set (let the texture determine size)
-        // This is optional - users can override this in the inspector
+/// System that sizes texture-backed sprites in world units.
+///
+/// Sprites without a `custom_size` render at one world unit per texture pixel.
+/// A sprite whose `AssetPath` is set while it has no `custom_size` is marked
+/// [`AutoSized`]; from then on its `custom_size` follows the loaded texture
+/// (or its `rect`) divided by `pixels_per_unit`, and is cleared at the default
+/// of 1. Resizing or clearing the `custom_size` by hand removes the marker,
+/// so the sprite keeps the size it was given.
+fn auto_size_sprites(
+    mut commands: Commands,
+    settings: Res<ProjectSettings>,
+    images: Res<Assets<Image>>,
+    mut query: Query<(Entity, Ref<AssetPath>, &mut Sprite, Option<&mut AutoSized>)>,
+) {
+    for (entity, asset_path, mut sprite, auto_sized) in &mut query {
+        let Some(mut auto_sized) = auto_sized else {
+            if asset_path.is_changed() && sprite.custom_size.is_none() {
+                commands.entity(entity).insert(AutoSized::default());
+            }
+            continue;
+        };
+        if sprite.custom_size != auto_sized.size {
+            commands.entity(entity).remove::<AutoSized>();
+            continue;
+        }
+        let size = if settings.pixels_per_unit == 1.0 {
+            None
+        } else {
+            let pixel_size = match sprite.rect {
+                Some(rect) => rect.size(),
+                None => match images.get(&sprite.image) {
+                    Some(image) => image.size_f32(),
+                    None => continue,
+                },
+            };
+            Some(pixel_size / settings.pixels_per_unit)
+        };
+        if size != auto_sized.size {
+            sprite.custom_size = size;
+            auto_sized.size = size;
+        }
     }
 }
 
-/// System that advances sprite animations.
-fn animate_sprites(time: Res<Time>, mut query: Query<(&mut SpriteAnimation, &mut Sprite)>) {
-    for (mut animation, mut sprite) in &mut query {
+/// System that advances sprite animations, honoring `LocalTimeScale`.
+fn animate_sprites(
+    time: Res<Time>,
+    mut query: Query<(&mut SpriteAnimation, &mut Sprite, Option<&LocalTimeScale>)>,
+) {
+    for (mut animation, mut sprite, time_scale) in &mut query {
         if !animation.playing || animation.frames.is_empty() {
             continue;
         }
 
-        animation.timer += time.delta_secs();
+        animation.timer += LocalTimeScale::delta_secs(&time, time_scale);
 
         // Get current frame duration
         let current_frame = &animation.frames[animation.current_frame];
@@ -0,0 +1,296 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Criterion benchmarks for engine and game hot paths (`bench` feature).
+//!
+//! A `WorldBench` builds a headless app populated with a given number of
+//! entities and measures one routine against it, such as a frame update or
+//! a scene save. `run_benches` measures every bench at each of its entity
+//! counts and reports throughput in entities per second, so results can be
+//! compared across sizes and commits.
+//!
+//! The engine's own benches (`engine_benches`) run with
+//! `cargo bench -p sandbox_engine --features bench`. The engine has no
+//! spatial index yet, so contact detection stands in for spatial queries.
+//! The particle bench counts live particles, kept steady by emitters that
+//! replace them as they expire.
+//!
+//! # Registering game benches
+//! Add `criterion` and `sandbox_engine` with the `bench` feature as
+//! dev-dependencies, declare a `[[bench]]` target with `harness = false`,
+//! and register game benches alongside (or instead of) the engine's:
+//! ```ignore
+//! use criterion::{criterion_group, criterion_main, Criterion};
+//! use sandbox_engine::bench::{engine_benches, headless_app, run_benches, WorldBench};
+//!
+//! fn benches(c: &mut Criterion) {
+//!     let mut benches = engine_benches();
+//!     benches.push(WorldBench::new(
+//!         "asteroids/update",
+//!         |count| {
+//!             let mut app = headless_app();
+//!             app.add_plugins(AsteroidsPlugin);
+//!             spawn_asteroids(app.world_mut(), count);
+//!             app
+//!         },
+//!         |app| app.update(),
+//!     ));
+//!     run_benches(c, &benches);
+//! }
+//!
+//! criterion_group!(game, benches);
+//! criterion_main!(game);
+//! ```
+
+use bevy::app::PluginsState;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use criterion::{BenchmarkId, Criterion, Throughput};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::assets::{AnimationFrame, AssetPathPlugin, SpriteAnimation};
+use crate::particles::{EmitterShape, ParticleEmitter, ParticlePlugin};
+use crate::physics::{Collider, LinearVelocity, PhysicsPlugin};
+use crate::scene::{load_scene, save_scene, ScenePlugin};
+
+/// Entity counts used when a bench does not set its own.
+pub const DEFAULT_SIZES: &[usize] = &[100, 1_000, 10_000];
+
+/// Duration of one `app.update()` in a headless bench app.
+pub const TICK: Duration = Duration::from_nanos(16_666_667);
+
+type Setup = Box<dyn Fn(usize) -> App>;
+type Routine = Box<dyn Fn(&mut App)>;
+
+/// A benchmark of one routine on a world populated with a number of entities.
+pub struct WorldBench {
+    name: String,
+    sizes: Vec<usize>,
+    setup: Setup,
+    routine: Routine,
+}
+
+impl WorldBench {
+    /// Creates a bench that builds its app with `setup(count)` and times `routine`.
+    ///
+    /// `setup` runs once per entity count, outside the measurement.
+    pub fn new(
+        name: impl Into<String>,
+        setup: impl Fn(usize) -> App + 'static,
+        routine: impl Fn(&mut App) + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            sizes: DEFAULT_SIZES.to_vec(),
+            setup: Box::new(setup),
+            routine: Box::new(routine),
+        }
+    }
+
+    /// Sets the entity counts to measure.
+    pub fn with_sizes(mut self, sizes: &[usize]) -> Self {
+        self.sizes = sizes.to_vec();
+        self
+    }
+
+    /// The bench name, used as the criterion group name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Measures every bench at each of its entity counts.
+pub fn run_benches(c: &mut Criterion, benches: &[WorldBench]) {
+    for bench in benches {
+        let mut group = c.benchmark_group(bench.name.as_str());
+        for &count in &bench.sizes {
+            let mut app = (bench.setup)(count);
+            group.throughput(Throughput::Elements(count as u64));
+            group.bench_function(BenchmarkId::from_parameter(count), |b| {
+                b.iter(|| (bench.routine)(&mut app));
+            });
+        }
+        group.finish();
+    }
+}
+
+/// A windowless app with time, assets, and transforms, advancing `TICK` per update.
+///
+/// Plugins added afterwards are finished by the first `app.update()`.
+pub fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), TransformPlugin))
+        .init_asset::<Image>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(TICK));
+    app
+}
+
+/// Finishes plugin setup and runs one update so benches time steady-state frames.
+pub fn warm_up(app: &mut App) {
+    if app.plugins_state() == PluginsState::Ready {
+        app.finish();
+        app.cleanup();
+    }
+    app.update();
+}
+
+/// The engine's hot-path benches: scene save/load, animation, contacts,
+/// integration, and particles.
+pub fn engine_benches() -> Vec<WorldBench> {
+    vec![
+        WorldBench::new("scene/save", scene_app, |app| {
+            let path = scene_file(app);
+            save_scene(app.world_mut(), &path).expect("bench scene should save");
+        }),
+        WorldBench::new(
+            "scene/load",
+            |count| {
+                let mut app = scene_app(count);
+                let path = scene_file(&app);
+                save_scene(app.world_mut(), &path).expect("bench scene should save");
+                app
+            },
+            |app| {
+                let path = scene_file(app);
+                load_scene(app.world_mut(), &path).expect("bench scene should load");
+            },
+        ),
+        WorldBench::new(
+            "animation/update",
+            |count| {
+                let mut app = headless_app();
+                app.add_plugins(AssetPathPlugin);
+                spawn_animated_sprites(app.world_mut(), count);
+                warm_up(&mut app);
+                app
+            },
+            App::update,
+        ),
+        // Contact detection tests every pair, so sizes stay small
+        WorldBench::new("physics/contacts", contacts_app, App::update)
+            .with_sizes(&[100, 500, 2_000]),
+        WorldBench::new("physics/integrate", integrate_app, App::update),
+        WorldBench::new("particles/update", particles_app, App::update),
+    ]
+}
+
+/// Temporary scene file for a scene bench, unique per entity count.
+#[derive(Resource)]
+struct BenchScenePath(PathBuf);
+
+fn scene_file(app: &App) -> PathBuf {
+    app.world().resource::<BenchScenePath>().0.clone()
+}
+
+/// An app with `count` sprite entities carrying typical saved components.
+fn scene_app(count: usize) -> App {
+    let mut app = App::new();
+    app.add_plugins((ScenePlugin, AssetPathPlugin, PhysicsPlugin))
+        .register_type::<Transform>()
+        .register_type::<Name>()
+        .register_type::<Sprite>()
+        .insert_resource(BenchScenePath(std::env::temp_dir().join(format!(
+            "sandbox_bench_{}_{}.scn.ron",
+            std::process::id(),
+            count
+        ))));
+    let world = app.world_mut();
+    for index in 0..count {
+        world.spawn((
+            Name::new(format!("Entity {}", index)),
+            Transform::from_translation(grid_position(index).extend(0.0)),
+            Sprite::from_color(Color::WHITE, Vec2::splat(32.0)),
+            Collider::rectangle(Vec2::splat(32.0)),
+        ));
+    }
+    app
+}
+
+fn spawn_animated_sprites(world: &mut World, count: usize) {
+    let frames: Vec<AnimationFrame> = (0..4)
+        .map(|index| AnimationFrame {
+            rect: Rect::new(index as f32 * 16.0, 0.0, (index + 1) as f32 * 16.0, 16.0),
+            // Varied durations so frames advance on different ticks
+            duration: TICK.as_secs_f32() * (1 + index % 3) as f32,
+        })
+        .collect();
+    for index in 0..count {
+        let mut animation = SpriteAnimation::new(frames.clone());
+        animation.current_frame = index % frames.len();
+        animation.play();
+        world.spawn((
+            Sprite::default(),
+            animation,
+            Transform::from_translation(grid_position(index).extend(0.0)),
+        ));
+    }
+}
+
+/// An app with `count` colliders on a grid, some overlapping their neighbours.
+fn contacts_app(count: usize) -> App {
+    let mut app = headless_app();
+    app.add_plugins(PhysicsPlugin);
+    let world = app.world_mut();
+    for index in 0..count {
+        world.spawn((
+            Transform::from_translation(grid_position(index).extend(0.0)),
+            Collider::circle(if index % 2 == 0 { 20.0 } else { 12.0 }),
+        ));
+    }
+    warm_up(&mut app);
+    app
+}
+
+/// An app with `count` bodies moving in different directions, without colliders.
+fn integrate_app(count: usize) -> App {
+    let mut app = headless_app();
+    app.add_plugins(PhysicsPlugin);
+    let world = app.world_mut();
+    for index in 0..count {
+        world.spawn((
+            Transform::from_translation(grid_position(index).extend(0.0)),
+            LinearVelocity(Vec2::from_angle(index as f32) * 50.0),
+        ));
+    }
+    warm_up(&mut app);
+    app
+}
+
+/// Live particles per emitter in the particle bench.
+const PARTICLES_PER_EMITTER: usize = 100;
+
+/// An app with about `count` live particles, spawned and expiring at a
+/// steady rate by emitters of `PARTICLES_PER_EMITTER` each.
+fn particles_app(count: usize) -> App {
+    let mut app = headless_app();
+    app.add_plugins(ParticlePlugin);
+    let lifetime = 1.0;
+    let world = app.world_mut();
+    for index in 0..count.div_ceil(PARTICLES_PER_EMITTER) {
+        world.spawn((
+            ParticleEmitter {
+                shape: EmitterShape::Circle { radius: 16.0 },
+                rate: PARTICLES_PER_EMITTER as f32 / lifetime,
+                lifetime,
+                spread: 180.0,
+                acceleration: Vec2::new(0.0, -20.0),
+                max_particles: PARTICLES_PER_EMITTER as u32,
+                ..default()
+            },
+            Transform::from_translation(grid_position(index).extend(0.0)),
+        ));
+    }
+    warm_up(&mut app);
+    // Run for a lifetime so particles expire as fast as they are spawned
+    for _ in 0..(lifetime / TICK.as_secs_f32()).ceil() as usize {
+        app.update();
+    }
+    app
+}
+
+/// Position of the `index`th entity on a square grid with 32-unit spacing.
+fn grid_position(index: usize) -> Vec2 {
+    const COLUMNS: usize = 100;
+    Vec2::new((index % COLUMNS) as f32, (index / COLUMNS) as f32) * 32.0
+}
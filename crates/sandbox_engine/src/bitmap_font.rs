@@ -0,0 +1,497 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Crisp pixel-art text from BMFont bitmap fonts.
+//!
+//! A `BitmapFont` is an asset read by `BitmapFontLoader` from a BMFont text
+//! descriptor (`.fnt`, as written by AngelCode's BMFont, Hiero, and most
+//! pixel font tools) next to its page textures. `BitmapText` names the font
+//! by its path, so it saves with the scene like any other asset reference,
+//! and is drawn once the font has loaded, as one sprite per glyph, cut from
+//! the pages with nearest-neighbor sampling. Nothing goes
+//! through Bevy's TTF text pipeline, so glyphs stay pixel-exact at whole
+//! number scales.
+//!
+//! The glyph sprites are runtime-only children of the text: they are never
+//! saved, aren't listed in the editor's hierarchy, and are rebuilt whenever
+//! the text, its settings, or the font file change.
+//!
+//! # Example
+//! ```ignore
+//! commands.spawn((
+//!     BitmapText::new("fonts/retro.fnt", "SCORE 0100").with_scale(2.0),
+//!     Transform::from_xyz(0.0, 200.0, 10.0),
+//! ));
+//! ```
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::image::{ImageLoaderSettings, ImageSampler};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::inspector::InspectorOptions;
+use crate::project::ProjectSettings;
+use crate::scene::SceneExcluded;
+
+/// Plugin that loads bitmap fonts and lays out `BitmapText`.
+///
+/// Must be added after `AssetPlugin` (e.g. after `DefaultPlugins`).
+pub struct BitmapFontPlugin;
+
+impl Plugin for BitmapFontPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<BitmapText>()
+            .register_type::<BitmapTextAlign>()
+            .init_asset::<BitmapFont>()
+            .register_asset_loader(BitmapFontLoader)
+            .add_systems(Update, layout_bitmap_text);
+    }
+}
+
+/// Errors that can occur reading a bitmap font.
+#[derive(Debug)]
+pub enum BitmapFontError {
+    /// Failed to read the `.fnt` file.
+    Io(std::io::Error),
+    /// The file isn't a valid BMFont text descriptor.
+    Invalid(String),
+}
+
+impl std::fmt::Display for BitmapFontError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitmapFontError::Io(e) => write!(f, "Failed to read bitmap font: {}", e),
+            BitmapFontError::Invalid(e) => write!(f, "Invalid bitmap font: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for BitmapFontError {}
+
+/// Loads `.fnt` files as [`BitmapFont`]s.
+#[derive(Default)]
+pub struct BitmapFontLoader;
+
+impl AssetLoader for BitmapFontLoader {
+    type Asset = BitmapFont;
+    type Settings = ();
+    type Error = BitmapFontError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<BitmapFont, BitmapFontError> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(BitmapFontError::Io)?;
+        let data = std::str::from_utf8(&bytes)
+            .map_err(|e| BitmapFontError::Invalid(format!("not UTF-8 text: {}", e)))?;
+        BitmapFont::parse(data)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["fnt"]
+    }
+}
+
+/// One character of a bitmap font.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FontGlyph {
+    /// Area of the page holding the glyph, in pixels.
+    pub rect: Rect,
+    /// Offset of the glyph's top-left corner from the pen, in pixels, with Y down.
+    pub offset: Vec2,
+    /// How far the pen moves after the glyph, in pixels.
+    pub advance: f32,
+    /// Index of the page texture holding the glyph.
+    pub page: usize,
+}
+
+/// A bitmap font read from a BMFont `.fnt` file.
+#[derive(Asset, TypePath, Clone, Debug, Default, PartialEq)]
+pub struct BitmapFont {
+    /// Name of the font face.
+    pub face: String,
+    /// Distance between lines, in pixels.
+    pub line_height: f32,
+    /// Distance from the top of a line to the baseline, in pixels.
+    pub base: f32,
+    /// Page texture files, relative to the `.fnt` file.
+    pub pages: Vec<String>,
+    /// Glyphs by character.
+    pub glyphs: HashMap<char, FontGlyph>,
+    /// Pen adjustments between pairs of characters, in pixels.
+    pub kerning: HashMap<(char, char), f32>,
+}
+
+/// A glyph positioned by [`BitmapFont::layout`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlacedGlyph {
+    /// Character the glyph draws.
+    pub character: char,
+    /// The glyph drawn.
+    pub glyph: FontGlyph,
+    /// Top-left corner of the glyph relative to the top-left of the text, in
+    /// pixels with Y down.
+    pub position: Vec2,
+    /// Line the glyph is on, counting from 0.
+    pub line: usize,
+}
+
+/// Glyphs of a laid out text, with the size of each line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextLayout {
+    /// Glyphs in text order; spaces, and characters the font lacks when it
+    /// has no `?`, have none.
+    pub glyphs: Vec<PlacedGlyph>,
+    /// Width of each line, in pixels.
+    pub line_widths: Vec<f32>,
+    /// Height of the whole text, in pixels.
+    pub height: f32,
+}
+
+impl BitmapFont {
+    /// Parses a BMFont text descriptor (`info`, `common`, `page`, `char`,
+    /// and `kerning` lines; others are ignored).
+    pub fn parse(data: &str) -> Result<Self, BitmapFontError> {
+        let mut font = BitmapFont::default();
+        let mut pages: Vec<(usize, String)> = Vec::new();
+        for (index, line) in data.lines().enumerate() {
+            let (tag, fields) = parse_line(line);
+            let error = |message: String| {
+                BitmapFontError::Invalid(format!("line {}: {}", index + 1, message))
+            };
+            let number = |key: &str| -> Result<f32, BitmapFontError> {
+                let value = fields
+                    .get(key)
+                    .ok_or_else(|| error(format!("'{}' is missing '{}'", tag, key)))?;
+                value
+                    .parse()
+                    .map_err(|_| error(format!("'{}' is not a number", value)))
+            };
+            let character = |key: &str| -> Result<char, BitmapFontError> {
+                let code = number(key)?;
+                char::from_u32(code as u32)
+                    .ok_or_else(|| error(format!("{} is not a character", code)))
+            };
+            match tag {
+                "info" => {
+                    font.face = fields.get("face").cloned().unwrap_or_default();
+                }
+                "common" => {
+                    font.line_height = number("lineHeight")?;
+                    font.base = number("base")?;
+                }
+                "page" => {
+                    let file = fields
+                        .get("file")
+                        .ok_or_else(|| error("'page' is missing 'file'".to_string()))?;
+                    pages.push((number("id")? as usize, file.clone()));
+                }
+                "char" => {
+                    let glyph = FontGlyph {
+                        rect: Rect::new(
+                            number("x")?,
+                            number("y")?,
+                            number("x")? + number("width")?,
+                            number("y")? + number("height")?,
+                        ),
+                        offset: Vec2::new(number("xoffset")?, number("yoffset")?),
+                        advance: number("xadvance")?,
+                        page: number("page")? as usize,
+                    };
+                    font.glyphs.insert(character("id")?, glyph);
+                }
+                "kerning" => {
+                    let pair = (character("first")?, character("second")?);
+                    font.kerning.insert(pair, number("amount")?);
+                }
+                _ => {}
+            }
+        }
+        if font.glyphs.is_empty() {
+            return Err(BitmapFontError::Invalid(
+                "the font has no characters".to_string(),
+            ));
+        }
+        pages.sort_by_key(|(id, _)| *id);
+        font.pages = pages.into_iter().map(|(_, file)| file).collect();
+        Ok(font)
+    }
+
+    /// The glyph drawn for `character`: the character's own, else `?`.
+    pub fn glyph(&self, character: char) -> Option<&FontGlyph> {
+        self.glyphs
+            .get(&character)
+            .or_else(|| self.glyphs.get(&'?'))
+    }
+
+    /// Places the glyphs of `text`, breaking lines at `\n` and adding
+    /// `letter_spacing` pixels between characters.
+    pub fn layout(&self, text: &str, letter_spacing: f32) -> TextLayout {
+        let mut layout = TextLayout::default();
+        for (line, text) in text.split('\n').enumerate() {
+            let top = line as f32 * self.line_height;
+            let mut pen = 0.0;
+            let mut previous = None;
+            for character in text.chars() {
+                if let Some(previous) = previous {
+                    pen += self
+                        .kerning
+                        .get(&(previous, character))
+                        .copied()
+                        .unwrap_or(0.0)
+                        + letter_spacing;
+                }
+                previous = Some(character);
+                let Some(glyph) = self.glyph(character) else {
+                    continue;
+                };
+                if glyph.rect.width() > 0.0 && glyph.rect.height() > 0.0 {
+                    layout.glyphs.push(PlacedGlyph {
+                        character,
+                        glyph: *glyph,
+                        position: Vec2::new(pen, top) + glyph.offset,
+                        line,
+                    });
+                }
+                pen += glyph.advance;
+            }
+            layout.line_widths.push(pen);
+        }
+        layout.height = layout.line_widths.len() as f32 * self.line_height;
+        layout
+    }
+}
+
+/// Splits a descriptor line into its tag and `key=value` fields, unquoting
+/// quoted values (which may contain spaces).
+fn parse_line(line: &str) -> (&str, HashMap<String, String>) {
+    let line = line.trim();
+    let (tag, mut rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let mut fields = HashMap::new();
+    loop {
+        rest = rest.trim_start();
+        let Some((key, after)) = rest.split_once('=') else {
+            break;
+        };
+        let (value, after) = match after.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"').unwrap_or((quoted, "")),
+            None => after.split_once(char::is_whitespace).unwrap_or((after, "")),
+        };
+        fields.insert(key.trim().to_string(), value.to_string());
+        rest = after;
+    }
+    (tag, fields)
+}
+
+/// How the lines of a `BitmapText` line up with its origin.
+#[derive(Reflect, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BitmapTextAlign {
+    /// Lines start at the origin.
+    Left,
+    /// Lines are centered on the origin.
+    #[default]
+    Center,
+    /// Lines end at the origin.
+    Right,
+}
+
+/// Text drawn with a bitmap font.
+///
+/// The text is centered vertically on the entity, and each line is placed
+/// by `align`.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+#[require(Transform, Visibility, BitmapTextGlyphs)]
+pub struct BitmapText {
+    /// `.fnt` file, relative to the assets directory.
+    pub font: String,
+    /// Text to draw; `\n` starts a new line.
+    pub text: String,
+    /// Color multiplied into the glyphs.
+    pub color: Color,
+    /// Size of a font pixel, in texture pixels: glyphs are `scale` divided by
+    /// the project's `pixels_per_unit` world units per font pixel, so at 1
+    /// they match the pixels of auto-sized sprites.
+    #[reflect(@InspectorOptions::min(0.0).with_step(1.0).with_tooltip("Whole numbers stay crisp"))]
+    pub scale: f32,
+    /// How lines line up with the origin.
+    pub align: BitmapTextAlign,
+    /// Extra font pixels between characters.
+    #[reflect(@InspectorOptions::tooltip("Negative values squeeze letters together").with_step(1.0))]
+    pub letter_spacing: f32,
+}
+
+impl Default for BitmapText {
+    fn default() -> Self {
+        Self {
+            font: String::new(),
+            text: String::new(),
+            color: Color::WHITE,
+            scale: 1.0,
+            align: BitmapTextAlign::Center,
+            letter_spacing: 0.0,
+        }
+    }
+}
+
+impl BitmapText {
+    /// Draws `text` with the font at `font`.
+    pub fn new(font: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            font: font.into(),
+            text: text.into(),
+            ..default()
+        }
+    }
+
+    /// Sets the size of a font pixel, in texture pixels.
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the glyph color.
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Sets how lines line up with the origin.
+    pub fn with_align(mut self, align: BitmapTextAlign) -> Self {
+        self.align = align;
+        self
+    }
+}
+
+/// A glyph sprite, spawned as a child of its `BitmapText`.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[require(SceneExcluded)]
+pub struct BitmapGlyph {
+    /// Character the glyph draws.
+    pub character: char,
+}
+
+/// The font a `BitmapText` uses and the glyph sprites it last spawned.
+#[derive(Component, Default)]
+struct BitmapTextGlyphs {
+    font: Handle<BitmapFont>,
+    entities: Vec<Entity>,
+    /// Whether the glyphs wait for the font to load.
+    pending: bool,
+}
+
+/// Asset path of a font page, relative to the assets directory.
+fn page_path(font: &str, page: &str) -> String {
+    match font.rsplit_once('/') {
+        Some((dir, _)) => format!("{}/{}", dir, page),
+        None => page.to_string(),
+    }
+}
+
+/// Respawns the glyph sprites of texts that changed, whose glyphs are gone,
+/// or whose font was edited, once the font has loaded.
+fn layout_bitmap_text(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Option<Res<ProjectSettings>>,
+    fonts: Res<Assets<BitmapFont>>,
+    mut font_events: EventReader<AssetEvent<BitmapFont>>,
+    mut texts: Query<(Entity, Ref<BitmapText>, &mut BitmapTextGlyphs)>,
+    existing: Query<(), With<BitmapGlyph>>,
+) {
+    let modified: Vec<AssetId<BitmapFont>> = font_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+    let pixels_per_unit = settings.map_or(1.0, |settings| settings.pixels_per_unit);
+    for (entity, text, mut glyphs) in &mut texts {
+        // Glyphs go missing when the editor stops after they were rebuilt in play
+        let missing = glyphs
+            .entities
+            .iter()
+            .any(|&glyph| !existing.contains(glyph));
+        if text.is_changed() || missing || modified.contains(&glyphs.font.id()) {
+            for glyph in glyphs.entities.drain(..) {
+                if let Some(glyph) = commands.get_entity(glyph) {
+                    glyph.despawn_recursive();
+                }
+            }
+            glyphs.font = if text.font.is_empty() {
+                Handle::default()
+            } else {
+                asset_server.load(&text.font)
+            };
+            glyphs.pending = !text.font.is_empty();
+        }
+        if !glyphs.pending {
+            continue;
+        }
+        let Some(font) = fonts.get(&glyphs.font) else {
+            // The asset server logs fonts that fail to load
+            if asset_server.load_state(&glyphs.font).is_failed() {
+                glyphs.pending = false;
+            }
+            continue;
+        };
+        glyphs.pending = false;
+
+        let layout = font.layout(&text.text, text.letter_spacing);
+        let pages: Vec<Handle<Image>> = font
+            .pages
+            .iter()
+            .map(|page| {
+                asset_server.load_with_settings(
+                    page_path(&text.font, page),
+                    |settings: &mut ImageLoaderSettings| {
+                        settings.sampler = ImageSampler::nearest();
+                    },
+                )
+            })
+            .collect();
+        let scale = text.scale / pixels_per_unit;
+        commands.entity(entity).with_children(|parent| {
+            for placed in &layout.glyphs {
+                let Some(page) = pages.get(placed.glyph.page) else {
+                    continue;
+                };
+                let line_width = layout.line_widths[placed.line];
+                let line_start = match text.align {
+                    BitmapTextAlign::Left => 0.0,
+                    BitmapTextAlign::Center => -line_width / 2.0,
+                    BitmapTextAlign::Right => -line_width,
+                };
+                let size = placed.glyph.rect.size();
+                let center = Vec2::new(
+                    line_start + placed.position.x + size.x / 2.0,
+                    layout.height / 2.0 - placed.position.y - size.y / 2.0,
+                );
+                let glyph = parent
+                    .spawn((
+                        BitmapGlyph {
+                            character: placed.character,
+                        },
+                        Sprite {
+                            image: page.clone(),
+                            rect: Some(placed.glyph.rect),
+                            custom_size: Some(size * scale),
+                            color: text.color,
+                            ..default()
+                        },
+                        Transform::from_translation((center * scale).extend(0.0)),
+                    ))
+                    .id();
+                glyphs.entities.push(glyph);
+            }
+        });
+    }
+}
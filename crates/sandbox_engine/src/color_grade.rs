@@ -0,0 +1,150 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Color grading by entity group.
+//!
+//! A `ColorGroup` component puts a sprite in a named group ("background",
+//! "hazards"). The project's `ColorGrades` (in `ProjectSettings`, saved in
+//! the project file) give each group a tint and a brightness, so a whole
+//! category can be recolored at once while tuning. Groups without a grade
+//! keep their colors.
+//!
+//! Like `GlobalTint`, grades are applied as sprites are handed to the
+//! renderer: `Sprite::color` and saved scenes never change, and edits to the
+//! table show up on the next frame, during play too.
+//!
+//! # Example
+//! ```ignore
+//! // Sandbox.ron
+//! (
+//!     color_grades: (
+//!         groups: {
+//!             "background": (tint: Srgba((red: 0.6, green: 0.7, blue: 1.0, alpha: 1.0)), brightness: 0.8),
+//!             "hazards": (tint: Srgba((red: 1.0, green: 0.4, blue: 0.4, alpha: 1.0)), brightness: 1.2),
+//!         },
+//!     ),
+//! )
+//!
+//! commands.spawn((Sprite::from_image(nebula), ColorGroup::new("background")));
+//! ```
+
+use bevy::prelude::*;
+use bevy::render::{Extract, RenderApp};
+use bevy::sprite::{ExtractedSprites, SpriteSystem};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::inspector::InspectorOptions;
+use crate::project::{init_project_settings, ProjectSettings};
+use crate::tint::multiply_tint;
+
+/// Plugin that registers `ColorGroup` and grades sprites by their group.
+pub struct ColorGradePlugin;
+
+impl Plugin for ColorGradePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ColorGroup>();
+        init_project_settings(app);
+
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app.add_systems(
+                ExtractSchedule,
+                grade_extracted_sprites
+                    .after(SpriteSystem::ExtractSprites)
+                    .after(bevy::text::extract_text2d_sprite),
+            );
+        }
+    }
+}
+
+/// The color group a sprite (or 2D text) belongs to.
+#[derive(Component, Reflect, Default, Clone, Debug, PartialEq, Eq, Hash)]
+#[reflect(Component, Default)]
+pub struct ColorGroup(
+    #[reflect(@InspectorOptions::tooltip("Grades for each group are set in Window › Color Grades"))]
+    pub String,
+);
+
+impl ColorGroup {
+    /// Puts the entity in the group `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+}
+
+/// Color adjustment for one group.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColorGrade {
+    /// Color the group's sprites are multiplied by.
+    pub tint: Color,
+    /// Multiplier on the tinted color; 1 leaves it alone.
+    pub brightness: f32,
+}
+
+impl Default for ColorGrade {
+    fn default() -> Self {
+        Self {
+            tint: Color::WHITE,
+            brightness: 1.0,
+        }
+    }
+}
+
+impl ColorGrade {
+    /// Whether the grade leaves colors unchanged.
+    pub fn is_identity(&self) -> bool {
+        self.tint == Color::WHITE && self.brightness == 1.0
+    }
+
+    /// `color` with the grade applied, keeping its alpha.
+    pub fn apply(&self, color: LinearRgba) -> LinearRgba {
+        let tint = self.tint.to_linear();
+        let factor = LinearRgba::new(
+            tint.red * self.brightness,
+            tint.green * self.brightness,
+            tint.blue * self.brightness,
+            1.0,
+        );
+        multiply_tint(color, factor)
+    }
+}
+
+/// Project-wide grades for each color group.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColorGrades {
+    /// Grades by group name. Groups not listed keep their colors.
+    pub groups: BTreeMap<String, ColorGrade>,
+}
+
+impl ColorGrades {
+    /// The grade of `group`, if it has one.
+    pub fn grade(&self, group: &str) -> Option<&ColorGrade> {
+        self.groups.get(group)
+    }
+}
+
+/// Applies each grouped sprite's grade to its color as it is sent to the renderer.
+fn grade_extracted_sprites(
+    settings: Extract<Option<Res<ProjectSettings>>>,
+    groups: Extract<Query<&ColorGroup>>,
+    mut sprites: ResMut<ExtractedSprites>,
+) {
+    let Some(settings) = settings.as_ref() else {
+        return;
+    };
+    let grades = &settings.color_grades;
+    if grades.groups.values().all(ColorGrade::is_identity) {
+        return;
+    }
+    for ((_, main_entity), sprite) in sprites.sprites.iter_mut() {
+        let entity = sprite.original_entity.unwrap_or(main_entity.id());
+        let Ok(group) = groups.get(entity) else {
+            continue;
+        };
+        if let Some(grade) = grades.grade(&group.0) {
+            sprite.color = grade.apply(sprite.color);
+        }
+    }
+}
@@ -0,0 +1,248 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Entities that break apart as they take damage.
+//!
+//! A `Destructible` has health and a list of stages. When its health drops to
+//! a stage's threshold, the stage swaps the entity's sprite and spawns debris
+//! prefabs around it. At zero health the entity is despawned. Every stage
+//! reached and the final destruction send a `DestructionEvent`.
+//!
+//! # Example
+//! ```ignore
+//! // An asteroid that cracks at half health and breaks into three chunks
+//! commands.spawn((
+//!     Destructible::new(40.0)
+//!         .with_stage(DestructionStage::new(20.0).with_sprite("textures/asteroid_cracked.png"))
+//!         .with_stage(DestructionStage::new(0.0).with_debris("prefabs/chunk.scn.ron", 3)),
+//!     Transform::default(),
+//! ));
+//!
+//! fn mine(mut asteroids: Query<&mut Destructible>) {
+//!     for mut asteroid in &mut asteroids {
+//!         asteroid.damage(5.0);
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+use std::path::PathBuf;
+
+use crate::assets::AssetPath;
+use crate::editor_state::GameplaySystemSet;
+use crate::inspector::InspectorOptions;
+use crate::project::ProjectRoot;
+use crate::scene::instantiate_prefab;
+use crate::system_toggle::ToggleableSystem;
+
+/// Plugin that advances destructibles through their stages.
+pub struct DestructiblePlugin;
+
+impl Plugin for DestructiblePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Destructible>()
+            .register_type::<DestructionStage>()
+            .add_event::<DestructionEvent>()
+            .add_systems(
+                Update,
+                update_destructibles.toggleable().in_set(GameplaySystemSet),
+            );
+    }
+}
+
+/// One step in breaking a destructible apart.
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(Default)]
+pub struct DestructionStage {
+    /// The stage is reached when health drops to this value or below.
+    #[reflect(@InspectorOptions::min(0.0))]
+    pub health_threshold: f32,
+    /// Image shown from this stage on, relative to the assets directory;
+    /// empty keeps the current sprite.
+    pub sprite: String,
+    /// Prefab spawned as debris, relative to the assets directory; empty
+    /// spawns none.
+    pub debris_prefab: String,
+    /// Number of debris prefabs spawned.
+    pub debris_count: u32,
+    /// Distance from the entity at which debris is spread out.
+    #[reflect(@InspectorOptions::min(0.0))]
+    pub debris_spread: f32,
+}
+
+impl Default for DestructionStage {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+impl DestructionStage {
+    /// A stage reached at `health_threshold` that changes nothing yet.
+    pub fn new(health_threshold: f32) -> Self {
+        Self {
+            health_threshold,
+            sprite: String::new(),
+            debris_prefab: String::new(),
+            debris_count: 1,
+            debris_spread: 20.0,
+        }
+    }
+
+    /// Swaps the sprite's image when the stage is reached.
+    pub fn with_sprite(mut self, path: impl Into<String>) -> Self {
+        self.sprite = path.into();
+        self
+    }
+
+    /// Spawns `count` copies of a prefab when the stage is reached.
+    pub fn with_debris(mut self, prefab: impl Into<String>, count: u32) -> Self {
+        self.debris_prefab = prefab.into();
+        self.debris_count = count;
+        self
+    }
+}
+
+/// Health with stages that change the entity as it drops.
+///
+/// Stages are reached in list order, so list them from the highest threshold
+/// down. The entity is despawned, with its children, once health reaches zero.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+#[require(Transform)]
+pub struct Destructible {
+    /// Health at full strength.
+    #[reflect(@InspectorOptions::min(0.0))]
+    pub max_health: f32,
+    /// Current health.
+    #[reflect(@InspectorOptions::min(0.0))]
+    pub health: f32,
+    /// Stages, from the highest threshold down.
+    pub stages: Vec<DestructionStage>,
+    /// Number of stages already reached.
+    pub reached: usize,
+}
+
+impl Default for Destructible {
+    fn default() -> Self {
+        Self::new(100.0)
+    }
+}
+
+impl Destructible {
+    /// A destructible at full `health`, with no stages.
+    pub fn new(health: f32) -> Self {
+        Self {
+            max_health: health,
+            health,
+            stages: Vec::new(),
+            reached: 0,
+        }
+    }
+
+    /// Adds a stage after the existing ones.
+    pub fn with_stage(mut self, stage: DestructionStage) -> Self {
+        self.stages.push(stage);
+        self
+    }
+
+    /// Lowers health by `amount`, stopping at zero.
+    pub fn damage(&mut self, amount: f32) {
+        self.health = (self.health - amount).max(0.0);
+    }
+
+    /// Whether health has reached zero.
+    pub fn is_destroyed(&self) -> bool {
+        self.health <= 0.0
+    }
+}
+
+/// What happened to a destructible.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DestructionEventKind {
+    /// The stage at this index was reached.
+    StageReached(usize),
+    /// Health reached zero and the entity was despawned.
+    Destroyed,
+}
+
+/// Sent when a destructible reaches a stage or is destroyed.
+#[derive(Event, Clone, Debug)]
+pub struct DestructionEvent {
+    /// The destructible entity; already despawned for `Destroyed`.
+    pub entity: Entity,
+    /// What happened.
+    pub kind: DestructionEventKind,
+    /// World position of the entity at the time.
+    pub position: Vec2,
+}
+
+fn update_destructibles(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Destructible, &GlobalTransform), Changed<Destructible>>,
+    mut events: EventWriter<DestructionEvent>,
+) {
+    for (entity, mut destructible, transform) in &mut query {
+        let position = transform.translation().truncate();
+        while let Some(stage) = destructible
+            .stages
+            .get(destructible.reached)
+            .filter(|stage| destructible.health <= stage.health_threshold)
+            .cloned()
+        {
+            let index = destructible.reached;
+            destructible.reached += 1;
+            if !stage.sprite.is_empty() {
+                commands
+                    .entity(entity)
+                    .insert(AssetPath::new(stage.sprite.clone()));
+            }
+            if !stage.debris_prefab.is_empty() && stage.debris_count > 0 {
+                commands.queue(move |world: &mut World| spawn_debris(world, &stage, position));
+            }
+            events.send(DestructionEvent {
+                entity,
+                kind: DestructionEventKind::StageReached(index),
+                position,
+            });
+        }
+
+        if destructible.is_destroyed() {
+            commands.entity(entity).despawn_recursive();
+            events.send(DestructionEvent {
+                entity,
+                kind: DestructionEventKind::Destroyed,
+                position,
+            });
+        }
+    }
+}
+
+/// Spawns a stage's debris prefabs evenly around `position`.
+fn spawn_debris(world: &mut World, stage: &DestructionStage, position: Vec2) {
+    let path = world.get_resource::<ProjectRoot>().map_or_else(
+        || PathBuf::from(&stage.debris_prefab),
+        |root| root.resolve_asset(&stage.debris_prefab),
+    );
+    for index in 0..stage.debris_count {
+        let spawned = match instantiate_prefab(world, &path) {
+            Ok(spawned) => spawned,
+            Err(e) => {
+                warn!("Failed to spawn debris {}: {}", stage.debris_prefab, e);
+                return;
+            }
+        };
+        let angle = TAU * index as f32 / stage.debris_count as f32;
+        let offset = position + Vec2::from_angle(angle) * stage.debris_spread;
+        // Children stay where their parents put them
+        for entity in spawned {
+            if world.get::<Parent>(entity).is_some() {
+                continue;
+            }
+            if let Some(mut transform) = world.get_mut::<Transform>(entity) {
+                transform.translation.x += offset.x;
+                transform.translation.y += offset.y;
+            }
+        }
+    }
+}
@@ -0,0 +1,354 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Simulation determinism checking.
+//!
+//! Record keyboard input with `InputRecorderPlugin`, then feed the recording
+//! to `run_replay` on a freshly built app. Each tick the serializable world
+//! state (the components a scene save would write) is hashed per entity and
+//! component into a `ReplayLog`. Comparing two logs with `compare_logs`, from
+//! two runs or from two machines via `ReplayLog::save`, reports the first tick
+//! and component that diverged.
+//!
+//! # Example
+//! ```ignore
+//! let replay = InputReplay::load(Path::new("replays/level1.ron"))?;
+//! match check_determinism(build_game_app, &replay) {
+//!     Ok(log) => println!("{} ticks matched", log.ticks.len()),
+//!     Err(divergence) => panic!("{divergence}"),
+//! }
+//! ```
+
+use bevy::app::PluginsState;
+use bevy::prelude::*;
+use bevy::reflect::serde::ReflectSerializer;
+use bevy::scene::DynamicSceneBuilder;
+use bevy::time::TimeUpdateStrategy;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::scene::should_serialize_entity;
+
+/// Keys held down during one tick.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct InputFrame {
+    pub pressed: Vec<KeyCode>,
+}
+
+/// A recorded input sequence replayed at a fixed tick rate.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InputReplay {
+    /// Duration of each tick in seconds.
+    pub tick_seconds: f32,
+    /// Input for each tick, in order.
+    pub frames: Vec<InputFrame>,
+}
+
+impl Default for InputReplay {
+    fn default() -> Self {
+        Self {
+            tick_seconds: 1.0 / 60.0,
+            frames: Vec::new(),
+        }
+    }
+}
+
+impl InputReplay {
+    /// Reads a replay from a RON file.
+    pub fn load(path: &Path) -> Result<Self, ReplayError> {
+        read_ron(path)
+    }
+
+    /// Writes the replay to a RON file.
+    pub fn save(&self, path: &Path) -> Result<(), ReplayError> {
+        write_ron(self, path)
+    }
+}
+
+/// Hash of one component on one entity.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ComponentHash {
+    /// Entity bits (`Entity::to_bits`), stable across identical runs.
+    pub entity: u64,
+    /// Type path of the component.
+    pub component: String,
+    pub hash: u64,
+}
+
+/// Hashes of the world state after one tick.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TickHash {
+    pub tick: usize,
+    /// Combined hash of all components.
+    pub hash: u64,
+    /// Per-component hashes, sorted by entity then type path.
+    pub components: Vec<ComponentHash>,
+}
+
+/// World state hashes for every tick of a replay run.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReplayLog {
+    pub ticks: Vec<TickHash>,
+}
+
+impl ReplayLog {
+    /// Reads a log from a RON file, e.g. one produced on another platform.
+    pub fn load(path: &Path) -> Result<Self, ReplayError> {
+        read_ron(path)
+    }
+
+    /// Writes the log to a RON file.
+    pub fn save(&self, path: &Path) -> Result<(), ReplayError> {
+        write_ron(self, path)
+    }
+}
+
+/// The first point where two replay runs disagree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Divergence {
+    pub tick: usize,
+    /// Entity bits of the differing component, if the difference is attributable.
+    pub entity: Option<u64>,
+    /// Type path of the differing component, if the difference is attributable.
+    pub component: Option<String>,
+}
+
+impl std::fmt::Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Simulation diverged at tick {}", self.tick)?;
+        match (self.entity, &self.component) {
+            (Some(entity), Some(component)) => match Entity::try_from_bits(entity) {
+                Ok(entity) => write!(f, " in {} on entity {}", component, entity),
+                Err(_) => write!(f, " in {} on entity bits {:#x}", component, entity),
+            },
+            _ => write!(f, " (runs have different lengths)"),
+        }
+    }
+}
+
+/// Errors that can occur reading or writing replay files.
+#[derive(Debug)]
+pub enum ReplayError {
+    /// Failed to read or write the file.
+    Io(std::io::Error),
+    /// Failed to parse or serialize RON.
+    Ron(String),
+}
+
+impl std::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayError::Io(e) => write!(f, "Replay file error: {}", e),
+            ReplayError::Ron(e) => write!(f, "Invalid replay data: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// Plugin that records keyboard input into the `InputRecorder` resource.
+pub struct InputRecorderPlugin;
+
+impl Plugin for InputRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputRecorder>()
+            .add_systems(PreUpdate, record_input.after(bevy::input::InputSystem));
+    }
+}
+
+/// Resource holding the input recorded so far.
+#[derive(Resource, Default)]
+pub struct InputRecorder {
+    /// Whether input is currently being recorded.
+    pub recording: bool,
+    pub replay: InputReplay,
+}
+
+impl InputRecorder {
+    /// Clears any previous recording and starts a new one.
+    pub fn start(&mut self) {
+        self.replay.frames.clear();
+        self.recording = true;
+    }
+
+    /// Stops recording and returns the recorded replay.
+    pub fn stop(&mut self) -> InputReplay {
+        self.recording = false;
+        std::mem::take(&mut self.replay)
+    }
+}
+
+fn record_input(keyboard: Res<ButtonInput<KeyCode>>, mut recorder: ResMut<InputRecorder>) {
+    if !recorder.recording {
+        return;
+    }
+    let mut pressed: Vec<KeyCode> = keyboard.get_pressed().copied().collect();
+    pressed.sort_by_key(|key| format!("{:?}", key));
+    recorder.replay.frames.push(InputFrame { pressed });
+}
+
+/// Runs a replay on an app and hashes the world state after every tick.
+///
+/// Time advances by exactly `tick_seconds` per update. Input is written to
+/// `ButtonInput<KeyCode>` directly, so the app should not include
+/// `InputPlugin` (e.g. build it from `MinimalPlugins` plus gameplay plugins).
+pub fn run_replay(app: &mut App, replay: &InputReplay) -> ReplayLog {
+    if app.plugins_state() == PluginsState::Ready {
+        app.finish();
+        app.cleanup();
+    }
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        replay.tick_seconds,
+    )));
+    app.init_resource::<ButtonInput<KeyCode>>();
+
+    let mut log = ReplayLog::default();
+    for (tick, frame) in replay.frames.iter().enumerate() {
+        {
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.clear();
+            let released: Vec<KeyCode> = keyboard
+                .get_pressed()
+                .filter(|key| !frame.pressed.contains(key))
+                .copied()
+                .collect();
+            for key in released {
+                keyboard.release(key);
+            }
+            for &key in &frame.pressed {
+                keyboard.press(key);
+            }
+        }
+        app.update();
+        log.ticks.push(hash_world(app.world(), tick));
+    }
+    log
+}
+
+/// Builds two apps, runs the replay on each, and compares the results.
+///
+/// Returns the first run's log if both runs match.
+pub fn check_determinism(
+    build_app: impl Fn() -> App,
+    replay: &InputReplay,
+) -> Result<ReplayLog, Divergence> {
+    let first = run_replay(&mut build_app(), replay);
+    let second = run_replay(&mut build_app(), replay);
+    match compare_logs(&first, &second) {
+        Some(divergence) => Err(divergence),
+        None => Ok(first),
+    }
+}
+
+/// Finds the first tick and component at which two logs differ.
+pub fn compare_logs(a: &ReplayLog, b: &ReplayLog) -> Option<Divergence> {
+    for (tick_a, tick_b) in a.ticks.iter().zip(&b.ticks) {
+        if tick_a.hash == tick_b.hash {
+            continue;
+        }
+
+        let hashes_a = component_map(tick_a);
+        let hashes_b = component_map(tick_b);
+        let first_difference = hashes_a
+            .keys()
+            .chain(hashes_b.keys())
+            .filter(|key| hashes_a.get(*key) != hashes_b.get(*key))
+            .min()
+            .cloned();
+
+        let (entity, component) = match first_difference {
+            Some((entity, component)) => (Some(entity), Some(component.to_string())),
+            None => (None, None),
+        };
+        return Some(Divergence {
+            tick: tick_a.tick,
+            entity,
+            component,
+        });
+    }
+
+    (a.ticks.len() != b.ticks.len()).then(|| Divergence {
+        tick: a.ticks.len().min(b.ticks.len()),
+        entity: None,
+        component: None,
+    })
+}
+
+/// Hashes every serializable component in the world.
+///
+/// Uses the same entity filter as scene saving, and skips components that
+/// cannot be serialized through reflection.
+pub fn hash_world(world: &World, tick: usize) -> TickHash {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+
+    let entities: Vec<Entity> = world
+        .iter_entities()
+        .filter(|entity| should_serialize_entity(world, entity))
+        .map(|entity| entity.id())
+        .collect();
+    let scene = DynamicSceneBuilder::from_world(world)
+        .extract_entities(entities.into_iter())
+        .build();
+
+    let mut components = Vec::new();
+    for entity in &scene.entities {
+        for component in &entity.components {
+            let serializer = ReflectSerializer::new(component.as_ref(), &type_registry);
+            let Ok(serialized) = bevy::scene::ron::to_string(&serializer) else {
+                continue;
+            };
+            components.push(ComponentHash {
+                entity: entity.entity.to_bits(),
+                component: component.reflect_type_path().to_string(),
+                hash: fnv1a(serialized.as_bytes()),
+            });
+        }
+    }
+    components.sort_by(|a, b| (a.entity, &a.component).cmp(&(b.entity, &b.component)));
+
+    let mut combined = Vec::with_capacity(components.len() * 8);
+    for component in &components {
+        combined.extend_from_slice(&component.hash.to_le_bytes());
+    }
+
+    TickHash {
+        tick,
+        hash: fnv1a(&combined),
+        components,
+    }
+}
+
+fn component_map(tick: &TickHash) -> BTreeMap<(u64, &str), u64> {
+    tick.components
+        .iter()
+        .map(|component| {
+            (
+                (component.entity, component.component.as_str()),
+                component.hash,
+            )
+        })
+        .collect()
+}
+
+/// 64-bit FNV-1a, chosen because its output is identical on every platform and Rust version.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+fn read_ron<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T, ReplayError> {
+    let data = std::fs::read_to_string(path).map_err(ReplayError::Io)?;
+    bevy::scene::ron::from_str(&data).map_err(|e| ReplayError::Ron(e.to_string()))
+}
+
+fn write_ron<T: Serialize>(value: &T, path: &Path) -> Result<(), ReplayError> {
+    let data = bevy::scene::ron::ser::to_string_pretty(value, Default::default())
+        .map_err(|e| ReplayError::Ron(e.to_string()))?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(ReplayError::Io)?;
+    }
+    std::fs::write(path, data).map_err(ReplayError::Io)
+}
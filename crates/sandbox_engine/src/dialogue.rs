@@ -0,0 +1,564 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Branching conversations scripted in data files.
+//!
+//! A `Dialogue` is a set of named nodes, in the spirit of Yarn Spinner. Each
+//! node has lines of text, then either choices for the player or the node to
+//! continue with. Lines and choices can be gated on `WorldFlags` with a
+//! `FlagCondition`, and entering a node or picking a choice can change flags
+//! with `FlagChange`s. Text shows flag values with `{key}`.
+//!
+//! Dialogues are RON files, conventionally `assets/dialogue/*.dialogue.ron`:
+//! ```ron
+//! (
+//!     start: "greeting",
+//!     nodes: {
+//!         "greeting": (
+//!             lines: [
+//!                 (speaker: "Trader", text: "Welcome to Outpost 7."),
+//!                 (speaker: "Trader", text: "You've docked {times_docked} times.",
+//!                     condition: AtLeast("times_docked", 2.0)),
+//!             ],
+//!             choices: [
+//!                 (text: "Sell ore", next: Some("sell"), condition: AtLeast("ore", 1.0)),
+//!                 (text: "Leave"),
+//!             ],
+//!         ),
+//!         "sell": (
+//!             actions: [Add("credits", 10.0), Set("ore", Number(0.0))],
+//!             lines: [(speaker: "Trader", text: "Pleasure doing business.")],
+//!         ),
+//!     },
+//! )
+//! ```
+//!
+//! `DialogueRunner` steps through a dialogue on its own, e.g. for previews
+//! and tests. In a game, `DialogueTrigger` starts the dialogue when its
+//! `Interactable` is used, and the conversation runs in `ActiveDialogue`:
+//! the built-in dialogue box shows it (Enter continues, 1-9 choose), or games
+//! read `ActiveDialogue` to draw their own and turn the box off in
+//! `DialogueSettings`.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::editor_state::GameplaySystemSet;
+use crate::flags::{FlagChange, FlagCondition, WorldFlags};
+use crate::interaction::InteractEvent;
+use crate::project::ProjectRoot;
+use crate::scene::{SceneError, SceneResult};
+use crate::system_toggle::ToggleableSystem;
+
+/// Plugin that starts dialogues from triggers and runs the dialogue box.
+pub struct DialoguePlugin;
+
+impl Plugin for DialoguePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<DialogueTrigger>()
+            .init_resource::<WorldFlags>()
+            .init_resource::<ActiveDialogue>()
+            .init_resource::<DialogueSettings>()
+            .add_event::<DialogueEvent>()
+            .add_event::<InteractEvent>()
+            .add_systems(
+                Update,
+                (
+                    start_triggered_dialogues.toggleable(),
+                    dialogue_input.toggleable(),
+                    show_dialogue_box.toggleable(),
+                )
+                    .chain()
+                    .in_set(GameplaySystemSet),
+            );
+    }
+}
+
+/// A conversation: named nodes and the node it starts at.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Dialogue {
+    /// Node the conversation starts at.
+    pub start: String,
+    /// Nodes by name.
+    pub nodes: BTreeMap<String, DialogueNode>,
+}
+
+/// One step of a conversation.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DialogueNode {
+    /// Flag changes made when the node is entered.
+    pub actions: Vec<FlagChange>,
+    /// Lines shown in order; lines whose condition fails are skipped.
+    pub lines: Vec<DialogueLine>,
+    /// Choices offered after the lines; unavailable ones are hidden.
+    pub choices: Vec<DialogueChoice>,
+    /// Node to continue with when no choice is available; `None` ends the
+    /// conversation.
+    pub next: Option<String>,
+}
+
+/// A line of dialogue.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DialogueLine {
+    /// Who says it; empty for narration.
+    pub speaker: String,
+    /// What is said, with `{key}` replaced by the flag's value.
+    pub text: String,
+    /// The line is only shown if this holds.
+    pub condition: FlagCondition,
+}
+
+/// A reply the player can pick.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DialogueChoice {
+    /// Reply text, with `{key}` replaced by the flag's value.
+    pub text: String,
+    /// The choice is only offered if this holds.
+    pub condition: FlagCondition,
+    /// Flag changes made when the choice is picked.
+    pub actions: Vec<FlagChange>,
+    /// Node the choice leads to; `None` ends the conversation.
+    pub next: Option<String>,
+}
+
+impl Dialogue {
+    /// Reads a dialogue from a RON file.
+    pub fn load(path: &Path) -> SceneResult<Self> {
+        let data = std::fs::read_to_string(path).map_err(SceneError::FileRead)?;
+        bevy::scene::ron::from_str(&data).map_err(|e| SceneError::Deserialization(e.to_string()))
+    }
+
+    /// Describes references to nodes that don't exist, for authoring tools.
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if !self.nodes.contains_key(&self.start) {
+            problems.push(format!("Start node '{}' doesn't exist", self.start));
+        }
+        for (name, node) in &self.nodes {
+            let targets = node
+                .choices
+                .iter()
+                .filter_map(|choice| choice.next.as_ref())
+                .chain(&node.next);
+            for target in targets {
+                if !self.nodes.contains_key(target) {
+                    problems.push(format!("'{}' leads to missing node '{}'", name, target));
+                }
+            }
+        }
+        problems
+    }
+}
+
+/// Replaces `{key}` in `text` with the value of that flag; missing flags are blank.
+pub fn format_dialogue_text(text: &str, flags: &WorldFlags) -> String {
+    let mut formatted = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(open) = rest.find('{') {
+        let Some(close) = rest[open..].find('}') else {
+            break;
+        };
+        formatted.push_str(&rest[..open]);
+        let key = &rest[open + 1..open + close];
+        if let Some(value) = flags.get(key) {
+            formatted.push_str(&value.to_string());
+        }
+        rest = &rest[open + close + 1..];
+    }
+    formatted.push_str(rest);
+    formatted
+}
+
+/// What a running conversation is showing.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DialogueStep {
+    /// A line waiting to be continued.
+    Line {
+        /// Who says it; empty for narration.
+        speaker: String,
+        /// The line, with flags filled in.
+        text: String,
+    },
+    /// Choices waiting for the player, as (index in the node, text).
+    Choices(Vec<(usize, String)>),
+    /// The conversation is over.
+    End,
+}
+
+/// Steps through a dialogue, reading and changing world flags as it goes.
+#[derive(Clone, Debug)]
+pub struct DialogueRunner {
+    dialogue: Dialogue,
+    /// Current node, or `None` once ended.
+    node: Option<String>,
+    /// Index of the current line in the node.
+    line: usize,
+    step: DialogueStep,
+}
+
+impl DialogueRunner {
+    /// Starts `dialogue` at its start node.
+    pub fn new(dialogue: Dialogue, flags: &mut WorldFlags) -> Self {
+        let start = dialogue.start.clone();
+        Self::new_at(dialogue, &start, flags)
+    }
+
+    /// Starts `dialogue` at the node named `node`.
+    pub fn new_at(dialogue: Dialogue, node: &str, flags: &mut WorldFlags) -> Self {
+        let mut runner = Self {
+            dialogue,
+            node: None,
+            line: 0,
+            step: DialogueStep::End,
+        };
+        runner.enter(Some(node.to_string()), flags);
+        runner
+    }
+
+    /// What the conversation is showing.
+    pub fn step(&self) -> &DialogueStep {
+        &self.step
+    }
+
+    /// Name of the current node, if the conversation hasn't ended.
+    pub fn node(&self) -> Option<&str> {
+        self.node.as_deref()
+    }
+
+    /// Whether the conversation is over.
+    pub fn is_finished(&self) -> bool {
+        self.step == DialogueStep::End
+    }
+
+    /// Moves past the current line. Does nothing while choices are shown.
+    pub fn advance(&mut self, flags: &mut WorldFlags) {
+        if matches!(self.step, DialogueStep::Line { .. }) {
+            self.line += 1;
+            self.settle(flags);
+        }
+    }
+
+    /// Picks the choice at `index` in the current node, returning whether it
+    /// was available.
+    pub fn choose(&mut self, index: usize, flags: &mut WorldFlags) -> bool {
+        let DialogueStep::Choices(choices) = &self.step else {
+            return false;
+        };
+        if !choices.iter().any(|(available, _)| *available == index) {
+            return false;
+        }
+        let Some(choice) = self.current_node().map(|node| node.choices[index].clone()) else {
+            return false;
+        };
+        for action in &choice.actions {
+            action.apply(flags);
+        }
+        self.enter(choice.next, flags);
+        true
+    }
+
+    fn current_node(&self) -> Option<&DialogueNode> {
+        self.dialogue.nodes.get(self.node.as_ref()?)
+    }
+
+    /// Enters a node and settles on what to show.
+    fn enter(&mut self, node: Option<String>, flags: &mut WorldFlags) {
+        self.move_to(node, flags);
+        self.settle(flags);
+    }
+
+    /// Moves to the start of a node, running its actions.
+    fn move_to(&mut self, node: Option<String>, flags: &mut WorldFlags) {
+        if let Some(name) = &node {
+            if !self.dialogue.nodes.contains_key(name) {
+                warn!("Dialogue node '{}' doesn't exist", name);
+            }
+        }
+        self.node = node.filter(|name| self.dialogue.nodes.contains_key(name));
+        self.line = 0;
+        if let Some(node) = self.current_node() {
+            for action in node.actions.clone() {
+                action.apply(flags);
+            }
+        }
+    }
+
+    /// Finds the next line or choices to show from the current position,
+    /// following `next` through nodes with nothing left to show.
+    fn settle(&mut self, flags: &mut WorldFlags) {
+        // Nodes that only point onwards can't loop forever
+        for _ in 0..=self.dialogue.nodes.len() {
+            let Some(node) = self.current_node() else {
+                self.step = DialogueStep::End;
+                return;
+            };
+            let line = node.lines[self.line.min(node.lines.len())..]
+                .iter()
+                .position(|line| line.condition.evaluate(flags));
+            if let Some(offset) = line {
+                let line = &node.lines[self.line + offset];
+                self.step = DialogueStep::Line {
+                    speaker: line.speaker.clone(),
+                    text: format_dialogue_text(&line.text, flags),
+                };
+                self.line += offset;
+                return;
+            }
+            let choices: Vec<(usize, String)> = node
+                .choices
+                .iter()
+                .enumerate()
+                .filter(|(_, choice)| choice.condition.evaluate(flags))
+                .map(|(index, choice)| (index, format_dialogue_text(&choice.text, flags)))
+                .collect();
+            if !choices.is_empty() {
+                self.step = DialogueStep::Choices(choices);
+                return;
+            }
+            let next = node.next.clone();
+            self.move_to(next, flags);
+        }
+        warn!("Dialogue nodes lead to each other without showing anything");
+        self.node = None;
+        self.step = DialogueStep::End;
+    }
+}
+
+/// Starts a dialogue when its `Interactable` is used.
+#[derive(Component, Reflect, Default, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct DialogueTrigger {
+    /// Dialogue file, relative to the assets directory.
+    pub dialogue: String,
+    /// Node to start at; empty uses the dialogue's start node.
+    pub start: String,
+}
+
+impl DialogueTrigger {
+    /// Starts the dialogue in `path` at its start node.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            dialogue: path.into(),
+            start: String::new(),
+        }
+    }
+}
+
+/// The conversation in progress, if any.
+#[derive(Resource, Default)]
+pub struct ActiveDialogue {
+    /// Runner for the conversation; `None` when no one is talking.
+    pub runner: Option<DialogueRunner>,
+    /// Entity whose trigger started it, if any.
+    pub source: Option<Entity>,
+}
+
+impl ActiveDialogue {
+    /// Whether a conversation is in progress.
+    pub fn is_active(&self) -> bool {
+        self.runner.is_some()
+    }
+
+    /// What the conversation is showing, if one is in progress.
+    pub fn step(&self) -> Option<&DialogueStep> {
+        self.runner.as_ref().map(DialogueRunner::step)
+    }
+}
+
+/// What happened to a conversation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DialogueEventKind {
+    /// It started.
+    Started,
+    /// It ended.
+    Ended,
+}
+
+/// Sent when a conversation in `ActiveDialogue` starts or ends.
+#[derive(Event, Clone, Debug)]
+pub struct DialogueEvent {
+    /// Entity whose trigger started it, if any.
+    pub source: Option<Entity>,
+    /// What happened.
+    pub kind: DialogueEventKind,
+}
+
+/// Resource configuring the built-in dialogue box.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct DialogueSettings {
+    /// Whether the built-in dialogue box is shown.
+    pub show_box: bool,
+    /// Key that continues past a line.
+    pub advance_key: KeyCode,
+}
+
+impl Default for DialogueSettings {
+    fn default() -> Self {
+        Self {
+            show_box: true,
+            advance_key: KeyCode::Enter,
+        }
+    }
+}
+
+/// Marks the UI node of the built-in dialogue box.
+#[derive(Component)]
+pub struct DialogueBox;
+
+/// Keys that pick the first nine choices.
+const CHOICE_KEYS: [KeyCode; 9] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+];
+
+/// Starts the dialogue of an interacted-with `DialogueTrigger`.
+fn start_triggered_dialogues(
+    mut interactions: EventReader<InteractEvent>,
+    triggers: Query<&DialogueTrigger>,
+    root: Option<Res<ProjectRoot>>,
+    mut active: ResMut<ActiveDialogue>,
+    mut flags: ResMut<WorldFlags>,
+    mut events: EventWriter<DialogueEvent>,
+) {
+    for interaction in interactions.read() {
+        let Ok(trigger) = triggers.get(interaction.target) else {
+            continue;
+        };
+        if active.is_active() {
+            continue;
+        }
+        let path = match &root {
+            Some(root) => root.resolve_asset(&trigger.dialogue),
+            None => trigger.dialogue.clone().into(),
+        };
+        let dialogue = match Dialogue::load(&path) {
+            Ok(dialogue) => dialogue,
+            Err(e) => {
+                warn!("Failed to load dialogue {}: {}", trigger.dialogue, e);
+                continue;
+            }
+        };
+        let start = match trigger.start.as_str() {
+            "" => dialogue.start.clone(),
+            start => start.to_string(),
+        };
+        let runner = DialogueRunner::new_at(dialogue, &start, &mut flags);
+        active.source = Some(interaction.target);
+        active.runner = Some(runner);
+        events.send(DialogueEvent {
+            source: active.source,
+            kind: DialogueEventKind::Started,
+        });
+    }
+}
+
+/// Continues and picks choices from the keyboard, and ends finished conversations.
+fn dialogue_input(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    settings: Res<DialogueSettings>,
+    mut active: ResMut<ActiveDialogue>,
+    mut flags: ResMut<WorldFlags>,
+    mut events: EventWriter<DialogueEvent>,
+) {
+    let active = &mut *active;
+    let Some(runner) = &mut active.runner else {
+        return;
+    };
+    if let Some(keyboard) = keyboard {
+        match runner.step() {
+            DialogueStep::Line { .. } if keyboard.just_pressed(settings.advance_key) => {
+                runner.advance(&mut flags);
+            }
+            DialogueStep::Choices(choices) => {
+                let picked = CHOICE_KEYS
+                    .iter()
+                    .zip(choices)
+                    .find(|(key, _)| keyboard.just_pressed(**key))
+                    .map(|(_, (index, _))| *index);
+                if let Some(index) = picked {
+                    runner.choose(index, &mut flags);
+                }
+            }
+            _ => {}
+        }
+    }
+    if runner.is_finished() {
+        active.runner = None;
+        events.send(DialogueEvent {
+            source: active.source.take(),
+            kind: DialogueEventKind::Ended,
+        });
+    }
+}
+
+/// Keeps the built-in dialogue box in sync with `ActiveDialogue`.
+fn show_dialogue_box(
+    mut commands: Commands,
+    settings: Res<DialogueSettings>,
+    active: Res<ActiveDialogue>,
+    mut boxes: Query<(Entity, &mut Text), With<DialogueBox>>,
+) {
+    let step = active.step().filter(|_| settings.show_box);
+    let text = match step {
+        None | Some(DialogueStep::End) => {
+            for (entity, _) in &boxes {
+                commands.entity(entity).despawn_recursive();
+            }
+            return;
+        }
+        Some(DialogueStep::Line { speaker, text }) if speaker.is_empty() => {
+            format!("{}\n[{:?}] Continue", text, settings.advance_key)
+        }
+        Some(DialogueStep::Line { speaker, text }) => {
+            format!(
+                "{}: {}\n[{:?}] Continue",
+                speaker, text, settings.advance_key
+            )
+        }
+        Some(DialogueStep::Choices(choices)) => choices
+            .iter()
+            .enumerate()
+            .map(|(number, (_, text))| format!("{}. {}", number + 1, text))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    };
+    match boxes.get_single_mut() {
+        Ok((_, mut current)) => {
+            if current.0 != text {
+                current.0 = text;
+            }
+        }
+        Err(_) => {
+            commands.spawn((
+                DialogueBox,
+                Text::new(text),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(10.0),
+                    right: Val::Percent(10.0),
+                    bottom: Val::Px(24.0),
+                    padding: UiRect::all(Val::Px(12.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.85)),
+            ));
+        }
+    }
+}
@@ -6,7 +6,13 @@
 //! This module provides the state machine that controls editor execution modes
 //! and the system set for gating gameplay systems.
 
+use bevy::ecs::entity::EntityHashMap;
 use bevy::prelude::*;
+use bevy::scene::DynamicSceneBuilder;
+use bevy::utils::HashSet;
+use std::any::TypeId;
+
+use crate::scene::{scene_resource_filter, should_serialize_entity, SceneEntityId};
 
 /// Editor execution state for play/pause/stop controls.
 #[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
@@ -22,18 +28,85 @@ pub enum EditorPlayState {
 
 /// System set for gameplay systems that should only run when playing.
 ///
-/// Configure your gameplay systems to run in this set:
+/// Configure your gameplay systems to run in this set, toggleable so the
+/// editor's Systems window can switch them off one by one:
 /// ```ignore
-/// app.add_systems(Update, my_system.in_set(GameplaySystemSet));
+/// app.add_systems(Update, my_system.toggleable().in_set(GameplaySystemSet));
 /// ```
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GameplaySystemSet;
 
 /// Snapshot of entity states for restoring after stopping playback.
+///
+/// Taken when play starts from stopped (not when resuming from pause). On
+/// Stop, unless `keep_runtime_changes` is set, scene entities get their
+/// reflected components back and lose the ones added during play, scene
+/// resources get their values back, entities destroyed during play are
+/// respawned, and entities spawned during play are despawned. Systems that
+/// adjust the restored world can run `.after(restore_snapshot)` in
+/// `OnEnter(Stopped)`.
 #[derive(Resource, Default)]
 pub struct EditorSnapshot {
     /// Stored entity transforms and velocities for restoration.
     pub entity_states: Vec<EntityState>,
+    /// Reflected components of the scene's entities, and the scene resources;
+    /// `None` until play starts.
+    pub scene: Option<DynamicScene>,
+    /// Skips the next restore, keeping the play-mode state. Cleared on Stop.
+    pub keep_runtime_changes: bool,
+    /// File keys of the scene's entities, given back to respawned ones so
+    /// they keep their place in the saved file.
+    scene_ids: EntityHashMap<SceneEntityId>,
+}
+
+impl EditorSnapshot {
+    /// Scene entities spawned since the snapshot was taken.
+    pub fn spawned_entities(&self, world: &World) -> Vec<Entity> {
+        let known: HashSet<Entity> = self
+            .entity_states
+            .iter()
+            .map(|s| s.entity)
+            .chain(
+                self.scene
+                    .iter()
+                    .flat_map(|scene| &scene.entities)
+                    .map(|e| e.entity),
+            )
+            .collect();
+        world
+            .iter_entities()
+            .filter(|entity| {
+                should_serialize_entity(world, entity) && !known.contains(&entity.id())
+            })
+            .map(|entity| entity.id())
+            .collect()
+    }
+
+    /// Snapshotted scene entities that no longer exist.
+    pub fn despawned_entities(&self, world: &World) -> Vec<Entity> {
+        self.scene
+            .iter()
+            .flat_map(|scene| &scene.entities)
+            .map(|entity| entity.entity)
+            .filter(|&entity| world.get_entity(entity).is_err())
+            .collect()
+    }
+
+    /// Snapshotted scene entities whose transform has changed since.
+    pub fn moved_entities(&self, world: &World) -> Vec<Entity> {
+        self.entity_states
+            .iter()
+            .filter(|state| {
+                world.get_entity(state.entity).is_ok_and(|entity| {
+                    should_serialize_entity(world, &entity)
+                        && entity
+                            .get::<Transform>()
+                            .is_some_and(|transform| *transform != state.transform)
+                })
+            })
+            .map(|state| state.entity)
+            .collect()
+    }
 }
 
 /// State of a single entity for snapshot/restore.
@@ -58,28 +131,137 @@ impl Plugin for EditorStatePlugin {
                 Update,
                 GameplaySystemSet.run_if(in_state(EditorPlayState::Playing)),
             )
-            .add_systems(OnEnter(EditorPlayState::Playing), capture_snapshot)
+            .add_systems(
+                OnTransition {
+                    exited: EditorPlayState::Stopped,
+                    entered: EditorPlayState::Playing,
+                },
+                capture_snapshot,
+            )
             .add_systems(OnEnter(EditorPlayState::Stopped), restore_snapshot);
     }
 }
 
 /// Captures entity states when entering play mode.
-fn capture_snapshot(mut snapshot: ResMut<EditorSnapshot>, query: Query<(Entity, &Transform)>) {
-    snapshot.entity_states.clear();
-    for (entity, transform) in &query {
-        snapshot.entity_states.push(EntityState {
+fn capture_snapshot(world: &mut World) {
+    let mut query = world.query::<(Entity, &Transform)>();
+    let entity_states = query
+        .iter(world)
+        .map(|(entity, transform)| EntityState {
             entity,
             transform: *transform,
             velocity: None, // Will be filled by game-specific systems if needed
-        });
-    }
+        })
+        .collect();
+
+    let scene_entities: Vec<Entity> = world
+        .iter_entities()
+        .filter(|entity| should_serialize_entity(world, entity))
+        .map(|entity| entity.id())
+        .collect();
+    let scene = DynamicSceneBuilder::from_world(world)
+        .with_resource_filter(scene_resource_filter(world))
+        .extract_entities(scene_entities.into_iter())
+        .extract_resources()
+        .build();
+
+    let scene_ids = scene
+        .entities
+        .iter()
+        .filter_map(|entity| {
+            let id = world.get::<SceneEntityId>(entity.entity)?;
+            Some((entity.entity, *id))
+        })
+        .collect();
+
+    let mut snapshot = world.resource_mut::<EditorSnapshot>();
+    snapshot.entity_states = entity_states;
+    snapshot.scene = Some(scene);
+    snapshot.scene_ids = scene_ids;
 }
 
-/// Restores entity states when returning to stopped mode.
-fn restore_snapshot(snapshot: Res<EditorSnapshot>, mut query: Query<&mut Transform>) {
-    for state in &snapshot.entity_states {
-        if let Ok(mut transform) = query.get_mut(state.entity) {
+/// Restores the snapshot when returning to stopped mode.
+///
+/// Despawns entities spawned during play, removes components added during
+/// play, writes the snapshotted components and scene resources back
+/// (respawning destroyed entities), and resets the remaining transforms, such
+/// as cameras'.
+pub fn restore_snapshot(world: &mut World) {
+    let mut snapshot = world.resource_mut::<EditorSnapshot>();
+    let keep = std::mem::take(&mut snapshot.keep_runtime_changes);
+    let scene_ids = std::mem::take(&mut snapshot.scene_ids);
+    // Nothing to restore at startup or after keeping runtime changes
+    let Some(scene) = snapshot.scene.take() else {
+        return;
+    };
+    if keep {
+        return;
+    }
+
+    // The scene was taken above, so leave out its transform-less entities here
+    let snapshot = world.resource::<EditorSnapshot>();
+    let spawned = snapshot.spawned_entities(world);
+    let states = snapshot.entity_states.clone();
+    let saved: HashSet<Entity> = scene.entities.iter().map(|entity| entity.entity).collect();
+    for entity in spawned.into_iter().filter(|entity| !saved.contains(entity)) {
+        // May already be gone as the child of another spawned entity
+        if let Ok(entity) = world.get_entity_mut(entity) {
+            entity.despawn_recursive();
+        }
+    }
+
+    // Writing the snapshot only inserts and overwrites components
+    remove_components_added_in_play(world, &scene);
+
+    // Existing entities map to themselves; destroyed ones are respawned
+    let mut entity_map: EntityHashMap<Entity> = scene
+        .entities
+        .iter()
+        .map(|entity| entity.entity)
+        .filter(|&entity| world.get_entity(entity).is_ok())
+        .map(|entity| (entity, entity))
+        .collect();
+    if let Err(error) = scene.write_to_world(world, &mut entity_map) {
+        warn!("Failed to restore the play-mode snapshot: {}", error);
+    }
+    for (original, entity) in entity_map {
+        if let Some(&id) = scene_ids.get(&original).filter(|_| original != entity) {
+            world.entity_mut(entity).insert(id);
+        }
+    }
+
+    for state in states {
+        if let Some(mut transform) = world.get_mut::<Transform>(state.entity) {
             *transform = state.transform;
         }
     }
 }
+
+/// Removes the reflected components that the snapshot's entities didn't have
+/// when play started, such as status effects applied during play.
+fn remove_components_added_in_play(world: &mut World, scene: &DynamicScene) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    for snapshotted in &scene.entities {
+        let Ok(entity) = world.get_entity(snapshotted.entity) else {
+            continue;
+        };
+        let kept: HashSet<TypeId> = snapshotted
+            .components
+            .iter()
+            .filter_map(|component| component.get_represented_type_info())
+            .map(|info| info.type_id())
+            .collect();
+        let added: Vec<&ReflectComponent> = entity
+            .archetype()
+            .components()
+            .filter_map(|id| world.components().get_info(id)?.type_id())
+            .filter(|type_id| !kept.contains(type_id))
+            .filter_map(|type_id| type_registry.get_type_data::<ReflectComponent>(type_id))
+            .collect();
+        let mut entity = world.entity_mut(snapshotted.entity);
+        for component in added {
+            component.remove(&mut entity);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Built-in fallback assets bundled into the binary.
+//!
+//! Enabled by the `embedded_assets` feature. These assets let the engine and
+//! editor degrade gracefully when the project's assets directory is empty or
+//! a referenced file fails to load.
+
+use bevy::asset::{embedded_asset, LoadState};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::assets::AssetPath;
+
+/// Asset path of the built-in missing-texture placeholder.
+pub const MISSING_TEXTURE_PATH: &str = "embedded://sandbox_engine/embedded/missing_texture.png";
+
+/// Asset path of the built-in beep sound.
+pub const BEEP_SOUND_PATH: &str = "embedded://sandbox_engine/embedded/beep.wav";
+
+/// Asset path of the built-in engine hum, a one-second seamless loop.
+pub const HUM_SOUND_PATH: &str = "embedded://sandbox_engine/embedded/hum.wav";
+
+/// Handles to the built-in assets.
+#[derive(Resource, Clone, Debug)]
+pub struct DefaultAssets {
+    /// Magenta/black checkerboard shown in place of images that fail to load.
+    pub missing_texture: Handle<Image>,
+    /// Default UI font (Bevy's built-in font).
+    pub font: Handle<Font>,
+    /// Short beep for UI feedback and placeholder sound effects.
+    pub beep: Handle<AudioSource>,
+    /// Low engine hum for looping ambience.
+    pub hum: Handle<AudioSource>,
+}
+
+/// Colors and sizes for game UI when a game does not provide its own skin.
+#[derive(Resource, Deserialize, Clone, Debug)]
+pub struct UiSkin {
+    /// Background color of panels (sRGBA).
+    pub panel_color: [f32; 4],
+    /// Border color of panels (sRGBA).
+    pub border_color: [f32; 4],
+    /// Text color (sRGBA).
+    pub text_color: [f32; 4],
+    /// Highlight color for buttons and selections (sRGBA).
+    pub accent_color: [f32; 4],
+    /// Default font size in pixels.
+    pub font_size: f32,
+}
+
+impl UiSkin {
+    /// Returns the panel color.
+    pub fn panel(&self) -> Color {
+        srgba(self.panel_color)
+    }
+
+    /// Returns the border color.
+    pub fn border(&self) -> Color {
+        srgba(self.border_color)
+    }
+
+    /// Returns the text color.
+    pub fn text(&self) -> Color {
+        srgba(self.text_color)
+    }
+
+    /// Returns the accent color.
+    pub fn accent(&self) -> Color {
+        srgba(self.accent_color)
+    }
+}
+
+impl Default for UiSkin {
+    fn default() -> Self {
+        bevy::scene::ron::from_str(include_str!("embedded/ui_skin.ron"))
+            .expect("built-in UI skin should be valid RON")
+    }
+}
+
+fn srgba([r, g, b, a]: [f32; 4]) -> Color {
+    Color::srgba(r, g, b, a)
+}
+
+/// Plugin that registers the built-in assets and the missing-texture fallback.
+///
+/// Must be added after `AssetPlugin` (e.g. after `DefaultPlugins`).
+pub struct EmbeddedAssetsPlugin;
+
+impl Plugin for EmbeddedAssetsPlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "embedded/missing_texture.png");
+        embedded_asset!(app, "embedded/beep.wav");
+        embedded_asset!(app, "embedded/hum.wav");
+
+        let asset_server = app.world().resource::<AssetServer>();
+        let default_assets = DefaultAssets {
+            missing_texture: asset_server.load(MISSING_TEXTURE_PATH),
+            font: Handle::default(),
+            beep: asset_server.load(BEEP_SOUND_PATH),
+            hum: asset_server.load(HUM_SOUND_PATH),
+        };
+
+        app.insert_resource(default_assets)
+            .init_resource::<UiSkin>()
+            .add_systems(Update, replace_failed_images);
+    }
+}
+
+/// Swaps sprite images that failed to load for the missing-texture placeholder.
+fn replace_failed_images(
+    asset_server: Res<AssetServer>,
+    default_assets: Res<DefaultAssets>,
+    mut query: Query<&mut Sprite, With<AssetPath>>,
+) {
+    for mut sprite in &mut query {
+        if let Some(LoadState::Failed(_)) = asset_server.get_load_state(&sprite.image) {
+            sprite.image = default_assets.missing_texture.clone();
+        }
+    }
+}
@@ -0,0 +1,251 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Persistent world-state flags for missions, dialogue, and spawners.
+//!
+//! `WorldFlags` is a key-value store of bools, numbers, and strings that
+//! records story and progression state ("contract_3_complete", "ore_sold").
+//! It is saved with the rest of a savegame through `WorldFlags::save`. Gate
+//! behavior on it with `FlagCondition`, or with the `flag_set` and
+//! `flag_condition` run conditions.
+//!
+//! # Example
+//! ```ignore
+//! app.add_systems(Update, spawn_station.run_if(flag_set("contract_3_complete")));
+//!
+//! fn complete_contract(mut flags: ResMut<WorldFlags>) {
+//!     flags.set("contract_3_complete", true);
+//!     flags.add("contracts_done", 1.0);
+//! }
+//! ```
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::scene::{SceneError, SceneResult};
+
+/// Plugin that sets up the `WorldFlags` resource.
+pub struct WorldFlagsPlugin;
+
+impl Plugin for WorldFlagsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<WorldFlags>()
+            .register_type::<FlagValue>()
+            .register_type::<FlagCondition>()
+            .register_type::<FlagChange>()
+            .init_resource::<WorldFlags>();
+    }
+}
+
+/// Value of a world flag.
+#[derive(Reflect, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum FlagValue {
+    Bool(bool),
+    Number(f64),
+    Text(String),
+}
+
+impl FlagValue {
+    /// Whether the value counts as set: true, non-zero, or non-empty.
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            FlagValue::Bool(value) => *value,
+            FlagValue::Number(value) => *value != 0.0,
+            FlagValue::Text(value) => !value.is_empty(),
+        }
+    }
+}
+
+impl std::fmt::Display for FlagValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlagValue::Bool(value) => write!(f, "{}", value),
+            FlagValue::Number(value) => write!(f, "{}", value),
+            FlagValue::Text(value) => f.write_str(value),
+        }
+    }
+}
+
+impl From<bool> for FlagValue {
+    fn from(value: bool) -> Self {
+        FlagValue::Bool(value)
+    }
+}
+
+impl From<f64> for FlagValue {
+    fn from(value: f64) -> Self {
+        FlagValue::Number(value)
+    }
+}
+
+impl From<i32> for FlagValue {
+    fn from(value: i32) -> Self {
+        FlagValue::Number(value.into())
+    }
+}
+
+impl From<&str> for FlagValue {
+    fn from(value: &str) -> Self {
+        FlagValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for FlagValue {
+    fn from(value: String) -> Self {
+        FlagValue::Text(value)
+    }
+}
+
+/// Resource holding the world's progression flags.
+#[derive(Resource, Reflect, Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[reflect(Resource, Default)]
+pub struct WorldFlags {
+    /// Flags by key, sorted so saves are stable.
+    pub values: BTreeMap<String, FlagValue>,
+}
+
+impl WorldFlags {
+    /// Returns a flag's value, if set.
+    pub fn get(&self, key: &str) -> Option<&FlagValue> {
+        self.values.get(key)
+    }
+
+    /// Sets a flag, replacing any previous value.
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<FlagValue>) {
+        self.values.insert(key.into(), value.into());
+    }
+
+    /// Removes a flag, returning its value.
+    pub fn remove(&mut self, key: &str) -> Option<FlagValue> {
+        self.values.remove(key)
+    }
+
+    /// Whether a flag is set to a truthy value (see `FlagValue::is_truthy`).
+    pub fn is_set(&self, key: &str) -> bool {
+        self.get(key).is_some_and(FlagValue::is_truthy)
+    }
+
+    /// Returns a number flag, or 0 if it is missing or not a number.
+    pub fn number(&self, key: &str) -> f64 {
+        match self.get(key) {
+            Some(FlagValue::Number(value)) => *value,
+            _ => 0.0,
+        }
+    }
+
+    /// Returns a text flag, if set to text.
+    pub fn text(&self, key: &str) -> Option<&str> {
+        match self.get(key) {
+            Some(FlagValue::Text(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Adds to a number flag, treating a missing or non-number flag as 0.
+    pub fn add(&mut self, key: impl Into<String>, amount: f64) {
+        let key = key.into();
+        let value = self.number(&key) + amount;
+        self.set(key, value);
+    }
+
+    /// Writes the flags to a RON file.
+    pub fn save(&self, path: &Path) -> SceneResult<()> {
+        let data = bevy::scene::ron::ser::to_string_pretty(self, Default::default())
+            .map_err(|e| SceneError::Serialization(e.to_string()))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(SceneError::Io)?;
+        }
+        std::fs::write(path, data).map_err(SceneError::Io)
+    }
+
+    /// Reads flags from a RON file.
+    pub fn load(path: &Path) -> SceneResult<Self> {
+        let data = std::fs::read_to_string(path).map_err(SceneError::FileRead)?;
+        bevy::scene::ron::from_str(&data).map_err(|e| SceneError::Deserialization(e.to_string()))
+    }
+}
+
+/// A condition on world flags, e.g. for spawners, dialogue options, or mission steps.
+///
+/// Conditions serialize to RON, so they can be authored in scenes and data files:
+/// `All([IsSet("contract_3_complete"), AtLeast("reputation", 10.0)])`.
+#[derive(Reflect, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[reflect(no_field_bounds)]
+pub enum FlagCondition {
+    /// Always true.
+    #[default]
+    Always,
+    /// The flag is set to a truthy value.
+    IsSet(String),
+    /// The flag is missing or falsy.
+    NotSet(String),
+    /// The flag equals the value exactly.
+    Equals(String, FlagValue),
+    /// The flag is a number at least this large.
+    AtLeast(String, f64),
+    /// The flag is missing or a number at most this large.
+    AtMost(String, f64),
+    /// Every condition holds.
+    All(Vec<FlagCondition>),
+    /// At least one condition holds.
+    Any(Vec<FlagCondition>),
+}
+
+impl FlagCondition {
+    /// Evaluates the condition against the flags.
+    pub fn evaluate(&self, flags: &WorldFlags) -> bool {
+        match self {
+            FlagCondition::Always => true,
+            FlagCondition::IsSet(key) => flags.is_set(key),
+            FlagCondition::NotSet(key) => !flags.is_set(key),
+            FlagCondition::Equals(key, value) => flags.get(key) == Some(value),
+            FlagCondition::AtLeast(key, min) => {
+                matches!(flags.get(key), Some(FlagValue::Number(value)) if value >= min)
+            }
+            FlagCondition::AtMost(key, max) => flags.number(key) <= *max,
+            FlagCondition::All(conditions) => conditions.iter().all(|c| c.evaluate(flags)),
+            FlagCondition::Any(conditions) => conditions.iter().any(|c| c.evaluate(flags)),
+        }
+    }
+}
+
+/// A change to world flags, e.g. made by a dialogue choice or a mission step.
+///
+/// Like conditions, changes serialize to RON: `Add("ore_sold", 5.0)`.
+#[derive(Reflect, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum FlagChange {
+    /// Sets the flag, replacing any previous value.
+    Set(String, FlagValue),
+    /// Adds to a number flag (see `WorldFlags::add`).
+    Add(String, f64),
+    /// Removes the flag.
+    Remove(String),
+}
+
+impl FlagChange {
+    /// Applies the change to the flags.
+    pub fn apply(&self, flags: &mut WorldFlags) {
+        match self {
+            FlagChange::Set(key, value) => flags.set(key.clone(), value.clone()),
+            FlagChange::Add(key, amount) => flags.add(key.clone(), *amount),
+            FlagChange::Remove(key) => {
+                flags.remove(key);
+            }
+        }
+    }
+}
+
+/// Run condition that is true while a flag is set to a truthy value.
+pub fn flag_set(key: impl Into<String>) -> impl Fn(Option<Res<WorldFlags>>) -> bool + Clone {
+    let key = key.into();
+    move |flags: Option<Res<WorldFlags>>| flags.is_some_and(|flags| flags.is_set(&key))
+}
+
+/// Run condition that is true while a `FlagCondition` holds.
+pub fn flag_condition(
+    condition: FlagCondition,
+) -> impl Fn(Option<Res<WorldFlags>>) -> bool + Clone {
+    move |flags: Option<Res<WorldFlags>>| flags.is_some_and(|flags| condition.evaluate(&flags))
+}
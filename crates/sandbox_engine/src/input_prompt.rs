@@ -0,0 +1,351 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Button prompts that follow the device the player is using.
+//!
+//! An `ActionBinding` pairs a keyboard key with a gamepad button. The
+//! `InputDevice` resource tracks which device was used last: any key press
+//! switches it to the keyboard, and a gamepad button or stick switches it to
+//! that gamepad, recognized as Xbox, PlayStation, or Nintendo by its USB
+//! vendor or its name. `ActionBinding::label` then names the binding the way
+//! that device prints it: "E" on a keyboard, "A" on an Xbox pad, "Cross" on
+//! a PlayStation pad.
+//!
+//! `InputPrompt` keeps a `Text` or `Text2d` up to date, replacing `{}` in its
+//! template with the label, so "Press [{}] to dock" reads "Press [E] to dock"
+//! or "Press [A] to dock" as the player switches devices. `Interactable`
+//! prompts do the same.
+//!
+//! For icons instead of text, [`ActionBinding::icon`] names an image per
+//! device and input, e.g. `xbox/south.png` or `keyboard/e.png`, to look up in
+//! the game's prompt icon folder.
+//!
+//! # Example
+//! ```ignore
+//! commands.spawn((
+//!     Text::default(),
+//!     InputPrompt::new("Press [{}] to dock", ActionBinding::new(KeyCode::KeyE, GamepadButton::South)),
+//! ));
+//! ```
+
+use bevy::input::gamepad::{GamepadAxisChangedEvent, GamepadButtonChangedEvent};
+use bevy::input::keyboard::KeyboardInput;
+use bevy::prelude::*;
+
+/// How far a stick or trigger must move to count as using its gamepad.
+const GAMEPAD_ACTIVITY_THRESHOLD: f32 = 0.5;
+
+/// Plugin that tracks the active input device and updates `InputPrompt`s.
+pub struct InputPromptPlugin;
+
+impl Plugin for InputPromptPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ActionBinding>()
+            .register_type::<InputPrompt>()
+            .init_resource::<InputDevice>()
+            .add_systems(
+                PreUpdate,
+                track_input_device.after(bevy::input::InputSystem),
+            )
+            .add_systems(Update, update_input_prompts);
+    }
+}
+
+/// Gamepad families, which label their buttons differently.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GamepadKind {
+    /// Xbox and most PC pads: A, B, X, Y.
+    Xbox,
+    /// DualShock and DualSense: Cross, Circle, Square, Triangle.
+    PlayStation,
+    /// Switch Pro and Joy-Con: B, A, Y, X (the confirm button is on the right).
+    Nintendo,
+    /// Anything else; labeled like an Xbox pad.
+    #[default]
+    Generic,
+}
+
+impl GamepadKind {
+    /// The family of a pad with the USB vendor ID `vendor_id`.
+    pub fn from_vendor_id(vendor_id: u16) -> Self {
+        match vendor_id {
+            0x045e => GamepadKind::Xbox,
+            0x054c => GamepadKind::PlayStation,
+            0x057e => GamepadKind::Nintendo,
+            _ => GamepadKind::Generic,
+        }
+    }
+
+    /// The family of a pad going by its name, for pads without a vendor ID.
+    pub fn from_name(name: &str) -> Self {
+        let name = name.to_lowercase();
+        let any = |words: &[&str]| words.iter().any(|word| name.contains(word));
+        if any(&["xbox", "x-box", "xinput"]) {
+            GamepadKind::Xbox
+        } else if any(&["playstation", "dualshock", "dualsense", "ps3", "ps4", "ps5"]) {
+            GamepadKind::PlayStation
+        } else if any(&["nintendo", "switch", "joy-con", "pro controller"]) {
+            GamepadKind::Nintendo
+        } else {
+            GamepadKind::Generic
+        }
+    }
+
+    /// The family of `gamepad`, by its vendor ID or else its name.
+    pub fn of(gamepad: &Gamepad, name: Option<&Name>) -> Self {
+        match gamepad.vendor_id().map(Self::from_vendor_id) {
+            Some(GamepadKind::Generic) | None => {
+                name.map_or(GamepadKind::Generic, |name| Self::from_name(name))
+            }
+            Some(kind) => kind,
+        }
+    }
+
+    /// Folder name for the family's prompt icons.
+    pub fn icon_folder(self) -> &'static str {
+        match self {
+            GamepadKind::Xbox => "xbox",
+            GamepadKind::PlayStation => "playstation",
+            GamepadKind::Nintendo => "nintendo",
+            GamepadKind::Generic => "gamepad",
+        }
+    }
+
+    /// How the family prints `button`.
+    pub fn button_label(self, button: GamepadButton) -> String {
+        use GamepadButton::*;
+        let label = match (self, button) {
+            (GamepadKind::PlayStation, South) => "Cross",
+            (GamepadKind::PlayStation, East) => "Circle",
+            (GamepadKind::PlayStation, West) => "Square",
+            (GamepadKind::PlayStation, North) => "Triangle",
+            (GamepadKind::PlayStation, LeftTrigger) => "L1",
+            (GamepadKind::PlayStation, LeftTrigger2) => "L2",
+            (GamepadKind::PlayStation, RightTrigger) => "R1",
+            (GamepadKind::PlayStation, RightTrigger2) => "R2",
+            (GamepadKind::PlayStation, Select) => "Share",
+            (GamepadKind::PlayStation, Start) => "Options",
+            (GamepadKind::PlayStation, Mode) => "PS",
+            (GamepadKind::PlayStation, LeftThumb) => "L3",
+            (GamepadKind::PlayStation, RightThumb) => "R3",
+            (GamepadKind::Nintendo, South) => "B",
+            (GamepadKind::Nintendo, East) => "A",
+            (GamepadKind::Nintendo, West) => "Y",
+            (GamepadKind::Nintendo, North) => "X",
+            (GamepadKind::Nintendo, LeftTrigger) => "L",
+            (GamepadKind::Nintendo, LeftTrigger2) => "ZL",
+            (GamepadKind::Nintendo, RightTrigger) => "R",
+            (GamepadKind::Nintendo, RightTrigger2) => "ZR",
+            (GamepadKind::Nintendo, Select) => "−",
+            (GamepadKind::Nintendo, Start) => "+",
+            (GamepadKind::Nintendo, Mode) => "Home",
+            (_, South) => "A",
+            (_, East) => "B",
+            (_, West) => "X",
+            (_, North) => "Y",
+            (_, LeftTrigger) => "LB",
+            (_, LeftTrigger2) => "LT",
+            (_, RightTrigger) => "RB",
+            (_, RightTrigger2) => "RT",
+            (_, Select) => "View",
+            (_, Start) => "Menu",
+            (_, Mode) => "Guide",
+            (_, LeftThumb) => "LS",
+            (_, RightThumb) => "RS",
+            (_, DPadUp) => "D-Pad Up",
+            (_, DPadDown) => "D-Pad Down",
+            (_, DPadLeft) => "D-Pad Left",
+            (_, DPadRight) => "D-Pad Right",
+            (_, C) => "C",
+            (_, Z) => "Z",
+            (_, Other(number)) => return format!("Button {}", number),
+        };
+        label.to_string()
+    }
+}
+
+/// The device the player used last, which prompts are shown for.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum InputDevice {
+    /// Keyboard and mouse.
+    #[default]
+    Keyboard,
+    /// A gamepad of the given family.
+    Gamepad(GamepadKind),
+}
+
+/// A keyboard key and, optionally, a gamepad button doing the same thing.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[reflect(Default)]
+pub struct ActionBinding {
+    /// Key on the keyboard.
+    pub key: KeyCode,
+    /// Button on any gamepad; `None` for keyboard-only actions.
+    pub button: Option<GamepadButton>,
+}
+
+impl Default for ActionBinding {
+    fn default() -> Self {
+        Self {
+            key: KeyCode::KeyE,
+            button: Some(GamepadButton::South),
+        }
+    }
+}
+
+impl ActionBinding {
+    /// Binds `key` and `button`.
+    pub fn new(key: KeyCode, button: GamepadButton) -> Self {
+        Self {
+            key,
+            button: Some(button),
+        }
+    }
+
+    /// Binds only `key`.
+    pub fn key(key: KeyCode) -> Self {
+        Self { key, button: None }
+    }
+
+    /// Whether the binding was pressed this frame on the keyboard or any gamepad.
+    pub fn just_pressed<'a>(
+        &self,
+        keyboard: Option<&ButtonInput<KeyCode>>,
+        gamepads: impl IntoIterator<Item = &'a Gamepad>,
+    ) -> bool {
+        keyboard.is_some_and(|keyboard| keyboard.just_pressed(self.key))
+            || self.button.is_some_and(|button| {
+                gamepads
+                    .into_iter()
+                    .any(|gamepad| gamepad.just_pressed(button))
+            })
+    }
+
+    /// How `device` prints the binding, e.g. "E", "A", or "Cross". Gamepads
+    /// show the key when the binding has no button.
+    pub fn label(&self, device: InputDevice) -> String {
+        match (device, self.button) {
+            (InputDevice::Gamepad(kind), Some(button)) => kind.button_label(button),
+            _ => key_label(self.key),
+        }
+    }
+
+    /// Icon image for the binding on `device`, relative to the game's prompt
+    /// icon folder: `keyboard/<key>.png` or `<family>/<button>.png`, with the
+    /// key or button name lowercased (`keyboard/space.png`, `xbox/south.png`,
+    /// `playstation/lefttrigger2.png`).
+    pub fn icon(&self, device: InputDevice) -> String {
+        match (device, self.button) {
+            (InputDevice::Gamepad(kind), Some(button)) => format!(
+                "{}/{}.png",
+                kind.icon_folder(),
+                format!("{:?}", button)
+                    .replace(['(', ')'], "")
+                    .to_lowercase()
+            ),
+            _ => format!("keyboard/{}.png", key_label(self.key).to_lowercase()),
+        }
+    }
+}
+
+/// Short display name for a key, e.g. "E" for `KeyCode::KeyE`.
+pub fn key_label(key: KeyCode) -> String {
+    let name = format!("{:?}", key);
+    name.strip_prefix("Key")
+        .or_else(|| name.strip_prefix("Digit"))
+        .unwrap_or(&name)
+        .to_string()
+}
+
+/// Keeps the entity's `Text` or `Text2d` showing `template` with `{}`
+/// replaced by the binding's label for the active `InputDevice`.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct InputPrompt {
+    /// Prompt text; `{}` is replaced by the binding's label.
+    pub template: String,
+    /// Binding whose label is shown.
+    pub binding: ActionBinding,
+}
+
+impl Default for InputPrompt {
+    fn default() -> Self {
+        Self {
+            template: "[{}]".to_string(),
+            binding: ActionBinding::default(),
+        }
+    }
+}
+
+impl InputPrompt {
+    /// Shows `template` with `{}` replaced by `binding`'s label.
+    pub fn new(template: impl Into<String>, binding: ActionBinding) -> Self {
+        Self {
+            template: template.into(),
+            binding,
+        }
+    }
+
+    /// The prompt as `device` shows it.
+    pub fn text(&self, device: InputDevice) -> String {
+        self.template.replace("{}", &self.binding.label(device))
+    }
+}
+
+/// Switches the `InputDevice` to whichever device was used this frame.
+fn track_input_device(
+    mut keys: EventReader<KeyboardInput>,
+    mut buttons: EventReader<GamepadButtonChangedEvent>,
+    mut axes: EventReader<GamepadAxisChangedEvent>,
+    gamepads: Query<(&Gamepad, Option<&Name>)>,
+    mut device: ResMut<InputDevice>,
+) {
+    let gamepad = buttons
+        .read()
+        .filter(|event| event.value >= GAMEPAD_ACTIVITY_THRESHOLD)
+        .map(|event| event.entity)
+        .chain(
+            axes.read()
+                .filter(|event| event.value.abs() >= GAMEPAD_ACTIVITY_THRESHOLD)
+                .map(|event| event.entity),
+        )
+        .last();
+    let used = match gamepad {
+        Some(entity) => Some(InputDevice::Gamepad(
+            gamepads
+                .get(entity)
+                .map_or(GamepadKind::Generic, |(gamepad, name)| {
+                    GamepadKind::of(gamepad, name)
+                }),
+        )),
+        // Gamepad input wins a tie, since a held key keeps repeating
+        None => keys
+            .read()
+            .any(|key| key.state.is_pressed() && !key.repeat)
+            .then_some(InputDevice::Keyboard),
+    };
+    keys.clear();
+    if let Some(used) = used.filter(|used| *device != *used) {
+        *device = used;
+    }
+}
+
+/// Rewrites prompts whose template or binding changed, or all of them when
+/// the device changes.
+fn update_input_prompts(
+    device: Res<InputDevice>,
+    mut prompts: Query<(Ref<InputPrompt>, Option<&mut Text>, Option<&mut Text2d>)>,
+) {
+    for (prompt, text, text_2d) in &mut prompts {
+        if !prompt.is_changed() && !device.is_changed() {
+            continue;
+        }
+        let shown = prompt.text(*device);
+        if let Some(mut text) = text.filter(|text| text.0 != shown) {
+            text.0 = shown.clone();
+        }
+        if let Some(mut text) = text_2d.filter(|text| text.0 != shown) {
+            text.0 = shown;
+        }
+    }
+}
@@ -0,0 +1,151 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Inspector metadata for reflected fields.
+//!
+//! Attach `InspectorOptions` to a field as a reflect custom attribute to give
+//! the editor's numeric drags a range and step size, and the field a tooltip.
+//! The options are part of the type's reflected `TypeInfo`, so they need no
+//! extra registration beyond the usual `register_type::<T>()`.
+//!
+//! # Example
+//! ```ignore
+//! #[derive(Component, Reflect, Default)]
+//! #[reflect(Component, Default)]
+//! struct Turret {
+//!     #[reflect(@InspectorOptions::range(0.0, 360.0).with_step(1.0).with_tooltip("Degrees per second"))]
+//!     turn_speed: f32,
+//!     #[reflect(@InspectorOptions::min(1.0))]
+//!     ammo: u32,
+//! }
+//! ```
+//!
+//! Options apply to every component of a vector field (e.g. a `Vec2` size)
+//! and to struct fields of enum variants.
+//!
+//! [`default_value`] and [`default_variant`] build values from registered
+//! defaults, for the inspector's Add buttons and variant pickers.
+
+use bevy::prelude::*;
+use bevy::reflect::{
+    DynamicStruct, DynamicTuple, DynamicVariant, NamedField, PartialReflect, TypeInfo,
+    TypeRegistry, UnnamedField, VariantInfo,
+};
+use std::any::TypeId;
+
+/// Range, drag step, and tooltip for a field shown in the editor inspector.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq)]
+pub struct InspectorOptions {
+    /// Smallest value a drag can reach.
+    pub min: Option<f64>,
+    /// Largest value a drag can reach.
+    pub max: Option<f64>,
+    /// Value change per pixel dragged.
+    pub step: Option<f64>,
+    /// Text shown when hovering the field.
+    pub tooltip: Option<&'static str>,
+}
+
+impl InspectorOptions {
+    /// Options limiting the field to `min..=max`.
+    pub const fn range(min: f64, max: f64) -> Self {
+        Self {
+            min: Some(min),
+            max: Some(max),
+            step: None,
+            tooltip: None,
+        }
+    }
+
+    /// Options limiting the field to values of at least `min`.
+    pub const fn min(min: f64) -> Self {
+        Self {
+            min: Some(min),
+            max: None,
+            step: None,
+            tooltip: None,
+        }
+    }
+
+    /// Options with only a tooltip.
+    pub const fn tooltip(tooltip: &'static str) -> Self {
+        Self {
+            min: None,
+            max: None,
+            step: None,
+            tooltip: Some(tooltip),
+        }
+    }
+
+    /// Sets the value change per pixel dragged.
+    pub const fn with_step(mut self, step: f64) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Sets the text shown when hovering the field.
+    pub const fn with_tooltip(mut self, tooltip: &'static str) -> Self {
+        self.tooltip = Some(tooltip);
+        self
+    }
+
+    /// Options declared on the `index`th field of a struct or tuple struct.
+    pub fn of_field(info: &TypeInfo, index: usize) -> Option<&Self> {
+        match info {
+            TypeInfo::Struct(info) => info.field_at(index).and_then(Self::of_named),
+            TypeInfo::TupleStruct(info) => info.field_at(index).and_then(Self::of_unnamed),
+            _ => None,
+        }
+    }
+
+    /// Options declared on the `index`th field of an enum variant.
+    pub fn of_variant_field<'a>(
+        info: &'a TypeInfo,
+        variant: &str,
+        index: usize,
+    ) -> Option<&'a Self> {
+        let TypeInfo::Enum(info) = info else {
+            return None;
+        };
+        match info.variant(variant)? {
+            VariantInfo::Struct(info) => info.field_at(index).and_then(Self::of_named),
+            VariantInfo::Tuple(info) => info.field_at(index).and_then(Self::of_unnamed),
+            VariantInfo::Unit(_) => None,
+        }
+    }
+
+    fn of_named(field: &NamedField) -> Option<&Self> {
+        field.get_attribute::<Self>()
+    }
+
+    fn of_unnamed(field: &UnnamedField) -> Option<&Self> {
+        field.get_attribute::<Self>()
+    }
+}
+
+/// The registered default of a type, if it has one.
+pub fn default_value(type_id: TypeId, registry: &TypeRegistry) -> Option<Box<dyn PartialReflect>> {
+    let default = registry.get(type_id)?.data::<ReflectDefault>()?;
+    Some(default.default().into_partial_reflect())
+}
+
+/// Builds a variant with default field values, if every field type has a default.
+pub fn default_variant(variant: &VariantInfo, registry: &TypeRegistry) -> Option<DynamicVariant> {
+    match variant {
+        VariantInfo::Unit(_) => Some(DynamicVariant::Unit),
+        VariantInfo::Tuple(info) => {
+            let mut fields = DynamicTuple::default();
+            for field in info.iter() {
+                fields.insert_boxed(default_value(field.type_id(), registry)?);
+            }
+            Some(DynamicVariant::Tuple(fields))
+        }
+        VariantInfo::Struct(info) => {
+            let mut fields = DynamicStruct::default();
+            for field in info.iter() {
+                fields.insert_boxed(field.name(), default_value(field.type_id(), registry)?);
+            }
+            Some(DynamicVariant::Struct(fields))
+        }
+    }
+}
@@ -0,0 +1,237 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Proximity interactions: docking, looting, dialogue triggers.
+//!
+//! The entity marked `Interactor` (usually the player) focuses the nearest
+//! `Interactable` whose radius it is inside. The focused interactable's prompt
+//! is shown above it, and pressing its action key (or gamepad button) sends
+//! an `InteractEvent`. The prompt names the key or button for the device the
+//! player is using (see [`crate::input_prompt`]).
+//!
+//! # Example
+//! ```ignore
+//! commands.spawn((Name::new("Station"), Interactable::new("Dock", 120.0), Transform::default()));
+//!
+//! fn dock(mut events: EventReader<InteractEvent>, names: Query<&Name>) {
+//!     for event in events.read() {
+//!         info!("Docked at {:?}", names.get(event.target));
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+
+use crate::editor_state::GameplaySystemSet;
+use crate::input_prompt::{ActionBinding, InputDevice};
+use crate::inspector::InspectorOptions;
+use crate::system_toggle::ToggleableSystem;
+use crate::world_ui::WorldAnchoredUi;
+
+/// Plugin that tracks the focused interactable, shows its prompt, and sends `InteractEvent`s.
+pub struct InteractablePlugin;
+
+impl Plugin for InteractablePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Interactable>()
+            .register_type::<Interactor>()
+            .init_resource::<InteractionFocus>()
+            .add_event::<InteractEvent>()
+            .add_systems(
+                Update,
+                (
+                    update_interaction_focus.toggleable(),
+                    show_interaction_prompt.toggleable(),
+                    interact.toggleable(),
+                )
+                    .chain()
+                    .in_set(GameplaySystemSet),
+            );
+    }
+}
+
+/// Something the `Interactor` can interact with when close enough.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component, Default)]
+pub struct Interactable {
+    /// Prompt text, e.g. "Dock" or "Talk". Shown after the action key.
+    pub prompt: String,
+    /// Distance from the interactable within which it can be focused.
+    #[reflect(@InspectorOptions::min(0.0).with_step(1.0))]
+    pub radius: f32,
+    /// Key that triggers the interaction while focused.
+    pub action: KeyCode,
+    /// Gamepad button that also triggers it; `None` for keyboard only.
+    /// Scenes saved before interactions had buttons load without one.
+    #[reflect(default)]
+    pub button: Option<GamepadButton>,
+}
+
+impl Default for Interactable {
+    fn default() -> Self {
+        Self {
+            prompt: "Interact".to_string(),
+            radius: 64.0,
+            action: KeyCode::KeyE,
+            button: Some(GamepadButton::South),
+        }
+    }
+}
+
+impl Interactable {
+    /// Creates an interactable triggered with E or a gamepad's bottom face button.
+    pub fn new(prompt: impl Into<String>, radius: f32) -> Self {
+        Self {
+            prompt: prompt.into(),
+            radius,
+            ..default()
+        }
+    }
+
+    /// Sets the key that triggers the interaction.
+    pub fn with_action(mut self, action: KeyCode) -> Self {
+        self.action = action;
+        self
+    }
+
+    /// Sets the gamepad button that triggers the interaction, or `None` for
+    /// keyboard only.
+    pub fn with_button(mut self, button: Option<GamepadButton>) -> Self {
+        self.button = button;
+        self
+    }
+
+    /// The action key and gamepad button as a binding.
+    pub fn binding(&self) -> ActionBinding {
+        ActionBinding {
+            key: self.action,
+            button: self.button,
+        }
+    }
+
+    /// Prompt text including the action key, e.g. "[E] Dock".
+    pub fn prompt_text(&self) -> String {
+        self.prompt_text_for(InputDevice::Keyboard)
+    }
+
+    /// Prompt text including the action as `device` labels it, e.g.
+    /// "[E] Dock" or "[A] Dock".
+    pub fn prompt_text_for(&self, device: InputDevice) -> String {
+        format!("[{}] {}", self.binding().label(device), self.prompt)
+    }
+}
+
+/// Marks the entity that interacts with `Interactable`s, usually the player.
+#[derive(Component, Reflect, Default, Clone, Copy, Debug)]
+#[reflect(Component, Default)]
+pub struct Interactor;
+
+/// Resource holding the interactable currently in focus, if any.
+#[derive(Resource, Default, Debug)]
+pub struct InteractionFocus {
+    pub target: Option<Entity>,
+}
+
+/// Sent when the interactor triggers the focused interactable.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct InteractEvent {
+    pub interactor: Entity,
+    pub target: Entity,
+}
+
+/// Marks the UI node that displays the focused interactable's prompt.
+#[derive(Component)]
+pub struct InteractionPrompt;
+
+/// Distance in world units the prompt is shown above the interactable.
+const PROMPT_OFFSET: f32 = 40.0;
+
+/// Focuses the nearest interactable whose radius contains the interactor.
+fn update_interaction_focus(
+    mut focus: ResMut<InteractionFocus>,
+    interactors: Query<&GlobalTransform, With<Interactor>>,
+    interactables: Query<(Entity, &Interactable, &GlobalTransform)>,
+) {
+    let target = interactors.get_single().ok().and_then(|interactor| {
+        let position = interactor.translation().truncate();
+        interactables
+            .iter()
+            .filter_map(|(entity, interactable, transform)| {
+                let distance = transform.translation().truncate().distance(position);
+                (distance <= interactable.radius).then_some((entity, distance))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(entity, _)| entity)
+    });
+    if focus.target != target {
+        focus.target = target;
+    }
+}
+
+/// Keeps a single prompt node over the focused interactable.
+fn show_interaction_prompt(
+    mut commands: Commands,
+    focus: Res<InteractionFocus>,
+    device: Option<Res<InputDevice>>,
+    interactables: Query<&Interactable>,
+    mut prompts: Query<(Entity, &mut WorldAnchoredUi, &mut Text), With<InteractionPrompt>>,
+) {
+    let focused = focus
+        .target
+        .and_then(|target| Some((target, interactables.get(target).ok()?)));
+
+    let Some((target, interactable)) = focused else {
+        for (entity, _, _) in &prompts {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+
+    let text =
+        interactable.prompt_text_for(device.map_or_else(InputDevice::default, |device| *device));
+    match prompts.get_single_mut() {
+        Ok((_, mut anchor, mut prompt)) => {
+            if anchor.target != target {
+                anchor.target = target;
+            }
+            if prompt.0 != text {
+                prompt.0 = text;
+            }
+        }
+        Err(_) => {
+            commands.spawn((
+                InteractionPrompt,
+                Text::new(text),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                WorldAnchoredUi::new(target).with_offset(Vec2::new(0.0, PROMPT_OFFSET)),
+            ));
+        }
+    }
+}
+
+/// Sends an `InteractEvent` when the focused interactable's action is pressed.
+fn interact(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    gamepads: Query<&Gamepad>,
+    focus: Res<InteractionFocus>,
+    interactors: Query<Entity, With<Interactor>>,
+    interactables: Query<&Interactable>,
+    mut events: EventWriter<InteractEvent>,
+) {
+    let Some(target) = focus.target else {
+        return;
+    };
+    let (Ok(interactor), Ok(interactable)) = (interactors.get_single(), interactables.get(target))
+    else {
+        return;
+    };
+    if interactable
+        .binding()
+        .just_pressed(keyboard.as_deref(), &gamepads)
+    {
+        events.send(InteractEvent { interactor, target });
+    }
+}
@@ -0,0 +1,165 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Joints that tie two physics bodies together.
+//!
+//! A `DistanceJoint` keeps an anchor point on its entity a fixed `length`
+//! from an anchor point on the `connected` entity, like a rigid rod: a tow
+//! cable, a pendulum, or a chain link. Anchors are offsets in each entity's
+//! local space, so they turn and scale with it.
+//!
+//! Bodies with a `LinearVelocity` are moved to satisfy the joint and lose
+//! the part of their velocity that would stretch or squash it; bodies
+//! without one are treated as fixed. Joints act on translations, so both
+//! entities should be unparented.
+//!
+//! # Example
+//! ```ignore
+//! let hook = commands.spawn(Transform::from_xyz(0.0, 200.0, 0.0)).id();
+//! commands.spawn((
+//!     DistanceJoint::new(hook, 120.0),
+//!     LinearVelocity::default(),
+//!     Transform::from_xyz(120.0, 200.0, 0.0),
+//! ));
+//! ```
+
+use bevy::ecs::entity::{VisitEntities, VisitEntitiesMut};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+
+use crate::editor_state::GameplaySystemSet;
+use crate::inspector::InspectorOptions;
+use crate::physics::{integrate_velocity, LinearVelocity};
+use crate::system_toggle::ToggleableSystem;
+
+/// Plugin that solves joints after velocities are integrated.
+pub struct JointPlugin;
+
+impl Plugin for JointPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<DistanceJoint>().add_systems(
+            Update,
+            solve_distance_joints
+                .toggleable()
+                .after(integrate_velocity)
+                .in_set(GameplaySystemSet),
+        );
+    }
+}
+
+/// Holds an anchor on this entity at a fixed distance from an anchor on
+/// another entity.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component, Default, MapEntities)]
+#[require(Transform)]
+pub struct DistanceJoint {
+    /// Entity on the other end; the joint does nothing without one.
+    pub connected: Option<Entity>,
+    /// Attachment point in this entity's local space.
+    pub anchor: Vec2,
+    /// Attachment point in the connected entity's local space.
+    pub connected_anchor: Vec2,
+    /// Distance kept between the two anchors.
+    #[reflect(@InspectorOptions::min(0.0).with_step(1.0))]
+    pub length: f32,
+}
+
+impl Default for DistanceJoint {
+    fn default() -> Self {
+        Self {
+            connected: None,
+            anchor: Vec2::ZERO,
+            connected_anchor: Vec2::ZERO,
+            length: 100.0,
+        }
+    }
+}
+
+impl DistanceJoint {
+    /// Joins the entity's origin to `connected`'s origin, `length` apart.
+    pub fn new(connected: Entity, length: f32) -> Self {
+        Self {
+            connected: Some(connected),
+            length,
+            ..default()
+        }
+    }
+
+    /// World positions of the anchor and the connected anchor, given the
+    /// two entities' transforms.
+    pub fn world_anchors(&self, transform: &Transform, connected: &Transform) -> (Vec2, Vec2) {
+        (
+            transform
+                .transform_point(self.anchor.extend(0.0))
+                .truncate(),
+            connected
+                .transform_point(self.connected_anchor.extend(0.0))
+                .truncate(),
+        )
+    }
+}
+
+impl VisitEntities for DistanceJoint {
+    fn visit_entities<F: FnMut(Entity)>(&self, mut f: F) {
+        if let Some(connected) = self.connected {
+            f(connected);
+        }
+    }
+}
+
+impl VisitEntitiesMut for DistanceJoint {
+    fn visit_entities_mut<F: FnMut(&mut Entity)>(&mut self, mut f: F) {
+        if let Some(connected) = &mut self.connected {
+            f(connected);
+        }
+    }
+}
+
+/// Pulls or pushes jointed bodies back to their joint's length.
+fn solve_distance_joints(
+    joints: Query<(Entity, &DistanceJoint)>,
+    mut bodies: Query<(&mut Transform, Option<&mut LinearVelocity>)>,
+) {
+    for (entity, joint) in &joints {
+        let Some(connected) = joint.connected else {
+            continue;
+        };
+        let Ok([(mut transform, mut velocity), (mut other, mut other_velocity)]) =
+            bodies.get_many_mut([entity, connected])
+        else {
+            continue;
+        };
+
+        // Share the correction between the bodies that can move
+        let weight = if velocity.is_some() { 1.0 } else { 0.0 };
+        let other_weight = if other_velocity.is_some() { 1.0 } else { 0.0 };
+        let total = weight + other_weight;
+        if total == 0.0 {
+            continue;
+        }
+
+        let (anchor, other_anchor) = joint.world_anchors(&transform, &other);
+        let offset = other_anchor - anchor;
+        let distance = offset.length();
+        if distance <= f32::EPSILON {
+            continue;
+        }
+        let normal = offset / distance;
+        let stretch = distance - joint.length;
+        transform.translation += (normal * stretch * weight / total).extend(0.0);
+        other.translation -= (normal * stretch * other_weight / total).extend(0.0);
+
+        // Drop the velocity along the joint so the bodies swing instead of bouncing
+        let own = velocity.as_ref().map_or(Vec2::ZERO, |velocity| velocity.0);
+        let theirs = other_velocity
+            .as_ref()
+            .map_or(Vec2::ZERO, |velocity| velocity.0);
+        let separating = (theirs - own).dot(normal);
+        if let Some(velocity) = &mut velocity {
+            velocity.0 += normal * separating * weight / total;
+        }
+        if let Some(velocity) = &mut other_velocity {
+            velocity.0 -= normal * separating * other_weight / total;
+        }
+    }
+}
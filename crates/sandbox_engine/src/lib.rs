@@ -4,45 +4,242 @@
 //! Sandbox Engine - A Bevy-based 2D game engine
 //!
 //! This crate provides common 2D game setup and utilities built on top of Bevy.
+//!
+//! Optional subsystems sit behind cargo features so games compile only what
+//! they use:
+//! - `physics` (default): colliders, velocity, contacts, joints, projectiles, and the debug overlay
+//! - `particles` (default): particle emitters and their simulation
+//! - `audio` (default): ambient sounds, audio zones, and ambient presets (enables `particles`)
+//! - `embedded_assets` (default): built-in fallback assets
+//! - `bench`: Criterion helpers for benchmarks
+//! - `test_utils`: golden image tests that render scenes headlessly
+//!
+//! Gated subsystems join [`SandboxPlugins`] when enabled. Smaller modules that
+//! cost nothing unless used (scenes, dialogue, bitmap fonts, telemetry, and
+//! the like) are always compiled; leave their plugins out with `.disable::<X>()`.
 
 pub use bevy;
 
+#[cfg(feature = "audio")]
+pub mod ambient;
 pub mod assets;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod bitmap_font;
+pub mod color_grade;
+pub mod destructible;
+pub mod determinism;
+pub mod dialogue;
 pub mod editor_state;
+#[cfg(feature = "embedded_assets")]
+pub mod embedded;
+pub mod flags;
+pub mod input_prompt;
+pub mod inspector;
+pub mod interaction;
+#[cfg(feature = "physics")]
+pub mod joints;
+#[cfg(feature = "particles")]
+pub mod particles;
+#[cfg(feature = "physics")]
+pub mod physics;
+pub mod prefab;
+pub mod project;
+#[cfg(feature = "physics")]
+pub mod projectile;
 pub mod scene;
+pub mod sequence;
+pub mod spawn;
+pub mod status_effect;
+pub mod system_toggle;
+pub mod team;
+pub mod telemetry;
+#[cfg(feature = "test_utils")]
+pub mod test_utils;
+pub mod time_control;
+pub mod tint;
+pub mod world_ui;
+pub mod wrap;
 
 pub mod prelude {
-    pub use crate::assets::{AnimationFrame, AssetPath, AssetPathPlugin, SpriteAnimation};
+    #[cfg(feature = "audio")]
+    pub use crate::ambient::{
+        AmbientPreset, AmbientSound, AmbientSoundPlugin, AudioZone, AudioZoneKind,
+    };
+    pub use crate::assets::{
+        AnimationFrame, AssetPath, AssetPathPlugin, AutoSized, SpriteAnimation, SpriteVariant,
+    };
+    pub use crate::bitmap_font::{
+        BitmapFont, BitmapFontError, BitmapFontLoader, BitmapFontPlugin, BitmapGlyph, BitmapText,
+        BitmapTextAlign,
+    };
+    pub use crate::color_grade::{ColorGrade, ColorGradePlugin, ColorGrades, ColorGroup};
+    pub use crate::destructible::{
+        Destructible, DestructiblePlugin, DestructionEvent, DestructionEventKind, DestructionStage,
+    };
+    pub use crate::dialogue::{
+        ActiveDialogue, Dialogue, DialogueChoice, DialogueEvent, DialogueEventKind, DialogueLine,
+        DialogueNode, DialoguePlugin, DialogueRunner, DialogueSettings, DialogueStep,
+        DialogueTrigger,
+    };
     pub use crate::editor_state::{
         EditorPlayState, EditorSnapshot, EditorStatePlugin, EntityState, GameplaySystemSet,
     };
+    #[cfg(feature = "embedded_assets")]
+    pub use crate::embedded::{DefaultAssets, EmbeddedAssetsPlugin, UiSkin};
+    pub use crate::flags::{
+        flag_condition, flag_set, FlagChange, FlagCondition, FlagValue, WorldFlags,
+        WorldFlagsPlugin,
+    };
+    pub use crate::input_prompt::{
+        ActionBinding, GamepadKind, InputDevice, InputPrompt, InputPromptPlugin,
+    };
+    pub use crate::inspector::InspectorOptions;
+    pub use crate::interaction::{
+        InteractEvent, Interactable, InteractablePlugin, InteractionFocus, Interactor,
+    };
+    #[cfg(feature = "physics")]
+    pub use crate::joints::{DistanceJoint, JointPlugin};
+    #[cfg(feature = "particles")]
+    pub use crate::particles::{
+        EmitterShape, Particle, ParticleEmitter, ParticlePlugin, ParticlePreview,
+    };
+    #[cfg(feature = "physics")]
+    pub use crate::physics::{
+        Attractor, Collider, ColliderShape, Contact, Falloff, LinearVelocity, PhysicsContacts,
+        PhysicsDebugPlugin, PhysicsDebugSettings, PhysicsPlugin, Repulsor, Sensor,
+    };
+    pub use crate::prefab::{
+        apply_to_prefab, prefab_overrides, prefab_root, revert_to_prefab, OverrideKind,
+        PrefabOverride, PrefabSource,
+    };
+    pub use crate::project::{ProjectRoot, ProjectSettings};
+    #[cfg(feature = "physics")]
+    pub use crate::projectile::{
+        Projectile, ProjectileEvent, ProjectileEventKind, ProjectilePlugin,
+    };
     pub use crate::scene::{
-        clear_scene_entities, load_scene, new_scene, save_scene, spawn_prefab, SceneError,
-        SceneManager, ScenePlugin, SceneResult,
+        clear_scene_entities, instantiate_prefab, load_scene, new_scene, owning_instance,
+        save_scene, spawn_prefab, validate_scene, EditorGroup, EditorLocked, EditorNote,
+        ReflectEditorOnly, RegisterSceneResourceExt, SceneCleared, SceneEntityId, SceneError,
+        SceneExcluded, SceneFilter, SceneFormat, SceneInstance, SceneIssue, SceneLoadFailed,
+        SceneLoadSettings, SceneLoaded, SceneManager, ScenePlugin, SceneResources, SceneResult,
+        SceneSaved, SceneSerialized,
     };
-    pub use crate::SandboxPlugin;
+    pub use crate::sequence::{
+        sequence_playing, Sequence, SequenceCommand, SequenceEvent, SequenceEventKind,
+        SequencePlayback, SequencePlayer, SequencePlugin, SequenceStep,
+    };
+    pub use crate::spawn::{SpawnKind, SpawnPoint, SpawnPointPlugin, SpawnPoints, PLAYER_START};
+    pub use crate::status_effect::{
+        Status, StatusCommandsExt, StatusEffect, StatusEffectPlugin, StatusEvent, StatusEventKind,
+    };
+    pub use crate::system_toggle::{SystemTogglePlugin, SystemToggles, ToggleableSystem};
+    pub use crate::team::{Relation, Team, TeamPlugin, TeamTable};
+    pub use crate::telemetry::{
+        GameTelemetry, NoTelemetry, Telemetry, TelemetryPlugin, TelemetryValue,
+    };
+    pub use crate::time_control::{
+        LocalTimeScale, PauseExempt, SlowMotionCurve, TimeControl, TimeControlPlugin,
+    };
+    pub use crate::tint::{
+        CurrentTint, GlobalTint, GlobalTintPlugin, TimeOfDay, TintGradient, TintKey, Untinted,
+    };
+    pub use crate::world_ui::{WorldAnchoredUi, WorldUiPlugin};
+    pub use crate::wrap::{WrapAround, WrapAroundPlugin};
+    pub use crate::{SandboxPlugin, SandboxPlugins};
     pub use bevy::prelude::*;
 }
 
+use bevy::app::PluginGroupBuilder;
 use bevy::prelude::*;
+use project::ProjectRoot;
 
 /// Main plugin that bundles common 2D game setup.
 ///
 /// This plugin adds:
-/// - Default Bevy plugins
+/// - Default Bevy plugins, loading assets from the project's assets directory
+/// - The resolved `ProjectRoot` resource
+/// - Built-in fallback assets (with the `embedded_assets` feature)
 /// - A 2D camera
 pub struct SandboxPlugin;
 
 impl Plugin for SandboxPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(DefaultPlugins.set(WindowPlugin {
-            primary_window: Some(Window {
-                title: "Sandbox Engine".into(),
-                ..default()
-            }),
-            ..default()
-        }))
+        let project_root = ProjectRoot::discover();
+
+        app.add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: Some(Window {
+                        title: "Sandbox Engine".into(),
+                        ..default()
+                    }),
+                    ..default()
+                })
+                .set(project_root.asset_plugin()),
+        )
+        .insert_resource(project_root)
         .add_systems(Startup, setup_camera);
+
+        #[cfg(feature = "embedded_assets")]
+        app.add_plugins(embedded::EmbeddedAssetsPlugin);
+    }
+}
+
+/// Plugin group with the engine's subsystems enabled by cargo features.
+///
+/// Add it after `DefaultPlugins` (or [`SandboxPlugin`]). Individual plugins
+/// can be left out:
+/// ```ignore
+/// app.add_plugins(SandboxPlugins.build().disable::<PhysicsDebugPlugin>());
+/// ```
+///
+/// Contains `EditorStatePlugin`, `SystemTogglePlugin`, `ScenePlugin`,
+/// `TelemetryPlugin`, `AssetPathPlugin`, `TimeControlPlugin`,
+/// `WorldFlagsPlugin`, `InputPromptPlugin`, `InteractablePlugin`,
+/// `DialoguePlugin`, `SequencePlugin`, `SpawnPointPlugin`,
+/// `DestructiblePlugin`, `TeamPlugin`, `GlobalTintPlugin`, `ColorGradePlugin`,
+/// `WorldUiPlugin`, `BitmapFontPlugin`, and `WrapAroundPlugin`, plus
+/// `ParticlePlugin` with the `particles` feature, `AmbientSoundPlugin` with
+/// `audio`, and `PhysicsPlugin`, `JointPlugin`, `ProjectilePlugin`, and
+/// `PhysicsDebugPlugin` with `physics`. Generic plugins such as
+/// `StatusEffectPlugin::<T>` are added per type by the game.
+pub struct SandboxPlugins;
+
+impl PluginGroup for SandboxPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        let group = PluginGroupBuilder::start::<Self>()
+            .add(editor_state::EditorStatePlugin)
+            .add(system_toggle::SystemTogglePlugin)
+            .add(scene::ScenePlugin)
+            .add(telemetry::TelemetryPlugin)
+            .add(assets::AssetPathPlugin)
+            .add(time_control::TimeControlPlugin)
+            .add(flags::WorldFlagsPlugin)
+            .add(input_prompt::InputPromptPlugin)
+            .add(interaction::InteractablePlugin)
+            .add(dialogue::DialoguePlugin)
+            .add(sequence::SequencePlugin)
+            .add(spawn::SpawnPointPlugin)
+            .add(destructible::DestructiblePlugin)
+            .add(team::TeamPlugin)
+            .add(tint::GlobalTintPlugin)
+            .add(color_grade::ColorGradePlugin)
+            .add(world_ui::WorldUiPlugin)
+            .add(bitmap_font::BitmapFontPlugin)
+            .add(wrap::WrapAroundPlugin);
+        #[cfg(feature = "particles")]
+        let group = group.add(particles::ParticlePlugin);
+        #[cfg(feature = "audio")]
+        let group = group.add(ambient::AmbientSoundPlugin);
+        #[cfg(feature = "physics")]
+        let group = group
+            .add(physics::PhysicsPlugin)
+            .add(joints::JointPlugin)
+            .add(projectile::ProjectilePlugin)
+            .add(physics::PhysicsDebugPlugin);
+        group
     }
 }
 
@@ -0,0 +1,351 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Simple sprite particles for dust, snow, sparks, and other ambience.
+//!
+//! A `ParticleEmitter` spawns `rate` particles a second within its
+//! `EmitterShape` while gameplay runs. Each particle is a small colored
+//! sprite, a child of its emitter, that flies at `speed` in `direction`
+//! (give or take `spread`), accelerates by `acceleration`, and fades from
+//! `color` to `end_color` over its `lifetime`.
+//!
+//! Particles are runtime-only: they are never saved with the scene, aren't
+//! listed in the editor's hierarchy, and are cleared when the editor stops.
+//! While [`ParticlePreview`] is enabled, emitters also run while the editor is
+//! stopped, so effects can be tuned without playing; their particles are
+//! cleared when the preview is turned off or play starts.
+//!
+//! # Example
+//! ```ignore
+//! commands.spawn((
+//!     Name::new("Sparks"),
+//!     ParticleEmitter {
+//!         shape: EmitterShape::Cone { angle: 30.0, radius: 8.0 },
+//!         rate: 40.0,
+//!         ..default()
+//!     },
+//!     Transform::from_xyz(0.0, -20.0, 1.0),
+//! ));
+//! ```
+
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+use crate::editor_state::{EditorPlayState, GameplaySystemSet};
+use crate::inspector::InspectorOptions;
+use crate::scene::SceneExcluded;
+use crate::system_toggle::ToggleableSystem;
+
+/// Plugin that emits and moves particles.
+pub struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ParticleEmitter>()
+            .register_type::<EmitterShape>()
+            .init_resource::<ParticlePreview>()
+            .add_systems(
+                Update,
+                (emit_particles.toggleable(), update_particles.toggleable())
+                    .chain()
+                    .in_set(GameplaySystemSet),
+            )
+            .add_systems(
+                Update,
+                (
+                    clear_particles.run_if(preview_turned_off),
+                    (emit_particles.toggleable(), update_particles.toggleable())
+                        .chain()
+                        .run_if(preview_running),
+                )
+                    .chain(),
+            )
+            .add_systems(OnEnter(EditorPlayState::Stopped), clear_particles)
+            .add_systems(OnExit(EditorPlayState::Stopped), clear_particles);
+    }
+}
+
+/// Runs particle emitters while the editor is stopped.
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParticlePreview {
+    /// Whether emitters simulate outside of play.
+    pub enabled: bool,
+}
+
+/// Whether the preview should simulate emitters this frame.
+fn preview_running(
+    preview: Res<ParticlePreview>,
+    state: Option<Res<State<EditorPlayState>>>,
+) -> bool {
+    preview.enabled && state.is_some_and(|state| *state.get() == EditorPlayState::Stopped)
+}
+
+/// Whether the preview was just turned off while stopped, leaving its
+/// particles behind.
+fn preview_turned_off(
+    preview: Res<ParticlePreview>,
+    state: Option<Res<State<EditorPlayState>>>,
+) -> bool {
+    preview.is_changed()
+        && !preview.enabled
+        && state.is_some_and(|state| *state.get() == EditorPlayState::Stopped)
+}
+
+/// Where an emitter's particles start, in its local space.
+#[derive(Reflect, Default, Clone, Copy, Debug, PartialEq)]
+pub enum EmitterShape {
+    /// All particles start at the emitter.
+    #[default]
+    Point,
+    /// Particles start anywhere inside a circle.
+    Circle {
+        #[reflect(@InspectorOptions::min(0.0))]
+        radius: f32,
+    },
+    /// Particles start anywhere inside a rectangle.
+    Box {
+        #[reflect(@InspectorOptions::min(0.0).with_tooltip("Half the width and height"))]
+        half_size: Vec2,
+    },
+    /// Particles start inside a slice of a circle around `direction` and fly
+    /// straight out of it, ignoring `spread`.
+    Cone {
+        #[reflect(@InspectorOptions::range(0.0, 180.0).with_tooltip("Degrees either side of the direction"))]
+        angle: f32,
+        #[reflect(@InspectorOptions::min(0.0))]
+        radius: f32,
+    },
+}
+
+/// Spawns particles while gameplay runs.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+#[require(Transform, Visibility, EmitterState)]
+pub struct ParticleEmitter {
+    /// Where particles start.
+    pub shape: EmitterShape,
+    /// Particles spawned per second.
+    #[reflect(@InspectorOptions::min(0.0))]
+    pub rate: f32,
+    /// Seconds each particle lives.
+    #[reflect(@InspectorOptions::min(0.0))]
+    pub lifetime: f32,
+    /// Starting speed in units per second.
+    #[reflect(@InspectorOptions::min(0.0).with_step(1.0))]
+    pub speed: f32,
+    /// Direction particles fly in, in degrees counterclockwise from +X.
+    pub direction: f32,
+    /// Degrees either side of `direction` a particle may fly in.
+    #[reflect(@InspectorOptions::range(0.0, 180.0))]
+    pub spread: f32,
+    /// Change in velocity per second, e.g. gravity or wind.
+    pub acceleration: Vec2,
+    /// Width and height of each particle.
+    #[reflect(@InspectorOptions::min(0.0))]
+    pub size: f32,
+    /// Color a particle starts with.
+    pub color: Color,
+    /// Color a particle fades to by the end of its life.
+    pub end_color: Color,
+    /// Most particles alive at once.
+    pub max_particles: u32,
+    /// Whether new particles are spawned; live ones carry on either way.
+    pub emitting: bool,
+}
+
+impl Default for ParticleEmitter {
+    fn default() -> Self {
+        Self {
+            shape: EmitterShape::Point,
+            rate: 10.0,
+            lifetime: 2.0,
+            speed: 50.0,
+            direction: 90.0,
+            spread: 15.0,
+            acceleration: Vec2::ZERO,
+            size: 4.0,
+            color: Color::WHITE,
+            end_color: Color::WHITE.with_alpha(0.0),
+            max_particles: 500,
+            emitting: true,
+        }
+    }
+}
+
+/// A live particle, spawned as a child of its emitter. Particles are never
+/// saved.
+#[derive(Component, Clone, Debug)]
+#[require(Transform, Visibility, SceneExcluded)]
+pub struct Particle {
+    /// Velocity in the emitter's space, in units per second.
+    pub velocity: Vec2,
+    /// Change in velocity per second.
+    pub acceleration: Vec2,
+    /// Seconds since the particle spawned.
+    pub age: f32,
+    /// Seconds the particle lives.
+    pub lifetime: f32,
+    /// Color at the start of its life.
+    pub color: Color,
+    /// Color at the end of its life.
+    pub end_color: Color,
+}
+
+impl Particle {
+    /// The particle's color at its current age.
+    pub fn current_color(&self) -> Color {
+        let t = if self.lifetime > 0.0 {
+            (self.age / self.lifetime).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        self.color.mix(&self.end_color, t)
+    }
+}
+
+/// Emission progress of an emitter.
+#[derive(Component, Default)]
+struct EmitterState {
+    /// Fraction of a particle owed from previous frames.
+    pending: f32,
+    /// Random number state; seeded from the entity on first use.
+    rng: u64,
+}
+
+impl EmitterState {
+    /// Next random number in `0.0..1.0` (xorshift).
+    fn next(&mut self) -> f32 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        (self.rng >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Random number in `-1.0..1.0`.
+    fn signed(&mut self) -> f32 {
+        self.next() * 2.0 - 1.0
+    }
+}
+
+/// Spawns the particles each emitter owes for this frame.
+fn emit_particles(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut emitters: Query<(
+        Entity,
+        &ParticleEmitter,
+        &mut EmitterState,
+        Option<&Children>,
+    )>,
+    particles: Query<(), With<Particle>>,
+) {
+    for (entity, emitter, mut state, children) in &mut emitters {
+        if !emitter.emitting || emitter.lifetime <= 0.0 {
+            state.pending = 0.0;
+            continue;
+        }
+        if state.rng == 0 {
+            // Any nonzero seed works; mixing the entity in keeps emitters apart
+            state.rng = entity.to_bits() | 1;
+        }
+
+        state.pending += emitter.rate.max(0.0) * time.delta_secs();
+        let owed = state.pending.floor();
+        state.pending -= owed;
+
+        let alive = children.map_or(0, |children| {
+            children
+                .iter()
+                .filter(|&&child| particles.contains(child))
+                .count()
+        });
+        let room = (emitter.max_particles as usize).saturating_sub(alive);
+        let count = (owed as usize).min(room);
+        if count == 0 {
+            continue;
+        }
+
+        commands.entity(entity).with_children(|parent| {
+            for _ in 0..count {
+                let (position, velocity) = launch(emitter, &mut state);
+                parent.spawn((
+                    Particle {
+                        velocity,
+                        acceleration: emitter.acceleration,
+                        age: 0.0,
+                        lifetime: emitter.lifetime,
+                        color: emitter.color,
+                        end_color: emitter.end_color,
+                    },
+                    Sprite {
+                        color: emitter.color,
+                        custom_size: Some(Vec2::splat(emitter.size)),
+                        ..default()
+                    },
+                    Transform::from_translation(position.extend(0.0)),
+                ));
+            }
+        });
+    }
+}
+
+/// Picks a starting position and velocity for a new particle.
+fn launch(emitter: &ParticleEmitter, state: &mut EmitterState) -> (Vec2, Vec2) {
+    let direction = emitter.direction.to_radians();
+    match emitter.shape {
+        EmitterShape::Cone { angle, radius } => {
+            let heading = direction + state.signed() * angle.to_radians();
+            let along = Vec2::from_angle(heading);
+            let position = along * radius * state.next().sqrt();
+            (position, along * emitter.speed)
+        }
+        shape => {
+            let position = match shape {
+                EmitterShape::Circle { radius } => {
+                    Vec2::from_angle(state.next() * TAU) * radius * state.next().sqrt()
+                }
+                EmitterShape::Box { half_size } => {
+                    Vec2::new(state.signed(), state.signed()) * half_size
+                }
+                _ => Vec2::ZERO,
+            };
+            let heading = direction + state.signed() * emitter.spread.to_radians();
+            (position, Vec2::from_angle(heading) * emitter.speed)
+        }
+    }
+}
+
+/// Ages, moves, and fades particles, despawning them when their time is up.
+fn update_particles(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut particles: Query<(Entity, &mut Particle, &mut Transform, &mut Sprite)>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut particle, mut transform, mut sprite) in &mut particles {
+        particle.age += delta;
+        if particle.age >= particle.lifetime {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        let acceleration = particle.acceleration;
+        particle.velocity += acceleration * delta;
+        transform.translation += (particle.velocity * delta).extend(0.0);
+        sprite.color = particle.current_color();
+    }
+}
+
+/// Removes every particle when the editor stops or starts playing, or the
+/// preview is turned off, so effects restart cleanly.
+fn clear_particles(
+    mut commands: Commands,
+    particles: Query<Entity, With<Particle>>,
+    mut emitters: Query<&mut EmitterState>,
+) {
+    for entity in &particles {
+        commands.entity(entity).despawn_recursive();
+    }
+    for mut state in &mut emitters {
+        state.pending = 0.0;
+    }
+}
@@ -0,0 +1,590 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Lightweight 2D physics primitives and debug visualization.
+//!
+//! `PhysicsPlugin` provides collider shapes, trigger areas (`Sensor`), linear
+//! velocity integration, force fields (`Attractor`, `Repulsor`), and overlap
+//! detection into the `PhysicsContacts` resource. Colliders on friendly
+//! teams (see [`crate::team`]) don't touch. `PhysicsDebugPlugin` draws
+//! colliders, velocities, and contacts with Bevy gizmos.
+//!
+//! # Example
+//! ```ignore
+//! commands.spawn((
+//!     Collider::circle(20.0),
+//!     LinearVelocity(Vec2::new(100.0, 0.0)),
+//!     Transform::default(),
+//! ));
+//! ```
+
+use bevy::prelude::*;
+
+use crate::editor_state::GameplaySystemSet;
+use crate::inspector::InspectorOptions;
+use crate::project::ProjectSettings;
+use crate::system_toggle::ToggleableSystem;
+use crate::team::{Team, TeamTable};
+use crate::time_control::LocalTimeScale;
+
+/// Plugin that sets up colliders, velocity integration, and contact detection.
+pub struct PhysicsPlugin;
+
+impl Plugin for PhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Collider>()
+            .register_type::<ColliderShape>()
+            .register_type::<Sensor>()
+            .register_type::<LinearVelocity>()
+            .register_type::<Falloff>()
+            .register_type::<Attractor>()
+            .register_type::<Repulsor>()
+            .init_resource::<PhysicsContacts>()
+            .add_systems(
+                Update,
+                (
+                    (
+                        apply_force_fields.toggleable(),
+                        integrate_velocity.toggleable(),
+                    )
+                        .chain()
+                        .in_set(GameplaySystemSet),
+                    detect_contacts,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Shape of a collider in the entity's local space.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub enum ColliderShape {
+    /// Circle centered on the entity.
+    Circle {
+        #[reflect(@InspectorOptions::min(0.0))]
+        radius: f32,
+    },
+    /// Rectangle centered on the entity, rotated with it.
+    Rectangle {
+        #[reflect(@InspectorOptions::min(0.0).with_tooltip("Half the width and height"))]
+        half_size: Vec2,
+    },
+}
+
+impl Default for ColliderShape {
+    fn default() -> Self {
+        Self::Circle { radius: 16.0 }
+    }
+}
+
+/// Collision shape attached to an entity.
+///
+/// The shape is scaled and rotated by the entity's `GlobalTransform`.
+#[derive(Component, Reflect, Default, Clone, Copy, Debug)]
+#[reflect(Component, Default)]
+pub struct Collider {
+    pub shape: ColliderShape,
+}
+
+impl Collider {
+    /// Creates a circle collider.
+    pub fn circle(radius: f32) -> Self {
+        Self {
+            shape: ColliderShape::Circle { radius },
+        }
+    }
+
+    /// Creates a rectangle collider with the given full size.
+    pub fn rectangle(size: Vec2) -> Self {
+        Self {
+            shape: ColliderShape::Rectangle {
+                half_size: size / 2.0,
+            },
+        }
+    }
+}
+
+/// Marks a collider as a trigger area that reports overlaps but is not solid.
+#[derive(Component, Reflect, Default, Clone, Copy, Debug)]
+#[reflect(Component, Default)]
+pub struct Sensor;
+
+/// Linear velocity in world units per second, applied to `Transform` while playing.
+#[derive(Component, Reflect, Default, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct LinearVelocity(pub Vec2);
+
+/// How a force field or explosion weakens between its center and its radius.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Falloff {
+    /// Full strength everywhere inside the radius.
+    Constant,
+    /// Fades linearly to nothing at the radius.
+    #[default]
+    Linear,
+    /// Fades quadratically, so the pull is concentrated near the center.
+    Quadratic,
+}
+
+impl Falloff {
+    /// Strength multiplier at `distance` from the center of a field of `radius`.
+    pub fn factor(self, distance: f32, radius: f32) -> f32 {
+        if radius <= 0.0 || distance > radius {
+            return 0.0;
+        }
+        let remaining = 1.0 - distance / radius;
+        match self {
+            Self::Constant => 1.0,
+            Self::Linear => remaining,
+            Self::Quadratic => remaining * remaining,
+        }
+    }
+}
+
+/// Pulls bodies (entities with `LinearVelocity`) within `radius` towards the entity.
+///
+/// For tractor beams, black holes, and pickup magnets. The radius is in world
+/// units and is not scaled by the transform.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct Attractor {
+    /// Acceleration at the center, in world units per second squared.
+    #[reflect(@InspectorOptions::min(0.0).with_step(1.0))]
+    pub strength: f32,
+    /// Distance within which bodies are affected.
+    #[reflect(@InspectorOptions::min(0.0).with_step(1.0))]
+    pub radius: f32,
+    /// How the pull weakens towards the radius.
+    pub falloff: Falloff,
+}
+
+impl Default for Attractor {
+    fn default() -> Self {
+        Self::new(200.0, 150.0)
+    }
+}
+
+impl Attractor {
+    /// Creates an attractor with linear falloff.
+    pub fn new(strength: f32, radius: f32) -> Self {
+        Self {
+            strength,
+            radius,
+            falloff: Falloff::Linear,
+        }
+    }
+
+    /// Acceleration of a body at `offset` from the attractor.
+    pub fn acceleration(&self, offset: Vec2) -> Vec2 {
+        field_acceleration(
+            -offset,
+            offset.length(),
+            self.strength,
+            self.radius,
+            self.falloff,
+        )
+    }
+}
+
+/// Pushes bodies (entities with `LinearVelocity`) within `radius` away from the entity.
+///
+/// The counterpart of [`Attractor`], e.g. for shields and explosions.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct Repulsor {
+    /// Acceleration at the center, in world units per second squared.
+    #[reflect(@InspectorOptions::min(0.0).with_step(1.0))]
+    pub strength: f32,
+    /// Distance within which bodies are affected.
+    #[reflect(@InspectorOptions::min(0.0).with_step(1.0))]
+    pub radius: f32,
+    /// How the push weakens towards the radius.
+    pub falloff: Falloff,
+}
+
+impl Default for Repulsor {
+    fn default() -> Self {
+        Self::new(200.0, 150.0)
+    }
+}
+
+impl Repulsor {
+    /// Creates a repulsor with linear falloff.
+    pub fn new(strength: f32, radius: f32) -> Self {
+        Self {
+            strength,
+            radius,
+            falloff: Falloff::Linear,
+        }
+    }
+
+    /// Acceleration of a body at `offset` from the repulsor.
+    pub fn acceleration(&self, offset: Vec2) -> Vec2 {
+        field_acceleration(
+            offset,
+            offset.length(),
+            self.strength,
+            self.radius,
+            self.falloff,
+        )
+    }
+}
+
+/// Acceleration along `direction` for a body `distance` from a field's center.
+fn field_acceleration(
+    direction: Vec2,
+    distance: f32,
+    strength: f32,
+    radius: f32,
+    falloff: Falloff,
+) -> Vec2 {
+    direction.normalize_or_zero() * strength * falloff.factor(distance, radius)
+}
+
+/// A single overlap between two colliders.
+#[derive(Clone, Copy, Debug)]
+pub struct Contact {
+    pub a: Entity,
+    pub b: Entity,
+    /// Approximate world-space contact point.
+    pub point: Vec2,
+    /// Whether either collider is a `Sensor`.
+    pub sensor: bool,
+}
+
+/// Resource listing the collider overlaps found this frame.
+#[derive(Resource, Default, Debug)]
+pub struct PhysicsContacts {
+    pub contacts: Vec<Contact>,
+}
+
+/// A collider resolved into world space.
+#[derive(Clone, Copy, Debug)]
+pub enum WorldShape {
+    Circle {
+        center: Vec2,
+        radius: f32,
+    },
+    Rectangle {
+        center: Vec2,
+        half_size: Vec2,
+        rotation: Rot2,
+    },
+}
+
+impl WorldShape {
+    /// Resolves a collider against the entity's global transform.
+    pub fn new(collider: &Collider, transform: &GlobalTransform) -> Self {
+        let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+        let center = translation.truncate();
+        match collider.shape {
+            ColliderShape::Circle { radius } => Self::Circle {
+                center,
+                radius: radius * scale.x.abs().max(scale.y.abs()),
+            },
+            ColliderShape::Rectangle { half_size } => Self::Rectangle {
+                center,
+                half_size: half_size * scale.truncate().abs(),
+                rotation: Rot2::radians(rotation.to_euler(EulerRot::ZYX).0),
+            },
+        }
+    }
+
+    /// Returns the world-space axis-aligned bounds of the shape.
+    pub fn bounds(&self) -> Rect {
+        match *self {
+            Self::Circle { center, radius } => {
+                Rect::from_center_half_size(center, Vec2::splat(radius))
+            }
+            Self::Rectangle {
+                center,
+                half_size,
+                rotation,
+            } => {
+                let x = (rotation * Vec2::new(half_size.x, 0.0)).abs();
+                let y = (rotation * Vec2::new(0.0, half_size.y)).abs();
+                Rect::from_center_half_size(center, x + y)
+            }
+        }
+    }
+
+    /// Returns the contact point if the two shapes overlap.
+    ///
+    /// Circle pairs and circle/rectangle pairs are exact; rectangle pairs are
+    /// tested with their axis-aligned bounds.
+    pub fn contact(&self, other: &WorldShape) -> Option<Vec2> {
+        match (*self, *other) {
+            (
+                Self::Circle {
+                    center: a,
+                    radius: ra,
+                },
+                Self::Circle {
+                    center: b,
+                    radius: rb,
+                },
+            ) => {
+                let offset = b - a;
+                let distance = offset.length();
+                (distance <= ra + rb).then(|| {
+                    // Midpoint of the overlapping region along the center line
+                    let direction = offset.try_normalize().unwrap_or(Vec2::X);
+                    a + direction * (ra + distance - rb) / 2.0
+                })
+            }
+            (
+                Self::Circle { center, radius },
+                Self::Rectangle {
+                    center: box_center,
+                    half_size,
+                    rotation,
+                },
+            )
+            | (
+                Self::Rectangle {
+                    center: box_center,
+                    half_size,
+                    rotation,
+                },
+                Self::Circle { center, radius },
+            ) => {
+                let local = rotation.inverse() * (center - box_center);
+                let closest = box_center + rotation * local.clamp(-half_size, half_size);
+                (closest.distance(center) <= radius).then_some(closest)
+            }
+            (Self::Rectangle { .. }, Self::Rectangle { .. }) => {
+                let overlap = self.bounds().intersect(other.bounds());
+                (!overlap.is_empty()).then(|| overlap.center())
+            }
+        }
+    }
+}
+
+/// Accelerates bodies inside attractor and repulsor fields, honoring `LocalTimeScale`.
+///
+/// A field doesn't act on its own entity.
+fn apply_force_fields(
+    time: Res<Time>,
+    attractors: Query<(Entity, &Attractor, &GlobalTransform)>,
+    repulsors: Query<(Entity, &Repulsor, &GlobalTransform)>,
+    mut bodies: Query<(
+        Entity,
+        &mut LinearVelocity,
+        &GlobalTransform,
+        Option<&LocalTimeScale>,
+    )>,
+) {
+    if attractors.is_empty() && repulsors.is_empty() {
+        return;
+    }
+    for (body, mut velocity, transform, time_scale) in &mut bodies {
+        let position = transform.translation().truncate();
+        let mut acceleration = Vec2::ZERO;
+        for (entity, attractor, field) in &attractors {
+            if entity != body {
+                acceleration += attractor.acceleration(position - field.translation().truncate());
+            }
+        }
+        for (entity, repulsor, field) in &repulsors {
+            if entity != body {
+                acceleration += repulsor.acceleration(position - field.translation().truncate());
+            }
+        }
+        if acceleration != Vec2::ZERO {
+            velocity.0 += acceleration * LocalTimeScale::delta_secs(&time, time_scale);
+        }
+    }
+}
+
+/// Moves entities by their linear velocity, honoring `LocalTimeScale`.
+pub(crate) fn integrate_velocity(
+    time: Res<Time>,
+    mut query: Query<(&LinearVelocity, &mut Transform, Option<&LocalTimeScale>)>,
+) {
+    for (velocity, mut transform, time_scale) in &mut query {
+        let delta = LocalTimeScale::delta_secs(&time, time_scale);
+        transform.translation += (velocity.0 * delta).extend(0.0);
+    }
+}
+
+/// Query data for colliders checked by `detect_contacts`.
+type ContactCandidate<'a> = (
+    Entity,
+    &'a Collider,
+    &'a GlobalTransform,
+    Has<Sensor>,
+    Option<&'a Team>,
+);
+
+/// Finds overlapping colliders and records them in `PhysicsContacts`.
+///
+/// Colliders on teams friendly to each other are skipped.
+pub(crate) fn detect_contacts(
+    mut contacts: ResMut<PhysicsContacts>,
+    settings: Option<Res<ProjectSettings>>,
+    query: Query<ContactCandidate>,
+) {
+    contacts.contacts.clear();
+    let no_teams = TeamTable::default();
+    let teams = settings
+        .as_ref()
+        .map_or(&no_teams, |settings| &settings.teams);
+    let shapes: Vec<(Entity, WorldShape, bool, Option<&Team>)> = query
+        .iter()
+        .map(|(entity, collider, transform, sensor, team)| {
+            (entity, WorldShape::new(collider, transform), sensor, team)
+        })
+        .collect();
+
+    for (i, (a, shape_a, sensor_a, team_a)) in shapes.iter().enumerate() {
+        for (b, shape_b, sensor_b, team_b) in &shapes[i + 1..] {
+            if teams.are_allies(*team_a, *team_b) {
+                continue;
+            }
+            if let Some(point) = shape_a.contact(shape_b) {
+                contacts.contacts.push(Contact {
+                    a: *a,
+                    b: *b,
+                    point,
+                    sensor: *sensor_a || *sensor_b,
+                });
+            }
+        }
+    }
+}
+
+/// Plugin that draws colliders, velocities, contacts, and trigger areas with gizmos.
+///
+/// Drawing is off by default; toggle it with `PhysicsDebugSettings::toggle_key`
+/// (F3) or by setting `PhysicsDebugSettings::enabled`.
+pub struct PhysicsDebugPlugin;
+
+impl Plugin for PhysicsDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhysicsDebugSettings>().add_systems(
+            Update,
+            (
+                toggle_physics_debug,
+                draw_physics_debug
+                    .run_if(|settings: Res<PhysicsDebugSettings>| settings.enabled)
+                    .after(detect_contacts),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Resource controlling what the physics debug overlay draws.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct PhysicsDebugSettings {
+    /// Whether the overlay is drawn at all.
+    pub enabled: bool,
+    /// Draw solid collider outlines.
+    pub colliders: bool,
+    /// Draw velocity vectors.
+    pub velocities: bool,
+    /// Draw contact points.
+    pub contacts: bool,
+    /// Draw sensor (trigger) areas.
+    pub sensors: bool,
+    /// Seconds of travel a velocity arrow represents.
+    pub velocity_scale: f32,
+    /// Key that toggles the overlay, if any.
+    pub toggle_key: Option<KeyCode>,
+}
+
+impl Default for PhysicsDebugSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            colliders: true,
+            velocities: true,
+            contacts: true,
+            sensors: true,
+            velocity_scale: 0.25,
+            toggle_key: Some(KeyCode::F3),
+        }
+    }
+}
+
+const COLLIDER_COLOR: Color = Color::srgb(0.3, 0.9, 0.4);
+const SENSOR_COLOR: Color = Color::srgb(0.3, 0.7, 1.0);
+const VELOCITY_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+const CONTACT_COLOR: Color = Color::srgb(1.0, 0.3, 0.3);
+
+/// Flips `PhysicsDebugSettings::enabled` when the toggle key is pressed.
+fn toggle_physics_debug(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    mut settings: ResMut<PhysicsDebugSettings>,
+) {
+    let (Some(keyboard), Some(key)) = (keyboard, settings.toggle_key) else {
+        return;
+    };
+    if keyboard.just_pressed(key) {
+        settings.enabled = !settings.enabled;
+    }
+}
+
+/// Draws the overlay; contacts are skipped when `PhysicsContacts` is absent.
+fn draw_physics_debug(
+    mut gizmos: Gizmos,
+    settings: Res<PhysicsDebugSettings>,
+    contacts: Option<Res<PhysicsContacts>>,
+    colliders: Query<(&Collider, &GlobalTransform, Has<Sensor>)>,
+    velocities: Query<(&LinearVelocity, &GlobalTransform)>,
+) {
+    for (collider, transform, sensor) in &colliders {
+        let color = match sensor {
+            true if settings.sensors => SENSOR_COLOR,
+            false if settings.colliders => COLLIDER_COLOR,
+            _ => continue,
+        };
+        match WorldShape::new(collider, transform) {
+            WorldShape::Circle { center, radius } => {
+                gizmos.circle_2d(center, radius, color);
+            }
+            WorldShape::Rectangle {
+                center,
+                half_size,
+                rotation,
+            } => {
+                gizmos.rect_2d(Isometry2d::new(center, rotation), half_size * 2.0, color);
+            }
+        }
+        if sensor {
+            // Cross marks trigger areas apart from solid colliders
+            let center = transform.translation().truncate();
+            gizmos.line_2d(center - Vec2::splat(4.0), center + Vec2::splat(4.0), color);
+            gizmos.line_2d(
+                center + Vec2::new(-4.0, 4.0),
+                center + Vec2::new(4.0, -4.0),
+                color,
+            );
+        }
+    }
+
+    if settings.velocities {
+        for (velocity, transform) in &velocities {
+            if velocity.0 == Vec2::ZERO {
+                continue;
+            }
+            let start = transform.translation().truncate();
+            gizmos.arrow_2d(
+                start,
+                start + velocity.0 * settings.velocity_scale,
+                VELOCITY_COLOR,
+            );
+        }
+    }
+
+    if let Some(contacts) = contacts.filter(|_| settings.contacts) {
+        for contact in &contacts.contacts {
+            let color = if contact.sensor {
+                SENSOR_COLOR
+            } else {
+                CONTACT_COLOR
+            };
+            gizmos.circle_2d(contact.point, 3.0, color);
+        }
+    }
+}
@@ -0,0 +1,425 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Links between spawned prefabs and their files.
+//!
+//! [`instantiate_prefab`](crate::scene::instantiate_prefab) gives each
+//! top-level entity it spawns a [`PrefabSource`]. The linked entity and its
+//! descendants (matched to the prefab's by child order) can then be compared
+//! with the file: [`prefab_overrides`] lists the component fields changed
+//! locally, [`apply_to_prefab`] writes them into the file, and
+//! [`revert_to_prefab`] resets them to the file's values.
+//!
+//! The root's `Transform` places the instance, so it is never an override.
+//! Only components are compared: children added or removed in the instance
+//! stay local. Applying changes the file but not other instances of it.
+
+use bevy::ecs::entity::{EntityHashMap, EntityMapper};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+use bevy::reflect::{ReflectFromReflect, ReflectMut, ReflectRef, TypeRegistration, TypeRegistry};
+use bevy::render::primitives::Aabb;
+use bevy::scene::DynamicSceneBuilder;
+use std::any::TypeId;
+use std::path::PathBuf;
+
+use crate::project::{write_keeping_header, ProjectRoot};
+use crate::scene::{
+    assign_scene_ids, read_scene, write_scene, SceneError, SceneManager, SceneResult,
+};
+
+/// Links an entity spawned from a prefab back to the prefab file.
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PrefabSource {
+    /// Prefab file, relative to the assets directory.
+    pub path: String,
+    /// Which of the prefab's top-level entities this is, in file order.
+    pub root: usize,
+}
+
+/// How a component differs from the prefab.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OverrideKind {
+    /// A field has a different value. `None` if the component isn't a
+    /// struct, so it is compared as a whole.
+    Changed(Option<String>),
+    /// The instance has the component but the prefab doesn't.
+    Added,
+    /// The prefab has the component but the instance doesn't.
+    Removed,
+}
+
+/// A local change to a prefab instance, found by [`prefab_overrides`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrefabOverride {
+    /// Entity in the instance: the linked root or one of its descendants.
+    pub entity: Entity,
+    /// The component's type.
+    pub type_id: TypeId,
+    /// The component's short type path, e.g. `Sprite`.
+    pub component: String,
+    pub kind: OverrideKind,
+}
+
+/// The entity's prefab root: the entity itself or its nearest ancestor with
+/// a [`PrefabSource`].
+pub fn prefab_root(world: &World, entity: Entity) -> Option<Entity> {
+    let mut current = entity;
+    loop {
+        if world.get::<PrefabSource>(current).is_some() {
+            return Some(current);
+        }
+        current = world.get::<Parent>(current)?.get();
+    }
+}
+
+/// Lists the component fields of `root`'s instance that differ from its
+/// prefab file, in hierarchy order.
+pub fn prefab_overrides(world: &World, root: Entity) -> SceneResult<Vec<PrefabOverride>> {
+    let prefab = LinkedPrefab::load(world, root)?;
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    Ok(prefab
+        .diff(world, &type_registry)
+        .into_iter()
+        .map(|(_, change)| change)
+        .collect())
+}
+
+/// Writes the overrides of `root`'s instance into its prefab file.
+///
+/// The root's `Transform` and anything outside the matched hierarchy keep
+/// their values in the file. Returns the number of overrides applied.
+pub fn apply_to_prefab(world: &World, root: Entity) -> SceneResult<usize> {
+    let mut prefab = LinkedPrefab::load(world, root)?;
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let changes = prefab.diff(world, &type_registry);
+
+    let mut to_prefab = PairMapper(prefab.pairs.iter().copied().collect());
+    for (prefab_entity, change) in &changes {
+        copy_override(
+            world,
+            change.entity,
+            &mut prefab.world,
+            *prefab_entity,
+            change,
+            &mut to_prefab,
+            &type_registry,
+        );
+    }
+
+    let mut scene = DynamicSceneBuilder::from_world(&prefab.world)
+        .extract_entities(prefab.entities.iter().copied())
+        .build();
+    assign_scene_ids(&mut prefab.world, &mut scene, &type_registry);
+    let serialized = scene
+        .serialize(&type_registry)
+        .map_err(|e| SceneError::Serialization(e.to_string()))?;
+    write_keeping_header(&prefab.path, &serialized).map_err(SceneError::Io)?;
+    Ok(changes.len())
+}
+
+/// Resets the overrides of `root`'s instance to its prefab file's values.
+///
+/// Returns the number of overrides reverted and marks the scene dirty if
+/// there were any.
+pub fn revert_to_prefab(world: &mut World, root: Entity) -> SceneResult<usize> {
+    let prefab = LinkedPrefab::load(world, root)?;
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let changes = prefab.diff(world, &type_registry);
+
+    let mut to_instance = PairMapper(
+        prefab
+            .pairs
+            .iter()
+            .map(|&(instance, prefab_entity)| (prefab_entity, instance))
+            .collect(),
+    );
+    for (prefab_entity, change) in &changes {
+        let reverse = PrefabOverride {
+            kind: match &change.kind {
+                OverrideKind::Added => OverrideKind::Removed,
+                OverrideKind::Removed => OverrideKind::Added,
+                changed => changed.clone(),
+            },
+            ..change.clone()
+        };
+        copy_override(
+            &prefab.world,
+            *prefab_entity,
+            world,
+            change.entity,
+            &reverse,
+            &mut to_instance,
+            &type_registry,
+        );
+    }
+
+    if !changes.is_empty() {
+        if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+            manager.mark_dirty();
+        }
+    }
+    Ok(changes.len())
+}
+
+/// A prefab file spawned into its own world, matched against an instance.
+struct LinkedPrefab {
+    /// Resolved path of the prefab file.
+    path: PathBuf,
+    world: World,
+    /// The prefab's entities, in file order.
+    entities: Vec<Entity>,
+    /// Instance entities and their counterparts in the prefab, root first.
+    pairs: Vec<(Entity, Entity)>,
+}
+
+impl LinkedPrefab {
+    fn load(world: &World, root: Entity) -> SceneResult<Self> {
+        let source = world.get::<PrefabSource>(root).ok_or_else(|| {
+            SceneError::PrefabLink(format!("{:?} wasn't spawned from a prefab", root))
+        })?;
+        let path = match world.get_resource::<ProjectRoot>() {
+            Some(project) => project.resolve_asset(&source.path),
+            None => PathBuf::from(&source.path),
+        };
+        let scene = read_scene(world, &path)?;
+
+        let mut prefab_world = World::new();
+        prefab_world.insert_resource(world.resource::<AppTypeRegistry>().clone());
+        let entities = write_scene(&mut prefab_world, &scene)?;
+        let prefab_root = entities
+            .iter()
+            .copied()
+            .filter(|&entity| prefab_world.get::<Parent>(entity).is_none())
+            .nth(source.root)
+            .ok_or_else(|| {
+                SceneError::PrefabLink(format!(
+                    "{} has no top-level entity #{}",
+                    source.path, source.root
+                ))
+            })?;
+
+        let mut pairs = Vec::new();
+        match_hierarchy(world, root, &prefab_world, prefab_root, &mut pairs);
+        Ok(Self {
+            path,
+            world: prefab_world,
+            entities,
+            pairs,
+        })
+    }
+
+    /// Compares every matched pair, returning each override with the
+    /// prefab entity it differs from.
+    fn diff(&self, world: &World, type_registry: &TypeRegistry) -> Vec<(Entity, PrefabOverride)> {
+        let mut to_instance = PairMapper(
+            self.pairs
+                .iter()
+                .map(|&(instance, prefab_entity)| (prefab_entity, instance))
+                .collect(),
+        );
+        let mut registrations: Vec<&TypeRegistration> = type_registry
+            .iter()
+            .filter(|registration| registration.data::<ReflectComponent>().is_some())
+            .collect();
+        registrations.sort_by_key(|registration| registration.type_info().type_path());
+
+        let mut changes = Vec::new();
+        for (index, &(instance, prefab_entity)) in self.pairs.iter().enumerate() {
+            for registration in &registrations {
+                let type_id = registration.type_id();
+                if !is_compared(type_id, index == 0) {
+                    continue;
+                }
+                let reflect_component = registration.data::<ReflectComponent>().unwrap();
+                let ours = reflect_component.reflect(world.entity(instance));
+                let theirs = match reflect_component.reflect(self.world.entity(prefab_entity)) {
+                    Some(value) => match mapped_copy(value, registration, &mut to_instance) {
+                        Some(copy) => Some(copy),
+                        None => continue,
+                    },
+                    None => None,
+                };
+                let kinds = match (ours, theirs) {
+                    (None, None) => continue,
+                    (Some(_), None) => vec![OverrideKind::Added],
+                    (None, Some(_)) => vec![OverrideKind::Removed],
+                    // A name's cached hash follows its text, so names compare whole
+                    (Some(ours), Some(theirs)) if type_id == TypeId::of::<Name>() => {
+                        if values_equal(ours.as_partial_reflect(), theirs.as_partial_reflect()) {
+                            continue;
+                        }
+                        vec![OverrideKind::Changed(None)]
+                    }
+                    (Some(ours), Some(theirs)) => changed_fields(ours, theirs.as_ref())
+                        .into_iter()
+                        .map(OverrideKind::Changed)
+                        .collect(),
+                };
+                let component = registration.type_info().type_path_table().short_path();
+                changes.extend(kinds.into_iter().map(|kind| {
+                    let change = PrefabOverride {
+                        entity: instance,
+                        type_id,
+                        component: component.to_string(),
+                        kind,
+                    };
+                    (prefab_entity, change)
+                }));
+            }
+        }
+        changes
+    }
+}
+
+/// Maps entities between an instance and its prefab; others are kept.
+struct PairMapper(EntityHashMap<Entity>);
+
+impl EntityMapper for PairMapper {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
+/// Pairs `instance` with `prefab_entity`, then their children in order.
+fn match_hierarchy(
+    world: &World,
+    instance: Entity,
+    prefab: &World,
+    prefab_entity: Entity,
+    pairs: &mut Vec<(Entity, Entity)>,
+) {
+    pairs.push((instance, prefab_entity));
+    let (Some(ours), Some(theirs)) = (
+        world.get::<Children>(instance),
+        prefab.get::<Children>(prefab_entity),
+    ) else {
+        return;
+    };
+    for (&child, &prefab_child) in ours.iter().zip(theirs.iter()) {
+        match_hierarchy(world, child, prefab, prefab_child, pairs);
+    }
+}
+
+/// Whether a component type is compared with the prefab.
+///
+/// Hierarchy and derived components never are, and neither are the root's
+/// placement and link.
+fn is_compared(type_id: TypeId, root: bool) -> bool {
+    let derived = [
+        TypeId::of::<Parent>(),
+        TypeId::of::<Children>(),
+        TypeId::of::<GlobalTransform>(),
+        TypeId::of::<InheritedVisibility>(),
+        TypeId::of::<ViewVisibility>(),
+        TypeId::of::<Aabb>(),
+    ];
+    let placement = [TypeId::of::<Transform>(), TypeId::of::<PrefabSource>()];
+    !(derived.contains(&type_id) || root && placement.contains(&type_id))
+}
+
+/// Copies a component into a concrete value with its entity references
+/// mapped, or `None` if the type can't be rebuilt from reflection.
+fn mapped_copy(
+    value: &dyn Reflect,
+    registration: &TypeRegistration,
+    mapper: &mut PairMapper,
+) -> Option<Box<dyn Reflect>> {
+    let mut copy = registration
+        .data::<ReflectFromReflect>()?
+        .from_reflect(value.as_partial_reflect())?;
+    if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+        map_entities.map_entities(copy.as_partial_reflect_mut(), mapper);
+    }
+    Some(copy)
+}
+
+/// Names the fields of a struct component that differ, or returns `[None]`
+/// if a component of another kind differs as a whole.
+///
+/// Image handles are loaded from `AssetPath`s at runtime, so they aren't
+/// compared.
+fn changed_fields(ours: &dyn Reflect, theirs: &dyn Reflect) -> Vec<Option<String>> {
+    match (ours.reflect_ref(), theirs.reflect_ref()) {
+        (ReflectRef::Struct(ours), ReflectRef::Struct(theirs)) => (0..ours.field_len())
+            .filter_map(|index| {
+                let name = ours.name_at(index)?;
+                let field = ours.field_at(index)?;
+                if field.try_downcast_ref::<Handle<Image>>().is_some() {
+                    return None;
+                }
+                let equal = theirs
+                    .field(name)
+                    .is_some_and(|other| values_equal(field, other));
+                (!equal).then(|| Some(name.to_string()))
+            })
+            .collect(),
+        _ if values_equal(ours.as_partial_reflect(), theirs.as_partial_reflect()) => Vec::new(),
+        _ => vec![None],
+    }
+}
+
+fn values_equal(a: &dyn PartialReflect, b: &dyn PartialReflect) -> bool {
+    a.reflect_partial_eq(b)
+        .unwrap_or_else(|| format!("{:?}", a) == format!("{:?}", b))
+}
+
+/// Copies one override from `source`'s entity onto `target`'s: inserts or
+/// replaces the component for `Added` and whole-component changes, removes
+/// it for `Removed`, and sets just the field for field changes.
+fn copy_override(
+    source: &World,
+    source_entity: Entity,
+    target: &mut World,
+    target_entity: Entity,
+    change: &PrefabOverride,
+    mapper: &mut PairMapper,
+    type_registry: &TypeRegistry,
+) {
+    let Some(registration) = type_registry.get(change.type_id) else {
+        return;
+    };
+    let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+        return;
+    };
+    let Ok(mut entity) = target.get_entity_mut(target_entity) else {
+        return;
+    };
+    if change.kind == OverrideKind::Removed {
+        reflect_component.remove(&mut entity);
+        return;
+    }
+    let Some(value) = reflect_component
+        .reflect(source.entity(source_entity))
+        .and_then(|value| mapped_copy(value, registration, mapper))
+    else {
+        return;
+    };
+
+    match &change.kind {
+        OverrideKind::Changed(Some(field)) => {
+            let Some(mut component) = reflect_component.reflect_mut(entity) else {
+                return;
+            };
+            let (ReflectMut::Struct(component), ReflectRef::Struct(value)) =
+                (component.reflect_mut(), value.reflect_ref())
+            else {
+                return;
+            };
+            if let (Some(target_field), Some(value)) =
+                (component.field_mut(field), value.field(field))
+            {
+                let _ = target_field.try_apply(value);
+            }
+        }
+        _ => reflect_component.apply_or_insert(
+            &mut entity,
+            value.as_partial_reflect(),
+            type_registry,
+        ),
+    }
+}
@@ -0,0 +1,242 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Project root resolution.
+//!
+//! Scene and asset paths are relative to the project root, which is the
+//! directory containing the project file (`Sandbox.ron`). This module locates
+//! that directory so the editor and games work no matter which directory they
+//! are launched from. The project file also holds project-wide settings.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::color_grade::ColorGrades;
+use crate::team::TeamTable;
+
+/// Name of the file that marks the root of a Sandbox project.
+pub const PROJECT_FILE_NAME: &str = "Sandbox.ron";
+
+/// Environment variable that overrides project root discovery.
+pub const PROJECT_ROOT_ENV: &str = "SANDBOX_PROJECT_ROOT";
+
+/// Name of the assets directory inside the project root.
+pub const ASSETS_DIR_NAME: &str = "assets";
+
+/// Resource holding the resolved project root directory.
+#[derive(Resource, Clone, Debug)]
+pub struct ProjectRoot {
+    /// Absolute path to the project root.
+    pub path: PathBuf,
+}
+
+impl ProjectRoot {
+    /// Creates a project root at the given directory.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Locates the project root.
+    ///
+    /// Resolution order:
+    /// 1. The `SANDBOX_PROJECT_ROOT` environment variable
+    /// 2. Walking up from the current directory to the project file
+    /// 3. Walking up from the executable's directory to the project file
+    /// 4. The current directory
+    pub fn discover() -> Self {
+        if let Ok(root) = std::env::var(PROJECT_ROOT_ENV) {
+            if !root.is_empty() {
+                return Self::new(absolute(Path::new(&root)));
+            }
+        }
+
+        let current_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+        if let Some(root) = find_project_file(&current_dir) {
+            return Self::new(root);
+        }
+
+        if let Some(exe_dir) = std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(Path::to_path_buf))
+        {
+            if let Some(root) = find_project_file(&exe_dir) {
+                return Self::new(root);
+            }
+        }
+
+        warn!(
+            "No {} found; using {} as the project root",
+            PROJECT_FILE_NAME,
+            current_dir.display()
+        );
+        Self::new(current_dir)
+    }
+
+    /// Returns the path to the project file.
+    pub fn project_file(&self) -> PathBuf {
+        self.path.join(PROJECT_FILE_NAME)
+    }
+
+    /// Returns the absolute path to the assets directory.
+    pub fn assets_dir(&self) -> PathBuf {
+        self.path.join(ASSETS_DIR_NAME)
+    }
+
+    /// Resolves a project-relative path. Absolute paths are returned unchanged.
+    pub fn resolve(&self, path: impl AsRef<Path>) -> PathBuf {
+        let path = path.as_ref();
+        if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            self.path.join(path)
+        }
+    }
+
+    /// Resolves a path relative to the assets directory.
+    pub fn resolve_asset(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.assets_dir().join(path)
+    }
+
+    /// Converts an absolute path into an asset path relative to the assets directory.
+    ///
+    /// Returns `None` if the path is outside the assets directory.
+    pub fn to_asset_path(&self, path: impl AsRef<Path>) -> Option<String> {
+        path.as_ref()
+            .strip_prefix(self.assets_dir())
+            .ok()
+            .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+    }
+
+    /// Returns an `AssetPlugin` that loads assets from this project's assets directory.
+    pub fn asset_plugin(&self) -> AssetPlugin {
+        AssetPlugin {
+            file_path: self.assets_dir().to_string_lossy().into_owned(),
+            ..default()
+        }
+    }
+}
+
+impl Default for ProjectRoot {
+    fn default() -> Self {
+        Self::discover()
+    }
+}
+
+/// Project-wide settings stored in the project file.
+///
+/// Missing fields fall back to their defaults, so an empty project file `()` is valid.
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProjectSettings {
+    /// Texture pixels per world unit.
+    ///
+    /// Sprites auto-sized from their texture are scaled by `1 / pixels_per_unit`,
+    /// and the editor labels sizes in world units and fine grid lines in pixels. Pixel-art projects
+    /// typically use their tile size (e.g. 16); the default of 1 keeps one world
+    /// unit per pixel.
+    pub pixels_per_unit: f32,
+    /// Team names and the relations between teams.
+    pub teams: TeamTable,
+    /// Tint and brightness for each sprite color group.
+    pub color_grades: ColorGrades,
+}
+
+impl Default for ProjectSettings {
+    fn default() -> Self {
+        Self {
+            pixels_per_unit: 1.0,
+            teams: TeamTable::default(),
+            color_grades: ColorGrades::default(),
+        }
+    }
+}
+
+impl ProjectSettings {
+    /// Reads the settings from the project's project file.
+    ///
+    /// Falls back to defaults if the file is missing or invalid.
+    pub fn load(root: &ProjectRoot) -> Self {
+        let path = root.project_file();
+        let Ok(data) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        match bevy::scene::ron::from_str::<Self>(&data) {
+            Ok(settings) if settings.pixels_per_unit > 0.0 => settings,
+            Ok(_) => {
+                warn!("{}: pixels_per_unit must be positive", path.display());
+                Self::default()
+            }
+            Err(e) => {
+                warn!("Failed to parse {}: {}", path.display(), e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Writes the settings to the project's project file.
+    ///
+    /// Comment lines at the top of the existing file are kept.
+    pub fn save(&self, root: &ProjectRoot) -> std::io::Result<()> {
+        let config = bevy::scene::ron::ser::PrettyConfig::default();
+        let body = bevy::scene::ron::ser::to_string_pretty(self, config)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        write_keeping_header(&root.project_file(), &body)
+    }
+
+    /// Converts a length in texture pixels to world units.
+    pub fn pixels_to_units(&self, pixels: f32) -> f32 {
+        pixels / self.pixels_per_unit
+    }
+}
+
+/// Writes `body` to `path`, keeping the comment lines (license header and
+/// notes) at the top of the file it replaces.
+pub(crate) fn write_keeping_header(path: &Path, body: &str) -> std::io::Result<()> {
+    let existing = std::fs::read_to_string(path).unwrap_or_default();
+    let header: String = existing
+        .lines()
+        .take_while(|line| line.trim().is_empty() || line.trim_start().starts_with("//"))
+        .map(|line| format!("{}\n", line))
+        .collect();
+    std::fs::write(path, format!("{}{}\n", header, body))
+}
+
+/// Loads `ProjectSettings` from the app's `ProjectRoot` unless already present.
+pub(crate) fn init_project_settings(app: &mut App) {
+    if app.world().contains_resource::<ProjectSettings>() {
+        return;
+    }
+    let settings = match app.world().get_resource::<ProjectRoot>() {
+        Some(root) => ProjectSettings::load(root),
+        None => ProjectSettings::load(&ProjectRoot::discover()),
+    };
+    app.insert_resource(settings);
+}
+
+/// Walks up from `start` looking for a directory containing the project file.
+fn find_project_file(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| dir.join(PROJECT_FILE_NAME).is_file())
+        .map(Path::to_path_buf)
+}
+
+/// Makes a path absolute relative to the current directory.
+fn absolute(path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .map(|dir| dir.join(path))
+            .unwrap_or_else(|_| path.to_path_buf())
+    }
+}
+
+/// Resolves a scene path against the world's `ProjectRoot`, if present.
+pub(crate) fn resolve_in_world(world: &World, path: &Path) -> PathBuf {
+    match world.get_resource::<ProjectRoot>() {
+        Some(root) => root.resolve(path),
+        None => path.to_path_buf(),
+    }
+}
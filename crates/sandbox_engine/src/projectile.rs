@@ -0,0 +1,338 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Data-driven projectiles: homing, arcs, piercing, bouncing, and explosions.
+//!
+//! A `Projectile` flies by its `LinearVelocity` and collides through its
+//! `Collider`. Every behavior is a field, so a new weapon is a new set of
+//! values (or a prefab) rather than new code:
+//! - `gravity` pulls the shot down into an arc
+//! - `homing_turn_rate` steers it towards the nearest `Destructible` in
+//!   `homing_range`, turning at most that many degrees per second; a
+//!   projectile with a `Team` only homes in on hostile teams
+//! - `pierce` lets it pass through that many damageable targets
+//! - `bounces` lets it glance off that many solid colliders
+//! - `explosion_radius` damages every `Destructible` around the point where
+//!   it stops, whether by impact or by running out of `lifetime`
+//!
+//! Hitting a `Destructible` deals `damage` to it. Sensors, other projectiles,
+//! and the projectile's `owner` are ignored, as are allies of its `Team`
+//! (explosions included). Each hit, bounce, and stop sends a
+//! `ProjectileEvent`.
+//!
+//! # Example
+//! ```ignore
+//! // A grenade: lobbed, bounces twice, explodes after two seconds
+//! commands.spawn((
+//!     Projectile {
+//!         gravity: 400.0,
+//!         bounces: 2,
+//!         lifetime: 2.0,
+//!         explosion_radius: 80.0,
+//!         explosion_damage: 40.0,
+//!         ..default()
+//!     }
+//!     .with_owner(ship),
+//!     Collider::circle(4.0),
+//!     LinearVelocity(aim * 300.0),
+//!     Transform::from_translation(muzzle),
+//! ));
+//! ```
+
+use bevy::prelude::*;
+
+use crate::destructible::Destructible;
+use crate::editor_state::GameplaySystemSet;
+use crate::inspector::InspectorOptions;
+use crate::physics::{
+    detect_contacts, integrate_velocity, Falloff, LinearVelocity, PhysicsContacts,
+};
+use crate::project::ProjectSettings;
+use crate::system_toggle::ToggleableSystem;
+use crate::team::{Team, TeamTable};
+use crate::time_control::LocalTimeScale;
+
+/// Plugin that steers projectiles and resolves their hits.
+pub struct ProjectilePlugin;
+
+impl Plugin for ProjectilePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Projectile>()
+            .add_event::<ProjectileEvent>()
+            .add_systems(
+                Update,
+                (
+                    steer_projectiles.toggleable().before(integrate_velocity),
+                    resolve_projectile_hits.toggleable().after(detect_contacts),
+                )
+                    .in_set(GameplaySystemSet),
+            );
+    }
+}
+
+/// A shot fired by a weapon.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+#[require(Transform, LinearVelocity, Touching)]
+pub struct Projectile {
+    /// Damage dealt to a `Destructible` it hits.
+    #[reflect(@InspectorOptions::min(0.0))]
+    pub damage: f32,
+    /// Seconds before the projectile stops on its own.
+    #[reflect(@InspectorOptions::min(0.0).with_tooltip("Seconds"))]
+    pub lifetime: f32,
+    /// Downward acceleration; zero flies straight.
+    pub gravity: f32,
+    /// Degrees per second the projectile can turn towards its target; zero
+    /// doesn't home.
+    #[reflect(@InspectorOptions::min(0.0).with_tooltip("Degrees per second"))]
+    pub homing_turn_rate: f32,
+    /// Distance within which a homing projectile picks its target.
+    #[reflect(@InspectorOptions::min(0.0))]
+    pub homing_range: f32,
+    /// Damageable targets it can still pass through.
+    pub pierce: u32,
+    /// Solid colliders it can still bounce off.
+    pub bounces: u32,
+    /// Radius of the explosion when it stops; zero doesn't explode.
+    #[reflect(@InspectorOptions::min(0.0))]
+    pub explosion_radius: f32,
+    /// Damage at the center of the explosion.
+    #[reflect(@InspectorOptions::min(0.0))]
+    pub explosion_damage: f32,
+    /// How explosion damage falls off towards its radius.
+    pub explosion_falloff: Falloff,
+    /// Seconds since it was fired.
+    pub age: f32,
+    /// Entity that fired it, which it never hits. Not saved.
+    #[reflect(ignore)]
+    pub owner: Option<Entity>,
+}
+
+/// Colliders a projectile touched last frame, so a contact counts once.
+#[derive(Component, Default)]
+struct Touching(Vec<Entity>);
+
+impl Default for Projectile {
+    fn default() -> Self {
+        Self {
+            damage: 10.0,
+            lifetime: 3.0,
+            gravity: 0.0,
+            homing_turn_rate: 0.0,
+            homing_range: 300.0,
+            pierce: 0,
+            bounces: 0,
+            explosion_radius: 0.0,
+            explosion_damage: 0.0,
+            explosion_falloff: Falloff::Linear,
+            age: 0.0,
+            owner: None,
+        }
+    }
+}
+
+impl Projectile {
+    /// A straight shot dealing `damage`.
+    pub fn new(damage: f32) -> Self {
+        Self {
+            damage,
+            ..default()
+        }
+    }
+
+    /// Never hits `owner`, typically the entity that fired it.
+    pub fn with_owner(mut self, owner: Entity) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    /// Turns `velocity` towards `target_direction` by at most `max_angle` radians.
+    pub fn turn_towards(velocity: Vec2, target_direction: Vec2, max_angle: f32) -> Vec2 {
+        let angle = velocity.angle_to(target_direction);
+        if !angle.is_finite() {
+            return velocity;
+        }
+        Vec2::from_angle(angle.clamp(-max_angle, max_angle)).rotate(velocity)
+    }
+}
+
+/// What happened to a projectile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProjectileEventKind {
+    /// It damaged this entity.
+    Hit(Entity),
+    /// It bounced off this entity.
+    Bounced(Entity),
+    /// It stopped and was despawned, exploding if it has an explosion radius.
+    Stopped,
+}
+
+/// Sent when a projectile hits, bounces, or stops.
+#[derive(Event, Clone, Debug)]
+pub struct ProjectileEvent {
+    /// The projectile; already despawned for `Stopped`.
+    pub projectile: Entity,
+    /// What happened.
+    pub kind: ProjectileEventKind,
+    /// World position of the projectile at the time.
+    pub position: Vec2,
+}
+
+/// Query data for projectiles steered by `steer_projectiles`.
+type SteeredProjectile<'a> = (
+    &'a Projectile,
+    &'a mut LinearVelocity,
+    &'a GlobalTransform,
+    Option<&'a LocalTimeScale>,
+    Option<&'a Team>,
+);
+
+/// Applies gravity and homing to projectile velocities.
+fn steer_projectiles(
+    time: Res<Time>,
+    settings: Option<Res<ProjectSettings>>,
+    mut projectiles: Query<SteeredProjectile>,
+    targets: Query<(Entity, &GlobalTransform, Option<&Team>), With<Destructible>>,
+) {
+    let no_teams = TeamTable::default();
+    let teams = settings
+        .as_ref()
+        .map_or(&no_teams, |settings| &settings.teams);
+    for (projectile, mut velocity, transform, time_scale, team) in &mut projectiles {
+        let delta = LocalTimeScale::delta_secs(&time, time_scale);
+        velocity.0.y -= projectile.gravity * delta;
+
+        if projectile.homing_turn_rate <= 0.0 {
+            continue;
+        }
+        let position = transform.translation().truncate();
+        let target = targets
+            .iter()
+            .filter(|(entity, _, target_team)| {
+                Some(*entity) != projectile.owner && teams.can_target(team, *target_team)
+            })
+            .map(|(_, target, _)| target.translation().truncate() - position)
+            .filter(|offset| offset.length() <= projectile.homing_range)
+            .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()));
+        if let Some(offset) = target {
+            let max_angle = projectile.homing_turn_rate.to_radians() * delta;
+            velocity.0 = Projectile::turn_towards(velocity.0, offset, max_angle);
+        }
+    }
+}
+
+/// Query data for projectiles resolved by `resolve_projectile_hits`.
+type MovingProjectile<'a> = (
+    Entity,
+    &'a mut Projectile,
+    &'a mut Touching,
+    &'a mut LinearVelocity,
+    &'a GlobalTransform,
+    Option<&'a LocalTimeScale>,
+    Option<&'a Team>,
+);
+
+/// Damages, bounces, and stops projectiles by this frame's contacts and lifetimes.
+#[allow(clippy::too_many_arguments)]
+fn resolve_projectile_hits(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Option<Res<ProjectSettings>>,
+    contacts: Res<PhysicsContacts>,
+    mut projectiles: Query<MovingProjectile>,
+    others: Query<(), Without<Projectile>>,
+    mut destructibles: Query<
+        (Entity, &mut Destructible, &GlobalTransform, Option<&Team>),
+        Without<Projectile>,
+    >,
+    mut events: EventWriter<ProjectileEvent>,
+) {
+    let no_teams = TeamTable::default();
+    let teams = settings
+        .as_ref()
+        .map_or(&no_teams, |settings| &settings.teams);
+    for (entity, mut projectile, mut touched, mut velocity, transform, time_scale, team) in
+        &mut projectiles
+    {
+        let position = transform.translation().truncate();
+        projectile.age += LocalTimeScale::delta_secs(&time, time_scale);
+
+        let mut touching: Vec<(Entity, Vec2)> = contacts
+            .contacts
+            .iter()
+            .filter(|contact| !contact.sensor)
+            .filter_map(|contact| match (contact.a == entity, contact.b == entity) {
+                (true, false) => Some((contact.b, contact.point)),
+                (false, true) => Some((contact.a, contact.point)),
+                _ => None,
+            })
+            .filter(|(other, _)| Some(*other) != projectile.owner && others.contains(*other))
+            .collect();
+        touching.sort_by_key(|(other, _)| *other);
+        let previous = std::mem::replace(
+            &mut touched.0,
+            touching.iter().map(|(other, _)| *other).collect(),
+        );
+
+        let mut stopped = projectile.age >= projectile.lifetime;
+        for (other, point) in touching {
+            if stopped || previous.contains(&other) {
+                continue;
+            }
+            if let Ok((_, mut destructible, _, _)) = destructibles.get_mut(other) {
+                destructible.damage(projectile.damage);
+                events.send(ProjectileEvent {
+                    projectile: entity,
+                    kind: ProjectileEventKind::Hit(other),
+                    position,
+                });
+                if projectile.pierce > 0 {
+                    projectile.pierce -= 1;
+                } else {
+                    stopped = true;
+                }
+            } else if projectile.bounces > 0 {
+                projectile.bounces -= 1;
+                // Reflect off the surface, facing from the contact to the projectile
+                let normal = (position - point)
+                    .try_normalize()
+                    .unwrap_or_else(|| -velocity.0.normalize_or_zero());
+                if velocity.0.dot(normal) < 0.0 {
+                    velocity.0 = velocity.0.reflect(normal);
+                }
+                events.send(ProjectileEvent {
+                    projectile: entity,
+                    kind: ProjectileEventKind::Bounced(other),
+                    position,
+                });
+            } else {
+                stopped = true;
+            }
+        }
+
+        if !stopped {
+            continue;
+        }
+        if projectile.explosion_radius > 0.0 {
+            for (target, mut destructible, target_transform, target_team) in &mut destructibles {
+                if Some(target) == projectile.owner || teams.are_allies(team, target_team) {
+                    continue;
+                }
+                let distance = target_transform.translation().truncate().distance(position);
+                let factor = projectile
+                    .explosion_falloff
+                    .factor(distance, projectile.explosion_radius);
+                if factor > 0.0 {
+                    destructible.damage(projectile.explosion_damage * factor);
+                }
+            }
+        }
+        commands.entity(entity).despawn_recursive();
+        events.send(ProjectileEvent {
+            projectile: entity,
+            kind: ProjectileEventKind::Stopped,
+            position,
+        });
+    }
+}
@@ -4,20 +4,342 @@
 //! Scene management for saving and loading entity hierarchies.
 //!
 //! This module provides scene serialization using Bevy's `DynamicScene` system
-//! with RON format output.
+//! with RON format output. [`SceneFormat::Json`] writes the same entities
+//! and components as JSON instead, for tools and pipelines that can't parse
+//! RON: [`save_scene`] picks the format from the file extension (`.scn.json`
+//! for JSON) and [`load_scene`] detects it from the file's contents.
+//! [`save_scene_json`] and [`load_scene_json`] force JSON; forced saves are
+//! exports that don't change which file the open scene saves to.
+//!
+//! Which entities are saved is decided by [`should_serialize_entity`]: the
+//! [`SceneSerialized`] and [`SceneExcluded`] markers always win, and other
+//! entities go through the [`SceneManager::filter`].
+//!
+//! Hierarchies are saved with their `Parent` and `Children` components, so
+//! child order survives a round trip. An entity is only part of the scene if
+//! its ancestors are too: children of a camera stay with the camera.
+//!
+//! A `SceneInstance` composes a scene from others: the referenced file is
+//! spawned as the instance's children whenever the scene loads (or the
+//! reference changes), and saving stores only the instance entity itself, so
+//! edits to the referenced file show up everywhere it is used.
+//!
+//! Resources registered with
+//! [`register_scene_resource`](RegisterSceneResourceExt::register_scene_resource),
+//! such as a level's gravity or ambient color, are saved with the entities
+//! and restored when the scene loads. A scene without one resets it to its
+//! default, as does [`new_scene`]; prefabs and scene instances never touch
+//! them.
+//!
+//! Saving is deterministic so scene files diff cleanly under version
+//! control: entities are written in order of their [`SceneEntityId`] (the
+//! key they had in the file they were loaded from; new entities get the next
+//! free one), and components in order of type path. Saving an unchanged scene
+//! reproduces the file byte for byte.
+//!
+//! Loading, saving, and starting a new scene send [`SceneLoaded`],
+//! [`SceneSaved`], and [`SceneCleared`] (or [`SceneLoadFailed`]), so systems
+//! can rebuild caches or show messages without polling the `SceneManager`.
+//!
+//! [`validate_scene`] lists what a save would silently lose or write out
+//! broken (unregistered components, dangling parents, missing assets, NaN
+//! transforms), so tools can warn before saving.
+//!
+//! Prefabs are copied instead: their entities are saved with the scene, and
+//! each top-level one keeps a [`PrefabSource`] link back to the file so
+//! [`crate::prefab`] can compare, apply, and revert its overrides.
 
+use bevy::ecs::entity::{EntityHashMap, EntityHashSet, EntityMapper};
+use bevy::ecs::reflect::ReflectMapEntities;
 use bevy::prelude::*;
-use bevy::scene::serde::SceneDeserializer;
+use bevy::reflect::{FromType, GetTypeRegistration, ReflectMut, TypeRegistry};
+use bevy::scene::serde::{SceneDeserializer, SceneSerializer};
 use bevy::scene::DynamicSceneBuilder;
 use serde::de::DeserializeSeed;
-use std::path::PathBuf;
+use std::any::TypeId;
+use std::path::{Path, PathBuf};
+
+use crate::assets::AssetPath;
+use crate::prefab::PrefabSource;
+use crate::project::{init_project_settings, resolve_in_world, ProjectRoot};
 
 /// Plugin that sets up scene management.
 pub struct ScenePlugin;
 
 impl Plugin for ScenePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<SceneManager>();
+        app.register_type::<Parent>()
+            .register_type::<Children>()
+            .register_type::<EditorGroup>()
+            .register_type::<EditorLocked>()
+            .register_type::<EditorNote>()
+            .register_type::<SceneInstance>()
+            .register_type::<SceneSerialized>()
+            .register_type::<SceneExcluded>()
+            .register_type::<PrefabSource>()
+            .init_resource::<ProjectRoot>()
+            .init_resource::<SceneManager>()
+            .init_resource::<SceneLoadSettings>()
+            .init_resource::<SceneResources>()
+            .add_event::<SceneLoaded>()
+            .add_event::<SceneSaved>()
+            .add_event::<SceneCleared>()
+            .add_event::<SceneLoadFailed>()
+            .add_systems(Update, sync_scene_instances);
+        init_project_settings(app);
+    }
+}
+
+/// Marks an entity as part of the scene, whatever the [`SceneFilter`] says.
+///
+/// Use it for entities the filter skips, e.g. logic entities without a
+/// `Transform`.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct SceneSerialized;
+
+/// Keeps an entity out of saved scenes, whatever the [`SceneFilter`] says.
+///
+/// Use it for runtime and editor helpers that have a `Transform`. Takes
+/// precedence over [`SceneSerialized`].
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct SceneExcluded;
+
+/// An entity's key in the scene file it was loaded from or last saved to.
+///
+/// Saving writes entities in key order and keeps the keys they had, so
+/// unchanged entities stay where they are in the file. It is not reflected:
+/// copies of an entity (duplicates, pasted entities, prefab instances) are
+/// new entities in the file and get new keys.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SceneEntityId(pub u32);
+
+/// Decides which unmarked entities are saved with the scene.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum SceneFilter {
+    /// Entities with a `Transform`, except cameras and UI nodes.
+    #[default]
+    Transforms,
+    /// Only entities marked [`SceneSerialized`].
+    MarkedOnly,
+    /// Entities the function accepts.
+    Custom(fn(&EntityRef) -> bool),
+}
+
+impl SceneFilter {
+    /// Whether the filter accepts an unmarked entity.
+    pub fn accepts(&self, entity: &EntityRef) -> bool {
+        match self {
+            SceneFilter::Transforms => {
+                entity.contains::<Transform>()
+                    && !entity.contains::<Camera>()
+                    && !entity.contains::<Node>()
+            }
+            SceneFilter::MarkedOnly => false,
+            SceneFilter::Custom(accepts) => accepts(entity),
+        }
+    }
+}
+
+/// Marks a lightweight grouping entity created in the editor.
+///
+/// Groups only carry a `Name`, `Transform`, and visibility, and parent the
+/// entities they contain, so they act as folders in the hierarchy and save
+/// like any other entity.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct EditorGroup;
+
+/// Marks an entity as locked in the editor.
+///
+/// Locked entities can't be picked in the viewport and are read-only in the
+/// inspector and gizmos, but can still be selected from the hierarchy. The
+/// marker is saved with the scene and has no effect at runtime.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct EditorLocked;
+
+/// A designer's note attached to an entity, e.g. a TODO left in a level.
+///
+/// The editor shows notes as an icon in the viewport and hierarchy. Notes are
+/// saved with the scene but are [editor-only](ReflectEditorOnly), so they are
+/// stripped when a game loads the scene.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default, EditorOnly)]
+pub struct EditorNote {
+    /// Note text; may span several lines.
+    pub text: String,
+    /// Color of the note's icon.
+    pub color: Color,
+}
+
+impl Default for EditorNote {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            color: Color::srgb(1.0, 0.85, 0.3),
+        }
+    }
+}
+
+/// Spawns another scene file as this entity's children.
+///
+/// Only the instance entity is saved; its children are respawned from the
+/// referenced file on every load, so they can be moved as a whole through the
+/// instance's transform but not edited individually. Instances may nest.
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Component, Default)]
+#[require(Transform, Visibility)]
+pub struct SceneInstance {
+    /// Scene file, relative to the assets directory.
+    pub scene: String,
+}
+
+impl SceneInstance {
+    /// Instances the scene in `path`.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { scene: path.into() }
+    }
+}
+
+/// The `SceneInstance` whose scene spawned `entity`, if any: its nearest
+/// ancestor with one.
+pub fn owning_instance(world: &World, entity: Entity) -> Option<Entity> {
+    let mut current = entity;
+    while let Some(parent) = world.get::<Parent>(current) {
+        current = parent.get();
+        if world.get::<SceneInstance>(current).is_some() {
+            return Some(current);
+        }
+    }
+    None
+}
+
+/// The scene file an instance's children were spawned from.
+#[derive(Component)]
+struct LoadedSceneInstance(String);
+
+/// Reflect type data marking a component as editor-only.
+///
+/// Add it with `#[reflect(EditorOnly)]`. Editor-only components are saved
+/// with scenes, and dropped by [`load_scene`] and [`spawn_prefab`] unless
+/// [`SceneLoadSettings::keep_editor_only`] is set.
+#[derive(Clone, Copy, Debug)]
+pub struct ReflectEditorOnly;
+
+impl<T> FromType<T> for ReflectEditorOnly {
+    fn from_type() -> Self {
+        Self
+    }
+}
+
+/// Resource controlling how scenes are loaded.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct SceneLoadSettings {
+    /// Keep editor-only components when loading; the editor sets this.
+    pub keep_editor_only: bool,
+}
+
+/// Resources saved with the scene, in registration order.
+///
+/// Register them with
+/// [`register_scene_resource`](RegisterSceneResourceExt::register_scene_resource).
+#[derive(Resource, Default)]
+pub struct SceneResources {
+    /// Each resource's type, with a function resetting it to its default.
+    resources: Vec<(TypeId, ResetResource)>,
+}
+
+/// Replaces a resource with its default.
+type ResetResource = fn(&mut World);
+
+impl SceneResources {
+    /// Saves resource `R` with the scene. Use
+    /// [`register_scene_resource`](RegisterSceneResourceExt::register_scene_resource)
+    /// on an `App`, which also registers its reflection.
+    pub fn register<R: Resource + FromWorld>(&mut self) {
+        if !self.contains(TypeId::of::<R>()) {
+            self.resources
+                .push((TypeId::of::<R>(), reset_resource::<R>));
+        }
+    }
+
+    /// Whether the resource with `type_id` is saved with the scene.
+    pub fn contains(&self, type_id: TypeId) -> bool {
+        self.resources.iter().any(|(id, _)| *id == type_id)
+    }
+
+    /// Types of the resources saved with the scene.
+    pub fn type_ids(&self) -> impl Iterator<Item = TypeId> + '_ {
+        self.resources.iter().map(|(id, _)| *id)
+    }
+}
+
+/// Replaces resource `R` with its default.
+fn reset_resource<R: Resource + FromWorld>(world: &mut World) {
+    let resource = R::from_world(world);
+    world.insert_resource(resource);
+}
+
+/// Registers resources to save with the scene on an `App`.
+pub trait RegisterSceneResourceExt {
+    /// Saves resource `R` with the scene and restores it on load.
+    ///
+    /// Registers `R` for reflection (with `ReflectResource`) and initializes
+    /// it if it's missing. Scenes saved without `R` reset it to its default
+    /// when they load.
+    fn register_scene_resource<R>(&mut self) -> &mut Self
+    where
+        R: Resource + FromWorld + FromReflect + TypePath + GetTypeRegistration;
+}
+
+impl RegisterSceneResourceExt for App {
+    fn register_scene_resource<R>(&mut self) -> &mut Self
+    where
+        R: Resource + FromWorld + FromReflect + TypePath + GetTypeRegistration,
+    {
+        self.register_type::<R>()
+            .register_type_data::<R, ReflectResource>()
+            .init_resource::<R>();
+        self.world_mut()
+            .get_resource_or_init::<SceneResources>()
+            .register::<R>();
+        self
+    }
+}
+
+/// Filter letting only the [`SceneResources`] into a `DynamicScene`.
+pub(crate) fn scene_resource_filter(world: &World) -> bevy::scene::SceneFilter {
+    let filter = bevy::scene::SceneFilter::deny_all();
+    match world.get_resource::<SceneResources>() {
+        Some(resources) => resources
+            .type_ids()
+            .fold(filter, |filter, type_id| filter.allow_by_id(type_id)),
+        None => filter,
+    }
+}
+
+/// Resets the [`SceneResources`] that `scene` doesn't contain; all of them
+/// without a scene.
+fn reset_scene_resources(world: &mut World, scene: Option<&DynamicScene>) {
+    let Some(resources) = world.get_resource::<SceneResources>() else {
+        return;
+    };
+    let in_scene: Vec<TypeId> = scene
+        .into_iter()
+        .flat_map(|scene| &scene.resources)
+        .filter_map(|resource| resource.get_represented_type_info())
+        .map(|info| info.type_id())
+        .collect();
+    let resets: Vec<ResetResource> = resources
+        .resources
+        .iter()
+        .filter(|(type_id, _)| !in_scene.contains(type_id))
+        .map(|(_, reset)| *reset)
+        .collect();
+    for reset in resets {
+        reset(world);
     }
 }
 
@@ -28,6 +350,12 @@ pub struct SceneManager {
     pub current_scene_path: Option<PathBuf>,
     /// Whether the scene has unsaved changes.
     pub dirty: bool,
+    /// Reload the scene without asking when its file changes on disk and
+    /// there are no unsaved changes. Read by the editor's scene watcher.
+    pub auto_reload: bool,
+    /// Which entities without a [`SceneSerialized`] or [`SceneExcluded`]
+    /// marker are saved.
+    pub filter: SceneFilter,
 }
 
 impl SceneManager {
@@ -42,20 +370,156 @@ impl SceneManager {
     }
 }
 
+/// Sent when a scene file has been loaded into the world.
+#[derive(Event, Clone, Debug, PartialEq)]
+pub struct SceneLoaded {
+    /// The file, resolved against the project root.
+    pub path: PathBuf,
+}
+
+/// Sent when the scene has been written to a file.
+#[derive(Event, Clone, Debug, PartialEq)]
+pub struct SceneSaved {
+    /// The file, resolved against the project root.
+    pub path: PathBuf,
+}
+
+/// Sent when [`new_scene`] has emptied the world for a new scene.
+#[derive(Event, Clone, Debug, PartialEq)]
+pub struct SceneCleared;
+
+/// Sent when a scene file couldn't be loaded; the world is left as it was
+/// unless the file was read but its entities failed to spawn.
+#[derive(Event, Clone, Debug, PartialEq)]
+pub struct SceneLoadFailed {
+    /// The file, resolved against the project root.
+    pub path: PathBuf,
+    /// Why loading failed.
+    pub error: String,
+}
+
+/// Something about the scene that saving would lose or write out broken,
+/// found by [`validate_scene`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum SceneIssue {
+    /// A component that isn't saved because its type isn't registered for
+    /// reflection, or is registered without `#[reflect(Component)]`.
+    UnregisteredComponent { entity: Entity, type_name: String },
+    /// A `Parent` pointing at an entity that no longer exists; the entity
+    /// and its descendants aren't saved.
+    DanglingParent { entity: Entity, parent: Entity },
+    /// An `AssetPath` naming a file missing from the assets directory.
+    MissingAsset { entity: Entity, path: String },
+    /// A `Transform` with a NaN or infinite value.
+    InvalidTransform { entity: Entity },
+}
+
+impl SceneIssue {
+    /// The entity with the issue.
+    pub fn entity(&self) -> Entity {
+        match self {
+            SceneIssue::UnregisteredComponent { entity, .. }
+            | SceneIssue::DanglingParent { entity, .. }
+            | SceneIssue::MissingAsset { entity, .. }
+            | SceneIssue::InvalidTransform { entity } => *entity,
+        }
+    }
+
+    /// One-line description, without the entity.
+    pub fn message(&self) -> String {
+        match self {
+            SceneIssue::UnregisteredComponent { type_name, .. } => {
+                format!(
+                    "{} isn't registered for reflection and won't be saved",
+                    type_name
+                )
+            }
+            SceneIssue::DanglingParent { parent, .. } => {
+                format!("Parent {} no longer exists, so it won't be saved", parent)
+            }
+            SceneIssue::MissingAsset { path, .. } => format!("Missing asset '{}'", path),
+            SceneIssue::InvalidTransform { .. } => "Transform is NaN or infinite".to_string(),
+        }
+    }
+}
+
 /// Result type for scene operations.
 pub type SceneResult<T> = Result<T, SceneError>;
 
+/// File format of a scene. Both hold the same entities and components.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SceneFormat {
+    /// Bevy's RON scene format (`.scn.ron`).
+    #[default]
+    Ron,
+    /// The same structure as JSON (`.scn.json`), for external tools.
+    Json,
+}
+
+impl SceneFormat {
+    /// Extension of scene files in this format, without the leading dot.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            SceneFormat::Ron => "scn.ron",
+            SceneFormat::Json => "scn.json",
+        }
+    }
+
+    /// The format to save `path` in: JSON for a `.json` extension, RON otherwise.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension() {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => SceneFormat::Json,
+            _ => SceneFormat::Ron,
+        }
+    }
+
+    /// The format of a scene file named `name`, or `None` if it isn't one.
+    pub fn from_file_name(name: &str) -> Option<Self> {
+        [SceneFormat::Ron, SceneFormat::Json]
+            .into_iter()
+            .find(|format| name.ends_with(&format!(".{}", format.extension())))
+    }
+
+    /// The format of scene file contents: JSON scenes are objects (`{`), RON
+    /// scenes structs (`(`).
+    pub fn detect(data: &str) -> Self {
+        if data.trim_start().starts_with('{') {
+            SceneFormat::Json
+        } else {
+            SceneFormat::Ron
+        }
+    }
+
+    /// Serializer writing this format.
+    fn serializer(self) -> fn(&DynamicScene, &TypeRegistry) -> SceneResult<String> {
+        match self {
+            SceneFormat::Ron => serialize_ron,
+            SceneFormat::Json => serialize_json,
+        }
+    }
+
+    /// Deserializer reading this format.
+    fn deserializer(self) -> fn(&str, &TypeRegistry) -> SceneResult<DynamicScene> {
+        match self {
+            SceneFormat::Ron => deserialize_ron,
+            SceneFormat::Json => deserialize_json,
+        }
+    }
+}
+
 /// Errors that can occur during scene operations.
 #[derive(Debug)]
 pub enum SceneError {
-    /// Failed to serialize scene to RON.
+    /// Failed to serialize scene to RON or JSON.
     Serialization(String),
     /// Failed to write scene file.
     Io(std::io::Error),
     /// Failed to read scene file.
     FileRead(std::io::Error),
-    /// Failed to deserialize scene from RON.
+    /// Failed to deserialize scene from RON or JSON.
     Deserialization(String),
+    /// An entity isn't linked to a prefab, or its prefab no longer matches it.
+    PrefabLink(String),
 }
 
 impl std::fmt::Display for SceneError {
@@ -65,6 +529,7 @@ impl std::fmt::Display for SceneError {
             SceneError::Io(e) => write!(f, "Failed to write scene file: {}", e),
             SceneError::FileRead(e) => write!(f, "Failed to read scene file: {}", e),
             SceneError::Deserialization(e) => write!(f, "Failed to deserialize scene: {}", e),
+            SceneError::PrefabLink(e) => write!(f, "Broken prefab link: {}", e),
         }
     }
 }
@@ -73,57 +538,175 @@ impl std::error::Error for SceneError {}
 
 /// Checks if an entity should be included in scene serialization.
 ///
-/// Excludes cameras and other runtime-only entities.
-pub fn should_serialize_entity(entity: &EntityRef) -> bool {
-    // Exclude cameras - they're runtime only
-    if entity.contains::<Camera2d>() {
+/// [`SceneExcluded`] entities never are and [`SceneSerialized`] ones always
+/// are; the rest are up to the world's [`SceneManager::filter`], or the
+/// default filter without a `SceneManager`.
+pub fn should_serialize_entity(world: &World, entity: &EntityRef) -> bool {
+    if entity.contains::<SceneExcluded>() {
         return false;
     }
-
-    // Exclude entities without transforms (usually internal bevy entities)
-    if !entity.contains::<Transform>() {
-        return false;
+    if entity.contains::<SceneSerialized>() {
+        return true;
     }
+    world
+        .get_resource::<SceneManager>()
+        .map_or_else(SceneFilter::default, |manager| manager.filter)
+        .accepts(entity)
+}
 
-    true
+/// Returns the entities saved with the scene.
+///
+/// These pass [`should_serialize_entity`], as do all their ancestors, and
+/// don't belong to a [`SceneInstance`].
+pub fn scene_entities(world: &World) -> Vec<Entity> {
+    let candidates: EntityHashSet = world
+        .iter_entities()
+        .filter(|entity| should_serialize_entity(world, entity))
+        .map(|entity| entity.id())
+        .collect();
+    let mut entities: Vec<Entity> = candidates
+        .iter()
+        .copied()
+        .filter(|&entity| {
+            let mut current = entity;
+            while let Some(parent) = world.get::<Parent>(current) {
+                current = parent.get();
+                if !candidates.contains(&current) || world.get::<SceneInstance>(current).is_some() {
+                    return false;
+                }
+            }
+            true
+        })
+        .collect();
+    entities.sort();
+    entities
+}
+
+/// Checks the entities that would be saved ([`scene_entities`]) for problems
+/// saving would hide.
+///
+/// Looks for components that won't be written because their type isn't
+/// registered with `#[reflect(Component)]`, `AssetPath`s naming files missing
+/// from the project's assets directory, and non-finite transforms, as well
+/// as entities left out of the save because their `Parent` was despawned. Components from Bevy and the
+/// `sandbox_*` crates are left out of the first check, as their unregistered
+/// components hold runtime state. Issues are in entity order.
+pub fn validate_scene(world: &World) -> Vec<SceneIssue> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let assets_dir = world
+        .get_resource::<ProjectRoot>()
+        .map(ProjectRoot::assets_dir);
+
+    // Saving leaves out entities whose parent is gone, so look for those
+    // among all the entities that would otherwise be saved
+    let mut issues: Vec<SceneIssue> = world
+        .iter_entities()
+        .filter(|entity| should_serialize_entity(world, entity))
+        .filter_map(|entity| {
+            let parent = entity.get::<Parent>()?.get();
+            world
+                .get_entity(parent)
+                .is_err()
+                .then(|| SceneIssue::DanglingParent {
+                    entity: entity.id(),
+                    parent,
+                })
+        })
+        .collect();
+
+    for id in scene_entities(world) {
+        let entity = world.entity(id);
+        for component in entity.archetype().components() {
+            let Some(info) = world.components().get_info(component) else {
+                continue;
+            };
+            let registered = info.type_id().is_some_and(|type_id| {
+                type_registry
+                    .get_type_data::<ReflectComponent>(type_id)
+                    .is_some()
+            });
+            let type_name = info.name();
+            if !registered && !type_name.starts_with("bevy_") && !type_name.starts_with("sandbox_")
+            {
+                issues.push(SceneIssue::UnregisteredComponent {
+                    entity: id,
+                    type_name: type_name.to_string(),
+                });
+            }
+        }
+        if let (Some(asset), Some(assets_dir)) = (entity.get::<AssetPath>(), &assets_dir) {
+            let path = &asset.path;
+            if !path.is_empty() && !path.contains("://") && !assets_dir.join(path).is_file() {
+                issues.push(SceneIssue::MissingAsset {
+                    entity: id,
+                    path: path.clone(),
+                });
+            }
+        }
+        if let Some(transform) = entity.get::<Transform>() {
+            if !(transform.translation.is_finite()
+                && transform.rotation.is_finite()
+                && transform.scale.is_finite())
+            {
+                issues.push(SceneIssue::InvalidTransform { entity: id });
+            }
+        }
+    }
+    // Each entity has either a dangling parent or other issues, so a stable
+    // sort keeps the order of an entity's issues
+    issues.sort_by_key(SceneIssue::entity);
+    issues
 }
 
 /// Saves the current world state to a scene file.
 ///
+/// Relative paths are resolved against the `ProjectRoot`, if present. The
+/// format follows the extension ([`SceneFormat::from_path`]), so
+/// `level.scn.json` is written as JSON.
+///
 /// # Arguments
 /// * `world` - The world to save
 /// * `path` - Path to save the scene file
 ///
 /// # Returns
 /// `Ok(())` on success, or a `SceneError` on failure.
-pub fn save_scene(world: &mut World, path: &PathBuf) -> SceneResult<()> {
-    let type_registry = world.resource::<AppTypeRegistry>().clone();
-    let type_registry = type_registry.read();
-
-    // Collect entities to save
-    let entities_to_save: Vec<Entity> = world
-        .iter_entities()
-        .filter(|e| should_serialize_entity(e))
-        .map(|e| e.id())
-        .collect();
+pub fn save_scene(world: &mut World, path: &Path) -> SceneResult<()> {
+    save_scene_with(world, path, SceneFormat::from_path(path).serializer())
+}
 
-    // Build the dynamic scene
-    let scene = DynamicSceneBuilder::from_world(world)
-        .extract_entities(entities_to_save.into_iter())
-        .build();
+/// Exports a copy of the scene to a JSON file, whatever the path's extension.
+///
+/// Works like [`save_scene_as`] with [`SceneFormat::Json`].
+pub fn save_scene_json(world: &mut World, path: &Path) -> SceneResult<()> {
+    save_scene_as(world, path, SceneFormat::Json)
+}
 
-    // Serialize to RON
-    let serialized = scene
-        .serialize(&type_registry)
-        .map_err(|e| SceneError::Serialization(e.to_string()))?;
+/// Exports a copy of the scene to `path` in `format`, whatever the path's
+/// extension.
+///
+/// Like [`save_scene_copy`], this leaves the `SceneManager` alone, so the
+/// open scene keeps saving to its own file in the format its extension
+/// names.
+pub fn save_scene_as(world: &mut World, path: &Path, format: SceneFormat) -> SceneResult<()> {
+    write_scene_with(world, path, format.serializer()).map(|_| ())
+}
 
-    // Ensure parent directory exists
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent).map_err(SceneError::Io)?;
-    }
+/// Writes a copy of the scene to `path`, for autosaves and backups.
+///
+/// Works like [`save_scene`], but leaves the `SceneManager` alone: the
+/// current scene path doesn't change, the scene stays dirty, and no
+/// [`SceneSaved`] is sent.
+pub fn save_scene_copy(world: &mut World, path: &Path) -> SceneResult<()> {
+    save_scene_as(world, path, SceneFormat::from_path(path))
+}
 
-    // Write to file
-    std::fs::write(path, serialized).map_err(SceneError::Io)?;
+/// Saves the scene entities to `path` in the format `serialize` writes.
+fn save_scene_with(
+    world: &mut World,
+    path: &Path,
+    serialize: fn(&DynamicScene, &TypeRegistry) -> SceneResult<String>,
+) -> SceneResult<()> {
+    let path = write_scene_with(world, path, serialize)?;
 
     // Update scene manager
     if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
@@ -131,24 +714,81 @@ pub fn save_scene(world: &mut World, path: &PathBuf) -> SceneResult<()> {
         manager.mark_clean();
     }
 
+    send_scene_event(world, SceneSaved { path });
     Ok(())
 }
 
+/// Writes the scene entities to `path` in the format `serialize` writes,
+/// returning the path resolved against the project root.
+fn write_scene_with(
+    world: &mut World,
+    path: &Path,
+    serialize: fn(&DynamicScene, &TypeRegistry) -> SceneResult<String>,
+) -> SceneResult<PathBuf> {
+    let path = resolve_in_world(world, path);
+
+    let scene = extract_scene(world);
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let serialized = serialize(&scene, &type_registry.read())?;
+
+    // Ensure parent directory exists
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(SceneError::Io)?;
+    }
+
+    // Write to file
+    std::fs::write(&path, serialized).map_err(SceneError::Io)?;
+    Ok(path)
+}
+
+/// Builds the scene that saving would write, without writing it.
+///
+/// Entities are keyed and ordered by [`SceneEntityId`] as in a saved file,
+/// so the result can be compared with a parsed scene file. Entities that
+/// had no key yet are given one, as on save.
+pub fn extract_scene(world: &mut World) -> DynamicScene {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+
+    // Collect entities to save
+    let entities_to_save = scene_entities(world);
+    let saved: EntityHashSet = entities_to_save.iter().copied().collect();
+
+    // Build the dynamic scene
+    let mut scene = DynamicSceneBuilder::from_world(world)
+        .with_resource_filter(scene_resource_filter(world))
+        .extract_entities(entities_to_save.into_iter())
+        .extract_resources()
+        .build();
+    drop_unsaved_children(world, &mut scene, &saved);
+    assign_scene_ids(world, &mut scene, &type_registry);
+    scene
+}
+
+/// Serializes a scene to RON.
+fn serialize_ron(scene: &DynamicScene, type_registry: &TypeRegistry) -> SceneResult<String> {
+    scene
+        .serialize(type_registry)
+        .map_err(|e| SceneError::Serialization(e.to_string()))
+}
+
+/// Serializes a scene to pretty-printed JSON, with the same structure as RON.
+fn serialize_json(scene: &DynamicScene, type_registry: &TypeRegistry) -> SceneResult<String> {
+    serde_json::to_string_pretty(&SceneSerializer::new(scene, type_registry))
+        .map_err(|e| SceneError::Serialization(e.to_string()))
+}
+
 /// Clears all serializable entities from the world.
 ///
 /// This removes entities that would be saved in a scene, leaving cameras
 /// and other runtime entities intact.
 pub fn clear_scene_entities(world: &mut World) {
-    let entities_to_despawn: Vec<Entity> = world
-        .iter_entities()
-        .filter(|e| should_serialize_entity(e))
-        .map(|e| e.id())
-        .collect();
+    let entities_to_despawn = scene_entities(world);
 
     for entity in entities_to_despawn {
         // Check if entity still exists (may have been despawned as a child of another entity)
-        if world.get_entity(entity).is_ok() {
-            world.despawn(entity);
+        if let Ok(entity) = world.get_entity_mut(entity) {
+            entity.despawn_recursive();
         }
     }
 }
@@ -156,6 +796,8 @@ pub fn clear_scene_entities(world: &mut World) {
 /// Loads a scene from a file, replacing the current scene.
 ///
 /// This clears existing scene entities before loading the new scene.
+/// Relative paths are resolved against the `ProjectRoot`, if present. RON
+/// and JSON files are both read, told apart by [`SceneFormat::detect`].
 ///
 /// # Arguments
 /// * `world` - The world to load into
@@ -163,36 +805,58 @@ pub fn clear_scene_entities(world: &mut World) {
 ///
 /// # Returns
 /// `Ok(())` on success, or a `SceneError` on failure.
-pub fn load_scene(world: &mut World, path: &PathBuf) -> SceneResult<()> {
-    // Read the scene file
-    let scene_data = std::fs::read_to_string(path).map_err(SceneError::FileRead)?;
-
-    // Get type registry for deserialization
-    let type_registry = world.resource::<AppTypeRegistry>().clone();
+pub fn load_scene(world: &mut World, path: &Path) -> SceneResult<()> {
+    load_scene_with(world, path, parse_scene)
+}
 
-    // Deserialize the scene using RON
-    let mut deserializer = bevy::scene::ron::de::Deserializer::from_str(&scene_data)
-        .map_err(|e| SceneError::Deserialization(e.to_string()))?;
+/// Loads a scene from a JSON file written by [`save_scene_json`] (or a tool
+/// producing the same structure), replacing the current scene.
+///
+/// Works like [`load_scene`]; only the file format differs.
+pub fn load_scene_json(world: &mut World, path: &Path) -> SceneResult<()> {
+    load_scene_with(world, path, deserialize_json)
+}
 
-    let scene_deserializer = SceneDeserializer {
-        type_registry: &type_registry.read(),
-    };
+/// Replaces the scene with the one at `path`, read by `deserialize`.
+fn load_scene_with(
+    world: &mut World,
+    path: &Path,
+    deserialize: fn(&str, &TypeRegistry) -> SceneResult<DynamicScene>,
+) -> SceneResult<()> {
+    let path = &resolve_in_world(world, path);
+    let result = replace_scene(world, path, deserialize);
+    match &result {
+        Ok(()) => send_scene_event(world, SceneLoaded { path: path.clone() }),
+        Err(e) => send_scene_event(
+            world,
+            SceneLoadFailed {
+                path: path.clone(),
+                error: e.to_string(),
+            },
+        ),
+    }
+    result
+}
 
-    let scene: DynamicScene = scene_deserializer
-        .deserialize(&mut deserializer)
-        .map_err(|e| SceneError::Deserialization(e.to_string()))?;
+/// Replaces the scene with the one at the resolved `path`.
+fn replace_scene(
+    world: &mut World,
+    path: &Path,
+    deserialize: fn(&str, &TypeRegistry) -> SceneResult<DynamicScene>,
+) -> SceneResult<()> {
+    let scene = read_scene_with(world, path, deserialize)?;
 
     // Clear existing scene entities
     clear_scene_entities(world);
 
-    // Spawn the scene entities
-    scene
-        .write_to_world(world, &mut bevy::ecs::entity::EntityHashMap::default())
-        .map_err(|e| SceneError::Deserialization(format!("{:?}", e)))?;
+    // Spawn the scene entities and restore its resources
+    let entities = write_scene(world, &scene)?;
+    reset_scene_resources(world, Some(&scene));
+    spawn_scene_instances(world, &entities, &mut vec![path.to_path_buf()]);
 
     // Update scene manager
     if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
-        manager.current_scene_path = Some(path.clone());
+        manager.current_scene_path = Some(path.to_path_buf());
         manager.mark_clean();
     }
 
@@ -200,6 +864,7 @@ pub fn load_scene(world: &mut World, path: &PathBuf) -> SceneResult<()> {
 }
 
 /// Spawns a prefab into the current scene without clearing existing entities.
+/// Relative paths are resolved against the `ProjectRoot`, if present.
 ///
 /// # Arguments
 /// * `world` - The world to spawn into
@@ -207,44 +872,354 @@ pub fn load_scene(world: &mut World, path: &PathBuf) -> SceneResult<()> {
 ///
 /// # Returns
 /// `Ok(())` on success, or a `SceneError` on failure.
-pub fn spawn_prefab(world: &mut World, path: &PathBuf) -> SceneResult<()> {
-    // Read the prefab file
-    let prefab_data = std::fs::read_to_string(path).map_err(SceneError::FileRead)?;
+pub fn spawn_prefab(world: &mut World, path: &Path) -> SceneResult<()> {
+    instantiate_prefab(world, path)?;
 
-    // Get type registry for deserialization
+    // Mark scene as dirty since we added entities
+    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
+        manager.mark_dirty();
+    }
+
+    Ok(())
+}
+
+/// Spawns a prefab's entities and returns them, without marking the scene dirty.
+///
+/// Each top-level entity gets a [`PrefabSource`] linking it back to the file.
+/// Use this from gameplay code; [`spawn_prefab`] is the editor's variant.
+/// Relative paths are resolved against the `ProjectRoot`, if present.
+pub fn instantiate_prefab(world: &mut World, path: &Path) -> SceneResult<Vec<Entity>> {
+    let path = &resolve_in_world(world, path);
+    let scene = read_scene(world, path)?;
+
+    // Spawn the prefab entities (don't clear existing). They are new to the
+    // scene, so they don't keep the prefab file's keys.
+    let mut spawned = write_scene(world, &scene)?;
+    for &entity in &spawned {
+        world.entity_mut(entity).remove::<SceneEntityId>();
+    }
+    spawn_scene_instances(world, &spawned, &mut vec![path.clone()]);
+
+    // Link the top-level entities back to the file, in file order
+    let source = world
+        .get_resource::<ProjectRoot>()
+        .and_then(|project| project.to_asset_path(path))
+        .unwrap_or_else(|| path.to_string_lossy().into_owned());
+    let top_level: Vec<Entity> = spawned
+        .iter()
+        .copied()
+        .filter(|&entity| world.get::<Parent>(entity).is_none())
+        .collect();
+    for (root, entity) in top_level.into_iter().enumerate() {
+        world.entity_mut(entity).insert(PrefabSource {
+            path: source.clone(),
+            root,
+        });
+    }
+
+    spawned.sort();
+    Ok(spawned)
+}
+
+/// Gives the scene's entities their [`SceneEntityId`] keys and sorts them by key.
+///
+/// Entities keep the key they were loaded with unless another entity already
+/// has it; the rest get the next free keys, in entity order, which are also
+/// stored on the world's entities so the next save uses them again. Entity
+/// references inside components are rewritten to the keys.
+pub(crate) fn assign_scene_ids(
+    world: &mut World,
+    scene: &mut DynamicScene,
+    type_registry: &TypeRegistry,
+) {
+    let mut used = std::collections::BTreeSet::new();
+    let mut keys: EntityHashMap<Entity> = EntityHashMap::default();
+    let mut unassigned = Vec::new();
+    for entity in scene.entities.iter().map(|entity| entity.entity) {
+        match world.get::<SceneEntityId>(entity) {
+            Some(&SceneEntityId(id)) if used.insert(id) => {
+                keys.insert(entity, Entity::from_raw(id));
+            }
+            _ => unassigned.push(entity),
+        }
+    }
+    let first_free = used.last().map_or(0, |&id| id + 1);
+    for (id, entity) in (first_free..).zip(unassigned) {
+        keys.insert(entity, Entity::from_raw(id));
+        world.entity_mut(entity).insert(SceneEntityId(id));
+    }
+
+    let mut mapper = SceneKeyMapper(keys);
+    for entity in &mut scene.entities {
+        entity.entity = mapper.map_entity(entity.entity);
+        for component in &mut entity.components {
+            let map_entities = component
+                .get_represented_type_info()
+                .and_then(|info| type_registry.get_type_data::<ReflectMapEntities>(info.type_id()));
+            if let Some(map_entities) = map_entities {
+                map_entities.map_entities(component.as_partial_reflect_mut(), &mut mapper);
+            }
+        }
+    }
+    scene.entities.sort_by_key(|entity| entity.entity);
+}
+
+/// Maps saved entities to their file keys; references to unsaved ones are kept.
+struct SceneKeyMapper(EntityHashMap<Entity>);
+
+impl EntityMapper for SceneKeyMapper {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
+/// Reads and deserializes a scene file for its entities, dropping
+/// editor-only components unless the world keeps them.
+///
+/// The file's resources are left out: prefabs and scene instances only add
+/// entities to the scene that is open.
+pub(crate) fn read_scene(world: &World, path: &Path) -> SceneResult<DynamicScene> {
+    let mut scene = read_scene_with(world, path, parse_scene)?;
+    scene.resources.clear();
+    Ok(scene)
+}
+
+/// Reads a scene file with `deserialize`, dropping editor-only components
+/// unless the world keeps them.
+fn read_scene_with(
+    world: &World,
+    path: &Path,
+    deserialize: fn(&str, &TypeRegistry) -> SceneResult<DynamicScene>,
+) -> SceneResult<DynamicScene> {
+    let scene_data = std::fs::read_to_string(path).map_err(SceneError::FileRead)?;
     let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let mut scene = deserialize(&scene_data, &type_registry)?;
+    strip_editor_only(world, &mut scene, &type_registry);
+    Ok(scene)
+}
 
-    // Deserialize the prefab using RON
-    let mut deserializer = bevy::scene::ron::de::Deserializer::from_str(&prefab_data)
-        .map_err(|e| SceneError::Deserialization(e.to_string()))?;
+/// Parses scene file contents in the format [`SceneFormat::detect`] finds.
+///
+/// For tools that inspect scene files without loading them; editor-only
+/// components are kept.
+pub fn parse_scene(data: &str, type_registry: &TypeRegistry) -> SceneResult<DynamicScene> {
+    SceneFormat::detect(data).deserializer()(data, type_registry)
+}
 
-    let scene_deserializer = SceneDeserializer {
-        type_registry: &type_registry.read(),
-    };
+/// Deserializes a RON scene.
+fn deserialize_ron(data: &str, type_registry: &TypeRegistry) -> SceneResult<DynamicScene> {
+    let mut deserializer = bevy::scene::ron::de::Deserializer::from_str(data)
+        .map_err(|e| SceneError::Deserialization(e.to_string()))?;
+    SceneDeserializer { type_registry }
+        .deserialize(&mut deserializer)
+        .map_err(|e| SceneError::Deserialization(e.to_string()))
+}
 
-    let scene: DynamicScene = scene_deserializer
+/// Deserializes a JSON scene.
+fn deserialize_json(data: &str, type_registry: &TypeRegistry) -> SceneResult<DynamicScene> {
+    let mut deserializer = serde_json::Deserializer::from_str(data);
+    let scene = SceneDeserializer { type_registry }
         .deserialize(&mut deserializer)
         .map_err(|e| SceneError::Deserialization(e.to_string()))?;
+    deserializer
+        .end()
+        .map_err(|e| SceneError::Deserialization(e.to_string()))?;
+    Ok(scene)
+}
 
-    // Spawn the prefab entities (don't clear existing)
+/// Spawns a scene's entities as new entities and returns them, in the
+/// order the scene lists them. Each remembers its key as a [`SceneEntityId`].
+pub(crate) fn write_scene(world: &mut World, scene: &DynamicScene) -> SceneResult<Vec<Entity>> {
+    let mut entity_map = EntityHashMap::default();
     scene
-        .write_to_world(world, &mut bevy::ecs::entity::EntityHashMap::default())
+        .write_to_world(world, &mut entity_map)
         .map_err(|e| SceneError::Deserialization(format!("{:?}", e)))?;
+    detach_missing_relatives(world, entity_map.values().copied());
+    let mut spawned = Vec::with_capacity(scene.entities.len());
+    for key in scene.entities.iter().map(|entity| entity.entity) {
+        if let Some(&entity) = entity_map.get(&key) {
+            world.entity_mut(entity).insert(SceneEntityId(key.index()));
+            spawned.push(entity);
+        }
+    }
+    Ok(spawned)
+}
 
-    // Mark scene as dirty since we added entities
-    if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
-        manager.mark_dirty();
+/// Spawns the scenes referenced by `SceneInstance`s among `entities` as their
+/// children, replacing any they had.
+///
+/// `including` lists the scene files being spawned, outermost first, so a
+/// scene that includes itself is skipped instead of recursing forever.
+/// Failures are logged so one broken reference doesn't stop a level loading.
+fn spawn_scene_instances(world: &mut World, entities: &[Entity], including: &mut Vec<PathBuf>) {
+    for &root in entities {
+        let Some(instance) = world.get::<SceneInstance>(root).cloned() else {
+            continue;
+        };
+        let mut root_entity = world.entity_mut(root);
+        root_entity.despawn_descendants();
+        root_entity.insert(LoadedSceneInstance(instance.scene.clone()));
+        if instance.scene.is_empty() {
+            continue;
+        }
+
+        let path = match world.get_resource::<ProjectRoot>() {
+            Some(project) => project.resolve_asset(&instance.scene),
+            None => PathBuf::from(&instance.scene),
+        };
+        if including.contains(&path) {
+            warn!("Scene instance {} includes itself; skipped", instance.scene);
+            continue;
+        }
+        let spawned = match read_scene(world, &path).and_then(|scene| write_scene(world, &scene)) {
+            Ok(spawned) => spawned,
+            Err(e) => {
+                warn!("Failed to spawn scene instance {}: {}", instance.scene, e);
+                continue;
+            }
+        };
+
+        let mut top_level: Vec<Entity> = spawned
+            .iter()
+            .copied()
+            .filter(|&entity| world.get::<Parent>(entity).is_none())
+            .collect();
+        top_level.sort();
+        world.entity_mut(root).add_children(&top_level);
+
+        including.push(path);
+        spawn_scene_instances(world, &spawned, including);
+        including.pop();
     }
+}
 
-    Ok(())
+/// Respawns an instance's children when its `SceneInstance` is added,
+/// repointed, or removed, e.g. from the editor's inspector or undo.
+fn sync_scene_instances(world: &mut World) {
+    let mut stale = world.query::<(Entity, &SceneInstance, Option<&LoadedSceneInstance>)>();
+    let changed: Vec<Entity> = stale
+        .iter(world)
+        .filter(|(_, instance, loaded)| !loaded.is_some_and(|loaded| loaded.0 == instance.scene))
+        .map(|(entity, _, _)| entity)
+        .collect();
+    let mut removed =
+        world.query_filtered::<Entity, (With<LoadedSceneInstance>, Without<SceneInstance>)>();
+    let removed: Vec<Entity> = removed.iter(world).collect();
+    for entity in removed {
+        let mut entity = world.entity_mut(entity);
+        entity.despawn_descendants();
+        entity.remove::<LoadedSceneInstance>();
+    }
+    if changed.is_empty() {
+        return;
+    }
+    let mut including: Vec<PathBuf> = world
+        .get_resource::<SceneManager>()
+        .and_then(|manager| manager.current_scene_path.clone())
+        .into_iter()
+        .collect();
+    spawn_scene_instances(world, &changed, &mut including);
 }
 
-/// Creates a new empty scene, clearing all existing entities.
+/// Removes children that aren't in `saved` from the `Children` of the
+/// scene's entities.
+pub fn drop_unsaved_children(world: &World, scene: &mut DynamicScene, saved: &EntityHashSet) {
+    for entity in &mut scene.entities {
+        let Some(children) = world.get::<Children>(entity.entity) else {
+            continue;
+        };
+        if children.iter().all(|child| saved.contains(child)) {
+            continue;
+        }
+        for component in &mut entity.components {
+            let is_children = component
+                .get_represented_type_info()
+                .is_some_and(|info| info.type_id() == std::any::TypeId::of::<Children>());
+            if !is_children {
+                continue;
+            }
+            let ReflectMut::TupleStruct(children) = component.reflect_mut() else {
+                continue;
+            };
+            let Some(ReflectMut::List(list)) =
+                children.field_mut(0).map(PartialReflect::reflect_mut)
+            else {
+                continue;
+            };
+            for index in (0..list.len()).rev() {
+                let child = list
+                    .get(index)
+                    .and_then(|child| child.try_downcast_ref::<Entity>());
+                if !child.is_some_and(|child| saved.contains(child)) {
+                    list.remove(index);
+                }
+            }
+        }
+    }
+}
+
+/// Detaches loaded entities from parents and children the scene file didn't
+/// contain, e.g. in files saved before hierarchies were filtered.
+fn detach_missing_relatives(world: &mut World, entities: impl Iterator<Item = Entity>) {
+    for entity in entities {
+        let parent = world.get::<Parent>(entity).map(Parent::get);
+        if parent.is_some_and(|parent| world.get_entity(parent).is_err()) {
+            world.entity_mut(entity).remove::<Parent>();
+        }
+        let Some(children) = world.get::<Children>(entity) else {
+            continue;
+        };
+        let remaining: Vec<Entity> = children
+            .iter()
+            .copied()
+            .filter(|child| world.get_entity(*child).is_ok())
+            .collect();
+        if remaining.len() < children.len() {
+            // `remove_children` expects live children, so rebuild the list in order
+            let mut entity = world.entity_mut(entity);
+            entity.remove::<Children>();
+            entity.add_children(&remaining);
+        }
+    }
+}
+
+/// Removes editor-only components from a loaded scene, unless the world keeps them.
+fn strip_editor_only(world: &World, scene: &mut DynamicScene, type_registry: &TypeRegistry) {
+    let keep = world
+        .get_resource::<SceneLoadSettings>()
+        .is_some_and(|settings| settings.keep_editor_only);
+    if keep {
+        return;
+    }
+    for entity in &mut scene.entities {
+        entity.components.retain(|component| {
+            !component.get_represented_type_info().is_some_and(|info| {
+                type_registry
+                    .get_type_data::<ReflectEditorOnly>(info.type_id())
+                    .is_some()
+            })
+        });
+    }
+}
+
+/// Creates a new empty scene, clearing all existing entities and resetting
+/// the [`SceneResources`].
 pub fn new_scene(world: &mut World) {
     clear_scene_entities(world);
+    reset_scene_resources(world, None);
 
     if let Some(mut manager) = world.get_resource_mut::<SceneManager>() {
         manager.current_scene_path = None;
         manager.mark_clean();
     }
+    send_scene_event(world, SceneCleared);
+}
+
+/// Sends `event` if the world has its `Events` (from [`ScenePlugin`]), so
+/// scene functions also work on bare worlds.
+fn send_scene_event<E: Event>(world: &mut World, event: E) {
+    if let Some(mut events) = world.get_resource_mut::<Events<E>>() {
+        events.send(event);
+    }
 }
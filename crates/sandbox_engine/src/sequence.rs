@@ -0,0 +1,509 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Cutscenes and other scripted moments, played from a timeline of commands.
+//!
+//! A `Sequence` is a list of steps run one after another. A step marked
+//! `with_previous` starts together with the step before it, so a camera can
+//! pan while a ship flies in; the next step waits for the whole group. The
+//! commands are:
+//! - `Wait(seconds)` does nothing for a while
+//! - `MoveAlong` moves a named entity from where it is through a path of
+//!   world positions, at constant speed over `duration` seconds
+//! - `PlayAnimation` restarts a named entity's `SpriteAnimation` and moves on
+//! - `ShowDialogue` starts a conversation in `ActiveDialogue` and waits for
+//!   it to end
+//! - `CameraPan` eases the active 2D camera to a position over `duration`
+//!   seconds
+//!
+//! Sequences are RON files, conventionally `assets/sequences/*.sequence.ron`:
+//! ```ron
+//! (
+//!     steps: [
+//!         (command: CameraPan(to: (300.0, 200.0), duration: 1.5)),
+//!         (command: ShowDialogue(dialogue: "dialogue/intro.dialogue.ron")),
+//!         (command: CameraPan(to: (0.0, 0.0), duration: 1.0)),
+//!         (
+//!             command: MoveAlong(entity: "Player Ship", path: [(0.0, 0.0)], duration: 1.0),
+//!             with_previous: true,
+//!         ),
+//!     ],
+//! )
+//! ```
+//!
+//! A `SequencePlayer` with `play_on_start` plays its sequence when gameplay
+//! starts; game code plays others with `SequencePlayback::play`. Running
+//! sequences are dropped when the editor stops. The `sequence_playing` run
+//! condition holds back player input or camera follow while one runs.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::assets::SpriteAnimation;
+use crate::dialogue::{ActiveDialogue, Dialogue, DialogueEvent, DialogueEventKind, DialogueRunner};
+use crate::editor_state::{EditorPlayState, GameplaySystemSet};
+use crate::flags::WorldFlags;
+use crate::project::{write_keeping_header, ProjectRoot};
+use crate::scene::{SceneError, SceneResult};
+use crate::system_toggle::ToggleableSystem;
+
+/// Plugin that plays sequences.
+pub struct SequencePlugin;
+
+impl Plugin for SequencePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SequencePlayer>()
+            .init_resource::<SequencePlayback>()
+            .init_resource::<ActiveDialogue>()
+            .init_resource::<WorldFlags>()
+            .add_event::<SequenceEvent>()
+            .add_event::<DialogueEvent>()
+            .add_systems(
+                Update,
+                (autoplay_sequences.toggleable(), play_sequences.toggleable())
+                    .chain()
+                    .in_set(GameplaySystemSet),
+            )
+            .add_systems(OnEnter(EditorPlayState::Stopped), stop_sequences);
+    }
+}
+
+/// A scripted moment: steps run in order, or alongside the previous one.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Sequence {
+    /// Steps in the order they start.
+    pub steps: Vec<SequenceStep>,
+}
+
+/// One command on a sequence's timeline.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SequenceStep {
+    /// What the step does.
+    pub command: SequenceCommand,
+    /// Starts together with the previous step instead of after it.
+    pub with_previous: bool,
+}
+
+/// What a sequence step does.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum SequenceCommand {
+    /// Does nothing for this many seconds.
+    Wait(f32),
+    /// Moves the entity named `entity` from where it is through `path`.
+    MoveAlong {
+        /// Name of the entity to move.
+        entity: String,
+        /// World positions to pass through, ending at the last.
+        path: Vec<Vec2>,
+        /// Seconds the whole path takes.
+        duration: f32,
+    },
+    /// Restarts the `SpriteAnimation` of the entity named `entity`.
+    PlayAnimation {
+        /// Name of the animated entity.
+        entity: String,
+    },
+    /// Starts a conversation and waits for it to end.
+    ShowDialogue {
+        /// Dialogue file, relative to the assets directory.
+        dialogue: String,
+        /// Node to start at; empty uses the dialogue's start node.
+        #[serde(default)]
+        start: String,
+    },
+    /// Eases the active 2D camera to `to`.
+    CameraPan {
+        /// World position to center on.
+        to: Vec2,
+        /// Seconds the pan takes.
+        duration: f32,
+    },
+}
+
+impl Default for SequenceCommand {
+    fn default() -> Self {
+        SequenceCommand::Wait(1.0)
+    }
+}
+
+impl SequenceCommand {
+    /// Display name of the command.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SequenceCommand::Wait(_) => "Wait",
+            SequenceCommand::MoveAlong { .. } => "Move Along",
+            SequenceCommand::PlayAnimation { .. } => "Play Animation",
+            SequenceCommand::ShowDialogue { .. } => "Show Dialogue",
+            SequenceCommand::CameraPan { .. } => "Camera Pan",
+        }
+    }
+
+    /// Seconds the command takes, or `None` if it lasts until the player
+    /// finishes it (dialogues).
+    pub fn duration(&self) -> Option<f32> {
+        match self {
+            SequenceCommand::Wait(seconds) => Some(seconds.max(0.0)),
+            SequenceCommand::MoveAlong { duration, .. }
+            | SequenceCommand::CameraPan { duration, .. } => Some(duration.max(0.0)),
+            SequenceCommand::PlayAnimation { .. } => Some(0.0),
+            SequenceCommand::ShowDialogue { .. } => None,
+        }
+    }
+}
+
+impl Sequence {
+    /// Reads a sequence from a RON file.
+    pub fn load(path: &Path) -> SceneResult<Self> {
+        let data = std::fs::read_to_string(path).map_err(SceneError::FileRead)?;
+        bevy::scene::ron::from_str(&data).map_err(|e| SceneError::Deserialization(e.to_string()))
+    }
+
+    /// Writes the sequence to a RON file, keeping the file's header comments.
+    pub fn save(&self, path: &Path) -> SceneResult<()> {
+        let data = bevy::scene::ron::ser::to_string_pretty(self, Default::default())
+            .map_err(|e| SceneError::Serialization(e.to_string()))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(SceneError::Io)?;
+        }
+        write_keeping_header(path, &data).map_err(SceneError::Io)
+    }
+
+    /// Seconds from the start at which each step starts, counting steps
+    /// without a duration (dialogues) as `open_ended` seconds long.
+    pub fn start_times(&self, open_ended: f32) -> Vec<f32> {
+        let mut times = Vec::with_capacity(self.steps.len());
+        let mut group_start = 0.0;
+        let mut group_end: f32 = 0.0;
+        for (index, step) in self.steps.iter().enumerate() {
+            if index > 0 && !step.with_previous {
+                group_start = group_end;
+            }
+            times.push(group_start);
+            let duration = step.command.duration().unwrap_or(open_ended);
+            group_end = group_end.max(group_start + duration);
+        }
+        times
+    }
+
+    /// Seconds the whole sequence takes, counting steps without a duration
+    /// as `open_ended` seconds long.
+    pub fn length(&self, open_ended: f32) -> f32 {
+        self.start_times(open_ended)
+            .iter()
+            .zip(&self.steps)
+            .map(|(start, step)| start + step.command.duration().unwrap_or(open_ended))
+            .fold(0.0, f32::max)
+    }
+}
+
+/// Plays a sequence file, e.g. a level intro.
+#[derive(Component, Reflect, Default, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct SequencePlayer {
+    /// Sequence file, relative to the assets directory.
+    pub sequence: String,
+    /// Plays the sequence once when gameplay starts.
+    pub play_on_start: bool,
+}
+
+impl SequencePlayer {
+    /// Plays the sequence in `path` when gameplay starts.
+    pub fn on_start(path: impl Into<String>) -> Self {
+        Self {
+            sequence: path.into(),
+            play_on_start: true,
+        }
+    }
+}
+
+/// Sequences being played.
+#[derive(Resource, Default)]
+pub struct SequencePlayback {
+    runs: Vec<SequenceRun>,
+    /// Players whose `play_on_start` sequence has been started.
+    started: Vec<Entity>,
+}
+
+impl SequencePlayback {
+    /// Starts playing `sequence` on the next update. `source` is reported in
+    /// its `SequenceEvent`s.
+    pub fn play(&mut self, sequence: Sequence, source: Option<Entity>) {
+        self.runs.push(SequenceRun {
+            sequence,
+            source,
+            next: 0,
+            running: Vec::new(),
+            announced: false,
+        });
+    }
+
+    /// Whether any sequence is playing.
+    pub fn is_playing(&self) -> bool {
+        !self.runs.is_empty()
+    }
+
+    /// Stops every sequence where it is.
+    pub fn stop_all(&mut self) {
+        self.runs.clear();
+    }
+}
+
+/// Run condition that holds while a sequence is playing.
+pub fn sequence_playing(playback: Option<Res<SequencePlayback>>) -> bool {
+    playback.is_some_and(|playback| playback.is_playing())
+}
+
+/// A sequence in progress.
+struct SequenceRun {
+    sequence: Sequence,
+    source: Option<Entity>,
+    /// Index of the next step to start.
+    next: usize,
+    /// Steps started and not yet finished.
+    running: Vec<RunningStep>,
+    /// Whether `Started` has been sent.
+    announced: bool,
+}
+
+/// A step in progress.
+struct RunningStep {
+    index: usize,
+    /// Seconds since the step started.
+    elapsed: f32,
+    /// Entity the step moves, with its position when the step started.
+    target: Option<(Entity, Vec3)>,
+    /// Whether the step's dialogue has been started.
+    dialogue_started: bool,
+}
+
+/// What happened to a sequence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SequenceEventKind {
+    /// It started playing.
+    Started,
+    /// Its last step finished.
+    Finished,
+}
+
+/// Sent when a sequence starts or finishes.
+#[derive(Event, Clone, Debug)]
+pub struct SequenceEvent {
+    /// Entity whose `SequencePlayer` played it, if any.
+    pub source: Option<Entity>,
+    /// What happened.
+    pub kind: SequenceEventKind,
+}
+
+/// Drops running sequences and forgets which players have played.
+fn stop_sequences(mut playback: ResMut<SequencePlayback>) {
+    *playback = SequencePlayback::default();
+}
+
+/// Plays the sequences of `play_on_start` players that haven't played yet.
+fn autoplay_sequences(
+    players: Query<(Entity, &SequencePlayer)>,
+    root: Option<Res<ProjectRoot>>,
+    mut playback: ResMut<SequencePlayback>,
+) {
+    for (entity, player) in &players {
+        if !player.play_on_start || playback.started.contains(&entity) {
+            continue;
+        }
+        playback.started.push(entity);
+        let path = match &root {
+            Some(root) => root.resolve_asset(&player.sequence),
+            None => player.sequence.clone().into(),
+        };
+        match Sequence::load(&path) {
+            Ok(sequence) => playback.play(sequence, Some(entity)),
+            Err(e) => warn!("Failed to load sequence {}: {}", player.sequence, e),
+        }
+    }
+}
+
+/// Advances running sequences, starting each group of steps when the
+/// previous group has finished.
+#[allow(clippy::too_many_arguments)]
+fn play_sequences(
+    time: Res<Time>,
+    root: Option<Res<ProjectRoot>>,
+    mut playback: ResMut<SequencePlayback>,
+    names: Query<(Entity, &Name)>,
+    cameras: Query<(Entity, &Camera), With<Camera2d>>,
+    mut transforms: Query<&mut Transform>,
+    mut animations: Query<&mut SpriteAnimation>,
+    mut dialogue: ResMut<ActiveDialogue>,
+    mut flags: ResMut<WorldFlags>,
+    mut dialogue_events: EventWriter<DialogueEvent>,
+    mut events: EventWriter<SequenceEvent>,
+) {
+    let delta = time.delta_secs();
+    let find_named = |name: &str| {
+        let found = names
+            .iter()
+            .find(|(_, entity_name)| entity_name.as_str() == name)
+            .map(|(entity, _)| entity);
+        if found.is_none() {
+            warn!("Sequence step skipped: no entity named '{}'", name);
+        }
+        found
+    };
+
+    for run in &mut playback.runs {
+        if !run.announced {
+            run.announced = true;
+            events.send(SequenceEvent {
+                source: run.source,
+                kind: SequenceEventKind::Started,
+            });
+        }
+
+        // Start the next group once the current one is done
+        if run.running.is_empty() {
+            while run.next < run.sequence.steps.len() {
+                let command = &run.sequence.steps[run.next].command;
+                let target = match command {
+                    SequenceCommand::MoveAlong { entity, .. }
+                    | SequenceCommand::PlayAnimation { entity } => find_named(entity),
+                    SequenceCommand::CameraPan { .. } => cameras
+                        .iter()
+                        .find(|(_, camera)| camera.is_active)
+                        .map(|(entity, _)| entity),
+                    _ => None,
+                };
+                if let (SequenceCommand::PlayAnimation { .. }, Some(entity)) = (command, target) {
+                    if let Ok(mut animation) = animations.get_mut(entity) {
+                        animation.reset();
+                        animation.play();
+                    }
+                }
+                run.running.push(RunningStep {
+                    index: run.next,
+                    elapsed: 0.0,
+                    target: target.and_then(|entity| {
+                        let translation = transforms.get(entity).ok()?.translation;
+                        Some((entity, translation))
+                    }),
+                    dialogue_started: false,
+                });
+                run.next += 1;
+                let joins = run
+                    .sequence
+                    .steps
+                    .get(run.next)
+                    .is_some_and(|step| step.with_previous);
+                if !joins {
+                    break;
+                }
+            }
+        }
+
+        let steps = &run.sequence.steps;
+        run.running.retain_mut(|step| {
+            step.elapsed += delta;
+            let progress = |duration: f32| {
+                if duration > 0.0 {
+                    (step.elapsed / duration).min(1.0)
+                } else {
+                    1.0
+                }
+            };
+            match &steps[step.index].command {
+                SequenceCommand::Wait(seconds) => step.elapsed < *seconds,
+                SequenceCommand::PlayAnimation { .. } => false,
+                SequenceCommand::MoveAlong { path, duration, .. } => {
+                    let Some((entity, from)) = step.target else {
+                        return false;
+                    };
+                    let t = progress(*duration);
+                    if let Ok(mut transform) = transforms.get_mut(entity) {
+                        let position = point_along(from.truncate(), path, t);
+                        transform.translation = position.extend(from.z);
+                    }
+                    t < 1.0
+                }
+                SequenceCommand::CameraPan { to, duration } => {
+                    let Some((entity, from)) = step.target else {
+                        return false;
+                    };
+                    let t = progress(*duration);
+                    let eased = t * t * (3.0 - 2.0 * t);
+                    if let Ok(mut transform) = transforms.get_mut(entity) {
+                        let position = from.truncate().lerp(*to, eased);
+                        transform.translation = position.extend(from.z);
+                    }
+                    t < 1.0
+                }
+                SequenceCommand::ShowDialogue {
+                    dialogue: file,
+                    start,
+                } => {
+                    if step.dialogue_started {
+                        return dialogue.is_active();
+                    }
+                    // Wait for any other conversation to end first
+                    if dialogue.is_active() {
+                        return true;
+                    }
+                    let path = match &root {
+                        Some(root) => root.resolve_asset(file),
+                        None => file.into(),
+                    };
+                    let loaded = match Dialogue::load(&path) {
+                        Ok(loaded) => loaded,
+                        Err(e) => {
+                            warn!("Failed to load dialogue {}: {}", file, e);
+                            return false;
+                        }
+                    };
+                    let start = match start.as_str() {
+                        "" => loaded.start.clone(),
+                        start => start.to_string(),
+                    };
+                    dialogue.runner = Some(DialogueRunner::new_at(loaded, &start, &mut flags));
+                    dialogue.source = None;
+                    dialogue_events.send(DialogueEvent {
+                        source: None,
+                        kind: DialogueEventKind::Started,
+                    });
+                    step.dialogue_started = true;
+                    true
+                }
+            }
+        });
+    }
+
+    playback.runs.retain(|run| {
+        let finished = run.running.is_empty() && run.next >= run.sequence.steps.len();
+        if finished {
+            events.send(SequenceEvent {
+                source: run.source,
+                kind: SequenceEventKind::Finished,
+            });
+        }
+        !finished
+    });
+}
+
+/// Position a fraction `t` of the way along the polyline from `from` through `path`.
+fn point_along(from: Vec2, path: &[Vec2], t: f32) -> Vec2 {
+    let length: f32 = std::iter::once(from)
+        .chain(path.iter().copied())
+        .zip(path)
+        .map(|(a, b)| a.distance(*b))
+        .sum();
+    let mut remaining = length * t;
+    let mut position = from;
+    for &point in path {
+        let segment = position.distance(point);
+        if remaining <= segment && segment > 0.0 {
+            return position.lerp(point, remaining / segment);
+        }
+        remaining -= segment;
+        position = point;
+    }
+    position
+}
@@ -0,0 +1,125 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Spawn points placed in scenes, such as the player start.
+//!
+//! A `SpawnPoint` marks a named location in a scene. Gameplay code looks
+//! spawn points up through the `SpawnPoints` system parameter:
+//!
+//! ```ignore
+//! fn place_player(spawns: SpawnPoints, mut players: Query<&mut Transform, With<Player>>) {
+//!     if let Some(start) = spawns.find_spawn(PLAYER_START) {
+//!         for mut transform in &mut players {
+//!             transform.translation = start.translation;
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// Id of the spawn point where the player starts.
+pub const PLAYER_START: &str = "player_start";
+
+/// Plugin registering spawn point types.
+pub struct SpawnPointPlugin;
+
+impl Plugin for SpawnPointPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SpawnPoint>()
+            .register_type::<SpawnKind>();
+    }
+}
+
+/// What a spawn point is for.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SpawnKind {
+    /// Where the player appears.
+    #[default]
+    Player,
+    /// Where enemies appear.
+    Enemy,
+    /// Where pickups appear.
+    Item,
+    /// Anything else; tell spawn points apart by id.
+    Other,
+}
+
+/// A named location in a scene where something is spawned.
+///
+/// Only the entity's transform matters; spawn points are invisible in game.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+#[require(Transform)]
+pub struct SpawnPoint {
+    /// Id gameplay code looks the spawn point up by, e.g. "player_start".
+    pub id: String,
+    /// What the spawn point is for.
+    pub kind: SpawnKind,
+}
+
+impl Default for SpawnPoint {
+    fn default() -> Self {
+        Self::new(PLAYER_START, SpawnKind::Player)
+    }
+}
+
+impl SpawnPoint {
+    /// Creates a spawn point with the given id and kind.
+    pub fn new(id: impl Into<String>, kind: SpawnKind) -> Self {
+        Self {
+            id: id.into(),
+            kind,
+        }
+    }
+}
+
+/// System parameter for finding spawn points and their world transforms.
+///
+/// Transforms are computed from the parent chain, so spawn points from a
+/// scene loaded this frame are placed correctly before transform propagation.
+#[derive(SystemParam)]
+pub struct SpawnPoints<'w, 's> {
+    spawns: Query<'w, 's, (Entity, &'static SpawnPoint)>,
+    transforms: Query<'w, 's, (&'static Transform, Option<&'static Parent>)>,
+}
+
+impl SpawnPoints<'_, '_> {
+    /// World transform of the spawn point with `id`.
+    ///
+    /// If several share the id, the one with the lowest entity index is used.
+    pub fn find_spawn(&self, id: &str) -> Option<Transform> {
+        self.spawns
+            .iter()
+            .filter(|(_, spawn)| spawn.id == id)
+            .min_by_key(|(entity, _)| entity.index())
+            .and_then(|(entity, _)| self.world_transform(entity))
+    }
+
+    /// Spawn points of one kind with their world transforms, in entity order.
+    pub fn of_kind(&self, kind: SpawnKind) -> Vec<(&SpawnPoint, Transform)> {
+        let mut spawns: Vec<_> = self
+            .spawns
+            .iter()
+            .filter(|(_, spawn)| spawn.kind == kind)
+            .filter_map(|(entity, spawn)| Some((entity, spawn, self.world_transform(entity)?)))
+            .collect();
+        spawns.sort_by_key(|(entity, _, _)| entity.index());
+        spawns
+            .into_iter()
+            .map(|(_, spawn, transform)| (spawn, transform))
+            .collect()
+    }
+
+    fn world_transform(&self, entity: Entity) -> Option<Transform> {
+        let (&transform, mut parent) = self.transforms.get(entity).ok()?;
+        let mut world_transform = transform;
+        while let Some(entity) = parent {
+            let (&parent_transform, grandparent) = self.transforms.get(entity.get()).ok()?;
+            world_transform = parent_transform.mul_transform(world_transform);
+            parent = grandparent;
+        }
+        Some(world_transform)
+    }
+}
@@ -0,0 +1,285 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Timed, stackable status effects such as "slowed", "burning", or "magnet boost".
+//!
+//! A status effect is a reflected game type implementing `StatusEffect`. It is
+//! attached to an entity as a `Status<T>` component, which tracks stacks,
+//! remaining duration, and periodic ticks. Register each effect type with
+//! `StatusEffectPlugin::<T>`, apply it with `apply_status`, and react to it
+//! through the trait's hooks or `StatusEvent<T>`.
+//!
+//! # Example
+//! ```ignore
+//! #[derive(Reflect, Clone)]
+//! struct Burning { damage: f32 }
+//!
+//! impl StatusEffect for Burning {}
+//!
+//! app.add_plugins(StatusEffectPlugin::<Burning>::default());
+//!
+//! commands.entity(ship).apply_status(
+//!     Status::new(Burning { damage: 5.0 }).with_duration(3.0).with_tick_interval(0.5),
+//! );
+//!
+//! fn burn(mut events: EventReader<StatusEvent<Burning>>) {
+//!     for event in events.read() {
+//!         if event.kind == StatusEventKind::Ticked {
+//!             info!("{} takes {} damage", event.entity, event.effect.damage * event.stacks as f32);
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use bevy::reflect::{GetTypeRegistration, Typed};
+use std::marker::PhantomData;
+
+use crate::editor_state::GameplaySystemSet;
+use crate::system_toggle::ToggleableSystem;
+use crate::time_control::LocalTimeScale;
+
+/// A status effect payload, e.g. `Slowed { factor: 0.5 }`.
+///
+/// The hooks run with world access when the effect is applied (including when
+/// it gains stacks), on every tick, and when it is removed or expires. They
+/// do nothing by default.
+pub trait StatusEffect:
+    Reflect + FromReflect + TypePath + Typed + GetTypeRegistration + Clone + Send + Sync + 'static
+{
+    /// Called after the effect is applied or re-applied, with the new stack count.
+    fn on_apply(&self, _entity: &mut EntityWorldMut, _stacks: u32) {}
+
+    /// Called every tick interval while the effect is active.
+    fn on_tick(&self, _entity: &mut EntityWorldMut, _stacks: u32) {}
+
+    /// Called after the effect is removed or expires.
+    fn on_remove(&self, _entity: &mut EntityWorldMut) {}
+}
+
+/// Plugin that runs durations, ticks, and events for one status effect type.
+pub struct StatusEffectPlugin<T: StatusEffect>(PhantomData<T>);
+
+impl<T: StatusEffect> Default for StatusEffectPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: StatusEffect> Plugin for StatusEffectPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Status<T>>()
+            .add_event::<StatusEvent<T>>()
+            .add_systems(
+                Update,
+                update_statuses::<T>.toggleable().in_set(GameplaySystemSet),
+            );
+    }
+}
+
+/// An active status effect on an entity.
+///
+/// Applying the same effect type again adds stacks up to `max_stacks`,
+/// replaces the payload and tick interval, and extends the remaining time to
+/// at least the new duration. If either application is permanent, the effect
+/// lasts until removed.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct Status<T: StatusEffect> {
+    /// The effect's payload.
+    pub effect: T,
+    /// Current number of stacks (at least 1).
+    pub stacks: u32,
+    /// Maximum number of stacks.
+    pub max_stacks: u32,
+    /// Seconds left before the effect expires; `None` lasts until removed.
+    pub remaining: Option<f32>,
+    /// Seconds between ticks; `None` never ticks.
+    pub tick_interval: Option<f32>,
+    /// Seconds until the next tick.
+    until_tick: f32,
+}
+
+impl<T: StatusEffect> Status<T> {
+    /// Creates a single-stack, permanent, non-ticking status.
+    pub fn new(effect: T) -> Self {
+        Self {
+            effect,
+            stacks: 1,
+            max_stacks: 1,
+            remaining: None,
+            tick_interval: None,
+            until_tick: 0.0,
+        }
+    }
+
+    /// Expires the effect after `seconds`.
+    pub fn with_duration(mut self, seconds: f32) -> Self {
+        self.remaining = Some(seconds);
+        self
+    }
+
+    /// Ticks the effect every `seconds`, starting one interval after it is applied.
+    pub fn with_tick_interval(mut self, seconds: f32) -> Self {
+        self.tick_interval = Some(seconds);
+        self.until_tick = seconds;
+        self
+    }
+
+    /// Allows the effect to stack up to `max_stacks` times.
+    pub fn with_max_stacks(mut self, max_stacks: u32) -> Self {
+        self.max_stacks = max_stacks.max(1);
+        self
+    }
+
+    /// Merges a re-application of the same effect into this one.
+    fn stack(&mut self, other: Self) {
+        self.max_stacks = other.max_stacks;
+        self.stacks = (self.stacks + other.stacks).min(self.max_stacks);
+        // A permanent effect stays permanent when a timed one is re-applied
+        self.remaining = match (self.remaining, other.remaining) {
+            (Some(current), Some(new)) => Some(current.max(new)),
+            _ => None,
+        };
+        if self.tick_interval != other.tick_interval {
+            self.tick_interval = other.tick_interval;
+            self.until_tick = other.until_tick;
+        }
+        self.effect = other.effect;
+    }
+}
+
+/// What happened to a status effect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatusEventKind {
+    /// The effect was applied or gained stacks.
+    Applied,
+    /// The effect's tick interval elapsed.
+    Ticked,
+    /// The effect was removed or expired.
+    Removed,
+}
+
+/// Sent when a status effect of type `T` is applied, ticks, or is removed.
+#[derive(Event, Clone, Debug)]
+pub struct StatusEvent<T: StatusEffect> {
+    pub entity: Entity,
+    pub kind: StatusEventKind,
+    /// Stack count at the time of the event.
+    pub stacks: u32,
+    pub effect: T,
+}
+
+/// Status effect commands on `EntityCommands`.
+pub trait StatusCommandsExt {
+    /// Applies a status effect, stacking it with an existing one of the same type.
+    fn apply_status<T: StatusEffect>(&mut self, status: Status<T>) -> &mut Self;
+
+    /// Removes a status effect, running its `on_remove` hook.
+    fn remove_status<T: StatusEffect>(&mut self) -> &mut Self;
+}
+
+impl StatusCommandsExt for EntityCommands<'_> {
+    fn apply_status<T: StatusEffect>(&mut self, status: Status<T>) -> &mut Self {
+        self.queue(move |entity: Entity, world: &mut World| apply_status(world, entity, status))
+    }
+
+    fn remove_status<T: StatusEffect>(&mut self) -> &mut Self {
+        self.queue(|entity: Entity, world: &mut World| remove_status::<T>(world, entity))
+    }
+}
+
+/// Applies a status effect to an entity, stacking it with an existing one.
+pub fn apply_status<T: StatusEffect>(world: &mut World, entity: Entity, status: Status<T>) {
+    let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+        return;
+    };
+    let (effect, stacks) = match entity_mut.get_mut::<Status<T>>() {
+        Some(mut existing) => {
+            existing.stack(status);
+            (existing.effect.clone(), existing.stacks)
+        }
+        None => {
+            let mut status = status;
+            status.stacks = status.stacks.clamp(1, status.max_stacks);
+            let applied = (status.effect.clone(), status.stacks);
+            entity_mut.insert(status);
+            applied
+        }
+    };
+
+    effect.on_apply(&mut entity_mut, stacks);
+    world.send_event(StatusEvent {
+        entity,
+        kind: StatusEventKind::Applied,
+        stacks,
+        effect,
+    });
+}
+
+/// Removes a status effect from an entity, running its `on_remove` hook.
+pub fn remove_status<T: StatusEffect>(world: &mut World, entity: Entity) {
+    let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+        return;
+    };
+    let Some(status) = entity_mut.take::<Status<T>>() else {
+        return;
+    };
+
+    status.effect.on_remove(&mut entity_mut);
+    world.send_event(StatusEvent {
+        entity,
+        kind: StatusEventKind::Removed,
+        stacks: status.stacks,
+        effect: status.effect,
+    });
+}
+
+/// Advances durations and tick timers, honoring `LocalTimeScale`.
+fn update_statuses<T: StatusEffect>(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Status<T>, Option<&LocalTimeScale>)>,
+    mut events: EventWriter<StatusEvent<T>>,
+) {
+    for (entity, mut status, time_scale) in &mut query {
+        let delta = LocalTimeScale::delta_secs(&time, time_scale);
+
+        let mut ticks = 0;
+        if let Some(interval) = status.tick_interval.filter(|interval| *interval > 0.0) {
+            status.until_tick -= delta;
+            while status.until_tick <= 0.0 {
+                status.until_tick += interval;
+                ticks += 1;
+            }
+        }
+        if ticks > 0 {
+            let effect = status.effect.clone();
+            let stacks = status.stacks;
+            for _ in 0..ticks {
+                events.send(StatusEvent {
+                    entity,
+                    kind: StatusEventKind::Ticked,
+                    stacks,
+                    effect: effect.clone(),
+                });
+            }
+            commands
+                .entity(entity)
+                .queue(move |entity: Entity, world: &mut World| {
+                    if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
+                        for _ in 0..ticks {
+                            effect.on_tick(&mut entity_mut, stacks);
+                        }
+                    }
+                });
+        }
+
+        if let Some(remaining) = &mut status.remaining {
+            *remaining -= delta;
+            if *remaining <= 0.0 {
+                commands.entity(entity).remove_status::<T>();
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Switching individual gameplay systems off at runtime.
+//!
+//! A system added with [`ToggleableSystem::toggleable`] checks the
+//! [`SystemToggles`] resource before it runs, so a misbehaving system can be
+//! isolated during play without rebuilding. The resource also lists the
+//! toggleable systems in `Update`, grouped by the named sets they are in
+//! (such as `GameplaySystemSet`), for the editor's Systems window.
+//!
+//! Bevy can't attach a run condition to a system after it is added, which is
+//! why systems opt in where they are added.
+//!
+//! # Example
+//! ```ignore
+//! app.add_systems(
+//!     Update,
+//!     (steer_ships.toggleable(), fire_weapons.toggleable())
+//!         .chain()
+//!         .in_set(GameplaySystemSet),
+//! );
+//!
+//! world
+//!     .resource_mut::<SystemToggles>()
+//!     .set_enabled("my_game::fire_weapons", false);
+//! ```
+
+use bevy::ecs::schedule::{NodeId, ScheduleGraph, SystemConfigs};
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+
+/// Plugin that keeps the list of toggleable systems up to date.
+pub struct SystemTogglePlugin;
+
+impl Plugin for SystemTogglePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SystemToggles>()
+            .add_systems(Last, list_toggleable_systems);
+    }
+}
+
+/// Set every toggleable system is in, so they can be found in the schedule.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ToggleableSystemSet;
+
+/// Lets a system be switched off at runtime.
+pub trait ToggleableSystem<Marker>: IntoSystem<(), (), Marker> + Sized {
+    /// Runs the system only while [`SystemToggles`] has it enabled (always,
+    /// without the resource).
+    fn toggleable(self) -> SystemConfigs {
+        let system = IntoSystem::into_system(self);
+        let name = system.name().to_string();
+        system
+            .in_set(ToggleableSystemSet)
+            .run_if(move |toggles: Option<Res<SystemToggles>>| {
+                toggles.is_none_or(|toggles| toggles.is_enabled(&name))
+            })
+    }
+}
+
+impl<Marker, S: IntoSystem<(), (), Marker>> ToggleableSystem<Marker> for S {}
+
+/// A toggleable system found in the `Update` schedule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ToggleableSystemInfo {
+    /// Full name of the system, e.g. `sandbox_engine::wrap::wrap_around`.
+    pub name: String,
+    /// Named sets the system is in, e.g. `GameplaySystemSet`, or "Other".
+    pub group: String,
+}
+
+/// Which toggleable systems are switched off.
+///
+/// Systems are identified by name, so every instance of a system added more
+/// than once is switched together.
+#[derive(Resource, Default, Debug)]
+pub struct SystemToggles {
+    disabled: HashSet<String>,
+    /// Sorted by group, then name.
+    systems: Vec<ToggleableSystemInfo>,
+    /// Number of systems in `Update` when `systems` was listed.
+    listed_len: usize,
+}
+
+impl SystemToggles {
+    /// Whether the system called `name` runs.
+    pub fn is_enabled(&self, name: &str) -> bool {
+        !self.disabled.contains(name)
+    }
+
+    /// Switches the system called `name` on or off.
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) {
+        if enabled {
+            self.disabled.remove(name);
+        } else {
+            self.disabled.insert(name.to_string());
+        }
+    }
+
+    /// Switches every system back on.
+    pub fn enable_all(&mut self) {
+        self.disabled.clear();
+    }
+
+    /// Number of systems switched off.
+    pub fn disabled_count(&self) -> usize {
+        self.disabled.len()
+    }
+
+    /// The toggleable systems in `Update`, sorted by group, then name. Empty
+    /// until `Update` has run once.
+    pub fn systems(&self) -> &[ToggleableSystemInfo] {
+        &self.systems
+    }
+}
+
+/// Lists the toggleable systems in `Update` whenever its system count changes.
+fn list_toggleable_systems(world: &mut World) {
+    let Some(schedule) = world.resource::<Schedules>().get(Update) else {
+        return;
+    };
+    if schedule.systems_len() == world.resource::<SystemToggles>().listed_len {
+        return;
+    }
+    let Ok(systems) = schedule.systems() else {
+        return;
+    };
+
+    let graph = schedule.graph();
+    let mut parents: HashMap<NodeId, Vec<NodeId>> = HashMap::default();
+    for (parent, child, _) in graph.hierarchy().graph().all_edges() {
+        parents.entry(child).or_default().push(parent);
+    }
+    let is_marked = |node: NodeId| {
+        parents.get(&node).is_some_and(|sets| {
+            sets.iter()
+                .any(|&set| graph.get_set_at(set).is_some_and(is_marker))
+        })
+    };
+
+    let mut listed: Vec<ToggleableSystemInfo> = systems
+        .filter(|(node, _)| is_marked(*node))
+        .map(|(node, system)| ToggleableSystemInfo {
+            name: system.name().to_string(),
+            group: group_name(graph, &parents, node),
+        })
+        .collect();
+    listed.sort_by(|a, b| a.group.cmp(&b.group).then_with(|| a.name.cmp(&b.name)));
+    listed.dedup();
+    let len = schedule.systems_len();
+
+    let mut toggles = world.resource_mut::<SystemToggles>();
+    toggles.systems = listed;
+    toggles.listed_len = len;
+}
+
+/// Names of the nearest named sets above `node`, looking through anonymous
+/// ones (such as a chained tuple).
+fn group_name(
+    graph: &ScheduleGraph,
+    parents: &HashMap<NodeId, Vec<NodeId>>,
+    node: NodeId,
+) -> String {
+    let mut names = Vec::new();
+    let mut pending: Vec<NodeId> = parents.get(&node).cloned().unwrap_or_default();
+    let mut seen: HashSet<NodeId> = HashSet::default();
+    while let Some(id) = pending.pop() {
+        if !seen.insert(id) {
+            continue;
+        }
+        let Some(set) = graph.get_set_at(id) else {
+            continue;
+        };
+        if set.is_anonymous() {
+            pending.extend(parents.get(&id).into_iter().flatten());
+        } else if set.system_type().is_none() && !is_marker(set) {
+            names.push(format!("{:?}", set));
+        }
+    }
+    if names.is_empty() {
+        return "Other".to_string();
+    }
+    names.sort();
+    names.dedup();
+    names.join(", ")
+}
+
+/// Whether `set` is [`ToggleableSystemSet`].
+fn is_marker(set: &dyn SystemSet) -> bool {
+    set == &ToggleableSystemSet as &dyn SystemSet
+}
@@ -0,0 +1,164 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Teams and the relations between them.
+//!
+//! A `Team` component puts an entity on a numbered team. How teams regard
+//! each other is project-wide: the `TeamTable` in `ProjectSettings` (saved in
+//! the project file) marks pairs of teams friendly, neutral, or hostile. A
+//! team is always friendly to itself and unlisted pairs are neutral.
+//!
+//! The engine uses the table in two places:
+//! - colliders on friendly teams don't touch, so allies pass through each
+//!   other and projectiles don't hit their own side
+//! - homing projectiles only pick hostile targets
+//!
+//! Entities without a team are neutral to everyone but are still fair game
+//! as targets. Game AI asks the same questions through
+//! [`TeamTable::can_target`] and [`TeamTable::are_allies`].
+//!
+//! # Example
+//! ```ignore
+//! // Sandbox.ron
+//! (
+//!     teams: (
+//!         names: ["Miners", "Pirates", "Traders"],
+//!         relations: { (0, 1): Hostile, (0, 2): Friendly },
+//!     ),
+//! )
+//!
+//! fn pick_target(
+//!     settings: Res<ProjectSettings>,
+//!     me: Single<&Team, With<Turret>>,
+//!     others: Query<(Entity, Option<&Team>), With<Ship>>,
+//! ) {
+//!     let target = others
+//!         .iter()
+//!         .find(|(_, team)| settings.teams.can_target(Some(*me), *team));
+//! }
+//! ```
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::inspector::InspectorOptions;
+use crate::project::init_project_settings;
+
+/// Plugin that registers `Team` and loads the project's team table.
+pub struct TeamPlugin;
+
+impl Plugin for TeamPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Team>();
+        init_project_settings(app);
+    }
+}
+
+/// The team an entity fights for.
+#[derive(Component, Reflect, Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[reflect(Component, Default)]
+pub struct Team(
+    #[reflect(@InspectorOptions::tooltip("Relations between teams are set in Window › Teams"))]
+    pub u8,
+);
+
+/// How one team regards another.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Relation {
+    /// Allies: they don't collide and never target each other.
+    Friendly,
+    /// They collide but don't seek each other out.
+    #[default]
+    Neutral,
+    /// Enemies: homing projectiles and AI pick them as targets.
+    Hostile,
+}
+
+impl Relation {
+    /// Every relation, in menu order.
+    pub const ALL: [Relation; 3] = [Relation::Friendly, Relation::Neutral, Relation::Hostile];
+
+    /// Display name.
+    pub fn label(self) -> &'static str {
+        match self {
+            Relation::Friendly => "Friendly",
+            Relation::Neutral => "Neutral",
+            Relation::Hostile => "Hostile",
+        }
+    }
+}
+
+/// Project-wide names of teams and the relations between them.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeamTable {
+    /// Display names, indexed by team number.
+    pub names: Vec<String>,
+    /// Relations between different teams, keyed with the lower team first.
+    /// Unlisted pairs are neutral.
+    pub relations: BTreeMap<(u8, u8), Relation>,
+}
+
+impl TeamTable {
+    /// Display name of `team`, falling back to "Team N".
+    pub fn name(&self, team: u8) -> String {
+        self.names
+            .get(team as usize)
+            .filter(|name| !name.is_empty())
+            .cloned()
+            .unwrap_or_else(|| format!("Team {}", team))
+    }
+
+    /// How teams `a` and `b` regard each other.
+    pub fn relation(&self, a: u8, b: u8) -> Relation {
+        if a == b {
+            return Relation::Friendly;
+        }
+        self.relations
+            .get(&(a.min(b), a.max(b)))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Sets how teams `a` and `b` regard each other. A team's relation to
+    /// itself can't be changed.
+    pub fn set_relation(&mut self, a: u8, b: u8, relation: Relation) {
+        if a == b {
+            return;
+        }
+        let key = (a.min(b), a.max(b));
+        if relation == Relation::Neutral {
+            self.relations.remove(&key);
+        } else {
+            self.relations.insert(key, relation);
+        }
+    }
+
+    /// Removes the last named team and its relations.
+    pub fn remove_last(&mut self) {
+        if self.names.pop().is_some() {
+            let team = self.names.len() as u8;
+            self.relations.retain(|&(a, b), _| a != team && b != team);
+        }
+    }
+
+    /// Whether both entities are on teams that are friendly to each other.
+    pub fn are_allies(&self, a: Option<&Team>, b: Option<&Team>) -> bool {
+        match (a, b) {
+            (Some(a), Some(b)) => self.relation(a.0, b.0) == Relation::Friendly,
+            _ => false,
+        }
+    }
+
+    /// Whether an entity on team `attacker` should pick `target`.
+    ///
+    /// Entities without a team are fair game, and so are teams hostile to
+    /// the attacker's.
+    pub fn can_target(&self, attacker: Option<&Team>, target: Option<&Team>) -> bool {
+        match (attacker, target) {
+            (Some(a), Some(b)) => self.relation(a.0, b.0) == Relation::Hostile,
+            _ => true,
+        }
+    }
+}
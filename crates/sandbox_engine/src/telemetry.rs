@@ -0,0 +1,255 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Pluggable telemetry: structured engine events for any analytics backend.
+//!
+//! A game implements [`GameTelemetry`] for its backend (a log file, an HTTP
+//! batcher, a test recorder) and inserts it as the [`Telemetry`] resource.
+//! Without one, events go nowhere. `TelemetryPlugin` records:
+//!
+//! | Event           | Fields                                          |
+//! |-----------------|-------------------------------------------------|
+//! | `session_start` | `engine_version`, `os`                          |
+//! | `session_end`   | `duration_secs`                                 |
+//! | `scene_loaded`  | `path`                                          |
+//! | `error`         | `source` (`scene` or `asset`), `path`, `message` |
+//!
+//! Paths are relative to the assets directory when they are inside it, so
+//! reports don't include the player's folders. Games record their own events
+//! through the same resource.
+//!
+//! # Example
+//! ```ignore
+//! struct LogTelemetry;
+//!
+//! impl GameTelemetry for LogTelemetry {
+//!     fn record(&self, event: &str, fields: &[(&str, TelemetryValue)]) {
+//!         info!("{} {:?}", event, fields);
+//!     }
+//! }
+//!
+//! app.insert_resource(Telemetry::new(LogTelemetry));
+//!
+//! fn sell_ore(telemetry: Res<Telemetry>) {
+//!     telemetry.record("ore_sold", &[("amount", 12.into()), ("station", "Vesta".into())]);
+//! }
+//! ```
+
+use bevy::asset::AssetLoadFailedEvent;
+use bevy::audio::AudioSource;
+use bevy::prelude::*;
+use std::path::Path;
+
+use crate::project::ProjectRoot;
+use crate::scene::{SceneLoadFailed, SceneLoaded};
+
+/// Plugin that records session, scene, and error events to the [`Telemetry`].
+pub struct TelemetryPlugin;
+
+impl Plugin for TelemetryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Telemetry>()
+            .add_event::<SceneLoaded>()
+            .add_event::<SceneLoadFailed>()
+            .add_event::<AssetLoadFailedEvent<Image>>()
+            .add_event::<AssetLoadFailedEvent<AudioSource>>()
+            .add_systems(PostStartup, record_session_start)
+            .add_systems(
+                Last,
+                (
+                    record_scene_events,
+                    record_asset_failures::<Image>,
+                    record_asset_failures::<AudioSource>,
+                    record_session_end,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// A telemetry backend. Games implement [`record`](Self::record) to send
+/// events on; it does nothing by default.
+///
+/// Events are recorded from systems that may run in parallel, so backends
+/// that buffer events need their own locking (a `Mutex` or a channel).
+pub trait GameTelemetry: Send + Sync + 'static {
+    /// Called for every event with its name and fields, in recording order.
+    fn record(&self, _event: &str, _fields: &[(&str, TelemetryValue)]) {}
+}
+
+/// The backend used when the game doesn't set one; records nothing.
+pub struct NoTelemetry;
+
+impl GameTelemetry for NoTelemetry {}
+
+/// A field value of a telemetry event.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TelemetryValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl std::fmt::Display for TelemetryValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TelemetryValue::Bool(value) => write!(f, "{}", value),
+            TelemetryValue::Int(value) => write!(f, "{}", value),
+            TelemetryValue::Float(value) => write!(f, "{}", value),
+            TelemetryValue::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<bool> for TelemetryValue {
+    fn from(value: bool) -> Self {
+        TelemetryValue::Bool(value)
+    }
+}
+
+impl From<i32> for TelemetryValue {
+    fn from(value: i32) -> Self {
+        TelemetryValue::Int(value.into())
+    }
+}
+
+impl From<i64> for TelemetryValue {
+    fn from(value: i64) -> Self {
+        TelemetryValue::Int(value)
+    }
+}
+
+impl From<u32> for TelemetryValue {
+    fn from(value: u32) -> Self {
+        TelemetryValue::Int(value.into())
+    }
+}
+
+impl From<usize> for TelemetryValue {
+    fn from(value: usize) -> Self {
+        TelemetryValue::Int(value as i64)
+    }
+}
+
+impl From<f32> for TelemetryValue {
+    fn from(value: f32) -> Self {
+        TelemetryValue::Float(value.into())
+    }
+}
+
+impl From<f64> for TelemetryValue {
+    fn from(value: f64) -> Self {
+        TelemetryValue::Float(value)
+    }
+}
+
+impl From<&str> for TelemetryValue {
+    fn from(value: &str) -> Self {
+        TelemetryValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for TelemetryValue {
+    fn from(value: String) -> Self {
+        TelemetryValue::Text(value)
+    }
+}
+
+/// The game's telemetry backend; [`NoTelemetry`] by default.
+#[derive(Resource)]
+pub struct Telemetry(Box<dyn GameTelemetry>);
+
+impl Default for Telemetry {
+    fn default() -> Self {
+        Self::new(NoTelemetry)
+    }
+}
+
+impl Telemetry {
+    /// Sends events to `backend`.
+    pub fn new(backend: impl GameTelemetry) -> Self {
+        Self(Box::new(backend))
+    }
+
+    /// Records an event with the backend.
+    pub fn record(&self, event: &str, fields: &[(&str, TelemetryValue)]) {
+        self.0.record(event, fields);
+    }
+}
+
+/// Records `session_start` once the app has started up.
+fn record_session_start(telemetry: Res<Telemetry>) {
+    telemetry.record(
+        "session_start",
+        &[
+            ("engine_version", env!("CARGO_PKG_VERSION").into()),
+            ("os", std::env::consts::OS.into()),
+        ],
+    );
+}
+
+/// Records `session_end` with the session's length when the app exits.
+fn record_session_end(
+    mut exits: EventReader<AppExit>,
+    time: Res<Time<Real>>,
+    telemetry: Res<Telemetry>,
+) {
+    if exits.read().count() == 0 {
+        return;
+    }
+    telemetry.record(
+        "session_end",
+        &[("duration_secs", time.elapsed_secs_f64().into())],
+    );
+}
+
+/// Records `scene_loaded` for loaded scenes and `error` for failed loads.
+fn record_scene_events(
+    mut loaded: EventReader<SceneLoaded>,
+    mut failed: EventReader<SceneLoadFailed>,
+    root: Option<Res<ProjectRoot>>,
+    telemetry: Res<Telemetry>,
+) {
+    let root = root.as_deref();
+    for event in loaded.read() {
+        telemetry.record("scene_loaded", &[("path", report_path(root, &event.path))]);
+    }
+    for event in failed.read() {
+        telemetry.record(
+            "error",
+            &[
+                ("source", "scene".into()),
+                ("path", report_path(root, &event.path)),
+                ("message", event.error.clone().into()),
+            ],
+        );
+    }
+}
+
+/// Records `error` for assets of type `A` that failed to load.
+fn record_asset_failures<A: Asset>(
+    mut failed: EventReader<AssetLoadFailedEvent<A>>,
+    telemetry: Res<Telemetry>,
+) {
+    for event in failed.read() {
+        telemetry.record(
+            "error",
+            &[
+                ("source", "asset".into()),
+                ("path", event.path.to_string().into()),
+                ("message", event.error.to_string().into()),
+            ],
+        );
+    }
+}
+
+/// A path as reported: relative to the assets directory if it is inside it.
+fn report_path(root: Option<&ProjectRoot>, path: &Path) -> TelemetryValue {
+    let relative = root.and_then(|root| path.strip_prefix(root.assets_dir()).ok());
+    relative
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+        .into()
+}
@@ -0,0 +1,457 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Golden image tests for rendering (`test_utils` feature).
+//!
+//! [`assert_scene_renders`] loads a scene into a windowless app with the
+//! engine's plugins, renders it once into an offscreen image through the real
+//! render pipeline, and compares the result with a committed PNG. Time is
+//! frozen, and the camera has no MSAA, tonemapping, or dithering, so the same
+//! scene renders the same pixels from run to run. Small differences between
+//! GPUs and drivers are absorbed by a per-channel tolerance and a share of
+//! pixels allowed to differ beyond it ([`GoldenTest`]).
+//!
+//! Scene paths resolve like [`load_scene`]'s, against the project root found
+//! by [`ProjectRoot::discover`], and textures load from that project's assets.
+//! Golden paths are relative to the current directory, which `cargo test`
+//! sets to the package's directory.
+//!
+//! Run with `SANDBOX_UPDATE_GOLDENS=1` to write the renders as the new
+//! goldens instead of comparing, then review and commit them. A failed
+//! comparison writes the render and a diff (mismatched pixels in red) to
+//! `sandbox_goldens/` in the system temp directory. Without a GPU adapter the
+//! render is skipped with a message and the assertion passes.
+//!
+//! # Example
+//! ```ignore
+//! // In a game's tests, with `sandbox_engine` as a dev-dependency with the
+//! // `test_utils` feature:
+//! use sandbox_engine::test_utils::{assert_scene_renders, GoldenTest};
+//!
+//! #[test]
+//! fn title_screen_renders() {
+//!     assert_scene_renders("assets/scenes/title.scn.ron", "tests/goldens/title.png");
+//! }
+//!
+//! #[test]
+//! fn asteroid_field_renders() {
+//!     GoldenTest::new()
+//!         .with_size(UVec2::new(640, 360))
+//!         .with_setup(|app| {
+//!             app.register_type::<Asteroid>();
+//!         })
+//!         .assert_renders("assets/scenes/field.scn.ron", "tests/goldens/field.png");
+//! }
+//! ```
+
+use bevy::app::TerminalCtrlCHandlerPlugin;
+use bevy::core_pipeline::tonemapping::{DebandDither, Tonemapping};
+use bevy::log::LogPlugin;
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::pipelined_rendering::PipelinedRenderingPlugin;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{
+    Extent3d, PipelineCache, TextureDimension, TextureFormat, TextureUsages,
+};
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use bevy::render::RenderApp;
+use bevy::time::TimeUpdateStrategy;
+use bevy::window::ExitCondition;
+use bevy::winit::WinitPlugin;
+use image::RgbaImage;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::project::ProjectRoot;
+use crate::scene::load_scene;
+use crate::SandboxPlugins;
+
+/// Environment variable that makes golden tests write their renders as the
+/// new goldens instead of comparing.
+pub const UPDATE_GOLDENS_ENV: &str = "SANDBOX_UPDATE_GOLDENS";
+
+/// Updates to wait for the scene's textures and shaders before rendering anyway.
+const MAX_LOAD_FRAMES: usize = 600;
+
+/// Updates in a row with nothing left loading before the render is captured.
+const SETTLED_FRAMES: usize = 3;
+
+/// Updates to wait for the rendered image to be read back.
+const MAX_CAPTURE_FRAMES: usize = 60;
+
+type Setup = Box<dyn Fn(&mut App)>;
+
+/// How a scene is rendered and how closely it must match its golden.
+pub struct GoldenTest {
+    /// Size of the render, in pixels.
+    pub size: UVec2,
+    /// World position at the center of the render.
+    pub camera_position: Vec2,
+    /// World units per pixel.
+    pub camera_scale: f32,
+    /// Color behind the scene.
+    pub clear_color: Color,
+    /// Largest difference in any color channel (0-255) that still matches.
+    pub tolerance: u8,
+    /// Share of pixels (0.0-1.0) allowed to differ by more than `tolerance`.
+    pub max_mismatched: f32,
+    setup: Vec<Setup>,
+}
+
+impl Default for GoldenTest {
+    fn default() -> Self {
+        Self {
+            size: UVec2::new(320, 180),
+            camera_position: Vec2::ZERO,
+            camera_scale: 1.0,
+            clear_color: Color::BLACK,
+            tolerance: 2,
+            max_mismatched: 0.001,
+            setup: Vec::new(),
+        }
+    }
+}
+
+/// Result of comparing a render with its golden.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageComparison {
+    /// Pixels differing by more than the tolerance in some channel.
+    pub mismatched: usize,
+    /// Pixels compared.
+    pub total: usize,
+    /// Largest channel difference found.
+    pub max_difference: u8,
+}
+
+impl ImageComparison {
+    /// Share of pixels that differ by more than the tolerance.
+    pub fn mismatched_share(&self) -> f32 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.mismatched as f32 / self.total as f32
+        }
+    }
+}
+
+impl GoldenTest {
+    /// Settings for a 320×180 render centered on the origin, one world unit
+    /// per pixel, on black.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the size of the render, in pixels.
+    pub fn with_size(mut self, size: UVec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Centers the render on `position`, showing `scale` world units per pixel.
+    pub fn with_camera(mut self, position: Vec2, scale: f32) -> Self {
+        self.camera_position = position;
+        self.camera_scale = scale;
+        self
+    }
+
+    /// Sets the per-channel tolerance and the share of pixels allowed past it.
+    pub fn with_tolerance(mut self, tolerance: u8, max_mismatched: f32) -> Self {
+        self.tolerance = tolerance;
+        self.max_mismatched = max_mismatched;
+        self
+    }
+
+    /// Adds a step run on the app before the scene loads, e.g. to add a
+    /// game's plugins or register its component types.
+    pub fn with_setup(mut self, setup: impl Fn(&mut App) + 'static) -> Self {
+        self.setup.push(Box::new(setup));
+        self
+    }
+
+    /// Renders `scene` and asserts it matches the PNG at `golden`.
+    ///
+    /// # Panics
+    /// If the scene fails to load or render, the golden is missing, or too
+    /// many pixels differ.
+    pub fn assert_renders(&self, scene: impl AsRef<Path>, golden: impl AsRef<Path>) {
+        let (scene, golden) = (scene.as_ref(), golden.as_ref());
+        if !gpu_available() {
+            eprintln!(
+                "skipping golden test for {}: no GPU adapter available",
+                scene.display()
+            );
+            return;
+        }
+        let actual = self
+            .render(scene)
+            .unwrap_or_else(|e| panic!("failed to render {}: {}", scene.display(), e));
+
+        if std::env::var_os(UPDATE_GOLDENS_ENV).is_some_and(|value| !value.is_empty()) {
+            if let Some(parent) = golden.parent() {
+                std::fs::create_dir_all(parent).expect("golden directory should be writable");
+            }
+            actual
+                .save(golden)
+                .unwrap_or_else(|e| panic!("failed to write {}: {}", golden.display(), e));
+            eprintln!("wrote golden {}", golden.display());
+            return;
+        }
+
+        let expected = match image::open(golden) {
+            Ok(expected) => expected.to_rgba8(),
+            Err(e) => panic!(
+                "failed to read golden {}: {} (run with {}=1 to create it)",
+                golden.display(),
+                e,
+                UPDATE_GOLDENS_ENV
+            ),
+        };
+        if expected.dimensions() != actual.dimensions() {
+            let (actual_path, _) = write_failure(golden, &actual, None);
+            panic!(
+                "{} rendered at {:?} but the golden {} is {:?}; render written to {}",
+                scene.display(),
+                actual.dimensions(),
+                golden.display(),
+                expected.dimensions(),
+                actual_path.display()
+            );
+        }
+
+        let comparison = compare_images(&actual, &expected, self.tolerance);
+        if comparison.mismatched_share() > self.max_mismatched {
+            let diff = diff_image(&actual, &expected, self.tolerance);
+            let (actual_path, diff_path) = write_failure(golden, &actual, Some(&diff));
+            panic!(
+                "{} doesn't match {}: {} of {} pixels differ (largest channel difference {}); \
+                 render written to {}, diff to {}",
+                scene.display(),
+                golden.display(),
+                comparison.mismatched,
+                comparison.total,
+                comparison.max_difference,
+                actual_path.display(),
+                diff_path.unwrap_or_default().display()
+            );
+        }
+    }
+
+    /// Loads `scene` into a fresh app and renders it once.
+    pub fn render(&self, scene: impl AsRef<Path>) -> Result<RgbaImage, String> {
+        let root = ProjectRoot::discover();
+        let mut app = render_app(&root);
+        for setup in &self.setup {
+            setup(&mut app);
+        }
+        app.finish();
+        app.cleanup();
+        let target = self.spawn_camera(app.world_mut());
+        app.update();
+
+        load_scene(app.world_mut(), scene.as_ref()).map_err(|e| e.to_string())?;
+        // `AssetPath`s become image handles on the next update
+        app.update();
+        // Pipelines are compiled in the background and draws wait for them,
+        // so frames rendered before then are missing sprites
+        let mut settled = 0;
+        for _ in 0..MAX_LOAD_FRAMES {
+            if render_settled(&app) {
+                settled += 1;
+                if settled == SETTLED_FRAMES {
+                    break;
+                }
+            } else {
+                settled = 0;
+            }
+            app.update();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        let captured: Arc<Mutex<Option<Image>>> = Arc::default();
+        let sink = captured.clone();
+        app.world_mut().spawn(Screenshot::image(target)).observe(
+            move |trigger: Trigger<ScreenshotCaptured>| {
+                *sink.lock().unwrap() = Some(trigger.event().0.clone());
+            },
+        );
+        for _ in 0..MAX_CAPTURE_FRAMES {
+            app.update();
+            if let Some(image) = captured.lock().unwrap().take() {
+                return image
+                    .try_into_dynamic()
+                    .map(|image| image.to_rgba8())
+                    .map_err(|e| e.to_string());
+            }
+        }
+        Err("the render was never read back".to_string())
+    }
+
+    /// Spawns the camera rendering into a new image, returning the image.
+    fn spawn_camera(&self, world: &mut World) -> Handle<Image> {
+        let mut image = Image::new_fill(
+            Extent3d {
+                width: self.size.x,
+                height: self.size.y,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[0, 0, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        image.texture_descriptor.usage = TextureUsages::TEXTURE_BINDING
+            | TextureUsages::COPY_SRC
+            | TextureUsages::COPY_DST
+            | TextureUsages::RENDER_ATTACHMENT;
+        let target = world.resource_mut::<Assets<Image>>().add(image);
+
+        world.spawn((
+            Camera2d,
+            Camera {
+                target: RenderTarget::Image(target.clone()),
+                clear_color: ClearColorConfig::Custom(self.clear_color),
+                ..default()
+            },
+            OrthographicProjection {
+                scale: self.camera_scale,
+                ..OrthographicProjection::default_2d()
+            },
+            Transform::from_translation(self.camera_position.extend(0.0)),
+            Msaa::Off,
+            Tonemapping::None,
+            DebandDither::Disabled,
+        ));
+        target
+    }
+}
+
+/// Renders `scene` and asserts it matches the PNG at `golden`, with the
+/// default [`GoldenTest`] settings.
+pub fn assert_scene_renders(scene: impl AsRef<Path>, golden: impl AsRef<Path>) {
+    GoldenTest::new().assert_renders(scene, golden);
+}
+
+/// Whether a GPU adapter (hardware or software) can be found for rendering.
+pub fn gpu_available() -> bool {
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends: wgpu::util::backend_bits_from_env().unwrap_or(wgpu::Backends::all()),
+        ..default()
+    });
+    bevy::tasks::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+        .is_some()
+}
+
+/// Compares two images of the same size pixel by pixel.
+pub fn compare_images(actual: &RgbaImage, expected: &RgbaImage, tolerance: u8) -> ImageComparison {
+    let mut comparison = ImageComparison {
+        mismatched: 0,
+        total: 0,
+        max_difference: 0,
+    };
+    for (a, b) in actual.pixels().zip(expected.pixels()) {
+        let difference = channel_difference(a, b);
+        comparison.total += 1;
+        comparison.max_difference = comparison.max_difference.max(difference);
+        if difference > tolerance {
+            comparison.mismatched += 1;
+        }
+    }
+    comparison
+}
+
+/// The expected image dimmed, with pixels past the tolerance in red.
+pub fn diff_image(actual: &RgbaImage, expected: &RgbaImage, tolerance: u8) -> RgbaImage {
+    RgbaImage::from_fn(expected.width(), expected.height(), |x, y| {
+        let b = expected.get_pixel(x, y);
+        let differs = actual
+            .get_pixel_checked(x, y)
+            .is_none_or(|a| channel_difference(a, b) > tolerance);
+        if differs {
+            image::Rgba([255, 0, 0, 255])
+        } else {
+            image::Rgba([b[0] / 4, b[1] / 4, b[2] / 4, 255])
+        }
+    })
+}
+
+/// Largest difference between two pixels in any channel.
+fn channel_difference(a: &image::Rgba<u8>, b: &image::Rgba<u8>) -> u8 {
+    a.0.iter()
+        .zip(b.0)
+        .map(|(a, b)| a.abs_diff(b))
+        .max()
+        .unwrap_or(0)
+}
+
+/// A windowless app with the engine's plugins, loading assets from `root`
+/// and with time frozen.
+fn render_app(root: &ProjectRoot) -> App {
+    let mut app = App::new();
+    app.add_plugins(
+        DefaultPlugins
+            .set(WindowPlugin {
+                primary_window: None,
+                exit_condition: ExitCondition::DontExit,
+                close_when_requested: false,
+            })
+            .set(root.asset_plugin())
+            .disable::<WinitPlugin>()
+            .disable::<LogPlugin>()
+            .disable::<TerminalCtrlCHandlerPlugin>()
+            // Keeps the render world in the app, so each update renders its own frame
+            .disable::<PipelinedRenderingPlugin>(),
+    )
+    .insert_resource(root.clone())
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO))
+    .add_plugins(SandboxPlugins);
+    #[cfg(feature = "embedded_assets")]
+    app.add_plugins(crate::embedded::EmbeddedAssetsPlugin);
+    app
+}
+
+/// Whether every sprite texture has finished loading (or failed to) and no
+/// render pipeline is still compiling.
+fn render_settled(app: &App) -> bool {
+    let compiling = app
+        .get_sub_app(RenderApp)
+        .and_then(|render_app| render_app.world().get_resource::<PipelineCache>())
+        .is_some_and(|cache| cache.waiting_pipelines().next().is_some());
+    !compiling && textures_settled(app.world())
+}
+
+/// Whether every sprite texture has finished loading, or failed to.
+fn textures_settled(world: &World) -> bool {
+    let server = world.resource::<AssetServer>();
+    world
+        .iter_entities()
+        .filter_map(|entity| entity.get::<Sprite>())
+        .all(|sprite| {
+            server
+                .get_load_state(sprite.image.id())
+                .is_none_or(|state| state.is_loaded() || state.is_failed())
+        })
+}
+
+/// Writes a failed render, and its diff if any, to the temp directory.
+fn write_failure(
+    golden: &Path,
+    actual: &RgbaImage,
+    diff: Option<&RgbaImage>,
+) -> (PathBuf, Option<PathBuf>) {
+    let dir = std::env::temp_dir().join("sandbox_goldens");
+    let _ = std::fs::create_dir_all(&dir);
+    let stem = golden
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "render".to_string());
+    let actual_path = dir.join(format!("{}.actual.png", stem));
+    let _ = actual.save(&actual_path);
+    let diff_path = diff.map(|diff| {
+        let path = dir.join(format!("{}.diff.png", stem));
+        let _ = diff.save(&path);
+        path
+    });
+    (actual_path, diff_path)
+}
@@ -0,0 +1,278 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Game speed control: pausing, slow motion, hitstop, and per-entity time scaling.
+//!
+//! `TimeControl` drives the speed of `Time<Virtual>`, which every gameplay
+//! system reads through `Res<Time>`. The effective speed is the base scale
+//! times the active slow-motion curve, or zero during a hitstop. Entities with
+//! a `LocalTimeScale` additionally run faster or slower than the rest of the
+//! world in systems that honor it (sprite animation and physics).
+//!
+//! [`TimeControl::pause`] stops the virtual clock, so everything driven by
+//! `Res<Time>` (animations, particles, sequences, physics) freezes in place,
+//! and pauses playing audio. Anything timed with `Time<Real>`, like editor
+//! UI, keeps going, and so do sounds marked [`PauseExempt`]. Entering
+//! `EditorPlayState::Paused` pauses the game this way. Pause through
+//! `TimeControl` rather than `Time<Virtual>::pause`, which it overrides.
+//!
+//! # Example
+//! ```ignore
+//! fn on_heavy_hit(mut time_control: ResMut<TimeControl>) {
+//!     time_control.hitstop(6);
+//!     time_control.slow_motion(0.3, 0.5, 0.25);
+//! }
+//! ```
+
+use bevy::prelude::*;
+
+use crate::editor_state::EditorPlayState;
+use crate::inspector::InspectorOptions;
+
+/// Plugin that applies `TimeControl` to virtual time.
+pub struct TimeControlPlugin;
+
+impl Plugin for TimeControlPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<LocalTimeScale>()
+            .init_resource::<TimeControl>()
+            .add_systems(First, apply_time_control.before(bevy::time::TimeSystem))
+            .add_systems(PostUpdate, pause_audio)
+            .add_systems(OnEnter(EditorPlayState::Paused), pause_game)
+            .add_systems(OnExit(EditorPlayState::Paused), resume_game);
+    }
+}
+
+/// A slow-motion curve: time scale keyframes over real seconds since it started.
+///
+/// The scale is linearly interpolated between keys and returns to 1 once the
+/// last key has passed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlowMotionCurve {
+    /// `(seconds, scale)` pairs sorted by time.
+    pub keys: Vec<(f32, f32)>,
+}
+
+impl SlowMotionCurve {
+    /// Creates a curve from `(seconds, scale)` keys, sorting them by time.
+    pub fn new(mut keys: Vec<(f32, f32)>) -> Self {
+        keys.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { keys }
+    }
+
+    /// Eases into `scale`, holds it, then eases back to normal speed.
+    pub fn ease(scale: f32, hold_seconds: f32, blend_seconds: f32) -> Self {
+        Self::new(vec![
+            (0.0, 1.0),
+            (blend_seconds, scale),
+            (blend_seconds + hold_seconds, scale),
+            (2.0 * blend_seconds + hold_seconds, 1.0),
+        ])
+    }
+
+    /// Total length of the curve in seconds.
+    pub fn duration(&self) -> f32 {
+        self.keys.last().map_or(0.0, |key| key.0)
+    }
+
+    /// Samples the time scale at `seconds` into the curve.
+    pub fn sample(&self, seconds: f32) -> f32 {
+        let Some(next) = self.keys.iter().position(|key| key.0 > seconds) else {
+            return self.keys.last().map_or(1.0, |key| key.1);
+        };
+        if next == 0 {
+            return self.keys[0].1;
+        }
+        let (t0, s0) = self.keys[next - 1];
+        let (t1, s1) = self.keys[next];
+        s0 + (s1 - s0) * (seconds - t0) / (t1 - t0)
+    }
+}
+
+/// Resource controlling the global game speed.
+#[derive(Resource, Debug, Clone)]
+pub struct TimeControl {
+    /// Baseline speed multiplier (1 = normal), e.g. set from a debug slider.
+    pub base_scale: f32,
+    /// Active slow-motion curve and real seconds elapsed in it.
+    curve: Option<(SlowMotionCurve, f32)>,
+    /// Frames of hitstop remaining.
+    hitstop_frames: u32,
+    /// Whether the game is paused.
+    paused: bool,
+}
+
+impl Default for TimeControl {
+    fn default() -> Self {
+        Self {
+            base_scale: 1.0,
+            curve: None,
+            hitstop_frames: 0,
+            paused: false,
+        }
+    }
+}
+
+impl TimeControl {
+    /// Plays a slow-motion curve, replacing any active one.
+    pub fn play_curve(&mut self, curve: SlowMotionCurve) {
+        self.curve = Some((curve, 0.0));
+    }
+
+    /// Eases into `scale` over `blend_seconds`, holds it for `hold_seconds`, then eases out.
+    pub fn slow_motion(&mut self, scale: f32, hold_seconds: f32, blend_seconds: f32) {
+        self.play_curve(SlowMotionCurve::ease(scale, hold_seconds, blend_seconds));
+    }
+
+    /// Freezes game time for the given number of frames, e.g. on a heavy impact.
+    ///
+    /// Overlapping hitstops do not stack; the longer one wins.
+    pub fn hitstop(&mut self, frames: u32) {
+        self.hitstop_frames = self.hitstop_frames.max(frames);
+    }
+
+    /// Cancels any slow motion and hitstop, keeping the base scale.
+    pub fn reset(&mut self) {
+        self.curve = None;
+        self.hitstop_frames = 0;
+    }
+
+    /// Stops game time and pauses audio until [`resume`](Self::resume).
+    ///
+    /// Slow motion and hitstops wait too, and carry on after resuming.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Restarts game time and the audio paused with it.
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Whether the game is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Whether a hitstop is currently freezing time.
+    pub fn in_hitstop(&self) -> bool {
+        self.hitstop_frames > 0
+    }
+
+    /// The speed applied to virtual time this frame.
+    pub fn effective_scale(&self) -> f32 {
+        if self.in_hitstop() {
+            return 0.0;
+        }
+        let curve_scale = self
+            .curve
+            .as_ref()
+            .map_or(1.0, |(curve, elapsed)| curve.sample(*elapsed));
+        (self.base_scale * curve_scale).max(0.0)
+    }
+}
+
+/// Keeps an entity's audio playing while the game is paused, e.g. pause
+/// menu music or the editor's audio preview.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct PauseExempt;
+
+/// Marks audio paused along with the game, to be resumed with it.
+#[derive(Component)]
+struct PausedWithGame;
+
+/// Per-entity time multiplier, applied on top of the global game speed.
+///
+/// Honored by sprite animation and velocity integration; game systems can use
+/// `LocalTimeScale::delta_secs` to honor it as well.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Default)]
+pub struct LocalTimeScale(
+    #[reflect(@InspectorOptions::range(0.0, 10.0).with_step(0.01).with_tooltip("1 is normal speed, 0 freezes the entity"))]
+    pub f32,
+);
+
+impl Default for LocalTimeScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl LocalTimeScale {
+    /// Returns the frame delta scaled by an entity's optional `LocalTimeScale`.
+    pub fn delta_secs(time: &Time, scale: Option<&LocalTimeScale>) -> f32 {
+        time.delta_secs() * scale.map_or(1.0, |scale| scale.0.max(0.0))
+    }
+}
+
+/// Advances slow motion and hitstop and sets the virtual clock's speed.
+///
+/// Runs before the clocks update so the speed applies to the current frame.
+fn apply_time_control(
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut control: ResMut<TimeControl>,
+) {
+    let real_delta = real_time.delta_secs();
+    let control = control.bypass_change_detection();
+    if control.paused != virtual_time.is_paused() {
+        if control.paused {
+            virtual_time.pause();
+        } else {
+            virtual_time.unpause();
+        }
+    }
+    if control.paused {
+        return;
+    }
+
+    let scale = control.effective_scale();
+    if control.hitstop_frames > 0 {
+        control.hitstop_frames -= 1;
+    } else if let Some((curve, elapsed)) = &mut control.curve {
+        *elapsed += real_delta;
+        if *elapsed >= curve.duration() {
+            control.curve = None;
+        }
+    }
+
+    if virtual_time.relative_speed() != scale {
+        virtual_time.set_relative_speed(scale);
+    }
+}
+
+/// Pauses playing audio while the game is paused, and resumes it afterwards.
+///
+/// Runs every frame, so sounds started during the pause are held too.
+fn pause_audio(
+    mut commands: Commands,
+    control: Res<TimeControl>,
+    sinks: Query<(Entity, &AudioSink, Has<PausedWithGame>), Without<PauseExempt>>,
+    spatial_sinks: Query<(Entity, &SpatialAudioSink, Has<PausedWithGame>), Without<PauseExempt>>,
+) {
+    let sinks = sinks
+        .iter()
+        .map(|(entity, sink, held)| (entity, sink as &dyn AudioSinkPlayback, held))
+        .chain(
+            spatial_sinks
+                .iter()
+                .map(|(entity, sink, held)| (entity, sink as &dyn AudioSinkPlayback, held)),
+        );
+    for (entity, sink, held) in sinks {
+        if control.paused && !held && !sink.is_paused() {
+            sink.pause();
+            commands.entity(entity).insert(PausedWithGame);
+        } else if !control.paused && held {
+            sink.play();
+            commands.entity(entity).remove::<PausedWithGame>();
+        }
+    }
+}
+
+fn pause_game(mut control: ResMut<TimeControl>) {
+    control.pause();
+}
+
+fn resume_game(mut control: ResMut<TimeControl>) {
+    control.resume();
+}
@@ -0,0 +1,364 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Day/night cycles and other global color moods.
+//!
+//! `TimeOfDay` is a clock running from hour 0 to 24 over `day_length`
+//! seconds of play. A scene with a `GlobalTint` samples its `TintGradient`
+//! at the current hour and multiplies every sprite's color, and the clear
+//! color, by the result. Sprites marked `Untinted` (lamps, effects) keep
+//! their own colors.
+//!
+//! Sprites are tinted as they are handed to the renderer, so the colors
+//! stored in the scene never change and scrubbing the time in the editor
+//! previews the mood without touching anything that gets saved. The clock
+//! goes back to where it was when play stopped.
+//!
+//! Gradients are RON files, conventionally `assets/gradients/*.gradient.ron`;
+//! a `GlobalTint` without one uses [`TintGradient::day_night`]:
+//! ```ron
+//! (
+//!     keys: [
+//!         (hour: 0.0, color: LinearRgba((red: 0.2, green: 0.25, blue: 0.5, alpha: 1.0))),
+//!         (hour: 12.0, color: LinearRgba((red: 1.0, green: 1.0, blue: 1.0, alpha: 1.0))),
+//!     ],
+//! )
+//! ```
+
+use bevy::prelude::*;
+use bevy::render::{Extract, RenderApp};
+use bevy::sprite::{ExtractedSprites, SpriteSystem};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::editor_state::{EditorPlayState, GameplaySystemSet};
+use crate::inspector::InspectorOptions;
+use crate::project::{write_keeping_header, ProjectRoot};
+use crate::scene::{SceneError, SceneResult};
+use crate::system_toggle::ToggleableSystem;
+
+/// Hours in a day.
+pub const HOURS_PER_DAY: f32 = 24.0;
+
+/// Plugin that runs the time of day and tints the world by it.
+pub struct GlobalTintPlugin;
+
+impl Plugin for GlobalTintPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<GlobalTint>()
+            .register_type::<Untinted>()
+            .register_type::<TimeOfDay>()
+            .init_resource::<TimeOfDay>()
+            .init_resource::<CurrentTint>()
+            .init_resource::<PlayStartHour>()
+            .add_systems(
+                Update,
+                advance_time_of_day.toggleable().in_set(GameplaySystemSet),
+            )
+            .add_systems(Update, update_tint.after(advance_time_of_day))
+            .add_systems(
+                OnTransition {
+                    exited: EditorPlayState::Stopped,
+                    entered: EditorPlayState::Playing,
+                },
+                remember_time_of_day,
+            )
+            .add_systems(OnEnter(EditorPlayState::Stopped), rewind_time_of_day);
+
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app.add_systems(
+                ExtractSchedule,
+                tint_extracted_sprites
+                    .after(SpriteSystem::ExtractSprites)
+                    .after(bevy::text::extract_text2d_sprite),
+            );
+        }
+    }
+}
+
+/// The world's clock.
+#[derive(Resource, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct TimeOfDay {
+    /// Current hour, from 0 up to 24.
+    #[reflect(@InspectorOptions::range(0.0, 24.0))]
+    pub hour: f32,
+    /// Seconds of play a whole day takes.
+    #[reflect(@InspectorOptions::min(0.0).with_tooltip("Seconds"))]
+    pub day_length: f32,
+    /// Whether the clock runs during play.
+    pub running: bool,
+}
+
+impl Default for TimeOfDay {
+    fn default() -> Self {
+        Self {
+            hour: 12.0,
+            day_length: 600.0,
+            running: true,
+        }
+    }
+}
+
+impl TimeOfDay {
+    /// A clock at `hour` completing a day every `day_length` seconds.
+    pub fn new(hour: f32, day_length: f32) -> Self {
+        Self {
+            hour: hour.rem_euclid(HOURS_PER_DAY),
+            day_length,
+            ..default()
+        }
+    }
+
+    /// Moves the clock on by `seconds` of play, wrapping past midnight.
+    pub fn advance(&mut self, seconds: f32) {
+        if self.day_length > 0.0 {
+            let hours = seconds / self.day_length * HOURS_PER_DAY;
+            self.hour = (self.hour + hours).rem_euclid(HOURS_PER_DAY);
+        }
+    }
+}
+
+/// A color for each hour of the day, blended between keys.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TintGradient {
+    /// Colors at given hours; the order doesn't matter.
+    pub keys: Vec<TintKey>,
+}
+
+/// The tint at one hour of the day.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TintKey {
+    /// Hour, from 0 up to 24.
+    pub hour: f32,
+    /// Color sprites are multiplied by at that hour.
+    pub color: Color,
+}
+
+impl TintGradient {
+    /// A night, dawn, day, and dusk cycle.
+    pub fn day_night() -> Self {
+        let key = |hour, red, green, blue| TintKey {
+            hour,
+            color: Color::srgb(red, green, blue),
+        };
+        Self {
+            keys: vec![
+                key(0.0, 0.25, 0.3, 0.55),
+                key(5.0, 0.35, 0.35, 0.6),
+                key(7.0, 1.0, 0.78, 0.62),
+                key(10.0, 1.0, 1.0, 1.0),
+                key(17.0, 1.0, 1.0, 1.0),
+                key(19.5, 1.0, 0.65, 0.5),
+                key(21.5, 0.35, 0.38, 0.65),
+            ],
+        }
+    }
+
+    /// Reads a gradient from a RON file.
+    pub fn load(path: &Path) -> SceneResult<Self> {
+        let data = std::fs::read_to_string(path).map_err(SceneError::FileRead)?;
+        bevy::scene::ron::from_str(&data).map_err(|e| SceneError::Deserialization(e.to_string()))
+    }
+
+    /// Writes the gradient to a RON file, keeping the file's header comments.
+    pub fn save(&self, path: &Path) -> SceneResult<()> {
+        let data = bevy::scene::ron::ser::to_string_pretty(self, Default::default())
+            .map_err(|e| SceneError::Serialization(e.to_string()))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(SceneError::Io)?;
+        }
+        write_keeping_header(path, &data).map_err(SceneError::Io)
+    }
+
+    /// The color at `hour`, blended in linear space between the keys around
+    /// it and wrapping past midnight. White when there are no keys.
+    pub fn sample(&self, hour: f32) -> Color {
+        let hour = hour.rem_euclid(HOURS_PER_DAY);
+        let mut keys: Vec<TintKey> = self.keys.clone();
+        keys.sort_by(|a, b| a.hour.total_cmp(&b.hour));
+        let (Some(first), Some(last)) = (keys.first(), keys.last()) else {
+            return Color::WHITE;
+        };
+        // The keys either side, treating the last key as also coming before the first
+        let after = keys.iter().position(|key| key.hour > hour);
+        let (from, to) = match after {
+            Some(0) | None => (*last, *first),
+            Some(index) => (keys[index - 1], keys[index]),
+        };
+        let span = (to.hour - from.hour).rem_euclid(HOURS_PER_DAY);
+        let offset = (hour - from.hour).rem_euclid(HOURS_PER_DAY);
+        let factor = if span > 0.0 { offset / span } else { 0.0 };
+        let from = from.color.to_linear();
+        let to = to.color.to_linear();
+        Color::from(from.mix(&to, factor))
+    }
+}
+
+/// Tints the world by the time of day. One per scene.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct GlobalTint {
+    /// Gradient file, relative to the assets directory; empty uses the
+    /// built-in day/night cycle.
+    pub gradient: String,
+    /// Whether the clear color is tinted too.
+    pub tint_background: bool,
+}
+
+impl Default for GlobalTint {
+    fn default() -> Self {
+        Self {
+            gradient: String::new(),
+            tint_background: true,
+        }
+    }
+}
+
+impl GlobalTint {
+    /// Tints by the gradient in `path`.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            gradient: path.into(),
+            ..default()
+        }
+    }
+}
+
+/// Keeps a sprite's own colors under a `GlobalTint`.
+#[derive(Component, Reflect, Default, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component, Default)]
+pub struct Untinted;
+
+/// The tint applied this frame and the gradient it comes from.
+#[derive(Resource, Clone, Debug)]
+pub struct CurrentTint {
+    /// Color sprites are multiplied by; white without a `GlobalTint`.
+    pub color: Color,
+    /// Gradient file the `gradient` was loaded from.
+    pub path: String,
+    /// The scene's gradient. Replacing it (with `path` unchanged) previews
+    /// edits before they are saved.
+    pub gradient: TintGradient,
+    /// Clear color before tinting, and the tinted color last written over it.
+    background: Option<(Color, Color)>,
+}
+
+impl Default for CurrentTint {
+    fn default() -> Self {
+        Self {
+            color: Color::WHITE,
+            path: String::new(),
+            gradient: TintGradient::day_night(),
+            background: None,
+        }
+    }
+}
+
+/// Multiplies two colors channel by channel, keeping `base`'s alpha.
+pub fn multiply_tint(base: LinearRgba, tint: LinearRgba) -> LinearRgba {
+    LinearRgba::new(
+        base.red * tint.red,
+        base.green * tint.green,
+        base.blue * tint.blue,
+        base.alpha,
+    )
+}
+
+fn advance_time_of_day(time: Res<Time>, mut clock: ResMut<TimeOfDay>) {
+    if clock.running {
+        clock.advance(time.delta_secs());
+    }
+}
+
+/// Hour when play started, restored on Stop.
+#[derive(Resource, Default)]
+struct PlayStartHour(Option<f32>);
+
+fn remember_time_of_day(clock: Res<TimeOfDay>, mut start: ResMut<PlayStartHour>) {
+    start.0 = Some(clock.hour);
+}
+
+fn rewind_time_of_day(mut clock: ResMut<TimeOfDay>, mut start: ResMut<PlayStartHour>) {
+    if let Some(hour) = start.0.take() {
+        clock.hour = hour;
+    }
+}
+
+/// Samples the scene's gradient, loading it when its path changes, and
+/// tints the clear color.
+fn update_tint(
+    tints: Query<&GlobalTint>,
+    clock: Res<TimeOfDay>,
+    root: Option<Res<ProjectRoot>>,
+    clear_color: Option<ResMut<ClearColor>>,
+    mut current: ResMut<CurrentTint>,
+) {
+    let tint = tints.iter().next();
+    if let Some(tint) = tint {
+        if tint.gradient != current.path {
+            current.path = tint.gradient.clone();
+            current.gradient = if tint.gradient.is_empty() {
+                TintGradient::day_night()
+            } else {
+                let path = match &root {
+                    Some(root) => root.resolve_asset(&tint.gradient),
+                    None => tint.gradient.clone().into(),
+                };
+                TintGradient::load(&path).unwrap_or_else(|e| {
+                    warn!("Failed to load tint gradient {}: {}", tint.gradient, e);
+                    TintGradient::default()
+                })
+            };
+        }
+    }
+    let color = match tint {
+        Some(_) => current.gradient.sample(clock.hour),
+        None => Color::WHITE,
+    };
+    if current.color != color {
+        current.color = color;
+    }
+
+    let Some(mut clear_color) = clear_color else {
+        return;
+    };
+    // Something else changed the clear color since it was tinted
+    let base = match current.background {
+        Some((base, applied)) if applied == clear_color.0 => base,
+        _ => clear_color.0,
+    };
+    let tinted = match tint {
+        Some(tint) if tint.tint_background => {
+            Color::from(multiply_tint(base.to_linear(), color.to_linear()))
+        }
+        _ => base,
+    };
+    if clear_color.0 != tinted {
+        clear_color.0 = tinted;
+    }
+    current.background = Some((base, tinted));
+}
+
+/// Multiplies the colors of sprites (and 2D text) sent to the renderer by
+/// the current tint.
+fn tint_extracted_sprites(
+    current: Extract<Option<Res<CurrentTint>>>,
+    untinted: Extract<Query<(), With<Untinted>>>,
+    mut sprites: ResMut<ExtractedSprites>,
+) {
+    let Some(current) = current.as_ref() else {
+        return;
+    };
+    if current.color == Color::WHITE {
+        return;
+    }
+    let tint = current.color.to_linear();
+    for ((_, main_entity), sprite) in sprites.sprites.iter_mut() {
+        let entity = sprite.original_entity.unwrap_or(main_entity.id());
+        if !untinted.contains(entity) {
+            sprite.color = multiply_tint(sprite.color, tint);
+        }
+    }
+}
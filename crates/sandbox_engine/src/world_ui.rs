@@ -0,0 +1,172 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! UI nodes that follow world entities on screen.
+//!
+//! Add `WorldAnchoredUi` to an absolutely positioned UI node to keep it over a
+//! world entity, e.g. for nameplates, health bars, and interaction prompts.
+//!
+//! # Example
+//! ```ignore
+//! commands.spawn((
+//!     Text::new("Player"),
+//!     WorldAnchoredUi::new(ship).with_offset(Vec2::new(0.0, 40.0)),
+//! ));
+//! ```
+
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+/// Plugin that positions `WorldAnchoredUi` nodes each frame.
+pub struct WorldUiPlugin;
+
+impl Plugin for WorldUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            position_world_anchored_ui.before(UiSystem::Layout),
+        );
+    }
+}
+
+/// Keeps a UI node positioned over a world entity.
+///
+/// The node is made absolutely positioned and its `left`/`top` are overwritten
+/// every frame, so it should be a root node rather than nested in a layout.
+/// When the target is despawned, the node is despawned too.
+#[derive(Component, Clone, Debug)]
+#[require(Node(absolute_node))]
+pub struct WorldAnchoredUi {
+    /// World entity to follow.
+    pub target: Entity,
+    /// Offset from the target's position, in world units.
+    pub offset: Vec2,
+    /// Point of the node placed at the anchor, as a fraction of its size.
+    /// `(0.5, 1.0)` puts the node's bottom center on the anchor.
+    pub pivot: Vec2,
+    /// Keep the node inside the screen when the target leaves it, instead of hiding it.
+    pub clamp_to_screen: bool,
+    /// Distance from the screen edges when clamping, in logical pixels.
+    pub margin: f32,
+    /// Camera to project through; defaults to the first active 2D camera.
+    pub camera: Option<Entity>,
+}
+
+impl WorldAnchoredUi {
+    /// Anchors to `target` with the node's bottom center on the target's origin.
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            offset: Vec2::ZERO,
+            pivot: Vec2::new(0.5, 1.0),
+            clamp_to_screen: false,
+            margin: 8.0,
+            camera: None,
+        }
+    }
+
+    /// Sets the world-space offset from the target.
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the pivot, as a fraction of the node's size.
+    pub fn with_pivot(mut self, pivot: Vec2) -> Self {
+        self.pivot = pivot;
+        self
+    }
+
+    /// Clamps the node to the screen edges instead of hiding it off-screen.
+    pub fn clamped(mut self, margin: f32) -> Self {
+        self.clamp_to_screen = true;
+        self.margin = margin;
+        self
+    }
+}
+
+fn absolute_node() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        ..default()
+    }
+}
+
+/// Query data for nodes positioned by `position_world_anchored_ui`.
+type AnchoredNode<'a> = (
+    Entity,
+    &'a WorldAnchoredUi,
+    &'a mut Node,
+    Option<&'a ComputedNode>,
+    Option<&'a mut Visibility>,
+);
+
+/// Projects each anchor into its camera's viewport and moves the node there.
+///
+/// Uses last frame's layout size for the pivot, and hides nodes whose target
+/// is off-screen unless they clamp.
+fn position_world_anchored_ui(
+    mut commands: Commands,
+    cameras: Query<(Entity, &Camera, &GlobalTransform), With<Camera2d>>,
+    targets: Query<&GlobalTransform>,
+    mut nodes: Query<AnchoredNode>,
+) {
+    let default_camera = cameras
+        .iter()
+        .filter(|(_, camera, _)| camera.is_active)
+        .min_by_key(|(_, camera, _)| camera.order)
+        .map(|(entity, _, _)| entity);
+
+    for (entity, anchor, mut node, computed, visibility) in &mut nodes {
+        let Ok(target) = targets.get(anchor.target) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+        let Some((_, camera, camera_transform)) = anchor
+            .camera
+            .or(default_camera)
+            .and_then(|camera| cameras.get(camera).ok())
+        else {
+            continue;
+        };
+        let Some(viewport) = camera.logical_viewport_rect() else {
+            continue;
+        };
+
+        let world_position = target.translation() + anchor.offset.extend(0.0);
+        let Ok(screen) = camera.world_to_viewport(camera_transform, world_position) else {
+            continue;
+        };
+
+        let size = computed
+            .map(|computed| computed.size() * computed.inverse_scale_factor())
+            .unwrap_or_default();
+        let mut top_left = viewport.min + screen - size * anchor.pivot;
+
+        let on_screen = viewport.contains(viewport.min + screen);
+        if anchor.clamp_to_screen {
+            let min = viewport.min + Vec2::splat(anchor.margin);
+            let max = (viewport.max - size - Vec2::splat(anchor.margin)).max(min);
+            top_left = top_left.clamp(min, max);
+        }
+
+        if let Some(mut visibility) = visibility {
+            let wanted = if on_screen || anchor.clamp_to_screen {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+            visibility.set_if_neq(wanted);
+        }
+
+        if node.position_type != PositionType::Absolute {
+            node.position_type = PositionType::Absolute;
+        }
+        let left = Val::Px(top_left.x);
+        let top = Val::Px(top_left.y);
+        if node.left != left || node.top != top {
+            node.left = left;
+            node.top = top;
+        }
+    }
+}
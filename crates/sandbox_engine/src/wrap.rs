@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Screen-edge world wrapping, as in Asteroids.
+//!
+//! An entity with `WrapAround` that leaves its bounds reappears at the
+//! opposite edge, keeping its velocity and the distance it overshot.
+//!
+//! # Example
+//! ```ignore
+//! commands.spawn((
+//!     WrapAround::new(Rect::from_center_size(Vec2::ZERO, Vec2::new(1600.0, 900.0))),
+//!     Transform::default(),
+//! ));
+//! ```
+
+use bevy::prelude::*;
+
+use crate::editor_state::GameplaySystemSet;
+use crate::system_toggle::ToggleableSystem;
+
+/// Plugin that wraps `WrapAround` entities back into their bounds.
+pub struct WrapAroundPlugin;
+
+impl Plugin for WrapAroundPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<WrapAround>()
+            .add_systems(Update, wrap_around.toggleable().in_set(GameplaySystemSet));
+    }
+}
+
+/// Teleports the entity to the opposite side when it leaves `bounds`.
+///
+/// Bounds are in world space and apply to the entity's translation, so the
+/// entity should not have a parent.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component, Default)]
+#[require(Transform)]
+pub struct WrapAround {
+    /// Region the entity stays within.
+    pub bounds: Rect,
+}
+
+impl Default for WrapAround {
+    fn default() -> Self {
+        Self::new(Rect::from_center_size(Vec2::ZERO, Vec2::new(1600.0, 900.0)))
+    }
+}
+
+impl WrapAround {
+    /// Wraps within `bounds`.
+    pub fn new(bounds: Rect) -> Self {
+        Self { bounds }
+    }
+
+    /// Where `position` ends up after wrapping; positions inside are unchanged.
+    pub fn wrap(&self, position: Vec2) -> Vec2 {
+        Vec2::new(
+            wrap_axis(position.x, self.bounds.min.x, self.bounds.max.x),
+            wrap_axis(position.y, self.bounds.min.y, self.bounds.max.y),
+        )
+    }
+}
+
+/// Wraps `value` into `min..=max`, carrying over how far it went past the edge.
+fn wrap_axis(value: f32, min: f32, max: f32) -> f32 {
+    let size = max - min;
+    if size <= 0.0 || (min..=max).contains(&value) {
+        return value;
+    }
+    min + (value - min).rem_euclid(size)
+}
+
+fn wrap_around(mut query: Query<(&WrapAround, &mut Transform)>) {
+    for (wrap, mut transform) in &mut query {
+        let position = transform.translation.truncate();
+        let wrapped = wrap.wrap(position);
+        // Compare first so unmoved entities aren't marked changed
+        if wrapped != position {
+            transform.translation.x = wrapped.x;
+            transform.translation.y = wrapped.y;
+        }
+    }
+}
@@ -0,0 +1,191 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for ambient sounds and presets.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use sandbox_engine::ambient::{
+    AmbientPreset, AmbientSound, AmbientSoundPlugin, AudioZone, AudioZoneVoice,
+};
+use sandbox_engine::editor_state::{EditorPlayState, EditorStatePlugin};
+use sandbox_engine::particles::ParticleEmitter;
+use std::time::Duration;
+
+#[test]
+fn ambient_sounds_fade_out_past_their_radius() {
+    let sound = AmbientSound {
+        volume: 0.8,
+        radius: 100.0,
+        falloff: 50.0,
+        ..AmbientSound::new("audio/hum.ogg")
+    };
+    assert_eq!(sound.volume_at(0.0), 0.8);
+    assert_eq!(sound.volume_at(100.0), 0.8);
+    assert!((sound.volume_at(125.0) - 0.4).abs() < 1e-5);
+    assert_eq!(sound.volume_at(150.0), 0.0);
+    assert_eq!(sound.volume_at(1000.0), 0.0);
+
+    // Without a falloff the sound cuts off at the radius
+    let sharp = AmbientSound {
+        falloff: 0.0,
+        ..sound
+    };
+    assert_eq!(sharp.volume_at(100.0), 0.8);
+    assert_eq!(sharp.volume_at(100.5), 0.0);
+}
+
+#[test]
+fn presets_spawn_named_effects() {
+    let mut world = World::new();
+    for &preset in AmbientPreset::ALL {
+        let entity = preset.spawn(&mut world, Vec3::new(10.0, 20.0, 1.0));
+        let entity = world.entity(entity);
+        assert_eq!(entity.get::<Name>().unwrap().as_str(), preset.label());
+        assert_eq!(
+            entity.get::<Transform>().unwrap().translation,
+            Vec3::new(10.0, 20.0, 1.0)
+        );
+        assert_eq!(
+            entity.get::<ParticleEmitter>().cloned(),
+            preset.emitter(),
+            "{:?}",
+            preset
+        );
+        assert_eq!(
+            entity.get::<AmbientSound>().cloned(),
+            preset.sound(),
+            "{:?}",
+            preset
+        );
+        assert!(preset.emitter().is_some() || preset.sound().is_some());
+    }
+}
+
+fn zone_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        bevy::state::app::StatesPlugin,
+        EditorStatePlugin,
+        AmbientSoundPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )));
+    app.world_mut().spawn((Camera2d, Transform::default()));
+    // The first frame has no delta
+    app.update();
+    app
+}
+
+fn set_play_state(app: &mut App, state: EditorPlayState) {
+    app.world_mut()
+        .resource_mut::<NextState<EditorPlayState>>()
+        .set(state);
+}
+
+fn zone_levels(app: &mut App) -> Vec<(Entity, f32)> {
+    let mut levels: Vec<(Entity, f32)> = app
+        .world_mut()
+        .query::<&AudioZoneVoice>()
+        .iter(app.world())
+        .map(|voice| (voice.zone, voice.level))
+        .collect();
+    levels.sort_by_key(|(zone, _)| *zone);
+    levels
+}
+
+fn move_listener(app: &mut App, x: f32) {
+    let mut cameras = app
+        .world_mut()
+        .query_filtered::<&mut Transform, With<Camera2d>>();
+    cameras.single_mut(app.world_mut()).translation.x = x;
+    // Zones hear the listener's GlobalTransform, updated after this frame
+    app.update();
+}
+
+#[test]
+fn audio_zones_crossfade_music_and_layer_ambience() {
+    let mut app = zone_app();
+    let world = app.world_mut();
+    let town = world
+        .spawn(AudioZone {
+            fade_in: 0.5,
+            fade_out: 1.0,
+            ..AudioZone::music("audio/town.ogg", Vec2::new(1000.0, 1000.0))
+        })
+        .id();
+    let tavern = world
+        .spawn((
+            AudioZone {
+                fade_in: 0.5,
+                fade_out: 0.5,
+                ..AudioZone::music("audio/tavern.ogg", Vec2::new(100.0, 100.0))
+            },
+            Transform::from_xyz(500.0, 0.0, 0.0),
+        ))
+        .id();
+    let fire = world
+        .spawn((
+            AudioZone {
+                fade_in: 0.0,
+                fade_out: 0.0,
+                ..AudioZone::ambience("audio/fire.ogg", Vec2::new(50.0, 50.0))
+            },
+            Transform::from_xyz(500.0, 0.0, 0.0),
+        ))
+        .id();
+
+    // Place the zones before playing
+    app.update();
+
+    // Entering the town fades its music in
+    set_play_state(&mut app, EditorPlayState::Playing);
+    app.update();
+    assert_eq!(zone_levels(&mut app), vec![(town, 0.5)]);
+    app.update();
+    app.update();
+    assert_eq!(zone_levels(&mut app), vec![(town, 1.0)]);
+
+    // Inside the tavern, its music takes over and the fire crackles at once
+    move_listener(&mut app, 500.0);
+    app.update();
+    assert_eq!(
+        zone_levels(&mut app),
+        vec![(town, 0.75), (tavern, 0.5), (fire, 1.0)]
+    );
+    app.update();
+    app.update();
+    app.update();
+    assert_eq!(zone_levels(&mut app), vec![(tavern, 1.0), (fire, 1.0)]);
+
+    // Leaving everything fades out what's left
+    move_listener(&mut app, 5000.0);
+    app.update();
+    assert_eq!(zone_levels(&mut app), vec![(tavern, 0.5)]);
+    app.update();
+    assert!(zone_levels(&mut app).is_empty());
+}
+
+#[test]
+fn audio_zones_fall_silent_when_play_stops() {
+    let mut app = zone_app();
+    let zone = app
+        .world_mut()
+        .spawn(AudioZone::ambience("audio/wind.ogg", Vec2::splat(100.0)))
+        .id();
+    set_play_state(&mut app, EditorPlayState::Playing);
+    app.update();
+    assert_eq!(zone_levels(&mut app).len(), 1);
+    assert!(AudioZone::ambience("", Vec2::splat(100.0)).contains(
+        &GlobalTransform::from_xyz(50.0, 0.0, 0.0),
+        Vec2::new(140.0, -90.0)
+    ));
+
+    set_play_state(&mut app, EditorPlayState::Stopped);
+    app.update();
+    assert!(zone_levels(&mut app).is_empty());
+    assert!(app.world().get::<AudioZone>(zone).is_some());
+}
@@ -0,0 +1,138 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for reading BMFont descriptors and drawing `BitmapText`.
+
+mod common;
+
+use bevy::prelude::*;
+use sandbox_engine::bitmap_font::{
+    BitmapFont, BitmapFontError, BitmapFontPlugin, BitmapGlyph, BitmapText, BitmapTextAlign,
+};
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::SceneExcluded;
+
+const RETRO: &str = r#"info face="Retro Mono" size=8 bold=0 italic=0 charset="" unicode=1 padding=0,0,0,0 spacing=1,1
+common lineHeight=10 base=8 scaleW=64 scaleH=64 pages=1 packed=0
+page id=0 file="retro_0.png"
+chars count=4
+char id=32   x=0  y=0 width=0 height=0 xoffset=0 yoffset=0 xadvance=4 page=0 chnl=15
+char id=65   x=0  y=0 width=6 height=8 xoffset=0 yoffset=1 xadvance=7 page=0 chnl=15
+char id=86   x=8  y=0 width=6 height=8 xoffset=0 yoffset=1 xadvance=7 page=0 chnl=15
+char id=63   x=16 y=0 width=5 height=8 xoffset=1 yoffset=1 xadvance=7 page=0 chnl=15
+kernings count=1
+kerning first=65 second=86 amount=-2
+"#;
+
+fn retro() -> BitmapFont {
+    BitmapFont::parse(RETRO).unwrap()
+}
+
+/// Updates until the font has loaded in the background and glyphs are drawn.
+fn wait_for_glyphs(app: &mut App) {
+    for _ in 0..200 {
+        app.update();
+        let mut query = app.world_mut().query::<&BitmapGlyph>();
+        if query.iter(app.world()).next().is_some() {
+            return;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+    panic!("the font never loaded");
+}
+
+#[test]
+fn descriptors_are_parsed_into_glyphs_pages_and_kerning() {
+    let font = retro();
+    assert_eq!(font.face, "Retro Mono");
+    assert_eq!((font.line_height, font.base), (10.0, 8.0));
+    assert_eq!(font.pages, vec!["retro_0.png".to_string()]);
+    let v = font.glyphs[&'V'];
+    assert_eq!(v.rect, Rect::new(8.0, 0.0, 14.0, 8.0));
+    assert_eq!((v.offset, v.advance, v.page), (Vec2::new(0.0, 1.0), 7.0, 0));
+    assert_eq!(font.kerning[&('A', 'V')], -2.0);
+
+    assert!(matches!(
+        BitmapFont::parse("common lineHeight=10 base=8"),
+        Err(BitmapFontError::Invalid(_))
+    ));
+    assert!(BitmapFont::parse("char id=65 x=zero").is_err());
+}
+
+#[test]
+fn layout_applies_kerning_spacing_lines_and_fallbacks() {
+    let font = retro();
+    let layout = font.layout("AV A\nx", 1.0);
+    let placed: Vec<(char, Vec2, usize)> = layout
+        .glyphs
+        .iter()
+        .map(|glyph| (glyph.character, glyph.position, glyph.line))
+        .collect();
+    // The space has no quad, and the unknown `x` falls back to `?`
+    assert_eq!(
+        placed,
+        vec![
+            ('A', Vec2::new(0.0, 1.0), 0),
+            ('V', Vec2::new(6.0, 1.0), 0),
+            ('A', Vec2::new(19.0, 1.0), 0),
+            ('x', Vec2::new(1.0, 11.0), 1),
+        ]
+    );
+    assert_eq!(layout.line_widths, vec![26.0, 7.0]);
+    assert_eq!(layout.height, 20.0);
+}
+
+#[test]
+fn bitmap_text_spawns_unsaved_glyph_sprites_and_rebuilds_on_change() {
+    let project = common::temp_dir("project");
+    std::fs::create_dir_all(project.join("assets/fonts")).unwrap();
+    std::fs::write(project.join("assets/fonts/retro.fnt"), RETRO).unwrap();
+
+    let root = ProjectRoot::new(&project);
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, root.asset_plugin()))
+        .init_asset::<Image>()
+        .add_plugins(BitmapFontPlugin);
+    let text = app
+        .world_mut()
+        .spawn(
+            BitmapText::new("fonts/retro.fnt", "AV")
+                .with_scale(2.0)
+                .with_align(BitmapTextAlign::Left),
+        )
+        .id();
+    let glyphs = |app: &mut App| {
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&BitmapGlyph, &Parent, &Sprite, &Transform), With<SceneExcluded>>();
+        let mut glyphs: Vec<(char, Vec3)> = query
+            .iter(app.world())
+            .inspect(|(_, parent, sprite, _)| {
+                assert_eq!(parent.get(), text);
+                assert_eq!(sprite.custom_size, Some(Vec2::new(12.0, 16.0)));
+            })
+            .map(|(glyph, _, _, transform)| (glyph.character, transform.translation))
+            .collect();
+        glyphs.sort_by(|a, b| a.1.x.total_cmp(&b.1.x));
+        glyphs
+    };
+    // Nothing is drawn until the font has loaded
+    app.update();
+    assert!(glyphs(&mut app).is_empty());
+    wait_for_glyphs(&mut app);
+
+    // Scaled up, centered vertically on the text, and never saved
+    assert_eq!(
+        glyphs(&mut app),
+        vec![
+            ('A', Vec3::new(6.0, 0.0, 0.0)),
+            ('V', Vec3::new(16.0, 0.0, 0.0))
+        ]
+    );
+
+    app.world_mut().get_mut::<BitmapText>(text).unwrap().text = "V".to_string();
+    app.update();
+    assert_eq!(glyphs(&mut app), vec![('V', Vec3::new(6.0, 0.0, 0.0))]);
+
+    std::fs::remove_dir_all(&project).ok();
+}
@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for color grading by entity group.
+
+mod common;
+
+use bevy::prelude::*;
+use sandbox_engine::color_grade::{ColorGrade, ColorGradePlugin, ColorGrades, ColorGroup};
+use sandbox_engine::project::{ProjectRoot, ProjectSettings};
+
+fn assert_color(actual: LinearRgba, expected: LinearRgba) {
+    assert!(
+        actual.to_vec4().distance(expected.to_vec4()) < 1e-4,
+        "expected {:?}, got {:?}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn grades_tint_and_brighten_colors_but_keep_alpha() {
+    let grade = ColorGrade {
+        tint: Color::linear_rgb(1.0, 0.5, 0.0),
+        brightness: 2.0,
+    };
+    assert_color(
+        grade.apply(LinearRgba::new(0.25, 0.5, 1.0, 0.5)),
+        LinearRgba::new(0.5, 0.5, 0.0, 0.5),
+    );
+    assert!(!grade.is_identity());
+
+    let identity = ColorGrade::default();
+    assert!(identity.is_identity());
+    assert_color(
+        identity.apply(LinearRgba::new(0.2, 0.4, 0.6, 1.0)),
+        LinearRgba::new(0.2, 0.4, 0.6, 1.0),
+    );
+}
+
+#[test]
+fn grades_are_saved_in_the_project_file_and_loaded_by_the_plugin() {
+    let dir = common::temp_dir("project");
+    let root = ProjectRoot::new(&dir);
+    let mut grades = ColorGrades::default();
+    grades.groups.insert(
+        "background".to_string(),
+        ColorGrade {
+            tint: Color::srgb(0.6, 0.7, 1.0),
+            brightness: 0.8,
+        },
+    );
+    ProjectSettings {
+        color_grades: grades.clone(),
+        ..default()
+    }
+    .save(&root)
+    .unwrap();
+
+    let mut app = App::new();
+    app.insert_resource(root).add_plugins(ColorGradePlugin);
+    let settings = app.world().resource::<ProjectSettings>();
+    assert_eq!(settings.color_grades, grades);
+    assert!(settings.color_grades.grade("hazards").is_none());
+    assert!(app
+        .world()
+        .resource::<AppTypeRegistry>()
+        .read()
+        .get(std::any::TypeId::of::<ColorGroup>())
+        .is_some());
+    std::fs::remove_dir_all(&dir).ok();
+}
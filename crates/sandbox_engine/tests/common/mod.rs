@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Helpers shared by the scene tests.
+
+// Each test binary compiles its own copy and uses only some of the helpers
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use sandbox_engine::scene::ScenePlugin;
+use std::path::PathBuf;
+
+/// App with the scene plugin and the `Transform` and `Name` types scene
+/// tests spawn.
+pub fn build_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(ScenePlugin)
+        .register_type::<Transform>()
+        .register_type::<Name>();
+    app
+}
+
+/// A RON scene file in the temp directory, unique to the test binary, the
+/// process, and `name`.
+pub fn temp_scene_path(name: &str) -> PathBuf {
+    temp_path(name, "scn.ron")
+}
+
+/// Like [`temp_scene_path`], with the given extension.
+pub fn temp_path(name: &str, extension: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("{}.{}", temp_name(name), extension))
+}
+
+/// An empty directory in the temp directory, unique like [`temp_scene_path`],
+/// for tests that need a project or assets directory.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(temp_name(name));
+    std::fs::remove_dir_all(&dir).ok();
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn temp_name(name: &str) -> String {
+    format!(
+        "sandbox_{}_{}_{}",
+        env!("CARGO_CRATE_NAME"),
+        name,
+        std::process::id()
+    )
+}
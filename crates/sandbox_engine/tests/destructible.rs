@@ -0,0 +1,127 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for destructible stages, debris, and destruction.
+
+mod common;
+
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::*;
+use sandbox_engine::assets::AssetPath;
+use sandbox_engine::destructible::{
+    Destructible, DestructiblePlugin, DestructionEvent, DestructionEventKind, DestructionStage,
+};
+use sandbox_engine::project::ProjectRoot;
+
+fn build_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, TransformPlugin, DestructiblePlugin));
+    app
+}
+
+/// Events sent since `cursor` last read them.
+fn event_kinds(app: &App, cursor: &mut EventCursor<DestructionEvent>) -> Vec<DestructionEventKind> {
+    let events = app.world().resource::<Events<DestructionEvent>>();
+    cursor.read(events).map(|event| event.kind).collect()
+}
+
+#[test]
+fn stages_are_reached_in_order_until_the_entity_is_destroyed() {
+    let mut app = build_app();
+    let rock = app
+        .world_mut()
+        .spawn(
+            Destructible::new(100.0)
+                .with_stage(DestructionStage::new(60.0).with_sprite("textures/cracked.png"))
+                .with_stage(DestructionStage::new(30.0).with_sprite("textures/crumbling.png"))
+                .with_stage(DestructionStage::new(0.0)),
+        )
+        .id();
+    let mut cursor = EventCursor::default();
+    app.update();
+    assert!(event_kinds(&app, &mut cursor).is_empty());
+
+    app.world_mut()
+        .get_mut::<Destructible>(rock)
+        .unwrap()
+        .damage(50.0);
+    app.update();
+    assert_eq!(
+        event_kinds(&app, &mut cursor),
+        vec![DestructionEventKind::StageReached(0)]
+    );
+    assert_eq!(
+        app.world().get::<AssetPath>(rock).unwrap().path,
+        "textures/cracked.png"
+    );
+
+    // One large hit passes through every remaining stage
+    app.world_mut()
+        .get_mut::<Destructible>(rock)
+        .unwrap()
+        .damage(500.0);
+    app.update();
+    assert_eq!(
+        event_kinds(&app, &mut cursor),
+        vec![
+            DestructionEventKind::StageReached(1),
+            DestructionEventKind::StageReached(2),
+            DestructionEventKind::Destroyed,
+        ]
+    );
+    assert!(app.world().get_entity(rock).is_err());
+}
+
+#[test]
+fn debris_prefabs_spawn_around_the_destructible() {
+    let project = common::temp_dir("project");
+    let prefabs = project.join("assets/prefabs");
+    std::fs::create_dir_all(&prefabs).unwrap();
+    std::fs::write(
+        prefabs.join("chunk.scn.ron"),
+        r#"(resources: {}, entities: { 4294967296: (components: {
+            "bevy_core::name::Name": "Chunk",
+            "bevy_transform::components::transform::Transform": (
+                translation: (0.0, 5.0, 1.0),
+                rotation: (0.0, 0.0, 0.0, 1.0),
+                scale: (1.0, 1.0, 1.0),
+            ),
+        }) })"#,
+    )
+    .unwrap();
+
+    let mut app = build_app();
+    app.insert_resource(ProjectRoot::new(&project));
+    let rock = app
+        .world_mut()
+        .spawn((
+            Destructible::new(10.0).with_stage(DestructionStage {
+                debris_spread: 20.0,
+                ..DestructionStage::new(0.0).with_debris("prefabs/chunk.scn.ron", 2)
+            }),
+            Transform::from_xyz(100.0, 0.0, 0.0),
+        ))
+        .id();
+    app.update();
+    app.world_mut()
+        .get_mut::<Destructible>(rock)
+        .unwrap()
+        .damage(10.0);
+    app.update();
+
+    let mut chunks = app
+        .world_mut()
+        .query::<(&Name, &Transform)>()
+        .iter(app.world())
+        .map(|(name, transform)| (name.to_string(), transform.translation))
+        .collect::<Vec<_>>();
+    chunks.sort_by(|a, b| a.1.x.total_cmp(&b.1.x));
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].0, "Chunk");
+    // Spread evenly around the destructible, keeping the prefab's own offset
+    assert!(chunks[0].1.abs_diff_eq(Vec3::new(80.0, 5.0, 1.0), 1e-4));
+    assert!(chunks[1].1.abs_diff_eq(Vec3::new(120.0, 5.0, 1.0), 1e-4));
+    assert!(app.world().get_entity(rock).is_err());
+
+    std::fs::remove_dir_all(&project).unwrap();
+}
@@ -0,0 +1,107 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for the replay determinism harness.
+
+use bevy::prelude::*;
+use sandbox_engine::determinism::{
+    check_determinism, compare_logs, run_replay, InputFrame, InputReplay,
+};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Counter(u32);
+
+/// Counts ticks the space bar is held.
+fn count_presses(keyboard: Res<ButtonInput<KeyCode>>, mut query: Query<&mut Counter>) {
+    if keyboard.pressed(KeyCode::Space) {
+        for mut counter in &mut query {
+            counter.0 += 1;
+        }
+    }
+}
+
+fn spawn_counter(mut commands: Commands) {
+    commands.spawn((Counter(0), Transform::default()));
+}
+
+fn build_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .register_type::<Counter>()
+        .add_systems(Startup, spawn_counter)
+        .add_systems(Update, count_presses);
+    app
+}
+
+fn replay() -> InputReplay {
+    let frames = (0..10)
+        .map(|tick| InputFrame {
+            pressed: if tick % 3 == 0 {
+                vec![KeyCode::Space]
+            } else {
+                Vec::new()
+            },
+        })
+        .collect();
+    InputReplay {
+        frames,
+        ..default()
+    }
+}
+
+#[test]
+fn identical_runs_match() {
+    let log = check_determinism(build_app, &replay()).expect("runs should match");
+    assert_eq!(log.ticks.len(), 10);
+    assert!(!log.ticks[0].components.is_empty());
+}
+
+#[test]
+fn input_changes_world_hash() {
+    let log = run_replay(&mut build_app(), &replay());
+    // Space is held on ticks 0, 3, 6, and 9, so the state only changes on those ticks
+    assert_eq!(log.ticks[1].hash, log.ticks[2].hash);
+    assert_ne!(log.ticks[2].hash, log.ticks[3].hash);
+}
+
+#[test]
+fn reports_first_divergent_tick_and_component() {
+    static RUNS: AtomicU32 = AtomicU32::new(0);
+
+    // The second run starts counting four ticks late
+    fn build_drifting_app() -> App {
+        let delay = if RUNS.fetch_add(1, Ordering::SeqCst) == 0 {
+            0
+        } else {
+            4
+        };
+        let mut app = build_app();
+        app.add_systems(
+            Update,
+            move |mut ticks: Local<u32>, mut query: Query<&mut Counter>| {
+                *ticks += 1;
+                if *ticks > delay {
+                    for mut counter in &mut query {
+                        counter.0 += 100;
+                    }
+                }
+            },
+        );
+        app
+    }
+
+    let divergence = check_determinism(build_drifting_app, &replay()).unwrap_err();
+    assert_eq!(divergence.tick, 0);
+    assert!(divergence
+        .component
+        .as_deref()
+        .is_some_and(|component| component.ends_with("Counter")));
+
+    // Logs of different length diverge where the shorter one ends
+    let long = run_replay(&mut build_app(), &replay());
+    let mut short = long.clone();
+    short.ticks.truncate(4);
+    assert_eq!(compare_logs(&long, &short).map(|d| d.tick), Some(4));
+}
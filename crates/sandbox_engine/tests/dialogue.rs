@@ -0,0 +1,188 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for dialogue conditions, choices, flag changes, and triggers.
+
+mod common;
+
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::*;
+use sandbox_engine::dialogue::{
+    ActiveDialogue, Dialogue, DialogueEvent, DialogueEventKind, DialoguePlugin, DialogueRunner,
+    DialogueStep, DialogueTrigger,
+};
+use sandbox_engine::flags::WorldFlags;
+use sandbox_engine::interaction::InteractEvent;
+use sandbox_engine::project::ProjectRoot;
+
+const TRADER: &str = r#"(
+    start: "greeting",
+    nodes: {
+        "greeting": (
+            actions: [Add("times_docked", 1.0)],
+            lines: [
+                (speaker: "Trader", text: "Welcome, miner."),
+                (speaker: "Trader", text: "Visit number {times_docked}!", condition: AtLeast("times_docked", 2.0)),
+            ],
+            choices: [
+                (text: "Sell {ore} ore", next: Some("sell"), condition: AtLeast("ore", 1.0)),
+                (text: "Leave"),
+            ],
+        ),
+        "sell": (
+            actions: [Add("credits", 10.0), Remove("ore")],
+            lines: [(speaker: "Trader", text: "Pleasure doing business.")],
+            next: Some("farewell"),
+        ),
+        "farewell": (
+            lines: [(text: "The airlock hisses shut.")],
+        ),
+    },
+)"#;
+
+fn line(speaker: &str, text: &str) -> DialogueStep {
+    DialogueStep::Line {
+        speaker: speaker.to_string(),
+        text: text.to_string(),
+    }
+}
+
+fn trader() -> Dialogue {
+    bevy::scene::ron::from_str(TRADER).unwrap()
+}
+
+#[test]
+fn conditions_choices_and_actions_drive_the_conversation() {
+    let mut flags = WorldFlags::default();
+    flags.set("ore", 3.0);
+    flags.set("times_docked", 1.0);
+    let mut runner = DialogueRunner::new(trader(), &mut flags);
+    assert_eq!(flags.number("times_docked"), 2.0);
+    assert_eq!(runner.step(), &line("Trader", "Welcome, miner."));
+    runner.advance(&mut flags);
+    assert_eq!(runner.step(), &line("Trader", "Visit number 2!"));
+    runner.advance(&mut flags);
+    assert_eq!(
+        runner.step(),
+        &DialogueStep::Choices(vec![(0, "Sell 3 ore".into()), (1, "Leave".into())])
+    );
+    // Advancing doesn't skip choices, and unavailable choices can't be picked
+    runner.advance(&mut flags);
+    assert!(!runner.choose(5, &mut flags));
+
+    assert!(runner.choose(0, &mut flags));
+    assert_eq!(flags.number("credits"), 10.0);
+    assert!(flags.get("ore").is_none());
+    assert_eq!(runner.step(), &line("Trader", "Pleasure doing business."));
+    runner.advance(&mut flags);
+    assert_eq!(runner.node(), Some("farewell"));
+    assert_eq!(runner.step(), &line("", "The airlock hisses shut."));
+    runner.advance(&mut flags);
+    assert!(runner.is_finished());
+
+    // Without ore or an earlier visit, the gated line and choice are hidden
+    let mut flags = WorldFlags::default();
+    let mut runner = DialogueRunner::new(trader(), &mut flags);
+    runner.advance(&mut flags);
+    assert_eq!(
+        runner.step(),
+        &DialogueStep::Choices(vec![(1, "Leave".into())])
+    );
+    runner.choose(1, &mut flags);
+    assert!(runner.is_finished());
+}
+
+#[test]
+fn validation_reports_missing_nodes() {
+    let mut dialogue = trader();
+    assert!(dialogue.validate().is_empty());
+    dialogue.start = "hello".to_string();
+    dialogue.nodes.get_mut("sell").unwrap().next = Some("goodbye".to_string());
+    assert_eq!(
+        dialogue.validate(),
+        [
+            "Start node 'hello' doesn't exist",
+            "'sell' leads to missing node 'goodbye'",
+        ]
+    );
+}
+
+#[test]
+fn triggers_start_dialogues_that_the_keyboard_steps_through() {
+    let project = common::temp_dir("project");
+    std::fs::create_dir_all(project.join("assets/dialogue")).unwrap();
+    std::fs::write(project.join("assets/dialogue/trader.dialogue.ron"), TRADER).unwrap();
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, DialoguePlugin))
+        .insert_resource(ProjectRoot::new(&project))
+        .init_resource::<ButtonInput<KeyCode>>();
+    let world = app.world_mut();
+    let player = world.spawn_empty().id();
+    let station = world
+        .spawn(DialogueTrigger::new("dialogue/trader.dialogue.ron"))
+        .id();
+    world.send_event(InteractEvent {
+        interactor: player,
+        target: station,
+    });
+    let mut cursor = EventCursor::<DialogueEvent>::default();
+    app.update();
+
+    let active = app.world().resource::<ActiveDialogue>();
+    assert_eq!(active.source, Some(station));
+    assert_eq!(active.step(), Some(&line("Trader", "Welcome, miner.")));
+
+    let press = |app: &mut App, key: KeyCode| {
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release_all();
+        keyboard.clear();
+        keyboard.press(key);
+        app.update();
+    };
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(
+        app.world().resource::<ActiveDialogue>().step(),
+        Some(&DialogueStep::Choices(vec![(1, "Leave".into())]))
+    );
+    press(&mut app, KeyCode::Digit1);
+    assert!(!app.world().resource::<ActiveDialogue>().is_active());
+
+    let events = app.world().resource::<Events<DialogueEvent>>();
+    let kinds: Vec<_> = cursor.read(events).map(|event| event.kind).collect();
+    assert_eq!(
+        kinds,
+        [DialogueEventKind::Started, DialogueEventKind::Ended]
+    );
+    assert_eq!(
+        app.world().resource::<WorldFlags>().number("times_docked"),
+        1.0
+    );
+
+    std::fs::remove_dir_all(&project).unwrap();
+}
+
+#[test]
+fn the_spaceminer_station_dialogue_loads_cleanly() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../assets/dialogue/station.dialogue.ron");
+    let dialogue = Dialogue::load(&path).unwrap();
+    assert!(dialogue.validate().is_empty());
+
+    let mut flags = WorldFlags::default();
+    flags.set("ore", 4.0);
+    let mut runner = DialogueRunner::new(dialogue, &mut flags);
+    assert_eq!(
+        runner.step(),
+        &line("Trader", "Welcome to the station, miner.")
+    );
+    runner.advance(&mut flags);
+    assert!(runner.choose(0, &mut flags));
+    assert_eq!(
+        runner.step(),
+        &line(
+            "Trader",
+            "Pleasure doing business. You've got 10 credits now."
+        )
+    );
+}
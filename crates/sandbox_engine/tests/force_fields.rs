@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for attractor and repulsor force fields.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use sandbox_engine::physics::{Attractor, Falloff, LinearVelocity, PhysicsPlugin, Repulsor};
+use std::time::Duration;
+
+#[test]
+fn field_strength_falls_off_towards_the_radius() {
+    let attractor = Attractor::new(100.0, 50.0);
+    // Linear: half strength halfway out, pointing back at the center
+    assert_eq!(
+        attractor.acceleration(Vec2::new(25.0, 0.0)),
+        Vec2::new(-50.0, 0.0)
+    );
+    assert_eq!(attractor.acceleration(Vec2::new(0.0, 60.0)), Vec2::ZERO);
+
+    let repulsor = Repulsor {
+        falloff: Falloff::Constant,
+        ..Repulsor::new(100.0, 50.0)
+    };
+    assert_eq!(
+        repulsor.acceleration(Vec2::new(0.0, -40.0)),
+        Vec2::new(0.0, -100.0)
+    );
+    assert_eq!(Falloff::Quadratic.factor(25.0, 50.0), 0.25);
+}
+
+#[test]
+fn fields_accelerate_nearby_bodies() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, TransformPlugin, PhysicsPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+    let world = app.world_mut();
+    world.spawn((
+        Attractor::new(100.0, 200.0),
+        Transform::from_xyz(0.0, 0.0, 0.0),
+    ));
+    world.spawn((
+        Repulsor::new(100.0, 200.0),
+        Transform::from_xyz(1000.0, 0.0, 0.0),
+    ));
+    let pulled = world
+        .spawn((
+            LinearVelocity::default(),
+            Transform::from_xyz(50.0, 0.0, 0.0),
+        ))
+        .id();
+    let pushed = world
+        .spawn((
+            LinearVelocity::default(),
+            Transform::from_xyz(1000.0, 50.0, 0.0),
+        ))
+        .id();
+    let distant = world
+        .spawn((
+            LinearVelocity::default(),
+            Transform::from_xyz(500.0, 500.0, 0.0),
+        ))
+        .id();
+    // Global transforms are propagated at the end of the first update
+    app.update();
+    app.update();
+
+    let velocity = |entity| app.world().get::<LinearVelocity>(entity).unwrap().0;
+    assert!(velocity(pulled).x < 0.0 && velocity(pulled).y == 0.0);
+    assert!(velocity(pushed).y > 0.0 && velocity(pushed).x == 0.0);
+    assert_eq!(velocity(distant), Vec2::ZERO);
+}
@@ -0,0 +1,33 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Golden image tests: rendering scenes and comparing them with committed PNGs.
+
+use image::{Rgba, RgbaImage};
+use sandbox_engine::test_utils::{assert_scene_renders, compare_images, diff_image};
+
+/// Three overlapping colored sprites; the green one is drawn over the blue.
+const SPRITES_SCENE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/goldens/sprites.scn.ron");
+
+#[test]
+fn sprites_render_like_the_golden() {
+    assert_scene_renders(SPRITES_SCENE, "tests/goldens/sprites.png");
+}
+
+#[test]
+fn pixels_within_the_tolerance_match() {
+    let expected = RgbaImage::from_pixel(4, 4, Rgba([100, 100, 100, 255]));
+    let mut actual = expected.clone();
+    actual.put_pixel(0, 0, Rgba([102, 99, 100, 255]));
+    actual.put_pixel(3, 3, Rgba([100, 100, 140, 255]));
+
+    let comparison = compare_images(&actual, &expected, 2);
+    assert_eq!(comparison.mismatched, 1);
+    assert_eq!(comparison.total, 16);
+    assert_eq!(comparison.max_difference, 40);
+    assert_eq!(comparison.mismatched_share(), 1.0 / 16.0);
+
+    let diff = diff_image(&actual, &expected, 2);
+    assert_eq!(*diff.get_pixel(3, 3), Rgba([255, 0, 0, 255]));
+    assert_eq!(*diff.get_pixel(0, 0), Rgba([25, 25, 25, 255]));
+}
@@ -0,0 +1,191 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for device-aware input prompts.
+
+mod common;
+
+use bevy::input::gamepad::GamepadButtonChangedEvent;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::{ButtonState, InputPlugin};
+use bevy::prelude::*;
+use sandbox_engine::input_prompt::{
+    ActionBinding, GamepadKind, InputDevice, InputPrompt, InputPromptPlugin,
+};
+use sandbox_engine::interaction::{InteractEvent, Interactable, InteractablePlugin, Interactor};
+use sandbox_engine::scene::{load_scene, save_scene};
+
+fn dock() -> ActionBinding {
+    ActionBinding::new(KeyCode::KeyE, GamepadButton::South)
+}
+
+#[test]
+fn bindings_are_labeled_for_each_device() {
+    let labels: Vec<String> = [
+        InputDevice::Keyboard,
+        InputDevice::Gamepad(GamepadKind::Xbox),
+        InputDevice::Gamepad(GamepadKind::PlayStation),
+        InputDevice::Gamepad(GamepadKind::Nintendo),
+        InputDevice::Gamepad(GamepadKind::Generic),
+    ]
+    .into_iter()
+    .map(|device| dock().label(device))
+    .collect();
+    assert_eq!(labels, ["E", "A", "Cross", "B", "A"]);
+
+    // Keyboard-only actions keep showing their key
+    let pause = ActionBinding::key(KeyCode::Escape);
+    let playstation = InputDevice::Gamepad(GamepadKind::PlayStation);
+    assert_eq!(pause.label(playstation), "Escape");
+    assert_eq!(
+        ActionBinding::new(KeyCode::Digit1, GamepadButton::LeftTrigger2).label(playstation),
+        "L2"
+    );
+
+    assert_eq!(dock().icon(InputDevice::Keyboard), "keyboard/e.png");
+    assert_eq!(dock().icon(playstation), "playstation/south.png");
+    assert_eq!(
+        ActionBinding::new(KeyCode::Space, GamepadButton::Other(7))
+            .icon(InputDevice::Gamepad(GamepadKind::Generic)),
+        "gamepad/other7.png"
+    );
+}
+
+#[test]
+fn gamepads_are_recognized_by_vendor_or_name() {
+    assert_eq!(
+        GamepadKind::from_vendor_id(0x054c),
+        GamepadKind::PlayStation
+    );
+    assert_eq!(GamepadKind::from_vendor_id(0x057e), GamepadKind::Nintendo);
+    assert_eq!(GamepadKind::from_vendor_id(0x045e), GamepadKind::Xbox);
+    assert_eq!(GamepadKind::from_vendor_id(0x1234), GamepadKind::Generic);
+    assert_eq!(
+        GamepadKind::from_name("DualSense Wireless Controller"),
+        GamepadKind::PlayStation
+    );
+    assert_eq!(
+        GamepadKind::from_name("Nintendo Switch Pro Controller"),
+        GamepadKind::Nintendo
+    );
+    assert_eq!(
+        GamepadKind::from_name("Xbox Series X Controller"),
+        GamepadKind::Xbox
+    );
+    assert_eq!(GamepadKind::from_name("USB Joystick"), GamepadKind::Generic);
+}
+
+#[test]
+fn prompts_follow_the_device_used_last() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, InputPromptPlugin));
+    let gamepad = app
+        .world_mut()
+        .spawn((
+            Gamepad::default(),
+            Name::new("DualSense Wireless Controller"),
+        ))
+        .id();
+    let prompt = app
+        .world_mut()
+        .spawn((
+            Text::default(),
+            InputPrompt::new("Press [{}] to dock", dock()),
+        ))
+        .id();
+    let shown = |app: &App| app.world().get::<Text>(prompt).unwrap().0.clone();
+    app.update();
+    assert_eq!(shown(&app), "Press [E] to dock");
+
+    app.world_mut().send_event(GamepadButtonChangedEvent::new(
+        gamepad,
+        GamepadButton::East,
+        ButtonState::Pressed,
+        1.0,
+    ));
+    app.update();
+    assert_eq!(
+        *app.world().resource::<InputDevice>(),
+        InputDevice::Gamepad(GamepadKind::PlayStation)
+    );
+    assert_eq!(shown(&app), "Press [Cross] to dock");
+
+    app.world_mut().send_event(KeyboardInput {
+        key_code: KeyCode::KeyW,
+        logical_key: Key::Character("w".into()),
+        state: ButtonState::Pressed,
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    });
+    app.update();
+    assert_eq!(shown(&app), "Press [E] to dock");
+}
+
+#[test]
+fn gamepad_buttons_trigger_interactions() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, TransformPlugin, InteractablePlugin));
+    app.world_mut().spawn((Interactor, Transform::default()));
+    let station = app
+        .world_mut()
+        .spawn((Interactable::new("Dock", 50.0), Transform::default()))
+        .id();
+    let mut gamepad = Gamepad::default();
+    gamepad.digital_mut().press(GamepadButton::South);
+    app.world_mut().spawn(gamepad);
+    app.update();
+
+    let events = app.world().resource::<Events<InteractEvent>>();
+    let targets: Vec<Entity> = events
+        .iter_current_update_events()
+        .map(|event| event.target)
+        .collect();
+    assert_eq!(targets, vec![station]);
+}
+
+#[test]
+fn interactables_saved_before_gamepad_buttons_still_load() {
+    let path = common::temp_scene_path("keyboard_only");
+    std::fs::write(
+        &path,
+        r#"(resources: {}, entities: { 4294967296: (components: {
+            "bevy_transform::components::transform::Transform": (
+                translation: (0.0, 0.0, 0.0),
+                rotation: (0.0, 0.0, 0.0, 1.0),
+                scale: (1.0, 1.0, 1.0),
+            ),
+            "sandbox_engine::interaction::Interactable": (
+                prompt: "Dock",
+                radius: 120.0,
+                action: KeyF,
+            ),
+        }) })"#,
+    )
+    .unwrap();
+
+    let mut app = common::build_app();
+    app.add_plugins(InteractablePlugin);
+    let world = app.world_mut();
+    load_scene(world, &path).unwrap();
+    let mut query = world.query::<&Interactable>();
+    let interactable = query.single(world).clone();
+    assert_eq!(interactable.action, KeyCode::KeyF);
+    assert_eq!(interactable.button, None);
+    assert_eq!(interactable.prompt_text(), "[F] Dock");
+    assert_eq!(
+        interactable.prompt_text_for(InputDevice::Gamepad(GamepadKind::Xbox)),
+        "[F] Dock"
+    );
+
+    // Resaving keeps the key and adds the button
+    let mut query = world.query::<&mut Interactable>();
+    query.single_mut(world).button = Some(GamepadButton::West);
+    save_scene(world, &path).unwrap();
+    load_scene(world, &path).unwrap();
+    let mut query = world.query::<&Interactable>();
+    let interactable = query.single(world);
+    assert_eq!(interactable.action, KeyCode::KeyF);
+    assert_eq!(interactable.button, Some(GamepadButton::West));
+
+    std::fs::remove_file(&path).ok();
+}
@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for physics joints.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use sandbox_engine::joints::{DistanceJoint, JointPlugin};
+use sandbox_engine::physics::{LinearVelocity, PhysicsPlugin};
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::{load_scene, new_scene, save_scene};
+use std::time::Duration;
+
+#[test]
+fn distance_joints_hold_bodies_at_their_length() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, PhysicsPlugin, JointPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+    // A fixed hook and a weight hanging off it by an anchor on its top edge
+    let hook = app.world_mut().spawn(Transform::default()).id();
+    let weight = app
+        .world_mut()
+        .spawn((
+            DistanceJoint {
+                anchor: Vec2::new(0.0, 10.0),
+                ..DistanceJoint::new(hook, 50.0)
+            },
+            LinearVelocity(Vec2::new(100.0, -100.0)),
+            Transform::from_xyz(0.0, -60.0, 0.0),
+        ))
+        .id();
+
+    for _ in 0..5 {
+        app.update();
+        let world = app.world();
+        let joint = world.get::<DistanceJoint>(weight).unwrap();
+        let (anchor, hook_anchor) = joint.world_anchors(
+            world.get::<Transform>(weight).unwrap(),
+            world.get::<Transform>(hook).unwrap(),
+        );
+        assert!((anchor.distance(hook_anchor) - 50.0).abs() < 1e-3);
+        assert_eq!(
+            world.get::<Transform>(hook).unwrap().translation,
+            Vec3::ZERO
+        );
+    }
+
+    // Only the swing across the rod is left
+    let world = app.world();
+    let position = world
+        .get::<Transform>(weight)
+        .unwrap()
+        .translation
+        .truncate();
+    let velocity = world.get::<LinearVelocity>(weight).unwrap().0;
+    let along = (position + Vec2::new(0.0, 10.0)).normalize();
+    assert!(velocity.dot(along).abs() < 1e-3, "{}", velocity);
+    assert!(velocity.x > 0.0);
+}
+
+#[test]
+fn joints_keep_their_connection_through_save_and_load() {
+    let dir = common::temp_dir("project");
+    let mut app = common::build_app();
+    app.add_plugins((MinimalPlugins, PhysicsPlugin, JointPlugin))
+        .insert_resource(ProjectRoot::new(&dir));
+    let world = app.world_mut();
+
+    let hook = world
+        .spawn((Name::new("Hook"), Transform::from_xyz(0.0, 100.0, 0.0)))
+        .id();
+    world.spawn((
+        Name::new("Weight"),
+        DistanceJoint::new(hook, 80.0),
+        Transform::default(),
+    ));
+    let path = dir.join("pendulum.scn.ron");
+    save_scene(world, &path).unwrap();
+    new_scene(world);
+    load_scene(world, &path).unwrap();
+
+    let mut query = world.query::<(&Name, &DistanceJoint)>();
+    let (_, joint) = query.single(world);
+    let connected = joint.connected.unwrap();
+    assert_eq!(world.get::<Name>(connected).unwrap().as_str(), "Hook");
+    std::fs::remove_dir_all(&dir).ok();
+}
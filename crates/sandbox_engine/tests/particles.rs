@@ -0,0 +1,154 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for particle emitters.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use sandbox_engine::editor_state::{EditorPlayState, EditorStatePlugin};
+use sandbox_engine::particles::{
+    EmitterShape, Particle, ParticleEmitter, ParticlePlugin, ParticlePreview,
+};
+use sandbox_engine::scene::scene_entities;
+use std::time::Duration;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, ParticlePlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+    // The first frame has no delta
+    app.update();
+    app
+}
+
+fn particles(app: &mut App) -> Vec<(Particle, Transform, Sprite)> {
+    app.world_mut()
+        .query::<(&Particle, &Transform, &Sprite)>()
+        .iter(app.world())
+        .map(|(particle, transform, sprite)| (particle.clone(), *transform, sprite.clone()))
+        .collect()
+}
+
+#[test]
+fn emitters_spawn_runtime_only_particles_that_expire() {
+    let mut app = app();
+    let emitter = app
+        .world_mut()
+        .spawn(ParticleEmitter {
+            rate: 20.0,
+            lifetime: 0.45,
+            max_particles: 5,
+            ..default()
+        })
+        .id();
+
+    // Two particles a frame, held to the cap
+    app.update();
+    assert_eq!(particles(&mut app).len(), 2);
+    let children = app.world().get::<Children>(emitter).unwrap().len();
+    assert_eq!(children, 2);
+    app.update();
+    app.update();
+    assert_eq!(particles(&mut app).len(), 5);
+
+    // Particles are never saved with the scene
+    assert_eq!(scene_entities(app.world()), vec![emitter]);
+
+    // They expire, and stop coming when the emitter stops
+    app.world_mut()
+        .get_mut::<ParticleEmitter>(emitter)
+        .unwrap()
+        .emitting = false;
+    for _ in 0..6 {
+        app.update();
+    }
+    assert!(particles(&mut app).is_empty());
+}
+
+#[test]
+fn particles_fly_within_the_cone_and_fade() {
+    let mut app = app();
+    app.world_mut().spawn(ParticleEmitter {
+        shape: EmitterShape::Cone {
+            angle: 30.0,
+            radius: 10.0,
+        },
+        rate: 100.0,
+        lifetime: 1.0,
+        speed: 50.0,
+        direction: 90.0,
+        color: Color::WHITE,
+        end_color: Color::BLACK,
+        ..default()
+    });
+    app.update();
+
+    let launched = particles(&mut app);
+    assert_eq!(launched.len(), 10);
+    for (particle, transform, _) in &launched {
+        let heading = particle.velocity.to_angle().to_degrees();
+        assert!((60.0..=120.0).contains(&heading), "{}", heading);
+        assert!((particle.velocity.length() - 50.0).abs() < 1e-3);
+        // They've already had one frame of movement
+        let start = transform.translation.truncate() - particle.velocity * 0.1;
+        assert!(start.length() <= 10.0 + 1e-3, "{}", start);
+    }
+
+    // Halfway through their lives, particles are halfway to the end color
+    for _ in 0..4 {
+        app.update();
+    }
+    let oldest = particles(&mut app)
+        .into_iter()
+        .max_by(|a, b| a.0.age.total_cmp(&b.0.age))
+        .unwrap();
+    assert!((oldest.0.age - 0.5).abs() < 1e-4, "{}", oldest.0.age);
+    let expected = Color::WHITE.mix(&Color::BLACK, 0.5).to_linear();
+    let color = oldest.2.color.to_linear();
+    assert!((color.red - expected.red).abs() < 1e-4);
+}
+
+#[test]
+fn the_preview_runs_emitters_while_stopped() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        bevy::state::app::StatesPlugin,
+        EditorStatePlugin,
+        ParticlePlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.update();
+    app.world_mut().spawn(ParticleEmitter {
+        rate: 20.0,
+        ..default()
+    });
+
+    // Stopped emitters stay idle until previewed
+    app.update();
+    assert!(particles(&mut app).is_empty());
+    app.world_mut().resource_mut::<ParticlePreview>().enabled = true;
+    app.update();
+    app.update();
+    assert_eq!(particles(&mut app).len(), 4);
+
+    // Turning the preview off clears its particles
+    app.world_mut().resource_mut::<ParticlePreview>().enabled = false;
+    app.update();
+    assert!(particles(&mut app).is_empty());
+
+    // Play starts from a clean slate even with the preview on
+    app.world_mut().resource_mut::<ParticlePreview>().enabled = true;
+    app.update();
+    assert_eq!(particles(&mut app).len(), 2);
+    app.world_mut()
+        .resource_mut::<NextState<EditorPlayState>>()
+        .set(EditorPlayState::Playing);
+    app.update();
+    assert_eq!(particles(&mut app).len(), 2);
+    assert!(particles(&mut app).iter().all(|(p, ..)| p.age < 0.15));
+}
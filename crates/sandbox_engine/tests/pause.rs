@@ -0,0 +1,106 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for pausing game time.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use sandbox_engine::editor_state::{EditorPlayState, EditorStatePlugin};
+use sandbox_engine::time_control::{TimeControl, TimeControlPlugin};
+use std::time::Duration;
+
+/// Game and real seconds seen by `Update` systems.
+#[derive(Resource, Default)]
+struct Elapsed {
+    game: f32,
+    real: f32,
+}
+
+fn count_time(time: Res<Time>, real_time: Res<Time<Real>>, mut elapsed: ResMut<Elapsed>) {
+    elapsed.game += time.delta_secs();
+    elapsed.real += real_time.delta_secs();
+}
+
+fn build_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        bevy::state::app::StatesPlugin,
+        EditorStatePlugin,
+        TimeControlPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )))
+    .init_resource::<Elapsed>()
+    .add_systems(Update, count_time);
+    // The first frame has no delta
+    app.update();
+    app
+}
+
+fn elapsed(app: &App) -> (f32, f32) {
+    let elapsed = app.world().resource::<Elapsed>();
+    (
+        (elapsed.game * 10.0).round() / 10.0,
+        (elapsed.real * 10.0).round() / 10.0,
+    )
+}
+
+#[test]
+fn pausing_freezes_game_time_but_not_real_time() {
+    let mut app = build_app();
+    app.update();
+    assert_eq!(elapsed(&app), (0.1, 0.1));
+
+    app.world_mut().resource_mut::<TimeControl>().pause();
+    app.update();
+    app.update();
+    assert_eq!(elapsed(&app), (0.1, 0.3));
+    assert!(app.world().resource::<Time<Virtual>>().is_paused());
+
+    app.world_mut().resource_mut::<TimeControl>().resume();
+    app.update();
+    assert_eq!(elapsed(&app), (0.2, 0.4));
+}
+
+#[test]
+fn hitstops_wait_out_the_pause() {
+    let mut app = build_app();
+    app.world_mut().resource_mut::<TimeControl>().hitstop(1);
+    app.world_mut().resource_mut::<TimeControl>().pause();
+    app.update();
+    app.update();
+    assert!(app.world().resource::<TimeControl>().in_hitstop());
+
+    app.world_mut().resource_mut::<TimeControl>().resume();
+    app.update();
+    assert!(!app.world().resource::<TimeControl>().in_hitstop());
+    assert_eq!(elapsed(&app).0, 0.0);
+    app.update();
+    assert_eq!(elapsed(&app).0, 0.1);
+}
+
+#[test]
+fn the_editor_pause_state_pauses_the_game() {
+    let mut app = build_app();
+    let set_state = |app: &mut App, state| {
+        app.world_mut()
+            .resource_mut::<NextState<EditorPlayState>>()
+            .set(state);
+        app.update();
+    };
+    set_state(&mut app, EditorPlayState::Playing);
+    set_state(&mut app, EditorPlayState::Paused);
+    assert!(app.world().resource::<TimeControl>().is_paused());
+    let frozen = elapsed(&app).0;
+    app.update();
+    assert_eq!(elapsed(&app).0, frozen);
+
+    // Resuming and stopping both restart the clock
+    set_state(&mut app, EditorPlayState::Playing);
+    assert!(!app.world().resource::<TimeControl>().is_paused());
+    set_state(&mut app, EditorPlayState::Paused);
+    set_state(&mut app, EditorPlayState::Stopped);
+    assert!(!app.world().resource::<TimeControl>().is_paused());
+}
@@ -0,0 +1,158 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for prefab links and overrides.
+
+mod common;
+
+use bevy::prelude::*;
+use sandbox_engine::prefab::{
+    apply_to_prefab, prefab_overrides, prefab_root, revert_to_prefab, OverrideKind, PrefabSource,
+};
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::{instantiate_prefab, new_scene, save_scene, EditorLocked};
+use std::path::{Path, PathBuf};
+
+fn temp_project(name: &str) -> PathBuf {
+    let dir = common::temp_dir(name);
+    std::fs::create_dir_all(dir.join("assets/prefabs")).unwrap();
+    dir
+}
+
+fn build_app(project: &Path) -> App {
+    let mut app = common::build_app();
+    app.insert_resource(ProjectRoot::new(project));
+    app
+}
+
+/// Saves a "Turret" prefab with a "Barrel" child and spawns one copy of it.
+fn spawn_turret(world: &mut World, project: &Path) -> (Entity, Entity) {
+    let turret = world
+        .spawn((Name::new("Turret"), Transform::from_xyz(5.0, 5.0, 0.0)))
+        .id();
+    let barrel = world
+        .spawn((Name::new("Barrel"), Transform::from_xyz(0.0, 10.0, 0.0)))
+        .id();
+    world.entity_mut(turret).add_child(barrel);
+    save_scene(world, &project.join("assets/prefabs/turret.scn.ron")).unwrap();
+    new_scene(world);
+
+    let spawned = instantiate_prefab(world, Path::new("assets/prefabs/turret.scn.ron")).unwrap();
+    let turret = spawned
+        .iter()
+        .copied()
+        .find(|&entity| world.get::<PrefabSource>(entity).is_some())
+        .unwrap();
+    let barrel = world.get::<Children>(turret).unwrap()[0];
+    (turret, barrel)
+}
+
+#[test]
+fn instantiated_prefabs_link_back_to_their_file() {
+    let project = temp_project("link");
+    let mut app = build_app(&project);
+    let world = app.world_mut();
+    let (turret, barrel) = spawn_turret(world, &project);
+
+    assert_eq!(
+        world.get::<PrefabSource>(turret),
+        Some(&PrefabSource {
+            path: "prefabs/turret.scn.ron".to_string(),
+            root: 0,
+        })
+    );
+    assert!(world.get::<PrefabSource>(barrel).is_none());
+    assert_eq!(prefab_root(world, barrel), Some(turret));
+    assert!(prefab_overrides(world, turret).unwrap().is_empty());
+    std::fs::remove_dir_all(&project).ok();
+}
+
+#[test]
+fn overrides_list_local_changes_except_the_root_placement() {
+    let project = temp_project("overrides");
+    let mut app = build_app(&project);
+    let world = app.world_mut();
+    let (turret, barrel) = spawn_turret(world, &project);
+
+    world.get_mut::<Transform>(turret).unwrap().translation = Vec3::new(100.0, 0.0, 0.0);
+    world.get_mut::<Transform>(barrel).unwrap().scale = Vec3::splat(2.0);
+    world.entity_mut(barrel).insert(EditorLocked);
+
+    let overrides: Vec<_> = prefab_overrides(world, turret)
+        .unwrap()
+        .into_iter()
+        .map(|change| (change.entity, change.component, change.kind))
+        .collect();
+    assert_eq!(
+        overrides,
+        [
+            (
+                barrel,
+                "Transform".to_string(),
+                OverrideKind::Changed(Some("scale".to_string()))
+            ),
+            (barrel, "EditorLocked".to_string(), OverrideKind::Added),
+        ]
+    );
+    std::fs::remove_dir_all(&project).ok();
+}
+
+#[test]
+fn reverting_restores_the_prefab_values() {
+    let project = temp_project("revert");
+    let mut app = build_app(&project);
+    let world = app.world_mut();
+    let (turret, barrel) = spawn_turret(world, &project);
+
+    world.get_mut::<Transform>(turret).unwrap().translation = Vec3::new(100.0, 0.0, 0.0);
+    world.get_mut::<Transform>(barrel).unwrap().translation.y = 40.0;
+    world.entity_mut(barrel).insert(EditorLocked);
+
+    assert_eq!(revert_to_prefab(world, turret).unwrap(), 2);
+    assert_eq!(
+        world.get::<Transform>(barrel).unwrap().translation,
+        Vec3::new(0.0, 10.0, 0.0)
+    );
+    assert!(world.get::<EditorLocked>(barrel).is_none());
+    // The instance stays where it was placed
+    assert_eq!(
+        world.get::<Transform>(turret).unwrap().translation,
+        Vec3::new(100.0, 0.0, 0.0)
+    );
+    assert!(prefab_overrides(world, turret).unwrap().is_empty());
+    std::fs::remove_dir_all(&project).ok();
+}
+
+#[test]
+fn applying_writes_overrides_into_the_prefab_file() {
+    let project = temp_project("apply");
+    let mut app = build_app(&project);
+    let world = app.world_mut();
+    let (turret, barrel) = spawn_turret(world, &project);
+
+    world.get_mut::<Transform>(turret).unwrap().translation = Vec3::new(100.0, 0.0, 0.0);
+    world.get_mut::<Transform>(barrel).unwrap().translation.y = 40.0;
+    *world.get_mut::<Name>(turret).unwrap() = Name::new("Heavy Turret");
+
+    assert_eq!(apply_to_prefab(world, turret).unwrap(), 2);
+    assert!(prefab_overrides(world, turret).unwrap().is_empty());
+
+    // New copies get the applied values, but not the first copy's placement
+    let copy = instantiate_prefab(world, Path::new("assets/prefabs/turret.scn.ron")).unwrap();
+    let copy = copy
+        .iter()
+        .copied()
+        .find(|&entity| world.get::<PrefabSource>(entity).is_some())
+        .unwrap();
+    assert_eq!(world.get::<Name>(copy).unwrap().as_str(), "Heavy Turret");
+    assert_eq!(
+        world.get::<Transform>(copy).unwrap().translation,
+        Vec3::new(5.0, 5.0, 0.0)
+    );
+    let copy_barrel = world.get::<Children>(copy).unwrap()[0];
+    assert_eq!(
+        world.get::<Transform>(copy_barrel).unwrap().translation.y,
+        40.0
+    );
+    std::fs::remove_dir_all(&project).ok();
+}
@@ -0,0 +1,210 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for projectile homing, arcs, piercing, bouncing, and explosions.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use sandbox_engine::destructible::{Destructible, DestructiblePlugin};
+use sandbox_engine::physics::{Collider, Falloff, LinearVelocity, PhysicsPlugin};
+use sandbox_engine::projectile::{Projectile, ProjectilePlugin};
+use std::time::Duration;
+
+fn build_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        PhysicsPlugin,
+        DestructiblePlugin,
+        ProjectilePlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app
+}
+
+/// Transform and matching global transform, so contacts work on the first update.
+fn placed(x: f32, y: f32) -> (Transform, GlobalTransform) {
+    let transform = Transform::from_xyz(x, y, 0.0);
+    (transform, GlobalTransform::from(transform))
+}
+
+fn health(app: &App, entity: Entity) -> f32 {
+    app.world().get::<Destructible>(entity).unwrap().health
+}
+
+#[test]
+fn turning_is_limited_to_the_turn_rate() {
+    let turned = Projectile::turn_towards(Vec2::new(100.0, 0.0), Vec2::new(0.0, 5.0), 0.1);
+    assert!((turned.to_angle() - 0.1).abs() < 1e-5);
+    assert!((turned.length() - 100.0).abs() < 1e-3);
+    // Within the limit it turns all the way
+    let turned = Projectile::turn_towards(Vec2::new(100.0, 0.0), Vec2::new(1.0, -0.01), 0.1);
+    assert!((turned.normalize() - Vec2::new(1.0, -0.01).normalize()).length() < 1e-5);
+}
+
+#[test]
+fn homing_projectiles_steer_towards_targets_in_range() {
+    let mut app = build_app();
+    let world = app.world_mut();
+    world.spawn((Destructible::new(100.0), placed(0.0, 200.0)));
+    let homing = world
+        .spawn((
+            Projectile {
+                homing_turn_rate: 90.0,
+                ..default()
+            },
+            LinearVelocity(Vec2::new(100.0, 0.0)),
+            placed(0.0, 0.0),
+        ))
+        .id();
+    let out_of_range = world
+        .spawn((
+            Projectile {
+                homing_turn_rate: 90.0,
+                homing_range: 50.0,
+                ..default()
+            },
+            LinearVelocity(Vec2::new(100.0, 0.0)),
+            placed(0.0, 0.0),
+        ))
+        .id();
+    app.update();
+    app.update();
+    app.update();
+
+    let velocity = app.world().get::<LinearVelocity>(homing).unwrap().0;
+    let angle = velocity.to_angle().to_degrees();
+    assert!(
+        angle > 0.0 && angle <= 27.0 + 1e-3,
+        "turned {} degrees",
+        angle
+    );
+    assert!((velocity.length() - 100.0).abs() < 1e-3);
+    assert_eq!(
+        app.world().get::<LinearVelocity>(out_of_range).unwrap().0,
+        Vec2::new(100.0, 0.0)
+    );
+}
+
+#[test]
+fn gravity_bends_projectiles_into_an_arc() {
+    let mut app = build_app();
+    let lobbed = app
+        .world_mut()
+        .spawn((
+            Projectile {
+                gravity: 100.0,
+                ..default()
+            },
+            LinearVelocity(Vec2::new(100.0, 100.0)),
+            placed(0.0, 0.0),
+        ))
+        .id();
+    app.update();
+    app.update();
+    app.update();
+
+    let velocity = app.world().get::<LinearVelocity>(lobbed).unwrap().0;
+    assert_eq!(velocity.x, 100.0);
+    assert!(velocity.y < 100.0 && velocity.y >= 80.0 - 1e-3);
+}
+
+#[test]
+fn piercing_projectiles_pass_through_that_many_targets() {
+    let mut app = build_app();
+    let world = app.world_mut();
+    let targets: Vec<Entity> = [0.0, 100.0, 200.0]
+        .into_iter()
+        .map(|x| {
+            world
+                .spawn((
+                    Destructible::new(100.0),
+                    Collider::circle(10.0),
+                    placed(x, 0.0),
+                ))
+                .id()
+        })
+        .collect();
+    let shot = world
+        .spawn((
+            Projectile {
+                damage: 10.0,
+                pierce: 1,
+                ..default()
+            },
+            Collider::circle(5.0),
+            placed(0.0, 0.0),
+        ))
+        .id();
+    app.update();
+    assert_eq!(health(&app, targets[0]), 90.0);
+    // Still touching the first target doesn't hit it again
+    app.update();
+    assert_eq!(health(&app, targets[0]), 90.0);
+    assert_eq!(app.world().get::<Projectile>(shot).unwrap().pierce, 0);
+
+    app.world_mut().entity_mut(shot).insert(placed(100.0, 0.0));
+    app.update();
+    assert_eq!(health(&app, targets[1]), 90.0);
+    assert_eq!(health(&app, targets[2]), 100.0);
+    assert!(app.world().get_entity(shot).is_err());
+}
+
+#[test]
+fn bouncing_projectiles_reflect_off_solid_colliders() {
+    let mut app = build_app();
+    let world = app.world_mut();
+    world.spawn((
+        Collider::rectangle(Vec2::new(20.0, 100.0)),
+        placed(12.0, 0.0),
+    ));
+    let shot = world
+        .spawn((
+            Projectile {
+                bounces: 1,
+                ..default()
+            },
+            Collider::circle(5.0),
+            LinearVelocity(Vec2::new(100.0, 0.0)),
+            placed(0.0, 0.0),
+        ))
+        .id();
+    app.update();
+    app.update();
+
+    let projectile = app.world().get::<Projectile>(shot).unwrap();
+    assert_eq!(projectile.bounces, 0);
+    assert!(app.world().get::<LinearVelocity>(shot).unwrap().0.x < 0.0);
+}
+
+#[test]
+fn explosions_damage_destructibles_within_their_radius() {
+    let mut app = build_app();
+    let world = app.world_mut();
+    let near = world
+        .spawn((Destructible::new(100.0), placed(50.0, 0.0)))
+        .id();
+    let far = world
+        .spawn((Destructible::new(100.0), placed(150.0, 0.0)))
+        .id();
+    let shell = world
+        .spawn((
+            Projectile {
+                lifetime: 0.0,
+                explosion_radius: 100.0,
+                explosion_damage: 50.0,
+                explosion_falloff: Falloff::Constant,
+                ..default()
+            },
+            placed(0.0, 0.0),
+        ))
+        .id();
+    app.update();
+
+    assert_eq!(health(&app, near), 50.0);
+    assert_eq!(health(&app, far), 100.0);
+    assert!(app.world().get_entity(shell).is_err());
+}
@@ -0,0 +1,103 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for the events sent by scene loading, saving, and clearing.
+
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::*;
+use sandbox_engine::scene::{
+    load_scene, new_scene, save_scene, save_scene_copy, SceneCleared, SceneLoadFailed, SceneLoaded,
+    SceneManager, SceneSaved,
+};
+
+mod common;
+use common::{build_app, temp_scene_path};
+
+/// Every event of type `E` sent so far.
+fn sent<E: Event + Clone>(world: &World) -> Vec<E> {
+    let events = world.resource::<Events<E>>();
+    EventCursor::<E>::default().read(events).cloned().collect()
+}
+
+#[test]
+fn saving_loading_and_clearing_send_events() {
+    let path = temp_scene_path("lifecycle");
+    let mut app = build_app();
+    let world = app.world_mut();
+    world.spawn((Name::new("Rock"), Transform::default()));
+
+    save_scene(world, &path).unwrap();
+    assert_eq!(
+        sent::<SceneSaved>(world),
+        vec![SceneSaved { path: path.clone() }]
+    );
+
+    load_scene(world, &path).unwrap();
+    assert_eq!(
+        sent::<SceneLoaded>(world),
+        vec![SceneLoaded { path: path.clone() }]
+    );
+    assert!(sent::<SceneCleared>(world).is_empty());
+
+    new_scene(world);
+    assert_eq!(sent::<SceneCleared>(world), vec![SceneCleared]);
+    assert!(sent::<SceneLoadFailed>(world).is_empty());
+
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn a_failed_load_sends_the_error_and_keeps_the_scene() {
+    let path = temp_scene_path("missing");
+    let mut app = build_app();
+    let world = app.world_mut();
+    world.spawn((Name::new("Rock"), Transform::default()));
+
+    assert!(load_scene(world, &path).is_err());
+    let failures = sent::<SceneLoadFailed>(world);
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].path, path);
+    assert!(
+        failures[0].error.starts_with("Failed to read scene file"),
+        "{}",
+        failures[0].error
+    );
+    assert!(sent::<SceneLoaded>(world).is_empty());
+    assert_eq!(world.query::<&Name>().iter(world).count(), 1);
+}
+
+#[test]
+fn scene_functions_work_without_the_events() {
+    let path = temp_scene_path("bare");
+    let mut world = World::new();
+    world.init_resource::<AppTypeRegistry>();
+    world.spawn(Transform::default());
+    save_scene(&mut world, &path).unwrap();
+    load_scene(&mut world, &path).unwrap();
+    new_scene(&mut world);
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn copies_are_written_without_touching_the_open_scene() {
+    let path = temp_scene_path("original");
+    let copy = temp_scene_path("copy");
+    let mut app = build_app();
+    let world = app.world_mut();
+    world.spawn((Name::new("Rock"), Transform::default()));
+    save_scene(world, &path).unwrap();
+    world.resource_mut::<SceneManager>().mark_dirty();
+
+    save_scene_copy(world, &copy).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&copy).unwrap(),
+        std::fs::read_to_string(&path).unwrap()
+    );
+    let manager = world.resource::<SceneManager>();
+    assert_eq!(manager.current_scene_path.as_ref(), Some(&path));
+    assert!(manager.dirty);
+    assert_eq!(sent::<SceneSaved>(world).len(), 1);
+
+    std::fs::remove_file(&path).ok();
+    std::fs::remove_file(&copy).ok();
+}
@@ -0,0 +1,69 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for choosing which entities are saved with the scene.
+
+use bevy::prelude::*;
+use sandbox_engine::scene::{
+    load_scene, save_scene, scene_entities, SceneExcluded, SceneFilter, SceneManager,
+    SceneSerialized,
+};
+
+mod common;
+use common::{build_app, temp_scene_path};
+
+#[test]
+fn markers_override_the_default_filter() {
+    let mut app = build_app();
+    let world = app.world_mut();
+    let ship = world.spawn((Name::new("Ship"), Transform::default())).id();
+    let logic = world
+        .spawn((Name::new("Quest Logic"), SceneSerialized))
+        .id();
+    world.spawn((Name::new("Helper"), Transform::default(), SceneExcluded));
+    world.spawn((Name::new("Unmarked Logic"),));
+    world.spawn((Camera2d, SceneSerialized, SceneExcluded));
+    world.spawn((Node::default(), Transform::default()));
+
+    let mut expected = vec![ship, logic];
+    expected.sort();
+    assert_eq!(scene_entities(world), expected);
+}
+
+#[test]
+fn transform_less_entities_round_trip_when_marked() {
+    let mut app = build_app();
+    let world = app.world_mut();
+    world.spawn((Name::new("Quest Logic"), SceneSerialized));
+    let path = temp_scene_path("marked");
+    save_scene(world, &path).unwrap();
+
+    world.clear_entities();
+    load_scene(world, &path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    let mut query = world.query_filtered::<&Name, With<SceneSerialized>>();
+    let names: Vec<&str> = query.iter(world).map(Name::as_str).collect();
+    assert_eq!(names, ["Quest Logic"]);
+}
+
+#[test]
+fn scene_manager_filter_decides_for_unmarked_entities() {
+    let mut app = build_app();
+    let world = app.world_mut();
+    let ship = world.spawn((Name::new("Ship"), Transform::default())).id();
+    let marked = world
+        .spawn((Name::new("Beacon"), Transform::default(), SceneSerialized))
+        .id();
+
+    world.resource_mut::<SceneManager>().filter = SceneFilter::MarkedOnly;
+    assert_eq!(scene_entities(world), vec![marked]);
+
+    world.resource_mut::<SceneManager>().filter = SceneFilter::Custom(|entity| {
+        entity
+            .get::<Name>()
+            .is_some_and(|name| name.as_str() == "Ship")
+    });
+    let mut expected = vec![ship, marked];
+    expected.sort();
+    assert_eq!(scene_entities(world), expected);
+}
@@ -0,0 +1,148 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for parent/child hierarchies through scene save/load.
+
+use bevy::prelude::*;
+use sandbox_engine::scene::{instantiate_prefab, load_scene, save_scene};
+
+mod common;
+use common::{build_app, temp_scene_path};
+
+fn named(world: &mut World, name: &str) -> Vec<Entity> {
+    let mut query = world.query::<(Entity, &Name)>();
+    query
+        .iter(world)
+        .filter(|(_, entity_name)| entity_name.as_str() == name)
+        .map(|(entity, _)| entity)
+        .collect()
+}
+
+/// Names of `entity`'s children, in order.
+fn child_names(world: &World, entity: Entity) -> Vec<String> {
+    world
+        .get::<Children>(entity)
+        .map(|children| {
+            children
+                .iter()
+                .map(|child| world.get::<Name>(*child).unwrap().to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn spawn_named(world: &mut World, name: &str) -> Entity {
+    world
+        .spawn((Name::new(name.to_string()), Transform::default()))
+        .id()
+}
+
+#[test]
+fn hierarchies_and_child_order_survive_save_and_load() {
+    let mut app = build_app();
+    let world = app.world_mut();
+    let ship = spawn_named(world, "Ship");
+    // Spawned in one order, arranged in another
+    let [engine, hull, turret] = ["Engine", "Hull", "Turret"].map(|name| spawn_named(world, name));
+    let barrel = spawn_named(world, "Barrel");
+    world.entity_mut(ship).add_children(&[turret, engine, hull]);
+    world.entity_mut(turret).add_child(barrel);
+
+    let path = temp_scene_path("order");
+    save_scene(world, &path).unwrap();
+    load_scene(world, &path).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    let ships = named(world, "Ship");
+    assert_eq!(ships.len(), 1);
+    assert!(world.get::<Parent>(ships[0]).is_none());
+    assert_eq!(child_names(world, ships[0]), ["Turret", "Engine", "Hull"]);
+    let turret = named(world, "Turret")[0];
+    assert_eq!(world.get::<Parent>(turret).unwrap().get(), ships[0]);
+    let barrel = named(world, "Barrel")[0];
+    assert_eq!(world.get::<Parent>(barrel).unwrap().get(), turret);
+    assert_eq!(child_names(world, turret), ["Barrel"]);
+}
+
+#[test]
+fn only_entities_whose_ancestors_are_saved_belong_to_the_scene() {
+    let mut app = build_app();
+    let world = app.world_mut();
+    // A camera's children are runtime too
+    let camera = world.spawn((Camera2d, Transform::default())).id();
+    let reticle = spawn_named(world, "Reticle");
+    world.entity_mut(camera).add_child(reticle);
+    // Children without a transform aren't saved, but their parent is
+    let station = spawn_named(world, "Station");
+    let light = world.spawn(Name::new("Light")).id();
+    world.entity_mut(station).add_child(light);
+
+    let path = temp_scene_path("unsaved");
+    save_scene(world, &path).unwrap();
+    let saved = std::fs::read_to_string(&path).unwrap();
+    assert!(!saved.contains("Reticle"));
+    load_scene(world, &path).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    // The reticle is left alone, not cleared or duplicated
+    assert_eq!(named(world, "Reticle"), [reticle]);
+    assert_eq!(world.get::<Parent>(reticle).unwrap().get(), camera);
+    assert!(named(world, "Light").is_empty());
+    let station = named(world, "Station")[0];
+    assert!(world
+        .get::<Children>(station)
+        .is_none_or(|children| children.is_empty()));
+}
+
+#[test]
+fn references_to_entities_missing_from_the_file_are_dropped() {
+    let path = temp_scene_path("missing");
+    // A child listing a parent that isn't in the file, and a parent listing a missing child
+    std::fs::write(
+        &path,
+        r#"(resources: {}, entities: {
+            4294967296: (components: {
+                "bevy_core::name::Name": "Orphan",
+                "bevy_hierarchy::components::parent::Parent": (4294967297),
+            }),
+            4294967298: (components: {
+                "bevy_core::name::Name": "Lonely",
+                "bevy_hierarchy::components::children::Children": ([4294967299]),
+            }),
+        })"#,
+    )
+    .unwrap();
+    let mut app = build_app();
+    let world = app.world_mut();
+    load_scene(world, &path).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    let orphan = named(world, "Orphan")[0];
+    assert!(world.get::<Parent>(orphan).is_none());
+    let lonely = named(world, "Lonely")[0];
+    assert!(world
+        .get::<Children>(lonely)
+        .is_none_or(|children| children.is_empty()));
+}
+
+#[test]
+fn prefab_hierarchies_are_instantiated_per_copy() {
+    let mut app = build_app();
+    let world = app.world_mut();
+    let station = spawn_named(world, "Station");
+    let [dock, beacon] = ["Dock", "Beacon"].map(|name| spawn_named(world, name));
+    world.entity_mut(station).add_children(&[beacon, dock]);
+    let path = temp_scene_path("prefab");
+    save_scene(world, &path).unwrap();
+
+    instantiate_prefab(world, &path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    let stations = named(world, "Station");
+    assert_eq!(stations.len(), 2);
+    for station in stations {
+        assert_eq!(child_names(world, station), ["Beacon", "Dock"]);
+        for child in world.get::<Children>(station).unwrap() {
+            assert_eq!(world.get::<Parent>(*child).unwrap().get(), station);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for composing scenes from `SceneInstance`s.
+
+mod common;
+
+use bevy::prelude::*;
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::{load_scene, new_scene, save_scene, SceneInstance};
+use std::path::{Path, PathBuf};
+
+/// A project in a fresh temporary directory.
+fn build_app(name: &str) -> (App, PathBuf) {
+    let dir = common::temp_dir(name);
+    std::fs::create_dir_all(dir.join("assets/chunks")).unwrap();
+    let mut app = common::build_app();
+    app.add_plugins(MinimalPlugins)
+        .insert_resource(ProjectRoot::new(&dir));
+    (app, dir)
+}
+
+fn named(world: &mut World, name: &str) -> Vec<Entity> {
+    let mut query = world.query::<(Entity, &Name)>();
+    query
+        .iter(world)
+        .filter(|(_, entity_name)| entity_name.as_str() == name)
+        .map(|(entity, _)| entity)
+        .collect()
+}
+
+fn spawn_named(world: &mut World, name: &str, x: f32) -> Entity {
+    world
+        .spawn((
+            Name::new(name.to_string()),
+            Transform::from_xyz(x, 0.0, 0.0),
+        ))
+        .id()
+}
+
+/// Saves a scene of a rock with a tree on it to `chunks/grove.scn.ron`.
+fn save_grove(world: &mut World, dir: &Path) {
+    new_scene(world);
+    let rock = spawn_named(world, "Rock", 5.0);
+    let tree = spawn_named(world, "Tree", 1.0);
+    world.entity_mut(rock).add_child(tree);
+    save_scene(world, &dir.join("assets/chunks/grove.scn.ron")).unwrap();
+    new_scene(world);
+}
+
+#[test]
+fn instances_spawn_their_scene_as_children_and_save_only_the_reference() {
+    let (mut app, dir) = build_app("spawn");
+    let world = app.world_mut();
+    save_grove(world, &dir);
+
+    let room = world
+        .spawn((
+            Name::new("Room"),
+            SceneInstance::new("chunks/grove.scn.ron"),
+            Transform::from_xyz(100.0, 0.0, 0.0),
+        ))
+        .id();
+    let level = dir.join("assets/level.scn.ron");
+    save_scene(world, &level).unwrap();
+    let saved = std::fs::read_to_string(&level).unwrap();
+    assert!(saved.contains("chunks/grove.scn.ron"));
+    assert!(
+        !saved.contains("Rock"),
+        "instance contents were saved:\n{}",
+        saved
+    );
+    world.despawn(room);
+
+    // Loading spawns the instance's scene under it, twice over
+    load_scene(world, &level).unwrap();
+    load_scene(world, &level).unwrap();
+    let room = named(world, "Room")[0];
+    let rocks = named(world, "Rock");
+    assert_eq!(rocks.len(), 1);
+    assert_eq!(world.get::<Parent>(rocks[0]).unwrap().get(), room);
+    assert_eq!(world.get::<Transform>(rocks[0]).unwrap().translation.x, 5.0);
+    let tree = named(world, "Tree")[0];
+    assert_eq!(world.get::<Parent>(tree).unwrap().get(), rocks[0]);
+    assert_eq!(world.get::<Transform>(room).unwrap().translation.x, 100.0);
+
+    // Already spawned, so nothing happens on update
+    app.update();
+    assert_eq!(named(app.world_mut(), "Rock"), rocks);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn instances_follow_their_reference_and_nest() {
+    let (mut app, dir) = build_app("nest");
+    let world = app.world_mut();
+    save_grove(world, &dir);
+    // A forest of two groves, one of which includes the forest again
+    let east = world.spawn(SceneInstance::new("chunks/grove.scn.ron")).id();
+    let west = world
+        .spawn(SceneInstance::new("chunks/forest.scn.ron"))
+        .id();
+    save_scene(world, &dir.join("assets/chunks/forest.scn.ron")).unwrap();
+    world.entity_mut(west).despawn_recursive();
+    world.entity_mut(east).despawn_recursive();
+    // The open scene can't include itself either, so leave the forest file
+    new_scene(world);
+
+    let forest = world
+        .spawn(SceneInstance::new("chunks/forest.scn.ron"))
+        .id();
+    app.update();
+    let world = app.world_mut();
+    assert_eq!(named(world, "Rock").len(), 1, "the self-include is skipped");
+    let rock = named(world, "Rock")[0];
+    let grove = world.get::<Parent>(rock).unwrap().get();
+    assert_eq!(
+        world.get::<Parent>(grove).unwrap().get(),
+        forest,
+        "the grove is nested in the forest"
+    );
+
+    // Pointing elsewhere replaces the children; removing the instance clears them
+    world.get_mut::<SceneInstance>(forest).unwrap().scene = "chunks/grove.scn.ron".into();
+    app.update();
+    let world = app.world_mut();
+    let rock = named(world, "Rock")[0];
+    assert_eq!(world.get::<Parent>(rock).unwrap().get(), forest);
+    world.entity_mut(forest).remove::<SceneInstance>();
+    app.update();
+    assert!(named(app.world_mut(), "Rock").is_empty());
+    assert!(app.world().get::<Children>(forest).is_none());
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,161 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for JSON scene export and import.
+
+use bevy::prelude::*;
+use sandbox_engine::scene::{
+    load_scene, load_scene_json, save_scene, save_scene_as, save_scene_json, SceneError,
+    SceneFormat, SceneManager,
+};
+use std::path::Path;
+
+mod common;
+use common::{build_app, temp_path, temp_scene_path};
+
+#[test]
+fn json_scenes_round_trip_hierarchies_and_components() {
+    let path = temp_path("roundtrip", "scn.json");
+    let mut app = build_app();
+    let world = app.world_mut();
+    let ship = world
+        .spawn((Name::new("Ship"), Transform::from_xyz(1.0, 2.0, 0.0)))
+        .id();
+    let hull = world
+        .spawn((Name::new("Hull"), Transform::from_xyz(0.0, -1.0, 0.0)))
+        .id();
+    world.entity_mut(ship).add_child(hull);
+    save_scene_json(world, &path).unwrap();
+
+    let data = std::fs::read_to_string(&path).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&data).expect("scene is valid JSON");
+    assert_eq!(json["entities"].as_object().unwrap().len(), 2);
+
+    let mut app = build_app();
+    let world = app.world_mut();
+    load_scene_json(world, &path).unwrap();
+    let mut query = world.query::<(&Name, &Transform, Option<&Parent>)>();
+    let loaded: Vec<_> = query.iter(world).collect();
+    assert_eq!(loaded.len(), 2);
+    let (_, ship_transform, ship_parent) =
+        loaded.iter().find(|(n, ..)| n.as_str() == "Ship").unwrap();
+    assert_eq!(ship_transform.translation, Vec3::new(1.0, 2.0, 0.0));
+    assert!(ship_parent.is_none());
+    let (_, hull_transform, hull_parent) =
+        loaded.iter().find(|(n, ..)| n.as_str() == "Hull").unwrap();
+    assert_eq!(hull_transform.translation, Vec3::new(0.0, -1.0, 0.0));
+    let parent = hull_parent.expect("hull keeps its parent").get();
+    assert_eq!(world.get::<Name>(parent).unwrap().as_str(), "Ship");
+
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn malformed_json_is_a_deserialization_error() {
+    let path = temp_path("malformed", "scn.json");
+    std::fs::write(&path, "{ \"resources\": {}, \"entities\": ").unwrap();
+    let mut app = build_app();
+    let result = load_scene_json(app.world_mut(), &path);
+    assert!(matches!(result, Err(SceneError::Deserialization(_))));
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn the_extension_picks_the_format_and_loading_detects_it() {
+    let json_path = temp_path("detected", "scn.json");
+    let mut app = build_app();
+    let world = app.world_mut();
+    world.spawn((Name::new("Beacon"), Transform::from_xyz(3.0, 4.0, 0.0)));
+    save_scene(world, &json_path).unwrap();
+    let data = std::fs::read_to_string(&json_path).unwrap();
+    assert!(serde_json::from_str::<serde_json::Value>(&data).is_ok());
+
+    // Forcing RON under a JSON name still loads through `load_scene`
+    let ron_path = temp_path("forced_ron", "scn.json");
+    save_scene_as(world, &ron_path, SceneFormat::Ron).unwrap();
+    assert!(serde_json::from_str::<serde_json::Value>(
+        &std::fs::read_to_string(&ron_path).unwrap()
+    )
+    .is_err());
+
+    for path in [&json_path, &ron_path] {
+        let mut app = build_app();
+        let world = app.world_mut();
+        load_scene(world, path).unwrap();
+        let mut query = world.query::<(&Name, &Transform)>();
+        let (name, transform) = query.single(world);
+        assert_eq!(name.as_str(), "Beacon");
+        assert_eq!(transform.translation, Vec3::new(3.0, 4.0, 0.0));
+    }
+
+    std::fs::remove_file(&json_path).ok();
+    std::fs::remove_file(&ron_path).ok();
+}
+
+#[test]
+fn exports_leave_the_open_scene_and_json_scenes_save_as_json() {
+    let ron_path = temp_scene_path("open");
+    let export_path = temp_path("export", "scn.json");
+    let mut app = build_app();
+    let world = app.world_mut();
+    world.spawn((Name::new("Beacon"), Transform::default()));
+    save_scene(world, &ron_path).unwrap();
+    world.spawn((Name::new("Buoy"), Transform::default()));
+    world.resource_mut::<SceneManager>().mark_dirty();
+
+    // Exporting keeps the open scene on its RON file, still unsaved
+    save_scene_json(world, &export_path).unwrap();
+    let manager = world.resource::<SceneManager>();
+    assert_eq!(
+        manager.current_scene_path.as_deref(),
+        Some(ron_path.as_path())
+    );
+    assert!(manager.dirty);
+
+    // A JSON scene opened with load_scene_json saves back as JSON
+    load_scene_json(world, &export_path).unwrap();
+    assert_eq!(
+        world
+            .resource::<SceneManager>()
+            .current_scene_path
+            .as_deref(),
+        Some(export_path.as_path())
+    );
+    let path = world
+        .resource::<SceneManager>()
+        .current_scene_path
+        .clone()
+        .unwrap();
+    save_scene(world, &path).unwrap();
+    let data = std::fs::read_to_string(&export_path).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&data).expect("still JSON");
+    assert_eq!(json["entities"].as_object().unwrap().len(), 2);
+
+    std::fs::remove_file(&ron_path).ok();
+    std::fs::remove_file(&export_path).ok();
+}
+
+#[test]
+fn scene_formats_are_named_by_their_extensions() {
+    assert_eq!(
+        SceneFormat::from_path(Path::new("levels/one.scn.json")),
+        SceneFormat::Json
+    );
+    assert_eq!(
+        SceneFormat::from_path(Path::new("levels/one.scn.ron")),
+        SceneFormat::Ron
+    );
+    assert_eq!(
+        SceneFormat::from_file_name("one.scn.json"),
+        Some(SceneFormat::Json)
+    );
+    assert_eq!(SceneFormat::from_file_name("one.dialogue.ron"), None);
+    assert_eq!(
+        SceneFormat::detect("\n  {\"entities\": {}}"),
+        SceneFormat::Json
+    );
+    assert_eq!(
+        SceneFormat::detect("(\n  entities: {},\n)"),
+        SceneFormat::Ron
+    );
+}
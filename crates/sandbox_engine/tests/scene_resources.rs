@@ -0,0 +1,137 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for resources saved and restored with the scene.
+
+use bevy::prelude::*;
+use sandbox_engine::editor_state::{EditorPlayState, EditorStatePlugin};
+use sandbox_engine::scene::{
+    instantiate_prefab, load_scene, new_scene, save_scene, RegisterSceneResourceExt, ScenePlugin,
+};
+
+mod common;
+use common::{temp_path, temp_scene_path};
+
+#[derive(Resource, Reflect, Clone, Debug, PartialEq)]
+struct WorldSettings {
+    gravity: Vec2,
+    ambient: Color,
+}
+
+impl Default for WorldSettings {
+    fn default() -> Self {
+        Self {
+            gravity: Vec2::new(0.0, -9.8),
+            ambient: Color::WHITE,
+        }
+    }
+}
+
+/// Registered for reflection but not saved with the scene.
+#[derive(Resource, Reflect, Default, Clone, Debug, PartialEq)]
+#[reflect(Resource)]
+struct Score(u32);
+
+fn build_app() -> App {
+    let mut app = common::build_app();
+    app.register_type::<Score>()
+        .init_resource::<Score>()
+        .register_scene_resource::<WorldSettings>();
+    app
+}
+
+fn low_gravity() -> WorldSettings {
+    WorldSettings {
+        gravity: Vec2::new(0.0, -1.6),
+        ambient: Color::srgb(0.2, 0.3, 0.5),
+    }
+}
+
+#[test]
+fn registered_resources_round_trip_in_ron_and_json() {
+    for extension in ["scn.ron", "scn.json"] {
+        let path = temp_path("round_trip", extension);
+        let mut app = build_app();
+        let world = app.world_mut();
+        world.spawn((Name::new("Rock"), Transform::default()));
+        world.insert_resource(low_gravity());
+        world.insert_resource(Score(12));
+        save_scene(world, &path).unwrap();
+        let data = std::fs::read_to_string(&path).unwrap();
+        assert!(data.contains("WorldSettings"), "{}", data);
+        assert!(!data.contains("Score"), "{}", data);
+
+        world.insert_resource(WorldSettings::default());
+        world.insert_resource(Score(40));
+        load_scene(world, &path).unwrap();
+        assert_eq!(*world.resource::<WorldSettings>(), low_gravity());
+        assert_eq!(*world.resource::<Score>(), Score(40));
+
+        std::fs::remove_file(&path).ok();
+    }
+}
+
+#[test]
+fn scenes_without_a_resource_and_new_scenes_reset_it() {
+    let with = temp_scene_path("with");
+    let without = temp_scene_path("without");
+    // A scene saved before the resource was registered
+    let mut app = App::new();
+    app.add_plugins(ScenePlugin).register_type::<Transform>();
+    let world = app.world_mut();
+    world.insert_resource(low_gravity());
+    world.spawn(Transform::default());
+    save_scene(world, &without).unwrap();
+
+    let mut app = build_app();
+    let world = app.world_mut();
+    world.spawn(Transform::default());
+    world.insert_resource(low_gravity());
+    save_scene(world, &with).unwrap();
+    load_scene(world, &without).unwrap();
+    assert_eq!(*world.resource::<WorldSettings>(), WorldSettings::default());
+
+    load_scene(world, &with).unwrap();
+    assert_eq!(*world.resource::<WorldSettings>(), low_gravity());
+    new_scene(world);
+    assert_eq!(*world.resource::<WorldSettings>(), WorldSettings::default());
+
+    std::fs::remove_file(&with).ok();
+    std::fs::remove_file(&without).ok();
+}
+
+#[test]
+fn prefabs_leave_the_open_scenes_resources_alone() {
+    let prefab = temp_scene_path("prefab");
+    let mut app = build_app();
+    let world = app.world_mut();
+    world.spawn((Name::new("Crate"), Transform::default()));
+    world.insert_resource(low_gravity());
+    save_scene(world, &prefab).unwrap();
+
+    new_scene(world);
+    instantiate_prefab(world, &prefab).unwrap();
+    assert_eq!(*world.resource::<WorldSettings>(), WorldSettings::default());
+
+    std::fs::remove_file(&prefab).ok();
+}
+
+#[test]
+fn stopping_play_restores_scene_resources() {
+    let mut app = build_app();
+    app.add_plugins(bevy::state::app::StatesPlugin)
+        .add_plugins(EditorStatePlugin);
+    app.world_mut().insert_resource(low_gravity());
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<NextState<EditorPlayState>>()
+        .set(EditorPlayState::Playing);
+    app.update();
+    app.world_mut().resource_mut::<WorldSettings>().gravity = Vec2::ZERO;
+    app.world_mut()
+        .resource_mut::<NextState<EditorPlayState>>()
+        .set(EditorPlayState::Stopped);
+    app.update();
+    assert_eq!(*app.world().resource::<WorldSettings>(), low_gravity());
+}
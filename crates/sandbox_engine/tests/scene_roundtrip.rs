@@ -0,0 +1,467 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Property tests for scene save/load round-trips.
+//!
+//! Entities are generated from every component the engine's plugins register
+//! (plus `Transform` and `Name`), with field values filled in through
+//! reflection. A newly added component is therefore covered as soon as its
+//! plugin registers it with `#[reflect(Component, Default)]`.
+
+use bevy::prelude::*;
+use bevy::reflect::{DynamicEnum, PartialReflect, ReflectMut, ReflectRef, TypeInfo, TypeRegistry};
+use proptest::prelude::*;
+use proptest::test_runner::{FileFailurePersistence, TestCaseError};
+use sandbox_engine::ambient::AmbientSoundPlugin;
+use sandbox_engine::assets::AssetPathPlugin;
+use sandbox_engine::destructible::DestructiblePlugin;
+use sandbox_engine::dialogue::DialoguePlugin;
+use sandbox_engine::inspector::{default_value, default_variant};
+use sandbox_engine::interaction::InteractablePlugin;
+use sandbox_engine::joints::JointPlugin;
+use sandbox_engine::particles::ParticlePlugin;
+use sandbox_engine::physics::PhysicsPlugin;
+use sandbox_engine::projectile::ProjectilePlugin;
+use sandbox_engine::scene::{load_scene, save_scene, EditorNote, SceneExcluded, SceneLoadSettings};
+use sandbox_engine::sequence::SequencePlugin;
+use sandbox_engine::spawn::SpawnPointPlugin;
+use sandbox_engine::team::TeamPlugin;
+use sandbox_engine::time_control::TimeControlPlugin;
+use sandbox_engine::tint::GlobalTintPlugin;
+use sandbox_engine::wrap::WrapAroundPlugin;
+use std::any::TypeId;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+mod common;
+use common::temp_scene_path;
+
+/// Maximum nesting depth filled with random values.
+const MAX_DEPTH: usize = 6;
+
+/// Maximum length of generated lists.
+const MAX_LIST_LEN: u32 = 4;
+
+fn build_app() -> App {
+    let mut app = common::build_app();
+    app.add_plugins((
+        AssetPathPlugin,
+        (PhysicsPlugin, JointPlugin),
+        TimeControlPlugin,
+        InteractablePlugin,
+        SpawnPointPlugin,
+        WrapAroundPlugin,
+        DestructiblePlugin,
+        ProjectilePlugin,
+        TeamPlugin,
+        DialoguePlugin,
+        SequencePlugin,
+        GlobalTintPlugin,
+        ParticlePlugin,
+        AmbientSoundPlugin,
+    ))
+    // Round-trip editor-only components too, as the editor does
+    .insert_resource(SceneLoadSettings {
+        keep_editor_only: true,
+    });
+    app
+}
+
+/// Components under test: the engine's registered components plus `Transform` and `Name`.
+///
+/// Only types with a registered `Default` can be generated.
+fn component_types(registry: &TypeRegistry) -> Vec<(TypeId, &'static str)> {
+    let mut types: Vec<_> = registry
+        .iter()
+        .filter(|registration| {
+            registration.data::<ReflectComponent>().is_some()
+                && registration.data::<ReflectDefault>().is_some()
+        })
+        .map(|registration| {
+            let info = registration.type_info();
+            (info.type_id(), info.type_path())
+        })
+        .filter(|(type_id, type_path)| {
+            type_path.starts_with("sandbox_engine::")
+                || *type_id == TypeId::of::<Transform>()
+                || *type_id == TypeId::of::<Name>()
+        })
+        // Marks entities that are never saved
+        .filter(|(type_id, _)| *type_id != TypeId::of::<SceneExcluded>())
+        .collect();
+    types.sort_by_key(|(_, type_path)| *type_path);
+    types
+}
+
+/// Random choices drawn from proptest-generated numbers.
+///
+/// Shrinking the numbers toward zero shrinks the generated values toward
+/// defaults, so failures reduce to small cases.
+struct Entropy<'a> {
+    data: &'a [u32],
+    position: usize,
+}
+
+impl Entropy<'_> {
+    fn next(&mut self) -> u32 {
+        let value = self.data.get(self.position).copied().unwrap_or(0);
+        self.position += 1;
+        value
+    }
+
+    fn below(&mut self, bound: u32) -> u32 {
+        self.next() % bound.max(1)
+    }
+
+    /// A finite float that survives text serialization exactly.
+    fn float(&mut self) -> f64 {
+        (self.below(20_001) as f64 - 10_000.0) / 8.0
+    }
+
+    fn string(&mut self) -> String {
+        const ALPHABET: &[char] = &['a', 'Z', '0', ' ', '_', '"', '\\', '\n', 'é', '🚀'];
+        (0..self.below(8))
+            .map(|_| ALPHABET[self.below(ALPHABET.len() as u32) as usize])
+            .collect()
+    }
+}
+
+/// Overwrites a reflected value's fields with random values.
+///
+/// Opaque types other than primitives and strings (handles, entities) keep their defaults.
+fn randomize(
+    value: &mut dyn PartialReflect,
+    registry: &TypeRegistry,
+    entropy: &mut Entropy,
+    depth: usize,
+) {
+    if depth > MAX_DEPTH {
+        return;
+    }
+
+    macro_rules! random_leaf {
+        ($($ty:ty => $make:expr),* $(,)?) => {
+            $(
+                if let Some(v) = value.try_downcast_mut::<$ty>() {
+                    *v = $make;
+                    return;
+                }
+            )*
+        };
+    }
+    random_leaf!(
+        f32 => entropy.float() as f32,
+        f64 => entropy.float(),
+        bool => entropy.below(2) == 1,
+        u8 => entropy.next() as u8,
+        u16 => entropy.next() as u16,
+        u32 => entropy.next(),
+        u64 => entropy.next().into(),
+        usize => entropy.next() as usize,
+        i32 => entropy.next() as i32,
+        i64 => (entropy.next() as i32).into(),
+        String => entropy.string(),
+    );
+
+    if let (Some(TypeInfo::Enum(info)), ReflectRef::Enum(_)) =
+        (value.get_represented_type_info(), value.reflect_ref())
+    {
+        let mut variants: Vec<_> = info
+            .iter()
+            .filter_map(|variant| Some((variant.name(), default_variant(variant, registry)?)))
+            .collect();
+        if !variants.is_empty() {
+            let (name, fields) =
+                variants.swap_remove(entropy.below(variants.len() as u32) as usize);
+            let _ = value.try_apply(&DynamicEnum::new(name, fields));
+        }
+    }
+
+    let list_item = match value.get_represented_type_info() {
+        Some(TypeInfo::List(info)) => Some(info.item_ty().id()),
+        _ => None,
+    };
+
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => {
+            for index in 0..value.field_len() {
+                if let Some(field) = value.field_at_mut(index) {
+                    randomize(field, registry, entropy, depth + 1);
+                }
+            }
+        }
+        ReflectMut::TupleStruct(value) => {
+            for index in 0..value.field_len() {
+                if let Some(field) = value.field_mut(index) {
+                    randomize(field, registry, entropy, depth + 1);
+                }
+            }
+        }
+        ReflectMut::Tuple(value) => {
+            for index in 0..value.field_len() {
+                if let Some(field) = value.field_mut(index) {
+                    randomize(field, registry, entropy, depth + 1);
+                }
+            }
+        }
+        ReflectMut::Array(value) => {
+            for index in 0..value.len() {
+                if let Some(item) = value.get_mut(index) {
+                    randomize(item, registry, entropy, depth + 1);
+                }
+            }
+        }
+        ReflectMut::List(value) => {
+            let Some(item_type) = list_item else {
+                return;
+            };
+            for _ in 0..entropy.below(MAX_LIST_LEN + 1) {
+                if let Some(mut item) = default_value(item_type, registry) {
+                    randomize(item.as_mut(), registry, entropy, depth + 1);
+                    value.push(item);
+                }
+            }
+        }
+        ReflectMut::Enum(value) => {
+            for index in 0..value.field_len() {
+                if let Some(field) = value.field_at_mut(index) {
+                    randomize(field, registry, entropy, depth + 1);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Compares two reflected values field by field, naming the first differing field.
+fn compare_fields(
+    path: &str,
+    expected: &dyn PartialReflect,
+    actual: &dyn PartialReflect,
+) -> Result<(), String> {
+    let mismatch = || {
+        Err(format!(
+            "{}: expected {:?}, got {:?}",
+            path, expected, actual
+        ))
+    };
+
+    match (expected.reflect_ref(), actual.reflect_ref()) {
+        (ReflectRef::Struct(a), ReflectRef::Struct(b)) => {
+            for index in 0..a.field_len() {
+                let name = a.name_at(index).unwrap_or_default();
+                let (Some(a), Some(b)) = (a.field_at(index), b.field(name)) else {
+                    return mismatch();
+                };
+                compare_fields(&format!("{}.{}", path, name), a, b)?;
+            }
+            Ok(())
+        }
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => {
+            if a.field_len() != b.field_len() {
+                return mismatch();
+            }
+            for (index, (a, b)) in a.iter_fields().zip(b.iter_fields()).enumerate() {
+                compare_fields(&format!("{}.{}", path, index), a, b)?;
+            }
+            Ok(())
+        }
+        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => {
+            if a.field_len() != b.field_len() {
+                return mismatch();
+            }
+            for (index, (a, b)) in a.iter_fields().zip(b.iter_fields()).enumerate() {
+                compare_fields(&format!("{}.{}", path, index), a, b)?;
+            }
+            Ok(())
+        }
+        (ReflectRef::List(a), ReflectRef::List(b)) => {
+            if a.len() != b.len() {
+                return mismatch();
+            }
+            for (index, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+                compare_fields(&format!("{}[{}]", path, index), a, b)?;
+            }
+            Ok(())
+        }
+        (ReflectRef::Array(a), ReflectRef::Array(b)) => {
+            if a.len() != b.len() {
+                return mismatch();
+            }
+            for (index, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+                compare_fields(&format!("{}[{}]", path, index), a, b)?;
+            }
+            Ok(())
+        }
+        (ReflectRef::Enum(a), ReflectRef::Enum(b)) => {
+            if a.variant_name() != b.variant_name() || a.field_len() != b.field_len() {
+                return mismatch();
+            }
+            for (index, (a, b)) in a.iter_fields().zip(b.iter_fields()).enumerate() {
+                let name = a.name().map_or_else(|| index.to_string(), str::to_string);
+                compare_fields(
+                    &format!("{}::{}.{}", path, b.name().unwrap_or(""), name),
+                    a.value(),
+                    b.value(),
+                )?;
+            }
+            Ok(())
+        }
+        _ => {
+            // Entity references are remapped on load, so only their presence is compared
+            if expected.try_downcast_ref::<Entity>().is_some() {
+                return Ok(());
+            }
+            let equal = expected
+                .reflect_partial_eq(actual)
+                .unwrap_or_else(|| format!("{:?}", expected) == format!("{:?}", actual));
+            if equal {
+                Ok(())
+            } else {
+                mismatch()
+            }
+        }
+    }
+}
+
+/// A scene file for one round trip; proptest runs many in a row.
+fn next_scene_path() -> PathBuf {
+    static NEXT: AtomicU32 = AtomicU32::new(0);
+    temp_scene_path(&NEXT.fetch_add(1, Ordering::Relaxed).to_string())
+}
+
+/// Spawns generated entities, saves and reloads the scene, and compares every component.
+fn check_round_trip(entities: &[Vec<u32>]) -> Result<(), TestCaseError> {
+    let mut app = build_app();
+    let world = app.world_mut();
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let types = component_types(&type_registry);
+
+    // Every entity gets a `Transform` (so it is saved) and a unique `Name` (to match it after loading)
+    let mut expected = Vec::new();
+    for (index, data) in entities.iter().enumerate() {
+        let mut entropy = Entropy { data, position: 0 };
+        let name = format!("entity {}", index);
+        let mut entity = world.spawn((Name::new(name.clone()), Transform::default()));
+
+        let mut components = Vec::new();
+        for &(type_id, type_path) in &types {
+            let required = type_id == TypeId::of::<Transform>();
+            if type_id == TypeId::of::<Name>() || (!required && entropy.below(2) == 0) {
+                continue;
+            }
+            let registration = type_registry.get(type_id).unwrap();
+            let mut value = registration.data::<ReflectDefault>().unwrap().default();
+            randomize(
+                value.as_partial_reflect_mut(),
+                &type_registry,
+                &mut entropy,
+                0,
+            );
+            registration.data::<ReflectComponent>().unwrap().insert(
+                &mut entity,
+                value.as_partial_reflect(),
+                &type_registry,
+            );
+            components.push((type_id, type_path, value));
+        }
+        expected.push((name, components));
+    }
+
+    let path = next_scene_path();
+    let result = save_scene(world, &path).and_then(|()| load_scene(world, &path));
+    let _ = std::fs::remove_file(&path);
+    result.map_err(|e| TestCaseError::fail(format!("round trip failed: {}", e)))?;
+
+    for (name, components) in &expected {
+        let mut query = world.query::<(Entity, &Name)>();
+        let loaded: Vec<Entity> = query
+            .iter(world)
+            .filter(|(_, loaded_name)| loaded_name.as_str() == name)
+            .map(|(entity, _)| entity)
+            .collect();
+        prop_assert_eq!(loaded.len(), 1, "expected one {:?} after loading", name);
+        let entity = world.entity(loaded[0]);
+
+        for (type_id, type_path, value) in components {
+            let reflect_component = type_registry
+                .get(*type_id)
+                .and_then(|registration| registration.data::<ReflectComponent>())
+                .unwrap();
+            let loaded = reflect_component.reflect(entity).ok_or_else(|| {
+                TestCaseError::fail(format!("{}: {} missing after loading", name, type_path))
+            })?;
+            compare_fields(
+                &format!("{} {}", name, type_path),
+                value.as_partial_reflect(),
+                loaded.as_partial_reflect(),
+            )
+            .map_err(TestCaseError::fail)?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn engine_components_are_covered() {
+    let app = build_app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().read();
+    let types = component_types(&type_registry);
+    // Guards against the generator silently testing nothing
+    let paths: Vec<&str> = types.iter().map(|(_, type_path)| *type_path).collect();
+    for expected in [
+        "sandbox_engine::assets::AssetPath",
+        "sandbox_engine::assets::SpriteAnimation",
+        "sandbox_engine::physics::Collider",
+    ] {
+        assert!(
+            paths.contains(&expected),
+            "{} missing from {:?}",
+            expected,
+            paths
+        );
+    }
+}
+
+#[test]
+fn editor_only_components_are_stripped_outside_the_editor() {
+    let mut app = build_app();
+    let world = app.world_mut();
+    world.spawn((
+        Name::new("noted"),
+        Transform::default(),
+        EditorNote {
+            text: "TODO: more asteroids".to_string(),
+            ..default()
+        },
+    ));
+    let path = next_scene_path();
+    save_scene(world, &path).unwrap();
+
+    load_scene(world, &path).unwrap();
+    let mut notes = world.query::<&EditorNote>();
+    assert_eq!(notes.iter(world).count(), 1, "the editor keeps notes");
+
+    world.resource_mut::<SceneLoadSettings>().keep_editor_only = false;
+    load_scene(world, &path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(notes.iter(world).count(), 0, "games drop notes");
+    let mut names = world.query::<&Name>();
+    assert!(names.iter(world).any(|name| name.as_str() == "noted"));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 64,
+        failure_persistence: Some(Box::new(FileFailurePersistence::WithSource("regressions"))),
+        ..ProptestConfig::default()
+    })]
+
+    #[test]
+    fn scene_round_trip_preserves_components(
+        entities in prop::collection::vec(prop::collection::vec(any::<u32>(), 0..96), 1..5)
+    ) {
+        check_round_trip(&entities)?;
+    }
+}
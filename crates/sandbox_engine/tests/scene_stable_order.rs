@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for deterministic, diff-friendly scene files.
+
+use bevy::prelude::*;
+use sandbox_engine::scene::{load_scene, save_scene, SceneEntityId};
+
+mod common;
+use common::{build_app, temp_scene_path};
+
+fn spawn_named(world: &mut World, name: &str, x: f32) -> Entity {
+    world
+        .spawn((
+            Name::new(name.to_string()),
+            Transform::from_xyz(x, 0.0, 0.0),
+        ))
+        .id()
+}
+
+/// Spawns and despawns entities so the next spawns reuse ids out of order.
+fn churn_entity_ids(world: &mut World) {
+    let spares: Vec<Entity> = (0..8).map(|_| world.spawn_empty().id()).collect();
+    for entity in spares.into_iter().step_by(3) {
+        world.despawn(entity);
+    }
+}
+
+#[test]
+fn resaving_an_unchanged_scene_is_byte_identical() {
+    let path = temp_scene_path("resave");
+    let mut app = build_app();
+    let world = app.world_mut();
+    let ship = spawn_named(world, "Ship", 0.0);
+    let hull = spawn_named(world, "Hull", 1.0);
+    let engine = spawn_named(world, "Engine", 2.0);
+    world.entity_mut(ship).add_children(&[engine, hull]);
+    spawn_named(world, "Rock", 3.0);
+    save_scene(world, &path).unwrap();
+    let first = std::fs::read_to_string(&path).unwrap();
+
+    // A fresh world, with ids handed out in a different order
+    let mut app = build_app();
+    let world = app.world_mut();
+    churn_entity_ids(world);
+    load_scene(world, &path).unwrap();
+    save_scene(world, &path).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), first);
+
+    // Loading on top of the loaded scene reuses more ids
+    load_scene(world, &path).unwrap();
+    save_scene(world, &path).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), first);
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn edits_only_touch_the_lines_of_changed_entities() {
+    let path = temp_scene_path("edits");
+    let mut app = build_app();
+    let world = app.world_mut();
+    for (index, name) in ["A", "B", "C"].into_iter().enumerate() {
+        spawn_named(world, name, index as f32);
+    }
+    save_scene(world, &path).unwrap();
+
+    let mut app = build_app();
+    let world = app.world_mut();
+    churn_entity_ids(world);
+    load_scene(world, &path).unwrap();
+    let mut names = world.query::<(Entity, &Name, &SceneEntityId)>();
+    let ids: Vec<(String, u32)> = names
+        .iter(world)
+        .map(|(_, name, id)| (name.to_string(), id.0))
+        .collect();
+    let b = names
+        .iter(world)
+        .find(|(_, name, _)| name.as_str() == "B")
+        .map(|(entity, _, _)| entity)
+        .unwrap();
+    world.despawn(b);
+    let d = spawn_named(world, "D", 3.0);
+    save_scene(world, &path).unwrap();
+
+    // The rest keep their keys and new entities go at the end
+    let reloaded = std::fs::read_to_string(&path).unwrap();
+    let order: Vec<usize> = ["\"A\"", "\"C\"", "\"D\""]
+        .iter()
+        .map(|name| reloaded.find(name).unwrap())
+        .collect();
+    assert!(order.is_sorted(), "{}", reloaded);
+    let key_of = |name: &str| ids.iter().find(|(n, _)| n == name).unwrap().1;
+    assert_eq!(
+        world.get::<SceneEntityId>(d),
+        Some(&SceneEntityId(key_of("C") + 1))
+    );
+
+    let mut app = build_app();
+    let world = app.world_mut();
+    load_scene(world, &path).unwrap();
+    let mut names = world.query::<(&Name, &SceneEntityId)>();
+    let mut reloaded: Vec<(String, u32)> = names
+        .iter(world)
+        .map(|(name, id)| (name.to_string(), id.0))
+        .collect();
+    reloaded.sort_by_key(|(_, id)| *id);
+    assert_eq!(
+        reloaded,
+        [
+            ("A".to_string(), key_of("A")),
+            ("C".to_string(), key_of("C")),
+            ("D".to_string(), key_of("C") + 1),
+        ]
+    );
+    std::fs::remove_file(&path).ok();
+}
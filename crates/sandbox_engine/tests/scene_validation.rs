@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for checking a scene before it is saved.
+
+mod common;
+
+use bevy::prelude::*;
+use sandbox_engine::assets::AssetPath;
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::{validate_scene, SceneIssue};
+use std::path::Path;
+
+/// A game component someone forgot to register.
+#[derive(Component)]
+struct Forgotten;
+
+fn build_app(project: &Path) -> App {
+    let mut app = common::build_app();
+    app.register_type::<AssetPath>()
+        .insert_resource(ProjectRoot::new(project));
+    app
+}
+
+#[test]
+fn a_clean_scene_has_no_issues() {
+    let project = common::temp_dir("clean");
+    std::fs::create_dir_all(project.join("assets/sprites")).unwrap();
+    std::fs::write(project.join("assets/sprites/ship.png"), [0u8; 4]).unwrap();
+    let mut app = build_app(&project);
+    let world = app.world_mut();
+    let ship = world
+        .spawn((
+            Name::new("Ship"),
+            Transform::default(),
+            AssetPath::new("sprites/ship.png"),
+        ))
+        .id();
+    world
+        .spawn((Name::new("Hull"), Transform::default()))
+        .set_parent(ship);
+    assert_eq!(validate_scene(app.world()), Vec::new());
+    std::fs::remove_dir_all(&project).ok();
+}
+
+#[test]
+fn issues_are_found_on_saved_entities() {
+    let project = common::temp_dir("issues");
+    std::fs::create_dir_all(project.join("assets")).unwrap();
+    let mut app = build_app(&project);
+    let world = app.world_mut();
+    let crate_entity = world
+        .spawn((Name::new("Crate"), Transform::default(), Forgotten))
+        .id();
+    let gone = world.spawn(Transform::default()).id();
+    let orphan = world
+        .spawn((Name::new("Orphan"), Transform::default()))
+        .set_parent(gone)
+        .id();
+    // Despawn only the parent, leaving the child's Parent dangling
+    world.entity_mut(gone).remove::<Children>();
+    world.despawn(gone);
+    let rock = world
+        .spawn((
+            Transform::from_xyz(f32::NAN, 0.0, 0.0),
+            AssetPath::new("sprites/rock.png"),
+        ))
+        .id();
+    // Entities that aren't saved aren't checked, nor are their children
+    let camera = world
+        .spawn((Camera2d, Transform::from_xyz(f32::NAN, 0.0, 0.0), Forgotten))
+        .id();
+    world
+        .spawn((Transform::from_xyz(f32::NAN, 0.0, 0.0), Forgotten))
+        .set_parent(camera);
+
+    let issues = validate_scene(app.world());
+    assert_eq!(
+        issues,
+        vec![
+            SceneIssue::UnregisteredComponent {
+                entity: crate_entity,
+                type_name: std::any::type_name::<Forgotten>().to_string(),
+            },
+            SceneIssue::DanglingParent {
+                entity: orphan,
+                parent: gone,
+            },
+            SceneIssue::MissingAsset {
+                entity: rock,
+                path: "sprites/rock.png".to_string(),
+            },
+            SceneIssue::InvalidTransform { entity: rock },
+        ]
+    );
+    assert_eq!(issues[2].entity(), rock);
+    assert_eq!(issues[2].message(), "Missing asset 'sprites/rock.png'");
+    std::fs::remove_dir_all(&project).ok();
+}
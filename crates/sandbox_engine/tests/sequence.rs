@@ -0,0 +1,149 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for sequence timelines and playback.
+
+mod common;
+
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use sandbox_engine::dialogue::{ActiveDialogue, Dialogue};
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::sequence::{
+    Sequence, SequenceCommand, SequenceEvent, SequenceEventKind, SequencePlayback, SequencePlayer,
+    SequencePlugin, SequenceStep,
+};
+use std::time::Duration;
+
+const INTRO: &str = r#"(
+    steps: [
+        (command: MoveAlong(entity: "Ship", path: [(10.0, 0.0), (10.0, 10.0)], duration: 2.0)),
+        (command: CameraPan(to: (100.0, 0.0), duration: 1.0), with_previous: true),
+        (command: Wait(0.5)),
+        (command: ShowDialogue(dialogue: "dialogue/hello.dialogue.ron")),
+    ],
+)"#;
+
+fn build_app(project: &std::path::Path) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, SequencePlugin))
+        .insert_resource(ProjectRoot::new(project))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+    app
+}
+
+fn update(app: &mut App, frames: usize) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+fn position(app: &App, entity: Entity) -> Vec2 {
+    app.world()
+        .get::<Transform>(entity)
+        .unwrap()
+        .translation
+        .truncate()
+}
+
+#[test]
+fn steps_run_in_groups_on_the_timeline() {
+    let sequence: Sequence = bevy::scene::ron::from_str(INTRO).unwrap();
+    assert_eq!(
+        sequence.steps[1].command,
+        SequenceCommand::CameraPan {
+            to: Vec2::new(100.0, 0.0),
+            duration: 1.0
+        }
+    );
+    // The pan runs alongside the move, and the dialogue counts as three seconds here
+    assert_eq!(sequence.start_times(3.0), [0.0, 0.0, 2.0, 2.5]);
+    assert_eq!(sequence.length(3.0), 5.5);
+
+    let leading = Sequence {
+        steps: vec![SequenceStep {
+            command: SequenceCommand::Wait(1.0),
+            with_previous: true,
+        }],
+    };
+    assert_eq!(leading.start_times(0.0), [0.0]);
+}
+
+#[test]
+fn players_move_entities_pan_the_camera_and_wait_for_dialogue() {
+    let project = common::temp_dir("project");
+    std::fs::create_dir_all(project.join("assets/dialogue")).unwrap();
+    std::fs::create_dir_all(project.join("assets/sequences")).unwrap();
+    std::fs::write(project.join("assets/sequences/intro.sequence.ron"), INTRO).unwrap();
+    std::fs::write(
+        project.join("assets/dialogue/hello.dialogue.ron"),
+        r#"(start: "hi", nodes: { "hi": (lines: [(text: "Hello.")]) })"#,
+    )
+    .unwrap();
+
+    let mut app = build_app(&project);
+    let world = app.world_mut();
+    let ship = world
+        .spawn((Name::new("Ship"), Transform::from_xyz(0.0, 0.0, 5.0)))
+        .id();
+    let camera = world.spawn(Camera2d).id();
+    let player = world
+        .spawn(SequencePlayer::on_start("sequences/intro.sequence.ron"))
+        .id();
+    let mut cursor = EventCursor::<SequenceEvent>::default();
+    let mut kinds = |app: &App| -> Vec<_> {
+        let events = app.world().resource::<Events<SequenceEvent>>();
+        cursor
+            .read(events)
+            .map(|event| (event.source, event.kind))
+            .collect()
+    };
+
+    // The first frame has no elapsed time; then a second takes ten frames
+    update(&mut app, 1);
+    assert_eq!(kinds(&app), [(Some(player), SequenceEventKind::Started)]);
+    update(&mut app, 10);
+    assert!(app.world().resource::<SequencePlayback>().is_playing());
+    assert!(position(&app, ship).abs_diff_eq(Vec2::new(10.0, 0.0), 1e-3));
+    assert!(position(&app, camera).abs_diff_eq(Vec2::new(100.0, 0.0), 1e-3));
+    assert_eq!(
+        app.world().get::<Transform>(ship).unwrap().translation.z,
+        5.0
+    );
+
+    update(&mut app, 10);
+    assert!(position(&app, ship).abs_diff_eq(Vec2::new(10.0, 10.0), 1e-3));
+    assert!(!app.world().resource::<ActiveDialogue>().is_active());
+    update(&mut app, 6);
+    assert!(app.world().resource::<ActiveDialogue>().is_active());
+
+    // The sequence waits for the conversation to end
+    update(&mut app, 5);
+    assert!(app.world().resource::<SequencePlayback>().is_playing());
+    app.world_mut().resource_mut::<ActiveDialogue>().runner = None;
+    update(&mut app, 1);
+    assert!(!app.world().resource::<SequencePlayback>().is_playing());
+
+    assert_eq!(kinds(&app), [(Some(player), SequenceEventKind::Finished)]);
+    // Each player plays once
+    update(&mut app, 2);
+    assert!(!app.world().resource::<SequencePlayback>().is_playing());
+
+    std::fs::remove_dir_all(&project).unwrap();
+}
+
+#[test]
+fn the_spaceminer_intro_loads() {
+    let assets = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets");
+    let intro = Sequence::load(&assets.join("sequences/intro.sequence.ron")).unwrap();
+    assert_eq!(intro.start_times(0.0), [0.0, 1.5, 1.5, 1.5]);
+    for step in &intro.steps {
+        if let SequenceCommand::ShowDialogue { dialogue, .. } = &step.command {
+            let dialogue = Dialogue::load(&assets.join(dialogue)).unwrap();
+            assert!(dialogue.validate().is_empty());
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for finding spawn points at runtime.
+
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use sandbox_engine::spawn::{SpawnKind, SpawnPoint, SpawnPointPlugin, SpawnPoints, PLAYER_START};
+
+fn build_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(SpawnPointPlugin);
+    app
+}
+
+#[test]
+fn find_spawn_returns_the_world_transform_before_propagation() {
+    let mut app = build_app();
+    let world = app.world_mut();
+    let parent = world.spawn(Transform::from_xyz(100.0, 50.0, 0.0)).id();
+    world
+        .spawn((
+            SpawnPoint::new(PLAYER_START, SpawnKind::Player),
+            Transform::from_xyz(10.0, -5.0, 0.0),
+        ))
+        .set_parent(parent);
+    world.spawn((
+        SpawnPoint::new("ambush", SpawnKind::Enemy),
+        Transform::from_xyz(-300.0, 0.0, 0.0),
+    ));
+
+    let mut state = SystemState::<SpawnPoints>::new(world);
+    let spawns = state.get(world);
+    let start = spawns
+        .find_spawn(PLAYER_START)
+        .expect("player start exists");
+    assert_eq!(start.translation, Vec3::new(110.0, 45.0, 0.0));
+    assert_eq!(
+        spawns.find_spawn("ambush").map(|spawn| spawn.translation),
+        Some(Vec3::new(-300.0, 0.0, 0.0))
+    );
+    assert!(spawns.find_spawn("missing").is_none());
+}
+
+#[test]
+fn spawn_points_are_listed_by_kind_in_entity_order() {
+    let mut app = build_app();
+    let world = app.world_mut();
+    for (id, x) in [("wave_1", 0.0), ("wave_2", 64.0)] {
+        world.spawn((
+            SpawnPoint::new(id, SpawnKind::Enemy),
+            Transform::from_xyz(x, 0.0, 0.0),
+        ));
+    }
+    world.spawn(SpawnPoint::new("medkit", SpawnKind::Item));
+
+    let mut state = SystemState::<SpawnPoints>::new(world);
+    let spawns = state.get(world);
+    let enemies: Vec<_> = spawns
+        .of_kind(SpawnKind::Enemy)
+        .into_iter()
+        .map(|(spawn, transform)| (spawn.id.clone(), transform.translation.x))
+        .collect();
+    assert_eq!(
+        enemies,
+        vec![("wave_1".to_string(), 0.0), ("wave_2".to_string(), 64.0)]
+    );
+    assert_eq!(spawns.of_kind(SpawnKind::Player).len(), 0);
+}
@@ -0,0 +1,103 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for sizing texture-backed sprites by pixels per unit.
+
+use bevy::prelude::*;
+use sandbox_engine::assets::{AssetPath, AssetPathPlugin, AutoSized};
+use sandbox_engine::project::ProjectSettings;
+
+fn build_app(pixels_per_unit: f32) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Image>()
+        .insert_resource(ProjectSettings {
+            pixels_per_unit,
+            ..default()
+        })
+        .add_plugins(AssetPathPlugin);
+    app
+}
+
+/// A sprite showing a 16×32 px region of its texture, which needs no loaded image.
+fn crate_sprite() -> (Sprite, AssetPath) {
+    (
+        Sprite {
+            rect: Some(Rect::new(0.0, 0.0, 16.0, 32.0)),
+            ..default()
+        },
+        AssetPath::new("sprites/crate.png"),
+    )
+}
+
+fn custom_size(app: &App, entity: Entity) -> Option<Vec2> {
+    app.world().get::<Sprite>(entity).unwrap().custom_size
+}
+
+#[test]
+fn auto_sized_sprites_follow_the_texture_and_pixels_per_unit() {
+    let mut app = build_app(16.0);
+    let sprite = app.world_mut().spawn(crate_sprite()).id();
+    app.update();
+    app.update();
+    assert_eq!(custom_size(&app, sprite), Some(Vec2::new(1.0, 2.0)));
+    assert!(app.world().get::<AutoSized>(sprite).is_some());
+
+    app.world_mut()
+        .resource_mut::<ProjectSettings>()
+        .pixels_per_unit = 8.0;
+    app.update();
+    assert_eq!(custom_size(&app, sprite), Some(Vec2::new(2.0, 4.0)));
+
+    app.world_mut().get_mut::<Sprite>(sprite).unwrap().rect = Some(Rect::new(0.0, 0.0, 32.0, 32.0));
+    app.update();
+    assert_eq!(custom_size(&app, sprite), Some(Vec2::new(4.0, 4.0)));
+
+    // At one pixel per unit the texture's own size is used
+    app.world_mut()
+        .resource_mut::<ProjectSettings>()
+        .pixels_per_unit = 1.0;
+    app.update();
+    assert_eq!(custom_size(&app, sprite), None);
+    assert!(app.world().get::<AutoSized>(sprite).is_some());
+}
+
+#[test]
+fn sizes_set_or_cleared_by_hand_are_kept() {
+    let mut app = build_app(16.0);
+    let cleared = app.world_mut().spawn(crate_sprite()).id();
+    let resized = app.world_mut().spawn(crate_sprite()).id();
+    let (mut sprite, asset_path) = crate_sprite();
+    sprite.custom_size = Some(Vec2::splat(3.0));
+    let sized = app.world_mut().spawn((sprite, asset_path)).id();
+    app.update();
+    app.update();
+
+    app.world_mut()
+        .get_mut::<Sprite>(cleared)
+        .unwrap()
+        .custom_size = None;
+    app.world_mut()
+        .get_mut::<Sprite>(resized)
+        .unwrap()
+        .custom_size = Some(Vec2::splat(5.0));
+    app.update();
+    app.world_mut()
+        .resource_mut::<ProjectSettings>()
+        .pixels_per_unit = 8.0;
+    app.update();
+    app.update();
+
+    assert_eq!(custom_size(&app, cleared), None);
+    assert_eq!(custom_size(&app, resized), Some(Vec2::splat(5.0)));
+    assert_eq!(custom_size(&app, sized), Some(Vec2::splat(3.0)));
+    for entity in [cleared, resized, sized] {
+        assert!(app.world().get::<AutoSized>(entity).is_none());
+    }
+
+    // A new texture on a sprite without a size sizes it again
+    app.world_mut().get_mut::<AssetPath>(cleared).unwrap().path = "sprites/barrel.png".to_string();
+    app.update();
+    app.update();
+    assert_eq!(custom_size(&app, cleared), Some(Vec2::new(2.0, 4.0)));
+}
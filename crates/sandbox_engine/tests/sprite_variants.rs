@@ -0,0 +1,101 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for picking sprite textures from variants.
+
+use bevy::prelude::*;
+use sandbox_engine::assets::{AssetPath, AssetPathPlugin, SpriteVariant};
+
+const ROCKS: [&str; 4] = [
+    "sprites/rock_a.png",
+    "sprites/rock_b.png",
+    "sprites/rock_c.png",
+    "sprites/rock_d.png",
+];
+
+fn build_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Image>()
+        .add_plugins(AssetPathPlugin);
+    app
+}
+
+fn shown(app: &App, entity: Entity) -> (Option<usize>, Option<String>) {
+    let world = app.world();
+    (
+        world.get::<SpriteVariant>(entity).unwrap().selected,
+        world
+            .get::<AssetPath>(entity)
+            .map(|asset_path| asset_path.path.clone()),
+    )
+}
+
+#[test]
+fn random_picks_are_seeded_and_kept() {
+    let variants = SpriteVariant::random(ROCKS);
+    let entity = Entity::from_raw(7);
+    assert_eq!(variants.pick(entity), variants.pick(entity));
+    assert_eq!(SpriteVariant::default().pick(entity), None);
+
+    // Copies spread over the variants
+    let picks: Vec<usize> = (0..64)
+        .map(|index| variants.pick(Entity::from_raw(index)).unwrap())
+        .collect();
+    for variant in 0..ROCKS.len() {
+        assert!(picks.contains(&variant), "{:?}", picks);
+    }
+    let reseeded = SpriteVariant {
+        seed: 1,
+        ..variants.clone()
+    };
+    assert!((0..64)
+        .any(|index| reseeded.pick(Entity::from_raw(index))
+            != variants.pick(Entity::from_raw(index))));
+
+    // Spawning stores the pick and shows its texture
+    let mut app = build_app();
+    let rock = app
+        .world_mut()
+        .spawn((Sprite::default(), variants.clone()))
+        .id();
+    app.update();
+    let picked = variants.pick(rock).unwrap();
+    assert_eq!(
+        shown(&app, rock),
+        (Some(picked), Some(ROCKS[picked].to_string()))
+    );
+}
+
+#[test]
+fn explicit_selections_drive_the_asset_path() {
+    let mut app = build_app();
+    let rock = app
+        .world_mut()
+        .spawn((
+            Sprite::default(),
+            AssetPath::new("sprites/placeholder.png"),
+            SpriteVariant {
+                selected: Some(2),
+                ..SpriteVariant::random(ROCKS)
+            },
+        ))
+        .id();
+    app.update();
+    assert_eq!(shown(&app, rock), (Some(2), Some(ROCKS[2].to_string())));
+
+    app.world_mut()
+        .get_mut::<SpriteVariant>(rock)
+        .unwrap()
+        .selected = Some(0);
+    app.update();
+    assert_eq!(shown(&app, rock), (Some(0), Some(ROCKS[0].to_string())));
+
+    // An out-of-range selection leaves the texture alone
+    app.world_mut()
+        .get_mut::<SpriteVariant>(rock)
+        .unwrap()
+        .selected = Some(9);
+    app.update();
+    assert_eq!(shown(&app, rock), (Some(9), Some(ROCKS[0].to_string())));
+}
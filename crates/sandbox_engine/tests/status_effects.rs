@@ -0,0 +1,142 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for stacking and expiring status effects.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use sandbox_engine::editor_state::{EditorPlayState, EditorStatePlugin};
+use sandbox_engine::status_effect::{
+    apply_status, Status, StatusEffect, StatusEffectPlugin, StatusEvent, StatusEventKind,
+};
+use std::time::Duration;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+struct Slowed {
+    factor: f32,
+}
+
+impl StatusEffect for Slowed {}
+
+fn build_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatusEffectPlugin::<Slowed>::default()))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+    // The first frame has no delta
+    app.update();
+    app
+}
+
+fn slowed(factor: f32) -> Status<Slowed> {
+    Status::new(Slowed { factor })
+}
+
+fn event_kinds(app: &App) -> Vec<(StatusEventKind, u32)> {
+    let events = app.world().resource::<Events<StatusEvent<Slowed>>>();
+    events
+        .iter_current_update_events()
+        .map(|event| (event.kind, event.stacks))
+        .collect()
+}
+
+#[test]
+fn reapplying_adds_stacks_and_keeps_the_longer_duration() {
+    let mut app = build_app();
+    let ship = app.world_mut().spawn_empty().id();
+
+    let world = app.world_mut();
+    apply_status(
+        world,
+        ship,
+        slowed(0.5).with_duration(1.0).with_max_stacks(2),
+    );
+    apply_status(
+        world,
+        ship,
+        slowed(0.4).with_duration(0.3).with_max_stacks(2),
+    );
+    apply_status(
+        world,
+        ship,
+        slowed(0.3).with_duration(0.5).with_max_stacks(2),
+    );
+    let status = app.world().get::<Status<Slowed>>(ship).unwrap();
+    assert_eq!(status.stacks, 2);
+    assert_eq!(status.remaining, Some(1.0));
+    assert_eq!(status.effect, Slowed { factor: 0.3 });
+    assert_eq!(
+        event_kinds(&app),
+        [
+            (StatusEventKind::Applied, 1),
+            (StatusEventKind::Applied, 2),
+            (StatusEventKind::Applied, 2),
+        ]
+    );
+}
+
+#[test]
+fn timed_effects_expire_and_send_removed() {
+    let mut app = build_app();
+    let ship = app.world_mut().spawn_empty().id();
+    apply_status(app.world_mut(), ship, slowed(0.5).with_duration(0.25));
+
+    app.update();
+    app.update();
+    assert!(app.world().get::<Status<Slowed>>(ship).is_some());
+    app.update();
+    assert!(app.world().get::<Status<Slowed>>(ship).is_none());
+    assert_eq!(event_kinds(&app), [(StatusEventKind::Removed, 1)]);
+}
+
+#[test]
+fn permanent_effects_stay_permanent_when_a_timed_one_is_reapplied() {
+    let mut app = build_app();
+    let ship = app.world_mut().spawn_empty().id();
+
+    let world = app.world_mut();
+    apply_status(world, ship, slowed(0.5));
+    apply_status(world, ship, slowed(0.5).with_duration(0.1));
+    assert_eq!(
+        app.world().get::<Status<Slowed>>(ship).unwrap().remaining,
+        None
+    );
+    for _ in 0..5 {
+        app.update();
+    }
+    assert!(app.world().get::<Status<Slowed>>(ship).is_some());
+
+    // Re-applying a permanent effect over a timed one makes it permanent too
+    let other = app.world_mut().spawn_empty().id();
+    let world = app.world_mut();
+    apply_status(world, other, slowed(0.5).with_duration(0.1));
+    apply_status(world, other, slowed(0.5));
+    assert_eq!(
+        app.world().get::<Status<Slowed>>(other).unwrap().remaining,
+        None
+    );
+}
+
+#[test]
+fn statuses_applied_during_play_are_removed_on_stop() {
+    let mut app = build_app();
+    app.add_plugins((bevy::state::app::StatesPlugin, EditorStatePlugin));
+    let ship = app.world_mut().spawn(Transform::default()).id();
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<NextState<EditorPlayState>>()
+        .set(EditorPlayState::Playing);
+    app.update();
+    apply_status(app.world_mut(), ship, slowed(0.5).with_duration(10.0));
+    app.update();
+    assert!(app.world().get::<Status<Slowed>>(ship).is_some());
+
+    app.world_mut()
+        .resource_mut::<NextState<EditorPlayState>>()
+        .set(EditorPlayState::Stopped);
+    app.update();
+    assert!(app.world().get::<Status<Slowed>>(ship).is_none());
+    assert!(app.world().get::<Transform>(ship).is_some());
+}
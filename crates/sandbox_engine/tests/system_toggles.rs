@@ -0,0 +1,86 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for switching toggleable systems off at runtime.
+
+use bevy::prelude::*;
+use sandbox_engine::system_toggle::{SystemTogglePlugin, SystemToggles, ToggleableSystem};
+
+#[derive(Resource, Default)]
+struct Counts {
+    steer: u32,
+    fire: u32,
+}
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct Weapons;
+
+fn steer(mut counts: ResMut<Counts>) {
+    counts.steer += 1;
+}
+
+fn fire(mut counts: ResMut<Counts>) {
+    counts.fire += 1;
+}
+
+fn untoggleable() {}
+
+fn build_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(SystemTogglePlugin)
+        .init_resource::<Counts>()
+        .add_systems(
+            Update,
+            (
+                steer.toggleable(),
+                fire.toggleable().in_set(Weapons),
+                untoggleable,
+            )
+                .chain(),
+        );
+    app
+}
+
+fn name_of<M>(system: impl IntoSystem<(), (), M>) -> String {
+    IntoSystem::into_system(system).name().to_string()
+}
+
+#[test]
+fn switched_off_systems_skip_until_switched_back_on() {
+    let mut app = build_app();
+    app.update();
+    app.world_mut()
+        .resource_mut::<SystemToggles>()
+        .set_enabled(&name_of(fire), false);
+    app.update();
+    app.update();
+    let counts = app.world().resource::<Counts>();
+    assert_eq!((counts.steer, counts.fire), (3, 1));
+
+    app.world_mut().resource_mut::<SystemToggles>().enable_all();
+    app.update();
+    let counts = app.world().resource::<Counts>();
+    assert_eq!((counts.steer, counts.fire), (4, 2));
+}
+
+#[test]
+fn toggleable_systems_are_listed_by_their_sets() {
+    let mut app = build_app();
+    assert!(app.world().resource::<SystemToggles>().systems().is_empty());
+    app.update();
+
+    let listed: Vec<(String, String)> = app
+        .world()
+        .resource::<SystemToggles>()
+        .systems()
+        .iter()
+        .map(|system| (system.group.clone(), system.name.clone()))
+        .collect();
+    assert_eq!(
+        listed,
+        vec![
+            ("Other".to_string(), name_of(steer)),
+            ("Weapons".to_string(), name_of(fire)),
+        ]
+    );
+}
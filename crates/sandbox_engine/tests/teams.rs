@@ -0,0 +1,156 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for team relations, collision filtering, and target selection.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use sandbox_engine::destructible::{Destructible, DestructiblePlugin};
+use sandbox_engine::physics::{Collider, LinearVelocity, PhysicsContacts, PhysicsPlugin};
+use sandbox_engine::project::{ProjectRoot, ProjectSettings};
+use sandbox_engine::projectile::{Projectile, ProjectilePlugin};
+use sandbox_engine::team::{Relation, Team, TeamTable};
+use std::time::Duration;
+
+fn build_app(teams: TeamTable) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        PhysicsPlugin,
+        DestructiblePlugin,
+        ProjectilePlugin,
+    ))
+    .insert_resource(ProjectSettings { teams, ..default() })
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app
+}
+
+/// Transform and matching global transform, so contacts work on the first update.
+fn placed(x: f32, y: f32) -> (Transform, GlobalTransform) {
+    let transform = Transform::from_xyz(x, y, 0.0);
+    (transform, GlobalTransform::from(transform))
+}
+
+/// Team 0 is friendly with 1 and hostile to 2.
+fn three_teams() -> TeamTable {
+    let mut teams = TeamTable {
+        names: vec!["Miners".into(), "Traders".into(), "Pirates".into()],
+        ..default()
+    };
+    teams.set_relation(1, 0, Relation::Friendly);
+    teams.set_relation(0, 2, Relation::Hostile);
+    teams
+}
+
+#[test]
+fn relations_are_symmetric_and_saved_with_the_project() {
+    let mut teams = three_teams();
+    assert_eq!(teams.relation(0, 1), Relation::Friendly);
+    assert_eq!(teams.relation(2, 0), Relation::Hostile);
+    assert_eq!(teams.relation(1, 2), Relation::Neutral);
+    assert_eq!(teams.relation(2, 2), Relation::Friendly);
+    assert!(teams.are_allies(Some(&Team(0)), Some(&Team(1))));
+    assert!(!teams.are_allies(Some(&Team(0)), None));
+    assert!(teams.can_target(Some(&Team(0)), Some(&Team(2))));
+    assert!(!teams.can_target(Some(&Team(0)), Some(&Team(1))));
+    assert!(teams.can_target(Some(&Team(0)), None));
+    assert_eq!(teams.name(1), "Traders");
+    assert_eq!(teams.name(7), "Team 7");
+
+    let project = common::temp_dir("project");
+    let root = ProjectRoot::new(&project);
+    std::fs::write(root.project_file(), "// My game\n(pixels_per_unit: 16.0)\n").unwrap();
+    let mut settings = ProjectSettings::load(&root);
+    settings.teams = teams.clone();
+    settings.save(&root).unwrap();
+    assert!(std::fs::read_to_string(root.project_file())
+        .unwrap()
+        .starts_with("// My game\n"));
+    let loaded = ProjectSettings::load(&root);
+    assert_eq!(loaded.pixels_per_unit, 16.0);
+    assert_eq!(loaded.teams, teams);
+
+    // Removing a team drops its relations
+    teams.remove_last();
+    assert_eq!(teams.names.len(), 2);
+    assert_eq!(teams.relations.len(), 1);
+
+    std::fs::remove_dir_all(&project).unwrap();
+}
+
+#[test]
+fn friendly_colliders_do_not_touch() {
+    let mut app = build_app(three_teams());
+    let world = app.world_mut();
+    let miner = world
+        .spawn((Team(0), Collider::circle(10.0), placed(0.0, 0.0)))
+        .id();
+    let trader = world
+        .spawn((Team(1), Collider::circle(10.0), placed(5.0, 0.0)))
+        .id();
+    let pirate = world
+        .spawn((Team(2), Collider::circle(10.0), placed(-5.0, 0.0)))
+        .id();
+    app.update();
+
+    let touching = |a: Entity, b: Entity| {
+        app.world()
+            .resource::<PhysicsContacts>()
+            .contacts
+            .iter()
+            .any(|contact| (contact.a == a && contact.b == b) || (contact.a == b && contact.b == a))
+    };
+    assert!(!touching(miner, trader));
+    assert!(touching(miner, pirate));
+    assert!(touching(trader, pirate));
+}
+
+#[test]
+fn projectiles_home_in_on_hostile_teams_and_spare_allies() {
+    let mut app = build_app(three_teams());
+    let world = app.world_mut();
+    // The ally is nearer, above; the enemy is further, below
+    let ally = world
+        .spawn((
+            Team(1),
+            Destructible::new(100.0),
+            Collider::circle(10.0),
+            placed(0.0, 100.0),
+        ))
+        .id();
+    world.spawn((Team(2), Destructible::new(100.0), placed(0.0, -200.0)));
+    let missile = world
+        .spawn((
+            Team(0),
+            Projectile {
+                homing_turn_rate: 90.0,
+                explosion_radius: 200.0,
+                explosion_damage: 50.0,
+                ..default()
+            },
+            Collider::circle(5.0),
+            LinearVelocity(Vec2::new(100.0, 0.0)),
+            placed(0.0, 100.0),
+        ))
+        .id();
+    app.update();
+    app.update();
+
+    // Overlapping the ally neither hits it nor stops the missile
+    assert!(app.world().get::<LinearVelocity>(missile).unwrap().0.y < 0.0);
+    assert_eq!(app.world().get::<Destructible>(ally).unwrap().health, 100.0);
+
+    // Nor does the ally take damage when the missile explodes
+    app.world_mut()
+        .get_mut::<Projectile>(missile)
+        .unwrap()
+        .lifetime = 0.0;
+    app.update();
+    assert!(app.world().get_entity(missile).is_err());
+    assert_eq!(app.world().get::<Destructible>(ally).unwrap().health, 100.0);
+}
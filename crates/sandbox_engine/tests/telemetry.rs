@@ -0,0 +1,146 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for the telemetry events recorded by the engine.
+
+mod common;
+
+use bevy::prelude::*;
+use sandbox_engine::project::ProjectRoot;
+use sandbox_engine::scene::{load_scene, save_scene};
+use sandbox_engine::telemetry::{GameTelemetry, Telemetry, TelemetryPlugin, TelemetryValue};
+use std::sync::{Arc, Mutex};
+
+type Recorded = Vec<(String, Vec<(String, TelemetryValue)>)>;
+
+/// Backend that keeps every event for the test to inspect.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Recorded>>);
+
+impl GameTelemetry for Recorder {
+    fn record(&self, event: &str, fields: &[(&str, TelemetryValue)]) {
+        let fields = fields
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect();
+        self.0.lock().unwrap().push((event.to_string(), fields));
+    }
+}
+
+impl Recorder {
+    /// Takes the events recorded so far.
+    fn take(&self) -> Recorded {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+fn text(value: &str) -> TelemetryValue {
+    value.into()
+}
+
+#[test]
+fn sessions_scene_loads_and_errors_are_recorded() {
+    let project = common::temp_dir("project");
+    std::fs::create_dir_all(project.join("assets/scenes")).unwrap();
+    let recorder = Recorder::default();
+
+    let mut app = common::build_app();
+    app.add_plugins(MinimalPlugins)
+        .insert_resource(ProjectRoot::new(&project))
+        .insert_resource(Telemetry::new(recorder.clone()))
+        .add_plugins(TelemetryPlugin);
+    app.update();
+
+    let events = recorder.take();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].0, "session_start");
+    assert_eq!(
+        events[0].1[0],
+        (
+            "engine_version".to_string(),
+            text(env!("CARGO_PKG_VERSION"))
+        )
+    );
+
+    // Paths inside the assets directory are reported relative to it
+    let level = project.join("assets/scenes/level.scn.ron");
+    let world = app.world_mut();
+    world.spawn((Name::new("Rock"), Transform::default()));
+    save_scene(world, &level).unwrap();
+    load_scene(world, &level).unwrap();
+    assert!(load_scene(world, &project.join("assets/scenes/missing.scn.ron")).is_err());
+    app.update();
+
+    let events = recorder.take();
+    assert_eq!(
+        events[0],
+        (
+            "scene_loaded".to_string(),
+            vec![("path".to_string(), text("scenes/level.scn.ron"))]
+        )
+    );
+    assert_eq!(events[1].0, "error");
+    assert_eq!(
+        events[1].1[..2],
+        [
+            ("source".to_string(), text("scene")),
+            ("path".to_string(), text("scenes/missing.scn.ron")),
+        ]
+    );
+    assert!(
+        events[1].1[2]
+            .1
+            .to_string()
+            .starts_with("Failed to read scene file"),
+        "{:?}",
+        events[1].1[2]
+    );
+    assert_eq!(events.len(), 2);
+
+    app.world_mut().send_event(AppExit::Success);
+    app.update();
+    let events = recorder.take();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].0, "session_end");
+    assert!(matches!(
+        events[0].1[..],
+        [(ref name, TelemetryValue::Float(seconds))] if name == "duration_secs" && seconds >= 0.0
+    ));
+
+    std::fs::remove_dir_all(&project).ok();
+}
+
+#[test]
+fn games_record_their_own_events_and_default_to_no_backend() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, TelemetryPlugin));
+    app.update();
+    // Without a backend, events are dropped
+    app.world()
+        .resource::<Telemetry>()
+        .record("ore_sold", &[("amount", 12.into())]);
+
+    let recorder = Recorder::default();
+    app.insert_resource(Telemetry::new(recorder.clone()));
+    app.world().resource::<Telemetry>().record(
+        "ore_sold",
+        &[
+            ("amount", 12.into()),
+            ("station", "Vesta".into()),
+            ("price", 1.5f32.into()),
+            ("first_sale", true.into()),
+        ],
+    );
+    assert_eq!(
+        recorder.take(),
+        vec![(
+            "ore_sold".to_string(),
+            vec![
+                ("amount".to_string(), TelemetryValue::Int(12)),
+                ("station".to_string(), text("Vesta")),
+                ("price".to_string(), TelemetryValue::Float(1.5)),
+                ("first_sale".to_string(), TelemetryValue::Bool(true)),
+            ]
+        )]
+    );
+}
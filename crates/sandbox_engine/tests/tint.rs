@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for the time of day and global tint.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use sandbox_engine::tint::{
+    CurrentTint, GlobalTint, GlobalTintPlugin, TimeOfDay, TintGradient, TintKey,
+};
+use std::time::Duration;
+
+fn gradient() -> TintGradient {
+    TintGradient {
+        keys: vec![
+            TintKey {
+                hour: 18.0,
+                color: Color::linear_rgb(0.0, 0.0, 1.0),
+            },
+            TintKey {
+                hour: 6.0,
+                color: Color::linear_rgb(1.0, 1.0, 1.0),
+            },
+        ],
+    }
+}
+
+fn assert_color(actual: Color, expected: Color) {
+    let (actual, expected) = (actual.to_linear().to_vec4(), expected.to_linear().to_vec4());
+    assert!(
+        actual.distance(expected) < 1e-4,
+        "expected {:?}, got {:?}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn gradients_blend_between_keys_and_wrap_past_midnight() {
+    let gradient = gradient();
+    assert_color(gradient.sample(6.0), Color::linear_rgb(1.0, 1.0, 1.0));
+    assert_color(gradient.sample(12.0), Color::linear_rgb(0.5, 0.5, 1.0));
+    assert_color(gradient.sample(18.0), Color::linear_rgb(0.0, 0.0, 1.0));
+    // From 18h through midnight back to 6h
+    assert_color(gradient.sample(0.0), Color::linear_rgb(0.5, 0.5, 1.0));
+    assert_color(gradient.sample(3.0), Color::linear_rgb(0.75, 0.75, 1.0));
+    assert_color(gradient.sample(27.0), Color::linear_rgb(0.75, 0.75, 1.0));
+    // No keys leaves colors alone
+    assert_color(TintGradient::default().sample(9.0), Color::WHITE);
+}
+
+#[test]
+fn the_clock_advances_and_wraps() {
+    let mut clock = TimeOfDay::new(23.0, 240.0);
+    // Ten seconds is an hour
+    clock.advance(10.0);
+    assert!(clock.hour.abs() < 1e-4, "{}", clock.hour);
+    clock.advance(25.0);
+    assert!((clock.hour - 2.5).abs() < 1e-4, "{}", clock.hour);
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, GlobalTintPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .insert_resource(TimeOfDay::new(6.0, 2.4));
+    // The first frame has no delta
+    app.update();
+    app.update();
+    app.update();
+    let hour = app.world().resource::<TimeOfDay>().hour;
+    assert!((hour - 8.0).abs() < 1e-4, "{}", hour);
+}
+
+#[test]
+fn the_clear_color_is_tinted_from_its_untinted_base() {
+    let dir = common::temp_dir("gradients");
+    let path = dir.join("dusk.gradient.ron");
+    gradient().save(&path).unwrap();
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, GlobalTintPlugin))
+        .insert_resource(ClearColor(Color::linear_rgb(0.5, 0.5, 0.5)))
+        .insert_resource(TimeOfDay {
+            running: false,
+            ..TimeOfDay::new(12.0, 240.0)
+        });
+    // Nothing is tinted without a GlobalTint
+    app.update();
+    assert_color(app.world().resource::<CurrentTint>().color, Color::WHITE);
+
+    let tint = app
+        .world_mut()
+        .spawn(GlobalTint::new(path.to_string_lossy()))
+        .id();
+    app.update();
+    assert_eq!(app.world().resource::<CurrentTint>().gradient, gradient());
+    assert_color(
+        app.world().resource::<CurrentTint>().color,
+        Color::linear_rgb(0.5, 0.5, 1.0),
+    );
+    assert_color(
+        app.world().resource::<ClearColor>().0,
+        Color::linear_rgb(0.25, 0.25, 0.5),
+    );
+
+    // Tinting again starts from the base color, not the tinted one
+    app.world_mut().resource_mut::<TimeOfDay>().hour = 18.0;
+    app.update();
+    assert_color(
+        app.world().resource::<ClearColor>().0,
+        Color::linear_rgb(0.0, 0.0, 0.5),
+    );
+
+    // A new clear color becomes the base
+    app.world_mut().resource_mut::<ClearColor>().0 = Color::linear_rgb(1.0, 1.0, 1.0);
+    app.update();
+    assert_color(
+        app.world().resource::<ClearColor>().0,
+        Color::linear_rgb(0.0, 0.0, 1.0),
+    );
+
+    // Removing the tint restores it
+    app.world_mut().entity_mut(tint).despawn();
+    app.update();
+    assert_color(app.world().resource::<ClearColor>().0, Color::WHITE);
+    std::fs::remove_dir_all(&dir).ok();
+}
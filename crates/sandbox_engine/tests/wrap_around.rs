@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for screen-edge world wrapping.
+
+use bevy::prelude::*;
+use sandbox_engine::wrap::{WrapAround, WrapAroundPlugin};
+
+fn bounds() -> Rect {
+    Rect::new(-100.0, -50.0, 100.0, 50.0)
+}
+
+#[test]
+fn positions_outside_the_bounds_wrap_to_the_opposite_edge() {
+    let wrap = WrapAround::new(bounds());
+    assert_eq!(wrap.wrap(Vec2::new(10.0, 20.0)), Vec2::new(10.0, 20.0));
+    assert_eq!(wrap.wrap(Vec2::new(100.0, 50.0)), Vec2::new(100.0, 50.0));
+    // The overshoot is carried over to the other side
+    assert_eq!(wrap.wrap(Vec2::new(110.0, 0.0)), Vec2::new(-90.0, 0.0));
+    assert_eq!(wrap.wrap(Vec2::new(-105.0, -60.0)), Vec2::new(95.0, 40.0));
+    // Empty bounds leave positions alone
+    let empty = WrapAround::new(Rect::new(0.0, 0.0, 0.0, 0.0));
+    assert_eq!(empty.wrap(Vec2::new(5.0, -5.0)), Vec2::new(5.0, -5.0));
+}
+
+#[test]
+fn entities_leaving_their_bounds_are_teleported() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, WrapAroundPlugin));
+    let leaving = app
+        .world_mut()
+        .spawn((
+            WrapAround::new(bounds()),
+            Transform::from_xyz(120.0, 0.0, 3.0),
+        ))
+        .id();
+    let inside = app
+        .world_mut()
+        .spawn((
+            WrapAround::new(bounds()),
+            Transform::from_xyz(20.0, 0.0, 0.0),
+        ))
+        .id();
+    app.update();
+
+    let world = app.world();
+    assert_eq!(
+        world.get::<Transform>(leaving).unwrap().translation,
+        Vec3::new(-80.0, 0.0, 3.0)
+    );
+    assert_eq!(
+        world.get::<Transform>(inside).unwrap().translation,
+        Vec3::new(20.0, 0.0, 0.0)
+    );
+}
@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2026 the Sandbox contributors
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Editor integration: custom inspectors for spaceminer's components.
+
+use bevy::prelude::*;
+use sandbox_editor::custom_inspector::RegisterInspectorExt;
+use sandbox_editor::egui;
+
+use super::{Ship, Velocity, MAX_SPEED};
+
+/// Plugin registering spaceminer's inspectors with the editor.
+pub struct SpaceminerEditorPlugin;
+
+impl Plugin for SpaceminerEditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_inspector::<Ship>(ship_inspector)
+            .register_inspector::<Velocity>(velocity_inspector);
+    }
+}
+
+fn ship_inspector(ui: &mut egui::Ui, _world: &mut World, _entity: Entity) {
+    ui.label("Player-controlled (WASD), followed by the camera");
+}
+
+/// Shows speed against the cap and edits velocity as speed and heading.
+fn velocity_inspector(ui: &mut egui::Ui, world: &mut World, entity: Entity) {
+    let Some(velocity) = world.get::<Velocity>(entity) else {
+        return;
+    };
+    let mut speed = velocity.0.length();
+    let mut heading = velocity.0.to_angle().to_degrees();
+
+    ui.add(
+        egui::ProgressBar::new(speed / MAX_SPEED).text(format!("{:.0} / {:.0}", speed, MAX_SPEED)),
+    );
+    let mut changed = false;
+    egui::Grid::new(("velocity_inspector", entity))
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label("Speed");
+            changed |= ui
+                .add(egui::DragValue::new(&mut speed).range(0.0..=MAX_SPEED))
+                .changed();
+            ui.end_row();
+            ui.label("Heading");
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut heading)
+                        .range(-180.0..=180.0)
+                        .suffix("°"),
+                )
+                .changed();
+            ui.end_row();
+        });
+    let stop = ui.button("Stop").clicked();
+
+    if changed || stop {
+        let value = if stop {
+            Vec2::ZERO
+        } else {
+            Vec2::from_angle(heading.to_radians()) * speed
+        };
+        if let Some(mut velocity) = world.get_mut::<Velocity>(entity) {
+            velocity.0 = value;
+        }
+    }
+}
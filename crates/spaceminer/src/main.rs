@@ -6,25 +6,66 @@
 use bevy::prelude::*;
 use sandbox_engine::prelude::*;
 
+#[cfg(feature = "editor")]
+mod editor;
+
 fn main() {
-    App::new()
-        .add_plugins(DefaultPlugins.set(WindowPlugin {
-            primary_window: Some(Window {
-                title: "Spaceminer".into(),
+    let project_root = ProjectRoot::discover();
+
+    let mut app = App::new();
+    app.add_plugins(
+        DefaultPlugins
+            .set(WindowPlugin {
+                primary_window: Some(Window {
+                    title: "Spaceminer".into(),
+                    ..default()
+                }),
+                // In the editor, closing asks to save unsaved changes first
+                close_when_requested: !cfg!(feature = "editor"),
                 ..default()
-            }),
-            ..default()
-        }))
-        .add_plugins(EditorStatePlugin)
-        .register_type::<Ship>()
+            })
+            .set(project_root.asset_plugin()),
+    )
+    .insert_resource(project_root);
+
+    // The editor brings the engine plugins along with its own
+    #[cfg(feature = "editor")]
+    app.add_plugins((sandbox_editor::EditorPlugin, editor::SpaceminerEditorPlugin));
+    #[cfg(not(feature = "editor"))]
+    app.add_plugins(SandboxPlugins);
+
+    app.register_type::<Ship>()
         .register_type::<Velocity>()
+        .register_type::<Asteroid>()
         .add_systems(Startup, setup)
         .add_systems(
             Update,
-            (ship_input, apply_drag, apply_velocity, camera_follow)
+            (
+                ship_input.toggleable().run_if(not(sequence_playing)),
+                apply_drag.toggleable(),
+                apply_velocity.toggleable(),
+                camera_follow.toggleable().run_if(not(sequence_playing)),
+            )
                 .chain()
                 .in_set(GameplaySystemSet),
         )
+        .add_systems(
+            Update,
+            (
+                dock.toggleable(),
+                mine.toggleable(),
+                collect_ore.toggleable(),
+            )
+                .in_set(GameplaySystemSet),
+        )
+        .add_systems(Update, add_asteroid_sprites)
+        .add_systems(
+            Update,
+            place_ship_at_start
+                .toggleable()
+                .before(ship_input)
+                .in_set(GameplaySystemSet),
+        )
         .run();
 }
 
@@ -38,28 +79,93 @@ struct Ship;
 #[reflect(Component)]
 struct Velocity(Vec2);
 
+/// An asteroid that can be mined, drawn as a square of `size`.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Asteroid {
+    size: f32,
+}
+
 /// Movement constants
 const THRUST: f32 = 500.0;
 const DRAG: f32 = 0.98;
 const MAX_SPEED: f32 = 400.0;
 
+/// Mining constants
+const MINING_RANGE: f32 = 100.0;
+const MINING_DAMAGE_PER_SECOND: f32 = 20.0;
+
 fn setup(mut commands: Commands) {
     // Spawn camera
     commands.spawn(Camera2d);
 
     // Spawn player ship as a colored rectangle
+    let ship = commands
+        .spawn((
+            Name::new("Player Ship"),
+            Ship,
+            Interactor,
+            Velocity::default(),
+            Collider::rectangle(Vec2::new(40.0, 50.0)),
+            // Flying off the starfield brings the ship back on the other side
+            WrapAround::new(Rect::new(-1000.0, -1000.0, 1000.0, 1000.0)),
+            Sprite {
+                color: Color::srgb(0.2, 0.6, 0.9),
+                custom_size: Some(Vec2::new(40.0, 50.0)),
+                ..default()
+            },
+            Transform::default(),
+        ))
+        .id();
+
+    // Nameplate above the ship
+    commands.spawn((
+        Text::new("Player"),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        WorldAnchoredUi::new(ship).with_offset(Vec2::new(0.0, 35.0)),
+    ));
+
+    // Where the ship is placed when a scene loads
     commands.spawn((
-        Name::new("Player Ship"),
-        Ship,
-        Velocity::default(),
+        Name::new("Player Start"),
+        SpawnPoint::new(PLAYER_START, SpawnKind::Player),
+        Transform::default(),
+    ));
+
+    // Space station the ship can dock at
+    commands.spawn((
+        Name::new("Space Station"),
+        Interactable::new("Dock", 120.0),
+        // Docking opens a conversation with the station's trader
+        DialogueTrigger::new("dialogue/station.dialogue.ron"),
         Sprite {
-            color: Color::srgb(0.2, 0.6, 0.9),
-            custom_size: Some(Vec2::new(40.0, 50.0)),
+            color: Color::srgb(0.6, 0.6, 0.7),
+            custom_size: Some(Vec2::new(80.0, 80.0)),
             ..default()
         },
+        Transform::from_xyz(300.0, 200.0, 0.0),
+    ));
+
+    // Opening cutscene, played when the game starts
+    commands.spawn((
+        Name::new("Intro"),
+        SequencePlayer::on_start("sequences/intro.sequence.ron"),
         Transform::default(),
     ));
 
+    // Asteroid that breaks into chunks when mined
+    commands.spawn((
+        Name::new("Asteroid"),
+        Asteroid { size: 40.0 },
+        Destructible::new(30.0).with_stage(
+            DestructionStage::new(0.0).with_debris("prefabs/asteroid_chunk.scn.ron", 3),
+        ),
+        Transform::from_xyz(-250.0, 150.0, 0.0),
+    ));
+
     // Spawn some background stars for visual reference
     for i in 0..50 {
         let x = ((i * 137) % 2000) as f32 - 1000.0;
@@ -77,6 +183,25 @@ fn setup(mut commands: Commands) {
     }
 }
 
+/// Moves the ship to the player start whenever spawn points are loaded.
+fn place_ship_at_start(
+    added: Query<(), Added<SpawnPoint>>,
+    spawns: SpawnPoints,
+    mut ships: Query<(&mut Transform, &mut Velocity), With<Ship>>,
+) {
+    if added.is_empty() {
+        return;
+    }
+    let Some(start) = spawns.find_spawn(PLAYER_START) else {
+        return;
+    };
+    for (mut transform, mut velocity) in &mut ships {
+        transform.translation.x = start.translation.x;
+        transform.translation.y = start.translation.y;
+        velocity.0 = Vec2::ZERO;
+    }
+}
+
 fn ship_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     time: Res<Time>,
@@ -130,6 +255,73 @@ fn apply_velocity(time: Res<Time>, mut query: Query<(&Velocity, &mut Transform)>
     }
 }
 
+fn dock(
+    mut events: EventReader<InteractEvent>,
+    names: Query<&Name>,
+    mut ships: Query<&mut Velocity, With<Ship>>,
+) {
+    for event in events.read() {
+        if let Ok(mut velocity) = ships.get_mut(event.interactor) {
+            velocity.0 = Vec2::ZERO;
+        }
+        if let Ok(name) = names.get(event.target) {
+            info!("Docked at {}", name);
+        }
+    }
+}
+
+/// Gives asteroids, including chunks spawned from prefabs, their sprite.
+fn add_asteroid_sprites(
+    mut commands: Commands,
+    asteroids: Query<(Entity, &Asteroid), Added<Asteroid>>,
+) {
+    for (entity, asteroid) in &asteroids {
+        commands.entity(entity).insert(Sprite {
+            color: Color::srgb(0.6, 0.5, 0.4),
+            custom_size: Some(Vec2::splat(asteroid.size)),
+            ..default()
+        });
+    }
+}
+
+/// Holding Space grinds down the nearest asteroid in range.
+fn mine(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    ships: Query<&Transform, With<Ship>>,
+    mut asteroids: Query<(&Transform, &mut Destructible), With<Asteroid>>,
+) {
+    if !keyboard.pressed(KeyCode::Space) {
+        return;
+    }
+    let Ok(ship) = ships.get_single() else {
+        return;
+    };
+    let ship = ship.translation.truncate();
+    let nearest = asteroids
+        .iter_mut()
+        .map(|(transform, destructible)| {
+            (
+                transform.translation.truncate().distance(ship),
+                destructible,
+            )
+        })
+        .filter(|(distance, _)| *distance <= MINING_RANGE)
+        .min_by(|a, b| a.0.total_cmp(&b.0));
+    if let Some((_, mut destructible)) = nearest {
+        destructible.damage(MINING_DAMAGE_PER_SECOND * time.delta_secs());
+    }
+}
+
+/// Every destroyed asteroid and chunk yields ore to sell at the station.
+fn collect_ore(mut events: EventReader<DestructionEvent>, mut flags: ResMut<WorldFlags>) {
+    for event in events.read() {
+        if event.kind == DestructionEventKind::Destroyed {
+            flags.add("ore", 1.0);
+        }
+    }
+}
+
 fn camera_follow(
     ship_query: Query<&Transform, With<Ship>>,
     mut camera_query: Query<&mut Transform, (With<Camera2d>, Without<Ship>)>,